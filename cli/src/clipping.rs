@@ -0,0 +1,199 @@
+use std::{collections::BTreeSet, fmt};
+
+use anyhow::Context;
+use fontheight::{
+    ClippingProfile, Location, Report, Reporter, VerticalExtremes,
+    WordExtremes, WordList,
+};
+use log::warn;
+use serde_json::{Value, json};
+
+use crate::fmt::{
+    json::{AnalyzedExemplarJson, word_extremes_to_json, word_list_to_json},
+    units::Units,
+    words::WordStyle,
+};
+
+/// A platform to check for clipped exemplars with `--clipping`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, clap::ValueEnum)]
+pub enum ClippingArg {
+    /// Windows GDI: OS/2.usWinAscent & usWinDescent
+    Windows,
+    /// macOS & iOS: hhea.ascender & descender
+    Apple,
+    /// Android: OS/2.sTypoAscender & sTypoDescender
+    Android,
+    /// A CSS line box with line-height: normal
+    Css,
+    /// All of the above
+    All,
+}
+
+impl ClippingArg {
+    /// The distinct profiles chosen, in a consistent order.
+    pub fn profiles(args: &[ClippingArg]) -> Vec<ClippingProfile> {
+        args.iter()
+            .flat_map(|arg| match arg {
+                ClippingArg::Windows => &[ClippingProfile::Windows][..],
+                ClippingArg::Apple => &[ClippingProfile::Apple],
+                ClippingArg::Android => &[ClippingProfile::Android],
+                ClippingArg::Css => &[ClippingProfile::Css],
+                ClippingArg::All => &ClippingProfile::ALL,
+            })
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Which exemplars would be clipped by each platform.
+#[derive(Debug)]
+pub struct ClippingAnalysis<'a> {
+    pub profiles: Vec<ProfileClipping<'a>>,
+    /// The units to write the extremes in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+#[derive(Debug)]
+pub struct ProfileClipping<'a> {
+    pub profile: ClippingProfile,
+    pub clip_box: VerticalExtremes,
+    pub clipped: Vec<ClippedWord<'a>>,
+}
+
+/// An exemplar reaching beyond a profile's clip box.
+#[derive(Debug)]
+pub struct ClippedWord<'a> {
+    pub word_list: &'a WordList,
+    pub location: &'a Location,
+    pub word_extremes: WordExtremes<'a>,
+}
+
+/// Check the exemplars of `reports` against the clip box of each profile.
+///
+/// Only the exemplars are checked, so the number of words clipped is a lower
+/// bound.
+pub fn analyse_clipping<'a>(
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    profiles: &[ClippingProfile],
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<ClippingAnalysis<'a>> {
+    let profiles = profiles
+        .iter()
+        .map(|&profile| {
+            let clip_box = reporter.clip_box(profile).with_context(|| {
+                format!("failed to read the {profile} clipping metrics")
+            })?;
+            let clipped = reports
+                .iter()
+                .flat_map(|report| {
+                    // Words can be both the highest & lowest exemplars
+                    let mut seen = BTreeSet::new();
+                    report
+                        .exemplars
+                        .highest()
+                        .iter()
+                        .chain(report.exemplars.lowest())
+                        .filter(move |word_extremes| {
+                            seen.insert(word_extremes.index)
+                        })
+                        .filter(|word_extremes| {
+                            is_clipped(clip_box, word_extremes.extremes)
+                        })
+                        .map(|&word_extremes| ClippedWord {
+                            word_list: report.word_list,
+                            location: report.location,
+                            word_extremes,
+                        })
+                })
+                .collect::<Vec<_>>();
+            if !clipped.is_empty() {
+                warn!(
+                    "{} exemplars would be clipped by {profile}",
+                    clipped.len()
+                );
+            }
+            Ok(ProfileClipping {
+                profile,
+                clip_box,
+                clipped,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(ClippingAnalysis {
+        profiles,
+        units,
+        words,
+    })
+}
+
+pub fn is_clipped(
+    clip_box: VerticalExtremes,
+    extremes: VerticalExtremes,
+) -> bool {
+    extremes.highest() > clip_box.highest()
+        || extremes.lowest() < clip_box.lowest()
+}
+
+impl ClippingAnalysis<'_> {
+    pub fn to_json(&self) -> Value {
+        let profiles = self.profiles.iter().map(|profile| {
+            let clipped = profile
+                .clipped
+                .iter()
+                .map(|clipped| AnalyzedExemplarJson {
+                    exemplar: word_extremes_to_json(
+                        &clipped.word_extremes,
+                        self.units,
+                    ),
+                    details: json!({
+                        "word_list": word_list_to_json(clipped.word_list),
+                        "location": clipped.location,
+                    }),
+                })
+                .collect::<Vec<_>>();
+            let json = json!({
+                "clip_box": {
+                    "lowest": self.units.convert(profile.clip_box.lowest()),
+                    "highest": self.units.convert(profile.clip_box.highest()),
+                },
+                "clipped": clipped,
+            });
+            (profile.profile.name().to_owned(), json)
+        });
+        Value::Object(profiles.collect())
+    }
+}
+
+impl fmt::Display for ClippingAnalysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  clipping:")?;
+        self.profiles.iter().try_for_each(|profile| {
+            write!(
+                f,
+                "\n    {} ({} to {}): {} exemplars clipped",
+                profile.profile,
+                self.units.convert(profile.clip_box.lowest()),
+                self.units.convert(profile.clip_box.highest()),
+                profile.clipped.len(),
+            )?;
+            profile.clipped.iter().try_for_each(|clipped| {
+                let extremes = clipped.word_extremes.extremes;
+                write!(
+                    f,
+                    "\n      {} ({} to {}) in {} @ {:?}",
+                    self.words.display(clipped.word_extremes.word),
+                    self.units.convert(extremes.lowest()),
+                    self.units.convert(extremes.highest()),
+                    clipped.word_list.id(),
+                    clipped.location,
+                )
+            })
+        })
+    }
+}
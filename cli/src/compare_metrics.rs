@@ -0,0 +1,184 @@
+use std::fmt;
+
+use anyhow::Context;
+use fontheight::{
+    ClippingProfile, Extreme, LineMetrics, Report, Reporter, WordExtremes,
+};
+use serde_json::{Value, json};
+
+use crate::fmt::{units::Units, words::WordStyle};
+
+/// Whether the most extreme words checked stay within each of the font's
+/// vertical metrics.
+#[derive(Debug)]
+pub struct MetricComparison<'a> {
+    pub verdicts: Vec<MetricVerdict<'a>>,
+    /// The units to write the metrics, extremes & margins in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+/// A metric, and the word reaching furthest towards it across every report.
+#[derive(Debug)]
+pub struct MetricVerdict<'a> {
+    /// The metric's name, e.g. `OS/2.usWinAscent`
+    pub metric: &'static str,
+    /// The metric's value in font units, negative below the baseline (so
+    /// descents are negated)
+    pub value: f64,
+    /// Whether the metric is an ascent (compared with the highest word) or a
+    /// descent (compared with the lowest)
+    pub extreme: Extreme,
+    pub word: WordExtremes<'a>,
+    /// The report the word was found in
+    pub report: &'a Report<'a>,
+}
+
+impl MetricVerdict<'_> {
+    /// How high or low the word reached, in font units.
+    pub fn observed(&self) -> f64 {
+        match self.extreme {
+            Extreme::Highest => self.word.highest(),
+            Extreme::Lowest => self.word.lowest(),
+        }
+    }
+
+    /// How far the word stayed within the metric, in font units. Negative if
+    /// it reached beyond it.
+    pub fn margin(&self) -> f64 {
+        match self.extreme {
+            Extreme::Highest => self.value - self.observed(),
+            Extreme::Lowest => self.observed() - self.value,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.margin() >= 0.0
+    }
+
+    pub fn verdict(&self) -> &'static str {
+        if self.passed() { "PASS" } else { "FAIL" }
+    }
+}
+
+/// Compare the `OS/2` win & typo metrics and the `hhea` ascender & descender
+/// with the most extreme words in `reports`.
+///
+/// There are no verdicts if no words were measured.
+pub fn compare_metrics<'a>(
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<MetricComparison<'a>> {
+    let win = reporter
+        .clip_box(ClippingProfile::Windows)
+        .context("failed to read OS/2")?;
+    let typo = reporter
+        .line_box(LineMetrics::Typo)
+        .context("failed to read OS/2")?;
+    let hhea = reporter
+        .line_box(LineMetrics::Hhea)
+        .context("failed to read hhea")?;
+    let metrics = [
+        ("OS/2.usWinAscent", win.highest(), Extreme::Highest),
+        ("OS/2.usWinDescent", win.lowest(), Extreme::Lowest),
+        ("OS/2.sTypoAscender", typo.ascender, Extreme::Highest),
+        ("OS/2.sTypoDescender", typo.descender, Extreme::Lowest),
+        ("hhea.ascender", hhea.ascender, Extreme::Highest),
+        ("hhea.descender", hhea.descender, Extreme::Lowest),
+    ];
+    let verdicts = metrics
+        .into_iter()
+        .filter_map(|(metric, value, extreme)| {
+            let (word, report) = most_extreme(reports, extreme)?;
+            Some(MetricVerdict {
+                metric,
+                value,
+                extreme,
+                word,
+                report,
+            })
+        })
+        .collect();
+    Ok(MetricComparison {
+        verdicts,
+        units,
+        words,
+    })
+}
+
+/// The word reaching furthest in `extreme`'s direction, and its report.
+fn most_extreme<'a>(
+    reports: &'a [Report<'a>],
+    extreme: Extreme,
+) -> Option<(WordExtremes<'a>, &'a Report<'a>)> {
+    let candidates = reports.iter().filter_map(|report| {
+        let exemplars = match extreme {
+            Extreme::Highest => report.exemplars.highest(),
+            Extreme::Lowest => report.exemplars.lowest(),
+        };
+        exemplars.first().map(|exemplar| (*exemplar, report))
+    });
+    match extreme {
+        Extreme::Highest => candidates
+            .max_by(|(a, _), (b, _)| a.highest().total_cmp(&b.highest())),
+        Extreme::Lowest => candidates
+            .min_by(|(a, _), (b, _)| a.lowest().total_cmp(&b.lowest())),
+    }
+}
+
+impl MetricComparison<'_> {
+    /// The number of metrics words reached beyond.
+    pub fn failures(&self) -> usize {
+        self.verdicts
+            .iter()
+            .filter(|verdict| !verdict.passed())
+            .count()
+    }
+
+    pub fn to_json(&self) -> Value {
+        self.verdicts
+            .iter()
+            .map(|verdict| {
+                json!({
+                    "metric": verdict.metric,
+                    "value": self.units.convert(verdict.value),
+                    "observed": self.units.convert(verdict.observed()),
+                    "margin": self.units.convert(verdict.margin()),
+                    "verdict": verdict.verdict(),
+                    "word": verdict.word.word,
+                    "index": verdict.word.index,
+                    "word_list": verdict.report.word_list.id().to_string(),
+                    "location": verdict.report.location,
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for MetricComparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  metric verdicts:")?;
+        write!(
+            f,
+            "\n    {:<20} {:>10} {:>10} {:>10}  {:<7} word",
+            "metric", "value", "observed", "margin", "verdict"
+        )?;
+        self.verdicts.iter().try_for_each(|verdict| {
+            write!(
+                f,
+                "\n    {:<20} {:>10} {:>10} {:>10}  {:<7} {} ({} @ {:?})",
+                verdict.metric,
+                self.units.convert(verdict.value),
+                self.units.convert(verdict.observed()),
+                self.units.convert(verdict.margin()),
+                verdict.verdict(),
+                self.words.display(verdict.word.word),
+                verdict.report.word_list.id(),
+                verdict.report.location,
+            )
+        })
+    }
+}
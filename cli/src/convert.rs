@@ -0,0 +1,60 @@
+use std::{
+    fs::OpenOptions,
+    io::{Write, stdout},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use serde_json::Value;
+
+use crate::fmt::{OutputFormat, read_document, write_document};
+
+/// Convert reports between JSON & CBOR
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The report to convert, written by --format json or --format cbor
+    report_path: PathBuf,
+
+    /// The format to convert the report to.
+    /// Defaults to CBOR for JSON reports, and JSON for CBOR reports
+    #[arg(long, value_enum)]
+    to: Option<DocumentFormat>,
+
+    /// Write the converted report into the given path.
+    /// Will print to stdout if not specified
+    #[arg(short, long = "output")]
+    output_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, clap::ValueEnum)]
+enum DocumentFormat {
+    /// JSON
+    Json,
+    /// CBOR
+    Cbor,
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let (document, format) = read_document::<Value>(&args.report_path)?;
+    let to = match (args.to, format) {
+        (Some(DocumentFormat::Json), _) => OutputFormat::Json,
+        (Some(DocumentFormat::Cbor), _) => OutputFormat::Cbor,
+        (None, OutputFormat::Json) => OutputFormat::Cbor,
+        (None, _) => OutputFormat::Json,
+    };
+
+    let mut output: Box<dyn Write> = match &args.output_path {
+        None => Box::new(stdout().lock()),
+        Some(path) => {
+            let handle = OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .create(true)
+                .open(path)
+                .context("failed to open output file")?;
+            Box::new(handle)
+        },
+    };
+    write_document(&mut output, to, &document)
+        .context("failed to write to output")
+}
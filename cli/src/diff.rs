@@ -0,0 +1,172 @@
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{Write, stdout},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use fontheight::{
+    AnalysisOptions, InstanceReporter, Report, Reporter, VerticalExtremes,
+};
+use log::{info, warn};
+use rayon::prelude::*;
+use skrifa::raw::TableProvider;
+
+use crate::fmt::{
+    html::{self, ReportDiff, Theme},
+    units::UnitArgs,
+};
+
+/// Compare two versions of a font, drawing the exemplars of the new font with
+/// both fonts side-by-side in an HTML report
+///
+/// The same words are drawn at the same locations in both fonts, with how much
+/// higher or lower they reach in the new font
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The previous version of the font
+    old_font_path: PathBuf,
+
+    /// The new version of the font, whose exemplars are compared
+    new_font_path: PathBuf,
+
+    /// The number of exemplars to compare from each word list
+    #[arg(short = 'n', long, default_value_t = 5)]
+    results: usize,
+
+    /// The number of words from each list to test [default: all words]
+    #[arg(short = 'k', long = "words")]
+    words_per_list: Option<usize>,
+
+    /// Write the report into the given path.
+    /// Will print to stdout if not specified
+    #[arg(short, long = "output")]
+    output_path: Option<PathBuf>,
+
+    #[command(flatten)]
+    units: UnitArgs,
+
+    /// The colour scheme of the report. Printed reports are always light
+    #[arg(long, value_enum, default_value_t)]
+    theme: Theme,
+}
+
+/// How high & low each of the report's exemplars reach in the old font, by
+/// their index in the word list, for those it can shape
+fn old_extremes(
+    old_instance: &InstanceReporter,
+    report: &Report,
+) -> anyhow::Result<HashMap<usize, VerticalExtremes>> {
+    report
+        .exemplars
+        .highest()
+        .iter()
+        .chain(report.exemplars.lowest())
+        .filter_map(|exemplar| {
+            let runs = old_instance
+                .run_extremes(report.word_list, exemplar.word)
+                .transpose()?;
+            let extremes = runs.map(|runs| {
+                runs.into_iter()
+                    .map(|run| run.extremes)
+                    .reduce(VerticalExtremes::merge)
+            });
+            extremes
+                .map(|extremes| {
+                    extremes.map(|extremes| (exemplar.index, extremes))
+                })
+                .map_err(anyhow::Error::from)
+                .transpose()
+        })
+        .collect()
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let old_font_bytes =
+        fs::read(&args.old_font_path).context("failed to read old font")?;
+    let new_font_bytes =
+        fs::read(&args.new_font_path).context("failed to read new font")?;
+    let old_reporter = Reporter::new(&old_font_bytes)?;
+    let new_reporter = Reporter::new(&new_font_bytes)?;
+    let units = args.units.for_font(
+        new_reporter
+            .fontref()
+            .head()
+            .context("failed to read head")?
+            .units_per_em(),
+    );
+
+    let word_lists = static_lang_word_lists::ALL_WORD_LISTS
+        .iter()
+        .copied()
+        .filter(|word_list| new_reporter.supports_word_list(word_list))
+        .collect::<Vec<_>>();
+    let locations = new_reporter.interesting_locations();
+    info!(
+        "comparing {} word lists at {} locations",
+        word_lists.len(),
+        locations.len(),
+    );
+
+    let options = AnalysisOptions::new()
+        .with_words_limit(args.words_per_list)
+        .with_exemplars(args.results);
+    let diffs = locations
+        .par_iter()
+        .map(|location| {
+            let new_instance = new_reporter.instance(location)?;
+            // Axes may have been added, removed, or had their ranges changed
+            let old_instance = old_reporter
+                .instance(location)
+                .inspect_err(|err| {
+                    warn!(
+                        "can't compare at {location:?}, it isn't in the old \
+                         font: {err}"
+                    );
+                })
+                .ok();
+            word_lists
+                .iter()
+                .map(|word_list| {
+                    let report = new_instance.check(word_list, &options)?;
+                    let old_extremes = old_instance
+                        .as_ref()
+                        .map(|old_instance| old_extremes(old_instance, &report))
+                        .transpose()?;
+                    Ok(ReportDiff {
+                        report,
+                        old_extremes,
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let html = html::format_font_diff(
+        &diffs,
+        &new_reporter,
+        &old_reporter,
+        units,
+        args.theme,
+    )?;
+    let mut output: Box<dyn Write> = match &args.output_path {
+        None => Box::new(stdout().lock()),
+        Some(path) => {
+            let handle = OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .create(true)
+                .open(path)
+                .context("failed to open output file")?;
+            Box::new(handle)
+        },
+    };
+    output
+        .write_all(html.as_bytes())
+        .context("failed to write to output")?;
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use std::{
+    ffi::OsStr,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use anyhow::{Context, bail};
+use flate2::{Compression, write::GzEncoder};
+use fontheight::{Location, WordExtremes, WordList};
+use serde_json::json;
+
+use crate::{fmt::units::Units, glyph_bounds::csv_field};
+
+/// How detailed outputs are written, chosen by the file extension.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum DumpFormat {
+    /// `.csv`, a row per word, with a header
+    Csv,
+    /// `.jsonl` or `.ndjson`, a JSON object per line
+    JsonLines,
+}
+
+enum DumpWriter {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl DumpWriter {
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Self::Plain(mut writer) => writer.flush(),
+            Self::Gzip(encoder) => encoder.finish()?.flush(),
+        }
+    }
+}
+
+impl Write for DumpWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(writer) => writer.write(buf),
+            Self::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(writer) => writer.flush(),
+            Self::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+struct DumpState {
+    writer: DumpWriter,
+    // Words are written from the threads measuring them, which can't fail, so
+    // the first error is kept until the dump is finished
+    error: Option<io::Error>,
+}
+
+/// Streams the extremes of every word checked to a file as they're measured,
+/// rather than keeping them in memory, so entire word lists can be exported.
+pub struct WordDump {
+    format: DumpFormat,
+    state: Mutex<DumpState>,
+}
+
+impl WordDump {
+    /// Create the dump file, as CSV (`.csv`) or JSON Lines (`.jsonl` or
+    /// `.ndjson`), gzip compressed if the path also ends with `.gz`.
+    pub fn create(path: &Path) -> anyhow::Result<Self> {
+        let compressed = path.extension() == Some(OsStr::new("gz"));
+        let uncompressed_path = if compressed {
+            Path::new(path.file_stem().unwrap_or_default())
+        } else {
+            path
+        };
+        let format = match uncompressed_path.extension().and_then(OsStr::to_str)
+        {
+            Some("csv") => DumpFormat::Csv,
+            Some("jsonl" | "ndjson") => DumpFormat::JsonLines,
+            _ => bail!(
+                "can't tell what format to dump words in from {}, use a .csv, \
+                 .jsonl or .ndjson extension (optionally followed by .gz)",
+                path.display(),
+            ),
+        };
+
+        let file = File::create(path).with_context(|| {
+            format!("failed to create dump file {}", path.display())
+        })?;
+        let file = BufWriter::new(file);
+        let mut writer = if compressed {
+            DumpWriter::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            DumpWriter::Plain(file)
+        };
+        if format == DumpFormat::Csv {
+            writeln!(
+                writer,
+                "font,word_list,location,index,word,lowest,highest"
+            )
+            .context("failed to write to dump file")?;
+        }
+        Ok(Self {
+            format,
+            state: Mutex::new(DumpState {
+                writer,
+                error: None,
+            }),
+        })
+    }
+
+    /// Write a word's extremes, from checking `word_list` in `font` at
+    /// `location`.
+    pub fn write(
+        &self,
+        font: &str,
+        word_list: &WordList,
+        location: &Location,
+        word_extremes: &WordExtremes,
+        units: Units,
+    ) {
+        let lowest = units.convert(word_extremes.extremes.lowest());
+        let highest = units.convert(word_extremes.extremes.highest());
+        // Formatted before locking, so other threads can carry on writing
+        let line = match self.format {
+            DumpFormat::Csv => format!(
+                "{},{},{},{},{},{lowest},{highest}\n",
+                csv_field(font),
+                csv_field(&word_list.id().to_string()),
+                csv_field(&location.to_string()),
+                word_extremes.index,
+                csv_field(word_extremes.word),
+            ),
+            DumpFormat::JsonLines => {
+                let json = json!({
+                    "font": font,
+                    "word_list": word_list.id().to_string(),
+                    "location": location,
+                    "index": word_extremes.index,
+                    "word": word_extremes.word,
+                    "lowest": lowest,
+                    "highest": highest,
+                });
+                format!("{json}\n")
+            },
+        };
+
+        let mut state =
+            self.state.lock().unwrap_or_else(|err| err.into_inner());
+        if state.error.is_none()
+            && let Err(err) = state.writer.write_all(line.as_bytes())
+        {
+            state.error = Some(err);
+        }
+    }
+
+    /// Flush the dump file, reporting any error writing to it.
+    pub fn finish(self) -> anyhow::Result<()> {
+        let state = self
+            .state
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner());
+        if let Some(err) = state.error {
+            return Err(err).context("failed to write to dump file");
+        }
+        state
+            .writer
+            .finish()
+            .context("failed to write to dump file")
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::BTreeSet, fmt};
+
+use anyhow::Context;
+use fontheight::{
+    EmBox, Location, Report, Reporter, WordExtremes, WordList, is_cjk_script,
+};
+use log::warn;
+use serde_json::{Value, json};
+
+use crate::fmt::{
+    json::{AnalyzedExemplarJson, word_extremes_to_json, word_list_to_json},
+    units::Units,
+    words::WordStyle,
+};
+
+/// Which exemplars of CJK word lists reach beyond the font's ideographic
+/// em-box, at each location checked.
+#[derive(Debug)]
+pub struct EmBoxAnalysis<'a> {
+    pub em_box: EmBox,
+    /// The locations CJK word lists were checked at, in the order checked
+    pub locations: Vec<LocationProtrusions<'a>>,
+    /// The units to write the extremes in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+#[derive(Debug)]
+pub struct LocationProtrusions<'a> {
+    pub location: &'a Location,
+    /// Exemplars reaching above the em-box
+    pub above: Vec<Protrusion<'a>>,
+    /// Exemplars reaching below the em-box
+    pub below: Vec<Protrusion<'a>>,
+}
+
+/// An exemplar reaching beyond the em-box.
+#[derive(Debug)]
+pub struct Protrusion<'a> {
+    pub word_list: &'a WordList,
+    pub word_extremes: WordExtremes<'a>,
+    /// How far beyond the em-box the exemplar reaches, in font units
+    pub overflow: f64,
+}
+
+/// Check the exemplars of the CJK word lists in `reports` against the font's
+/// ideographic em-box.
+///
+/// Only the exemplars are checked, so the number of words reaching beyond the
+/// em-box is a lower bound.
+pub fn analyse_em_box<'a>(
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<EmBoxAnalysis<'a>> {
+    let em_box = reporter
+        .ideographic_em_box()
+        .context("failed to read the ideographic em-box")?;
+    let cjk_reports = reports
+        .iter()
+        .filter(|report| report.word_list.script().is_some_and(is_cjk_script))
+        .collect::<Vec<_>>();
+
+    let mut locations = Vec::<LocationProtrusions>::new();
+    for report in cjk_reports {
+        let index = match locations
+            .iter()
+            .position(|checked| checked.location == report.location)
+        {
+            Some(index) => index,
+            None => {
+                locations.push(LocationProtrusions {
+                    location: report.location,
+                    above: Vec::new(),
+                    below: Vec::new(),
+                });
+                locations.len() - 1
+            },
+        };
+        let checked = &mut locations[index];
+        // Words can be both the highest & lowest exemplars
+        let mut seen = BTreeSet::new();
+        report
+            .exemplars
+            .highest()
+            .iter()
+            .chain(report.exemplars.lowest())
+            .filter(|word_extremes| seen.insert(word_extremes.index))
+            .for_each(|&word_extremes| {
+                let protrusion = |overflow| Protrusion {
+                    word_list: report.word_list,
+                    word_extremes,
+                    overflow,
+                };
+                if let Some(overflow) = em_box.above(word_extremes.extremes) {
+                    checked.above.push(protrusion(overflow));
+                }
+                if let Some(overflow) = em_box.below(word_extremes.extremes) {
+                    checked.below.push(protrusion(overflow));
+                }
+            });
+    }
+
+    let protrusions = locations
+        .iter()
+        .map(|checked| checked.above.len() + checked.below.len())
+        .sum::<usize>();
+    if protrusions > 0 {
+        warn!(
+            "{protrusions} exemplars of CJK word lists reach beyond the \
+             ideographic em-box (from {})",
+            em_box.source,
+        );
+    }
+    Ok(EmBoxAnalysis {
+        em_box,
+        locations,
+        units,
+        words,
+    })
+}
+
+impl EmBoxAnalysis<'_> {
+    pub fn to_json(&self) -> Value {
+        let units = self.units;
+        let protrusions_to_json = |protrusions: &[Protrusion]| {
+            protrusions
+                .iter()
+                .map(|protrusion| AnalyzedExemplarJson {
+                    exemplar: word_extremes_to_json(
+                        &protrusion.word_extremes,
+                        units,
+                    ),
+                    details: json!({
+                        "word_list": word_list_to_json(protrusion.word_list),
+                        "overflow": units.convert(protrusion.overflow),
+                    }),
+                })
+                .collect::<Vec<_>>()
+        };
+        let locations = self
+            .locations
+            .iter()
+            .map(|checked| {
+                json!({
+                    "location": checked.location,
+                    "above": protrusions_to_json(&checked.above),
+                    "below": protrusions_to_json(&checked.below),
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({
+            "source": self.em_box.source.name(),
+            "top": units.convert(self.em_box.top),
+            "bottom": units.convert(self.em_box.bottom),
+            "locations": locations,
+        })
+    }
+}
+
+impl fmt::Display for EmBoxAnalysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = self.units;
+        write!(
+            f,
+            "  ideographic em-box ({} to {}, from {}):",
+            units.convert(self.em_box.bottom),
+            units.convert(self.em_box.top),
+            self.em_box.source,
+        )?;
+        if self.locations.is_empty() {
+            return write!(f, " no CJK word lists were checked");
+        }
+        self.locations.iter().try_for_each(|checked| {
+            write!(
+                f,
+                "\n    @ {:?}: {} exemplars reach above, {} below",
+                checked.location,
+                checked.above.len(),
+                checked.below.len(),
+            )?;
+            [("above", &checked.above), ("below", &checked.below)]
+                .into_iter()
+                .try_for_each(|(direction, protrusions)| {
+                    protrusions.iter().try_for_each(|protrusion| {
+                        write!(
+                            f,
+                            "\n      {} ({} {direction}) in {}",
+                            self.words.display(protrusion.word_extremes.word),
+                            units.convert(protrusion.overflow),
+                            protrusion.word_list.id(),
+                        )
+                    })
+                })
+        })
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    fmt, fs,
+    io::{Write, stdout},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use fontheight::{
+    DeltaAttribution, ExtremeDelta, Location, PlacedGlyph, Reporter, WordList,
+};
+use serde_json::{Value, json};
+use skrifa::{GlyphId, MetadataProvider, raw::FontRef};
+use static_lang_word_lists::WordListRegistry;
+
+use crate::{
+    fmt::{OutputFormat, write_document},
+    glyph_bounds::parse_location,
+};
+
+/// Explain why a word reaches further at one location of a variable font than
+/// another, splitting the difference into the part due to glyph outlines
+/// varying and the part due to positioning (GPOS) varying
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The TTF to shape the word with
+    font_path: PathBuf,
+
+    /// The word to explain
+    word: String,
+
+    /// The location to compare from, as comma-separated axis values (e.g.
+    /// wght=400) [default: the default location]
+    #[arg(long, value_parser = parse_location)]
+    from: Option<Location>,
+
+    /// The location to compare to, as comma-separated axis values (e.g.
+    /// wght=700,wdth=75)
+    #[arg(long, value_parser = parse_location)]
+    to: Location,
+
+    /// Shape the word with the script, language & features of this word list,
+    /// by name or ID (e.g. diffenator_latin) [default: guessed from the word]
+    #[arg(long = "word-list", value_name = "NAME")]
+    word_list: Option<String>,
+
+    /// The format to write the explanation in
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let font_bytes =
+        fs::read(&args.font_path).context("failed to read font file")?;
+    let reporter = Reporter::new(&font_bytes)?;
+    let registry = WordListRegistry::new();
+    let guessed;
+    let word_list = match &args.word_list {
+        Some(name) => registry
+            .lookup(name)
+            .with_context(|| format!("no word list called {name}"))?,
+        None => {
+            guessed = WordList::define("explain", [args.word.as_str()]);
+            &guessed
+        },
+    };
+    let from = args.from.unwrap_or_default();
+    let attribution = reporter
+        .instance(&from)?
+        .explain_delta(&reporter.instance(&args.to)?, word_list, &args.word)?
+        .with_context(|| {
+            format!(
+                "{:?} has glyphs missing from the font, or shapes to nothing",
+                args.word,
+            )
+        })?;
+
+    let font = reporter.fontref();
+    let mut output = stdout().lock();
+    match args.format {
+        OutputFormat::Human => write!(&mut output, "{}", Explanation {
+            font,
+            word: &args.word,
+            from: &from,
+            to: &args.to,
+            attribution: &attribution,
+        }),
+        OutputFormat::Json | OutputFormat::Cbor => {
+            let document = attribution_to_json(
+                font,
+                &args.word,
+                &from,
+                &args.to,
+                &attribution,
+            );
+            write_document(&mut output, args.format, &document)
+        },
+    }
+    .context("failed to write to output")
+}
+
+/// An explanation of a word's [`DeltaAttribution`] for people to read.
+struct Explanation<'a> {
+    font: &'a FontRef<'a>,
+    word: &'a str,
+    from: &'a Location,
+    to: &'a Location,
+    attribution: &'a DeltaAttribution,
+}
+
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Explanation {
+            font,
+            word,
+            from,
+            to,
+            attribution,
+        } = self;
+        let glyph_names = font.glyph_names();
+        let name = |glyph: &PlacedGlyph| {
+            glyph_names.get(GlyphId::new(glyph.glyph_id)).map_or_else(
+                || format!("gid{}", glyph.glyph_id),
+                |name| name.as_str().to_owned(),
+            )
+        };
+        writeln!(f, "{word:?} from {from:?} to {to:?}:")?;
+        writeln!(f, "  highest: {}", extreme_delta(&attribution.highest))?;
+        writeln!(f, "  lowest: {}", extreme_delta(&attribution.lowest))?;
+        let glyphs = attribution.glyphs();
+        if glyphs.is_empty() {
+            return writeln!(
+                f,
+                "  shaped to {} glyphs at {from:?}, but {} at {to:?}",
+                attribution.from.len(),
+                attribution.to.len(),
+            );
+        }
+        writeln!(f, "  glyphs:")?;
+        glyphs.iter().try_for_each(|glyph| {
+            write!(
+                f,
+                "    {} (at byte {}): top {:+}, bottom {:+}, raised {:+}",
+                name(&glyph.from),
+                glyph.from.cluster,
+                glyph.highest_outline(),
+                glyph.lowest_outline(),
+                glyph.y_offset(),
+            )?;
+            if glyph.substituted() {
+                write!(f, ", substituted by {}", name(&glyph.to))?;
+            }
+            writeln!(f)
+        })
+    }
+}
+
+/// How far an extreme moved, e.g. `700 => 750 (+50: outline +40,
+/// positioning +10)`.
+fn extreme_delta(delta: &ExtremeDelta) -> String {
+    match (delta.outline, delta.positioning) {
+        (Some(outline), Some(positioning)) => format!(
+            "{} => {} ({:+}: outline {outline:+}, positioning {positioning:+})",
+            delta.from,
+            delta.to,
+            delta.total(),
+        ),
+        _ => format!("{} => {} ({:+})", delta.from, delta.to, delta.total()),
+    }
+}
+
+fn attribution_to_json(
+    font: &FontRef,
+    word: &str,
+    from: &Location,
+    to: &Location,
+    attribution: &DeltaAttribution,
+) -> Value {
+    let glyph_names = font.glyph_names();
+    let glyph_to_json = |glyph: &PlacedGlyph| {
+        json!({
+            "glyph_id": glyph.glyph_id,
+            "name": glyph_names
+                .get(GlyphId::new(glyph.glyph_id))
+                .map(|name| name.as_str().to_owned()),
+            "cluster": glyph.cluster,
+            "lowest": glyph.bounds.lowest(),
+            "highest": glyph.bounds.highest(),
+            "y_offset": glyph.y_offset,
+        })
+    };
+    let delta_to_json = |delta: &ExtremeDelta| {
+        json!({
+            "from": delta.from,
+            "to": delta.to,
+            "total": delta.total(),
+            "outline": delta.outline,
+            "positioning": delta.positioning,
+        })
+    };
+    let glyphs_to_json = |glyphs: &[PlacedGlyph]| {
+        glyphs.iter().map(glyph_to_json).collect::<Vec<_>>()
+    };
+    json!({
+        "word": word,
+        "from": from,
+        "to": to,
+        "highest": delta_to_json(&attribution.highest),
+        "lowest": delta_to_json(&attribution.lowest),
+        "reshaped": attribution.reshaped(),
+        "glyphs": {
+            "from": glyphs_to_json(&attribution.from),
+            "to": glyphs_to_json(&attribution.to),
+        },
+    })
+}
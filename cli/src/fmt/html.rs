@@ -1,37 +1,74 @@
 use std::{
-    cell::RefCell,
     collections::{BTreeMap, HashMap, hash_map::Entry},
     fmt,
     fmt::Write,
     ops::Neg,
-    rc::Rc,
+    sync::{Arc, Mutex},
 };
 
 use anyhow::{Context, bail};
-use fontheight::{Location, Report, VerticalExtremes};
-use harfrust::{ShaperData, ShaperInstance, UnicodeBuffer};
+use fontheight::{
+    AxisInfo, ExemplarSearch, Extreme, Histogram, InstanceExtremes, LengthUnit,
+    Location, LongWords, Measurement, Provenance, Report, Reporter,
+    VerticalExtremes, WordExtremes, WordFilter, language_support::SupportLevel,
+};
+use harfrust::{Direction, ShaperData, ShaperInstance, UnicodeBuffer};
 use harfshapedfa::{
     HarfRustShaperExt, ShapingMeta,
     convert::{iso639_to_opentype, iso15924_to_opentype},
     pens::BoundsPen,
 };
-use log::{debug, error};
+use log::{debug, error, warn};
 use maud::{DOCTYPE, Escaper, Markup, PreEscaped, Render, html};
 use ordered_float::NotNan;
+use rayon::prelude::*;
 use skrifa::{
-    FontRef, GlyphId, MetadataProvider, OutlineGlyph,
+    FontRef, GlyphId, MetadataProvider, OutlineGlyph, Tag,
     instance::Size,
     outline::{DrawSettings, OutlinePen, pen::SvgPen},
-    raw::TableProvider,
+    raw::{TableProvider, tables::base::MinMax},
 };
 use static_lang_word_lists::WordList;
 use svg::node::element::{Group, Line, Path, SVG};
 
+use crate::{
+    compare_metrics::MetricComparison,
+    fmt::{script_label, shaping_labels, units::Units},
+};
+
 static CSS: &str = "\
+:root {
+    color-scheme: light dark;
+    --background: white;
+    --foreground: black;
+    --border: lightgrey;
+}
+
+:root[data-theme=light] {
+    color-scheme: light;
+}
+
+:root[data-theme=dark] {
+    color-scheme: dark;
+    --background: #1e1e1e;
+    --foreground: #e0e0e0;
+    --border: dimgrey;
+}
+
+@media (prefers-color-scheme: dark) {
+    :root:not([data-theme=light]) {
+        --background: #1e1e1e;
+        --foreground: #e0e0e0;
+        --border: dimgrey;
+    }
+}
+
 body {
     margin: 1em;
 
     font-family: sans-serif;
+    background: var(--background);
+    color: var(--foreground);
 }
 
 h1 {
@@ -68,8 +105,204 @@ ul.drawn {
 .drawn svg {
     height: 175px;
     border: 1px grey dashed;
+}
+
+.drawn svg path {
+    fill: var(--foreground);
+}
+
+.drawn li:target figure {
+    outline: 2px steelblue solid;
+    outline-offset: 0.5rem;
+}
+
+.drawn figcaption a.permalink {
+    color: inherit;
+    text-decoration: none;
+}
+
+.drawn figcaption button.copy {
+    font-size: smaller;
+}
+
+.histogram {
+    display: flex;
+    gap: 2rem;
+}
+
+.histogram rect {
+    fill: steelblue;
+}
+
+.histogram figcaption {
+    text-align: center;
+}
+
+.warnings {
+    color: darkorange;
+}
+
+.unsupported {
+    opacity: 0.6;
+}
+
+.diff figure > div {
+    display: flex;
+    gap: 0.5rem;
+}
+
+.further {
+    color: crimson;
+}
+
+.nearer {
+    color: seagreen;
+}
+
+.filters label {
+    margin-right: 2em;
+}
+
+table.sortable, table.axes {
+    border-collapse: collapse;
+}
+
+table.sortable th, table.sortable td, table.axes th, table.axes td {
+    padding: 0.25em 1em;
+    border-bottom: 1px var(--border) solid;
+    text-align: left;
+}
+
+table.sortable th {
+    cursor: pointer;
+    user-select: none;
+}
+
+table.sortable th[data-order=ascending]::after {
+    content: \" \\25B2\";
+}
+
+table.sortable th[data-order=descending]::after {
+    content: \" \\25BC\";
+}
+
+@media print {
+    :root, :root[data-theme=dark] {
+        color-scheme: light;
+        --background: white;
+        --foreground: black;
+        --border: lightgrey;
+    }
+
+    body {
+        margin: 0;
+        print-color-adjust: exact;
+        -webkit-print-color-adjust: exact;
+    }
+
+    .filters, .drawn figcaption a.permalink, .drawn figcaption button.copy {
+        display: none;
+    }
+
+    details[data-script] + details[data-script] {
+        break-before: page;
+    }
+
+    details {
+        margin: 1rem 0;
+    }
+
+    .drawn figure, .histogram figure, table.sortable tr {
+        break-inside: avoid;
+    }
+
+    /* Sized in physical units so the outlines are rasterised by the printer
+       rather than scaled up from screen pixels */
+    .drawn svg {
+        height: 3cm;
+        shape-rendering: geometricPrecision;
+    }
 }";
 
+// Sorting the exemplar table, filtering by script/word & copying words. Kept
+// dependency-free so reports work offline
+static JS: &str = r#"
+const scriptFilter = document.getElementById("script-filter");
+const wordFilter = document.getElementById("word-filter");
+
+function applyFilters() {
+    const script = scriptFilter.value;
+    const word = wordFilter.value.toLowerCase();
+    document.querySelectorAll(".filterable").forEach((element) => {
+        const { script: elementScript, word: elementWord } = element.dataset;
+        element.hidden =
+            (script !== "" &&
+                elementScript !== undefined &&
+                elementScript !== script) ||
+            (elementWord !== undefined &&
+                !elementWord.toLowerCase().includes(word));
+    });
+}
+
+scriptFilter.addEventListener("change", applyFilters);
+wordFilter.addEventListener("input", applyFilters);
+
+document.querySelectorAll("table.sortable").forEach((table) => {
+    const headers = Array.from(table.tHead.rows[0].cells);
+    headers.forEach((header, column) => {
+        header.addEventListener("click", () => {
+            const ascending = header.dataset.order !== "ascending";
+            headers.forEach((other) => delete other.dataset.order);
+            header.dataset.order = ascending ? "ascending" : "descending";
+
+            const numeric = header.dataset.sort === "number";
+            const key = (row) => {
+                const text = row.cells[column].textContent;
+                return numeric ? Number(text) : text;
+            };
+            const body = table.tBodies[0];
+            const rows = Array.from(body.rows).sort((rowA, rowB) => {
+                const [keyA, keyB] = [key(rowA), key(rowB)];
+                const order = numeric ? keyA - keyB : keyA.localeCompare(keyB);
+                return ascending ? order : -order;
+            });
+            body.append(...rows);
+        });
+    });
+});
+
+document.addEventListener("click", (event) => {
+    const button = event.target.closest("button.copy");
+    if (button !== null) {
+        navigator.clipboard.writeText(button.dataset.copy);
+    }
+});
+"#;
+
+/// The colour scheme of an HTML report.
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+pub enum Theme {
+    /// Follow the viewer's system preference
+    #[default]
+    Auto,
+    /// Always light
+    Light,
+    /// Always dark
+    Dark,
+}
+
+impl Theme {
+    /// The value of the `data-theme` attribute the stylesheet keys off, if
+    /// any.
+    const fn attribute(self) -> Option<&'static str> {
+        match self {
+            Theme::Auto => None,
+            Theme::Light => Some("light"),
+            Theme::Dark => Some("dark"),
+        }
+    }
+}
+
 // Percentage (0..=1) of UPM to pad SVG by
 const SVG_PAD_SCALE: f32 = 0.15;
 
@@ -87,9 +320,25 @@ impl<T: fmt::Debug> Render for RenderUsingDebug<T> {
 struct SimpleBase {
     min: Option<i16>,
     max: Option<i16>,
+    matched: BaseMatch,
 }
 
 impl SimpleBase {
+    fn new(
+        min_max: MinMax,
+        matched: BaseMatch,
+    ) -> Result<Self, skrifa::raw::ReadError> {
+        let min = min_max
+            .min_coord()
+            .transpose()?
+            .map(|base_coord| base_coord.coordinate());
+        let max = min_max
+            .max_coord()
+            .transpose()?
+            .map(|base_coord| base_coord.coordinate());
+        Ok(SimpleBase { min, max, matched })
+    }
+
     fn line_iter(self) -> impl Iterator<Item = (NotNan<f32>, &'static str)> {
         self.min
             .into_iter()
@@ -98,32 +347,80 @@ impl SimpleBase {
     }
 }
 
-// Think InstanceExtremes, but lazy instead of ahead-of-time. Also holds the
-// buffer so it can be re-used between words.
+/// Which BASE MinMax was found for a word list's script & language, from
+/// most to least specific
+#[derive(Debug, Copy, Clone)]
+enum BaseMatch {
+    /// The language's own MinMax, within the script
+    Language { script: Tag, language: Tag },
+    /// The script's default MinMax, as there was none for the language
+    Script(Tag),
+    /// The `DFLT` script's default MinMax, as there was none for the script
+    Default,
+}
+
+impl fmt::Display for BaseMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaseMatch::Language { script, language } => {
+                write!(
+                    f,
+                    "MinMax for language `{language}` of script `{script}`"
+                )
+            },
+            BaseMatch::Script(script) => {
+                write!(f, "default MinMax of script `{script}`")
+            },
+            BaseMatch::Default => write!(
+                f,
+                "default MinMax of the `DFLT` script (no entry for the word \
+                 list's script)",
+            ),
+        }
+    }
+}
+
+// Think InstanceExtremes, but lazy instead of ahead-of-time. Shared between
+// the threads drawing exemplars at the same location.
 #[derive(Debug)]
 struct LocationCache {
     skrifa_location: skrifa::instance::Location,
     shaper_instance: ShaperInstance,
-    glyph_bounds: HashMap<GlyphId, VerticalExtremes>,
-    buffer: Option<UnicodeBuffer>,
+    // The bounds drawn while checking, if the location was checked
+    checked_bounds: Option<Arc<InstanceExtremes>>,
+    glyph_bounds: Mutex<HashMap<GlyphId, VerticalExtremes>>,
 }
 
 impl LocationCache {
-    fn new(font: &FontRef, location: &Location) -> Self {
+    fn new(font_cache: &FontCache, location: &Location) -> Self {
+        let font = font_cache.font;
         Self {
             skrifa_location: location.to_skrifa(font),
             shaper_instance: ShaperInstance::from_variations(
                 font,
                 location.to_harfrust(),
             ),
+            checked_bounds: font_cache
+                .checked_bounds
+                .iter()
+                .find(|(checked_location, _)| checked_location == location)
+                .map(|(_, extremes)| Arc::clone(extremes)),
             glyph_bounds: Default::default(),
-            buffer: Some(UnicodeBuffer::new()),
         }
     }
 
-    fn get_extremes(&mut self, glyph: &OutlineGlyph) -> VerticalExtremes {
+    fn get_extremes(&self, glyph: &OutlineGlyph) -> VerticalExtremes {
+        if let Some(extremes) = self
+            .checked_bounds
+            .as_ref()
+            .and_then(|bounds| bounds.get(u32::from(glyph.glyph_id())))
+        {
+            return extremes;
+        }
         *self
             .glyph_bounds
+            .lock()
+            .unwrap()
             .entry(glyph.glyph_id())
             .or_insert_with(|| {
                 let mut bounds_pen = BoundsPen::new();
@@ -143,39 +440,79 @@ impl LocationCache {
     }
 }
 
+//                         (script , language       )
+type ScriptLanguage<'a> = (&'a str, Option<&'a str>);
+
 // Any information that only needs to be computed once
 struct FontCache<'a> {
     font: &'a FontRef<'a>,
     shaper_data: ShaperData,
-    //                    (script , language       )
-    base_entries: HashMap<(&'a str, Option<&'a str>), Option<SimpleBase>>,
+    base_entries: Mutex<HashMap<ScriptLanguage<'a>, Option<SimpleBase>>>,
     //                 (y          , colour      )
     const_metrics: Vec<(NotNan<f32>, &'static str)>,
     initial_highest: NotNan<f32>,
     initial_lowest: NotNan<f32>,
     upm: NotNan<f32>,
+    named_instances: Vec<(String, Location)>,
+    master_locations: Vec<Location>,
+    axes: Vec<AxisInfo>,
+    units: Units,
+    // Whether the tables metric lines are drawn from could be read
+    has_os2: bool,
+    has_head: bool,
+    /// Problems reading the font, shown at the top of the report
+    warnings: Vec<String>,
+    /// The glyph bounds drawn while checking each location
+    checked_bounds: Vec<(Location, Arc<InstanceExtremes>)>,
 }
 
 impl<'a> FontCache<'a> {
-    fn new(font: &'a FontRef<'a>) -> anyhow::Result<Self> {
-        let os2 = font.os2().context("failed to read OS/2")?;
-        let head = font.head().context("failed to read HEAD")?;
-        let upm = NotNan::<f32>::from(head.units_per_em());
+    fn new(reporter: &'a Reporter<'a>, units: Units) -> Self {
+        let font = reporter.fontref();
+        let mut warnings = Vec::new();
+        // Fonts under development may have broken tables, so their metric
+        // lines are left out rather than failing the whole report
+        let os2 = font
+            .os2()
+            .inspect_err(|why| {
+                warnings.push(format!(
+                    "failed to read OS/2 ({why}), so its metrics aren't drawn"
+                ));
+            })
+            .ok();
+        let head = font
+            .head()
+            .inspect_err(|why| {
+                warnings.push(format!(
+                    "failed to read head ({why}), so its metrics aren't drawn \
+                     and the font is assumed to have 1000 units per em"
+                ));
+            })
+            .ok();
+        let upm = head.as_ref().map_or(NotNan::new(1000.).unwrap(), |head| {
+            NotNan::<f32>::from(head.units_per_em())
+        });
 
-        let const_metrics = vec![
+        let mut const_metrics = vec![
             // Baseline
             (NotNan::default(), "grey"),
-            // Highs
-            (os2.s_typo_ascender().into(), "red"),
-            (os2.us_win_ascent().into(), "blue"),
-            (head.y_max().into(), "green"),
             (NotNan::new(1900. / 2048.).unwrap() * upm, "pink"),
-            // Lows
-            (os2.s_typo_descender().into(), "red"),
-            (NotNan::<f32>::from(os2.us_win_descent()).neg(), "blue"),
-            (head.y_min().into(), "green"),
             (NotNan::new(-500. / 2048.).unwrap() * upm, "pink"),
         ];
+        if let Some(os2) = &os2 {
+            const_metrics.extend([
+                (os2.s_typo_ascender().into(), "red"),
+                (os2.us_win_ascent().into(), "blue"),
+                (os2.s_typo_descender().into(), "red"),
+                (NotNan::<f32>::from(os2.us_win_descent()).neg(), "blue"),
+            ]);
+        }
+        if let Some(head) = &head {
+            const_metrics.extend([
+                (head.y_max().into(), "green"),
+                (head.y_min().into(), "green"),
+            ]);
+        }
 
         let initial_highest = const_metrics
             .iter()
@@ -190,7 +527,25 @@ impl<'a> FontCache<'a> {
             .min()
             .unwrap();
 
-        Ok(Self {
+        let master_locations = reporter
+            .master_locations()
+            .inspect_err(|why| {
+                warnings.push(format!(
+                    "failed to read master locations ({why}), so masters \
+                     aren't labelled"
+                ));
+            })
+            .unwrap_or_default();
+        warnings.iter().for_each(|warning| warn!("{warning}"));
+        // Logged by main already
+        warnings.extend(
+            reporter
+                .warnings()
+                .iter()
+                .map(|warning| warning.to_string()),
+        );
+
+        Self {
             shaper_data: ShaperData::new(font),
             base_entries: Default::default(),
             font,
@@ -198,13 +553,40 @@ impl<'a> FontCache<'a> {
             initial_highest,
             initial_lowest,
             upm,
-        })
+            named_instances: reporter.named_instances(),
+            master_locations,
+            axes: reporter.axes(),
+            units,
+            has_os2: os2.is_some(),
+            has_head: head.is_some(),
+            warnings,
+            checked_bounds: Vec::new(),
+        }
     }
 
-    fn get_base_entry(
-        &mut self,
-        word_list: &'a WordList,
-    ) -> Option<SimpleBase> {
+    /// Measure glyphs with the bounds drawn while checking, rather than
+    /// drawing them again
+    fn with_checked_bounds(
+        mut self,
+        checked_bounds: Vec<(Location, Arc<InstanceExtremes>)>,
+    ) -> Self {
+        self.checked_bounds = checked_bounds;
+        self
+    }
+
+    /// The name of the named instance at `location`, or "master" if it's the
+    /// location of a master
+    fn location_label(&self, location: &Location) -> Option<&str> {
+        self.named_instances
+            .iter()
+            .find(|(_, instance_location)| instance_location == location)
+            .map(|(name, _)| name.as_str())
+            .or_else(|| {
+                self.master_locations.contains(location).then_some("master")
+            })
+    }
+
+    fn get_base_entry(&self, word_list: &'a WordList) -> Option<SimpleBase> {
         fn get_uncached_base_entry(
             font: &FontRef,
             script: &str,
@@ -239,65 +621,75 @@ impl<'a> FontCache<'a> {
                 return Ok(None);
             };
             let base_script_list = horiz_axis?.base_script_list()?;
-            let Some(relevant_script_record) = base_script_list
-                .base_script_records()
-                .iter()
-                .find(|record| record.base_script_tag == ot_script)
-            else {
-                debug!("no BASE entry with script `{ot_script}`");
-                return Ok(None);
+            let find_script = |tag: Tag| {
+                base_script_list
+                    .base_script_records()
+                    .iter()
+                    .find(|record| record.base_script_tag == tag)
+                    .map(|record| {
+                        record.base_script(base_script_list.offset_data())
+                    })
+                    .transpose()
             };
 
-            let base_script = relevant_script_record
-                .base_script(base_script_list.offset_data())?;
-
-            let language_min_max = ot_language
-                .and_then(|lang| {
+            // Fonts may only register the old-style tag of scripts that have
+            // a new one (e.g. `deva` rather than `dev2`)
+            let old_script = ot_script
+                .to_string()
+                .ends_with('2')
+                .then(|| Tag::new_checked(script.to_lowercase().as_bytes()))
+                .and_then(Result::ok);
+            for script_tag in
+                [Some(ot_script), old_script].into_iter().flatten()
+            {
+                let Some(base_script) = find_script(script_tag)? else {
+                    continue;
+                };
+                if let Some(lang_record) = ot_language.and_then(|lang| {
                     base_script
                         .base_lang_sys_records()
                         .iter()
                         .find(|record| record.base_lang_sys_tag == lang)
-                })
-                .map(|lang_record| {
-                    lang_record.min_max(base_script.offset_data())
-                })
-                .transpose()?;
-
-            let min_max = match language_min_max {
-                None => {
-                    let Some(default_min_max) = base_script.default_min_max()
-                    else {
-                        debug!("no default MinMax for `{ot_script}`");
-                        return Ok(None);
-                    };
-                    debug!("found script BASE entry for `{ot_script}`");
-                    default_min_max?
-                },
-                Some(min_max) => {
+                }) {
                     debug!(
                         "found language-specific BASE override for \
-                         `{ot_script}`"
+                         `{script_tag}`"
                     );
-                    min_max
-                },
-            };
-
-            let min = min_max
-                .min_coord()
-                .transpose()?
-                .map(|base_coord| base_coord.coordinate());
-            let max = min_max
-                .max_coord()
-                .transpose()?
-                .map(|base_coord| base_coord.coordinate());
+                    return Ok(Some(SimpleBase::new(
+                        lang_record.min_max(base_script.offset_data())?,
+                        BaseMatch::Language {
+                            script: script_tag,
+                            language: lang_record.base_lang_sys_tag(),
+                        },
+                    )?));
+                }
+                if let Some(default_min_max) = base_script.default_min_max() {
+                    debug!("found script BASE entry for `{script_tag}`");
+                    return Ok(Some(SimpleBase::new(
+                        default_min_max?,
+                        BaseMatch::Script(script_tag),
+                    )?));
+                }
+                debug!("no default MinMax for `{script_tag}`");
+            }
 
-            Ok(Some(SimpleBase { min, max }))
+            if let Some(default_min_max) = find_script(Tag::new(b"DFLT"))?
+                .and_then(|base_script| base_script.default_min_max())
+            {
+                debug!("falling back to DFLT BASE entry for `{ot_script}`");
+                return Ok(Some(SimpleBase::new(
+                    default_min_max?,
+                    BaseMatch::Default,
+                )?));
+            }
+            debug!("no BASE entry with script `{ot_script}` or `DFLT`");
+            Ok(None)
         }
 
         let script = word_list.script()?;
         let language = word_list.language();
 
-        match self.base_entries.entry((script, language)) {
+        match self.base_entries.lock().unwrap().entry((script, language)) {
             Entry::Occupied(entry) => *entry.get(),
             Entry::Vacant(entry) => {
                 let opt_base =
@@ -331,6 +723,12 @@ struct ShapingAccumulator {
     ///
     /// Co-ordinates are in TTF-space
     y_origin: f32,
+    /// Whether glyphs are being laid out right-to-left, in which case the
+    /// origins move left from x = 0
+    rtl: bool,
+    /// The furthest left & right the origin has been, so that words with
+    /// negative advances still fit in the image
+    x_range: (f32, f32),
     /// All the glyphs in the current word
     ///
     /// Glyphs are flipped for SVG-space, but untranslated
@@ -338,46 +736,61 @@ struct ShapingAccumulator {
 }
 
 impl ShapingAccumulator {
-    fn new(word: &str) -> Self {
+    fn new(word: &str, rtl: bool) -> Self {
         Self {
             x_origin: 0f32,
             y_origin: 0f32,
+            rtl,
+            x_range: (0f32, 0f32),
             glyph_svgs: Vec::with_capacity(word.len()),
         }
     }
 
+    /// Where to draw the next glyph, before its offset is applied
+    ///
+    /// Right-to-left glyphs are visited in logical order, so the origin has to
+    /// move past the glyph before drawing it
+    fn glyph_origin(&self, x_advance: i32) -> f32 {
+        if self.rtl {
+            self.x_origin - x_advance as f32
+        } else {
+            self.x_origin
+        }
+    }
+
     // Taking self and returning a new one makes this easier to use with
     // Iterator::fold (i.e. the whole point of this struct)
     fn next(self, x_advance: i32, y_advance: i32, glyph_svg: Path) -> Self {
         let ShapingAccumulator {
             x_origin,
             y_origin,
+            rtl,
+            x_range: (x_low, x_high),
             mut glyph_svgs,
         } = self;
         glyph_svgs.push(glyph_svg);
+        let x_origin = if rtl {
+            x_origin - x_advance as f32
+        } else {
+            x_origin + x_advance as f32
+        };
         Self {
-            x_origin: x_origin + x_advance as f32,
+            x_origin,
             y_origin: y_origin + y_advance as f32,
+            rtl,
+            x_range: (x_low.min(x_origin), x_high.max(x_origin)),
             glyph_svgs,
         }
     }
 }
 
 fn draw_svg<'a>(
-    font_cache: Rc<RefCell<FontCache<'a>>>,
-    location_cache: Rc<RefCell<LocationCache>>,
+    font_cache: &FontCache<'a>,
+    location_cache: &LocationCache,
     word: &str,
     word_list: &'a WordList,
 ) -> SVG {
-    // We only ever process one SVG at a time, so we can just borrow mutably for
-    // the duration of this function for simplicity's sake
-    let mut font_cache = font_cache.borrow_mut();
-    let mut location_cache = location_cache.borrow_mut();
-
-    let mut buffer = location_cache
-        .buffer
-        .take()
-        .expect("GlyphBuffer was not returned to location_cache");
+    let mut buffer = UnicodeBuffer::new();
     buffer.push_str(word);
 
     let shaper = font_cache
@@ -389,18 +802,30 @@ fn draw_svg<'a>(
     // errors here; unwrapping is fine
     let shaping_meta = word_list
         .script()
-        .map(|script| ShapingMeta::new(script, word_list.language(), &shaper))
+        .map(|script| {
+            ShapingMeta::new_with_features(
+                script,
+                word_list.language(),
+                word_list.features(),
+                &shaper,
+            )
+        })
         .transpose()
         .unwrap();
 
     // Default features are still included by default
-    let glyph_buffer = match &shaping_meta {
-        Some(meta) => shaper.shape_with_meta(meta, buffer, &[]),
+    let (glyph_buffer, direction) = match &shaping_meta {
+        Some(meta) => (
+            shaper.shape_with_meta(meta, buffer, meta.features()),
+            meta.direction(),
+        ),
         None => {
             buffer.guess_segment_properties();
-            shaper.shape(buffer, &[])
+            let direction = buffer.direction();
+            (shaper.shape(buffer, &[]), direction)
         },
     };
+    let rtl = direction == Direction::RightToLeft;
 
     // These values do not factor in padding
     let mut highest = font_cache.initial_highest;
@@ -418,68 +843,74 @@ fn draw_svg<'a>(
 
     let svg_pad = font_cache.upm * SVG_PAD_SCALE;
     let outlines = font_cache.font.outline_glyphs();
-    // FIXME: in theory, using the final x_advance is insufficient. We would
-    //        have to use the bounds of the final glyph instead of just where
-    //        it reports the next one should start.
-    //        In practice, the padding will probably save us even if end_width
+    // FIXME: in theory, using the furthest the origin reached is insufficient.
+    //        We would have to use the bounds of the glyphs instead of just
+    //        where each reports the next one should start.
+    //        In practice, the padding will probably save us even if the range
     //        should be larger.
-    let ShapingAccumulator {
-        x_origin: end_width,
-        glyph_svgs,
-        ..
-    } = glyph_buffer
+    // harfrust gives glyphs in visual order; right-to-left words are drawn in
+    // logical order from the right-hand side
+    let glyphs = glyph_buffer
         .glyph_infos()
         .iter()
         .zip(glyph_buffer.glyph_positions())
-        .fold(
-            ShapingAccumulator::new(word),
-            |acc, (glyph_info, position)| {
-                let glyph = outlines.get(glyph_info.glyph_id.into()).unwrap();
-
-                // Draw the glyph, flipped because SVG space has y=0 at the top,
-                // unlike fonts
-                let mut svg_pen = SvgPen::new();
-                let mut flipped_svg_pen = VerticalFlipPen {
-                    inner: &mut svg_pen,
-                };
-                glyph
-                    .draw(
-                        DrawSettings::unhinted(
-                            Size::unscaled(),
-                            &location_cache.skrifa_location,
-                        ),
-                        &mut flipped_svg_pen,
-                    )
-                    .unwrap();
-
-                // Pull the SVG path out of the pen and position it correctly
-                let glyph_svg = Path::new()
-                    .set(
-                        "transform",
-                        format!(
-                            "translate({x}, {y})",
-                            x = acc.x_origin + position.x_offset as f32,
-                            // Our pen flips the TTF outlines, but we have to
-                            // negate the harfrust position ourselves
-                            y = -(acc.y_origin + position.y_offset as f32)
-                        ),
-                    )
-                    .set("d", svg_pen.to_string());
-
-                // Look at the bounds and update highest/lowest as needed
-                let extrema = location_cache.get_extremes(&glyph);
-                highest =
-                    highest.max(NotNan::new(extrema.highest() as f32).unwrap());
-                lowest =
-                    lowest.min(NotNan::new(extrema.lowest() as f32).unwrap());
-
-                acc.next(position.x_advance, position.y_advance, glyph_svg)
-            },
-        );
-    location_cache.buffer = Some(glyph_buffer.clear());
-
-    let x_min = -svg_pad;
-    let x_max = end_width + svg_pad;
+        .collect::<Vec<_>>();
+    let glyphs: Box<dyn Iterator<Item = _>> = if rtl {
+        Box::new(glyphs.into_iter().rev())
+    } else {
+        Box::new(glyphs.into_iter())
+    };
+    let ShapingAccumulator {
+        x_range: (x_low, x_high),
+        glyph_svgs,
+        ..
+    } = glyphs.fold(
+        ShapingAccumulator::new(word, rtl),
+        |acc, (glyph_info, position)| {
+            let glyph = outlines.get(glyph_info.glyph_id.into()).unwrap();
+
+            // Draw the glyph, flipped because SVG space has y=0 at the top,
+            // unlike fonts
+            let mut svg_pen = SvgPen::new();
+            let mut flipped_svg_pen = VerticalFlipPen {
+                inner: &mut svg_pen,
+            };
+            glyph
+                .draw(
+                    DrawSettings::unhinted(
+                        Size::unscaled(),
+                        &location_cache.skrifa_location,
+                    ),
+                    &mut flipped_svg_pen,
+                )
+                .unwrap();
+
+            // Pull the SVG path out of the pen and position it correctly
+            let glyph_svg = Path::new()
+                .set(
+                    "transform",
+                    format!(
+                        "translate({x}, {y})",
+                        x = acc.glyph_origin(position.x_advance)
+                            + position.x_offset as f32,
+                        // Our pen flips the TTF outlines, but we have to
+                        // negate the harfrust position ourselves
+                        y = -(acc.y_origin + position.y_offset as f32)
+                    ),
+                )
+                .set("d", svg_pen.to_string());
+
+            // Look at the bounds and update highest/lowest as needed
+            let extrema = location_cache.get_extremes(&glyph);
+            highest =
+                highest.max(NotNan::new(extrema.highest() as f32).unwrap());
+            lowest = lowest.min(NotNan::new(extrema.lowest() as f32).unwrap());
+
+            acc.next(position.x_advance, position.y_advance, glyph_svg)
+        },
+    );
+    let x_min = x_low - svg_pad;
+    let x_max = x_high + svg_pad;
     let y_min = lowest - svg_pad;
     let y_max = highest + svg_pad;
 
@@ -492,7 +923,7 @@ fn draw_svg<'a>(
             "transform",
             // Move the word down now to complete the move from font-land to
             // SVG-land, coordinates-wise
-            format!("translate({x}, {y})", x = svg_pad, y = y_max),
+            format!("translate({x}, {y})", x = -x_min, y = y_max),
         );
 
     let word_and_lines_svg = font_cache
@@ -524,62 +955,389 @@ fn draw_svg<'a>(
                 height = y_max - y_min
             ),
         )
-        .set("preserveAspectRatio", "meet")
+        // Right-to-left words are aligned to the right of their figure
+        .set(
+            "preserveAspectRatio",
+            if rtl { "xMaxYMid meet" } else { "meet" },
+        )
         .add(word_and_lines_svg)
 }
 
+/// A stable hash of `parts`, to make anchor IDs from
+fn anchor_hash(parts: &[&str]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    parts.iter().for_each(|part| {
+        hasher.update(part.as_bytes());
+        // Keep e.g. ("ab", "c") & ("a", "bc") apart
+        hasher.update(&[0]);
+    });
+    hasher.finalize()
+}
+
+/// The ID of a report's section, for the table of contents
+fn report_anchor(report: &Report) -> String {
+    format!(
+        "report-{:08x}",
+        anchor_hash(&[
+            &report.word_list.id().to_string(),
+            &report.location.to_string(),
+        ])
+    )
+}
+
+/// The ID of an exemplar's figure, stable across runs so findings can be
+/// linked to. `kind` tells apart words that are both a highest & a lowest
+/// exemplar
+fn exemplar_anchor(
+    word: &str,
+    kind: &str,
+    word_list: &WordList,
+    location: &Location,
+) -> String {
+    format!(
+        "exemplar-{:08x}-{kind}",
+        anchor_hash(&[
+            word,
+            &word_list.id().to_string(),
+            &location.to_string(),
+        ])
+    )
+}
+
 fn draw_exemplar<'a>(
-    font_cache: Rc<RefCell<FontCache<'a>>>,
-    location_cache: Rc<RefCell<LocationCache>>,
-    exemplar: &str,
+    font_cache: &FontCache<'a>,
+    location_cache: &LocationCache,
+    exemplar: &WordExtremes,
+    kind: &str,
     source: &'a WordList,
     location: &Location,
 ) -> Markup {
-    let svg =
-        draw_svg(font_cache, location_cache, exemplar, source).to_string();
+    let word = exemplar.shaped_text();
+    let extreme = match kind {
+        "highest" => exemplar.extremes.highest(),
+        _ => exemplar.extremes.lowest(),
+    };
+    let svg = draw_svg(font_cache, location_cache, &word, source).to_string();
+    let anchor = exemplar_anchor(&word, kind, source, location);
     html! {
-        li {
+        li.filterable id=(anchor) data-word=(word) {
             figure {
                 (PreEscaped(svg))
                 figcaption {
-                    "\"" (exemplar) "\" (from " (source.name()) ")" br;
-                    // TODO: give instance name if it is a named instance?
-                    (RenderUsingDebug(location))
+                    "\"" (word) "\" (from " (source.id()) ") => "
+                    (font_cache.units.convert(extreme)) " "
+                    @if exemplar.is_partial() {
+                        "(partial, " (exemplar.skipped_glyphs) " glyphs missing) "
+                    }
+                    a.permalink href={ "#" (anchor) } title="Link to this exemplar" { "#" }
+                    " "
+                    button.copy type="button" data-copy=(word) title="Copy the word" { "Copy" }
+                    br;
+                    (format_location(font_cache, location))
+                }
+            }
+        }
+    }
+}
+
+/// The problems reading the font, if there were any, prefixed with `label`
+fn format_font_warnings(font_cache: &FontCache, label: Option<&str>) -> Markup {
+    html! {
+        @if !font_cache.warnings.is_empty() {
+            ul.warnings {
+                @for warning in &font_cache.warnings {
+                    li {
+                        "Warning: "
+                        @if let Some(label) = label { (label) ": " }
+                        (warning)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The font's axes, if it's variable
+fn format_axes(font_cache: &FontCache) -> Markup {
+    html! {
+        @if !font_cache.axes.is_empty() {
+            h3 { "Axes" }
+            table.axes {
+                tr { th { "Tag" } th { "Name" } th { "Min" } th { "Default" } th { "Max" } }
+                @for axis in &font_cache.axes {
+                    tr {
+                        td { (axis.tag) }
+                        td { (axis.name) }
+                        td { (axis.min) }
+                        td { (axis.default) }
+                        td { (axis.max) }
+                    }
                 }
             }
         }
     }
 }
 
+/// When & how the report was made, with the options words were checked with
+/// and the hashes of the word lists checked, so it can be reproduced
+fn format_provenance(provenance: &Provenance) -> Markup {
+    let options = provenance.options();
+    let word_length_limit = options.word_length_limit().map(|limit| {
+        let unit = match limit.unit() {
+            LengthUnit::Chars => "characters",
+            LengthUnit::Clusters => "clusters",
+        };
+        let long_words = match limit.long_words() {
+            LongWords::Skip => "skipping",
+            LongWords::Truncate => "truncating",
+            LongWords::Chunk => "chunking",
+        };
+        format!("{} {unit}, {long_words} longer words", limit.max())
+    });
+    let exemplar_filter = options.exemplar_filter().map(|filter| {
+        format!(
+            "at least {} units of ink & {} glyphs",
+            filter.min_ink_height(),
+            filter.min_glyphs(),
+        )
+    });
+    let measurement = match options.measurement() {
+        Measurement::Instance => "glyph outlines",
+        Measurement::ControlBoxes => "control boxes",
+    };
+    let exemplar_search = match options.exemplar_search() {
+        ExemplarSearch::Exhaustive => "exhaustive".to_owned(),
+        ExemplarSearch::Refined { candidates } => {
+            format!("refined from {candidates} candidates")
+        },
+    };
+    html! {
+        p.provenance {
+            "Generated " (provenance.timestamp()) " by fontheight "
+            (env!("CARGO_PKG_VERSION")) " (library "
+            (Provenance::FONTHEIGHT_VERSION) ", harfrust "
+            (Provenance::HARFRUST_VERSION) ", skrifa "
+            (Provenance::SKRIFA_VERSION) ")"
+        }
+        details.provenance {
+            summary { "Configuration" }
+            table.axes {
+                tr { th { "Option" } th { "Value" } }
+                tr {
+                    td { "Words per list" }
+                    td {
+                        @match options.words_limit() {
+                            Some(limit) => (limit),
+                            None => "all",
+                        }
+                    }
+                }
+                tr { td { "Exemplars" } td { (options.exemplars()) } }
+                tr { td { "Distinct" } td { (options.distinct()) } }
+                tr { td { "Segmentation" } td { (options.segmentation().name()) } }
+                tr { td { "Punctuation" } td { (options.punctuation()) } }
+                tr { td { "Exemplar search" } td { (exemplar_search) } }
+                tr { td { "Measurement" } td { (measurement) } }
+                tr { td { "Partial words" } td { (options.partial_words()) } }
+                tr {
+                    td { "Word length limit" }
+                    td { (word_length_limit.as_deref().unwrap_or("none")) }
+                }
+                tr {
+                    td { "Exemplar filter" }
+                    td { (exemplar_filter.as_deref().unwrap_or("none")) }
+                }
+                @for feature in options.features() {
+                    tr { td { "Feature" } td { code { (feature) } } }
+                }
+                @if let Some(thresholds) = options.thresholds() {
+                    tr {
+                        td { "Thresholds" }
+                        td { (thresholds.lowest()) " to " (thresholds.highest()) }
+                    }
+                }
+                @for exclusion in options.exclusions() {
+                    tr {
+                        td { "Excluded" }
+                        td {
+                            @match exclusion {
+                                WordFilter::Pattern(pattern) => code { (pattern) },
+                                WordFilter::Categories(categories) => {
+                                    (format!("{categories:?}"))
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+            table.axes {
+                tr { th { "Word list" } th { "Content hash" } }
+                @for (name, content_hash) in provenance.word_lists() {
+                    tr {
+                        td { (name) }
+                        td { code { (format!("{content_hash:08x}")) } }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Each metric's `--compare-metrics` verdict, linking to the word compared
+/// with it
+fn format_metric_verdicts(
+    font_cache: &FontCache,
+    comparison: &MetricComparison,
+) -> Markup {
+    let units = font_cache.units;
+    html! {
+        h3 { "Metric verdicts" }
+        table.axes {
+            tr {
+                th { "Metric" } th { "Value" } th { "Observed" } th { "Margin" }
+                th { "Verdict" } th { "Word" }
+            }
+            @for verdict in &comparison.verdicts {
+                @let kind = match verdict.extreme {
+                    Extreme::Highest => "highest",
+                    Extreme::Lowest => "lowest",
+                };
+                @let word = verdict.word.shaped_text();
+                @let anchor = exemplar_anchor(
+                    &word,
+                    kind,
+                    verdict.report.word_list,
+                    verdict.report.location,
+                );
+                tr {
+                    td { (verdict.metric) }
+                    td { (units.convert(verdict.value)) }
+                    td { (units.convert(verdict.observed())) }
+                    td { (units.convert(verdict.margin())) }
+                    td { strong { (verdict.verdict()) } }
+                    td {
+                        a href={ "#" (anchor) } { (word) }
+                        " (" (verdict.report.word_list.id()) ")"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A location, prefixed with its named instance if it has one, with each axis
+/// by its name (e.g. "Weight 700, Width 75")
+fn format_location(font_cache: &FontCache, location: &Location) -> Markup {
+    let axis_values = location
+        .to_harfrust()
+        .map(|variation| {
+            let name = font_cache
+                .axes
+                .iter()
+                .find(|axis| axis.tag == variation.tag)
+                .map_or_else(
+                    || variation.tag.to_string(),
+                    |axis| axis.name.clone(),
+                );
+            format!("{name} {}", variation.value)
+        })
+        .collect::<Vec<_>>();
+    html! {
+        @if let Some(label) = font_cache.location_label(location) {
+            (label) " "
+        }
+        @if axis_values.is_empty() {
+            (RenderUsingDebug(location))
+        } @else {
+            (axis_values.join(", "))
+        }
+    }
+}
+
+/// Draw all of a report's exemplars, highest first, in parallel
+fn draw_report_exemplars<'a>(
+    font_cache: &FontCache<'a>,
+    report: &Report<'a>,
+) -> Vec<Markup> {
+    let location_cache = LocationCache::new(font_cache, report.location);
+    let exemplars = report
+        .exemplars
+        .highest()
+        .iter()
+        .map(|exemplar| (exemplar, "highest"))
+        .chain(
+            report
+                .exemplars
+                .lowest()
+                .iter()
+                .map(|exemplar| (exemplar, "lowest")),
+        )
+        .collect::<Vec<_>>();
+    exemplars
+        .into_par_iter()
+        .map(|(exemplar, kind)| {
+            draw_exemplar(
+                font_cache,
+                &location_cache,
+                exemplar,
+                kind,
+                report.word_list,
+                report.location,
+            )
+        })
+        .collect()
+}
+
 fn format_script_reports<'a>(
-    font_cache: Rc<RefCell<FontCache<'a>>>,
+    font_cache: &FontCache<'a>,
     script: &str,
     reports: &[&Report<'a>],
 ) -> Markup {
+    // Drawing is the slow part, so do it all up-front in parallel and only
+    // assemble the document afterwards
+    let drawn_reports = reports
+        .par_iter()
+        .map(|report| draw_report_exemplars(font_cache, report))
+        .collect::<Vec<_>>();
     html! {
-        details open {
-            summary { h2 { (script) } }
-            @for report in reports {
-                @let location_cache =
-                    Rc::new(RefCell::new(LocationCache::new(font_cache.borrow().font, report.location)));
-                ul.drawn {
-                    @for high_exemplar in report.exemplars.highest() {
-                        (draw_exemplar(
-                            font_cache.clone(),
-                            location_cache.clone(),
-                            high_exemplar.word,
-                            report.word_list,
-                            report.location,
-                        ))
+        details.filterable open id={ "script-" (script) } data-script=(script) {
+            summary { h2 { (script_label(script)) } }
+            @for (report, drawn_exemplars) in reports.iter().zip(drawn_reports) {
+                // Reports for languages the font doesn't fully support are
+                // dimmed, as their extremes may be misleading
+                details.unsupported[report.support.is_some_and(|support| support != SupportLevel::Full)] open id=(report_anchor(report)) {
+                    summary {
+                        (report.word_list.id()) " @ "
+                        (format_location(font_cache, report.location))
+                        @if let Some(feature_variation) = report.feature_variation {
+                            " (feature variation " (feature_variation) ")"
+                        }
+                        @match report.support {
+                            Some(SupportLevel::Partial) => " (partially supported)",
+                            Some(SupportLevel::None) => " (unsupported)",
+                            Some(SupportLevel::Full) | None => {},
+                        }
+                    }
+                    @if !report.shaping.is_empty() {
+                        p { "Shaped as: " (shaping_labels(&report.shaping)) }
+                    }
+                    @if let Some(base) = font_cache.get_base_entry(report.word_list) {
+                        p { "Cyan lines: BASE " (base.matched) }
+                    }
+                    @if !report.warnings.is_empty() {
+                        ul.warnings {
+                            @for warning in &report.warnings {
+                                li { "Warning: " (warning) }
+                            }
+                        }
+                    }
+                    ul.drawn {
+                        @for drawn_exemplar in drawn_exemplars {
+                            (drawn_exemplar)
+                        }
                     }
-                    @for low_exemplar in report.exemplars.lowest() {
-                        (draw_exemplar(
-                            font_cache.clone(),
-                            location_cache.clone(),
-                            low_exemplar.word,
-                            report.word_list,
-                            report.location,
-                        ))
+                    @if let Some(histogram) = &report.histogram {
+                        (format_histogram(font_cache, histogram))
                     }
                 }
             }
@@ -587,22 +1345,150 @@ fn format_script_reports<'a>(
     }
 }
 
-pub fn format_all_reports(
-    reports: &[Report],
-    font: &FontRef,
-) -> anyhow::Result<String> {
-    // Group on script and then present exemplars from word lists in order by
-    // name
-    let mut script_exemplars = BTreeMap::<&str, Vec<&Report>>::new();
-    reports.iter().for_each(|report| {
+/// Bar charts of how high & low the words of a report reached
+fn format_histogram(font_cache: &FontCache, histogram: &Histogram) -> Markup {
+    const BAR_WIDTH: f64 = 8.0;
+    const HEIGHT: f64 = 100.0;
+
+    let units = font_cache.units;
+    let bin_size = histogram.bin_size();
+    let charts = [
+        ("Highest points", histogram.highest().collect::<Vec<_>>()),
+        ("Lowest points", histogram.lowest().collect::<Vec<_>>()),
+    ];
+    html! {
+        div.histogram {
+            @for (name, bins) in charts {
+                @let first = bins.first().map_or(0.0, |(start, _)| *start);
+                @let last = bins.last().map_or(0.0, |(start, _)| *start);
+                @let width = ((last - first) / bin_size + 1.0) * BAR_WIDTH;
+                @let max = bins.iter().map(|(_, count)| *count).max().unwrap_or(1);
+                figure {
+                    svg xmlns="http://www.w3.org/2000/svg"
+                        viewBox=(format!("0 0 {width} {HEIGHT}"))
+                        width=(width) height=(HEIGHT) {
+                        @for (start, count) in &bins {
+                            @let x = (start - first) / bin_size * BAR_WIDTH;
+                            @let height = (*count as f64 / max as f64 * HEIGHT).max(1.0);
+                            rect x=(x) y=(HEIGHT - height)
+                                width=(BAR_WIDTH - 1.0) height=(height) {
+                                title {
+                                    (units.convert(*start)) ".."
+                                    (units.convert(start + bin_size)) ": "
+                                    (count) " words"
+                                }
+                            }
+                        }
+                    }
+                    figcaption {
+                        (name) " of " (histogram.len()) " words (bins of "
+                        (units.convert(bin_size)) ")"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Links to each script's section, and each of its reports
+fn format_contents<'a>(
+    font_cache: &FontCache<'a>,
+    script_reports: &BTreeMap<&str, Vec<&Report<'a>>>,
+) -> Markup {
+    html! {
+        nav.contents {
+            h3 { "Contents" }
+            ul {
+                @for (script, reports) in script_reports {
+                    li.filterable data-script=(script) {
+                        a href={ "#script-" (script) } { (script_label(script)) }
+                        ul {
+                            @for report in reports {
+                                li {
+                                    a href={ "#" (report_anchor(report)) } {
+                                        (report.word_list.id()) " @ "
+                                        (format_location(font_cache, report.location))
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A sortable table of every exemplar in every report
+fn format_exemplar_table<'a>(
+    font_cache: &FontCache<'a>,
+    script_reports: &BTreeMap<&str, Vec<&Report<'a>>>,
+) -> Markup {
+    let units = font_cache.units;
+    html! {
+        table.sortable {
+            thead {
+                tr {
+                    th { "Word" }
+                    th { "Extreme" }
+                    th data-sort="number" { "Value" }
+                    th { "Word list" }
+                    th { "Script" }
+                    th { "Location" }
+                }
+            }
+            tbody {
+                @for (script, reports) in script_reports {
+                    @for report in reports {
+                        @let exemplars = report
+                            .exemplars
+                            .highest()
+                            .iter()
+                            .map(|exemplar| {
+                                (exemplar.shaped_text(), "highest", exemplar.extremes.highest())
+                            })
+                            .chain(report.exemplars.lowest().iter().map(
+                                |exemplar| {
+                                    (exemplar.shaped_text(), "lowest", exemplar.extremes.lowest())
+                                },
+                            ));
+                        @for (word, extreme, value) in exemplars {
+                            tr.filterable data-script=(script) data-word=(word) {
+                                td {
+                                    a href={
+                                        "#" (exemplar_anchor(&word, extreme, report.word_list, report.location))
+                                    } { (word) }
+                                }
+                                td { (extreme) }
+                                td { (units.convert(value)) }
+                                td { (report.word_list.id()) }
+                                td { (script_label(script)) }
+                                td { (format_location(font_cache, report.location)) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Group on script, and then order by word list name & location
+fn group_by_script<'r, T>(
+    items: &'r [T],
+    report: impl Fn(&'r T) -> &'r Report<'r>,
+) -> BTreeMap<&'r str, Vec<&'r T>> {
+    let mut script_items = BTreeMap::<&str, Vec<&T>>::new();
+    items.iter().for_each(|item| {
         // ZWSP at the start of Unknown so it gets sorted last
-        let script = report.word_list.script().unwrap_or("\u{200B}Unknown");
-        script_exemplars.entry(script).or_default().push(report);
+        let script =
+            report(item).word_list.script().unwrap_or("\u{200B}Unknown");
+        script_items.entry(script).or_default().push(item);
     });
-    // Sort reports by name, then by location
-    script_exemplars.values_mut().for_each(|reports| {
-        reports.sort_unstable_by(|report_a, report_b| {
-            Ord::cmp(report_a.word_list.name(), report_b.word_list.name())
+    script_items.values_mut().for_each(|items| {
+        items.sort_unstable_by(|item_a, item_b| {
+            let (report_a, report_b) = (report(item_a), report(item_b));
+            Ord::cmp(&report_a.word_list.id(), &report_b.word_list.id())
                 .then_with(|| {
                     PartialOrd::partial_cmp(
                         &report_a.location,
@@ -612,19 +1498,46 @@ pub fn format_all_reports(
                 })
         });
     });
+    script_items
+}
 
-    let font_cache = Rc::new(RefCell::new(FontCache::new(font)?));
+pub fn format_all_reports<'a>(
+    reports: &[Report],
+    reporter: &'a Reporter<'a>,
+    units: Units,
+    theme: Theme,
+    comparison: Option<&MetricComparison>,
+    checked_bounds: Vec<(Location, Arc<InstanceExtremes>)>,
+    provenance: &Provenance,
+) -> anyhow::Result<String> {
+    let script_exemplars = group_by_script(reports, |report| report);
+
+    let font_cache =
+        FontCache::new(reporter, units).with_checked_bounds(checked_bounds);
+    let script_sections = script_exemplars
+        .par_iter()
+        .map(|(script, reports)| {
+            format_script_reports(&font_cache, script, reports)
+        })
+        .collect::<Vec<_>>();
+    let exemplar_table = format_exemplar_table(&font_cache, &script_exemplars);
 
     let html = html! {
         (DOCTYPE)
-        html {
+        html data-theme=[theme.attribute()] {
             head {
                 title { "Font Height report" }
                 meta charset="utf-8";
-                style { (CSS) }
+                style { (PreEscaped(CSS)) }
             }
             body {
                 h1 { "Font Height report" }
+                (format_provenance(provenance))
+                (format_font_warnings(&font_cache, None))
+                (format_axes(&font_cache))
+                @if let Some(comparison) = comparison {
+                    (format_metric_verdicts(&font_cache, comparison))
+                }
                 h3 { "Lines legend" }
                 p {
                     span style="color: grey" {
@@ -632,25 +1545,265 @@ pub fn format_all_reports(
                     } br;
                     span style="color: green" {
                         "Green: [head.yMax, head.yMin]"
+                        @if !font_cache.has_head { " (not drawn, head couldn't be read)" }
                     } br;
                     span style="color: blue" {
                         "Blue: [os2.usWinAscent, -os2.usWinDescent]"
+                        @if !font_cache.has_os2 { " (not drawn, OS/2 couldn't be read)" }
                     } br;
                     span style="color: red" {
                         "Red: [os2.sTypoAscender, os2.sTypoDescender] "
                         "= clipping limit for Android"
+                        @if !font_cache.has_os2 { " (not drawn, OS/2 couldn't be read)" }
                     } br;
                     span style="color: pink" {
                         (PreEscaped("Pink: [1900&frasl;2048&times;upem, "))
                         (PreEscaped("&minus;500&frasl;2048&times;upem]"))
                     } br;
                     span style="color: cyan" {
-                        "Cyan: BASE table entry for script/language (if present)"
+                        "Cyan: BASE table entry for script/language (if present, falling "
+                        "back to the script's default, then DFLT's)"
                     } br;
                 }
-                @for (script, reports) in script_exemplars {
-                    (format_script_reports(font_cache.clone(), script, &reports))
+                p.filters {
+                    label {
+                        "Script "
+                        select #script-filter {
+                            option value="" { "All" }
+                            @for script in script_exemplars.keys() {
+                                option value=(script) { (script_label(script)) }
+                            }
+                        }
+                    }
+                    label {
+                        "Word "
+                        input #word-filter type="search";
+                    }
+                }
+                (format_contents(&font_cache, &script_exemplars))
+                details {
+                    summary { h2 { "All exemplars" } }
+                    (exemplar_table)
+                }
+                @for script_section in script_sections {
+                    (script_section)
+                }
+                script { (PreEscaped(JS)) }
+            }
+        }
+    };
+    Ok(html.into_string())
+}
+
+/// A report on the new font being compared by `fontheight diff`, with how high
+/// & low its exemplars reached in the old font.
+pub struct ReportDiff<'a> {
+    pub report: Report<'a>,
+    /// The extremes of each exemplar in the old font by its index in the word
+    /// list, or `None` if the location is outside of the old font's design
+    /// space. Exemplars the old font couldn't shape are missing
+    pub old_extremes: Option<HashMap<usize, VerticalExtremes>>,
+}
+
+/// An exemplar drawn in both fonts
+fn draw_exemplar_diff<'a>(
+    new_cache: &FontCache<'a>,
+    new_location_cache: &LocationCache,
+    old: Option<(&FontCache<'a>, &LocationCache)>,
+    diff: &ReportDiff<'a>,
+    exemplar: &WordExtremes,
+    extreme: Extreme,
+) -> Markup {
+    let new_value = match extreme {
+        Extreme::Highest => exemplar.extremes.highest(),
+        Extreme::Lowest => exemplar.extremes.lowest(),
+    };
+    let source = diff.report.word_list;
+    let units = new_cache.units;
+    let old_value = diff
+        .old_extremes
+        .as_ref()
+        .and_then(|old_extremes| old_extremes.get(&exemplar.index))
+        .map(|extremes| match extreme {
+            Extreme::Highest => extremes.highest(),
+            Extreme::Lowest => extremes.lowest(),
+        });
+    let old_svg = old.filter(|_| old_value.is_some()).map(
+        |(old_cache, old_location_cache)| {
+            draw_svg(old_cache, old_location_cache, exemplar.word, source)
+                .to_string()
+        },
+    );
+    let new_svg =
+        draw_svg(new_cache, new_location_cache, exemplar.word, source)
+            .to_string();
+    html! {
+        li.filterable data-word=(exemplar.word) {
+            figure {
+                div {
+                    @if let Some(old_svg) = old_svg {
+                        (PreEscaped(old_svg))
+                    }
+                    (PreEscaped(new_svg))
+                }
+                figcaption {
+                    "\"" (exemplar.word) "\" (from " (source.id()) ") "
+                    (match extreme {
+                        Extreme::Highest => "highest",
+                        Extreme::Lowest => "lowest",
+                    })
+                    ": "
+                    @match old_value {
+                        Some(old_value) => {
+                            @let delta = units.convert(new_value - old_value);
+                            (units.convert(old_value)) " => "
+                            (units.convert(new_value)) " "
+                            @if delta == 0.0 {
+                                "(unchanged)"
+                            } @else {
+                                @let further = match extreme {
+                                    Extreme::Highest => delta > 0.0,
+                                    Extreme::Lowest => delta < 0.0,
+                                };
+                                span class=(if further { "further" } else { "nearer" }) {
+                                    "(" @if delta > 0.0 { "+" } (delta) ")"
+                                }
+                            }
+                        },
+                        None => {
+                            (units.convert(new_value)) " (not in the old font)"
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Draw all of a report's exemplars in both fonts, highest first, in parallel
+fn draw_report_diff<'a>(
+    new_cache: &FontCache<'a>,
+    old_cache: &FontCache<'a>,
+    diff: &ReportDiff<'a>,
+) -> Vec<Markup> {
+    let report = &diff.report;
+    let new_location_cache = LocationCache::new(new_cache, report.location);
+    let old_location_cache = diff
+        .old_extremes
+        .is_some()
+        .then(|| LocationCache::new(old_cache, report.location));
+    let exemplars = report
+        .exemplars
+        .highest()
+        .iter()
+        .map(|exemplar| (exemplar, Extreme::Highest))
+        .chain(
+            report
+                .exemplars
+                .lowest()
+                .iter()
+                .map(|exemplar| (exemplar, Extreme::Lowest)),
+        )
+        .collect::<Vec<_>>();
+    exemplars
+        .into_par_iter()
+        .map(|(exemplar, extreme)| {
+            draw_exemplar_diff(
+                new_cache,
+                &new_location_cache,
+                old_location_cache
+                    .as_ref()
+                    .map(|location_cache| (old_cache, location_cache)),
+                diff,
+                exemplar,
+                extreme,
+            )
+        })
+        .collect()
+}
+
+/// Compare the exemplars of the new font with how the same words look in the
+/// old font, side-by-side
+pub fn format_font_diff<'a>(
+    diffs: &[ReportDiff<'a>],
+    new_reporter: &'a Reporter<'a>,
+    old_reporter: &'a Reporter<'a>,
+    units: Units,
+    theme: Theme,
+) -> anyhow::Result<String> {
+    let script_diffs = group_by_script(diffs, |diff| &diff.report);
+    let new_cache = FontCache::new(new_reporter, units);
+    let old_cache = FontCache::new(old_reporter, units);
+    let script_sections = script_diffs
+        .par_iter()
+        .map(|(script, diffs)| {
+            let drawn_diffs = diffs
+                .par_iter()
+                .map(|diff| draw_report_diff(&new_cache, &old_cache, diff))
+                .collect::<Vec<_>>();
+            html! {
+                details.filterable open data-script=(script) {
+                    summary { h2 { (script_label(script)) } }
+                    @for (diff, drawn_exemplars) in diffs.iter().zip(drawn_diffs) {
+                        details open {
+                            summary {
+                                (diff.report.word_list.id()) " @ "
+                                (format_location(&new_cache, diff.report.location))
+                            }
+                            ul.drawn.diff {
+                                @for drawn_exemplar in drawn_exemplars {
+                                    (drawn_exemplar)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let html = html! {
+        (DOCTYPE)
+        html data-theme=[theme.attribute()] {
+            head {
+                title { "Font Height comparison" }
+                meta charset="utf-8";
+                style { (PreEscaped(CSS)) }
+            }
+            body {
+                h1 { "Font Height comparison" }
+                (format_font_warnings(&new_cache, Some("new font")))
+                (format_font_warnings(&old_cache, Some("old font")))
+                (format_axes(&new_cache))
+                p {
+                    "The exemplars of the new font, drawn with the old font "
+                    "(left) and the new font (right) at the same location. "
+                    "Lines show each font's own metrics (see the legend of "
+                    "the regular report). Differences are "
+                    span.further { "further from" }
+                    " or "
+                    span.nearer { "nearer to" }
+                    " the baseline in the new font."
+                }
+                p.filters {
+                    label {
+                        "Script "
+                        select #script-filter {
+                            option value="" { "All" }
+                            @for script in script_diffs.keys() {
+                                option value=(script) { (script_label(script)) }
+                            }
+                        }
+                    }
+                    label {
+                        "Word "
+                        input #word-filter type="search";
+                    }
+                }
+                @for script_section in script_sections {
+                    (script_section)
                 }
+                script { (PreEscaped(JS)) }
             }
         }
     };
@@ -0,0 +1,534 @@
+//! The documents written by `--format json` (and read back to merge them), as
+//! serde types.
+//!
+//! Extremes are in the [`Units`] reports are written in, except histograms,
+//! which are always in font units.
+
+use std::{collections::BTreeMap, convert::Infallible};
+
+use fontheight::{
+    AxisInfo, ClusterExtremes, Histogram, InstanceReporter, Provenance, Report,
+    ReportStatistics, ReportWarning, ShapingProperties, WordExtremes, WordList,
+    errors::FontHeightError,
+};
+use harfshapedfa::Location;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::fmt::units::{Units, UnitsJson};
+
+/// A check of some fonts, as written by `fontheight --format json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckJson {
+    /// Not written for merged reports, which are made from many runs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<ProvenanceJson>,
+    pub config: ConfigJson,
+    pub fonts: Vec<FontJson>,
+    /// The fonts that couldn't be checked, with --keep-going
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FontErrorJson>,
+}
+
+/// When & how a run's reports were made, so they can be reproduced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceJson {
+    pub generated_at: String,
+    pub fontheight_cli_version: String,
+    pub fontheight_version: String,
+    pub harfrust_version: String,
+    pub skrifa_version: String,
+    pub word_lists: Vec<ProvenanceWordListJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceWordListJson {
+    pub name: String,
+    pub content_hash: String,
+}
+
+/// The options that affect the results of a check, recorded so reports from
+/// different runs can be safely merged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigJson {
+    pub results: usize,
+    pub words: Option<usize>,
+    pub distinct: bool,
+    pub distinct_word_lists: Vec<String>,
+    pub segmentation: String,
+    pub punctuation: bool,
+    pub exclude_patterns: Vec<String>,
+    pub word_length_limit: Option<usize>,
+    pub word_length_unit: String,
+    pub long_words: String,
+    pub min_ink_height: Option<f64>,
+    pub min_glyphs: Option<usize>,
+    pub all_wordlists: bool,
+    pub ignore_meta: bool,
+    pub optimize_locations: bool,
+    pub word_lists: Vec<String>,
+    pub shard: Option<String>,
+    pub subset: Option<String>,
+    pub prioritize: Vec<String>,
+    pub deadline: Option<f64>,
+    pub clusters: bool,
+    pub fast: bool,
+    pub location_strategy: String,
+    pub masters: bool,
+    pub random_locations: Option<String>,
+    pub fallback_fonts: Vec<String>,
+    pub partial_words: bool,
+    pub histogram: Option<f64>,
+    pub script_margins: bool,
+    pub min_margin: f64,
+    pub clipping: Vec<String>,
+    pub joiner_permutations: bool,
+    pub line_stacking: bool,
+    pub em_box: bool,
+    pub compare_metrics: bool,
+    pub units: UnitsJson,
+}
+
+/// The reports of a font, and the analyses made of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FontJson {
+    pub font: String,
+    pub checksum: String,
+    pub axes: Vec<AxisJson>,
+    pub warnings: Vec<WarningJson>,
+    pub reports: Vec<ReportJson>,
+    /// The language system each word list was shaped with, by word list ID.
+    /// Not written for merged reports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_systems: Option<BTreeMap<String, Option<String>>>,
+    /// With --deadline, the word lists & locations that weren't checked in
+    /// time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_analyzed: Option<Vec<NotAnalyzedJson>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimized: Option<Vec<OptimizedJson>>,
+    // The other analyses are only written, so aren't typed any further
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_margins: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clipping: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_stacking: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub em_box: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric_verdicts: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joiner_permutations: Option<Value>,
+}
+
+/// A font that couldn't be checked, and why.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FontErrorJson {
+    pub font: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotAnalyzedJson {
+    pub word_list: WordListJson,
+    pub location: Location,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordListJson {
+    pub id: String,
+    pub name: String,
+    pub source: Option<String>,
+    pub script: Option<ScriptJson>,
+    pub language: Option<String>,
+    pub license: Option<String>,
+    /// `None` for merged shards, as each shard only hashes its own part of
+    /// the word list
+    pub content_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptJson {
+    pub code: String,
+    pub name: Option<String>,
+    pub native_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisJson {
+    pub tag: String,
+    pub name: String,
+    pub min: f32,
+    pub default: f32,
+    pub max: f32,
+}
+
+/// A report of a word list at a location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportJson {
+    pub word_list: WordListJson,
+    pub location: Location,
+    pub highest: Vec<ExemplarJson>,
+    pub lowest: Vec<ExemplarJson>,
+    pub statistics: StatisticsJson,
+    pub feature_variation: Option<usize>,
+    pub histogram: Option<HistogramJson>,
+    pub warnings: Vec<WarningJson>,
+    pub support: Option<String>,
+    pub shaping: Vec<ShapingJson>,
+}
+
+/// A word & the extremes it reached.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExemplarJson {
+    pub word: String,
+    pub index: usize,
+    pub lowest: f64,
+    pub highest: f64,
+    /// Only words wrapped in punctuation (see --punctuation) have any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub punctuation: Option<String>,
+    /// Only partial words (see --partial-words) have glyphs skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped_glyphs: Option<usize>,
+    /// Only written with --clusters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clusters: Option<Vec<ClusterJson>>,
+}
+
+/// The extremes reached by a grapheme cluster of a word.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClusterJson {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub lowest: f64,
+    pub highest: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatisticsJson {
+    pub words_checked: usize,
+    pub missing_glyphs: usize,
+    pub anomalies: usize,
+    pub fallback: usize,
+    pub partial: usize,
+    pub excluded: usize,
+    pub too_long: usize,
+    pub filtered: usize,
+    pub exceeding: usize,
+}
+
+impl StatisticsJson {
+    /// Add the counts of another report of the same word list, e.g. another
+    /// shard of it.
+    pub const fn add(&mut self, other: &StatisticsJson) {
+        self.words_checked += other.words_checked;
+        self.missing_glyphs += other.missing_glyphs;
+        self.anomalies += other.anomalies;
+        self.fallback += other.fallback;
+        self.partial += other.partial;
+        self.excluded += other.excluded;
+        self.too_long += other.too_long;
+        self.filtered += other.filtered;
+        self.exceeding += other.exceeding;
+    }
+}
+
+/// A warning, with a message to show people & the details for tools.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarningJson {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declared: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detected: Option<String>,
+}
+
+/// The script, language & direction some of a report's words were shaped
+/// with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapingJson {
+    pub script: String,
+    pub language: Option<String>,
+    pub direction: String,
+    pub guessed: bool,
+}
+
+/// A histogram's bins, in font units regardless of the units reports are
+/// written in, so the bins of reports being merged line up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistogramJson {
+    pub bin_size: f64,
+    pub highest: Vec<BinJson>,
+    pub lowest: Vec<BinJson>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BinJson {
+    pub start: f64,
+    pub count: usize,
+}
+
+/// The most extreme word of a word list found by --optimize-locations, at
+/// the location it was found at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptimizedJson {
+    pub word_list: WordListJson,
+    pub highest: Option<OptimizedWordJson>,
+    pub lowest: Option<OptimizedWordJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptimizedWordJson {
+    pub word: String,
+    pub index: usize,
+    pub location: Location,
+    pub lowest: f64,
+    pub highest: f64,
+}
+
+pub fn provenance_to_json(provenance: &Provenance) -> ProvenanceJson {
+    ProvenanceJson {
+        generated_at: provenance.timestamp(),
+        fontheight_cli_version: env!("CARGO_PKG_VERSION").to_owned(),
+        fontheight_version: Provenance::FONTHEIGHT_VERSION.to_owned(),
+        harfrust_version: Provenance::HARFRUST_VERSION.to_owned(),
+        skrifa_version: Provenance::SKRIFA_VERSION.to_owned(),
+        word_lists: provenance
+            .word_lists()
+            .iter()
+            .map(|(name, content_hash)| ProvenanceWordListJson {
+                name: name.clone(),
+                content_hash: format!("{content_hash:08x}"),
+            })
+            .collect(),
+    }
+}
+
+pub fn word_list_to_json(word_list: &WordList) -> WordListJson {
+    WordListJson {
+        id: word_list.id().to_string(),
+        name: word_list.name().to_owned(),
+        source: word_list.source().map(str::to_owned),
+        script: word_list.script().map(|script| ScriptJson {
+            code: script.to_owned(),
+            name: static_lang_word_lists::script_name(script)
+                .map(str::to_owned),
+            native_name: static_lang_word_lists::script_native_name(script)
+                .map(str::to_owned),
+        }),
+        language: word_list.language().map(str::to_owned),
+        license: word_list.license().map(str::to_owned),
+        content_hash: Some(format!("{:08x}", word_list.content_hash())),
+    }
+}
+
+pub fn axes_to_json(axes: &[AxisInfo]) -> Vec<AxisJson> {
+    axes.iter()
+        .map(|axis| AxisJson {
+            tag: axis.tag.to_string(),
+            name: axis.name.clone(),
+            min: axis.min,
+            default: axis.default,
+            max: axis.max,
+        })
+        .collect()
+}
+
+pub fn word_extremes_to_json(
+    word_extremes: &WordExtremes,
+    units: Units,
+) -> ExemplarJson {
+    ExemplarJson {
+        word: word_extremes.word.to_owned(),
+        index: word_extremes.index,
+        lowest: units.convert(word_extremes.extremes.lowest()),
+        highest: units.convert(word_extremes.extremes.highest()),
+        punctuation: word_extremes
+            .punctuation
+            .map(|punctuation| punctuation.name().to_owned()),
+        skipped_glyphs: word_extremes
+            .is_partial()
+            .then_some(word_extremes.skipped_glyphs),
+        clusters: None,
+    }
+}
+
+pub const fn statistics_to_json(
+    statistics: &ReportStatistics,
+) -> StatisticsJson {
+    StatisticsJson {
+        words_checked: statistics.words_checked,
+        missing_glyphs: statistics.missing_glyphs,
+        anomalies: statistics.anomalies,
+        fallback: statistics.fallback,
+        partial: statistics.partial,
+        excluded: statistics.excluded,
+        too_long: statistics.too_long,
+        filtered: statistics.filtered,
+        exceeding: statistics.exceeding,
+    }
+}
+
+pub fn warning_to_json(warning: &ReportWarning) -> WarningJson {
+    let json = WarningJson {
+        message: warning.to_string(),
+        kind: None,
+        declared: None,
+        detected: None,
+    };
+    match warning {
+        ReportWarning::ScriptMismatch { declared, detected } => WarningJson {
+            kind: Some("script_mismatch".to_owned()),
+            declared: Some(declared.clone()),
+            detected: Some(detected.clone()),
+            ..json
+        },
+        ReportWarning::NoNamedInstances => WarningJson {
+            kind: Some("no_named_instances".to_owned()),
+            ..json
+        },
+        _ => json,
+    }
+}
+
+/// The extremes reached by each grapheme cluster of a word.
+pub fn clusters_to_json(
+    word: &str,
+    clusters: &[ClusterExtremes],
+    units: Units,
+) -> Vec<ClusterJson> {
+    clusters
+        .iter()
+        .map(|(range, extremes)| ClusterJson {
+            text: word[range.clone()].to_owned(),
+            start: range.start,
+            end: range.end,
+            lowest: units.convert(extremes.lowest()),
+            highest: units.convert(extremes.highest()),
+        })
+        .collect()
+}
+
+pub fn report_to_json(report: &Report, units: Units) -> ReportJson {
+    let Ok(json) = report_to_json_with(report, |word_extremes| {
+        Ok::<_, Infallible>(word_extremes_to_json(word_extremes, units))
+    });
+    json
+}
+
+/// Like [`report_to_json`], but also includes the extremes reached by each
+/// grapheme cluster of the exemplars.
+///
+/// `instance` must be the [`InstanceReporter`] that made the report.
+pub fn report_with_clusters_to_json(
+    report: &Report,
+    instance: &InstanceReporter,
+    units: Units,
+) -> Result<ReportJson, FontHeightError> {
+    report_to_json_with(report, |word_extremes| {
+        let clusters = instance
+            .cluster_extremes(report.word_list, word_extremes.word)?
+            .unwrap_or_default();
+        Ok(ExemplarJson {
+            clusters: Some(clusters_to_json(
+                word_extremes.word,
+                &clusters,
+                units,
+            )),
+            ..word_extremes_to_json(word_extremes, units)
+        })
+    })
+}
+
+fn report_to_json_with<E>(
+    report: &Report,
+    mut exemplar_to_json: impl FnMut(&WordExtremes) -> Result<ExemplarJson, E>,
+) -> Result<ReportJson, E> {
+    let mut exemplars_to_json = |exemplars: &[WordExtremes]| {
+        exemplars
+            .iter()
+            .map(&mut exemplar_to_json)
+            .collect::<Result<Vec<_>, _>>()
+    };
+    Ok(ReportJson {
+        word_list: word_list_to_json(report.word_list),
+        location: report.location.clone(),
+        highest: exemplars_to_json(report.exemplars.highest())?,
+        lowest: exemplars_to_json(report.exemplars.lowest())?,
+        statistics: statistics_to_json(&report.statistics),
+        feature_variation: report.feature_variation,
+        histogram: report.histogram.as_ref().map(histogram_to_json),
+        warnings: report.warnings.iter().map(warning_to_json).collect(),
+        support: report.support.map(|support| support.name().to_owned()),
+        shaping: report.shaping.iter().map(shaping_to_json).collect(),
+    })
+}
+
+pub fn shaping_to_json(properties: &ShapingProperties) -> ShapingJson {
+    ShapingJson {
+        script: properties.script.clone(),
+        language: properties.language.clone(),
+        direction: properties.direction.name().to_owned(),
+        guessed: properties.guessed,
+    }
+}
+
+pub fn histogram_to_json(histogram: &Histogram) -> HistogramJson {
+    let bins_to_json = |bins: &mut dyn Iterator<Item = (f64, usize)>| {
+        bins.map(|(start, count)| BinJson { start, count })
+            .collect()
+    };
+    HistogramJson {
+        bin_size: histogram.bin_size(),
+        highest: bins_to_json(&mut histogram.highest()),
+        lowest: bins_to_json(&mut histogram.lowest()),
+    }
+}
+
+/// An exemplar picked out by one of the analyses of a check (e.g. as it's
+/// clipped), followed by the analysis' details of it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnalyzedExemplarJson<T> {
+    #[serde(flatten)]
+    pub exemplar: ExemplarJson,
+    #[serde(flatten)]
+    pub details: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn reads_exemplars_back() {
+        let json = json!({
+            "word": "Åsa",
+            "index": 3,
+            "lowest": -12.0,
+            "highest": 940.5,
+            "punctuation": "quotes",
+        });
+        let exemplar =
+            serde_json::from_value::<ExemplarJson>(json.clone()).unwrap();
+        assert_eq!(exemplar.punctuation.as_deref(), Some("quotes"));
+        assert_eq!(exemplar.skipped_glyphs, None);
+        // Optional fields that weren't there aren't written back
+        assert_eq!(serde_json::to_value(&exemplar).unwrap(), json);
+
+        let analyzed = AnalyzedExemplarJson {
+            exemplar,
+            details: json!({ "overflow": 40.5 }),
+        };
+        assert_eq!(
+            serde_json::to_value(&analyzed).unwrap()["overflow"],
+            json!(40.5),
+        );
+    }
+}
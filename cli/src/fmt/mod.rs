@@ -1,33 +1,156 @@
-use std::fmt;
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::Path,
+};
 
-use fontheight::Report;
+use anyhow::Context;
+use fontheight::{
+    Histogram, Report, ShapingProperties, language_support::SupportLevel,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::fmt::{units::Units, words::WordStyle};
 
 pub mod html;
+pub mod json;
+pub mod sarif;
+pub mod units;
+pub mod words;
+
+/// A script's English name with its ISO 15924 code (e.g. `Devanagari (Deva)`),
+/// or only the code if its name isn't known.
+pub fn script_label(code: &str) -> String {
+    match static_lang_word_lists::script_name(code) {
+        Some(name) => format!("{name} ({code})"),
+        None => code.to_owned(),
+    }
+}
+
+/// Describe the script, language & direction words were shaped with, e.g.
+/// "Latn (sv, ltr)" or "Arab (rtl, guessed)".
+pub fn shaping_label(properties: &ShapingProperties) -> String {
+    let mut label = format!("{} (", properties.script);
+    if let Some(language) = &properties.language {
+        label.push_str(language);
+        label.push_str(", ");
+    }
+    label.push_str(properties.direction.name());
+    if properties.guessed {
+        label.push_str(", guessed");
+    }
+    label.push(')');
+    label
+}
+
+/// Describe every script, language & direction a report's words were shaped
+/// with.
+pub fn shaping_labels(shaping: &[ShapingProperties]) -> String {
+    shaping
+        .iter()
+        .map(shaping_label)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
 
 #[derive(Debug, Copy, Clone)]
 pub struct ReportFormatter<'a> {
     report: &'a Report<'a>,
     format: OutputFormat,
+    units: Units,
+    words: WordStyle,
 }
 
 impl fmt::Display for ReportFormatter<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let ReportFormatter { report, format } = *self;
+        let ReportFormatter {
+            report,
+            format,
+            units,
+            words,
+        } = *self;
         match format {
             OutputFormat::Human => {
-                writeln!(
+                write!(
                     f,
-                    "  {} @ {:?}:",
-                    report.word_list.name(),
-                    report.location,
+                    "  {} @ {:?}",
+                    report.word_list.id(),
+                    report.location
                 )?;
+                if let Some(feature_variation) = report.feature_variation {
+                    write!(f, " (feature variation {feature_variation})")?;
+                }
+                match report.support {
+                    Some(SupportLevel::Partial) => {
+                        write!(f, " (partially supported)")?;
+                    },
+                    Some(SupportLevel::None) => write!(f, " (unsupported)")?,
+                    Some(SupportLevel::Full) | None => {},
+                }
+                writeln!(f, ":")?;
+                report.warnings.iter().try_for_each(|warning| {
+                    writeln!(f, "    warning: {warning}")
+                })?;
+                if !report.shaping.is_empty() {
+                    writeln!(
+                        f,
+                        "    shaped as: {}",
+                        shaping_labels(&report.shaping),
+                    )?;
+                }
+                if report.statistics.excluded > 0 {
+                    writeln!(
+                        f,
+                        "    {} words excluded by --exclude-pattern",
+                        report.statistics.excluded,
+                    )?;
+                }
+                if report.statistics.too_long > 0 {
+                    writeln!(
+                        f,
+                        "    {} words longer than --word-length-limit",
+                        report.statistics.too_long,
+                    )?;
+                }
+                if report.statistics.filtered > 0 {
+                    writeln!(
+                        f,
+                        "    {} words kept out of the exemplars by \
+                         --min-ink-height & --min-glyphs",
+                        report.statistics.filtered,
+                    )?;
+                }
+                if report.statistics.anomalies > 0 {
+                    writeln!(
+                        f,
+                        "    {} words skipped as they shaped to nothing \
+                         visible",
+                        report.statistics.anomalies,
+                    )?;
+                }
+                if report.statistics.fallback > 0 {
+                    writeln!(
+                        f,
+                        "    {} words measured without characters left to \
+                         fallback fonts",
+                        report.statistics.fallback,
+                    )?;
+                }
+                if report.statistics.partial > 0 {
+                    writeln!(
+                        f,
+                        "    {} words measured without glyphs missing from \
+                         the font",
+                        report.statistics.partial,
+                    )?;
+                }
                 writeln!(f, "    {} tallest words:", report.exemplars.len(),)?;
                 report.exemplars.highest().iter().try_for_each(|exemplar| {
                     writeln!(
                         f,
-                        "      \"{}\" => {}",
-                        exemplar.word,
-                        exemplar.extremes.highest(),
+                        "      {} => {}",
+                        words.display(&exemplar.shaped_text()),
+                        units.convert(exemplar.extremes.highest()),
                     )
                 })?;
                 writeln!(f, "    {} lowest words:", report.exemplars.len(),)?;
@@ -39,40 +162,193 @@ impl fmt::Display for ReportFormatter<'_> {
                         if index != last {
                             writeln!(
                                 f,
-                                "      \"{}\" => {}",
-                                exemplar.word,
-                                exemplar.extremes.lowest(),
+                                "      {} => {}",
+                                words.display(&exemplar.shaped_text()),
+                                units.convert(exemplar.extremes.lowest()),
                             )
                         } else {
                             write!(
                                 f,
-                                "      \"{}\" => {}",
-                                exemplar.word,
-                                exemplar.extremes.lowest(),
+                                "      {} => {}",
+                                words.display(&exemplar.shaped_text()),
+                                units.convert(exemplar.extremes.lowest()),
                             )
                         }
                     },
                 )?;
+                if let Some(histogram) = &report.histogram {
+                    write_histogram(f, histogram, units)?;
+                }
+            },
+            OutputFormat::Json => {
+                let json =
+                    serde_json::to_string(&json::report_to_json(report, units))
+                        .map_err(|_| fmt::Error)?;
+                f.write_str(&json)?;
+            },
+            OutputFormat::Cbor => {
+                unreachable!("CBOR is binary, write it with write_document")
             },
         }
         Ok(())
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+/// Draw the bins of a histogram as bars of `#`s, each line starting with a
+/// newline.
+fn write_histogram(
+    f: &mut fmt::Formatter<'_>,
+    histogram: &Histogram,
+    units: Units,
+) -> fmt::Result {
+    const WIDTH: usize = 40;
+
+    let bin_size = histogram.bin_size();
+    let bins = [
+        ("highest", histogram.highest().collect::<Vec<_>>()),
+        ("lowest", histogram.lowest().collect::<Vec<_>>()),
+    ];
+    bins.iter().try_for_each(|(name, bins)| {
+        write!(
+            f,
+            "\n    {name} points of {} words (bins of {}):",
+            histogram.len(),
+            units.convert(bin_size),
+        )?;
+        let max = bins.iter().map(|(_, count)| *count).max().unwrap_or(1);
+        bins.iter().try_for_each(|(start, count)| {
+            let range = format!(
+                "{}..{}",
+                units.convert(*start),
+                units.convert(start + bin_size),
+            );
+            let bar = "#".repeat((count * WIDTH / max).max(1));
+            write!(f, "\n      {range:>13} | {bar} {count}")
+        })
+    })
+}
+
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
 pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
     Human,
+    /// JSON, for consumption by other tools
+    Json,
+    /// CBOR, a compact binary equivalent of the JSON output
+    Cbor,
+}
+
+/// Write a machine-readable document in the given format.
+///
+/// # Panics
+///
+/// If `format` is [`OutputFormat::Human`].
+pub fn write_document(
+    output: &mut impl Write,
+    format: OutputFormat,
+    document: &impl Serialize,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Human => {
+            unreachable!("documents are only written in JSON or CBOR")
+        },
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *output, document)?;
+            writeln!(output)
+        },
+        OutputFormat::Cbor => {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(document, &mut bytes)
+                .map_err(io::Error::other)?;
+            output.write_all(&bytes)
+        },
+    }
+}
+
+/// Read a document written in either JSON or CBOR, detecting which from its
+/// contents.
+pub fn read_document<T: DeserializeOwned>(
+    path: &Path,
+) -> anyhow::Result<(T, OutputFormat)> {
+    let bytes = fs::read(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    // Documents are always JSON objects or arrays, whereas all CBOR maps &
+    // arrays start with a byte >= 0x80
+    let is_json = bytes
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| matches!(byte, b'{' | b'['));
+    let parsed: anyhow::Result<_> = if is_json {
+        serde_json::from_slice(&bytes)
+            .map(|document| (document, OutputFormat::Json))
+            .map_err(Into::into)
+    } else {
+        ciborium::from_reader(bytes.as_slice())
+            .map(|document| (document, OutputFormat::Cbor))
+            .map_err(Into::into)
+    };
+    parsed.with_context(|| format!("failed to parse {}", path.display()))
 }
 
 pub trait FormatReport<'a> {
-    fn format(&'a self, format: OutputFormat) -> ReportFormatter<'a>;
+    fn format(
+        &'a self,
+        format: OutputFormat,
+        units: Units,
+        words: WordStyle,
+    ) -> ReportFormatter<'a>;
 }
 
 impl<'a> FormatReport<'a> for Report<'a> {
-    fn format(&'a self, format: OutputFormat) -> ReportFormatter<'a> {
+    fn format(
+        &'a self,
+        format: OutputFormat,
+        units: Units,
+        words: WordStyle,
+    ) -> ReportFormatter<'a> {
         ReportFormatter {
             report: self,
             format,
+            units,
+            words,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    #[test]
+    fn reads_documents_back() {
+        let document = json!({
+            "word": "ẞtraße",
+            "index": 70_000,
+            "lowest": -29.0,
+            "highest": 1556.5,
+            "source": null,
+            "items": [true, false],
+        });
+        for format in [OutputFormat::Json, OutputFormat::Cbor] {
+            let path = std::env::temp_dir().join(format!(
+                "fontheight-document-{}.{format:?}",
+                std::process::id(),
+            ));
+            let mut bytes = Vec::new();
+            write_document(&mut bytes, format, &document).unwrap();
+            fs::write(&path, bytes).unwrap();
+            let read = read_document::<Value>(&path);
+            fs::remove_file(&path).unwrap();
+            let (read, read_format) = read.unwrap();
+            assert_eq!(read, document);
+            assert!(matches!(
+                (format, read_format),
+                (OutputFormat::Json, OutputFormat::Json)
+                    | (OutputFormat::Cbor, OutputFormat::Cbor)
+            ));
         }
     }
 }
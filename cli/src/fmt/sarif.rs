@@ -0,0 +1,536 @@
+//! [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! logs, so CI systems (e.g. GitHub code scanning) can show the problems found
+//! as annotations.
+
+use std::path::Path;
+
+use fontheight::{Location, Report, ReportWarning, WordExtremes, WordList};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    clipping::ClippingAnalysis,
+    compare_metrics::MetricComparison,
+    em_box::EmBoxAnalysis,
+    fmt::{json::warning_to_json, words::WordStyle},
+    line_stacking::LineStackingAnalysis,
+    margins::MarginAnalysis,
+};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The kinds of problems reported, each a SARIF rule.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Rule {
+    Clipped,
+    LineOverlap,
+    EmBox,
+    ScriptMargin,
+    MetricExceeded,
+    FontWarning,
+    ReportWarning,
+    CheckFailed,
+}
+
+impl Rule {
+    const ALL: [Rule; 8] = [
+        Rule::Clipped,
+        Rule::LineOverlap,
+        Rule::EmBox,
+        Rule::ScriptMargin,
+        Rule::MetricExceeded,
+        Rule::FontWarning,
+        Rule::ReportWarning,
+        Rule::CheckFailed,
+    ];
+
+    const fn id(self) -> &'static str {
+        match self {
+            Rule::Clipped => "clipped",
+            Rule::LineOverlap => "line-overlap",
+            Rule::EmBox => "em-box",
+            Rule::ScriptMargin => "script-margin",
+            Rule::MetricExceeded => "metric-exceeded",
+            Rule::FontWarning => "font-warning",
+            Rule::ReportWarning => "report-warning",
+            Rule::CheckFailed => "check-failed",
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Rule::Clipped => "A word reaches beyond a platform's clip box",
+            Rule::LineOverlap => {
+                "A word reaches into the line above or below it"
+            },
+            Rule::EmBox => "A CJK word reaches beyond the ideographic em-box",
+            Rule::ScriptMargin => {
+                "A script's words come too close to the typo ascender or \
+                 descender"
+            },
+            Rule::MetricExceeded => "A word reaches beyond a vertical metric",
+            Rule::FontWarning => "The font may not be checked as intended",
+            Rule::ReportWarning => {
+                "A word list may not have been checked as intended"
+            },
+            Rule::CheckFailed => "The font couldn't be checked",
+        }
+    }
+
+    const fn level(self) -> &'static str {
+        match self {
+            Rule::Clipped | Rule::MetricExceeded | Rule::CheckFailed => "error",
+            Rule::LineOverlap | Rule::EmBox | Rule::ScriptMargin => "warning",
+            Rule::FontWarning | Rule::ReportWarning => "note",
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "id": self.id(),
+            "shortDescription": { "text": self.description() },
+            "defaultConfiguration": { "level": self.level() },
+        })
+    }
+}
+
+/// A SARIF log of the problems found in each font checked.
+#[derive(Debug, Default)]
+pub struct SarifLog {
+    /// The fonts checked, as URIs
+    artifacts: Vec<String>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug)]
+struct SarifResult {
+    rule: Rule,
+    /// The index of the font in the log's artifacts
+    artifact: usize,
+    message: String,
+    properties: Value,
+}
+
+impl SarifLog {
+    /// Add a font checked, returning its index to add its results with.
+    pub fn add_font(&mut self, font_path: &Path) -> usize {
+        self.artifacts.push(path_to_uri(font_path));
+        self.artifacts.len() - 1
+    }
+
+    fn push(
+        &mut self,
+        rule: Rule,
+        artifact: usize,
+        message: String,
+        properties: Value,
+    ) {
+        self.results.push(SarifResult {
+            rule,
+            artifact,
+            message,
+            properties,
+        });
+    }
+
+    /// Add the font's warnings, and those of each of its reports.
+    pub fn add_warnings(
+        &mut self,
+        artifact: usize,
+        font_warnings: &[ReportWarning],
+        reports: &[Report],
+    ) {
+        font_warnings.iter().for_each(|warning| {
+            self.push(
+                Rule::FontWarning,
+                artifact,
+                warning.to_string(),
+                json!(warning_to_json(warning)),
+            );
+        });
+        reports.iter().for_each(|report| {
+            report.warnings.iter().for_each(|warning| {
+                self.push(
+                    Rule::ReportWarning,
+                    artifact,
+                    format!(
+                        "{} @ {:?}: {warning}",
+                        report.word_list.id(),
+                        report.location,
+                    ),
+                    {
+                        let mut properties = json!(warning_to_json(warning));
+                        properties["word_list"] =
+                            report.word_list.id().to_string().into();
+                        properties["location"] = json!(report.location);
+                        properties
+                    },
+                );
+            });
+        });
+    }
+
+    /// Add each exemplar clipped.
+    pub fn add_clipping(
+        &mut self,
+        artifact: usize,
+        clipping: &ClippingAnalysis,
+    ) {
+        let units = clipping.units;
+        clipping.profiles.iter().for_each(|profile| {
+            profile.clipped.iter().for_each(|clipped| {
+                let extremes = clipped.word_extremes.extremes;
+                self.push(
+                    Rule::Clipped,
+                    artifact,
+                    format!(
+                        "{} ({} to {}) is clipped by the {} clip box ({} to \
+                         {})",
+                        describe_word(
+                            clipping.words,
+                            &clipped.word_extremes,
+                            clipped.word_list,
+                            clipped.location,
+                        ),
+                        units.convert(extremes.lowest()),
+                        units.convert(extremes.highest()),
+                        profile.profile,
+                        units.convert(profile.clip_box.lowest()),
+                        units.convert(profile.clip_box.highest()),
+                    ),
+                    word_properties(
+                        &clipped.word_extremes,
+                        clipped.word_list,
+                        clipped.location,
+                        [("profile", profile.profile.to_string().into())],
+                    ),
+                );
+            });
+        });
+    }
+
+    /// Add each exemplar reaching into an adjacent line.
+    pub fn add_line_stacking(
+        &mut self,
+        artifact: usize,
+        line_stacking: &LineStackingAnalysis,
+    ) {
+        let units = line_stacking.units;
+        line_stacking.line_boxes.iter().for_each(|overflow| {
+            [("above", &overflow.above), ("below", &overflow.below)]
+                .into_iter()
+                .for_each(|(direction, intrusions)| {
+                    intrusions.iter().for_each(|intrusion| {
+                        self.push(
+                            Rule::LineOverlap,
+                            artifact,
+                            format!(
+                                "{} reaches {} into the line {direction} when \
+                                 lines are stacked by the {} metrics",
+                                describe_word(
+                                    line_stacking.words,
+                                    &intrusion.word_extremes,
+                                    intrusion.word_list,
+                                    intrusion.location,
+                                ),
+                                units.convert(intrusion.overflow),
+                                overflow.line_box.metrics,
+                            ),
+                            word_properties(
+                                &intrusion.word_extremes,
+                                intrusion.word_list,
+                                intrusion.location,
+                                [
+                                    (
+                                        "metrics",
+                                        overflow
+                                            .line_box
+                                            .metrics
+                                            .to_string()
+                                            .into(),
+                                    ),
+                                    ("direction", direction.into()),
+                                    (
+                                        "overflow",
+                                        units
+                                            .convert(intrusion.overflow)
+                                            .into(),
+                                    ),
+                                ],
+                            ),
+                        );
+                    });
+                });
+        });
+    }
+
+    /// Add each exemplar of a CJK word list reaching beyond the em-box.
+    pub fn add_em_box(&mut self, artifact: usize, em_box: &EmBoxAnalysis) {
+        let units = em_box.units;
+        em_box.locations.iter().for_each(|checked| {
+            [("above", &checked.above), ("below", &checked.below)]
+                .into_iter()
+                .for_each(|(direction, protrusions)| {
+                    protrusions.iter().for_each(|protrusion| {
+                        self.push(
+                            Rule::EmBox,
+                            artifact,
+                            format!(
+                                "{} reaches {} {direction} the ideographic \
+                                 em-box (from {})",
+                                describe_word(
+                                    em_box.words,
+                                    &protrusion.word_extremes,
+                                    protrusion.word_list,
+                                    checked.location,
+                                ),
+                                units.convert(protrusion.overflow),
+                                em_box.em_box.source,
+                            ),
+                            word_properties(
+                                &protrusion.word_extremes,
+                                protrusion.word_list,
+                                checked.location,
+                                [
+                                    ("direction", direction.into()),
+                                    (
+                                        "overflow",
+                                        units
+                                            .convert(protrusion.overflow)
+                                            .into(),
+                                    ),
+                                ],
+                            ),
+                        );
+                    });
+                });
+        });
+    }
+
+    /// Add each script margin that's flagged.
+    pub fn add_margins(&mut self, artifact: usize, margins: &MarginAnalysis) {
+        let units = margins.units;
+        margins.scripts.iter().for_each(|script| {
+            [
+                ("ascender", &script.tallest, margins.above(script)),
+                ("descender", &script.lowest, margins.below(script)),
+            ]
+            .into_iter()
+            .filter(|(_, _, margin)| margins.is_flagged(*margin))
+            .for_each(
+                |(metric, (word_extremes, location), margin)| {
+                    self.push(
+                        Rule::ScriptMargin,
+                        artifact,
+                        format!(
+                            "{} leaves a margin of {} to the typo {metric} \
+                             for the {} script, less than {}% of the UPM",
+                            margins.words.display(&word_extremes.shaped_text()),
+                            units.convert(margin),
+                            script.script,
+                            margins.min_margin_percent,
+                        ),
+                        json!({
+                            "word": word_extremes.word,
+                            "index": word_extremes.index,
+                            "location": location,
+                            "script": script.script,
+                            "metric": metric,
+                            "margin": units.convert(margin),
+                        }),
+                    );
+                },
+            );
+        });
+    }
+
+    /// Add each metric the words checked reached beyond.
+    pub fn add_metric_verdicts(
+        &mut self,
+        artifact: usize,
+        comparison: &MetricComparison,
+    ) {
+        let units = comparison.units;
+        comparison
+            .verdicts
+            .iter()
+            .filter(|verdict| !verdict.passed())
+            .for_each(|verdict| {
+                self.push(
+                    Rule::MetricExceeded,
+                    artifact,
+                    format!(
+                        "{} reaches {} beyond {} ({})",
+                        describe_word(
+                            comparison.words,
+                            &verdict.word,
+                            verdict.report.word_list,
+                            verdict.report.location,
+                        ),
+                        units.convert(-verdict.margin()),
+                        verdict.metric,
+                        units.convert(verdict.value),
+                    ),
+                    word_properties(
+                        &verdict.word,
+                        verdict.report.word_list,
+                        verdict.report.location,
+                        [
+                            ("metric", verdict.metric.into()),
+                            ("margin", units.convert(verdict.margin()).into()),
+                        ],
+                    ),
+                );
+            });
+    }
+
+    /// Add a font that couldn't be checked.
+    pub fn add_failure(&mut self, artifact: usize, why: &anyhow::Error) {
+        self.push(Rule::CheckFailed, artifact, format!("{why:#}"), json!({}));
+    }
+
+    pub fn to_json(&self) -> Value {
+        let results = self
+            .results
+            .iter()
+            .map(|result| {
+                let rule_index = Rule::ALL
+                    .iter()
+                    .position(|rule| *rule == result.rule)
+                    .unwrap_or_default();
+                json!({
+                    "ruleId": result.rule.id(),
+                    "ruleIndex": rule_index,
+                    "level": result.rule.level(),
+                    "message": { "text": result.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": {
+                                "uri": self.artifacts[result.artifact],
+                                "index": result.artifact,
+                            },
+                        },
+                    }],
+                    "properties": result.properties,
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({
+            "$schema": SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "fontheight",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": Rule::ALL.map(Rule::to_json),
+                    },
+                },
+                "artifacts": self
+                    .artifacts
+                    .iter()
+                    .map(|uri| json!({ "location": { "uri": uri } }))
+                    .collect::<Vec<_>>(),
+                "results": results,
+            }],
+        })
+    }
+}
+
+/// A relative URI for relative paths (as code scanning expects, relative to the
+/// repository), or a `file://` URI for absolute paths.
+fn path_to_uri(path: &Path) -> String {
+    // URIs always use forward slashes
+    let path = path.display().to_string().replace('\\', "/");
+    let mut uri = String::with_capacity(path.len());
+    if path.starts_with('/') {
+        uri.push_str("file://");
+    } else if Path::new(&path).is_absolute() {
+        // e.g. C:/fonts on Windows
+        uri.push_str("file:///");
+    }
+    path.bytes().for_each(|byte| match byte {
+        b'A'..=b'Z'
+        | b'a'..=b'z'
+        | b'0'..=b'9'
+        | b'-'
+        | b'.'
+        | b'_'
+        | b'~'
+        | b'/'
+        | b':' => uri.push(char::from(byte)),
+        byte => uri.push_str(&format!("%{byte:02X}")),
+    });
+    uri
+}
+
+/// A word, where it's from & where it was checked, for messages.
+fn describe_word(
+    words: WordStyle,
+    word_extremes: &WordExtremes,
+    word_list: &WordList,
+    location: &Location,
+) -> String {
+    format!(
+        "{} (from {} @ {location:?})",
+        words.display(&word_extremes.shaped_text()),
+        word_list.id(),
+    )
+}
+
+/// A word, where it's from & where it was checked, for tools, followed by
+/// the `extra` properties of the result.
+fn word_properties<const N: usize>(
+    word_extremes: &WordExtremes,
+    word_list: &WordList,
+    location: &Location,
+    extra: [(&str, Value); N],
+) -> Value {
+    let mut properties = Map::new();
+    properties.insert("word".to_owned(), word_extremes.word.into());
+    properties.insert("index".to_owned(), word_extremes.index.into());
+    properties
+        .insert("word_list".to_owned(), word_list.id().to_string().into());
+    properties.insert("location".to_owned(), json!(location));
+    properties.extend(
+        extra
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value)),
+    );
+    Value::Object(properties)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_results_against_fonts() {
+        let mut log = SarifLog::default();
+        let font = log.add_font(Path::new("fonts/Test.ttf"));
+        log.add_warnings(font, &[ReportWarning::NoNamedInstances], &[]);
+
+        let json = log.to_json();
+        let run = &json["runs"][0];
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "font-warning");
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(
+            result["ruleIndex"],
+            rules
+                .iter()
+                .position(|rule| rule["id"] == result["ruleId"])
+                .unwrap()
+        );
+        assert_eq!(run["artifacts"][0]["location"]["uri"], "fonts/Test.ttf");
+    }
+
+    #[test]
+    fn paths_to_uris() {
+        assert_eq!(path_to_uri(Path::new("My Font.ttf")), "My%20Font.ttf");
+        assert_eq!(
+            path_to_uri(Path::new("/fonts/Test.ttf")),
+            "file:///fonts/Test.ttf"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Converting & rounding extremes for output.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// The unit to write extremes in
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+pub enum Unit {
+    /// The font's own units
+    #[default]
+    FontUnits,
+    /// Thousandths of the font's units per em
+    PerMille,
+    /// Pixels at the size given by --ppem
+    Pixels,
+}
+
+#[derive(Debug, Copy, Clone, clap::Args)]
+pub struct UnitArgs {
+    /// The unit to write extremes in
+    #[arg(long, value_enum, default_value_t)]
+    units: Unit,
+
+    /// The pixels per em to convert extremes to pixels at, for --units pixels
+    #[arg(long, required_if_eq("units", "pixels"))]
+    ppem: Option<f64>,
+
+    /// The number of decimal places to round extremes to
+    #[arg(long, default_value_t = 0)]
+    decimals: u8,
+}
+
+impl UnitArgs {
+    /// The [`Units`] to write a font's extremes in.
+    pub fn for_font(&self, units_per_em: u16) -> Units {
+        let units_per_em = f64::from(units_per_em);
+        let scale = match self.units {
+            Unit::FontUnits => 1.0,
+            Unit::PerMille => 1000.0 / units_per_em,
+            Unit::Pixels => {
+                self.ppem.expect("clap requires --ppem") / units_per_em
+            },
+        };
+        Units {
+            scale,
+            decimals: Some(self.decimals),
+        }
+    }
+
+    /// The options, as recorded in JSON reports.
+    pub fn to_json(self) -> UnitsJson {
+        UnitsJson {
+            unit: self
+                .units
+                .to_possible_value()
+                .expect("no skipped units")
+                .get_name()
+                .to_owned(),
+            ppem: self.ppem,
+            decimals: self.decimals,
+        }
+    }
+}
+
+/// The [`UnitArgs`] reports were written with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnitsJson {
+    pub unit: String,
+    pub ppem: Option<f64>,
+    pub decimals: u8,
+}
+
+/// How extremes (in font units) are written: scaled to a unit, and rounded.
+#[derive(Debug, Copy, Clone)]
+pub struct Units {
+    scale: f64,
+    decimals: Option<u8>,
+}
+
+impl Units {
+    /// Font units, unrounded.
+    pub const EXACT: Units = Units {
+        scale: 1.0,
+        decimals: None,
+    };
+
+    /// Convert a value in font units.
+    pub fn convert(self, font_units: f64) -> f64 {
+        let value = font_units * self.scale;
+        let value = match self.decimals {
+            Some(decimals) => {
+                let factor = 10f64.powi(i32::from(decimals));
+                (value * factor).round() / factor
+            },
+            None => value,
+        };
+        // Don't write -0 for values that round to zero
+        value + 0.0
+    }
+}
@@ -0,0 +1,146 @@
+//! Writing words safely in human-readable output.
+
+use std::{
+    fmt::{self, Write},
+    num::NonZeroUsize,
+};
+
+#[derive(Debug, Copy, Clone, clap::Args)]
+pub struct WordArgs {
+    /// Write words as sequences of code points (e.g. U+0628 U+0651), rather
+    /// than as text, in human-readable output. Control characters are always
+    /// escaped
+    #[arg(long)]
+    escape_words: bool,
+
+    /// Cut words longer than this many characters short with an ellipsis in
+    /// human-readable output
+    #[arg(long, value_name = "LENGTH")]
+    max_word_length: Option<NonZeroUsize>,
+}
+
+impl WordArgs {
+    /// The [`WordStyle`] to write words in.
+    pub fn style(&self) -> WordStyle {
+        WordStyle {
+            code_points: self.escape_words,
+            max_length: self.max_word_length.map(NonZeroUsize::get),
+        }
+    }
+}
+
+/// How words are written in human-readable output.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WordStyle {
+    code_points: bool,
+    max_length: Option<usize>,
+}
+
+impl WordStyle {
+    /// Whether words are written as code points.
+    pub const fn code_points(self) -> bool {
+        self.code_points
+    }
+
+    /// Display `word`, quoted unless written as code points.
+    pub const fn display(self, word: &str) -> DisplayWord<'_> {
+        DisplayWord { style: self, word }
+    }
+}
+
+/// Characters that would move or garble the surrounding output if written to
+/// a terminal as-is: control characters and bidi formatting characters.
+fn is_unsafe(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{061C}'
+                | '\u{200E}'
+                | '\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2066}'..='\u{2069}'
+        )
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayWord<'a> {
+    style: WordStyle,
+    word: &'a str,
+}
+
+impl fmt::Display for DisplayWord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let WordStyle {
+            code_points,
+            max_length,
+        } = self.style;
+        let max_length = max_length.unwrap_or(usize::MAX);
+        let truncated = self.word.chars().nth(max_length).is_some();
+        let chars = self.word.chars().take(max_length);
+        if code_points {
+            chars.enumerate().try_for_each(|(index, c)| {
+                if index > 0 {
+                    f.write_char(' ')?;
+                }
+                write!(f, "U+{:04X}", u32::from(c))
+            })?;
+            if truncated {
+                f.write_str(" …")?;
+            }
+            return Ok(());
+        }
+
+        f.write_char('"')?;
+        chars.into_iter().try_for_each(|c| {
+            if is_unsafe(c) {
+                write!(f, "<U+{:04X}>", u32::from(c))
+            } else {
+                f.write_char(c)
+            }
+        })?;
+        if truncated {
+            f.write_char('…')?;
+        }
+        f.write_char('"')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_unsafe_characters() {
+        let style = WordStyle::default();
+        assert_eq!(
+            style.display("a\u{202E}b\nc").to_string(),
+            "\"a<U+202E>b<U+000A>c\""
+        );
+        assert_eq!(style.display("بّ").to_string(), "\"بّ\"");
+    }
+
+    #[test]
+    fn writes_code_points() {
+        let style = WordStyle {
+            code_points: true,
+            max_length: None,
+        };
+        assert_eq!(style.display("بّ").to_string(), "U+0628 U+0651");
+    }
+
+    #[test]
+    fn truncates_long_words() {
+        let style = WordStyle {
+            code_points: false,
+            max_length: Some(3),
+        };
+        assert_eq!(style.display("abcdef").to_string(), "\"abc…\"");
+        assert_eq!(style.display("abc").to_string(), "\"abc\"");
+
+        let style = WordStyle {
+            code_points: true,
+            max_length: Some(1),
+        };
+        assert_eq!(style.display("ab").to_string(), "U+0061 …");
+    }
+}
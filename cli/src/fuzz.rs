@@ -0,0 +1,329 @@
+use std::{
+    fs,
+    io::{Write, stdout},
+    ops::RangeInclusive,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::{Context, bail};
+use fontheight::{Location, Report, Reporter, WordExtremes, WordList};
+use harfrust::{Script, UnicodeBuffer, script};
+use log::info;
+use rayon::prelude::*;
+use serde_json::json;
+use skrifa::{
+    MetadataProvider,
+    raw::{ReadError, TableProvider},
+};
+
+use crate::{
+    fmt::{OutputFormat, json, units::Units, write_document},
+    rng::SeededRng,
+};
+
+/// Shape pseudo-random sequences of the font's characters, reporting any that
+/// reach beyond the thresholds.
+///
+/// Words are generated from a seed, so runs can be reproduced
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The TTF to fuzz
+    font_path: PathBuf,
+
+    /// The seed to generate words from
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// The number of words to generate
+    #[arg(long, default_value_t = 10_000)]
+    count: usize,
+
+    /// The minimum number of characters in each word
+    #[arg(long, default_value_t = 1)]
+    min_length: usize,
+
+    /// The maximum number of characters in each word
+    #[arg(long, default_value_t = 8)]
+    max_length: usize,
+
+    /// Only use characters from this script (an ISO 15924 code, e.g. Latn),
+    /// and combining marks
+    #[arg(long)]
+    script: Option<String>,
+
+    /// The chance (0-1) of each character after the first being a combining
+    /// mark, to find tall or deep mark stacks
+    #[arg(long, default_value_t = 0.3)]
+    marks: f64,
+
+    /// Report words reaching above this height, in font units [default:
+    /// OS/2.usWinAscent]
+    #[arg(long, allow_hyphen_values = true)]
+    above: Option<f64>,
+
+    /// Report words reaching below this depth, in font units [default:
+    /// -OS/2.usWinDescent]
+    #[arg(long, allow_hyphen_values = true)]
+    below: Option<f64>,
+
+    /// The maximum number of words to report per location, in each direction
+    #[arg(short = 'n', long, default_value_t = 5)]
+    results: usize,
+
+    /// The format to write the words in
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+}
+
+/// The font's characters, split into those that can start a word & combining
+/// marks.
+struct CharacterPools {
+    bases: Vec<char>,
+    marks: Vec<char>,
+}
+
+impl CharacterPools {
+    fn new(
+        reporter: &Reporter,
+        only_script: Option<Script>,
+    ) -> anyhow::Result<Self> {
+        let font = reporter.fontref();
+        let mark_glyphs = match font.gdef() {
+            Ok(gdef) => Some(gdef.glyph_class_def().transpose()?),
+            Err(ReadError::TableIsMissing(_)) => None,
+            Err(why) => bail!("failed to read GDEF: {why}"),
+        };
+        let mut buffer = UnicodeBuffer::new();
+        let mut pools = CharacterPools {
+            bases: Vec::new(),
+            marks: Vec::new(),
+        };
+        font.charmap()
+            .mappings()
+            .filter_map(|(codepoint, glyph_id)| {
+                Some((char::from_u32(codepoint)?, glyph_id))
+            })
+            .filter(|(c, _)| !c.is_control() && !c.is_whitespace())
+            .for_each(|(c, glyph_id)| {
+                // Prefer the font's own idea of what's a mark, as GDEF is what
+                // mark positioning uses
+                let is_mark = match &mark_glyphs {
+                    Some(class_def) => class_def
+                        .as_ref()
+                        .is_some_and(|class_def| class_def.get(glyph_id) == 3),
+                    None => is_combining_mark(c),
+                };
+                buffer.clear();
+                buffer.add(c, 0);
+                buffer.guess_segment_properties();
+                // Characters shared between scripts (including most combining
+                // marks) aren't given one
+                let script = buffer.script();
+                let in_script = only_script.is_none_or(|only| {
+                    script == only || (is_mark && script == script::UNKNOWN)
+                });
+                match (in_script, is_mark) {
+                    (true, true) => pools.marks.push(c),
+                    (true, false) => pools.bases.push(c),
+                    (false, _) => {},
+                }
+            });
+        Ok(pools)
+    }
+
+    fn word(
+        &self,
+        rng: &mut SeededRng,
+        length: &RangeInclusive<usize>,
+        marks: f64,
+    ) -> String {
+        let length = rng.next_in(length);
+        (0..length)
+            .map(|index| {
+                let pool = if index > 0
+                    && !self.marks.is_empty()
+                    && rng.next_f64() < marks
+                {
+                    &self.marks
+                } else {
+                    &self.bases
+                };
+                pool[rng.next_index(pool.len())]
+            })
+            .collect()
+    }
+}
+
+/// Whether `c` is in one of the combining mark blocks.
+const fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// The words in a report that reached beyond the thresholds.
+struct Findings<'r> {
+    location: &'r Location,
+    above: Vec<&'r WordExtremes<'r>>,
+    below: Vec<&'r WordExtremes<'r>>,
+}
+
+impl<'r> Findings<'r> {
+    fn new(report: &'r Report, above: f64, below: f64) -> Self {
+        Findings {
+            location: report.location,
+            above: report
+                .exemplars
+                .highest()
+                .iter()
+                .filter(|exemplar| exemplar.highest() > above)
+                .collect(),
+            below: report
+                .exemplars
+                .lowest()
+                .iter()
+                .filter(|exemplar| exemplar.lowest() < below)
+                .collect(),
+        }
+    }
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    if args.min_length == 0 || args.min_length > args.max_length {
+        bail!("--min-length must be at least 1 and no more than --max-length");
+    }
+    if !(0.0..=1.0).contains(&args.marks) {
+        bail!("--marks must be between 0 and 1");
+    }
+    let only_script = args
+        .script
+        .as_deref()
+        .map(|script| {
+            Script::from_str(script).map_err(|_| {
+                anyhow::anyhow!("{script} isn't an ISO 15924 script code")
+            })
+        })
+        .transpose()?;
+
+    let font_bytes =
+        fs::read(&args.font_path).context("failed to read font file")?;
+    let reporter = Reporter::new(&font_bytes)?;
+    let os2 = reporter.fontref().os2().context("failed to read OS/2")?;
+    let above = args.above.unwrap_or(f64::from(os2.us_win_ascent()));
+    let below = args.below.unwrap_or(-f64::from(os2.us_win_descent()));
+
+    let pools = CharacterPools::new(&reporter, only_script)?;
+    if pools.bases.is_empty() {
+        bail!("the font has no characters to generate words from");
+    }
+    info!(
+        "generating {} words from {} characters & {} marks, with seed {}",
+        args.count,
+        pools.bases.len(),
+        pools.marks.len(),
+        args.seed,
+    );
+    let mut rng = SeededRng::new(args.seed);
+    let length = args.min_length..=args.max_length;
+    let word_list = WordList::define(
+        "fuzz",
+        (0..args.count).map(|_| pools.word(&mut rng, &length, args.marks)),
+    );
+
+    let locations = reporter.interesting_locations();
+    let instances = locations
+        .par_iter()
+        .map(|location| reporter.instance(location))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Only the most extreme words can exceed the thresholds, so there's no
+    // need to keep the rest
+    let reports = instances
+        .iter()
+        .map(|instance| instance.par_check(&word_list, None, args.results))
+        .collect::<Result<Vec<_>, _>>()?;
+    let findings = reports
+        .iter()
+        .map(|report| Findings::new(report, above, below))
+        .collect::<Vec<_>>();
+
+    let mut output = stdout().lock();
+    match args.format {
+        OutputFormat::Human => findings.iter().try_for_each(|findings| {
+            writeln!(&mut output, "{:?}:", findings.location)?;
+            writeln!(&mut output, "  words above {above}:")?;
+            findings.above.iter().try_for_each(|exemplar| {
+                write_word(&mut output, exemplar.word, exemplar.highest())
+            })?;
+            writeln!(&mut output, "  words below {below}:")?;
+            findings.below.iter().try_for_each(|exemplar| {
+                write_word(&mut output, exemplar.word, exemplar.lowest())
+            })
+        }),
+        OutputFormat::Json | OutputFormat::Cbor => {
+            let words_to_json = |words: &[&WordExtremes]| {
+                words
+                    .iter()
+                    .map(|exemplar| {
+                        json::word_extremes_to_json(exemplar, Units::EXACT)
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let locations = findings
+                .iter()
+                .map(|findings| {
+                    json!({
+                        "location": findings.location,
+                        "above": words_to_json(&findings.above),
+                        "below": words_to_json(&findings.below),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let document = json!({
+                "config": {
+                    "seed": args.seed.to_string(),
+                    "count": args.count,
+                    "min_length": args.min_length,
+                    "max_length": args.max_length,
+                    "script": args.script,
+                    "marks": args.marks,
+                    "above": above,
+                    "below": below,
+                },
+                "font": args.font_path.display().to_string(),
+                "locations": locations,
+            });
+            write_document(&mut output, args.format, &document)
+        },
+    }
+    .context("failed to write to output")?;
+
+    let found = findings
+        .iter()
+        .map(|findings| findings.above.len() + findings.below.len())
+        .sum::<usize>();
+    if found > 0 {
+        bail!("found {found} fuzzed words reaching beyond the thresholds");
+    }
+    Ok(())
+}
+
+/// Write a word & its extreme, with its codepoints as the word may not display
+/// well.
+fn write_word(
+    output: &mut impl Write,
+    word: &str,
+    extreme: f64,
+) -> std::io::Result<()> {
+    let codepoints = word
+        .chars()
+        .map(|c| format!("U+{:04X}", c as u32))
+        .collect::<Vec<_>>()
+        .join(" ");
+    writeln!(output, "    \"{word}\" ({codepoints}) => {extreme}")
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{Write, stdout},
+    path::PathBuf,
+};
+
+use anyhow::{Context, bail};
+use fontheight::{Location, Reporter, measure::ControlBounds};
+use serde_json::json;
+use skrifa::{GlyphId, MetadataProvider, raw::TableProvider};
+
+use crate::fmt::{OutputFormat, write_document};
+
+/// Write the vertical bounds of every glyph in a font at a location, for use
+/// in other tools
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The TTF to measure
+    font_path: PathBuf,
+
+    /// The location to measure glyphs at, as comma-separated axis values
+    /// (e.g. wght=700,wdth=75) [default: the default location]
+    #[arg(long, value_parser = parse_location)]
+    location: Option<Location>,
+
+    /// The format to write the bounds in
+    #[arg(long, value_enum, default_value_t)]
+    format: BoundsFormat,
+
+    /// Measure glyphs by their control boxes (see --fast when checking fonts)
+    #[arg(long)]
+    fast: bool,
+
+    /// Write the bounds into the given path.
+    /// Will print to stdout if not specified
+    #[arg(short, long = "output")]
+    output_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+enum BoundsFormat {
+    /// A row per glyph, with a header
+    #[default]
+    Csv,
+    /// JSON
+    Json,
+    /// CBOR, the same structure as JSON
+    Cbor,
+}
+
+/// Parse a location like `wght=700,wdth=75`.
+pub fn parse_location(s: &str) -> anyhow::Result<Location> {
+    let mut location = Location::new();
+    s.split(',')
+        .filter(|axis| !axis.trim().is_empty())
+        .try_for_each(|axis| -> anyhow::Result<()> {
+            let (tag, value) = axis
+                .split_once('=')
+                .context("expected axis values like wght=700,wdth=75")?;
+            let value = value
+                .trim()
+                .parse::<f32>()
+                .with_context(|| format!("invalid value for {tag}"))?;
+            if value.is_nan() {
+                bail!("invalid value for {tag}");
+            }
+            location.axis(tag.trim(), value)?;
+            Ok(())
+        })?;
+    Ok(location)
+}
+
+/// Quote a CSV field, if needed.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let font_bytes =
+        fs::read(&args.font_path).context("failed to read font file")?;
+    let mut reporter = Reporter::new(&font_bytes)?;
+    if args.fast {
+        reporter = reporter.with_glyph_measure(ControlBounds);
+    }
+    let location = args.location.unwrap_or_default();
+    let instance = reporter.instance(&location)?;
+    let instance_extremes = instance.instance_extremes();
+
+    let font = reporter.fontref();
+    let num_glyphs = font.maxp().context("failed to read maxp")?.num_glyphs();
+    let glyph_names = font.glyph_names();
+    // Glyphs without outlines have no bounds
+    let glyphs = (0..u32::from(num_glyphs))
+        .map(|glyph_id| {
+            let name = glyph_names
+                .get(GlyphId::new(glyph_id))
+                .map(|name| name.as_str().to_owned());
+            (glyph_id, name, instance_extremes.get(glyph_id))
+        })
+        .collect::<Vec<_>>();
+
+    let mut output: Box<dyn Write> = match &args.output_path {
+        None => Box::new(stdout().lock()),
+        Some(path) => {
+            let handle = OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .create(true)
+                .open(path)
+                .context("failed to open output file")?;
+            Box::new(handle)
+        },
+    };
+    match args.format {
+        BoundsFormat::Csv => {
+            writeln!(&mut output, "glyph_id,name,y_min,y_max")?;
+            glyphs.iter().try_for_each(|(glyph_id, name, extremes)| {
+                let name = name.as_deref().map(csv_field).unwrap_or_default();
+                match extremes {
+                    Some(extremes) => writeln!(
+                        &mut output,
+                        "{glyph_id},{name},{},{}",
+                        extremes.lowest(),
+                        extremes.highest(),
+                    ),
+                    None => writeln!(&mut output, "{glyph_id},{name},,"),
+                }
+            })
+        },
+        BoundsFormat::Json | BoundsFormat::Cbor => {
+            let format = match args.format {
+                BoundsFormat::Cbor => OutputFormat::Cbor,
+                _ => OutputFormat::Json,
+            };
+            let glyphs = glyphs
+                .iter()
+                .map(|(glyph_id, name, extremes)| {
+                    json!({
+                        "glyph_id": glyph_id,
+                        "name": name,
+                        "y_min": extremes.map(|extremes| extremes.lowest()),
+                        "y_max": extremes.map(|extremes| extremes.highest()),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let document = json!({
+                "font": args.font_path.display().to_string(),
+                "location": location,
+                "glyphs": glyphs,
+            });
+            write_document(&mut output, format, &document)
+        },
+    }
+    .context("failed to write to output")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_locations() {
+        let mut expected = Location::new();
+        expected
+            .axis("wght", 700.0)
+            .unwrap()
+            .axis("wdth", 75.0)
+            .unwrap();
+        assert_eq!(parse_location("wght=700,wdth=75").unwrap(), expected);
+        assert_eq!(parse_location("").unwrap(), Location::new());
+        assert!(parse_location("wght").is_err());
+        assert!(parse_location("wght=bold").is_err());
+    }
+}
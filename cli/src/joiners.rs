@@ -0,0 +1,183 @@
+use std::{collections::BTreeSet, fmt};
+
+use fontheight::{
+    InstanceReporter, JoinerPermutation, Location, Report, VerticalExtremes,
+    WordList, is_joining_script,
+};
+use log::warn;
+use serde_json::{Value, json};
+
+use crate::fmt::{json::word_list_to_json, units::Units, words::WordStyle};
+
+/// An exemplar, and the joining control permutation of it reaching furthest
+/// beyond it.
+#[derive(Debug)]
+pub struct JoinerFinding<'a> {
+    pub word_list: &'a WordList,
+    pub location: &'a Location,
+    pub index: usize,
+    pub word: String,
+    pub extremes: VerticalExtremes,
+    pub permutation: JoinerPermutation,
+}
+
+impl JoinerFinding<'_> {
+    /// How much further than the exemplar the permutation reaches, in font
+    /// units.
+    fn excess(&self) -> f64 {
+        excess(self.extremes, self.permutation.extremes)
+    }
+
+    /// The permuted word, with the control inserted spelt out, unless words
+    /// are written as code points anyway.
+    fn marked_word(&self, words: WordStyle) -> String {
+        if words.code_points() {
+            return self.permutation.word.clone();
+        }
+        let position = self.permutation.position;
+        format!(
+            "{}<{}>{}",
+            &self.word[..position],
+            self.permutation.control,
+            &self.word[position..],
+        )
+    }
+}
+
+fn excess(original: VerticalExtremes, permuted: VerticalExtremes) -> f64 {
+    f64::max(
+        permuted.highest() - original.highest(),
+        original.lowest() - permuted.lowest(),
+    )
+}
+
+/// Insert joining controls into the exemplars of `report`, keeping the
+/// permutation of each exemplar reaching furthest beyond it.
+///
+/// Only word lists in joining scripts are checked, and only their exemplars,
+/// so this is a sample rather than a search of every word.
+pub fn find_joiner_permutations<'a>(
+    instance: &InstanceReporter,
+    report: &Report<'a>,
+) -> anyhow::Result<Vec<JoinerFinding<'a>>> {
+    if !report.word_list.script().is_some_and(is_joining_script) {
+        return Ok(Vec::new());
+    }
+    // Words can be both the highest & lowest exemplars
+    let mut seen = BTreeSet::new();
+    report
+        .exemplars
+        .highest()
+        .iter()
+        .chain(report.exemplars.lowest())
+        .filter(|exemplar| seen.insert(exemplar.index))
+        .filter_map(|exemplar| {
+            let permutations = match instance
+                .joiner_permutations(report.word_list, exemplar.word)
+            {
+                Ok(permutations) => permutations,
+                Err(err) => return Some(Err(err.into())),
+            };
+            let permutation = permutations
+                .into_iter()
+                .filter(|permutation| {
+                    excess(exemplar.extremes, permutation.extremes) > 0.0
+                })
+                .max_by(|a, b| {
+                    excess(exemplar.extremes, a.extremes)
+                        .total_cmp(&excess(exemplar.extremes, b.extremes))
+                })?;
+            Some(Ok(JoinerFinding {
+                word_list: report.word_list,
+                location: report.location,
+                index: exemplar.index,
+                word: exemplar.word.to_owned(),
+                extremes: exemplar.extremes,
+                permutation,
+            }))
+        })
+        .collect()
+}
+
+/// The exemplars reaching further with joining controls inserted.
+#[derive(Debug)]
+pub struct JoinerAnalysis<'a> {
+    /// The findings, furthest reaching first
+    pub findings: Vec<JoinerFinding<'a>>,
+    /// The units to write the extremes in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+impl<'a> JoinerAnalysis<'a> {
+    pub fn new(
+        mut findings: Vec<JoinerFinding<'a>>,
+        units: Units,
+        words: WordStyle,
+    ) -> Self {
+        findings.sort_by(|a, b| b.excess().total_cmp(&a.excess()));
+        if !findings.is_empty() {
+            warn!(
+                "{} exemplars reach further with joining controls inserted",
+                findings.len(),
+            );
+        }
+        JoinerAnalysis {
+            findings,
+            units,
+            words,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let units = self.units;
+        self.findings
+            .iter()
+            .map(|finding| {
+                let permutation = &finding.permutation;
+                json!({
+                    "word_list": word_list_to_json(finding.word_list),
+                    "location": finding.location,
+                    "index": finding.index,
+                    "word": finding.word,
+                    "lowest": units.convert(finding.extremes.lowest()),
+                    "highest": units.convert(finding.extremes.highest()),
+                    "permutation": {
+                        "control": permutation.control.name(),
+                        "position": permutation.position,
+                        "word": permutation.word,
+                        "lowest": units.convert(permutation.extremes.lowest()),
+                        "highest": units.convert(permutation.extremes.highest()),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for JoinerAnalysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = self.units;
+        write!(
+            f,
+            "  joiner permutations: {} exemplars reach further",
+            self.findings.len(),
+        )?;
+        self.findings.iter().try_for_each(|finding| {
+            let permuted = finding.permutation.extremes;
+            write!(
+                f,
+                "\n    {} ({} to {}), from {} ({} to {}) in {} @ {:?}",
+                self.words.display(&finding.marked_word(self.words)),
+                units.convert(permuted.lowest()),
+                units.convert(permuted.highest()),
+                self.words.display(&finding.word),
+                units.convert(finding.extremes.lowest()),
+                units.convert(finding.extremes.highest()),
+                finding.word_list.id(),
+                finding.location,
+            )
+        })
+    }
+}
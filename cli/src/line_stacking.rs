@@ -0,0 +1,185 @@
+use std::{collections::BTreeSet, fmt};
+
+use anyhow::Context;
+use fontheight::{
+    LineBox, LineMetrics, Location, Report, Reporter, WordExtremes, WordList,
+};
+use log::warn;
+use serde_json::{Value, json};
+
+use crate::fmt::{
+    json::{AnalyzedExemplarJson, word_extremes_to_json, word_list_to_json},
+    units::Units,
+    words::WordStyle,
+};
+
+/// Which exemplars would overlap the lines above & below them when lines are
+/// stacked by each set of line metrics.
+#[derive(Debug)]
+pub struct LineStackingAnalysis<'a> {
+    pub line_boxes: Vec<LineBoxOverflow<'a>>,
+    /// The units to write the extremes in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+#[derive(Debug)]
+pub struct LineBoxOverflow<'a> {
+    pub line_box: LineBox,
+    /// Exemplars reaching into the box of the line above
+    pub above: Vec<Intrusion<'a>>,
+    /// Exemplars reaching into the box of the line below
+    pub below: Vec<Intrusion<'a>>,
+}
+
+/// An exemplar reaching into an adjacent line's box.
+#[derive(Debug)]
+pub struct Intrusion<'a> {
+    pub word_list: &'a WordList,
+    pub location: &'a Location,
+    pub word_extremes: WordExtremes<'a>,
+    /// How far into the adjacent line's box the exemplar reaches, in font
+    /// units
+    pub overflow: f64,
+}
+
+/// Check the exemplars of `reports` against the line boxes of the hhea & typo
+/// metrics.
+///
+/// Only the exemplars are checked, so the number of words overlapping
+/// adjacent lines is a lower bound.
+pub fn analyse_line_stacking<'a>(
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<LineStackingAnalysis<'a>> {
+    let line_boxes = LineMetrics::ALL
+        .into_iter()
+        .map(|metrics| {
+            let line_box = reporter.line_box(metrics).with_context(|| {
+                format!("failed to read the {metrics} line metrics")
+            })?;
+            let intrusions = |overflow: fn(&LineBox, _) -> Option<f64>| {
+                reports
+                    .iter()
+                    .flat_map(|report| {
+                        // Words can be both the highest & lowest exemplars
+                        let mut seen = BTreeSet::new();
+                        report
+                            .exemplars
+                            .highest()
+                            .iter()
+                            .chain(report.exemplars.lowest())
+                            .filter(move |word_extremes| {
+                                seen.insert(word_extremes.index)
+                            })
+                            .filter_map(move |&word_extremes| {
+                                Some(Intrusion {
+                                    word_list: report.word_list,
+                                    location: report.location,
+                                    word_extremes,
+                                    overflow: overflow(
+                                        &line_box,
+                                        word_extremes.extremes,
+                                    )?,
+                                })
+                            })
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let above = intrusions(LineBox::into_line_above);
+            let below = intrusions(LineBox::into_line_below);
+            if !above.is_empty() || !below.is_empty() {
+                warn!(
+                    "{} exemplars would overlap adjacent lines stacked by \
+                     {metrics} metrics",
+                    above.len() + below.len(),
+                );
+            }
+            Ok(LineBoxOverflow {
+                line_box,
+                above,
+                below,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(LineStackingAnalysis {
+        line_boxes,
+        units,
+        words,
+    })
+}
+
+impl LineStackingAnalysis<'_> {
+    pub fn to_json(&self) -> Value {
+        let units = self.units;
+        let intrusions_to_json = |intrusions: &[Intrusion]| {
+            intrusions
+                .iter()
+                .map(|intrusion| AnalyzedExemplarJson {
+                    exemplar: word_extremes_to_json(
+                        &intrusion.word_extremes,
+                        units,
+                    ),
+                    details: json!({
+                        "word_list": word_list_to_json(intrusion.word_list),
+                        "location": intrusion.location,
+                        "overflow": units.convert(intrusion.overflow),
+                    }),
+                })
+                .collect::<Vec<_>>()
+        };
+        let line_boxes = self.line_boxes.iter().map(|overflow| {
+            let line_box = overflow.line_box;
+            let json = json!({
+                "ascender": units.convert(line_box.ascender),
+                "descender": units.convert(line_box.descender),
+                "line_gap": units.convert(line_box.line_gap),
+                "line_height": units.convert(line_box.height()),
+                "above": intrusions_to_json(&overflow.above),
+                "below": intrusions_to_json(&overflow.below),
+            });
+            (line_box.metrics.name().to_owned(), json)
+        });
+        Value::Object(line_boxes.collect())
+    }
+}
+
+impl fmt::Display for LineStackingAnalysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = self.units;
+        write!(f, "  line stacking:")?;
+        self.line_boxes.iter().try_for_each(|overflow| {
+            let line_box = overflow.line_box;
+            let extremes = line_box.extremes();
+            write!(
+                f,
+                "\n    {} (line height {}, box {} to {}): {} exemplars reach \
+                 into the line above, {} into the line below",
+                line_box.metrics,
+                units.convert(line_box.height()),
+                units.convert(extremes.lowest()),
+                units.convert(extremes.highest()),
+                overflow.above.len(),
+                overflow.below.len(),
+            )?;
+            [("above", &overflow.above), ("below", &overflow.below)]
+                .into_iter()
+                .try_for_each(|(direction, intrusions)| {
+                    intrusions.iter().try_for_each(|intrusion| {
+                        write!(
+                            f,
+                            "\n      {} ({} into the line {direction}) in {} \
+                             @ {:?}",
+                            self.words.display(intrusion.word_extremes.word),
+                            units.convert(intrusion.overflow),
+                            intrusion.word_list.id(),
+                            intrusion.location,
+                        )
+                    })
+                })
+        })
+    }
+}
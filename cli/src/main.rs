@@ -1,25 +1,76 @@
 #![allow(missing_docs)]
 
 use std::{
+    collections::HashSet,
     fs,
     fs::OpenOptions,
-    io::{Write, stdout},
-    iter,
-    path::PathBuf,
+    io::{self, Write, stdout},
+    iter, mem,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
     process::ExitCode,
-    time::Instant,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::{Context, bail};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use clap_verbosity_flag::Verbosity;
+use clipping::ClippingArg;
 use env_logger::Env;
-use fmt::{FormatReport, OutputFormat};
-use fontheight::Reporter;
-use log::{error, info, warn};
+use fmt::{
+    FormatReport, OutputFormat,
+    html::Theme,
+    json::{
+        CheckJson, ConfigJson, FontErrorJson, FontJson, NotAnalyzedJson,
+        ReportJson,
+    },
+    sarif::SarifLog,
+    units::{UnitArgs, Units},
+    words::{WordArgs, WordStyle},
+};
+use fontheight::{
+    AnalysisOptions, DeclaredLanguage, ExemplarFilter, InstanceExtremes,
+    InstanceReporter, LengthUnit, Location, LongWords, Measurement, Pattern,
+    Provenance, Report, ReportWarning, Reporter, Segmentation, WordFilter,
+    WordLengthLimit, WordList,
+    language_support::CharacterCoverage,
+    locations::{
+        Corners, CornersAndNamed, LocationStrategy, Masters, NamedInstances,
+        RandomSampling,
+    },
+};
+use log::{debug, error, info, warn};
 use rayon::prelude::*;
+use schedule::{Priority, parse_duration};
+use skrifa::{MetadataProvider, Tag, raw::TableProvider};
+use subset::Subset;
+use summary::SummaryFormat;
 
+mod clipping;
+mod compare_metrics;
+mod convert;
+mod diff;
+mod dump;
+mod em_box;
+mod explain;
 mod fmt;
+mod fuzz;
+mod glyph_bounds;
+mod joiners;
+mod line_stacking;
+mod margins;
+mod merge;
+mod optimize;
+mod rng;
+mod scan_system;
+mod schedule;
+mod serve;
+mod subset;
+mod summary;
+mod trend;
+mod wordlists;
 
 fn main() -> ExitCode {
     match _main() {
@@ -38,8 +89,35 @@ type FontheightVerbosity = Verbosity<clap_verbosity_flag::DebugLevel>;
 type FontheightVerbosity = Verbosity<clap_verbosity_flag::InfoLevel>;
 
 #[derive(Debug, Parser)]
-#[command(version, about)]
+#[command(version, about, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    check: CheckArgs,
+
+    #[command(flatten)]
+    verbosity: FontheightVerbosity,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    #[command(name = "wordlists", alias = "wordlist")]
+    WordLists(wordlists::Args),
+    Merge(merge::Args),
+    Convert(convert::Args),
+    Fuzz(fuzz::Args),
+    Diff(diff::Args),
+    GlyphBounds(glyph_bounds::Args),
+    Explain(explain::Args),
+    ScanSystem(scan_system::Args),
+    Serve(serve::Args),
+    Trend(trend::Args),
+}
+
+#[derive(Debug, clap::Args)]
+struct CheckArgs {
     /// The TTF(s) to analyze
     #[arg(required = true)]
     font_path: Vec<PathBuf>,
@@ -52,30 +130,783 @@ struct Args {
     #[arg(short = 'k', long = "words")]
     words_per_list: Option<usize>,
 
-    #[command(flatten)]
-    verbosity: FontheightVerbosity,
-
     /// Write the reports into the given path.
     /// Will print to stdout if not specified
     #[arg(short, long = "output")]
     output_path: Option<PathBuf>,
 
+    /// The format to write the reports in
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
     /// Output all the reports into a single HTML file
-    #[arg(long)]
+    #[arg(long, conflicts_with = "format")]
     html: bool,
+
+    /// Write the problems found as a SARIF log, for CI systems to annotate
+    /// (e.g. GitHub code scanning): the font's warnings & those of its word
+    /// lists, and the words flagged by --clipping, --line-stacking, --em-box,
+    /// --script-margins & --compare-metrics
+    #[arg(long, conflicts_with_all = ["html", "format", "summary"])]
+    sarif: bool,
+
+    /// Only write a line per font, with its highest & lowest words and whether
+    /// they reach beyond the OS/2 win & typo metrics, as text or, with
+    /// --summary=csv, CSV. Useful when scanning many fonts
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "text",
+        conflicts_with_all = ["html", "format"]
+    )]
+    summary: Option<SummaryFormat>,
+
+    /// The colour scheme of the HTML report. Printed reports are always light
+    #[arg(long, value_enum, default_value_t, requires = "html")]
+    theme: Theme,
+
+    /// Only report the most extreme of words that differ only by case or
+    /// punctuation
+    #[arg(long)]
+    distinct: bool,
+
+    /// Only report the most extreme of words that differ only by case or
+    /// punctuation in this word list, by name or ID (e.g. diffenator_latin).
+    /// Can be given more than once, to apply to more word lists than
+    /// --distinct does
+    #[arg(long = "distinct-word-list", value_name = "NAME")]
+    distinct_word_lists: Vec<String>,
+
+    /// How to break up word list entries that are phrases before shaping
+    /// them. Results from words point back to the entry they came from
+    #[arg(long, value_enum, default_value_t)]
+    segmentation: SegmentationArg,
+
+    /// Also check each word wrapped in parentheses, brackets, quotes &
+    /// guillemets, as words are often set beside them (e.g. an Arabic word in
+    /// parentheses). Words found wrapped are reported as they were shaped
+    #[arg(long)]
+    punctuation: bool,
+
+    /// Leave out words matching a regular expression, e.g. '^[\p{N}\p{P}]+$'
+    /// for numbers & stray punctuation or 'https?://' for URLs. Uses the
+    /// syntax of Rust's regex crate, with \p{..} for Unicode general
+    /// categories (e.g. \p{S} for symbols). Can be repeated
+    #[arg(long = "exclude-pattern", value_name = "PATTERN")]
+    exclude_patterns: Vec<Pattern>,
+
+    /// Leave out words longer than LENGTH, which are slow to shape and rarely
+    /// matter (e.g. URLs & strings run together in scraped word lists). Use
+    /// --long-words to truncate or chunk them instead
+    #[arg(long, value_name = "LENGTH")]
+    word_length_limit: Option<usize>,
+
+    /// What --word-length-limit counts the length of words in
+    #[arg(long, value_enum, default_value_t, requires = "word_length_limit")]
+    word_length_unit: LengthUnitArg,
+
+    /// What to do with words longer than --word-length-limit
+    #[arg(long, value_enum, default_value_t, requires = "word_length_limit")]
+    long_words: LongWordsArg,
+
+    /// Keep words whose ink is less than this many font units tall out of the
+    /// exemplars, e.g. words made up only of dashes or dots that would
+    /// otherwise crowd the lowest words. Filtered words are still checked
+    #[arg(long, value_name = "UNITS")]
+    min_ink_height: Option<f64>,
+
+    /// Keep words shaped to fewer than this many glyphs out of the exemplars.
+    /// Filtered words are still checked
+    #[arg(long, value_name = "GLYPHS")]
+    min_glyphs: Option<usize>,
+
+    /// Additional word list(s) to check, one word per line.
+    /// Metadata is read from a TOML file with the same name, if present
+    #[arg(long = "word-list", value_name = "WORD_LIST_PATH")]
+    word_list_paths: Vec<PathBuf>,
+
+    /// Search the design space for where the tallest & lowest words of each
+    /// word list reach furthest, rather than only checking the interesting
+    /// locations
+    #[arg(long, conflicts_with = "html")]
+    optimize_locations: bool,
+
+    /// Only check the INDEX-th of COUNT equal parts of each word list, so runs
+    /// can be split across machines (e.g. 2/8). -k/--words applies to each
+    /// part
+    #[arg(long, value_name = "INDEX/COUNT")]
+    shard: Option<Shard>,
+
+    /// Only check words made up entirely of the code points a font is subset
+    /// to (e.g. by a webfont pipeline), as a CSS unicode-range (e.g.
+    /// "U+0000-00FF, U+0131, U+4??") or a list of code points (e.g. "0041
+    /// 00E9"). Word lists with no such words aren't checked
+    #[arg(long, value_name = "UNICODE_RANGE")]
+    subset: Option<Subset>,
+
+    /// Check word lists matching these first, in the order given (e.g.
+    /// script=Thai,script=Arab,language=fa,word-list=diffenator_latin), so
+    /// they're done before --deadline. Other word lists are checked after.
+    /// With --max-locations, this orders the checks of each group of
+    /// locations
+    #[arg(long, value_name = "PRIORITIES", value_delimiter = ',')]
+    prioritize: Vec<Priority>,
+
+    /// Stop starting checks of word lists once this long has passed since the
+    /// run began (e.g. 90s, 10m, 1.5h), reporting the word lists & locations
+    /// left as not analyzed. Checks already started are finished
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    deadline: Option<Duration>,
+
+    /// Check all built-in word lists, not just those written in scripts the
+    /// font supports
+    #[arg(long)]
+    all_wordlists: bool,
+
+    /// Choose word lists by the scripts in the font's cmap, even if the font
+    /// declares the languages it's designed for or supports in its meta
+    /// table
+    #[arg(long, conflicts_with = "all_wordlists")]
+    ignore_meta: bool,
+
+    /// Include the extremes reached by each grapheme cluster of the reported
+    /// words in JSON reports
+    #[arg(long)]
+    clusters: bool,
+
+    /// Measure words by their glyphs' control boxes, which is faster but may
+    /// overestimate extremes (they're never underestimated). Useful for a
+    /// quick check in CI, re-checking without --fast if anything looks too
+    /// tall. Implies --on-demand-glyphs
+    #[arg(long)]
+    fast: bool,
+
+    /// Only draw the glyphs words shape to, rather than every glyph at each
+    /// location. Saves memory for fonts with many glyphs (e.g. CJK fonts)
+    /// checked with word lists that only use a few of them
+    #[arg(long)]
+    on_demand_glyphs: bool,
+
+    /// Which locations of variable fonts to check. --masters &
+    /// --random-locations add to these
+    #[arg(long, value_enum, default_value_t)]
+    location_strategy: LocationStrategyArg,
+
+    /// Also check the locations of the font's masters, approximated from the
+    /// peaks of its glyph variations (gvar)
+    #[arg(long)]
+    masters: bool,
+
+    /// Also check COUNT locations sampled uniformly in the font's normalized
+    /// design space (after avar), generated from SEED [default: 0] so runs
+    /// can be reproduced (e.g. 50:7)
+    #[arg(long, value_name = "COUNT[:SEED]")]
+    random_locations: Option<RandomLocations>,
+
+    #[command(flatten)]
+    units: UnitArgs,
+
+    #[command(flatten)]
+    words: WordArgs,
+
+    /// Count how high & low all the words checked reached, in bins of this
+    /// many font units, shown as a histogram in each report
+    #[arg(long, value_name = "BIN_SIZE", value_parser = parse_bin_size)]
+    histogram: Option<f64>,
+
+    /// Font(s) to fall back to for characters missing from the font being
+    /// checked. Words with characters only the fallback fonts support are
+    /// measured without them, rather than skipped
+    #[arg(long = "fallback-font", value_name = "FALLBACK_FONT_PATH")]
+    fallback_font_paths: Vec<PathBuf>,
+
+    /// Measure words with glyphs missing from the font by the glyphs they do
+    /// have, rather than skipping them. Such words are marked as partial, as
+    /// their extremes may change once the font is finished
+    #[arg(long)]
+    partial_words: bool,
+
+    /// Summarise, for each script, the margin between its most extreme words
+    /// and OS/2.sTypoAscender/sTypoDescender
+    #[arg(long, conflicts_with = "html")]
+    script_margins: bool,
+
+    /// Report which exemplars would be clipped by each platform's clipping
+    /// metrics (e.g. windows,css)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "PROFILES",
+        value_delimiter = ',',
+        conflicts_with = "html"
+    )]
+    clipping: Vec<ClippingArg>,
+
+    /// Insert zero width (non-)joiners & soft hyphens into the exemplars of
+    /// word lists in joining scripts (e.g. Arabic, Devanagari), reporting
+    /// those reaching further with one inserted
+    #[arg(long, conflicts_with = "html")]
+    joiner_permutations: bool,
+
+    /// Report which exemplars would overlap the lines above & below them when
+    /// lines are stacked by the hhea or OS/2 typo metrics (ascender,
+    /// descender & line gap)
+    #[arg(long, conflicts_with = "html")]
+    line_stacking: bool,
+
+    /// Report which exemplars of CJK word lists reach beyond the font's
+    /// ideographic em-box at each location, reading the em-box from the BASE
+    /// table's ideo & idtp baselines, the typo metrics if they span one UPM,
+    /// or else the conventional 880 & -120 per 1000 units
+    #[arg(long, conflicts_with = "html")]
+    em_box: bool,
+
+    /// Compare the OS/2 win & typo metrics and the hhea ascender & descender
+    /// with the most extreme words, giving each a PASS/FAIL verdict; exits
+    /// with an error if any fail
+    #[arg(long)]
+    compare_metrics: bool,
+
+    /// Flag scripts with a margin smaller than this percentage of the UPM
+    /// (scripts reaching beyond the ascender/descender are always flagged)
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 0.0,
+        requires = "script_margins"
+    )]
+    min_margin: f64,
+
+    /// The maximum number of locations to check at once [default: all].
+    /// Each location caches the extremes of every glyph, so limit this to
+    /// reduce memory use when checking large fonts with many locations
+    #[arg(long, value_name = "COUNT")]
+    max_locations: Option<NonZeroUsize>,
+
+    /// Shape each word once and re-use its glyphs at every location, for
+    /// fonts that shape the same way at all locations. Speeds up checking
+    /// many locations, but holds the glyphs of every word checked in memory
+    #[arg(long)]
+    shaping_cache: bool,
+
+    /// Judge whether the font supports the language of each word list, by
+    /// whether it has the characters of at least 99% of its words, noting
+    /// partially supported & unsupported word lists in the reports (and
+    /// dimming them in HTML reports)
+    #[arg(long)]
+    language_support: bool,
+
+    /// Carry on checking the other fonts if one fails, summarising the
+    /// failures at the end
+    #[arg(long)]
+    keep_going: bool,
+
+    /// Also write how high & low every word checked reaches into this file,
+    /// as CSV (.csv) or JSON Lines (.jsonl), gzip compressed if it ends with
+    /// .gz (e.g. words.csv.gz). Words are written as they're measured, so
+    /// entire word lists can be exported without holding them in memory
+    #[arg(long, value_name = "DUMP_PATH")]
+    dump: Option<PathBuf>,
+
+    /// Append a compact summary of this run (how high & low each script
+    /// reaches, and how many exemplars each platform clips, per font) to this
+    /// JSON Lines file, for `fontheight trend` to compare runs
+    #[arg(long, value_name = "HISTORY_PATH")]
+    append_history: Option<PathBuf>,
 }
 
-fn _main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    if args.font_path.len() > 1 && args.html {
-        bail!("you can't pass multiple fonts if using --html");
+/// The outcome of checking a word list at a location.
+// Short-lived, so not worth boxing the report
+#[allow(clippy::large_enum_variant)]
+enum Checked<'a> {
+    /// The report, its cluster extremes (if requested) & joiner permutations
+    Analyzed(
+        Report<'a>,
+        Option<ReportJson>,
+        Vec<joiners::JoinerFinding<'a>>,
+    ),
+    /// The check wasn't started before the deadline
+    NotAnalyzed(&'a WordList, &'a Location),
+}
+
+/// The part of each word list to check, 1-indexed.
+#[derive(Debug, Copy, Clone)]
+struct Shard {
+    index: usize,
+    count: usize,
+}
+
+impl Shard {
+    fn of(self, word_list: &WordList) -> WordList {
+        word_list.chunk(self.index - 1, self.count)
+    }
+}
+
+impl std::fmt::Display for Shard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+impl FromStr for Shard {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, count) = s
+            .split_once('/')
+            .context("expected INDEX/COUNT, e.g. 2/8")?;
+        let index = index.parse::<usize>().context("invalid shard index")?;
+        let count = count.parse::<usize>().context("invalid shard count")?;
+        if index == 0 || index > count {
+            bail!("shard index must be between 1 and {count}");
+        }
+        Ok(Shard { index, count })
+    }
+}
+
+/// How to break up word list entries with `--segmentation`.
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+enum SegmentationArg {
+    /// Shape each entry whole
+    #[default]
+    Whole,
+    /// Shape each word of each entry on its own
+    Words,
+    /// Shape each entry whole, and each of its words on its own
+    Both,
+}
+
+impl From<SegmentationArg> for Segmentation {
+    fn from(arg: SegmentationArg) -> Self {
+        match arg {
+            SegmentationArg::Whole => Segmentation::Whole,
+            SegmentationArg::Words => Segmentation::Words,
+            SegmentationArg::Both => Segmentation::Both,
+        }
+    }
+}
+
+/// What `--word-length-limit` counts.
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+enum LengthUnitArg {
+    /// Characters (Unicode scalar values)
+    #[default]
+    Chars,
+    /// Grapheme clusters, e.g. a letter & its combining marks
+    Clusters,
+}
+
+impl LengthUnitArg {
+    const fn name(self) -> &'static str {
+        match self {
+            LengthUnitArg::Chars => "chars",
+            LengthUnitArg::Clusters => "clusters",
+        }
     }
+}
+
+impl From<LengthUnitArg> for LengthUnit {
+    fn from(arg: LengthUnitArg) -> Self {
+        match arg {
+            LengthUnitArg::Chars => LengthUnit::Chars,
+            LengthUnitArg::Clusters => LengthUnit::Clusters,
+        }
+    }
+}
+
+/// What to do with words over `--word-length-limit`.
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+enum LongWordsArg {
+    /// Leave them out
+    #[default]
+    Skip,
+    /// Only check their beginning
+    Truncate,
+    /// Check each piece of them up to the limit as a word of its own
+    Chunk,
+}
+
+impl LongWordsArg {
+    const fn name(self) -> &'static str {
+        match self {
+            LongWordsArg::Skip => "skip",
+            LongWordsArg::Truncate => "truncate",
+            LongWordsArg::Chunk => "chunk",
+        }
+    }
+}
+
+impl From<LongWordsArg> for LongWords {
+    fn from(arg: LongWordsArg) -> Self {
+        match arg {
+            LongWordsArg::Skip => LongWords::Skip,
+            LongWordsArg::Truncate => LongWords::Truncate,
+            LongWordsArg::Chunk => LongWords::Chunk,
+        }
+    }
+}
+
+/// Which locations to check with `--location-strategy`.
+#[derive(Debug, Copy, Clone, Default, clap::ValueEnum)]
+enum LocationStrategyArg {
+    /// Every combination of the coordinates of named instances, axis
+    /// extremes & feature variations
+    #[default]
+    CornersAndNamed,
+    /// Every combination of each axis' minimum, default & maximum
+    Corners,
+    /// The default location & named instances
+    Named,
+    /// The default location & the font's masters, as with --masters
+    Masters,
+    /// Only the locations sampled with --random-locations
+    Random,
+}
+
+impl LocationStrategyArg {
+    const fn name(self) -> &'static str {
+        match self {
+            LocationStrategyArg::CornersAndNamed => "corners-and-named",
+            LocationStrategyArg::Corners => "corners",
+            LocationStrategyArg::Named => "named",
+            LocationStrategyArg::Masters => "masters",
+            LocationStrategyArg::Random => "random",
+        }
+    }
+
+    fn strategy(
+        self,
+        random_locations: Option<RandomLocations>,
+    ) -> anyhow::Result<Box<dyn LocationStrategy + Sync>> {
+        Ok(match self {
+            LocationStrategyArg::CornersAndNamed => Box::new(CornersAndNamed),
+            LocationStrategyArg::Corners => Box::new(Corners),
+            LocationStrategyArg::Named => Box::new(NamedInstances),
+            LocationStrategyArg::Masters => Box::new(Masters),
+            LocationStrategyArg::Random => Box::new(
+                random_locations
+                    .context(
+                        "--location-strategy random needs --random-locations",
+                    )?
+                    .sampling(),
+            ),
+        })
+    }
+}
+
+/// How many random locations to check, and the seed to generate them from.
+#[derive(Debug, Copy, Clone)]
+struct RandomLocations {
+    count: usize,
+    seed: u64,
+}
+
+impl RandomLocations {
+    const fn sampling(self) -> RandomSampling {
+        RandomSampling {
+            count: self.count,
+            seed: self.seed,
+        }
+    }
+}
+
+impl std::fmt::Display for RandomLocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.count, self.seed)
+    }
+}
+
+impl FromStr for RandomLocations {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (count, seed) = s.split_once(':').unwrap_or((s, "0"));
+        Ok(RandomLocations {
+            count: count
+                .parse()
+                .context("invalid number of random locations")?,
+            seed: seed.parse().context("invalid random location seed")?,
+        })
+    }
+}
+
+/// When reports are made: the time given by `SOURCE_DATE_EPOCH`, for
+/// reproducible builds, or now.
+fn generated_at() -> anyhow::Result<SystemTime> {
+    match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => {
+            let secs = epoch
+                .trim()
+                .parse()
+                .context("SOURCE_DATE_EPOCH isn't a number of seconds")?;
+            Ok(UNIX_EPOCH + Duration::from_secs(secs))
+        },
+        Err(_) => Ok(SystemTime::now()),
+    }
+}
+
+/// Identifies the font a JSON report was made from, so reports from different
+/// runs can be safely merged.
+fn font_checksum(font_bytes: &[u8]) -> String {
+    format!("{:08x}", crc32fast::hash(font_bytes))
+}
+
+/// The options that affect the results of a check, recorded in JSON reports
+/// so reports from different runs can be safely merged.
+fn config_to_json(args: &CheckArgs) -> ConfigJson {
+    let paths = |paths: &[PathBuf]| {
+        paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect()
+    };
+    ConfigJson {
+        results: args.results,
+        words: args.words_per_list,
+        distinct: args.distinct,
+        distinct_word_lists: args.distinct_word_lists.clone(),
+        segmentation: Segmentation::from(args.segmentation).name().to_owned(),
+        punctuation: args.punctuation,
+        exclude_patterns: args
+            .exclude_patterns
+            .iter()
+            .map(|pattern| pattern.as_str().to_owned())
+            .collect(),
+        word_length_limit: args.word_length_limit,
+        word_length_unit: args.word_length_unit.name().to_owned(),
+        long_words: args.long_words.name().to_owned(),
+        min_ink_height: args.min_ink_height,
+        min_glyphs: args.min_glyphs,
+        all_wordlists: args.all_wordlists,
+        ignore_meta: args.ignore_meta,
+        optimize_locations: args.optimize_locations,
+        word_lists: paths(&args.word_list_paths),
+        shard: args.shard.map(|shard| shard.to_string()),
+        subset: args.subset.as_ref().map(|subset| subset.to_string()),
+        prioritize: args
+            .prioritize
+            .iter()
+            .map(|priority| priority.to_string())
+            .collect(),
+        deadline: args.deadline.map(|deadline| deadline.as_secs_f64()),
+        clusters: args.clusters,
+        fast: args.fast,
+        location_strategy: args.location_strategy.name().to_owned(),
+        masters: args.masters,
+        random_locations: args
+            .random_locations
+            .map(|random| random.to_string()),
+        fallback_fonts: paths(&args.fallback_font_paths),
+        partial_words: args.partial_words,
+        histogram: args.histogram,
+        script_margins: args.script_margins,
+        min_margin: args.min_margin,
+        clipping: ClippingArg::profiles(&args.clipping)
+            .into_iter()
+            .map(|profile| profile.name().to_owned())
+            .collect(),
+        joiner_permutations: args.joiner_permutations,
+        line_stacking: args.line_stacking,
+        em_box: args.em_box,
+        compare_metrics: args.compare_metrics,
+        units: args.units.to_json(),
+    }
+}
+
+fn parse_bin_size(s: &str) -> anyhow::Result<f64> {
+    let bin_size = s.parse::<f64>()?;
+    if !(bin_size > 0.0 && bin_size.is_finite()) {
+        bail!("the bin size must be a positive number");
+    }
+    Ok(bin_size)
+}
+
+/// Format a number of bytes for logging.
+fn format_bytes(bytes: usize) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn load_word_list(path: &Path) -> anyhow::Result<WordList> {
+    let metadata_path = path.with_extension("toml");
+    let word_list = if metadata_path.is_file() {
+        WordList::load(path, &metadata_path)
+    } else {
+        WordList::load_without_metadata(path)
+    }
+    .with_context(|| format!("failed to load {}", path.display()))?;
+
+    if let Some(builtin) = word_list.shadowed_builtin() {
+        warn!(
+            "{} has the same name as the built-in word list {}, it will be \
+             reported as {}",
+            path.display(),
+            builtin.id(),
+            word_list.id(),
+        );
+    }
+    Ok(word_list)
+}
+
+/// Whether `name`, a word list's name or ID as given on the command line,
+/// refers to the word list with `word_list_name` & `word_list_id`, or a view
+/// of it (e.g. a shard, named `latin[2/8]`).
+fn names_word_list(
+    name: &str,
+    word_list_name: &str,
+    word_list_id: &str,
+) -> bool {
+    [word_list_name, word_list_id].iter().any(|candidate| {
+        candidate
+            .strip_prefix(name)
+            .is_some_and(|view| view.is_empty() || view.starts_with('['))
+    })
+}
+
+fn _main() -> anyhow::Result<()> {
+    let Args {
+        command,
+        check: args,
+        verbosity,
+    } = Args::parse();
 
     env_logger::builder()
-        .filter_level(args.verbosity.into())
+        .filter_level(verbosity.into())
         .parse_env(Env::new().filter("FONTHEIGHT_LOG"))
         .init();
 
+    match command {
+        Some(Command::WordLists(args)) => wordlists::main(args),
+        Some(Command::Merge(args)) => merge::main(args),
+        Some(Command::Convert(args)) => convert::main(args),
+        Some(Command::Fuzz(args)) => fuzz::main(args),
+        Some(Command::Diff(args)) => diff::main(args),
+        Some(Command::GlyphBounds(args)) => glyph_bounds::main(args),
+        Some(Command::Explain(args)) => explain::main(args),
+        Some(Command::ScanSystem(args)) => scan_system::main(args),
+        Some(Command::Serve(args)) => serve::main(args),
+        Some(Command::Trend(args)) => trend::main(args),
+        None => check(args),
+    }
+}
+
+/// What's shared between checking each of the fonts of a run.
+struct CheckContext<'a> {
+    args: &'a CheckArgs,
+    location_strategy: Box<dyn LocationStrategy + Sync>,
+    user_word_lists: Vec<WordList>,
+    fallback_fonts: Vec<&'a [u8]>,
+    dump: Option<dump::WordDump>,
+    options: AnalysisOptions,
+    // For the word lists given with --distinct-word-list
+    distinct_options: AnalysisOptions,
+    deadline: Option<Instant>,
+    // Whether the cluster extremes of reports are written
+    with_clusters: bool,
+}
+
+impl CheckContext<'_> {
+    fn past_deadline(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// The options to check `word_list` with.
+    fn options(&self, word_list: &WordList) -> &AnalysisOptions {
+        let distinct = self.args.distinct_word_lists.iter().any(|name| {
+            names_word_list(name, word_list.name(), &word_list.id().to_string())
+        });
+        if distinct {
+            &self.distinct_options
+        } else {
+            &self.options
+        }
+    }
+}
+
+/// What's written as fonts are checked, and collected to be written once
+/// they all are.
+struct CheckRun {
+    output: Box<dyn Write>,
+    provenance: Provenance,
+    fonts_json: Vec<FontJson>,
+    sarif: SarifLog,
+    history: Vec<trend::FontSummary>,
+    metric_failures: usize,
+}
+
+/// The reports of a font's word lists at each of its locations, and what else
+/// was found while its instances were around.
+struct FontChecks<'a> {
+    reports: Vec<Report<'a>>,
+    // With --clusters, the reports with their clusters' extremes
+    clusters_json: Vec<ReportJson>,
+    joiner_findings: Vec<joiners::JoinerFinding<'a>>,
+    // Past the deadline
+    not_analyzed: Vec<(&'a WordList, &'a Location)>,
+    // Kept with --html for drawing exemplars, so their glyphs aren't drawn
+    // again
+    glyph_bounds: Vec<(Location, Arc<InstanceExtremes>)>,
+}
+
+/// The analyses made of a font's reports, each only if asked for.
+struct FontAnalyses<'a> {
+    optimized: Vec<optimize::OptimizedExemplars<'a>>,
+    margins: Option<margins::MarginAnalysis<'a>>,
+    clipping: Option<clipping::ClippingAnalysis<'a>>,
+    line_stacking: Option<line_stacking::LineStackingAnalysis<'a>>,
+    em_box: Option<em_box::EmBoxAnalysis<'a>>,
+    comparison: Option<compare_metrics::MetricComparison<'a>>,
+    joiners: Option<joiners::JoinerAnalysis<'a>>,
+}
+
+/// A font that's been checked, with everything to write about it.
+struct CheckedFont<'a> {
+    path: &'a Path,
+    checksum: String,
+    reporter: &'a Reporter<'a>,
+    warnings: Vec<ReportWarning>,
+    // Each word list with a language, and the language system shaping can
+    // use for it
+    language_systems: Vec<(String, Option<Tag>)>,
+    checks: &'a FontChecks<'a>,
+    units: Units,
+    words: WordStyle,
+}
+
+fn check(args: CheckArgs) -> anyhow::Result<()> {
+    if args.font_path.len() > 1 && args.html {
+        bail!("you can't pass multiple fonts if using --html");
+    }
+    if args.clusters && matches!(args.format, OutputFormat::Human) {
+        bail!("--clusters is only supported with --format json or cbor");
+    }
+    let location_strategy =
+        args.location_strategy.strategy(args.random_locations)?;
+    if args.fast {
+        info!(
+            "measuring glyphs by their control boxes, extremes may be \
+             overestimated"
+        );
+    }
+
+    let user_word_lists = args
+        .word_list_paths
+        .iter()
+        .map(|path| load_word_list(path))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let fallback_fonts = args
+        .fallback_font_paths
+        .iter()
+        .map(|path| {
+            fs::read(path).with_context(|| {
+                format!("failed to read fallback font {}", path.display())
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
     let mut output: Box<dyn Write> = match &args.output_path {
         None => Box::new(stdout().lock()),
         Some(path) => {
@@ -89,91 +920,891 @@ fn _main() -> anyhow::Result<()> {
         },
     };
 
-    args.font_path
-        .iter()
-        .try_for_each(|font_path| -> anyhow::Result<()> {
-            let font_bytes =
-                fs::read(font_path).context("failed to read font file")?;
+    if let Some(summary) = args.summary {
+        summary
+            .write_header(&mut output)
+            .context("failed to write to output")?;
+    }
+
+    let options = analysis_options(&args);
+    let context = CheckContext {
+        args: &args,
+        location_strategy,
+        user_word_lists,
+        fallback_fonts: fallback_fonts.iter().map(Vec::as_slice).collect(),
+        dump: args
+            .dump
+            .as_deref()
+            .map(dump::WordDump::create)
+            .transpose()?,
+        distinct_options: options.clone().with_distinct(true),
+        deadline: args.deadline.map(|deadline| Instant::now() + deadline),
+        with_clusters: args.clusters
+            && !args.html
+            && matches!(args.format, OutputFormat::Json | OutputFormat::Cbor),
+        options,
+    };
+    let mut run = CheckRun {
+        output,
+        provenance: Provenance::new(context.options.clone())
+            .with_generated_at(generated_at()?),
+        fonts_json: Vec::new(),
+        sarif: SarifLog::default(),
+        history: Vec::new(),
+        metric_failures: 0,
+    };
+
+    let mut failures = Vec::new();
+    for font_path in &args.font_path {
+        match check_font(&context, font_path, &mut run) {
+            Err(why) if args.keep_going => {
+                error!("failed to check {}: {why}", font_path.display());
+                failures.push((font_path, why));
+            },
+            checked => checked?,
+        }
+    }
+
+    if let Some(dump) = context.dump {
+        dump.finish()?;
+    }
+
+    if let Some(history_path) = &args.append_history {
+        trend::append_history(history_path, run.history)?;
+    }
 
-            let start = Instant::now();
-            let reporter = Reporter::new(&font_bytes)?;
-            let locations = reporter.interesting_locations();
+    if let OutputFormat::Json | OutputFormat::Cbor = args.format {
+        let document = CheckJson {
+            provenance: Some(fmt::json::provenance_to_json(&run.provenance)),
+            config: config_to_json(&args),
+            fonts: run.fonts_json,
+            errors: failures
+                .iter()
+                .map(|(font_path, why)| FontErrorJson {
+                    font: font_path.display().to_string(),
+                    error: why.to_string(),
+                })
+                .collect(),
+        };
+        fmt::write_document(&mut run.output, args.format, &document)
+            .context("failed to write to output")?;
+    }
+    if args.sarif {
+        failures.iter().for_each(|(font_path, why)| {
+            let font = run.sarif.add_font(font_path);
+            run.sarif.add_failure(font, why);
+        });
+        writeln!(&mut run.output, "{:#}", run.sarif.to_json())
+            .context("failed to write to output")?;
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "failed to check {} of {} fonts: {}",
+            failures.len(),
+            args.font_path.len(),
+            failures
+                .iter()
+                .map(|(font_path, _)| font_path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+    if run.metric_failures > 0 {
+        bail!(
+            "{} metrics were reached beyond by the words checked",
+            run.metric_failures,
+        );
+    }
+    Ok(())
+}
+
+/// The options to check each word list with, unless it's given with
+/// --distinct-word-list.
+fn analysis_options(args: &CheckArgs) -> AnalysisOptions {
+    args.exclude_patterns.iter().fold(
+        AnalysisOptions::new()
+            .with_words_limit(args.words_per_list)
+            .with_exemplars(args.results)
+            .with_distinct(args.distinct)
+            .with_segmentation(args.segmentation.into())
+            .with_punctuation(args.punctuation)
+            .with_partial_words(args.partial_words)
+            .with_measurement(if args.fast {
+                Measurement::ControlBoxes
+            } else {
+                Measurement::Instance
+            })
+            .with_word_length_limit(args.word_length_limit.map(|max| {
+                WordLengthLimit::new(max)
+                    .with_unit(args.word_length_unit.into())
+                    .with_long_words(args.long_words.into())
+            }))
+            .with_exemplar_filter(
+                (args.min_ink_height.is_some() || args.min_glyphs.is_some())
+                    .then(|| {
+                        ExemplarFilter::new()
+                            .with_min_ink_height(
+                                args.min_ink_height.unwrap_or_default(),
+                            )
+                            .with_min_glyphs(
+                                args.min_glyphs.unwrap_or_default(),
+                            )
+                    }),
+            ),
+        |options, pattern| {
+            options.with_exclusion(WordFilter::Pattern(pattern.clone()))
+        },
+    )
+}
+
+/// Check a font with every word list chosen for it, at every location chosen,
+/// then analyse & write its reports.
+fn check_font(
+    context: &CheckContext,
+    font_path: &Path,
+    run: &mut CheckRun,
+) -> anyhow::Result<()> {
+    let args = context.args;
+    let font_bytes = fs::read(font_path).context("failed to read font file")?;
+
+    let start = Instant::now();
+    // Words are measured by control boxes with --fast, so exact bounds are
+    // only drawn for anything else that needs them
+    let mut reporter = Reporter::new(&font_bytes)?
+        .with_on_demand_glyphs(args.on_demand_glyphs || args.fast);
+    if let Some(bin_size) = args.histogram {
+        reporter = reporter.with_histogram(bin_size);
+    }
+    reporter = reporter
+        .with_fallback_fonts(&context.fallback_fonts)
+        .context("failed to parse fallback fonts")?;
+    if args.language_support {
+        reporter = reporter.with_language_support(CharacterCoverage);
+    }
+    if args.shaping_cache {
+        reporter = reporter.with_shaping_cache(true);
+        if !reporter.caches_shaping() {
             info!(
-                "Found {} interesting locations in {}",
-                locations.len(),
+                "not caching shaping for {}, its shaping varies between \
+                 locations",
                 font_path.display(),
             );
+        }
+    }
+    let units = args.units.for_font(
+        reporter
+            .fontref()
+            .head()
+            .context("failed to read head")?
+            .units_per_em(),
+    );
+    let words = args.words.style();
 
-            let instances = locations
-                .par_iter()
-                .map(|location| reporter.instance(location))
-                .collect::<Result<Vec<_>, _>>()?;
-
-            if instances.len() >= 100 && args.words_per_list.is_none() {
-                warn!(
-                    "Testing {} instances with all words is probably going to \
-                     take a while. Consider passing -k/--words to limit the \
-                     number of words being checked",
-                    instances.len()
-                );
-            }
+    let (declared_languages, word_lists) = select_word_lists(
+        args,
+        &reporter,
+        font_path,
+        &context.user_word_lists,
+    )?;
+    let shards = args.shard.map(|shard| {
+        word_lists
+            .iter()
+            .map(|word_list| shard.of(word_list))
+            .collect::<Vec<_>>()
+    });
+    let word_lists = match &shards {
+        Some(shards) => shards.iter().collect(),
+        None => word_lists,
+    };
+    let subsets = args.subset.as_ref().map(|subset| {
+        let subsets = word_lists
+            .iter()
+            .map(|word_list| word_list.filter(|word| subset.encodes(word)))
+            .filter(|word_list| !word_list.is_empty())
+            .collect::<Vec<_>>();
+        info!(
+            "subset encodes {} of {} words, from {} of {} word lists",
+            subsets.iter().map(WordList::len).sum::<usize>(),
+            word_lists
+                .iter()
+                .map(|word_list| word_list.len())
+                .sum::<usize>(),
+            subsets.len(),
+            word_lists.len(),
+        );
+        subsets
+    });
+    let mut word_lists = match &subsets {
+        Some(subsets) => subsets.iter().collect(),
+        None => word_lists,
+    };
+    // Stable, so word lists of the same priority keep their order
+    word_lists
+        .sort_by_key(|word_list| Priority::rank(&args.prioritize, word_list));
+    // Only hashed when the reports written record them
+    if args.html
+        || matches!(args.format, OutputFormat::Json | OutputFormat::Cbor)
+    {
+        word_lists
+            .iter()
+            .for_each(|word_list| run.provenance.add_word_list(word_list));
+    }
+    if !declared_languages.is_empty() {
+        info!(
+            "{} declares {} in meta, checking {} word lists",
+            font_path.display(),
+            declared_languages
+                .iter()
+                .map(DeclaredLanguage::tag)
+                .collect::<Vec<_>>()
+                .join(", "),
+            word_lists.len(),
+        );
+    } else if !args.all_wordlists {
+        info!(
+            "{} supports {}, checking {} word lists",
+            font_path.display(),
+            reporter
+                .supported_scripts()
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", "),
+            word_lists.len(),
+        );
+    }
+
+    // Which OpenType language systems shaping can use for each word list with
+    // a language, rather than falling back to the default
+    let language_systems = word_lists
+        .iter()
+        .filter(|word_list| word_list.language().is_some())
+        .map(|word_list| {
+            let language_system = reporter
+                .language_system(word_list)
+                .context("failed to read language systems")?;
+            Ok((word_list.id().to_string(), language_system))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    info!(
+        "{} has language systems for {} of {} word lists with a language",
+        font_path.display(),
+        language_systems
+            .iter()
+            .filter(|(_, language_system)| language_system.is_some())
+            .count(),
+        language_systems.len(),
+    );
+    language_systems.iter().for_each(|(id, language_system)| {
+        debug!("{id}: language system {language_system:?}");
+    });
+
+    let font_warnings = reporter.warnings();
+    font_warnings.iter().for_each(|warning| match warning {
+        ReportWarning::NoNamedInstances
+            if !args.masters && args.random_locations.is_none() =>
+        {
+            warn!(
+                "{}: {warning}; try --masters or --random-locations to check \
+                 designs between them",
+                font_path.display(),
+            );
+        },
+        _ => warn!("{}: {warning}", font_path.display()),
+    });
+
+    let locations = select_locations(context, &reporter, font_path)?;
+    let mut checks = check_locations(
+        context,
+        &reporter,
+        font_path,
+        &word_lists,
+        &locations,
+        units,
+    )?;
+    let joiner_findings = mem::take(&mut checks.joiner_findings);
+    let analyses = analyse_font(
+        args,
+        &reporter,
+        font_path,
+        &checks.reports,
+        joiner_findings,
+        units,
+        words,
+    )?;
+    if let Some(comparison) = &analyses.comparison {
+        run.metric_failures += comparison.failures();
+    }
+
+    let took = start.elapsed();
+    info!("{} took {took:?}", font_path.display());
+
+    let checksum = font_checksum(&font_bytes);
+    if args.append_history.is_some() {
+        run.history.push(trend::summarise_font(
+            font_path,
+            checksum.clone(),
+            &reporter,
+            &checks.reports,
+        )?);
+    }
+
+    let font = CheckedFont {
+        path: font_path,
+        checksum,
+        reporter: &reporter,
+        warnings: font_warnings,
+        language_systems,
+        checks: &checks,
+        units,
+        words,
+    };
+    write_font(args, &font, &analyses, run)
+}
 
-            let reports = instances
+/// The word lists to check a font with, and the languages it declares in its
+/// `meta` table (if they're used to choose the word lists).
+fn select_word_lists<'a>(
+    args: &CheckArgs,
+    reporter: &Reporter,
+    font_path: &Path,
+    user_word_lists: &'a [WordList],
+) -> anyhow::Result<(Vec<DeclaredLanguage>, Vec<&'a WordList>)> {
+    // Languages declared in meta take precedence over the scripts in the cmap
+    let declared_languages = if args.all_wordlists || args.ignore_meta {
+        Vec::new()
+    } else {
+        reporter
+            .declared_languages()
+            .context("failed to read meta")?
+    };
+    declared_languages
+        .iter()
+        .filter(|language| {
+            !static_lang_word_lists::ALL_WORD_LISTS
                 .iter()
-                .flat_map(|instance| {
-                    static_lang_word_lists::ALL_WORD_LISTS
+                .any(|word_list| language.matches(word_list))
+        })
+        .for_each(|language| {
+            warn!(
+                "{} declares {} in meta, but no word list covers it",
+                font_path.display(),
+                language.tag(),
+            );
+        });
+
+    // User-provided word lists are always checked
+    let word_lists = static_lang_word_lists::ALL_WORD_LISTS
+        .iter()
+        .copied()
+        .filter(|word_list| {
+            args.all_wordlists
+                || if declared_languages.is_empty() {
+                    reporter.supports_word_list(word_list)
+                } else {
+                    declared_languages
                         .iter()
-                        .zip(iter::repeat(instance))
-                })
-                .par_bridge()
-                .map(|(word_list, instance)| -> anyhow::Result<_> {
-                    let report = instance.par_check(
-                        word_list,
-                        args.words_per_list,
-                        args.results,
-                    )?;
-                    info!(
-                        "finished checking {} at {:?}",
-                        word_list.name(),
-                        report.location
-                    );
-                    Ok(report)
+                        .any(|language| language.matches(word_list))
+                }
+        })
+        .chain(user_word_lists)
+        .collect();
+    Ok((declared_languages, word_lists))
+}
+
+/// The locations to check a font at: those chosen by --location-strategy,
+/// then its masters & random locations if asked for.
+fn select_locations(
+    context: &CheckContext,
+    reporter: &Reporter,
+    font_path: &Path,
+) -> anyhow::Result<Vec<Location>> {
+    let args = context.args;
+    let mut locations = reporter
+        .locations(&*context.location_strategy)
+        .context("failed to choose locations")?;
+    // Masters & random locations may be ones already chosen
+    let mut seen = locations.iter().cloned().collect::<HashSet<_>>();
+    let mut add_location = |location: Location| {
+        if seen.insert(location.clone()) {
+            locations.push(location);
+        }
+    };
+    if args.masters {
+        let masters = reporter
+            .master_locations()
+            .context("failed to read master locations")?;
+        info!(
+            "Found {} master locations in {}",
+            masters.len(),
+            font_path.display(),
+        );
+        masters.into_iter().for_each(&mut add_location);
+    }
+    if let Some(random_locations) = args.random_locations {
+        if reporter.fontref().axes().is_empty() {
+            warn!(
+                "{} isn't variable, so has no random locations to check",
+                font_path.display(),
+            );
+        } else {
+            reporter
+                .locations(&random_locations.sampling())
+                .context("failed to sample random locations")?
+                .into_iter()
+                .for_each(&mut add_location);
+        }
+    }
+    info!(
+        "Found {} interesting locations in {}",
+        locations.len(),
+        font_path.display(),
+    );
+
+    if locations.len() >= 100 && args.words_per_list.is_none() {
+        warn!(
+            "Testing {} instances with all words is probably going to take a \
+             while. Consider passing -k/--words to limit the number of words \
+             being checked",
+            locations.len()
+        );
+    }
+    Ok(locations)
+}
+
+/// Check each word list at each location, in waves of at most
+/// --max-locations instances, stopping at the deadline.
+fn check_locations<'a>(
+    context: &CheckContext,
+    reporter: &'a Reporter,
+    font_path: &Path,
+    word_lists: &[&'a WordList],
+    locations: &'a [Location],
+    units: Units,
+) -> anyhow::Result<FontChecks<'a>> {
+    let args = context.args;
+    // Each instance caches the extremes of every glyph, so only
+    // --max-locations of them are created at once
+    let wave_size = args
+        .max_locations
+        .map_or(locations.len(), NonZeroUsize::get)
+        .max(1);
+    let mut checks = FontChecks {
+        reports: Vec::new(),
+        clusters_json: Vec::new(),
+        joiner_findings: Vec::new(),
+        not_analyzed: Vec::new(),
+        glyph_bounds: Vec::new(),
+    };
+    let mut peak_memory = 0;
+    for wave in locations.chunks(wave_size) {
+        if context.past_deadline() {
+            checks.not_analyzed.extend(word_lists.iter().flat_map(
+                |&word_list| {
+                    wave.iter().map(move |location| (word_list, location))
+                },
+            ));
+            continue;
+        }
+        let instances = wave
+            .par_iter()
+            .map(|location| reporter.instance(location))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Word lists are the outer loop, so that higher priority word lists
+        // are checked first at every location
+        let wave_reports = word_lists
+            .iter()
+            .flat_map(|&word_list| {
+                iter::repeat(word_list).zip(instances.iter().zip(wave))
+            })
+            .par_bridge()
+            .map(|(word_list, (instance, location))| {
+                check_instance(
+                    context, font_path, instance, word_list, location, units,
+                )
+            })
+            .filter(|checked| {
+                !matches!(
+                    checked,
+                    Ok(Checked::Analyzed(report, ..))
+                        if report.exemplars.is_empty()
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Measured after checking, as glyphs drawn on demand are only drawn
+        // while checking
+        let memory = instances
+            .iter()
+            .map(InstanceReporter::estimated_memory)
+            .sum::<usize>();
+        peak_memory = peak_memory.max(memory);
+        if args.html {
+            checks.glyph_bounds.extend(instances.iter().map(|instance| {
+                (
+                    instance.location().clone(),
+                    instance.shared_instance_extremes(),
+                )
+            }));
+        }
+        debug!(
+            "checked {} locations with ~{} of glyph extremes",
+            instances.len(),
+            format_bytes(memory),
+        );
+        wave_reports.into_iter().for_each(|checked| match checked {
+            Checked::Analyzed(report, clusters, joiners) => {
+                checks.reports.push(report);
+                checks.clusters_json.extend(clusters);
+                checks.joiner_findings.extend(joiners);
+            },
+            Checked::NotAnalyzed(word_list, location) => {
+                checks.not_analyzed.push((word_list, location));
+            },
+        });
+    }
+    if !checks.not_analyzed.is_empty() {
+        warn!(
+            "{} word lists & locations of {} weren't analyzed before the \
+             deadline",
+            checks.not_analyzed.len(),
+            font_path.display(),
+        );
+    }
+    info!(
+        "Peak estimated memory used by glyph extremes for {}: {}",
+        font_path.display(),
+        format_bytes(peak_memory),
+    );
+    Ok(checks)
+}
+
+/// Check a word list at an instance, unless it's past the deadline. Finding
+/// cluster extremes & joiner permutations needs the instance, so it's done
+/// here too.
+fn check_instance<'a>(
+    context: &CheckContext,
+    font_path: &Path,
+    instance: &InstanceReporter<'a>,
+    word_list: &'a WordList,
+    location: &'a Location,
+    units: Units,
+) -> anyhow::Result<Checked<'a>> {
+    if context.past_deadline() {
+        return Ok(Checked::NotAnalyzed(word_list, location));
+    }
+    let options = context.options(word_list);
+    let report = match &context.dump {
+        Some(dump) => {
+            let font = font_path.display().to_string();
+            instance.check_streaming(word_list, options, |word_extremes| {
+                dump.write(
+                    &font,
+                    word_list,
+                    instance.location(),
+                    word_extremes,
+                    units,
+                );
+            })?
+        },
+        None => instance.check(word_list, options)?,
+    };
+    info!(
+        "finished checking {} at {:?}",
+        word_list.id(),
+        report.location
+    );
+    let clusters = context
+        .with_clusters
+        .then(|| {
+            fmt::json::report_with_clusters_to_json(&report, instance, units)
+        })
+        .transpose()?;
+    let joiners = if context.args.joiner_permutations {
+        joiners::find_joiner_permutations(instance, &report)?
+    } else {
+        Vec::new()
+    };
+    Ok(Checked::Analyzed(report, clusters, joiners))
+}
+
+/// Make the analyses of a font's reports asked for.
+fn analyse_font<'a>(
+    args: &CheckArgs,
+    reporter: &Reporter,
+    font_path: &Path,
+    reports: &'a [Report<'a>],
+    joiner_findings: Vec<joiners::JoinerFinding<'a>>,
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<FontAnalyses<'a>> {
+    let optimized = if !args.optimize_locations {
+        Vec::new()
+    } else if reporter.fontref().axes().is_empty() {
+        info!(
+            "{} has no axes, skipping optimising locations",
+            font_path.display()
+        );
+        Vec::new()
+    } else {
+        optimize::optimize_exemplars(reporter, reports, units, words)?
+    };
+
+    let margins = args
+        .script_margins
+        .then(|| {
+            margins::analyse_margins(
+                reporter,
+                reports,
+                args.min_margin,
+                units,
+                words,
+            )
+        })
+        .transpose()?;
+
+    let clipping_profiles = ClippingArg::profiles(&args.clipping);
+    let clipping = (!clipping_profiles.is_empty())
+        .then(|| {
+            clipping::analyse_clipping(
+                reporter,
+                reports,
+                &clipping_profiles,
+                units,
+                words,
+            )
+        })
+        .transpose()?;
+
+    let line_stacking = args
+        .line_stacking
+        .then(|| {
+            line_stacking::analyse_line_stacking(
+                reporter, reports, units, words,
+            )
+        })
+        .transpose()?;
+
+    let em_box = args
+        .em_box
+        .then(|| em_box::analyse_em_box(reporter, reports, units, words))
+        .transpose()?;
+
+    let comparison = args
+        .compare_metrics
+        .then(|| {
+            compare_metrics::compare_metrics(reporter, reports, units, words)
+        })
+        .transpose()?;
+
+    let joiners = args
+        .joiner_permutations
+        .then(|| joiners::JoinerAnalysis::new(joiner_findings, units, words));
+
+    Ok(FontAnalyses {
+        optimized,
+        margins,
+        clipping,
+        line_stacking,
+        em_box,
+        comparison,
+        joiners,
+    })
+}
+
+/// Write a checked font in the format asked for. JSON, CBOR & SARIF are
+/// collected into `run`, to be written as a single document once all the
+/// fonts are checked.
+fn write_font(
+    args: &CheckArgs,
+    font: &CheckedFont,
+    analyses: &FontAnalyses,
+    run: &mut CheckRun,
+) -> anyhow::Result<()> {
+    if args.html {
+        info!("generating HTML report");
+        let start = Instant::now();
+        let html = fmt::html::format_all_reports(
+            &font.checks.reports,
+            font.reporter,
+            font.units,
+            args.theme,
+            analyses.comparison.as_ref(),
+            font.checks.glyph_bounds.clone(),
+            &run.provenance,
+        )?;
+        info!("took {:?}", start.elapsed());
+        return run
+            .output
+            .write_all(html.as_bytes())
+            .context("failed to write to output");
+    }
+
+    if args.sarif {
+        let sarif = &mut run.sarif;
+        let artifact = sarif.add_font(font.path);
+        sarif.add_warnings(artifact, &font.warnings, &font.checks.reports);
+        if let Some(clipping) = &analyses.clipping {
+            sarif.add_clipping(artifact, clipping);
+        }
+        if let Some(line_stacking) = &analyses.line_stacking {
+            sarif.add_line_stacking(artifact, line_stacking);
+        }
+        if let Some(em_box) = &analyses.em_box {
+            sarif.add_em_box(artifact, em_box);
+        }
+        if let Some(margins) = &analyses.margins {
+            sarif.add_margins(artifact, margins);
+        }
+        if let Some(comparison) = &analyses.comparison {
+            sarif.add_metric_verdicts(artifact, comparison);
+        }
+        return Ok(());
+    }
+
+    if let Some(summary) = args.summary {
+        return summary::summarise_font(
+            font.path,
+            font.reporter,
+            &font.checks.reports,
+            font.units,
+            font.words,
+        )?
+        .write(&mut run.output, summary)
+        .context("failed to write to output");
+    }
+
+    match args.format {
+        OutputFormat::Human => {
+            write_human_font(&mut run.output, font, analyses)
+                .context("failed to write to output")
+        },
+        OutputFormat::Json | OutputFormat::Cbor => {
+            run.fonts_json.push(font_to_json(args, font, analyses));
+            Ok(())
+        },
+    }
+}
+
+fn write_human_font(
+    output: &mut impl Write,
+    font: &CheckedFont,
+    analyses: &FontAnalyses,
+) -> io::Result<()> {
+    writeln!(output, "{}:", font.path.display())?;
+    font.warnings
+        .iter()
+        .try_for_each(|warning| writeln!(output, "  warning: {warning}"))?;
+    font.checks.reports.iter().try_for_each(|report| {
+        writeln!(
+            output,
+            "{}",
+            report.format(OutputFormat::Human, font.units, font.words)
+        )
+    })?;
+    if !font.checks.not_analyzed.is_empty() {
+        writeln!(output, "  not analyzed before the deadline:")?;
+        font.checks.not_analyzed.iter().try_for_each(
+            |(word_list, location)| {
+                writeln!(output, "    {} @ {location:?}", word_list.id())
+            },
+        )?;
+    }
+    analyses
+        .optimized
+        .iter()
+        .try_for_each(|optimized| writeln!(output, "{optimized}"))?;
+    if let Some(margins) = &analyses.margins {
+        writeln!(output, "{margins}")?;
+    }
+    if let Some(clipping) = &analyses.clipping {
+        writeln!(output, "{clipping}")?;
+    }
+    if let Some(line_stacking) = &analyses.line_stacking {
+        writeln!(output, "{line_stacking}")?;
+    }
+    if let Some(em_box) = &analyses.em_box {
+        writeln!(output, "{em_box}")?;
+    }
+    if let Some(comparison) = &analyses.comparison {
+        writeln!(output, "{comparison}")?;
+    }
+    if let Some(joiners) = &analyses.joiners {
+        writeln!(output, "{joiners}")?;
+    }
+    Ok(())
+}
+
+fn font_to_json(
+    args: &CheckArgs,
+    font: &CheckedFont,
+    analyses: &FontAnalyses,
+) -> FontJson {
+    let reports = if args.clusters {
+        font.checks.clusters_json.clone()
+    } else {
+        font.checks
+            .reports
+            .iter()
+            .map(|report| fmt::json::report_to_json(report, font.units))
+            .collect()
+    };
+    FontJson {
+        font: font.path.display().to_string(),
+        checksum: font.checksum.clone(),
+        axes: fmt::json::axes_to_json(&font.reporter.axes()),
+        warnings: font
+            .warnings
+            .iter()
+            .map(fmt::json::warning_to_json)
+            .collect(),
+        reports,
+        language_systems: Some(
+            font.language_systems
+                .iter()
+                .map(|(id, language_system)| {
+                    (id.clone(), language_system.map(|tag| tag.to_string()))
                 })
-                .filter(|report_res| {
-                    report_res
-                        .as_ref()
-                        .map_or(true, |report| !report.exemplars.is_empty())
+                .collect(),
+        ),
+        not_analyzed: args.deadline.map(|_| {
+            font.checks
+                .not_analyzed
+                .iter()
+                .map(|(word_list, location)| NotAnalyzedJson {
+                    word_list: fmt::json::word_list_to_json(word_list),
+                    location: (*location).clone(),
                 })
-                .collect::<Result<Vec<_>, _>>()?;
-
-            let took = start.elapsed();
-            info!("{} took {took:?}", font_path.display());
-
-            if !args.html {
-                writeln!(&mut output, "{}:", font_path.display())
-                    .context("failed to write to output")?;
-                reports
-                    .iter()
-                    .try_for_each(|report| {
-                        writeln!(
-                            &mut output,
-                            "{}",
-                            report.format(OutputFormat::Human)
-                        )
-                    })
-                    .context("failed to write to output")?;
-            } else {
-                info!("generating HTML report");
-                let start = Instant::now();
-                let html = fmt::html::format_all_reports(
-                    &reports,
-                    reporter.fontref(),
-                )?;
-                info!("took {:?}", start.elapsed());
-                output
-                    .write_all(html.as_bytes())
-                    .context("failed to write to output")?;
-            }
-            Ok(())
-        })
+                .collect()
+        }),
+        optimized: args.optimize_locations.then(|| {
+            analyses
+                .optimized
+                .iter()
+                .map(optimize::OptimizedExemplars::to_json)
+                .collect()
+        }),
+        script_margins: analyses
+            .margins
+            .as_ref()
+            .map(|margins| margins.to_json()),
+        clipping: analyses
+            .clipping
+            .as_ref()
+            .map(|clipping| clipping.to_json()),
+        line_stacking: analyses
+            .line_stacking
+            .as_ref()
+            .map(|line_stacking| line_stacking.to_json()),
+        em_box: analyses.em_box.as_ref().map(|em_box| em_box.to_json()),
+        metric_verdicts: analyses
+            .comparison
+            .as_ref()
+            .map(|comparison| comparison.to_json()),
+        joiner_permutations: analyses
+            .joiners
+            .as_ref()
+            .map(|joiners| joiners.to_json()),
+    }
 }
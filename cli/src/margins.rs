@@ -0,0 +1,188 @@
+use std::{collections::BTreeMap, fmt};
+
+use anyhow::Context;
+use fontheight::{Extreme, Location, Report, Reporter, WordExtremes};
+use log::warn;
+use serde_json::{Value, json};
+use skrifa::raw::TableProvider;
+
+use crate::{
+    fmt::{script_label, units::Units, words::WordStyle},
+    optimize::most_extreme,
+};
+
+/// How far each script's most extreme words are from the typographic
+/// ascender & descender.
+#[derive(Debug)]
+pub struct MarginAnalysis<'a> {
+    /// `OS/2.sTypoAscender`
+    pub ascender: f64,
+    /// `OS/2.sTypoDescender`
+    pub descender: f64,
+    /// The percentage of the UPM that margins should be at least
+    pub min_margin_percent: f64,
+    /// The smallest acceptable margin, in font units
+    pub min_margin: f64,
+    pub scripts: Vec<ScriptMargins<'a>>,
+    /// The units to write the extremes & margins in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+/// The most extreme words of a script, across all its word lists & locations.
+#[derive(Debug)]
+pub struct ScriptMargins<'a> {
+    pub script: &'a str,
+    pub tallest: (WordExtremes<'a>, &'a Location),
+    pub lowest: (WordExtremes<'a>, &'a Location),
+}
+
+/// Find the margins for each script with a word list in `reports`.
+///
+/// Word lists without a script are skipped.
+pub fn analyse_margins<'a>(
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    min_margin_percent: f64,
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<MarginAnalysis<'a>> {
+    let font = reporter.fontref();
+    let os2 = font.os2().context("failed to read OS/2")?;
+    let units_per_em =
+        font.head().context("failed to read head")?.units_per_em();
+
+    let mut by_script = BTreeMap::<&str, Vec<&Report>>::new();
+    reports.iter().for_each(|report| {
+        if let Some(script) = report.word_list.script() {
+            by_script.entry(script).or_default().push(report);
+        }
+    });
+    let scripts = by_script
+        .into_iter()
+        .filter_map(|(script, reports)| {
+            Some(ScriptMargins {
+                script,
+                tallest: most_extreme(&reports, Extreme::Highest)?,
+                lowest: most_extreme(&reports, Extreme::Lowest)?,
+            })
+        })
+        .collect();
+
+    let analysis = MarginAnalysis {
+        ascender: f64::from(os2.s_typo_ascender()),
+        descender: f64::from(os2.s_typo_descender()),
+        min_margin_percent,
+        min_margin: min_margin_percent / 100.0 * f64::from(units_per_em),
+        scripts,
+        units,
+        words,
+    };
+    analysis.scripts.iter().for_each(|margins| {
+        if analysis.is_flagged(analysis.above(margins)) {
+            warn!(
+                "{} reaches {} above the typo ascender",
+                margins.script,
+                units.convert(-analysis.above(margins)),
+            );
+        }
+        if analysis.is_flagged(analysis.below(margins)) {
+            warn!(
+                "{} reaches {} below the typo descender",
+                margins.script,
+                units.convert(-analysis.below(margins)),
+            );
+        }
+    });
+    Ok(analysis)
+}
+
+impl MarginAnalysis<'_> {
+    /// The margin between a script's tallest word and the ascender, in font
+    /// units. Negative if the word reaches above the ascender.
+    pub fn above(&self, margins: &ScriptMargins) -> f64 {
+        self.ascender - margins.tallest.0.highest()
+    }
+
+    /// The margin between a script's lowest word and the descender, in font
+    /// units. Negative if the word reaches below the descender.
+    pub fn below(&self, margins: &ScriptMargins) -> f64 {
+        margins.lowest.0.lowest() - self.descender
+    }
+
+    /// Whether a margin is negative or less than the minimum.
+    pub fn is_flagged(&self, margin: f64) -> bool {
+        margin < 0.0 || margin < self.min_margin
+    }
+
+    pub fn to_json(&self) -> Value {
+        let margin_to_json =
+            |(word_extremes, location): &(WordExtremes, &Location),
+             extreme: f64,
+             margin: f64| {
+                json!({
+                    "word": word_extremes.word,
+                    "index": word_extremes.index,
+                    "location": location,
+                    "extreme": self.units.convert(extreme),
+                    "margin": self.units.convert(margin),
+                    "flagged": self.is_flagged(margin),
+                })
+            };
+        let scripts = self.scripts.iter().map(|margins| {
+            let json = json!({
+                "above": margin_to_json(
+                    &margins.tallest,
+                    margins.tallest.0.highest(),
+                    self.above(margins),
+                ),
+                "below": margin_to_json(
+                    &margins.lowest,
+                    margins.lowest.0.lowest(),
+                    self.below(margins),
+                ),
+            });
+            (margins.script.to_owned(), json)
+        });
+        json!({
+            "ascender": self.units.convert(self.ascender),
+            "descender": self.units.convert(self.descender),
+            "min_margin_percent": self.min_margin_percent,
+            "scripts": Value::Object(scripts.collect()),
+        })
+    }
+}
+
+impl fmt::Display for MarginAnalysis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "  script margins (typo ascender {}, descender {}, minimum {}% of \
+             UPM):",
+            self.units.convert(self.ascender),
+            self.units.convert(self.descender),
+            self.min_margin_percent,
+        )?;
+        write!(
+            f,
+            "\n    {:<24} {:>10} {:>10}  {:<24} lowest",
+            "script", "above", "below", "tallest"
+        )?;
+        self.scripts.iter().try_for_each(|margins| {
+            let margin = |margin: f64| {
+                let flag = if self.is_flagged(margin) { " !" } else { "" };
+                format!("{}{flag}", self.units.convert(margin))
+            };
+            write!(
+                f,
+                "\n    {:<24} {:>10} {:>10}  {:<24} {}",
+                script_label(margins.script),
+                margin(self.above(margins)),
+                margin(self.below(margins)),
+                self.words.display(margins.tallest.0.word).to_string(),
+                self.words.display(margins.lowest.0.word),
+            )
+        })
+    }
+}
@@ -0,0 +1,566 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::OpenOptions,
+    io::{Write, stdout},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, bail, ensure};
+use fontheight::{
+    CollectToExemplars, Location, Punctuation, VerticalExtremes, WordExtremes,
+};
+use log::{info, warn};
+
+use crate::{
+    Shard,
+    fmt::{
+        OutputFormat,
+        json::{
+            AxisJson, BinJson, CheckJson, ClusterJson, ConfigJson,
+            ExemplarJson, FontJson, HistogramJson, OptimizedJson, ReportJson,
+            ShapingJson, StatisticsJson, WarningJson, WordListJson,
+            word_extremes_to_json,
+        },
+        read_document,
+        units::Units,
+        write_document,
+    },
+    names_word_list,
+};
+
+/// Merge JSON reports from sharded or partial runs into a single report
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The reports to merge, as written by --format json or --format cbor
+    #[arg(required = true)]
+    report_paths: Vec<PathBuf>,
+
+    /// Write the merged report into the given path, in the same format as the
+    /// first report. Will print to stdout if not specified
+    #[arg(short, long = "output")]
+    output_path: Option<PathBuf>,
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let documents = args
+        .report_paths
+        .iter()
+        .map(|path| {
+            let (document, format) =
+                read_document::<CheckJson>(path).with_context(|| {
+                    format!(
+                        "{} isn't a fontheight JSON report",
+                        path.display(),
+                    )
+                })?;
+            Document::new(path, document, format)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let config = merge_configs(&documents)?;
+
+    // Fonts are matched by checksum, as the same font may have been at a
+    // different path on each machine
+    let mut fonts = Vec::<MergedFont>::new();
+    for document in &documents {
+        for font in &document.json.fonts {
+            let (name, checksum) = (&font.font, &font.checksum);
+            if let Some(other) = fonts.iter().find(|merged| {
+                merged.name == *name && merged.checksum != *checksum
+            }) {
+                bail!(
+                    "{name} differs between reports (checksums {} & \
+                     {checksum}), can't merge reports for different fonts",
+                    other.checksum,
+                );
+            }
+            let merged = match fonts
+                .iter_mut()
+                .position(|merged| merged.checksum == *checksum)
+            {
+                Some(index) => &mut fonts[index],
+                None => {
+                    fonts.push(MergedFont {
+                        name: name.clone(),
+                        checksum: checksum.clone(),
+                        axes: font.axes.clone(),
+                        warnings: font.warnings.clone(),
+                        reports: Vec::new(),
+                        optimized: None,
+                        drifted: BTreeSet::new(),
+                    });
+                    fonts.last_mut().unwrap()
+                },
+            };
+            merged.add(font, document.shard_suffix.as_deref())?;
+        }
+    }
+
+    let fonts = fonts
+        .iter()
+        .map(|font| {
+            font.to_json(config.results, |word_list| {
+                config.distinct
+                    || config.distinct_word_lists.iter().any(|name| {
+                        names_word_list(name, &word_list.name, &word_list.id)
+                    })
+            })
+        })
+        .collect();
+    let document = CheckJson {
+        provenance: None,
+        config,
+        fonts,
+        errors: Vec::new(),
+    };
+
+    let mut output: Box<dyn Write> = match &args.output_path {
+        None => Box::new(stdout().lock()),
+        Some(path) => {
+            let handle = OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .create(true)
+                .open(path)
+                .context("failed to open output file")?;
+            Box::new(handle)
+        },
+    };
+    write_document(&mut output, documents[0].format, &document)
+        .context("failed to write to output")?;
+    info!("merged {} reports", documents.len());
+    Ok(())
+}
+
+struct Document<'a> {
+    path: &'a Path,
+    json: CheckJson,
+    format: OutputFormat,
+    shard: Option<Shard>,
+    // Appended to the names of sharded word lists, e.g. [2/8]
+    shard_suffix: Option<String>,
+}
+
+impl<'a> Document<'a> {
+    fn new(
+        path: &'a Path,
+        json: CheckJson,
+        format: OutputFormat,
+    ) -> anyhow::Result<Self> {
+        let shard = json
+            .config
+            .shard
+            .as_deref()
+            .map(str::parse::<Shard>)
+            .transpose()
+            .with_context(|| {
+                format!("{} has an invalid shard", path.display())
+            })?;
+        Ok(Document {
+            path,
+            shard_suffix: shard.map(|shard| format!("[{shard}]")),
+            shard,
+            json,
+            format,
+        })
+    }
+}
+
+/// Check that all the reports were made with the same options, returning the
+/// options for the merged report.
+fn merge_configs(documents: &[Document]) -> anyhow::Result<ConfigJson> {
+    // Shards are expected to differ, everything else must match
+    let without_shard = |document: &Document| ConfigJson {
+        shard: None,
+        ..document.json.config.clone()
+    };
+
+    let first = &documents[0];
+    let config = without_shard(first);
+    for document in &documents[1..] {
+        ensure!(
+            without_shard(document) == config,
+            "{} & {} were made with different options, they can't be merged",
+            first.path.display(),
+            document.path.display(),
+        );
+    }
+
+    let shards = documents
+        .iter()
+        .map(|document| document.shard)
+        .collect::<Option<Vec<_>>>();
+    match shards {
+        Some(shards) => {
+            let count = shards[0].count;
+            ensure!(
+                shards.iter().all(|shard| shard.count == count),
+                "reports were split into different numbers of shards",
+            );
+            let indices = shards
+                .iter()
+                .map(|shard| shard.index)
+                .collect::<BTreeSet<_>>();
+            ensure!(
+                indices.len() == shards.len(),
+                "the same shard was given more than once",
+            );
+            if indices.len() != count {
+                warn!("only {} of {count} shards were merged", indices.len());
+            }
+        },
+        None => ensure!(
+            documents.iter().all(|document| document.shard.is_none()),
+            "can't merge sharded reports with unsharded ones",
+        ),
+    }
+
+    Ok(config)
+}
+
+struct MergedFont {
+    name: String,
+    checksum: String,
+    // Reports of the same font have the same axes & font warnings, so the
+    // first one's are kept
+    axes: Vec<AxisJson>,
+    warnings: Vec<WarningJson>,
+    // Keyed by word list ID & location
+    reports: Vec<((String, Location), MergedReport)>,
+    // Keyed by word list ID, if the reports have any
+    optimized: Option<Vec<(String, OptimizedJson)>>,
+    // Word lists already warned about for having different content hashes
+    drifted: BTreeSet<String>,
+}
+
+/// A word's index, the word itself, and the punctuation it was wrapped in.
+type WordKey = (usize, String, Option<Punctuation>);
+
+struct MergedReport {
+    word_list: WordListJson,
+    location: Location,
+    // Keyed by index, word & punctuation, to de-duplicate words in both the
+    // highest & lowest exemplars. Cluster breakdowns are kept as-is, if
+    // present
+    words:
+        BTreeMap<WordKey, (VerticalExtremes, usize, Option<Vec<ClusterJson>>)>,
+    // Summed across reports
+    statistics: StatisticsJson,
+    // The same for every shard, as it only depends on the location
+    feature_variation: Option<usize>,
+    // Summed across reports, if they have histograms
+    histogram: Option<MergedHistogram>,
+    // Every distinct warning from the reports
+    warnings: Vec<WarningJson>,
+    // The least support judged, as each shard is judged on its own words
+    support: Option<String>,
+    // Every distinct script, language & direction the shards were shaped with
+    shaping: Vec<ShapingJson>,
+}
+
+/// A histogram's bins, keyed by their index, with counts summed across
+/// reports.
+struct MergedHistogram {
+    bin_size: f64,
+    highest: BTreeMap<i64, usize>,
+    lowest: BTreeMap<i64, usize>,
+}
+
+impl MergedHistogram {
+    fn add(&mut self, histogram: &HistogramJson) -> anyhow::Result<()> {
+        ensure!(
+            histogram.bin_size == self.bin_size,
+            "can't merge histograms with different bin sizes"
+        );
+        for (bins, merged) in [
+            (&histogram.highest, &mut self.highest),
+            (&histogram.lowest, &mut self.lowest),
+        ] {
+            for bin in bins {
+                let index = (bin.start / self.bin_size).round() as i64;
+                *merged.entry(index).or_default() += bin.count;
+            }
+        }
+        Ok(())
+    }
+
+    fn to_json(&self) -> HistogramJson {
+        let bins_to_json = |bins: &BTreeMap<i64, usize>| {
+            bins.iter()
+                .map(|(&index, &count)| BinJson {
+                    start: index as f64 * self.bin_size,
+                    count,
+                })
+                .collect()
+        };
+        HistogramJson {
+            bin_size: self.bin_size,
+            highest: bins_to_json(&self.highest),
+            lowest: bins_to_json(&self.lowest),
+        }
+    }
+}
+
+impl MergedFont {
+    fn add(
+        &mut self,
+        font: &FontJson,
+        shard_suffix: Option<&str>,
+    ) -> anyhow::Result<()> {
+        for report in &font.reports {
+            let word_list = unshard_word_list(&report.word_list, shard_suffix);
+            let key = (word_list.id.clone(), report.location.clone());
+
+            let merged = match self.reports.iter().position(|(k, _)| *k == key)
+            {
+                Some(index) => {
+                    let merged = &mut self.reports[index].1;
+                    if merged.word_list.content_hash != word_list.content_hash
+                        && self.drifted.insert(key.0.clone())
+                    {
+                        warn!(
+                            "{} has a different content hash in some reports \
+                             for {}, the word list may have changed between \
+                             runs",
+                            key.0, self.name,
+                        );
+                    }
+                    merged
+                },
+                None => {
+                    self.reports.push((key, MergedReport {
+                        word_list,
+                        location: report.location.clone(),
+                        words: BTreeMap::new(),
+                        statistics: StatisticsJson::default(),
+                        feature_variation: report.feature_variation,
+                        histogram: None,
+                        warnings: Vec::new(),
+                        support: None,
+                        shaping: Vec::new(),
+                    }));
+                    &mut self.reports.last_mut().unwrap().1
+                },
+            };
+            for exemplar in report.highest.iter().chain(&report.lowest) {
+                let WordExtremes {
+                    word,
+                    index,
+                    extremes,
+                    skipped_glyphs,
+                    punctuation,
+                } = parse_exemplar(exemplar)?;
+                merged.words.insert(
+                    (index, word.to_owned(), punctuation),
+                    (extremes, skipped_glyphs, exemplar.clusters.clone()),
+                );
+            }
+            if let Some(histogram) = &report.histogram {
+                merged
+                    .histogram
+                    .get_or_insert_with(|| MergedHistogram {
+                        bin_size: histogram.bin_size,
+                        highest: BTreeMap::new(),
+                        lowest: BTreeMap::new(),
+                    })
+                    .add(histogram)?;
+            }
+            for warning in &report.warnings {
+                if !merged.warnings.contains(warning) {
+                    merged.warnings.push(warning.clone());
+                }
+            }
+            for properties in &report.shaping {
+                if !merged.shaping.contains(properties) {
+                    merged.shaping.push(properties.clone());
+                }
+            }
+            if let Some(support) = &report.support {
+                let rank = |support: &str| {
+                    ["none", "partial", "full"]
+                        .iter()
+                        .position(|level| *level == support)
+                };
+                if merged
+                    .support
+                    .as_deref()
+                    .is_none_or(|merged| rank(support) < rank(merged))
+                {
+                    merged.support = Some(support.clone());
+                }
+            }
+            merged.statistics.add(&report.statistics);
+        }
+
+        for entry in font.optimized.iter().flatten() {
+            let word_list = unshard_word_list(&entry.word_list, shard_suffix);
+            let optimized = self.optimized.get_or_insert_default();
+            let merged = match optimized
+                .iter()
+                .position(|(id, _)| *id == word_list.id)
+            {
+                Some(index) => &mut optimized[index].1,
+                None => {
+                    optimized.push((word_list.id.clone(), OptimizedJson {
+                        word_list,
+                        highest: None,
+                        lowest: None,
+                    }));
+                    &mut optimized.last_mut().unwrap().1
+                },
+            };
+            if let Some(highest) = &entry.highest
+                && merged
+                    .highest
+                    .as_ref()
+                    .is_none_or(|current| highest.highest > current.highest)
+            {
+                merged.highest = Some(highest.clone());
+            }
+            if let Some(lowest) = &entry.lowest
+                && merged
+                    .lowest
+                    .as_ref()
+                    .is_none_or(|current| lowest.lowest < current.lowest)
+            {
+                merged.lowest = Some(lowest.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// The merged reports, keeping only the most extreme of words that
+    /// differ only by case or punctuation in the word lists `distinct`
+    /// accepts.
+    fn to_json(
+        &self,
+        results: usize,
+        distinct: impl Fn(&WordListJson) -> bool,
+    ) -> FontJson {
+        let reports = self.reports.iter().map(|(_, report)| {
+            // Re-run the exemplar collection on everything the reports found
+            let word_extremes = report.words.iter().map(
+                |(
+                    (index, word, punctuation),
+                    (extremes, skipped_glyphs, _),
+                )| {
+                    WordExtremes {
+                        word,
+                        index: *index,
+                        extremes: *extremes,
+                        skipped_glyphs: *skipped_glyphs,
+                        punctuation: *punctuation,
+                    }
+                },
+            );
+            let exemplars = if distinct(&report.word_list) {
+                word_extremes.collect_distinct_min_max_extremes(results)
+            } else {
+                word_extremes.collect_min_max_extremes(results)
+            };
+            let exemplars_to_json = |exemplars: &[WordExtremes]| {
+                exemplars
+                    .iter()
+                    .map(|word_extremes| {
+                        // Values were already converted when the reports were
+                        // written
+                        let key = (
+                            word_extremes.index,
+                            word_extremes.word.to_owned(),
+                            word_extremes.punctuation,
+                        );
+                        ExemplarJson {
+                            clusters: report.words[&key].2.clone(),
+                            ..word_extremes_to_json(word_extremes, Units::EXACT)
+                        }
+                    })
+                    .collect()
+            };
+            ReportJson {
+                word_list: report.word_list.clone(),
+                location: report.location.clone(),
+                highest: exemplars_to_json(exemplars.highest()),
+                lowest: exemplars_to_json(exemplars.lowest()),
+                statistics: report.statistics.clone(),
+                feature_variation: report.feature_variation,
+                histogram: report
+                    .histogram
+                    .as_ref()
+                    .map(MergedHistogram::to_json),
+                warnings: report.warnings.clone(),
+                support: report.support.clone(),
+                shaping: report.shaping.clone(),
+            }
+        });
+
+        FontJson {
+            font: self.name.clone(),
+            checksum: self.checksum.clone(),
+            axes: self.axes.clone(),
+            warnings: self.warnings.clone(),
+            reports: reports.collect(),
+            language_systems: None,
+            not_analyzed: None,
+            optimized: self.optimized.as_ref().map(|optimized| {
+                optimized
+                    .iter()
+                    .map(|(_, optimized)| optimized.clone())
+                    .collect()
+            }),
+            script_margins: None,
+            clipping: None,
+            line_stacking: None,
+            em_box: None,
+            metric_verdicts: None,
+            joiner_permutations: None,
+        }
+    }
+}
+
+/// Remove the shard suffix from a word list's name & ID, so that all shards of
+/// a word list are merged together.
+fn unshard_word_list(
+    word_list: &WordListJson,
+    shard_suffix: Option<&str>,
+) -> WordListJson {
+    let Some(suffix) = shard_suffix else {
+        return word_list.clone();
+    };
+    let unshard = |s: &str| s.strip_suffix(suffix).unwrap_or(s).to_owned();
+    WordListJson {
+        id: unshard(&word_list.id),
+        name: unshard(&word_list.name),
+        // Each shard only hashes its own part of the word list
+        content_hash: None,
+        ..word_list.clone()
+    }
+}
+
+fn parse_exemplar(exemplar: &ExemplarJson) -> anyhow::Result<WordExtremes<'_>> {
+    let ExemplarJson {
+        word,
+        index,
+        lowest,
+        highest,
+        ..
+    } = exemplar;
+    ensure!(lowest <= highest, "exemplar {word} has lowest > highest");
+    Ok(WordExtremes {
+        word,
+        index: *index,
+        extremes: VerticalExtremes::new(*lowest, *highest),
+        // Only written for partial words
+        skipped_glyphs: exemplar.skipped_glyphs.unwrap_or(0),
+        // Only written for words wrapped in punctuation
+        punctuation: exemplar
+            .punctuation
+            .as_deref()
+            .map(|name| {
+                Punctuation::ALL
+                    .into_iter()
+                    .find(|punctuation| punctuation.name() == name)
+                    .with_context(|| format!("unknown punctuation {name}"))
+            })
+            .transpose()?,
+    })
+}
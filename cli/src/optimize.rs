@@ -0,0 +1,164 @@
+use std::{collections::BTreeMap, fmt};
+
+use fontheight::{
+    Extreme, Location, OptimizedLocation, Report, Reporter, WordExtremes,
+    WordList, WordListId,
+};
+use log::info;
+use rayon::prelude::*;
+
+use crate::fmt::{
+    json::{OptimizedJson, OptimizedWordJson, word_list_to_json},
+    units::Units,
+    words::WordStyle,
+};
+
+/// The most extreme exemplars for a word list, after searching the design
+/// space for where they reach furthest.
+#[derive(Debug)]
+pub struct OptimizedExemplars<'a> {
+    pub word_list: &'a WordList,
+    pub highest: Option<OptimizedExemplar<'a>>,
+    pub lowest: Option<OptimizedExemplar<'a>>,
+    /// The units to write the extremes in
+    pub units: Units,
+    /// How to write the words
+    pub words: WordStyle,
+}
+
+#[derive(Debug)]
+pub struct OptimizedExemplar<'a> {
+    pub word_extremes: WordExtremes<'a>,
+    pub optimized: OptimizedLocation,
+}
+
+/// Optimise the location of the tallest & lowest exemplar of each word list,
+/// starting from the location it was found at.
+pub fn optimize_exemplars<'a>(
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<Vec<OptimizedExemplars<'a>>> {
+    let mut by_word_list = BTreeMap::<WordListId, Vec<&Report>>::new();
+    reports.iter().for_each(|report| {
+        by_word_list
+            .entry(report.word_list.id())
+            .or_default()
+            .push(report);
+    });
+
+    by_word_list
+        .into_values()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|reports| -> anyhow::Result<_> {
+            let word_list = reports[0].word_list;
+            let optimize = |extreme| {
+                let (word_extremes, location) =
+                    most_extreme(&reports, extreme)?;
+                let optimized = reporter
+                    .optimize_location(
+                        word_list,
+                        word_extremes.word,
+                        extreme,
+                        location,
+                    )
+                    .transpose()?;
+                Some(optimized.map(|optimized| OptimizedExemplar {
+                    word_extremes,
+                    optimized,
+                }))
+            };
+            let optimized = OptimizedExemplars {
+                word_list,
+                highest: optimize(Extreme::Highest).transpose()?,
+                lowest: optimize(Extreme::Lowest).transpose()?,
+                units,
+                words,
+            };
+            info!("finished optimising {}", word_list.id());
+            Ok(optimized)
+        })
+        .collect()
+}
+
+/// Find the most extreme exemplar across all locations.
+pub fn most_extreme<'a>(
+    reports: &[&'a Report<'a>],
+    extreme: Extreme,
+) -> Option<(WordExtremes<'a>, &'a Location)> {
+    let candidates = reports.iter().filter_map(|report| {
+        let exemplars = match extreme {
+            Extreme::Highest => report.exemplars.highest(),
+            Extreme::Lowest => report.exemplars.lowest(),
+        };
+        exemplars
+            .first()
+            .map(|exemplar| (*exemplar, report.location))
+    });
+    match extreme {
+        Extreme::Highest => candidates.reduce(|best, other| {
+            if other.0.highest() > best.0.highest() {
+                other
+            } else {
+                best
+            }
+        }),
+        Extreme::Lowest => candidates.reduce(|best, other| {
+            if other.0.lowest() < best.0.lowest() {
+                other
+            } else {
+                best
+            }
+        }),
+    }
+}
+
+impl fmt::Display for OptimizedExemplars<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  {} optimised:", self.word_list.id())?;
+        if let Some(highest) = &self.highest {
+            write!(
+                f,
+                "\n    tallest: {} => {} @ {:?}",
+                self.words.display(highest.word_extremes.word),
+                self.units.convert(highest.optimized.extremes.highest()),
+                highest.optimized.location,
+            )?;
+        }
+        if let Some(lowest) = &self.lowest {
+            write!(
+                f,
+                "\n    lowest: {} => {} @ {:?}",
+                self.words.display(lowest.word_extremes.word),
+                self.units.convert(lowest.optimized.extremes.lowest()),
+                lowest.optimized.location,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl OptimizedExemplars<'_> {
+    pub fn to_json(&self) -> OptimizedJson {
+        let exemplar_to_json = |exemplar: &Option<OptimizedExemplar>| {
+            exemplar.as_ref().map(|exemplar| OptimizedWordJson {
+                word: exemplar.word_extremes.word.to_owned(),
+                index: exemplar.word_extremes.index,
+                location: exemplar.optimized.location.clone(),
+                lowest: self
+                    .units
+                    .convert(exemplar.optimized.extremes.lowest()),
+                highest: self
+                    .units
+                    .convert(exemplar.optimized.extremes.highest()),
+            })
+        };
+        OptimizedJson {
+            word_list: word_list_to_json(self.word_list),
+            highest: exemplar_to_json(&self.highest),
+            lowest: exemplar_to_json(&self.lowest),
+        }
+    }
+}
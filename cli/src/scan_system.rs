@@ -0,0 +1,328 @@
+use std::{
+    collections::BTreeSet,
+    env,
+    ffi::OsStr,
+    fs,
+    io::{Write, stdout},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, bail};
+use fontheight::{
+    AnalysisOptions, ClippingProfile, Measurement, Reporter, VerticalExtremes,
+};
+use log::{debug, info, warn};
+use rayon::prelude::*;
+use serde_json::{Value, json};
+use skrifa::raw::TableProvider;
+use static_lang_word_lists::ALL_WORD_LISTS;
+
+use crate::fmt::{
+    OutputFormat,
+    units::{UnitArgs, Units},
+    words::WordArgs,
+    write_document,
+};
+
+/// Scan the fonts installed on this system for words reaching beyond the
+/// font's own OS/2.usWinAscent/usWinDescent, where Windows clips them
+///
+/// A quick audit rather than a full check: fonts are only checked at their
+/// default location, with a sample of words from each word list they support.
+/// Font collections (.ttc/.otc) are skipped
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Also scan these directories for fonts
+    #[arg(long = "dir", value_name = "DIR")]
+    extra_dirs: Vec<PathBuf>,
+
+    /// The number of words from each list to test
+    #[arg(short = 'k', long = "words", default_value_t = 200)]
+    words_per_list: usize,
+
+    /// Measure words by their glyphs' control boxes, which is faster but may
+    /// overestimate extremes, flagging fonts which aren't clipped
+    #[arg(long)]
+    fast: bool,
+
+    /// The format to list the fonts in
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    #[command(flatten)]
+    units: UnitArgs,
+
+    #[command(flatten)]
+    words: WordArgs,
+}
+
+/// The directories fonts are installed into on this OS, for all users & the
+/// current user.
+fn system_font_dirs() -> Vec<PathBuf> {
+    let home = env::var_os("HOME").map(PathBuf::from);
+    let mut dirs = Vec::new();
+    if cfg!(target_os = "windows") {
+        let windows_dir =
+            env::var_os("WINDIR").unwrap_or_else(|| "C:\\Windows".into());
+        dirs.push(Path::new(&windows_dir).join("Fonts"));
+        if let Some(local_app_data) = env::var_os("LOCALAPPDATA") {
+            dirs.push(
+                Path::new(&local_app_data).join("Microsoft\\Windows\\Fonts"),
+            );
+        }
+    } else if cfg!(target_os = "macos") {
+        dirs.extend(
+            [
+                "/System/Library/Fonts",
+                "/Library/Fonts",
+                "/Network/Library/Fonts",
+            ]
+            .map(PathBuf::from),
+        );
+        dirs.extend(home.map(|home| home.join("Library/Fonts")));
+    } else {
+        dirs.extend(
+            ["/usr/share/fonts", "/usr/local/share/fonts"].map(PathBuf::from),
+        );
+        let data_home = env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".local/share")));
+        dirs.extend(data_home.map(|data_home| data_home.join("fonts")));
+        dirs.extend(home.map(|home| home.join(".fonts")));
+    }
+    dirs
+}
+
+/// Find the font files in `dir` and its subdirectories, skipping anything
+/// that can't be read.
+fn find_fonts(dir: &Path, fonts: &mut BTreeSet<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            debug!("not scanning {}: {err}", dir.display());
+            return;
+        },
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_fonts(&path, fonts);
+            continue;
+        }
+        let extension = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("ttf" | "otf") => {
+                // Distributions often symlink the same font into several
+                // directories
+                fonts.insert(fs::canonicalize(&path).unwrap_or(path));
+            },
+            Some("ttc" | "otc") => {
+                debug!("skipping font collection {}", path.display());
+            },
+            _ => {},
+        }
+    }
+}
+
+/// The most extreme word found in a font, beyond its Win metrics.
+#[derive(Debug)]
+struct ExceedingWord {
+    word: String,
+    word_list: String,
+    extreme: f64,
+}
+
+impl ExceedingWord {
+    fn to_json(&self, units: Units) -> Value {
+        json!({
+            "word": self.word,
+            "word_list": self.word_list,
+            "extreme": units.convert(self.extreme),
+        })
+    }
+}
+
+/// A font with words reaching beyond its Win metrics.
+#[derive(Debug)]
+struct ExceedingFont {
+    path: PathBuf,
+    win_metrics: VerticalExtremes,
+    units: Units,
+    highest: Option<ExceedingWord>,
+    lowest: Option<ExceedingWord>,
+}
+
+impl ExceedingFont {
+    fn to_json(&self) -> Value {
+        let units = self.units;
+        json!({
+            "path": self.path.display().to_string(),
+            "win_ascent": units.convert(self.win_metrics.highest()),
+            "win_descent": units.convert(-self.win_metrics.lowest()),
+            "highest": self.highest.as_ref().map(|word| word.to_json(units)),
+            "lowest": self.lowest.as_ref().map(|word| word.to_json(units)),
+        })
+    }
+}
+
+/// Check a font at its default location, returning the most extreme words
+/// beyond its Win metrics, if any.
+fn scan_font(
+    path: &Path,
+    args: &Args,
+) -> anyhow::Result<Option<ExceedingFont>> {
+    let font_bytes = fs::read(path).context("failed to read font file")?;
+    // Words are measured by control boxes with --fast, so exact bounds are
+    // never drawn
+    let reporter = Reporter::new(&font_bytes)?.with_on_demand_glyphs(args.fast);
+    let win_metrics = reporter
+        .clip_box(ClippingProfile::Windows)
+        .context("failed to read OS/2")?;
+    let units = args.units.for_font(
+        reporter
+            .fontref()
+            .head()
+            .context("failed to read head")?
+            .units_per_em(),
+    );
+    let instance = reporter.default_instance()?;
+
+    let options = AnalysisOptions::new()
+        .with_words_limit(Some(args.words_per_list))
+        .with_exemplars(1)
+        .with_measurement(if args.fast {
+            Measurement::ControlBoxes
+        } else {
+            Measurement::Instance
+        });
+    let mut highest = None::<ExceedingWord>;
+    let mut lowest = None::<ExceedingWord>;
+    for word_list in ALL_WORD_LISTS
+        .iter()
+        .filter(|word_list| reporter.supports_word_list(word_list))
+    {
+        let report = instance.check(word_list, &options)?;
+        if let Some(exemplar) = report.exemplars.highest().first() {
+            let extreme = exemplar.extremes.highest();
+            if extreme > win_metrics.highest()
+                && highest.as_ref().is_none_or(|word| extreme > word.extreme)
+            {
+                highest = Some(ExceedingWord {
+                    word: exemplar.word.to_owned(),
+                    word_list: word_list.id().to_string(),
+                    extreme,
+                });
+            }
+        }
+        if let Some(exemplar) = report.exemplars.lowest().first() {
+            let extreme = exemplar.extremes.lowest();
+            if extreme < win_metrics.lowest()
+                && lowest.as_ref().is_none_or(|word| extreme < word.extreme)
+            {
+                lowest = Some(ExceedingWord {
+                    word: exemplar.word.to_owned(),
+                    word_list: word_list.id().to_string(),
+                    extreme,
+                });
+            }
+        }
+    }
+
+    Ok(
+        (highest.is_some() || lowest.is_some()).then(|| ExceedingFont {
+            path: path.to_owned(),
+            win_metrics,
+            units,
+            highest,
+            lowest,
+        }),
+    )
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut font_paths = BTreeSet::new();
+    system_font_dirs()
+        .iter()
+        .chain(&args.extra_dirs)
+        .for_each(|dir| find_fonts(dir, &mut font_paths));
+    info!(
+        "scanning {} fonts with {} words from each word list",
+        font_paths.len(),
+        args.words_per_list,
+    );
+
+    let results = font_paths
+        .par_iter()
+        .map(|path| {
+            let result = scan_font(path, &args);
+            if let Err(err) = &result {
+                warn!("failed to check {}: {err:#}", path.display());
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+    let failures = results.iter().filter(|result| result.is_err()).count();
+    let exceeding = results
+        .into_iter()
+        .filter_map(Result::ok)
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let words = args.words.style();
+    let mut output = stdout().lock();
+    match args.format {
+        OutputFormat::Human => exceeding.iter().try_for_each(|font| {
+            let units = font.units;
+            writeln!(output, "{}:", font.path.display())?;
+            if let Some(word) = &font.highest {
+                writeln!(
+                    output,
+                    "  {} ({}) reaches {}, above usWinAscent ({})",
+                    words.display(&word.word),
+                    word.word_list,
+                    units.convert(word.extreme),
+                    units.convert(font.win_metrics.highest()),
+                )?;
+            }
+            if let Some(word) = &font.lowest {
+                writeln!(
+                    output,
+                    "  {} ({}) reaches {}, below -usWinDescent ({})",
+                    words.display(&word.word),
+                    word.word_list,
+                    units.convert(word.extreme),
+                    units.convert(font.win_metrics.lowest()),
+                )?;
+            }
+            Ok(())
+        }),
+        OutputFormat::Json | OutputFormat::Cbor => {
+            let exceeding = exceeding
+                .iter()
+                .map(ExceedingFont::to_json)
+                .collect::<Vec<_>>();
+            let document = json!({
+                "scanned": font_paths.len(),
+                "failed": failures,
+                "exceeding": exceeding,
+            });
+            write_document(&mut output, args.format, &document)
+        },
+    }
+    .context("failed to write to output")?;
+
+    if failures > 0 {
+        warn!("{failures} fonts couldn't be checked");
+    }
+    if !exceeding.is_empty() {
+        bail!(
+            "{} of {} fonts have words reaching beyond their Win metrics",
+            exceeding.len(),
+            font_paths.len(),
+        );
+    }
+    Ok(())
+}
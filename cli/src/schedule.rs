@@ -0,0 +1,116 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use anyhow::{Context, bail};
+use fontheight::WordList;
+
+/// Word lists to check before the others with `--prioritize`.
+#[derive(Debug, Clone)]
+pub enum Priority {
+    /// Word lists in a script, by its ISO 15924 code (e.g. `script=Thai`)
+    Script(String),
+    /// Word lists in a language (e.g. `language=fa`)
+    Language(String),
+    /// A word list, by its ID or name (e.g. `word-list=diffenator_latin`)
+    WordList(String),
+}
+
+impl Priority {
+    fn matches(&self, word_list: &WordList) -> bool {
+        match self {
+            Priority::Script(script) => word_list
+                .script()
+                .is_some_and(|other| other.eq_ignore_ascii_case(script)),
+            Priority::Language(language) => word_list
+                .language()
+                .is_some_and(|other| other.eq_ignore_ascii_case(language)),
+            Priority::WordList(name) => {
+                word_list.name() == name || word_list.id().to_string() == *name
+            },
+        }
+    }
+
+    /// Where `word_list` should be checked among the others: the index of the
+    /// first priority it matches, or after all of them if it matches none.
+    pub fn rank(priorities: &[Priority], word_list: &WordList) -> usize {
+        priorities
+            .iter()
+            .position(|priority| priority.matches(word_list))
+            .unwrap_or(priorities.len())
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Priority::Script(script) => write!(f, "script={script}"),
+            Priority::Language(language) => write!(f, "language={language}"),
+            Priority::WordList(name) => write!(f, "word-list={name}"),
+        }
+    }
+}
+
+impl FromStr for Priority {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = s
+            .split_once('=')
+            .context("expected KIND=VALUE, e.g. script=Thai")?;
+        if value.is_empty() {
+            bail!("expected a value after {kind}=");
+        }
+        let value = value.to_owned();
+        Ok(match kind {
+            "script" => Priority::Script(value),
+            "language" => Priority::Language(value),
+            "word-list" => Priority::WordList(value),
+            _ => bail!(
+                "unknown priority {kind:?}, expected script, language or \
+                 word-list"
+            ),
+        })
+    }
+}
+
+/// Parse a duration in seconds, minutes or hours (e.g. `90s`, `10m`, `1.5h`),
+/// defaulting to seconds.
+pub fn parse_duration(s: &str) -> anyhow::Result<Duration> {
+    let (number, scale) = match s.strip_suffix('h') {
+        Some(hours) => (hours, 3600.0),
+        None => match s.strip_suffix('m') {
+            Some(minutes) => (minutes, 60.0),
+            None => (s.strip_suffix('s').unwrap_or(s), 1.0),
+        },
+    };
+    let number = number
+        .parse::<f64>()
+        .with_context(|| format!("invalid duration {s:?}, e.g. 90s or 10m"))?;
+    Duration::try_from_secs_f64(number * scale)
+        .with_context(|| format!("invalid duration {s:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("10m").unwrap(), Duration::from_secs(600));
+        assert_eq!(parse_duration("1.5h").unwrap(), Duration::from_secs(5400));
+        assert!(parse_duration("-1m").is_err());
+        assert!(parse_duration("soon").is_err());
+    }
+
+    #[test]
+    fn ranks_word_lists() {
+        let priorities = ["script=arab", "word-list=other"]
+            .map(|priority| priority.parse::<Priority>().unwrap());
+        let other = WordList::define("other", ["a"]);
+        let unmatched = WordList::define("unmatched", ["a"]);
+        assert_eq!(Priority::rank(&priorities, &other), 1);
+        assert_eq!(Priority::rank(&priorities, &unmatched), 2);
+        assert!("colour=blue".parse::<Priority>().is_err());
+    }
+}
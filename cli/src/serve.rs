@@ -0,0 +1,445 @@
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use fontheight::{
+    AnalysisOptions, AnalysisSession, CancellationToken, InstanceReporter,
+    WordList,
+};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::{
+    Method, StatusCode,
+    body::{Body, Bytes},
+    header::CONTENT_TYPE,
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use log::{debug, info, warn};
+use serde_json::{Value, json};
+use skrifa::raw::TableProvider;
+use static_lang_word_lists::{ALL_WORD_LISTS, WordListRegistry};
+use tokio::{net::TcpListener, runtime, sync::Semaphore, task, time};
+
+use crate::fmt::{
+    json::{axes_to_json, report_to_json, word_list_to_json},
+    units::Units,
+};
+
+/// Serve checks over HTTP, so fonts can be checked repeatedly without
+/// starting a new process (and decompressing word lists again) each time,
+/// e.g. from a font editor's QA panel
+///
+/// Routes:
+///   GET  /wordlists  the word lists available, as JSON
+///   POST /check      check the font sent as the request body, returning a
+///                    JSON report like `fontheight --format json`
+///
+/// /check takes its options in the query string: `words` & `results` (as -k
+/// & -n), `distinct=true` (as --distinct), `punctuation=true` (as
+/// --punctuation), `default_only=true` to only check the default location, and
+/// `word_list=<ID or name>` (repeatable) to choose the word lists rather than
+/// checking those the font supports. Word lists given with --word-list can be
+/// chosen by name too, overriding built-in word lists of the same name. Word
+/// lists stay decompressed in memory
+/// once a request has needed them, and the most recently checked fonts stay
+/// parsed (with their glyphs drawn at each location checked). Checks taking
+/// longer than 30 seconds are cancelled, responding 503
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The address to listen on
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    listen: SocketAddr,
+
+    /// The largest font accepted, in MiB
+    #[arg(long, value_name = "MIB", default_value_t = 64)]
+    max_font_size: usize,
+
+    /// How many of the most recently checked fonts to keep parsed, so
+    /// checking them again is faster
+    #[arg(long, value_name = "FONTS", default_value_t = AnalysisSession::DEFAULT_MAX_FONTS)]
+    keep_fonts: usize,
+
+    /// How many connections to serve at once. Further connections wait to be
+    /// accepted until one is done
+    #[arg(long, value_name = "CONNECTIONS", default_value = "8")]
+    max_connections: NonZeroUsize,
+
+    /// Serve a word list file, one word per line, alongside the built-in
+    /// word lists (see `fontheight --word-list`)
+    #[arg(long = "word-list", value_name = "PATH")]
+    word_list_paths: Vec<PathBuf>,
+}
+
+/// What's shared between the connections being served.
+#[derive(Debug)]
+struct State {
+    session: AnalysisSession,
+    word_lists: WordListRegistry,
+    max_body: usize,
+}
+
+/// How long a connection may take to send its request's headers, and then
+/// its body
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a check may take before it's cancelled
+const CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The longest a connection may stay open, e.g. while receiving its response
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The most a request's line & headers may take up, in bytes
+const MAX_HEADER_SIZE: usize = 64 * 1024;
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let mut word_lists = WordListRegistry::new();
+    for path in &args.word_list_paths {
+        word_lists.register(crate::load_word_list(path)?);
+    }
+    let state = Arc::new(State {
+        session: AnalysisSession::new().with_max_fonts(args.keep_fonts),
+        word_lists,
+        max_body: args.max_font_size.saturating_mul(1024 * 1024),
+    });
+    // Connections only wait on the network here, checks are run on blocking
+    // threads
+    runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("failed to start the server")?
+        .block_on(serve(args.listen, args.max_connections, state))
+}
+
+async fn serve(
+    listen: SocketAddr,
+    max_connections: NonZeroUsize,
+    state: Arc<State>,
+) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(listen)
+        .await
+        .with_context(|| format!("failed to listen on {listen}"))?;
+    info!("serving checks on http://{listen}");
+    // Connections are only accepted once one of the permits is free
+    let permits = Arc::new(Semaphore::new(max_connections.get()));
+    loop {
+        let permit = Arc::clone(&permits)
+            .acquire_owned()
+            .await
+            .expect("connection permits are never closed");
+        let stream = match listener.accept().await {
+            Ok((stream, _address)) => stream,
+            Err(err) => {
+                warn!("failed to accept connection: {err}");
+                continue;
+            },
+        };
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            let service = service_fn(|request| {
+                handle_request(request, Arc::clone(&state))
+            });
+            let connection = http1::Builder::new()
+                .timer(TokioTimer::new())
+                .header_read_timeout(REQUEST_TIMEOUT)
+                .max_header_size(MAX_HEADER_SIZE)
+                .keep_alive(false)
+                .serve_connection(TokioIo::new(stream), service);
+            match time::timeout(CONNECTION_TIMEOUT, connection).await {
+                Ok(Ok(())) => {},
+                Ok(Err(err)) => debug!("connection failed: {err}"),
+                Err(_elapsed) => debug!("connection took too long"),
+            }
+            drop(permit);
+        });
+    }
+}
+
+/// A response's status code, and its JSON body.
+type Response = (StatusCode, Value);
+
+async fn handle_request<B>(
+    request: hyper::Request<B>,
+    state: Arc<State>,
+) -> Result<hyper::Response<Full<Bytes>>, Infallible>
+where
+    B: Body<Data = Bytes, Error: std::error::Error + Send + Sync + 'static>,
+{
+    let start = Instant::now();
+    let method = request.method().clone();
+    let uri = request.uri().clone();
+    let (status, body) = route(request, state).await;
+    info!(
+        "{method} {uri} => {} in {:.1?}",
+        status.as_u16(),
+        start.elapsed(),
+    );
+    let response = hyper::Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(body.to_string())))
+        .expect("response is valid");
+    Ok(response)
+}
+
+fn error_response(status: StatusCode, message: impl ToString) -> Response {
+    (status, json!({ "error": message.to_string() }))
+}
+
+/// A request's query string, decoded, in the order given.
+#[derive(Debug)]
+struct Query(Vec<(String, String)>);
+
+impl Query {
+    fn parse(query: &str) -> Self {
+        Query(
+            form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+        )
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn params<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.0
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+async fn route<B>(request: hyper::Request<B>, state: Arc<State>) -> Response
+where
+    B: Body<Data = Bytes, Error: std::error::Error + Send + Sync + 'static>,
+{
+    match (request.method(), request.uri().path()) {
+        (&Method::GET, "/wordlists") => (
+            StatusCode::OK,
+            json!(
+                state
+                    .word_lists
+                    .list()
+                    .map(word_list_to_json)
+                    .collect::<Vec<_>>()
+            ),
+        ),
+        (&Method::POST, "/check") => {
+            let query = Query::parse(request.uri().query().unwrap_or(""));
+            let font =
+                match read_font(request.into_body(), state.max_body).await {
+                    Ok(font) => font,
+                    Err(response) => return response,
+                };
+            let cancellation = CancellationToken::new();
+            let check = task::spawn_blocking({
+                let cancellation = cancellation.clone();
+                let state = Arc::clone(&state);
+                move || check(&query, &font, &state, &cancellation)
+            });
+            match time::timeout(CHECK_TIMEOUT, check).await {
+                Ok(Ok(Ok(report))) => (StatusCode::OK, report),
+                Ok(Ok(Err(err))) => error_response(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!("{err:#}"),
+                ),
+                Ok(Err(err)) => error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("check failed: {err}"),
+                ),
+                Err(_elapsed) => {
+                    // The check stops soon after, between words
+                    cancellation.cancel();
+                    error_response(
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        format!("check took longer than {CHECK_TIMEOUT:?}"),
+                    )
+                },
+            }
+        },
+        (_, "/wordlists" | "/check") => {
+            error_response(StatusCode::METHOD_NOT_ALLOWED, "method not allowed")
+        },
+        _ => error_response(StatusCode::NOT_FOUND, "no such route"),
+    }
+}
+
+/// Read the font sent as the request's body, as long as it's no larger than
+/// `max_body` and arrives within [`REQUEST_TIMEOUT`].
+async fn read_font<B>(body: B, max_body: usize) -> Result<Bytes, Response>
+where
+    B: Body<Data = Bytes, Error: std::error::Error + Send + Sync + 'static>,
+{
+    let too_large =
+        || error_response(StatusCode::PAYLOAD_TOO_LARGE, "font is too large");
+    // Refuse before reading anything (or telling the client to continue) if
+    // the body's length is known
+    if body.size_hint().lower() > max_body as u64 {
+        return Err(too_large());
+    }
+    let body = Limited::new(body, max_body).collect();
+    match time::timeout(REQUEST_TIMEOUT, body).await {
+        Ok(Ok(body)) => Ok(body.to_bytes()),
+        Ok(Err(err)) if err.is::<LengthLimitError>() => Err(too_large()),
+        Ok(Err(err)) => Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("failed to read font: {err}"),
+        )),
+        Err(_elapsed) => Err(error_response(
+            StatusCode::REQUEST_TIMEOUT,
+            "font took too long to arrive",
+        )),
+    }
+}
+
+/// Check `font`, as configured by the request's query string, until
+/// `cancellation` is cancelled.
+fn check(
+    query: &Query,
+    font: &[u8],
+    state: &State,
+    cancellation: &CancellationToken,
+) -> anyhow::Result<Value> {
+    let number = |key| {
+        query
+            .param(key)
+            .map(|value| {
+                value
+                    .parse::<usize>()
+                    .with_context(|| format!("{key} must be a number"))
+            })
+            .transpose()
+    };
+    let flag = |key| query.param(key).is_some_and(|value| value == "true");
+    let options = AnalysisOptions::new()
+        .with_words_limit(number("words")?)
+        .with_exemplars(
+            number("results")?.unwrap_or(AnalysisOptions::DEFAULT_EXEMPLARS),
+        )
+        .with_distinct(flag("distinct"))
+        .with_punctuation(flag("punctuation"));
+
+    let font = state.session.font(font)?;
+    let reporter = font.reporter();
+    let word_list_ids = query.params("word_list").collect::<Vec<_>>();
+    let word_lists = if word_list_ids.is_empty() {
+        // As when checking from the command line, word lists given with
+        // --word-list are always checked
+        state
+            .word_lists
+            .registered()
+            .iter()
+            .chain(ALL_WORD_LISTS.iter().copied().filter(|builtin| {
+                !state.word_lists.is_overridden(builtin)
+                    && reporter.supports_word_list(builtin)
+            }))
+            .collect::<Vec<&WordList>>()
+    } else {
+        word_list_ids
+            .iter()
+            .map(|id| {
+                state
+                    .word_lists
+                    .lookup(id)
+                    .with_context(|| format!("no word list called {id}"))
+            })
+            .collect::<anyhow::Result<_>>()?
+    };
+    let locations = if flag("default_only") {
+        Vec::new()
+    } else {
+        reporter.interesting_locations()
+    };
+
+    let units = Units::EXACT;
+    let mut reports = Vec::new();
+    let mut check_instance = |instance: InstanceReporter| {
+        word_lists.iter().try_for_each(|word_list| {
+            let report = instance.check_cancellable(
+                word_list,
+                &options,
+                cancellation,
+            )?;
+            reports.push(report_to_json(&report, units));
+            anyhow::Ok(())
+        })
+    };
+    if locations.is_empty() {
+        check_instance(reporter.default_instance()?)?;
+    }
+    for location in &locations {
+        check_instance(reporter.instance(location)?)?;
+    }
+    let units_per_em = reporter
+        .fontref()
+        .head()
+        .context("failed to read head")?
+        .units_per_em();
+    Ok(json!({
+        "units_per_em": units_per_em,
+        "checksum": format!("{:08x}", font.checksum()),
+        "axes": axes_to_json(&reporter.axes()),
+        "reports": reports,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_queries() {
+        let query = Query::parse("word_list=a%2Fb&distinct=true&x=1+2&y=%+f");
+        assert_eq!(query.params("word_list").collect::<Vec<_>>(), ["a/b"]);
+        assert_eq!(query.param("distinct"), Some("true"));
+        assert_eq!(query.param("x"), Some("1 2"));
+        assert_eq!(query.param("y"), Some("% f"));
+        assert_eq!(query.param("z"), None);
+    }
+
+    #[test]
+    fn routes_requests() {
+        let state = Arc::new(State {
+            session: AnalysisSession::new(),
+            word_lists: WordListRegistry::new(),
+            max_body: 4,
+        });
+        let runtime = runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let status = |method, uri, body: &'static str| {
+            let request = hyper::Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Full::new(Bytes::from(body)))
+                .unwrap();
+            runtime.block_on(route(request, Arc::clone(&state))).0
+        };
+        assert_eq!(
+            status(Method::POST, "/check", "not a font"),
+            StatusCode::PAYLOAD_TOO_LARGE,
+        );
+        assert_eq!(
+            status(Method::POST, "/check?distinct=true", "font"),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        );
+        assert_eq!(
+            status(Method::GET, "/check", ""),
+            StatusCode::METHOD_NOT_ALLOWED,
+        );
+        assert_eq!(status(Method::GET, "/nope", ""), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,124 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use anyhow::{Context, bail};
+
+/// The code points a font is subset to, e.g. by a webfont pipeline.
+///
+/// Parsed from a CSS `unicode-range` (e.g. `U+0000-00FF, U+0131, U+4??`), or
+/// a list of code points separated by commas or whitespace, with or without
+/// the `U+` prefix (e.g. `0041 0042 00E9`).
+#[derive(Debug, Clone)]
+pub struct Subset {
+    ranges: Vec<RangeInclusive<u32>>,
+}
+
+impl Subset {
+    /// Whether every character of `word` is in the subset.
+    pub fn encodes(&self, word: &str) -> bool {
+        word.chars().all(|c| {
+            let c = u32::from(c);
+            self.ranges.iter().any(|range| range.contains(&c))
+        })
+    }
+}
+
+fn parse_code_point(hex: &str) -> anyhow::Result<u32> {
+    if hex.is_empty() || hex.len() > 6 {
+        bail!("expected 1 to 6 hex digits, got {hex:?}");
+    }
+    let code_point = u32::from_str_radix(hex, 16)
+        .with_context(|| format!("invalid code point {hex:?}"))?;
+    if code_point > u32::from(char::MAX) {
+        bail!("U+{code_point:04X} is beyond the last code point, U+10FFFF");
+    }
+    Ok(code_point)
+}
+
+impl FromStr for Subset {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ranges = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                let hex = item
+                    .strip_prefix("U+")
+                    .or_else(|| item.strip_prefix("u+"))
+                    .unwrap_or(item);
+                let range = if let Some((start, end)) = hex.split_once('-') {
+                    parse_code_point(start)?..=parse_code_point(end)?
+                } else if hex.contains('?') {
+                    // Wildcards can only trail, e.g. U+4??
+                    let digits = hex.trim_end_matches('?');
+                    if digits.contains('?') {
+                        bail!("wildcards must be at the end of {item:?}");
+                    }
+                    let start = parse_code_point(&hex.replace('?', "0"))?;
+                    let wildcards = (hex.len() - digits.len()) as u32;
+                    let end = start + (16u32.pow(wildcards) - 1);
+                    start..=end.min(u32::from(char::MAX))
+                } else {
+                    let code_point = parse_code_point(hex)?;
+                    code_point..=code_point
+                };
+                if range.is_empty() {
+                    bail!("{item:?} ends before it starts");
+                }
+                Ok(range)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if ranges.is_empty() {
+            bail!("expected at least one code point or range");
+        }
+        Ok(Subset { ranges })
+    }
+}
+
+impl fmt::Display for Subset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ranges
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, range)| {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "U+{:04X}", range.start())?;
+                if range.start() != range.end() {
+                    write!(f, "-{:04X}", range.end())?;
+                }
+                Ok(())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unicode_range() {
+        let subset = "U+0000-00FF, U+0131,U+4??".parse::<Subset>().unwrap();
+        assert_eq!(subset.ranges, [0x0..=0xFF, 0x131..=0x131, 0x400..=0x4FF]);
+        assert_eq!(subset.to_string(), "U+0000-00FF, U+0131, U+0400-04FF");
+
+        let subset = "0041 0042\n00e9".parse::<Subset>().unwrap();
+        assert_eq!(subset.ranges, [0x41..=0x41, 0x42..=0x42, 0xE9..=0xE9]);
+
+        assert!("".parse::<Subset>().is_err());
+        assert!("U+00FF-0000".parse::<Subset>().is_err());
+        assert!("U+?4".parse::<Subset>().is_err());
+        assert!("U+110000".parse::<Subset>().is_err());
+        assert_eq!("U+1?????".parse::<Subset>().unwrap().ranges, [
+            0x100000..=0x10FFFF
+        ]);
+    }
+
+    #[test]
+    fn encodes_words() {
+        let subset = "U+0000-00FF".parse::<Subset>().unwrap();
+        assert!(subset.encodes("café"));
+        assert!(!subset.encodes("ıi"));
+    }
+}
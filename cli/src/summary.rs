@@ -0,0 +1,196 @@
+use std::{io, io::Write, path::Path};
+
+use anyhow::Context;
+use fontheight::{
+    ClippingProfile, Report, Reporter, VerticalExtremes, WordExtremes, WordList,
+};
+
+use crate::{
+    fmt::{units::Units, words::WordStyle},
+    glyph_bounds::csv_field,
+};
+
+/// How to write the line summarising each font with `--summary`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum SummaryFormat {
+    /// A line of text per font
+    #[default]
+    Text,
+    /// A row per font, with a header
+    Csv,
+}
+
+impl SummaryFormat {
+    /// Write the header of the summaries, if the format has one.
+    pub fn write_header(self, output: &mut impl Write) -> io::Result<()> {
+        match self {
+            SummaryFormat::Text => Ok(()),
+            SummaryFormat::Csv => writeln!(
+                output,
+                "font,highest,highest_word,highest_word_list,lowest,\
+                 lowest_word,lowest_word_list,exceeds_win,exceeds_typo"
+            ),
+        }
+    }
+}
+
+/// Which way words reach beyond a pair of vertical metrics.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Exceeds {
+    No,
+    Above,
+    Below,
+    Both,
+}
+
+impl Exceeds {
+    fn of(metrics: VerticalExtremes, extremes: VerticalExtremes) -> Self {
+        match (
+            extremes.highest() > metrics.highest(),
+            extremes.lowest() < metrics.lowest(),
+        ) {
+            (false, false) => Exceeds::No,
+            (true, false) => Exceeds::Above,
+            (false, true) => Exceeds::Below,
+            (true, true) => Exceeds::Both,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Exceeds::No => "no",
+            Exceeds::Above => "above",
+            Exceeds::Below => "below",
+            Exceeds::Both => "both",
+        }
+    }
+}
+
+/// A font's results in a single line: the highest & lowest words at any
+/// location, and whether they reach beyond the OS/2 win & typo metrics.
+#[derive(Debug)]
+pub struct FontSummary<'a> {
+    font: String,
+    highest: Option<(WordExtremes<'a>, &'a WordList)>,
+    lowest: Option<(WordExtremes<'a>, &'a WordList)>,
+    win: VerticalExtremes,
+    typo: VerticalExtremes,
+    /// The units to write the extremes in
+    pub units: Units,
+    /// How to write the words in text summaries
+    pub words: WordStyle,
+}
+
+pub fn summarise_font<'a>(
+    font_path: &Path,
+    reporter: &Reporter,
+    reports: &'a [Report<'a>],
+    units: Units,
+    words: WordStyle,
+) -> anyhow::Result<FontSummary<'a>> {
+    let exemplars = || {
+        reports.iter().flat_map(|report| {
+            report
+                .exemplars
+                .highest()
+                .iter()
+                .chain(report.exemplars.lowest())
+                .map(|&word_extremes| (word_extremes, report.word_list))
+        })
+    };
+    let metrics = |profile: ClippingProfile| {
+        reporter
+            .clip_box(profile)
+            .with_context(|| format!("failed to read the {profile} metrics"))
+    };
+    Ok(FontSummary {
+        font: font_path.display().to_string(),
+        highest: exemplars()
+            .max_by(|(a, _), (b, _)| a.highest().total_cmp(&b.highest())),
+        lowest: exemplars()
+            .min_by(|(a, _), (b, _)| a.lowest().total_cmp(&b.lowest())),
+        win: metrics(ClippingProfile::Windows)?,
+        // Android clips to the typo metrics
+        typo: metrics(ClippingProfile::Android)?,
+        units,
+        words,
+    })
+}
+
+impl FontSummary<'_> {
+    /// The extremes reached by the highest & lowest words, if any were
+    /// measured.
+    fn extremes(&self) -> Option<VerticalExtremes> {
+        let (highest, _) = self.highest?;
+        let (lowest, _) = self.lowest?;
+        Some(VerticalExtremes::new(lowest.lowest(), highest.highest()))
+    }
+
+    pub fn write(
+        &self,
+        output: &mut impl Write,
+        format: SummaryFormat,
+    ) -> io::Result<()> {
+        let exceeds = |metrics| {
+            self.extremes()
+                .map_or("", |extremes| Exceeds::of(metrics, extremes).name())
+        };
+        match format {
+            SummaryFormat::Text => {
+                let (
+                    Some((highest, highest_list)),
+                    Some((lowest, lowest_list)),
+                ) = (self.highest, self.lowest)
+                else {
+                    return writeln!(
+                        output,
+                        "{}: no words measured",
+                        self.font
+                    );
+                };
+                writeln!(
+                    output,
+                    "{}: highest {} {} ({}), lowest {} {} ({}), exceeds win: \
+                     {}, exceeds typo: {}",
+                    self.font,
+                    self.units.convert(highest.highest()),
+                    self.words.display(highest.word),
+                    highest_list.id(),
+                    self.units.convert(lowest.lowest()),
+                    self.words.display(lowest.word),
+                    lowest_list.id(),
+                    exceeds(self.win),
+                    exceeds(self.typo),
+                )
+            },
+            SummaryFormat::Csv => {
+                let word = |exemplar: Option<(f64, &str, &WordList)>| {
+                    exemplar.map_or_else(
+                        || String::from(",,"),
+                        |(extreme, word, word_list)| {
+                            format!(
+                                "{},{},{}",
+                                self.units.convert(extreme),
+                                csv_field(word),
+                                csv_field(&word_list.id().to_string()),
+                            )
+                        },
+                    )
+                };
+                writeln!(
+                    output,
+                    "{},{},{},{},{}",
+                    csv_field(&self.font),
+                    word(self.highest.map(|(highest, word_list)| {
+                        (highest.highest(), highest.word, word_list)
+                    })),
+                    word(self.lowest.map(|(lowest, word_list)| {
+                        (lowest.lowest(), lowest.word, word_list)
+                    })),
+                    exceeds(self.win),
+                    exceeds(self.typo),
+                )
+            },
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, bail};
+use fontheight::{ClippingProfile, Report, Reporter, VerticalExtremes};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use skrifa::{MetadataProvider, raw::TableProvider, string::StringId};
+
+use crate::clipping::is_clipped;
+
+/// Print how each font's extremes & clipped exemplars changed between the runs
+/// recorded by --append-history, flagging regressions
+///
+/// A regression is a script reaching further than in the previous run of the
+/// same font (by more than --tolerance), or more exemplars being clipped on a
+/// platform. Fails if the latest run of any font regressed
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The history file, as written by --append-history
+    history_path: PathBuf,
+
+    /// Only show the last N runs of each font
+    #[arg(long, value_name = "N")]
+    last: Option<usize>,
+
+    /// How much further a script's extremes can reach than in the previous run
+    /// without being a regression, in font units
+    #[arg(long, default_value_t = 0.0)]
+    tolerance: f64,
+}
+
+/// A compact summary of the check of a font, recorded in the history: how high
+/// & low each script reached, and how many exemplars each platform clips, in
+/// font units.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FontSummary {
+    font: String,
+    checksum: String,
+    version: String,
+    scripts: BTreeMap<String, VerticalExtremes>,
+    clipped: BTreeMap<String, usize>,
+}
+
+/// A line of the history: the summaries of each font checked in a run.
+#[derive(Debug, Serialize, Deserialize)]
+struct Run {
+    time: u64,
+    fonts: Vec<FontSummary>,
+}
+
+pub fn summarise_font(
+    font_path: &Path,
+    checksum: String,
+    reporter: &Reporter,
+    reports: &[Report],
+) -> anyhow::Result<FontSummary> {
+    let font = reporter.fontref();
+    let version = match font
+        .localized_strings(StringId::VERSION_STRING)
+        .english_or_first()
+    {
+        Some(version) => version.to_string(),
+        None => format!(
+            "{:.3}",
+            font.head()
+                .context("failed to read head")?
+                .font_revision()
+                .to_f64()
+        ),
+    };
+
+    let mut scripts = BTreeMap::<String, VerticalExtremes>::new();
+    reports.iter().for_each(|report| {
+        let extremes = report
+            .exemplars
+            .highest()
+            .iter()
+            .chain(report.exemplars.lowest())
+            .map(|word_extremes| word_extremes.extremes)
+            .reduce(VerticalExtremes::merge);
+        if let Some(extremes) = extremes {
+            scripts
+                .entry(
+                    report.word_list.script().unwrap_or("unknown").to_owned(),
+                )
+                .and_modify(|merged| *merged = merged.merge(extremes))
+                .or_insert(extremes);
+        }
+    });
+
+    let clipped = ClippingProfile::ALL
+        .iter()
+        .map(|&profile| {
+            let clip_box = reporter.clip_box(profile).with_context(|| {
+                format!("failed to read the {profile} clipping metrics")
+            })?;
+            let clipped = reports
+                .iter()
+                .map(|report| {
+                    report
+                        .exemplars
+                        .iter()
+                        .filter(|word_extremes| {
+                            is_clipped(clip_box, word_extremes.extremes)
+                        })
+                        .count()
+                })
+                .sum::<usize>();
+            Ok((profile.name().to_owned(), clipped))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(FontSummary {
+        font: font_path.display().to_string(),
+        checksum,
+        version,
+        scripts,
+        clipped,
+    })
+}
+
+/// Append a run, made up of the summaries of each font checked, to the
+/// history at `path` as a single line.
+pub fn append_history(
+    path: &Path,
+    fonts: Vec<FontSummary>,
+) -> anyhow::Result<()> {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("the system clock is before 1970")?
+        .as_secs();
+    let run = serde_json::to_string(&Run { time, fonts })?;
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    writeln!(file, "{run}")
+        .with_context(|| format!("failed to append to {}", path.display()))
+}
+
+/// A font's summary from one run in the history.
+#[derive(Debug)]
+struct FontRun {
+    time: u64,
+    summary: FontSummary,
+}
+
+/// Read every font's runs from the history, in the order they're first
+/// recorded.
+fn read_history(path: &Path) -> anyhow::Result<Vec<(String, Vec<FontRun>)>> {
+    let history = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let mut fonts = Vec::<(String, Vec<FontRun>)>::new();
+    for (index, line) in history.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let run = serde_json::from_str::<Run>(line).with_context(|| {
+            format!("failed to parse line {} of {}", index + 1, path.display())
+        })?;
+        for summary in run.fonts {
+            let font_run = FontRun {
+                time: run.time,
+                summary,
+            };
+            let name = &font_run.summary.font;
+            match fonts.iter_mut().find(|(font, _)| font == name) {
+                Some((_, runs)) => runs.push(font_run),
+                None => fonts.push((name.clone(), vec![font_run])),
+            }
+        }
+    }
+    Ok(fonts)
+}
+
+/// Format a Unix timestamp as a UTC date & time, e.g. `2025-10-31 02:00 UTC`.
+fn format_time(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let minutes = secs % 86_400 / 60;
+    // Howard Hinnant's days_from_civil, in reverse
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        minutes / 60,
+        minutes % 60,
+    )
+}
+
+/// Write `value` with how it changed from `previous`, e.g. `1020 (+12)`.
+fn with_delta(value: f64, previous: Option<f64>) -> String {
+    match previous {
+        Some(previous) if value != previous => {
+            format!("{value} ({:+})", value - previous)
+        },
+        _ => value.to_string(),
+    }
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    let fonts = read_history(&args.history_path)?;
+    if fonts.is_empty() {
+        bail!("{} has no runs recorded", args.history_path.display());
+    }
+
+    let mut output = String::new();
+    let mut regressed_fonts = Vec::new();
+    for (font, runs) in &fonts {
+        writeln!(output, "{font}:")?;
+        let skip = args.last.map_or(0, |last| runs.len().saturating_sub(last));
+        let mut regressed = false;
+        for (index, run) in runs.iter().enumerate().skip(skip) {
+            let previous = index.checked_sub(1).map(|index| &runs[index]);
+            let mut regressions = 0;
+            writeln!(
+                output,
+                "  {}, {} ({})",
+                format_time(run.time),
+                run.summary.version,
+                run.summary.checksum,
+            )?;
+            for (script, extremes) in &run.summary.scripts {
+                let before =
+                    previous.and_then(|run| run.summary.scripts.get(script));
+                let regression = before.is_some_and(|before| {
+                    extremes.highest() - before.highest() > args.tolerance
+                        || before.lowest() - extremes.lowest() > args.tolerance
+                });
+                regressions += usize::from(regression);
+                writeln!(
+                    output,
+                    "    {script}: {} to {}{}{}",
+                    with_delta(
+                        extremes.lowest(),
+                        before.map(VerticalExtremes::lowest)
+                    ),
+                    with_delta(
+                        extremes.highest(),
+                        before.map(VerticalExtremes::highest)
+                    ),
+                    if previous.is_some() && before.is_none() {
+                        " (new)"
+                    } else {
+                        ""
+                    },
+                    if regression { " REGRESSION" } else { "" },
+                )?;
+            }
+            let clipped = run
+                .summary
+                .clipped
+                .iter()
+                .map(|(profile, &count)| {
+                    let before = previous
+                        .and_then(|run| run.summary.clipped.get(profile))
+                        .copied();
+                    let regression =
+                        before.is_some_and(|before| count > before);
+                    regressions += usize::from(regression);
+                    format!(
+                        "{profile} {}{}",
+                        with_delta(count as f64, before.map(|b| b as f64)),
+                        if regression { " REGRESSION" } else { "" },
+                    )
+                })
+                .collect::<Vec<_>>();
+            writeln!(output, "    clipped exemplars: {}", clipped.join(", "))?;
+            // Only the latest run decides whether the font has regressed
+            regressed = regressions > 0;
+        }
+        if regressed {
+            warn!("{font} regressed in its latest run");
+            regressed_fonts.push(font.as_str());
+        }
+    }
+    print!("{output}");
+
+    if !regressed_fonts.is_empty() {
+        bail!(
+            "{} of {} fonts regressed in their latest run: {}",
+            regressed_fonts.len(),
+            fonts.len(),
+            regressed_fonts.join(", "),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_times() {
+        assert_eq!(format_time(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_time(1_761_876_000), "2025-10-31 02:00 UTC");
+        assert_eq!(format_time(951_782_400), "2000-02-29 00:00 UTC");
+    }
+}
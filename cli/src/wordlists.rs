@@ -0,0 +1,151 @@
+use std::{
+    io::{Write, stdout},
+    path::PathBuf,
+};
+
+use anyhow::{Context, bail};
+use serde_json::json;
+use static_lang_word_lists::{WordList, WordListRegistry};
+
+use crate::fmt::{OutputFormat, json::word_list_to_json, write_document};
+
+/// List the word lists built into fontheight, and any given with --word-list
+#[derive(Debug, clap::Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The format to list the word lists in
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// List a word list file, one word per line, alongside the built-in word
+    /// lists, as `fontheight --word-list` & `fontheight serve --word-list`
+    /// would see it
+    #[arg(long = "word-list", value_name = "PATH")]
+    word_list_paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+    Lint(LintArgs),
+}
+
+/// Check word list files for characters that are likely to be corpus
+/// problems, such as BOMs, bidi controls, or decomposed diacritics
+#[derive(Debug, clap::Args)]
+struct LintArgs {
+    /// The word list(s) to check, one word per line
+    #[arg(required = true)]
+    word_list_paths: Vec<PathBuf>,
+
+    /// The format to list the issues in
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+}
+
+pub fn main(args: Args) -> anyhow::Result<()> {
+    match args.command {
+        Some(Command::Lint(args)) => lint(args),
+        None => {
+            let mut word_lists = WordListRegistry::new();
+            for path in &args.word_list_paths {
+                word_lists.register(crate::load_word_list(path)?);
+            }
+            list(&word_lists, args.format)
+        },
+    }
+}
+
+fn list(
+    word_lists: &WordListRegistry,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let mut output = stdout().lock();
+    match format {
+        OutputFormat::Human => word_lists.list().try_for_each(|word_list| {
+            writeln!(
+                &mut output,
+                "{}: {} words (script: {}, language: {}, license: {})",
+                word_list.id(),
+                word_list.len(),
+                word_list.script().map_or_else(
+                    || String::from("-"),
+                    crate::fmt::script_label
+                ),
+                word_list.language().unwrap_or("-"),
+                word_list.license().unwrap_or("-"),
+            )
+        }),
+        OutputFormat::Json | OutputFormat::Cbor => {
+            let word_lists = word_lists
+                .list()
+                .map(|word_list| {
+                    let mut json = json!(word_list_to_json(word_list));
+                    json["words"] = word_list.len().into();
+                    json
+                })
+                .collect::<Vec<_>>();
+            write_document(&mut output, format, &word_lists)
+        },
+    }
+    .context("failed to write to output")
+}
+
+fn lint(args: LintArgs) -> anyhow::Result<()> {
+    let word_lists = args
+        .word_list_paths
+        .iter()
+        .map(|path| {
+            WordList::load_without_metadata(path)
+                .map(|word_list| (path, word_list))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let issues = word_lists
+        .iter()
+        .flat_map(|(path, word_list)| {
+            word_list
+                .validate()
+                .into_iter()
+                .map(move |issue| (path, word_list, issue))
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = stdout().lock();
+    match args.format {
+        OutputFormat::Human => {
+            issues.iter().try_for_each(|(path, word_list, issue)| {
+                writeln!(
+                    &mut output,
+                    "{}: word {} ({:?}) {}",
+                    path.display(),
+                    issue.index,
+                    &word_list[issue.index],
+                    issue.kind,
+                )
+            })
+        },
+        OutputFormat::Json | OutputFormat::Cbor => {
+            let issues = issues
+                .iter()
+                .map(|(path, word_list, issue)| {
+                    json!({
+                        "path": path.display().to_string(),
+                        "index": issue.index,
+                        "word": word_list[issue.index],
+                        "issue": issue.kind.to_string(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            write_document(&mut output, args.format, &issues)
+        },
+    }
+    .context("failed to write to output")?;
+
+    if !issues.is_empty() {
+        bail!("found {} issues in word lists", issues.len());
+    }
+    Ok(())
+}
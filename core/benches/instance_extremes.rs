@@ -0,0 +1,39 @@
+//! Compares building an instance's glyph cache on one thread & on all of them,
+//! with the bundled DejaVu Sans:
+//!
+//! ```sh
+//! cargo bench -p fontheight --bench instance_extremes
+//! ```
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fontheight::{InstanceExtremes, Location, measure::ExactBounds};
+use rayon::ThreadPoolBuilder;
+use skrifa::FontRef;
+
+static FONT: &[u8] = include_bytes!("../tests/fonts/DejaVuSans.ttf");
+
+fn instance_extremes(c: &mut Criterion) {
+    let font = FontRef::new(FONT).expect("failed to parse font");
+    let location = Location::new();
+    let mut group = c.benchmark_group("InstanceExtremes::new");
+    let mut thread_counts = vec![1, rayon::current_num_threads()];
+    thread_counts.dedup();
+    thread_counts.into_iter().for_each(|threads| {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("failed to build thread pool");
+        group.bench_function(BenchmarkId::new("threads", threads), |b| {
+            pool.install(|| {
+                b.iter(|| {
+                    InstanceExtremes::new(&font, &location, &ExactBounds)
+                        .expect("failed to measure glyphs")
+                });
+            });
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, instance_extremes);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Measures shaping throughput, in words per second, serially through
+//! `WordExtremesIterator` & in parallel through `InstanceReporter::par_check`,
+//! and how long measuring the font's glyphs takes, with the bundled DejaVu
+//! Sans:
+//!
+//! ```sh
+//! cargo bench -p fontheight --bench shaping
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use fontheight::{InstanceExtremes, Location, Reporter, measure::ExactBounds};
+use static_lang_word_lists::{
+    DIFFENATOR_ARABIC, DIFFENATOR_CYRILLIC, DIFFENATOR_LATIN,
+};
+
+static FONT: &[u8] = include_bytes!("../tests/fonts/DejaVuSans.ttf");
+// The first this many words of each word list are shaped, to keep runs short
+const WORDS: usize = 10_000;
+
+fn glyph_bounds(c: &mut Criterion) {
+    let reporter = Reporter::new(FONT).expect("failed to parse font");
+    let location = Location::new();
+    c.bench_function("glyph bounds", |b| {
+        b.iter(|| {
+            InstanceExtremes::new(reporter.fontref(), &location, &ExactBounds)
+                .expect("failed to measure glyphs")
+        });
+    });
+}
+
+fn shaping(c: &mut Criterion) {
+    let reporter = Reporter::new(FONT).expect("failed to parse font");
+    let location = Location::new();
+    let instance = reporter
+        .instance(&location)
+        .expect("failed to create instance");
+    [&DIFFENATOR_LATIN, &DIFFENATOR_CYRILLIC, &DIFFENATOR_ARABIC]
+        .into_iter()
+        .for_each(|word_list| {
+            let word_list = word_list.slice(..WORDS.min(word_list.len()));
+            let mut group = c.benchmark_group(word_list.id().to_string());
+            group.throughput(Throughput::Elements(word_list.len() as u64));
+            group.bench_function("WordExtremesIterator", |b| {
+                b.iter(|| {
+                    black_box(
+                        instance
+                            .to_word_extremes_iter(&word_list)
+                            .expect("failed to create shaping plan")
+                            .count(),
+                    )
+                });
+            });
+            group.bench_function("par_check", |b| {
+                b.iter(|| {
+                    instance
+                        .par_check(&word_list, None, 5)
+                        .expect("failed to check word list")
+                });
+            });
+            group.finish();
+        });
+}
+
+criterion_group!(benches, glyph_bounds, shaping);
+criterion_main!(benches);
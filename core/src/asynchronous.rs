@@ -0,0 +1,215 @@
+use std::{
+    borrow::Borrow,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, LazyLock, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::{
+    AnalysisOptions, CancellationToken, Location, Reporter, WordList,
+    errors::FontHeightError, owned::OwnedReport,
+};
+
+// Kept separate from rayon's global pool so async callers can't starve (or be
+// starved by) any other rayon users in the same process
+static ASYNC_POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
+    ThreadPoolBuilder::new()
+        .thread_name(|index| format!("fontheight-async-{index}"))
+        .build()
+        .expect("failed to build fontheight's async thread pool")
+});
+
+#[derive(Debug, Default)]
+struct Shared {
+    result: Option<Result<OwnedReport, FontHeightError>>,
+    waker: Option<Waker>,
+}
+
+/// A [`Future`] resolving to the [`OwnedReport`] of a check started by
+/// [`Reporter::check_async`].
+///
+/// The check runs on a dedicated thread pool, so polling this never blocks.
+/// Dropping the future cancels the check.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CheckFuture {
+    shared: Arc<Mutex<Shared>>,
+    cancellation: CancellationToken,
+}
+
+impl CheckFuture {
+    /// Stop the check as soon as possible.
+    ///
+    /// The future will then resolve to [`FontHeightError::Cancelled`], unless
+    /// the check already finished.
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    /// The [`CancellationToken`] the check stops at, to cancel it from
+    /// elsewhere (e.g. another task) without holding the future.
+    #[inline]
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+}
+
+impl Future for CheckFuture {
+    type Output = Result<OwnedReport, FontHeightError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().expect("CheckFuture lock poisoned");
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            },
+        }
+    }
+}
+
+impl Drop for CheckFuture {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+impl Reporter<'_> {
+    /// Check a [`WordList`] at a [`Location`] without blocking the current
+    /// thread, e.g. from within an async runtime.
+    ///
+    /// The font is parsed and checked on a dedicated [`rayon`] thread pool,
+    /// as configured by `options` like [`InstanceReporter::check`].
+    /// `font_bytes` and `word_list` can be anything that owns (or statically
+    /// borrows) them, such as a `Vec<u8>`/`Arc<[u8]>` and an
+    /// `Arc<WordList>`/`&'static WordList`.
+    ///
+    /// The check can be cancelled with [`CheckFuture::cancel`], through its
+    /// [`CheckFuture::cancellation_token`], or by dropping the future.
+    ///
+    /// [`InstanceReporter::check`]: crate::InstanceReporter::check
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn check_async<F, W>(
+        font_bytes: F,
+        location: Location,
+        word_list: W,
+        options: &AnalysisOptions,
+    ) -> CheckFuture
+    where
+        F: AsRef<[u8]> + Send + 'static,
+        W: Borrow<WordList> + Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let cancellation = CancellationToken::new();
+
+        let job_shared = Arc::clone(&shared);
+        let job_cancellation = cancellation.clone();
+        let options = options.clone();
+        ASYNC_POOL.spawn(move || {
+            let result = if job_cancellation.is_cancelled() {
+                Err(FontHeightError::Cancelled)
+            } else {
+                check(
+                    font_bytes.as_ref(),
+                    &location,
+                    word_list.borrow(),
+                    &options,
+                    &job_cancellation,
+                )
+            };
+
+            let mut shared =
+                job_shared.lock().expect("CheckFuture lock poisoned");
+            shared.result = Some(result);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        });
+
+        CheckFuture {
+            shared,
+            cancellation,
+        }
+    }
+}
+
+fn check(
+    font_bytes: &[u8],
+    location: &Location,
+    word_list: &WordList,
+    options: &AnalysisOptions,
+    cancellation: &CancellationToken,
+) -> Result<OwnedReport, FontHeightError> {
+    let reporter = Reporter::new(font_bytes)?;
+    let instance = reporter.instance(location)?;
+    let report =
+        instance.check_cancellable(word_list, options, cancellation)?;
+    if cancellation.is_cancelled() {
+        return Err(FontHeightError::Cancelled);
+    }
+    Ok(OwnedReport::from(&report))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::{
+        sync::Arc,
+        task::{Context, Poll, Wake},
+        thread::{self, Thread},
+    };
+
+    use super::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn resolves_with_error() {
+        let word_list = Arc::new(WordList::define("test", ["hello"]));
+        let future = Reporter::check_async(
+            b"not a font".to_vec(),
+            Location::new(),
+            word_list,
+            &AnalysisOptions::new(),
+        );
+        assert!(matches!(block_on(future), Err(FontHeightError::Skrifa(_))));
+    }
+
+    #[test]
+    fn shares_cancellation_token() {
+        let word_list = Arc::new(WordList::define("test", ["hello"]));
+        let future = Reporter::check_async(
+            b"not a font".to_vec(),
+            Location::new(),
+            word_list,
+            &AnalysisOptions::new(),
+        );
+        let token = future.cancellation_token();
+        assert!(!token.is_cancelled());
+        drop(future);
+        assert!(token.is_cancelled());
+    }
+}
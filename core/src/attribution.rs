@@ -0,0 +1,201 @@
+//! Explaining why a word's extremes differ between two locations.
+
+use crate::{PlacedGlyph, VerticalExtremes};
+
+/// Why a word reaches further (or less far) at one location than another:
+/// its extremes at each, with the difference split into the part due to its
+/// glyphs' outlines varying and the part due to their positioning varying.
+///
+/// See [`InstanceReporter::explain_delta`](crate::InstanceReporter::explain_delta).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaAttribution {
+    /// The glyphs the word shaped to at the first location.
+    pub from: Vec<PlacedGlyph>,
+    /// The glyphs the word shaped to at the second location.
+    pub to: Vec<PlacedGlyph>,
+    /// How the word's highest point moved.
+    pub highest: ExtremeDelta,
+    /// How the word's lowest point moved.
+    pub lowest: ExtremeDelta,
+}
+
+/// How far one of a word's extremes moved between two locations.
+///
+/// The outline & positioning parts always add up to the total: the outline
+/// part is how far the extreme would have moved if only the glyphs' outlines
+/// had varied (keeping their positions from the first location), and the
+/// positioning part is the rest.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExtremeDelta {
+    /// The extreme at the first location, in font units.
+    pub from: f64,
+    /// The extreme at the second location, in font units.
+    pub to: f64,
+    /// How far the extreme moved due to the glyphs' outlines (including any
+    /// substituted at the second location).
+    ///
+    /// `None` if the word shaped to a different number of glyphs at each
+    /// location, so their outlines can't be compared.
+    pub outline: Option<f64>,
+    /// How far the extreme moved due to the glyphs being positioned
+    /// differently, e.g. by `GPOS` mark attachment.
+    ///
+    /// `None` if the word shaped to a different number of glyphs at each
+    /// location.
+    pub positioning: Option<f64>,
+}
+
+impl ExtremeDelta {
+    /// How far the extreme moved, upwards.
+    #[inline]
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.to - self.from
+    }
+}
+
+/// How one of a word's glyphs differs between two locations, see
+/// [`DeltaAttribution::glyphs`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GlyphDelta {
+    /// The glyph at the first location.
+    pub from: PlacedGlyph,
+    /// The glyph in the same place at the second location.
+    pub to: PlacedGlyph,
+}
+
+impl GlyphDelta {
+    /// How much higher the top of the glyph's outline is.
+    #[inline]
+    #[must_use]
+    pub fn highest_outline(&self) -> f64 {
+        self.to.bounds.highest() - self.from.bounds.highest()
+    }
+
+    /// How much higher the bottom of the glyph's outline is.
+    #[inline]
+    #[must_use]
+    pub fn lowest_outline(&self) -> f64 {
+        self.to.bounds.lowest() - self.from.bounds.lowest()
+    }
+
+    /// How much further the glyph was raised.
+    #[inline]
+    #[must_use]
+    pub fn y_offset(&self) -> f64 {
+        self.to.y_offset - self.from.y_offset
+    }
+
+    /// Whether a different glyph was shaped at the second location, e.g. by
+    /// a `GSUB` feature variation.
+    #[inline]
+    #[must_use]
+    pub const fn substituted(&self) -> bool {
+        self.from.glyph_id != self.to.glyph_id
+    }
+}
+
+impl DeltaAttribution {
+    /// Compare the glyphs a word shaped to at two locations, or `None` if it
+    /// shaped to no glyphs at either.
+    pub(crate) fn new(
+        from: Vec<PlacedGlyph>,
+        to: Vec<PlacedGlyph>,
+    ) -> Option<Self> {
+        let from_extremes = extremes(&from)?;
+        let to_extremes = extremes(&to)?;
+        // Only the outlines vary: the second location's outlines, in the
+        // first location's positions
+        let outlines_varied = (from.len() == to.len())
+            .then(|| {
+                extremes(
+                    &from
+                        .iter()
+                        .zip(&to)
+                        .map(|(from, to)| PlacedGlyph {
+                            bounds: to.bounds,
+                            ..*from
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .flatten();
+        let delta = |extreme: fn(&VerticalExtremes) -> f64| ExtremeDelta {
+            from: extreme(&from_extremes),
+            to: extreme(&to_extremes),
+            outline: outlines_varied
+                .map(|varied| extreme(&varied) - extreme(&from_extremes)),
+            positioning: outlines_varied
+                .map(|varied| extreme(&to_extremes) - extreme(&varied)),
+        };
+        Some(DeltaAttribution {
+            highest: delta(VerticalExtremes::highest),
+            lowest: delta(VerticalExtremes::lowest),
+            from,
+            to,
+        })
+    }
+
+    /// How each glyph differs between the locations, pairing them up by
+    /// position.
+    ///
+    /// Empty if the word shaped to a different number of glyphs at each
+    /// location.
+    #[must_use]
+    pub fn glyphs(&self) -> Vec<GlyphDelta> {
+        if self.from.len() != self.to.len() {
+            return Vec::new();
+        }
+        self.from
+            .iter()
+            .zip(&self.to)
+            .map(|(&from, &to)| GlyphDelta { from, to })
+            .collect()
+    }
+
+    /// Whether the word shaped to different glyphs at each location, e.g.
+    /// due to `GSUB` feature variations.
+    #[must_use]
+    pub fn reshaped(&self) -> bool {
+        !self
+            .from
+            .iter()
+            .map(|glyph| glyph.glyph_id)
+            .eq(self.to.iter().map(|glyph| glyph.glyph_id))
+    }
+}
+
+/// The extremes reached by `glyphs`, once they're moved.
+fn extremes(glyphs: &[PlacedGlyph]) -> Option<VerticalExtremes> {
+    glyphs
+        .iter()
+        .map(PlacedGlyph::extremes)
+        .reduce(VerticalExtremes::merge)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn splits_outline_from_positioning() {
+        let glyph = |glyph_id, lowest, highest, y_offset| PlacedGlyph {
+            glyph_id,
+            cluster: 0,
+            bounds: VerticalExtremes::new(lowest, highest),
+            y_offset,
+        };
+        // A base growing taller, and a mark above it raised further still
+        let attribution = DeltaAttribution::new(
+            vec![glyph(1, 0.0, 700.0, 0.0), glyph(2, 0.0, 100.0, 700.0)],
+            vec![glyph(1, 0.0, 750.0, 0.0), glyph(2, 0.0, 120.0, 760.0)],
+        )
+        .unwrap();
+        assert_eq!(attribution.highest.total(), 80.0);
+        assert_eq!(attribution.highest.outline, Some(20.0));
+        assert_eq!(attribution.highest.positioning, Some(60.0));
+        assert_eq!(attribution.lowest.total(), 0.0);
+        assert!(!attribution.reshaped());
+        assert_eq!(attribution.glyphs()[1].y_offset(), 60.0);
+    }
+}
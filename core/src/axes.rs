@@ -0,0 +1,141 @@
+//! Metadata about a variable font's axes, for putting locations in context.
+
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use skrifa::{AxisCollection, MetadataProvider, NamedInstance, Tag};
+
+use crate::{Location, Reporter};
+
+/// A variation axis of a font, in user coordinates.
+///
+/// See [`Reporter::axes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisInfo {
+    /// The axis' tag (e.g. `wght`).
+    pub tag: Tag,
+    /// The axis' name from the `name` table (e.g. "Weight"), preferring
+    /// English, or its tag if it has none.
+    pub name: String,
+    /// The axis' minimum value.
+    pub min: f32,
+    /// The axis' default value.
+    pub default: f32,
+    /// The axis' maximum value.
+    pub max: f32,
+}
+
+impl Reporter<'_> {
+    /// Gets the font's variation axes, in `fvar` order. Static fonts have
+    /// none.
+    #[must_use]
+    pub fn axes(&self) -> Vec<AxisInfo> {
+        self.font
+            .axes()
+            .iter()
+            .map(|axis| AxisInfo {
+                tag: axis.tag(),
+                name: self
+                    .font
+                    .localized_strings(axis.name_id())
+                    .english_or_first()
+                    .map_or_else(
+                        || axis.tag().to_string(),
+                        |name| name.to_string(),
+                    ),
+                min: axis.min_value(),
+                default: axis.default_value(),
+                max: axis.max_value(),
+            })
+            .collect()
+    }
+}
+
+/// The user coordinates of a named instance, keyed by the tag of the axis each
+/// is on.
+///
+/// `fvar` stores an instance's coordinates in axis order without their tags, so
+/// this is the only place they're paired up by position. Coordinates beyond the
+/// last axis are ignored.
+pub(crate) fn named_instance_coords(
+    axes: &AxisCollection,
+    instance: &NamedInstance,
+) -> Vec<(Tag, f32)> {
+    axes.iter()
+        .zip(instance.user_coords())
+        .map(|(axis, coord)| (axis.tag(), coord))
+        .collect()
+}
+
+/// The coordinates seen on each axis, keyed by axis tag.
+#[derive(Debug)]
+pub(crate) struct AxisCoords {
+    axes: Vec<(Tag, BTreeSet<OrderedFloat<f32>>)>,
+}
+
+impl AxisCoords {
+    /// Track coordinates on the axes `tags`, which combinations of coordinates
+    /// are ordered by.
+    pub(crate) fn new(tags: impl IntoIterator<Item = Tag>) -> Self {
+        AxisCoords {
+            axes: tags.into_iter().map(|tag| (tag, BTreeSet::new())).collect(),
+        }
+    }
+
+    /// Add a coordinate on the axis `tag`. Coordinates on axes that aren't
+    /// tracked are ignored.
+    pub(crate) fn insert(&mut self, tag: Tag, coord: f32) {
+        if let Some((_, coords)) =
+            self.axes.iter_mut().find(|(axis, _)| *axis == tag)
+        {
+            coords.insert(coord.into());
+        }
+    }
+
+    /// Every combination of the coordinates seen on each axis.
+    ///
+    /// Note: the number of [`Location`]s returned scales exponentially with
+    /// the number of axes.
+    pub(crate) fn locations(&self) -> Vec<Location> {
+        self.axes
+            .iter()
+            .map(|(tag, coords)| coords.iter().map(move |coord| (*tag, coord)))
+            .multi_cartesian_product()
+            .map(|coords| {
+                coords
+                    .into_iter()
+                    .map(|(tag, coord)| (tag, coord.into_inner()))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn axis_coords_are_keyed_by_tag() {
+        let wght = Tag::new(b"wght");
+        let wdth = Tag::new(b"wdth");
+        let mut axis_coords = AxisCoords::new([wght, wdth]);
+        // Given in the opposite order to the axes
+        axis_coords.insert(wdth, 75.0);
+        axis_coords.insert(wght, 700.0);
+        axis_coords.insert(wdth, 100.0);
+        // Not an axis of the font
+        axis_coords.insert(Tag::new(b"opsz"), 12.0);
+
+        let location = |wght_coord, wdth_coord| {
+            [(wght, wght_coord), (wdth, wdth_coord)]
+                .into_iter()
+                .collect::<Location>()
+        };
+        assert_eq!(axis_coords.locations(), [
+            location(700.0, 75.0),
+            location(700.0, 100.0),
+        ]);
+    }
+}
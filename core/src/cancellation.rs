@@ -0,0 +1,53 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+/// A handle for stopping checks early, e.g. when the request that started
+/// them timed out.
+///
+/// Clones share the same flag, so a clone can be given to each check and
+/// the original kept to cancel them all. Checks look at the token between
+/// words, so they stop soon after it's cancelled, returning the words
+/// measured so far (see [`ReportStatistics::truncated`]).
+///
+/// ```
+/// # use fontheight::CancellationToken;
+/// let token = CancellationToken::new();
+/// let check_token = token.clone();
+/// assert!(!check_token.is_cancelled());
+/// token.cancel();
+/// assert!(check_token.is_cancelled());
+/// ```
+///
+/// [`ReportStatistics::truncated`]: crate::ReportStatistics::truncated
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that hasn't been cancelled.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    /// Stop the checks using this token (or any of its clones) as soon as
+    /// possible. Cancelling a token can't be undone.
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the token has been cancelled.
+    #[inline]
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub(crate) fn flag(&self) -> &AtomicBool {
+        &self.0
+    }
+}
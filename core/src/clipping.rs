@@ -0,0 +1,108 @@
+use std::fmt;
+
+use skrifa::{
+    FontRef,
+    raw::{TableProvider, tables::os2::SelectionFlags},
+};
+
+use crate::{LineBox, LineMetrics, VerticalExtremes, errors::SkrifaReadError};
+
+/// How a platform decides where to clip text, from the font's vertical
+/// metrics.
+///
+/// None of these are exact: platforms have their own heuristics & exceptions
+/// (e.g. for fallback fonts), but they're a good indication of which words
+/// are at risk of being clipped where. See
+/// [`Reporter::clip_box`](crate::Reporter::clip_box).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum ClippingProfile {
+    /// Windows GDI, which clips at `OS/2.usWinAscent` & `OS/2.usWinDescent`.
+    Windows,
+    /// macOS & iOS (Core Text), which clip at `hhea.ascender` &
+    /// `hhea.descender`.
+    Apple,
+    /// Android, which clips system fonts at `OS/2.sTypoAscender` &
+    /// `OS/2.sTypoDescender`.
+    Android,
+    /// A CSS line box with `line-height: normal`, using `OS/2`'s typographic
+    /// metrics if `USE_TYPO_METRICS` is set and `hhea` otherwise, with the
+    /// line gap split evenly above & below. Content outside the line box
+    /// overlaps adjacent lines, and is clipped by containers with
+    /// `overflow: hidden`.
+    Css,
+}
+
+impl ClippingProfile {
+    /// Every clipping profile.
+    pub const ALL: [ClippingProfile; 4] = [
+        ClippingProfile::Windows,
+        ClippingProfile::Apple,
+        ClippingProfile::Android,
+        ClippingProfile::Css,
+    ];
+
+    /// A short, lowercase name for the profile (e.g. `windows`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            ClippingProfile::Windows => "windows",
+            ClippingProfile::Apple => "apple",
+            ClippingProfile::Android => "android",
+            ClippingProfile::Css => "css",
+        }
+    }
+
+    /// The extremes beyond which text is clipped, in font units.
+    pub(crate) fn clip_box(
+        self,
+        font: &FontRef,
+    ) -> Result<VerticalExtremes, SkrifaReadError> {
+        let (highest, lowest) = match self {
+            ClippingProfile::Windows => {
+                let os2 = font.os2()?;
+                (
+                    f64::from(os2.us_win_ascent()),
+                    -f64::from(os2.us_win_descent()),
+                )
+            },
+            ClippingProfile::Apple => {
+                let hhea = font.hhea()?;
+                (
+                    f64::from(hhea.ascender().to_i16()),
+                    f64::from(hhea.descender().to_i16()),
+                )
+            },
+            ClippingProfile::Android => {
+                let os2 = font.os2()?;
+                (
+                    f64::from(os2.s_typo_ascender()),
+                    f64::from(os2.s_typo_descender()),
+                )
+            },
+            ClippingProfile::Css => {
+                let metrics = if font
+                    .os2()?
+                    .fs_selection()
+                    .contains(SelectionFlags::USE_TYPO_METRICS)
+                {
+                    LineMetrics::Typo
+                } else {
+                    LineMetrics::Hhea
+                };
+                return Ok(LineBox::new(font, metrics)?.extremes());
+            },
+        };
+        // Broken metrics (e.g. a descender above the ascender) shouldn't panic
+        Ok(VerticalExtremes::new(
+            lowest.min(highest),
+            highest.max(lowest),
+        ))
+    }
+}
+
+impl fmt::Display for ClippingProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use skrifa::{
+    FontRef,
+    raw::{
+        ReadError, TableProvider,
+        tables::meta::{DLNG, Metadata, SLNG},
+    },
+};
+
+use crate::{WordList, errors::SkrifaReadError};
+
+/// How a font declares a language in its `meta` table.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum LanguageDeclaration {
+    /// The font was designed for the language (`dlng`).
+    Design,
+    /// The font supports the language (`slng`), without necessarily being
+    /// designed for it.
+    Supported,
+}
+
+/// A language (and/or script) declared by a font in its `meta` table.
+///
+/// See [`Reporter::declared_languages`](crate::Reporter::declared_languages).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DeclaredLanguage {
+    tag: String,
+    declaration: LanguageDeclaration,
+}
+
+impl DeclaredLanguage {
+    /// The declared `ScriptLangTag`, a [BCP 47](https://www.rfc-editor.org/info/bcp47)
+    /// language tag such as `en-Latn`, `zh-Hant` or `Arab`.
+    #[inline]
+    #[must_use]
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Whether the font was designed for the language or only supports it.
+    #[inline]
+    #[must_use]
+    pub const fn declaration(&self) -> LanguageDeclaration {
+        self.declaration
+    }
+
+    /// The language subtag of the tag (e.g. `en`), if it has one.
+    ///
+    /// `None` for script-only tags (e.g. `Latn`) and undetermined languages
+    /// (e.g. `und-Latn`).
+    #[must_use]
+    pub fn language(&self) -> Option<&str> {
+        self.tag.split('-').next().filter(|subtag| {
+            matches!(subtag.len(), 2 | 3)
+                && subtag.chars().all(|c| c.is_ascii_alphabetic())
+                && !subtag.eq_ignore_ascii_case("und")
+        })
+    }
+
+    /// The script subtag of the tag (e.g. `Latn`), if it has one.
+    #[must_use]
+    pub fn script(&self) -> Option<&str> {
+        self.tag.split('-').find(|subtag| {
+            subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+        })
+    }
+
+    /// Whether the [`WordList`] covers this language.
+    ///
+    /// A word list matches if it's in the declared language (and script, if
+    /// both have one), or if it has no language and is in the declared
+    /// script. For example, `en-Latn` matches English word lists and
+    /// language-less Latin word lists, whereas `en` only matches English word
+    /// lists.
+    #[must_use]
+    pub fn matches(&self, word_list: &WordList) -> bool {
+        self.matches_metadata(word_list.language(), word_list.script())
+    }
+
+    fn matches_metadata(
+        &self,
+        language: Option<&str>,
+        script: Option<&str>,
+    ) -> bool {
+        let scripts_agree = match (self.script(), script) {
+            (Some(declared), Some(script)) => same_subtag(declared, script),
+            _ => true,
+        };
+        match (language, script) {
+            (Some(language), _) => {
+                self.language()
+                    .is_some_and(|declared| same_subtag(declared, language))
+                    && scripts_agree
+            },
+            (None, Some(script)) => self
+                .script()
+                .is_some_and(|declared| same_subtag(declared, script)),
+            (None, None) => false,
+        }
+    }
+}
+
+/// Subtags are case-insensitive (e.g. `Latn` and `latn` are the same script).
+const fn same_subtag(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+/// Read the languages declared in the font's `meta` table, designed-for
+/// languages first.
+pub(crate) fn declared_languages(
+    font: &FontRef,
+) -> Result<Vec<DeclaredLanguage>, SkrifaReadError> {
+    let meta = match font.meta() {
+        Ok(meta) => meta,
+        Err(ReadError::TableIsMissing(_)) => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut languages = Vec::new();
+    for record in meta.data_maps() {
+        let declaration = match record.tag() {
+            DLNG => LanguageDeclaration::Design,
+            SLNG => LanguageDeclaration::Supported,
+            _ => continue,
+        };
+        let Metadata::ScriptLangTags(tags) = record.data(meta.offset_data())?
+        else {
+            continue;
+        };
+        for tag in tags.iter() {
+            let tag = tag?;
+            if !tag.as_str().is_empty() {
+                languages.push(DeclaredLanguage {
+                    tag: tag.as_str().to_owned(),
+                    declaration,
+                });
+            }
+        }
+    }
+    // Languages can be both designed for & supported, keep the former
+    languages.sort_by_key(|language| language.declaration);
+    let mut seen = HashSet::new();
+    languages.retain(|language| seen.insert(language.tag.clone()));
+    Ok(languages)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn declared(tag: &str) -> DeclaredLanguage {
+        DeclaredLanguage {
+            tag: tag.to_owned(),
+            declaration: LanguageDeclaration::Design,
+        }
+    }
+
+    #[test]
+    fn parses_subtags() {
+        let en_latn_gb = declared("en-Latn-GB");
+        assert_eq!(en_latn_gb.language(), Some("en"));
+        assert_eq!(en_latn_gb.script(), Some("Latn"));
+
+        let arab = declared("Arab");
+        assert_eq!(arab.language(), None);
+        assert_eq!(arab.script(), Some("Arab"));
+
+        assert_eq!(declared("und-Deva").language(), None);
+        assert_eq!(declared("ja").script(), None);
+    }
+
+    #[test]
+    fn matches_word_lists() {
+        let en_latn = declared("en-Latn");
+        assert!(en_latn.matches_metadata(Some("en"), Some("Latn")));
+        assert!(en_latn.matches_metadata(None, Some("Latn")));
+        assert!(!en_latn.matches_metadata(Some("fr"), Some("Latn")));
+        assert!(!en_latn.matches_metadata(None, Some("Cyrl")));
+
+        let en = declared("en");
+        assert!(en.matches_metadata(Some("en"), Some("Latn")));
+        assert!(!en.matches_metadata(None, Some("Latn")));
+
+        let sr_cyrl = declared("sr-Cyrl");
+        assert!(!sr_cyrl.matches_metadata(Some("sr"), Some("Latn")));
+        assert!(sr_cyrl.matches_metadata(Some("sr"), None));
+    }
+}
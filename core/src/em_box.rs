@@ -0,0 +1,231 @@
+//! The ideographic em-box, that CJK text is designed (and clipped) within.
+
+use std::fmt;
+
+use skrifa::{
+    FontRef, Tag,
+    raw::{ReadError, TableProvider, tables::base::Axis},
+};
+
+use crate::{VerticalExtremes, errors::SkrifaReadError};
+
+/// Where an [`EmBox`] was read from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum EmBoxSource {
+    /// The `ideo` (bottom) and/or `idtp` (top) baselines of the `BASE`
+    /// table's entry for `script`, with the other a UPM away if it's
+    /// missing.
+    Base {
+        /// The OpenType script tag the baselines were read from (e.g. `hani`).
+        script: Tag,
+    },
+    /// `OS/2.sTypoAscender` & `OS/2.sTypoDescender`, which CJK fonts set to
+    /// the em-box (so they span exactly one UPM).
+    Typo,
+    /// The conventional em-box of 880 & -120 per 1000 units, for fonts
+    /// declaring the em-box neither way.
+    Convention,
+}
+
+impl EmBoxSource {
+    /// A short, lowercase name for where the em-box came from (e.g. `base`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            EmBoxSource::Base { .. } => "base",
+            EmBoxSource::Typo => "typo",
+            EmBoxSource::Convention => "convention",
+        }
+    }
+}
+
+impl fmt::Display for EmBoxSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmBoxSource::Base { script } => write!(f, "BASE ({script})"),
+            EmBoxSource::Typo => f.write_str("OS/2 typo metrics"),
+            EmBoxSource::Convention => f.write_str("convention"),
+        }
+    }
+}
+
+/// The ideographic em-box of a font: the square (usually one UPM tall) CJK
+/// glyphs are designed within, and that CJK layouts clip & align text by.
+///
+/// See [`Reporter::ideographic_em_box`](crate::Reporter::ideographic_em_box).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EmBox {
+    /// Where the em-box was read from.
+    pub source: EmBoxSource,
+    /// The top of the em-box, in font units above the baseline.
+    pub top: f64,
+    /// The bottom of the em-box, in font units (negative below the
+    /// baseline).
+    pub bottom: f64,
+}
+
+impl EmBox {
+    /// The BASE table's script tags to read the em-box from, in order of
+    /// preference.
+    const BASE_SCRIPTS: [Tag; 4] = [
+        Tag::new(b"hani"),
+        Tag::new(b"kana"),
+        Tag::new(b"hang"),
+        Tag::new(b"DFLT"),
+    ];
+
+    /// Read the ideographic em-box of `font`.
+    pub(crate) fn new(font: &FontRef) -> Result<Self, SkrifaReadError> {
+        let upem = f64::from(font.head()?.units_per_em());
+        if let Some(em_box) = Self::from_base(font, upem)? {
+            return Ok(em_box);
+        }
+        match font.os2() {
+            Ok(os2)
+                if i32::from(os2.s_typo_ascender())
+                    - i32::from(os2.s_typo_descender())
+                    == upem as i32 =>
+            {
+                return Ok(EmBox {
+                    source: EmBoxSource::Typo,
+                    top: f64::from(os2.s_typo_ascender()),
+                    bottom: f64::from(os2.s_typo_descender()),
+                });
+            },
+            Ok(_) | Err(ReadError::TableIsMissing(_)) => {},
+            Err(err) => return Err(err.into()),
+        }
+        Ok(EmBox {
+            source: EmBoxSource::Convention,
+            top: upem * 0.88,
+            bottom: upem * -0.12,
+        })
+    }
+
+    fn from_base(
+        font: &FontRef,
+        upem: f64,
+    ) -> Result<Option<Self>, SkrifaReadError> {
+        let base = match font.base() {
+            Ok(base) => base,
+            Err(ReadError::TableIsMissing(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let Some(horiz_axis) = base.horiz_axis().transpose()? else {
+            return Ok(None);
+        };
+        for script in Self::BASE_SCRIPTS {
+            let baseline = |tag| baseline(&horiz_axis, script, Tag::new(tag));
+            let (top, bottom) = match (baseline(b"idtp")?, baseline(b"ideo")?) {
+                (Some(top), Some(bottom)) => (top, bottom),
+                (Some(top), None) => (top, top - upem),
+                (None, Some(bottom)) => (bottom + upem, bottom),
+                (None, None) => continue,
+            };
+            return Ok(Some(EmBox {
+                source: EmBoxSource::Base { script },
+                top,
+                bottom,
+            }));
+        }
+        Ok(None)
+    }
+
+    /// The bottom & top of the em-box.
+    ///
+    /// Broken metrics (e.g. a bottom above the top) are swapped rather than
+    /// panicking.
+    #[must_use]
+    pub fn extremes(&self) -> VerticalExtremes {
+        VerticalExtremes::new(
+            self.bottom.min(self.top),
+            self.top.max(self.bottom),
+        )
+    }
+
+    /// How far `extremes` reach above the em-box, if at all.
+    #[must_use]
+    pub fn above(&self, extremes: VerticalExtremes) -> Option<f64> {
+        let overflow = extremes.highest() - self.extremes().highest();
+        (overflow > 0.0).then_some(overflow)
+    }
+
+    /// How far `extremes` reach below the em-box, if at all.
+    #[must_use]
+    pub fn below(&self, extremes: VerticalExtremes) -> Option<f64> {
+        let overflow = self.extremes().lowest() - extremes.lowest();
+        (overflow > 0.0).then_some(overflow)
+    }
+}
+
+/// The coordinate of the `baseline` of `script` in `axis`, if the font has
+/// one.
+fn baseline(
+    axis: &Axis,
+    script: Tag,
+    baseline: Tag,
+) -> Result<Option<f64>, ReadError> {
+    let Some(tags) = axis.base_tag_list().transpose()? else {
+        return Ok(None);
+    };
+    let Some(index) = tags
+        .baseline_tags()
+        .iter()
+        .position(|tag| tag.get() == baseline)
+    else {
+        return Ok(None);
+    };
+    let scripts = axis.base_script_list()?;
+    let Some(record) = scripts
+        .base_script_records()
+        .iter()
+        .find(|record| record.base_script_tag() == script)
+    else {
+        return Ok(None);
+    };
+    let Some(values) = record
+        .base_script(scripts.offset_data())?
+        .base_values()
+        .transpose()?
+    else {
+        return Ok(None);
+    };
+    values
+        .base_coords()
+        .get(index)
+        .map(|coord| Some(f64::from(coord.coordinate())))
+        .or_else(|err| match err {
+            // Fonts may list fewer coordinates than baselines
+            ReadError::OutOfBounds => Ok(None),
+            err => Err(err),
+        })
+}
+
+/// Whether a word list's `script` (an
+/// [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) code) is written in
+/// the ideographic em-box: Han, kana, Hangul & Bopomofo, and the Japanese &
+/// Korean combinations of them.
+///
+/// ```
+/// # use fontheight::is_cjk_script;
+/// assert!(is_cjk_script("Hani"));
+/// assert!(is_cjk_script("Jpan"));
+/// assert!(!is_cjk_script("Latn"));
+/// ```
+#[must_use]
+pub fn is_cjk_script(script: &str) -> bool {
+    matches!(
+        script,
+        "Hani"
+            | "Hans"
+            | "Hant"
+            | "Hira"
+            | "Kana"
+            | "Hrkt"
+            | "Jpan"
+            | "Hang"
+            | "Kore"
+            | "Bopo"
+    )
+}
@@ -6,6 +6,8 @@
 //! may return more specific errors (all of which will up-convert to
 //! [`FontHeightError`] one way or another).
 
+use std::io;
+
 use harfshapedfa::errors::ShapingPlanError;
 pub use harfshapedfa::errors::{InvalidTagError, MismatchedAxesError};
 use skrifa::outline::DrawError;
@@ -18,6 +20,11 @@ pub enum FontHeightError {
     /// [`skrifa`] could not parse the font.
     #[error("skrifa could not parse the font: {0}")]
     Skrifa(#[from] SkrifaReadError),
+    /// The font has no outlines to measure (no `glyf`, `CFF `, or `CFF2`
+    /// table), as is the case for bitmap-only fonts (e.g. `CBDT`/`sbix` emoji
+    /// fonts).
+    #[error("font has no outlines (bitmap-only fonts aren't supported)")]
+    NoOutlines,
     /// An axis tag you provided was invalid.
     #[error("invalid tag: {0}")]
     InvalidTag(InvalidTagError),
@@ -32,6 +39,62 @@ pub enum FontHeightError {
     /// shaping plan for it failed.
     #[error(transparent)]
     WordListMetadata(#[from] WordListShapingPlanError),
+    /// The check was cancelled before it finished.
+    #[error("the check was cancelled")]
+    Cancelled,
+    /// Shaping a word produced a glyph the font has no outline for (e.g. a
+    /// glyph ID past the end of a malformed font's glyphs).
+    #[error("no bounds for glyph {glyph_id} while shaping {word:?}")]
+    MissingGlyphBounds {
+        /// The glyph ID produced by shaping.
+        glyph_id: u32,
+        /// The word being shaped.
+        word: String,
+    },
+    /// Reading the words of a
+    /// [`WordSource`](crate::word_source::WordSource) failed, e.g. a file
+    /// being deleted while it was streamed.
+    #[error("failed to read words from {name}: {error}")]
+    ReadingWords {
+        /// The name of the word source.
+        name: String,
+        /// The error reading it.
+        error: io::Error,
+    },
+}
+
+/// A glyph with no entry in an [`InstanceExtremes`](crate::InstanceExtremes),
+/// without the word that produced it.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct MissingGlyphBoundsError(pub(crate) u32);
+
+impl MissingGlyphBoundsError {
+    /// Name the `word` that was being shaped.
+    pub(crate) fn for_word(self, word: &str) -> FontHeightError {
+        FontHeightError::MissingGlyphBounds {
+            glyph_id: self.0,
+            word: word.to_owned(),
+        }
+    }
+}
+
+/// Why a glyph's extremes couldn't be found while shaping a word, when glyphs
+/// are drawn on demand (see
+/// [`Reporter::with_on_demand_glyphs`](crate::Reporter::with_on_demand_glyphs)).
+#[derive(Debug)]
+pub(crate) enum GlyphBoundsError {
+    Missing(MissingGlyphBoundsError),
+    Drawing(SkrifaDrawError),
+}
+
+impl GlyphBoundsError {
+    /// Name the `word` that was being shaped.
+    pub(crate) fn for_word(self, word: &str) -> FontHeightError {
+        match self {
+            GlyphBoundsError::Missing(err) => err.for_word(word),
+            GlyphBoundsError::Drawing(err) => FontHeightError::Drawing(err),
+        }
+    }
 }
 
 /// Creating the shaping plan for a [`WordList`](crate::WordList) failed.
@@ -65,3 +128,8 @@ pub struct SkrifaReadError(#[from] skrifa::raw::ReadError);
 #[derive(Debug, Error)]
 #[error("could not draw glyph {0}: {1}")]
 pub struct SkrifaDrawError(pub(crate) skrifa::GlyphId, pub(crate) DrawError);
+
+/// A [`Pattern`](crate::Pattern) couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error(transparent)]
+pub struct PatternError(pub(crate) regex::Error);
@@ -0,0 +1,252 @@
+//! Leaving noisy words (e.g. numbers, URLs & stray punctuation) out of checks,
+//! so they don't crowd the exemplars.
+
+use std::{fmt, hash, str::FromStr, sync::LazyLock};
+
+use regex::Regex;
+
+use crate::errors::PatternError;
+
+/// A rule for leaving words out of a check, see
+/// [`AnalysisOptions::with_exclusion`](crate::AnalysisOptions::with_exclusion).
+///
+/// Excluded words aren't shaped, and are counted in
+/// [`ReportStatistics::excluded`](crate::ReportStatistics::excluded).
+///
+/// ```
+/// # use fontheight::{CharCategory, Pattern, WordFilter};
+/// let numbers = WordFilter::Categories(vec![
+///     CharCategory::Number,
+///     CharCategory::Punctuation,
+/// ]);
+/// assert!(numbers.excludes("3.14"));
+/// assert!(!numbers.excludes("π"));
+///
+/// let urls = WordFilter::Pattern(Pattern::new(r"^https?://")?);
+/// assert!(urls.excludes("https://fonts.google.com"));
+/// # Ok::<_, fontheight::errors::PatternError>(())
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum WordFilter {
+    /// Exclude words the [`Pattern`] matches anywhere within.
+    Pattern(Pattern),
+    /// Exclude words made up entirely of characters in any of the
+    /// categories, e.g. numbers & punctuation for "1,000" or "—".
+    Categories(Vec<CharCategory>),
+}
+
+impl WordFilter {
+    /// Whether `word` should be left out.
+    #[must_use]
+    pub fn excludes(&self, word: &str) -> bool {
+        match self {
+            WordFilter::Pattern(pattern) => pattern.is_match(word),
+            WordFilter::Categories(categories) => {
+                !word.is_empty()
+                    && word.chars().all(|c| {
+                        categories.iter().any(|category| category.contains(c))
+                    })
+            },
+        }
+    }
+}
+
+/// A major Unicode general category of characters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CharCategory {
+    /// Letters (`L`), of any case or script.
+    Letter,
+    /// Combining marks (`M`), e.g. accents & Indic vowel signs.
+    Mark,
+    /// Digits & other numeric characters (`N`).
+    Number,
+    /// Punctuation (`P`), e.g. dashes, quotes & brackets.
+    Punctuation,
+    /// Symbols (`S`), e.g. currency & maths signs, and emoji.
+    Symbol,
+    /// Spaces & line/paragraph separators (`Z`). Tabs & newlines are control
+    /// characters, so [`Other`](CharCategory::Other).
+    Separator,
+    /// Control, format, private use & unassigned characters (`C`).
+    Other,
+}
+
+impl CharCategory {
+    const ALL: [CharCategory; 7] = [
+        CharCategory::Letter,
+        CharCategory::Mark,
+        CharCategory::Number,
+        CharCategory::Punctuation,
+        CharCategory::Symbol,
+        CharCategory::Separator,
+        CharCategory::Other,
+    ];
+
+    /// Whether `c` is in this category.
+    #[must_use]
+    pub fn contains(self, c: char) -> bool {
+        // In the same order as CharCategory::ALL
+        static CATEGORIES: LazyLock<[Regex; 7]> = LazyLock::new(|| {
+            CharCategory::ALL.map(|category| {
+                Regex::new(&format!(r"\A\p{{{}}}\z", category.abbreviation()))
+                    .expect("general categories are valid patterns")
+            })
+        });
+        CATEGORIES[self as usize].is_match(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// The category's one-letter Unicode general category abbreviation, as
+    /// used in [`Pattern`]s (e.g. `\p{N}`).
+    #[must_use]
+    pub const fn abbreviation(self) -> char {
+        match self {
+            CharCategory::Letter => 'L',
+            CharCategory::Mark => 'M',
+            CharCategory::Number => 'N',
+            CharCategory::Punctuation => 'P',
+            CharCategory::Symbol => 'S',
+            CharCategory::Separator => 'Z',
+            CharCategory::Other => 'C',
+        }
+    }
+}
+
+/// A regular expression, with the syntax of the [`regex`] crate, e.g. `\d`,
+/// `[^a-z]`, `(ab|cd)+` and `\p{…}` for Unicode general categories (see
+/// [`CharCategory`]).
+///
+/// Patterns match anywhere within a word, unless anchored with `^`/`$`, and
+/// take time linear in the length of the word to match.
+///
+/// ```
+/// # use fontheight::Pattern;
+/// let pattern = Pattern::new(r"^\p{N}+([.,]\d+)?$")?;
+/// assert!(pattern.is_match("3,14"));
+/// assert!(!pattern.is_match("3,14 fois"));
+/// # Ok::<_, fontheight::errors::PatternError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pattern(Regex);
+
+impl Pattern {
+    /// Parse a pattern.
+    ///
+    /// Fails if the pattern is malformed, or would compile to an
+    /// unreasonably large matcher.
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        Regex::new(pattern).map(Pattern).map_err(PatternError)
+    }
+
+    /// The pattern as it was written.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Whether the pattern matches anywhere within `word`.
+    #[inline]
+    #[must_use]
+    pub fn is_match(&self, word: &str) -> bool {
+        self.0.is_match(word)
+    }
+}
+
+// Patterns are the same if they were written the same
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Pattern {}
+
+impl hash::Hash for Pattern {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Pattern::new(pattern)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn is_match(pattern: &str, word: &str) -> bool {
+        Pattern::new(pattern)
+            .unwrap_or_else(|err| panic!("{err}"))
+            .is_match(word)
+    }
+
+    #[test]
+    fn matches_patterns() {
+        assert!(is_match("ab", "cabd"));
+        assert!(!is_match("^ab", "cab"));
+        assert!(is_match("^a.c$", "abc"));
+        assert!(is_match("^(ab|cd)+$", "abcdab"));
+        assert!(!is_match("^(ab|cd)+$", "abc"));
+        assert!(is_match(r"^\d{2,3}$", "123"));
+        assert!(!is_match(r"^\d{2,3}$", "1234"));
+        assert!(is_match(r"^[^a-z]*$", "ÅÄÖ"));
+        assert!(is_match(r"^[\p{N}\p{P}]+$", "1,000"));
+        assert!(is_match(r"^\P{L}+$", "42!"));
+        assert!(is_match(r"www\.", "www.example.com"));
+        assert!(!is_match(r"www\.", "wwwx"));
+        assert!(is_match("^a*a*b$", "aaab"));
+        assert!(is_match("^(a?)*$", "aa"));
+        assert!(is_match("[]a]", "]"));
+        assert!(is_match("^$", ""));
+        assert!(is_match(r"^\p{S}+$", "€+"));
+        assert!(!is_match(r"^\p{S}+$", "\u{7}"));
+        assert!(is_match(r"^\p{Z}$", "\u{a0}"));
+        assert!(!is_match(r"^\p{Z}$", "\t"));
+    }
+
+    #[test]
+    fn rejects_malformed_patterns() {
+        for pattern in ["(a", "a)", "[a", "*a", r"\q", "a{3,1}", r"\p{X}"] {
+            assert!(Pattern::new(pattern).is_err(), "{pattern} was accepted");
+        }
+    }
+
+    #[test]
+    fn filters_by_category() {
+        let filter = WordFilter::Categories(vec![CharCategory::Punctuation]);
+        assert!(filter.excludes("—"));
+        assert!(filter.excludes("?!"));
+        assert!(!filter.excludes("a?"));
+        assert!(!filter.excludes(""));
+
+        let expected = [
+            ('a', CharCategory::Letter),
+            ('\u{301}', CharCategory::Mark),
+            ('٣', CharCategory::Number),
+            ('«', CharCategory::Punctuation),
+            ('€', CharCategory::Symbol),
+            ('\u{3000}', CharCategory::Separator),
+            ('\t', CharCategory::Other),
+        ];
+        for (c, category) in expected {
+            for other in CharCategory::ALL {
+                assert_eq!(
+                    other.contains(c),
+                    other == category,
+                    "{c:?} {other:?}"
+                );
+            }
+        }
+    }
+}
@@ -1,18 +1,35 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashSet},
+    iter::{Chain, FusedIterator},
+    slice, vec,
+};
 
-use crate::{Location, Report, WordExtremes, WordList};
+use itertools::Itertools;
+
+use crate::{Location, Report, VerticalExtremes, WordExtremes, WordList};
 
 /// A collection of the lowest lows and highest highs.
 ///
 /// Note: the low & high exemplars are not guaranteed to be mutually exclusive,
 /// there can be overlap.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Exemplars<'w> {
     lowest: Vec<WordExtremes<'w>>,
     highest: Vec<WordExtremes<'w>>,
 }
 
 impl<'a> Exemplars<'a> {
+    /// Exemplars already sorted as [`ExemplarCollector::build`] sorts them.
+    pub(crate) fn from_parts(
+        lowest: Vec<WordExtremes<'a>>,
+        highest: Vec<WordExtremes<'a>>,
+    ) -> Self {
+        debug_assert_eq!(lowest.len(), highest.len());
+        Exemplars { lowest, highest }
+    }
+
     /// Retrieve the lowest-reaching words.
     ///
     /// This slice will always be the same length as [`highest`](Self::highest).
@@ -60,6 +77,98 @@ impl<'a> Exemplars<'a> {
         self.lowest.len()
     }
 
+    /// How high & low the exemplars reach overall, or `None` if there are no
+    /// exemplars.
+    #[must_use]
+    pub fn extremes(&self) -> Option<VerticalExtremes> {
+        self.iter()
+            .map(|word_extremes| word_extremes.extremes)
+            .reduce(VerticalExtremes::merge)
+    }
+
+    /// Combine with another set of exemplars, e.g. from checking another part
+    /// of the same [`WordList`], keeping the `n` lowest & `n` highest words of
+    /// both.
+    ///
+    /// A word appearing in both is only kept once.
+    #[must_use]
+    pub fn merge(&self, other: &Exemplars<'a>, n: usize) -> Exemplars<'a> {
+        self.merge_impl(other, n, false)
+    }
+
+    /// Like [`merge`](Self::merge), but only keeping the most extreme word out
+    /// of words that are the same once normalised, as
+    /// [`collect_distinct_min_max_extremes`](CollectToExemplars::collect_distinct_min_max_extremes)
+    /// does.
+    #[must_use]
+    pub fn merge_distinct(
+        &self,
+        other: &Exemplars<'a>,
+        n: usize,
+    ) -> Exemplars<'a> {
+        self.merge_impl(other, n, true)
+    }
+
+    fn merge_impl(
+        &self,
+        other: &Exemplars<'a>,
+        n: usize,
+        distinct: bool,
+    ) -> Exemplars<'a> {
+        if n == 0 {
+            return Exemplars::from_parts(Vec::new(), Vec::new());
+        }
+        let mut collector = ExemplarCollector::new(n).distinct(distinct);
+        self.iter()
+            .chain(other)
+            .copied()
+            .unique()
+            .for_each(|word_extremes| collector.push(word_extremes));
+        collector.build()
+    }
+
+    /// Iterate over the lowest words, then the highest words that aren't
+    /// also among the lowest, so each word measured is only seen once.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> ExemplarsIter<'_, 'a> {
+        ExemplarsIter {
+            lowest: self.lowest.iter(),
+            highest: self.highest.iter(),
+            all_lowest: &self.lowest,
+        }
+    }
+
+    /// The words found, lowest first, with words that are among both the
+    /// highest & lowest (or appear more than once in the [`WordList`]) only
+    /// given once.
+    pub fn words(&self) -> impl Iterator<Item = &'a str> {
+        self.iter().map(|word_extremes| word_extremes.word).unique()
+    }
+
+    /// The [words](Self::words) found here but not in `other`, e.g. words that
+    /// became exemplars in a new version of a font.
+    ///
+    /// Words are compared alone, regardless of how high or low they reach.
+    pub fn difference<'b>(
+        &'b self,
+        other: &'b Exemplars<'_>,
+    ) -> impl Iterator<Item = &'a str> + 'b {
+        let others = other.words().collect::<HashSet<_>>();
+        self.words().filter(move |word| !others.contains(word))
+    }
+
+    /// The [words](Self::words) found both here and in `other`.
+    ///
+    /// Words are compared alone, regardless of how high or low they reach.
+    pub fn intersection<'b>(
+        &'b self,
+        other: &'b Exemplars<'_>,
+    ) -> impl Iterator<Item = &'a str> + 'b {
+        let others = other.words().collect::<HashSet<_>>();
+        self.words().filter(move |word| others.contains(word))
+    }
+
     /// Wraps the exemplars into a report.
     ///
     /// It is not validated that the [`Location`] and [`WordList`] are the ones
@@ -75,12 +184,66 @@ impl<'a> Exemplars<'a> {
     }
 }
 
+impl<'e, 'a> IntoIterator for &'e Exemplars<'a> {
+    type IntoIter = ExemplarsIter<'e, 'a>;
+    type Item = &'e WordExtremes<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for Exemplars<'a> {
+    type IntoIter =
+        Chain<vec::IntoIter<WordExtremes<'a>>, vec::IntoIter<WordExtremes<'a>>>;
+    type Item = WordExtremes<'a>;
+
+    /// Iterate over the exemplars as [`Exemplars::iter`] does.
+    fn into_iter(self) -> Self::IntoIter {
+        let Exemplars {
+            lowest,
+            mut highest,
+        } = self;
+        highest.retain(|word_extremes| !lowest.contains(word_extremes));
+        lowest.into_iter().chain(highest)
+    }
+}
+
+/// An iterator over the [`WordExtremes`] in an [`Exemplars`], see
+/// [`Exemplars::iter`].
+#[derive(Debug, Clone)]
+pub struct ExemplarsIter<'e, 'a> {
+    lowest: slice::Iter<'e, WordExtremes<'a>>,
+    highest: slice::Iter<'e, WordExtremes<'a>>,
+    all_lowest: &'e [WordExtremes<'a>],
+}
+
+impl<'e, 'a> Iterator for ExemplarsIter<'e, 'a> {
+    type Item = &'e WordExtremes<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lowest.next().or_else(|| {
+            let all_lowest = self.all_lowest;
+            self.highest
+                .find(|word_extremes| !all_lowest.contains(word_extremes))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lowest = self.lowest.len();
+        (lowest, Some(lowest + self.highest.len()))
+    }
+}
+
+impl FusedIterator for ExemplarsIter<'_, '_> {}
+
 /// A builder to construct a limited size summary from a stream of words. We do
 /// this as an explicit step with a binary heap for assured runtime complexity.
 #[derive(Debug, Clone)]
 pub(crate) struct ExemplarCollector<'w> {
     lowest: BinaryHeap<ByLowest<'w>>,
     highest: BinaryHeap<ByHighest<'w>>,
+    distinct: bool,
 }
 
 /// Report, but sorted ascending by lowest for a max-heap.
@@ -135,39 +298,29 @@ impl<'w> ExemplarCollector<'w> {
         Self {
             lowest: BinaryHeap::with_capacity(top_n),
             highest: BinaryHeap::with_capacity(top_n),
+            distinct: false,
         }
     }
 
+    /// Only keep the most extreme word out of words that are the same once
+    /// normalised (see [`normalise_word`]).
+    pub(crate) const fn distinct(mut self, distinct: bool) -> Self {
+        self.distinct = distinct;
+        self
+    }
+
     /// Consider adding a new report, if it is low and/or high enough.
     pub(crate) fn push(&mut self, elem: WordExtremes<'w>) {
-        // Store if this report is stronger than the weakest report that would
-        // be evicted from the heap to accommodate it.
-        let by_lowest = ByLowest(elem);
-        if self.lowest.len() < self.lowest.capacity() {
-            self.lowest.push(by_lowest);
-        } else if let Some(mut weakest_low) = self.lowest.peek_mut()
-            && by_lowest < *weakest_low
-        {
-            *weakest_low = by_lowest;
-        }
-
-        // Store if this report is stronger than the weakest report that would
-        // be evicted from the heap to accommodate it.
-        let by_highest = ByHighest(elem);
-        if self.highest.len() < self.highest.capacity() {
-            self.highest.push(by_highest);
-        } else if let Some(mut weakest_high) = self.highest.peek_mut()
-            && by_highest < *weakest_high
-        {
-            *weakest_high = by_highest;
-        }
+        let distinct = self.distinct;
+        push_bounded(&mut self.lowest, ByLowest(elem), distinct);
+        push_bounded(&mut self.highest, ByHighest(elem), distinct);
     }
 
     #[cfg(feature = "rayon")]
     pub(crate) fn merge_with(&mut self, other: Self) {
-        use itertools::Itertools;
-
-        let ExemplarCollector { lowest, highest } = other;
+        let ExemplarCollector {
+            lowest, highest, ..
+        } = other;
         highest
             .into_iter()
             .map(|ByHighest(extremes)| extremes)
@@ -195,12 +348,103 @@ impl<'w> ExemplarCollector<'w> {
     }
 }
 
+/// Access to the [`WordExtremes`] being ordered in a heap.
+trait HeapEntry<'w>: Ord {
+    fn word(&self) -> &'w str;
+}
+
+impl<'w> HeapEntry<'w> for ByLowest<'w> {
+    fn word(&self) -> &'w str {
+        self.0.word
+    }
+}
+
+impl<'w> HeapEntry<'w> for ByHighest<'w> {
+    fn word(&self) -> &'w str {
+        self.0.word
+    }
+}
+
+/// Push `elem` into a max-heap whose weakest element is at the top, never
+/// growing the heap beyond its initial capacity.
+///
+/// If `distinct`, any existing element with the same
+/// [normalised](normalise_word) word is replaced by `elem` if `elem` is
+/// stronger, otherwise `elem` is discarded.
+fn push_bounded<'w, T: HeapEntry<'w>>(
+    heap: &mut BinaryHeap<T>,
+    elem: T,
+    distinct: bool,
+) {
+    let has_space = heap.len() < heap.capacity();
+    // Checking this first means we only normalise words that would make the
+    // cut, which is the vast minority of them
+    if !has_space && heap.peek().is_some_and(|weakest| elem >= *weakest) {
+        return;
+    }
+
+    if distinct {
+        let normalised = normalise_word(elem.word());
+        let same_word = heap
+            .iter()
+            .find(|existing| normalise_word(existing.word()) == normalised);
+        if let Some(existing) = same_word {
+            if elem < *existing {
+                heap.retain(|existing| {
+                    normalise_word(existing.word()) != normalised
+                });
+                heap.push(elem);
+            }
+            return;
+        }
+    }
+
+    // Store if this report is stronger than the weakest report that would
+    // be evicted from the heap to accommodate it.
+    if has_space {
+        heap.push(elem);
+    } else if let Some(mut weakest) = heap.peek_mut() {
+        *weakest = elem;
+    }
+}
+
+/// Case-fold a word and strip punctuation from it, so words that are
+/// otherwise identical can be identified (e.g. "Ögonblick" and "ögonblick!").
+pub(crate) fn normalise_word(word: &str) -> String {
+    word.chars()
+        .filter(|&c| !is_punctuation(c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// A (non-exhaustive) check for whether a character is punctuation; covers
+/// ASCII, Latin-1, general punctuation, and CJK punctuation.
+pub(crate) const fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(
+            c,
+            '¡' | '«' | '·' | '»' | '¿'
+            // General Punctuation, sans spaces & format characters (e.g. ZWJ)
+            | '\u{2010}'..='\u{2027}'
+            | '\u{2030}'..='\u{205E}'
+            // CJK Symbols and Punctuation
+            | '\u{3001}'..='\u{3003}'
+            | '\u{3008}'..='\u{3011}'
+            | '\u{3014}'..='\u{301F}'
+        )
+}
+
 /// A helper trait to collect an iterator of [`WordExtremes`] into an
 /// [`Exemplars`].
 pub trait CollectToExemplars<'a>: private::Sealed {
     /// Collect the `n` highest and `n` lowest words observed into an
     /// [`Exemplars`].
     fn collect_min_max_extremes(self, n: usize) -> Exemplars<'a>;
+
+    /// Collect the `n` highest and `n` lowest words observed into an
+    /// [`Exemplars`], only keeping the most extreme of words that differ only
+    /// by case or punctuation (e.g. "Ögonblick" and "ögonblick!").
+    fn collect_distinct_min_max_extremes(self, n: usize) -> Exemplars<'a>;
 }
 
 impl<'a, I> CollectToExemplars<'a> for I
@@ -215,6 +459,18 @@ where
             })
             .build()
     }
+
+    fn collect_distinct_min_max_extremes(self, n: usize) -> Exemplars<'a> {
+        self.into_iter()
+            .fold(
+                ExemplarCollector::new(n).distinct(true),
+                |mut acc, report| {
+                    acc.push(report);
+                    acc
+                },
+            )
+            .build()
+    }
 }
 
 mod private {
@@ -239,6 +495,9 @@ mod unit_tests {
     ) -> WordExtremes<'_> {
         WordExtremes {
             word,
+            index: 0,
+            skipped_glyphs: 0,
+            punctuation: None,
             extremes: VerticalExtremes {
                 highest: NotNan::new(highest).expect("highest was NaN"),
                 lowest: NotNan::new(lowest).expect("lowest was NaN"),
@@ -246,6 +505,14 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn normalisation() {
+        assert_eq!(normalise_word("«Ögonblick»!"), "ögonblick");
+        assert_eq!(normalise_word("don't"), "dont");
+        // Joiners are kept
+        assert_eq!(normalise_word("क्\u{200D}ष"), "क्\u{200D}ष");
+    }
+
     #[test]
     fn by_lowest_sorting() {
         let a = word_extremes("a", 10., 10.);
@@ -269,4 +536,78 @@ mod unit_tests {
         // Tie break on string orer
         assert_eq!(ByHighest(a).cmp(&ByHighest(c)), Ordering::Less);
     }
+
+    #[test]
+    fn distinct_collection() {
+        let words = [
+            word_extremes("Ögonblick", -10., 110.),
+            word_extremes("ögonblick!", -20., 100.),
+            word_extremes("«ögonblick»", -5., 90.),
+            word_extremes("hej", -1., 50.),
+            word_extremes("Hej", 0., 60.),
+        ];
+
+        let exemplars = words.collect_distinct_min_max_extremes(3);
+        let highest = exemplars
+            .highest()
+            .iter()
+            .map(|extremes| extremes.word)
+            .collect::<Vec<_>>();
+        let lowest = exemplars
+            .lowest()
+            .iter()
+            .map(|extremes| extremes.word)
+            .collect::<Vec<_>>();
+        assert_eq!(highest, ["Ögonblick", "Hej"]);
+        assert_eq!(lowest, ["ögonblick!", "hej"]);
+
+        // Without deduplication, the "ögonblick"s crowd out the "hej"s
+        let exemplars = words.collect_min_max_extremes(3);
+        assert_eq!(exemplars.highest()[2].word, "«ögonblick»");
+    }
+
+    #[test]
+    fn merging() {
+        let first = [
+            word_extremes("a", -10., 50.),
+            word_extremes("b", -1., 100.),
+            word_extremes("c", 0., 10.),
+        ]
+        .collect_min_max_extremes(2);
+        let second =
+            [word_extremes("b", -1., 100.), word_extremes("d", -20., 20.)]
+                .collect_min_max_extremes(2);
+
+        let merged = first.merge(&second, 2);
+        fn words<'w>(exemplars: &[WordExtremes<'w>]) -> Vec<&'w str> {
+            exemplars.iter().map(|extremes| extremes.word).collect()
+        }
+        assert_eq!(words(merged.highest()), ["b", "a"]);
+        assert_eq!(words(merged.lowest()), ["d", "a"]);
+        assert_eq!(merged.extremes(), Some(VerticalExtremes::new(-20., 100.)),);
+        assert!(first.merge(&second, 0).is_empty());
+    }
+
+    #[test]
+    fn word_sets() {
+        let old = [
+            word_extremes("a", -10., 100.),
+            word_extremes("b", -1., 50.),
+            word_extremes("c", 0., 10.),
+        ]
+        .collect_min_max_extremes(2);
+        let new = [
+            word_extremes("a", -10., 100.),
+            word_extremes("d", -20., 20.),
+        ]
+        .collect_min_max_extremes(2);
+
+        // "a" is among both the highest & lowest, but only given once
+        assert_eq!(old.words().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(old.iter().count(), 2);
+        assert_eq!(old.clone().into_iter().count(), 2);
+        assert_eq!(new.difference(&old).collect::<Vec<_>>(), ["d"]);
+        assert_eq!(old.difference(&new).collect::<Vec<_>>(), ["b"]);
+        assert_eq!(new.intersection(&old).collect::<Vec<_>>(), ["a"]);
+    }
 }
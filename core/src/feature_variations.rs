@@ -0,0 +1,140 @@
+//! `GSUB` feature variations (e.g. `rvrn`), which substitute different glyphs
+//! in only part of the design space.
+
+use std::ops::RangeInclusive;
+
+use harfshapedfa::convert::denormalize;
+use skrifa::{
+    MetadataProvider, Tag,
+    raw::{
+        TableProvider,
+        tables::layout::{Condition, ConditionSet, FeatureVariations},
+        types::F2Dot14,
+    },
+};
+
+use crate::{Location, Reporter};
+
+/// The part of the design space that a `GSUB` feature variation applies in.
+///
+/// See [`Reporter::feature_variation_regions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureVariationRegion {
+    /// The index of the feature variation record in `GSUB`.
+    pub index: usize,
+    /// The range of each axis (in user coordinates) the region covers.
+    ///
+    /// Axes that aren't listed can be at any value.
+    pub conditions: Vec<(Tag, RangeInclusive<f32>)>,
+}
+
+impl Reporter<'_> {
+    /// Gets the regions of the design space that each of the font's `GSUB`
+    /// feature variations apply in, in the order they're tried.
+    ///
+    /// Feature variations can substitute taller or deeper alternates in only
+    /// part of the design space, so it's worth checking inside each region.
+    /// Variations with conditions other than axis ranges are skipped, as they
+    /// are when shaping, as is any malformed data.
+    #[must_use]
+    pub fn feature_variation_regions(&self) -> Vec<FeatureVariationRegion> {
+        let Some(feature_variations) = self.feature_variations() else {
+            return Vec::new();
+        };
+        let axes = self.font.axes();
+        condition_sets(&feature_variations)
+            .filter_map(|(index, condition_set)| {
+                let conditions = match condition_set {
+                    Some(condition_set) => axis_ranges(&condition_set)?,
+                    None => Vec::new(),
+                };
+                let conditions = conditions
+                    .into_iter()
+                    .filter_map(|(axis_index, range)| {
+                        let axis = axes.get(axis_index)?;
+                        let start =
+                            denormalize(&self.font, &axis, *range.start());
+                        let end = denormalize(&self.font, &axis, *range.end());
+                        Some((axis.tag(), start..=end))
+                    })
+                    .collect();
+                Some(FeatureVariationRegion { index, conditions })
+            })
+            .collect()
+    }
+
+    /// Gets the index of the `GSUB` feature variation applied at `location`,
+    /// if any.
+    ///
+    /// As when shaping, only the first feature variation whose conditions are
+    /// all met is applied.
+    #[must_use]
+    pub fn active_feature_variation(
+        &self,
+        location: &Location,
+    ) -> Option<usize> {
+        let feature_variations = self.feature_variations()?;
+        let skrifa_location = location.to_skrifa(&self.font);
+        let coords = skrifa_location.coords();
+        condition_sets(&feature_variations).find_map(
+            |(index, condition_set)| {
+                let Some(condition_set) = condition_set else {
+                    // No conditions; applies everywhere
+                    return Some(index);
+                };
+                axis_ranges(&condition_set)?
+                    .into_iter()
+                    .all(|(axis_index, range)| {
+                        range.contains(
+                            &coords
+                                .get(axis_index)
+                                .copied()
+                                .unwrap_or_default(),
+                        )
+                    })
+                    .then_some(index)
+            },
+        )
+    }
+
+    fn feature_variations(&self) -> Option<FeatureVariations<'_>> {
+        self.font.gsub().ok()?.feature_variations()?.ok()
+    }
+}
+
+/// Each feature variation's index & condition set, skipping any that fail to
+/// parse. A missing condition set matches everywhere.
+fn condition_sets<'a>(
+    feature_variations: &FeatureVariations<'a>,
+) -> impl Iterator<Item = (usize, Option<ConditionSet<'a>>)> {
+    let data = feature_variations.offset_data();
+    feature_variations
+        .feature_variation_records()
+        .iter()
+        .enumerate()
+        .filter_map(move |(index, record)| match record.condition_set(data) {
+            Some(Ok(condition_set)) => Some((index, Some(condition_set))),
+            Some(Err(_)) => None,
+            None => Some((index, None)),
+        })
+}
+
+/// The normalized axis ranges of a condition set, or `None` if it has any
+/// other kind of condition, which can never be met.
+fn axis_ranges(
+    condition_set: &ConditionSet,
+) -> Option<Vec<(usize, RangeInclusive<F2Dot14>)>> {
+    condition_set
+        .conditions()
+        .iter()
+        // Malformed conditions are ignored by harfrust
+        .filter_map(Result::ok)
+        .map(|condition| match condition {
+            Condition::Format1AxisRange(range) => Some((
+                usize::from(range.axis_index()),
+                range.filter_range_min_value()..=range.filter_range_max_value(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use crate::VerticalExtremes;
+
+/// Counts of how high & low words reached, in bins of a fixed size.
+///
+/// Exemplars only show the most extreme words; a histogram shows how the rest
+/// are distributed, which helps when choosing vertical metrics. Collected
+/// while checking if
+/// [`Reporter::with_histogram`](crate::Reporter::with_histogram) is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    bin_size: f64,
+    highest: BTreeMap<i64, usize>,
+    lowest: BTreeMap<i64, usize>,
+}
+
+impl Histogram {
+    /// Create an empty histogram, with bins `bin_size` font units wide.
+    ///
+    /// # Panics
+    ///
+    /// If `bin_size` isn't positive & finite.
+    #[must_use]
+    pub fn new(bin_size: f64) -> Self {
+        assert!(
+            bin_size > 0.0 && bin_size.is_finite(),
+            "histogram bin size must be positive"
+        );
+        Histogram {
+            bin_size,
+            highest: BTreeMap::new(),
+            lowest: BTreeMap::new(),
+        }
+    }
+
+    /// The width of each bin, in font units.
+    #[inline]
+    #[must_use]
+    pub const fn bin_size(&self) -> f64 {
+        self.bin_size
+    }
+
+    /// Count a word that reached `extremes`.
+    pub fn add(&mut self, extremes: VerticalExtremes) {
+        *self
+            .highest
+            .entry(self.bin(extremes.highest()))
+            .or_default() += 1;
+        *self.lowest.entry(self.bin(extremes.lowest())).or_default() += 1;
+    }
+
+    /// Add the counts of another histogram, e.g. for another part of a word
+    /// list.
+    ///
+    /// # Panics
+    ///
+    /// If the histograms have different bin sizes.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        assert_eq!(
+            self.bin_size, other.bin_size,
+            "can't merge histograms with different bin sizes"
+        );
+        other.highest.into_iter().for_each(|(bin, count)| {
+            *self.highest.entry(bin).or_default() += count;
+        });
+        other.lowest.into_iter().for_each(|(bin, count)| {
+            *self.lowest.entry(bin).or_default() += count;
+        });
+        self
+    }
+
+    /// The number of words counted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.highest.values().sum()
+    }
+
+    /// Returns `true` if no words have been counted.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.highest.is_empty()
+    }
+
+    /// The bins of how high words reached, from lowest to highest, as the
+    /// start of each bin (in font units) & the number of words in it.
+    ///
+    /// Empty bins are skipped.
+    pub fn highest(&self) -> impl Iterator<Item = (f64, usize)> + '_ {
+        self.bins(&self.highest)
+    }
+
+    /// The bins of how low words reached, from lowest to highest, as the
+    /// start of each bin (in font units) & the number of words in it.
+    ///
+    /// Empty bins are skipped.
+    pub fn lowest(&self) -> impl Iterator<Item = (f64, usize)> + '_ {
+        self.bins(&self.lowest)
+    }
+
+    fn bins<'a>(
+        &'a self,
+        bins: &'a BTreeMap<i64, usize>,
+    ) -> impl Iterator<Item = (f64, usize)> + 'a {
+        bins.iter()
+            .map(|(&bin, &count)| (bin as f64 * self.bin_size, count))
+    }
+
+    fn bin(&self, value: f64) -> i64 {
+        (value / self.bin_size).floor() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binning() {
+        let mut histogram = Histogram::new(100.0);
+        histogram.add(VerticalExtremes::new(-250.0, 700.0));
+        histogram.add(VerticalExtremes::new(-200.0, 799.0));
+        let mut other = Histogram::new(100.0);
+        other.add(VerticalExtremes::new(0.0, 1000.0));
+        let histogram = histogram.merge(other);
+
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram.highest().collect::<Vec<_>>(), [
+            (700.0, 2),
+            (1000.0, 1),
+        ]);
+        assert_eq!(histogram.lowest().collect::<Vec<_>>(), [
+            (-300.0, 1),
+            (-200.0, 1),
+            (0.0, 1),
+        ]);
+    }
+}
@@ -0,0 +1,202 @@
+//! The extremes of an [`InstanceReporter`](crate::InstanceReporter)'s glyphs,
+//! either all drawn up-front or each drawn the first time a word needs it.
+
+use std::{
+    collections::HashMap,
+    mem,
+    sync::{Arc, OnceLock, PoisonError, RwLock},
+};
+
+use skrifa::{FontRef, GlyphId, MetadataProvider, raw::TableProvider};
+
+use crate::{
+    InstanceExtremes, Location, VerticalExtremes, draw_extremes,
+    errors::{GlyphBoundsError, MissingGlyphBoundsError},
+    measure::GlyphMeasure,
+    shaping::GlyphExtremes,
+};
+
+pub(crate) enum InstanceGlyphs<'a> {
+    /// Every glyph, drawn when the instance was created
+    Drawn(Arc<InstanceExtremes>),
+    /// Only the glyphs that words have shaped to so far
+    OnDemand(OnDemandExtremes<'a>),
+}
+
+impl InstanceGlyphs<'_> {
+    /// The extremes of every glyph, drawing any not drawn yet.
+    pub(crate) fn all(&self) -> &InstanceExtremes {
+        match self {
+            InstanceGlyphs::Drawn(instance_extremes) => instance_extremes,
+            InstanceGlyphs::OnDemand(on_demand) => on_demand.all(),
+        }
+    }
+
+    /// The extremes of the glyphs drawn so far, to share beyond the
+    /// instance.
+    pub(crate) fn shared(&self) -> Arc<InstanceExtremes> {
+        match self {
+            InstanceGlyphs::Drawn(instance_extremes) => {
+                Arc::clone(instance_extremes)
+            },
+            InstanceGlyphs::OnDemand(on_demand) => {
+                Arc::new(on_demand.drawn_so_far())
+            },
+        }
+    }
+
+    /// Estimate the memory used by the glyphs drawn so far, in bytes.
+    pub(crate) fn estimated_memory(&self) -> usize {
+        match self {
+            InstanceGlyphs::Drawn(instance_extremes) => {
+                instance_extremes.estimated_memory()
+            },
+            InstanceGlyphs::OnDemand(on_demand) => on_demand.estimated_memory(),
+        }
+    }
+}
+
+impl GlyphExtremes for InstanceGlyphs<'_> {
+    type Error = GlyphBoundsError;
+
+    fn glyph_extremes(
+        &self,
+        glyph_id: u32,
+    ) -> Result<VerticalExtremes, Self::Error> {
+        match self {
+            InstanceGlyphs::Drawn(instance_extremes) => instance_extremes
+                .glyph_extremes(glyph_id)
+                .map_err(GlyphBoundsError::Missing),
+            InstanceGlyphs::OnDemand(on_demand) => {
+                on_demand.glyph_extremes(glyph_id)
+            },
+        }
+    }
+}
+
+/// Draws glyphs the first time they're needed, caching their extremes for
+/// the other words (and threads) that need them.
+///
+/// Unlike the `DrawnExtremes` used when optimising locations, this is shared
+/// between the threads checking a word list, and glyphs without outlines are
+/// missing, as they are from an [`InstanceExtremes`].
+pub(crate) struct OnDemandExtremes<'a> {
+    font: &'a FontRef<'a>,
+    location: &'a Location,
+    glyph_measure: &'a dyn GlyphMeasure,
+    skrifa_location: skrifa::instance::Location,
+    // None for glyphs without outlines
+    drawn: RwLock<HashMap<u32, Option<VerticalExtremes>>>,
+    all: OnceLock<InstanceExtremes>,
+}
+
+impl<'a> OnDemandExtremes<'a> {
+    /// Draw the glyphs of `font` at `location` as they're needed. `location`
+    /// should already have been validated for the font.
+    pub(crate) fn new(
+        font: &'a FontRef<'a>,
+        location: &'a Location,
+        glyph_measure: &'a dyn GlyphMeasure,
+    ) -> Self {
+        OnDemandExtremes {
+            font,
+            location,
+            glyph_measure,
+            skrifa_location: location.to_skrifa(font),
+            drawn: RwLock::default(),
+            all: OnceLock::new(),
+        }
+    }
+
+    fn glyph_extremes(
+        &self,
+        glyph_id: u32,
+    ) -> Result<VerticalExtremes, GlyphBoundsError> {
+        let missing =
+            || GlyphBoundsError::Missing(MissingGlyphBoundsError(glyph_id));
+        if let Some(&extremes) = self
+            .drawn
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&glyph_id)
+        {
+            return extremes.ok_or_else(missing);
+        }
+        // Drawn outside the lock, so glyphs needed by several threads at once
+        // may be drawn more than once
+        let id = GlyphId::new(glyph_id);
+        let extremes = match self.font.outline_glyphs().get(id) {
+            Some(outline) => Some(
+                draw_extremes(
+                    id,
+                    &outline,
+                    &self.skrifa_location,
+                    self.glyph_measure,
+                )
+                .map_err(GlyphBoundsError::Drawing)?,
+            ),
+            None => None,
+        };
+        self.drawn
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(glyph_id, extremes);
+        extremes.ok_or_else(missing)
+    }
+
+    /// Draw every glyph, the first time it's called.
+    ///
+    /// Glyphs that can't be drawn are left out, so they're missing as glyphs
+    /// without outlines are.
+    fn all(&self) -> &InstanceExtremes {
+        self.all.get_or_init(|| {
+            InstanceExtremes::new(self.font, self.location, self.glyph_measure)
+                .unwrap_or_else(|_| {
+                    let num_glyphs =
+                        self.font.maxp().map_or(0, |maxp| maxp.num_glyphs());
+                    InstanceExtremes(
+                        (0..u32::from(num_glyphs))
+                            .filter_map(|glyph_id| {
+                                let extremes =
+                                    self.glyph_extremes(glyph_id).ok()?;
+                                Some((glyph_id, extremes))
+                            })
+                            .collect(),
+                    )
+                })
+        })
+    }
+
+    /// The extremes of the glyphs drawn so far, leaving out those without
+    /// outlines.
+    fn drawn_so_far(&self) -> InstanceExtremes {
+        if let Some(all) = self.all.get() {
+            return InstanceExtremes(all.0.clone());
+        }
+        InstanceExtremes(
+            self.drawn
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .filter_map(|(&glyph_id, &extremes)| {
+                    Some((glyph_id, extremes?))
+                })
+                .collect(),
+        )
+    }
+
+    fn estimated_memory(&self) -> usize {
+        let drawn = self
+            .drawn
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .capacity();
+        mem::size_of::<Self>()
+            // hashbrown stores a control byte alongside each bucket
+            + drawn * (mem::size_of::<(u32, Option<VerticalExtremes>)>() + 1)
+            + self
+                .all
+                .get()
+                .map_or(0, InstanceExtremes::estimated_memory)
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use skrifa::{
+    FontRef, MetadataProvider,
+    raw::{TableProvider, tables::gdef::GlyphClassDef},
+};
+
+use crate::VerticalExtremes;
+
+/// An invisible character that changes how the characters either side of it
+/// join, inserted into words by
+/// [`InstanceReporter::joiner_permutations`](crate::InstanceReporter::joiner_permutations).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum JoiningControl {
+    /// `U+200D ZERO WIDTH JOINER`, requesting joined or half forms.
+    ZeroWidthJoiner,
+    /// `U+200C ZERO WIDTH NON-JOINER`, breaking joins, ligatures & conjuncts.
+    ZeroWidthNonJoiner,
+    /// `U+00AD SOFT HYPHEN`, which is invisible unless the line breaks
+    /// there, but still sits between the characters while shaping.
+    SoftHyphen,
+}
+
+impl JoiningControl {
+    /// Every joining control.
+    pub const ALL: [JoiningControl; 3] = [
+        JoiningControl::ZeroWidthJoiner,
+        JoiningControl::ZeroWidthNonJoiner,
+        JoiningControl::SoftHyphen,
+    ];
+
+    /// The character inserted.
+    #[must_use]
+    pub const fn char(self) -> char {
+        match self {
+            JoiningControl::ZeroWidthJoiner => '\u{200D}',
+            JoiningControl::ZeroWidthNonJoiner => '\u{200C}',
+            JoiningControl::SoftHyphen => '\u{00AD}',
+        }
+    }
+
+    /// The character's abbreviation (e.g. `ZWNJ`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            JoiningControl::ZeroWidthJoiner => "ZWJ",
+            JoiningControl::ZeroWidthNonJoiner => "ZWNJ",
+            JoiningControl::SoftHyphen => "SHY",
+        }
+    }
+}
+
+impl fmt::Display for JoiningControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Whether joining controls change how text in `script` (an ISO 15924 code,
+/// e.g. `Arab`) shapes: cursive scripts, and Brahmic scripts with conjuncts.
+#[must_use]
+pub fn is_joining_script(script: &str) -> bool {
+    const CURSIVE: &[&str] = &[
+        "Adlm", "Arab", "Chrs", "Mand", "Mani", "Mong", "Nkoo", "Ougr", "Phag",
+        "Phlp", "Rohg", "Sogd", "Syrc",
+    ];
+    const BRAHMIC: &[&str] = &[
+        "Beng", "Deva", "Gujr", "Guru", "Khmr", "Knda", "Mlym", "Mymr", "Orya",
+        "Sinh", "Taml", "Telu", "Tibt",
+    ];
+    CURSIVE
+        .iter()
+        .chain(BRAHMIC)
+        .any(|joining| joining.eq_ignore_ascii_case(script))
+}
+
+/// A word with a [`JoiningControl`] inserted into it, and how high & low it
+/// reaches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinerPermutation {
+    /// The joining control inserted.
+    pub control: JoiningControl,
+    /// The byte offset in the original word the control was inserted at.
+    pub position: usize,
+    /// The word, with the control inserted.
+    pub word: String,
+    /// The extremes reached by the word with the control inserted.
+    pub extremes: VerticalExtremes,
+}
+
+/// The byte offsets in `word` that joining controls could plausibly be
+/// inserted at: between two characters, but not before a combining mark
+/// (which would separate it from its base) or next to another joining
+/// control.
+///
+/// Marks are found by the font's `GDEF` glyph classes, so if it has none,
+/// every position between characters is used.
+pub(crate) fn joiner_positions(font: &FontRef, word: &str) -> Vec<usize> {
+    let charmap = font.charmap();
+    let glyph_classes = font
+        .gdef()
+        .ok()
+        .and_then(|gdef| gdef.glyph_class_def()?.ok());
+    let is_mark = |c: char| {
+        charmap.map(c).zip(glyph_classes.as_ref()).is_some_and(
+            |(glyph_id, glyph_classes)| {
+                glyph_classes.get(glyph_id) == GlyphClassDef::Mark as u16
+            },
+        )
+    };
+    let is_control =
+        |c: char| JoiningControl::ALL.map(JoiningControl::char).contains(&c);
+
+    let chars = word.char_indices().collect::<Vec<_>>();
+    chars
+        .windows(2)
+        .filter(|pair| {
+            let [(_, before), (_, after)] = **pair else {
+                unreachable!("windows are pairs");
+            };
+            !before.is_whitespace()
+                && !after.is_whitespace()
+                && !is_control(before)
+                && !is_control(after)
+                && !is_mark(after)
+        })
+        .map(|pair| pair[1].0)
+        .collect()
+}
@@ -0,0 +1,174 @@
+//! Judging whether a font supports the language of a word list, so extremes
+//! from word lists it only half supports can be told apart.
+//!
+//! Set a [`LanguageSupport`] with
+//! [`Reporter::with_language_support`](crate::Reporter::with_language_support)
+//! to annotate each [`Report`](crate::Report) with a [`SupportLevel`].
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, PoisonError},
+};
+
+use skrifa::{FontRef, MetadataProvider};
+use static_lang_word_lists::WordList;
+
+/// How well a font supports the language of a word list.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SupportLevel {
+    /// The language isn't supported at all.
+    None,
+    /// Some of the language is supported (e.g. most of its letters, but not
+    /// all), so its extremes may be misleading.
+    Partial,
+    /// The language is fully supported.
+    Full,
+}
+
+impl SupportLevel {
+    /// A short, lowercase name for the level (e.g. `partial`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            SupportLevel::None => "none",
+            SupportLevel::Partial => "partial",
+            SupportLevel::Full => "full",
+        }
+    }
+}
+
+impl fmt::Display for SupportLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A way of judging how well a font supports the language of a word list.
+///
+/// Implement this to plug in a dedicated checker, e.g.
+/// [shaperglot](https://github.com/googlefonts/shaperglot), which checks a
+/// language's orthography & shaping rather than only its characters.
+/// [`CharacterCoverage`] is a simple built-in implementation.
+pub trait LanguageSupport: Send + Sync {
+    /// Judge how well `font` supports the language of `word_list`, or `None`
+    /// if it can't be judged (e.g. the word list has no language).
+    ///
+    /// Judgements are cached per [`Reporter`](crate::Reporter), so this is
+    /// usually called once per word list, however many locations it's checked
+    /// at.
+    fn support(
+        &self,
+        font: &FontRef,
+        word_list: &WordList,
+    ) -> Option<SupportLevel>;
+}
+
+impl<F> LanguageSupport for F
+where
+    F: Fn(&FontRef, &WordList) -> Option<SupportLevel> + Send + Sync,
+{
+    fn support(
+        &self,
+        font: &FontRef,
+        word_list: &WordList,
+    ) -> Option<SupportLevel> {
+        self(font, word_list)
+    }
+}
+
+/// Judges support by how many of a word list's words the font has characters
+/// for: full if at least 99% of them, none if none of them, and partial
+/// otherwise.
+///
+/// A few words with stray characters (e.g. symbols or loanwords) don't stop a
+/// language being fully supported. Only characters are checked, not shaping.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CharacterCoverage;
+
+impl LanguageSupport for CharacterCoverage {
+    fn support(
+        &self,
+        font: &FontRef,
+        word_list: &WordList,
+    ) -> Option<SupportLevel> {
+        if word_list.is_empty() {
+            return None;
+        }
+        let charmap = font.charmap();
+        let covered = word_list
+            .iter()
+            .filter(|word| {
+                word.chars().all(|c| {
+                    // Fonts needn't map invisible controls (e.g. ZWJ)
+                    is_default_ignorable(c) || charmap.map(c).is_some()
+                })
+            })
+            .count();
+        Some(if covered == 0 {
+            SupportLevel::None
+        } else if covered * 100 >= word_list.len() * 99 {
+            SupportLevel::Full
+        } else {
+            SupportLevel::Partial
+        })
+    }
+}
+
+/// The most common `Default_Ignorable_Code_Point`s in word lists.
+const fn is_default_ignorable(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{034F}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{2060}'..='\u{206F}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+    )
+}
+
+/// A [`LanguageSupport`], and what it judged each word list checked so far.
+pub(crate) struct LanguageSupportCache {
+    checker: Box<dyn LanguageSupport>,
+    // By word list ID, with each word list's address to tell apart word lists
+    // that share an ID (e.g. filtered or loaded twice)
+    levels: Mutex<HashMap<(String, usize), Option<SupportLevel>>>,
+}
+
+impl LanguageSupportCache {
+    pub(crate) fn new(checker: impl LanguageSupport + 'static) -> Self {
+        LanguageSupportCache {
+            checker: Box::new(checker),
+            levels: Mutex::default(),
+        }
+    }
+
+    /// Judge the support of `word_list`, or recall the previous judgement.
+    pub(crate) fn support(
+        &self,
+        font: &FontRef,
+        word_list: &WordList,
+    ) -> Option<SupportLevel> {
+        let key = (
+            word_list.id().to_string(),
+            std::ptr::from_ref(word_list) as usize,
+        );
+        if let Some(&level) = self
+            .levels
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+        {
+            return level;
+        }
+        // Judged outside the lock, as it may be slow. Word lists checked at
+        // many locations at once may be judged more than once
+        let level = self.checker.support(font, word_list);
+        self.levels
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, level);
+        level
+    }
+}
@@ -31,47 +31,366 @@
 use std::{
     borrow::Cow,
     cmp,
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeSet, HashMap, HashSet},
+    iter, mem,
+    str::FromStr,
+    sync::{Arc, OnceLock, atomic::AtomicBool},
 };
 
-pub use exemplars::{CollectToExemplars, Exemplars};
-use harfrust::{Shaper, ShaperData, ShaperInstance, UnicodeBuffer};
+#[cfg(feature = "async")]
+pub use asynchronous::CheckFuture;
+pub use attribution::{DeltaAttribution, ExtremeDelta, GlyphDelta};
+use axes::AxisCoords;
+pub use axes::AxisInfo;
+pub use cancellation::CancellationToken;
+pub use clipping::ClippingProfile;
+pub use declared_languages::{DeclaredLanguage, LanguageDeclaration};
+pub use em_box::{EmBox, EmBoxSource, is_cjk_script};
+pub use exclusion::{CharCategory, Pattern, WordFilter};
+pub use exemplars::{CollectToExemplars, Exemplars, ExemplarsIter};
+pub use feature_variations::FeatureVariationRegion;
+use harfrust::{
+    GlyphBuffer, Script, Shaper, ShaperData, ShaperInstance, UnicodeBuffer,
+    script,
+};
 pub use harfshapedfa::Location;
-use harfshapedfa::{HarfRustShaperExt, ShapingMeta, pens::BoundsPen};
+use harfshapedfa::{
+    ShapingMeta,
+    convert::{iso639_to_opentype_all, iso15924_to_opentype},
+    pens::BoundsPen,
+};
+pub use histogram::Histogram;
+use instance_glyphs::{InstanceGlyphs, OnDemandExtremes};
 use itertools::Itertools;
-use ordered_float::{NotNan, OrderedFloat};
+pub use joiners::{JoinerPermutation, JoiningControl, is_joining_script};
+use language_support::{LanguageSupport, LanguageSupportCache, SupportLevel};
+pub use line_box::{LineBox, LineMetrics};
+use locations::LocationStrategy;
+use measure::{ControlBounds, ExactBounds, GlyphMeasure};
+pub use optimize::{Extreme, OptimizedLocation};
+pub use options::{
+    AnalysisOptions, ExemplarFilter, ExemplarSearch, Measurement,
+};
+use ordered_float::NotNan;
+pub use owned::{OwnedExemplars, OwnedReport, OwnedWordExtremes};
+pub use provenance::Provenance;
+pub use punctuation::Punctuation;
+pub use segmentation::Segmentation;
+use session::InstanceCache;
+pub use session::{AnalysisSession, SessionFont};
+pub use shaping::{
+    ClusterExtremes, PlacedGlyph, RunExtremes, ShapingProperties,
+    TextDirection, measure_shaped_word,
+};
+use shaping::{
+    FallbackCoverage, GlyphExtremes, Shaped, ShapedWord, WordShaper,
+};
+use shaping_cache::ShapingCache;
 use skrifa::{
-    FontRef, MetadataProvider, instance::Size, outline::DrawSettings,
+    FontRef, GlyphId, MetadataProvider, Tag,
+    instance::{LocationRef, Size},
+    outline::{DrawSettings, OutlineGlyph},
+    raw::{
+        ReadError, TableProvider, tables::layout::ScriptList, types::F2Dot14,
+    },
 };
-pub use static_lang_word_lists::WordList;
 use static_lang_word_lists::WordListIter;
+pub use static_lang_word_lists::{WordList, WordListId};
+pub use statistics::ReportStatistics;
+pub use thresholds::{Exceeding, ThresholdIteratorExt, Thresholds};
+pub use warnings::ReportWarning;
+pub use word_length::{LengthUnit, LongWords, WordLengthLimit};
+use word_source::WordSource;
 
 use crate::errors::{
-    FontHeightError, SkrifaDrawError, SkrifaReadError, WordListShapingPlanError,
+    FontHeightError, MissingGlyphBoundsError, SkrifaDrawError, SkrifaReadError,
+    WordListShapingPlanError,
 };
 
+#[cfg(feature = "async")]
+mod asynchronous;
+mod attribution;
+mod axes;
+mod cancellation;
+mod clipping;
+mod declared_languages;
+mod em_box;
 pub mod errors;
+mod exclusion;
 mod exemplars;
+mod feature_variations;
+mod histogram;
+mod instance_glyphs;
+mod joiners;
+pub mod language_support;
+mod line_box;
+pub mod locations;
+pub mod measure;
+mod optimize;
+mod options;
+mod owned;
+mod provenance;
+mod punctuation;
+mod segmentation;
+mod session;
+mod shaping;
+mod shaping_cache;
+mod statistics;
+mod thresholds;
+mod warnings;
+mod word_length;
+pub mod word_source;
 
 /// Font Height's entrypoint. Parses fonts and can check word lists at
 /// specified locations.
 pub struct Reporter<'a> {
     font: FontRef<'a>,
-    shaper_data: ShaperData,
+    shaper_data: Arc<ShaperData>,
+    supported_scripts: OnceLock<BTreeSet<String>>,
+    glyph_measure: Box<dyn GlyphMeasure>,
+    on_demand_glyphs: bool,
+    multi_line: bool,
+    default_location: Location,
+    fallback_coverage: Option<FallbackCoverage>,
+    histogram_bin_size: Option<f64>,
+    shaping_cache: Option<Arc<ShapingCache>>,
+    language_support: Option<Arc<LanguageSupportCache>>,
+    // Kept by an AnalysisSession, None once glyphs are measured differently
+    instance_cache: Option<Arc<InstanceCache>>,
 }
 
 impl<'a> Reporter<'a> {
     /// Parses the byte slice as a font to create a new [`Reporter`].
     ///
-    /// Fails if the bytes couldn't be parsed.
+    /// Fails if the bytes couldn't be parsed, or if the font has no outlines
+    /// (see [`FontHeightError::NoOutlines`]).
     pub fn new(font_bytes: &'a [u8]) -> Result<Self, FontHeightError> {
         let font = FontRef::new(font_bytes).map_err(SkrifaReadError::from)?;
-        Ok(Reporter {
-            shaper_data: ShaperData::new(&font),
+        if font.outline_glyphs().format().is_none() {
+            return Err(FontHeightError::NoOutlines);
+        }
+        let shaper_data = Arc::new(ShaperData::new(&font));
+        Ok(Reporter::from_shared(font, shaper_data, None))
+    }
+
+    /// Create a [`Reporter`] for an already-parsed font, e.g. one kept by an
+    /// [`AnalysisSession`].
+    fn from_shared(
+        font: FontRef<'a>,
+        shaper_data: Arc<ShaperData>,
+        instance_cache: Option<Arc<InstanceCache>>,
+    ) -> Self {
+        Reporter {
             font,
+            shaper_data,
+            supported_scripts: OnceLock::new(),
+            glyph_measure: Box::new(ExactBounds),
+            on_demand_glyphs: false,
+            multi_line: false,
+            default_location: Location::new(),
+            fallback_coverage: None,
+            histogram_bin_size: None,
+            shaping_cache: None,
+            language_support: None,
+            instance_cache,
+        }
+    }
+
+    /// Measure glyphs using `glyph_measure`, rather than the default of
+    /// [`ExactBounds`].
+    ///
+    /// Only affects [`InstanceReporter`]s created after this is set.
+    #[must_use]
+    pub fn with_glyph_measure(
+        mut self,
+        glyph_measure: impl GlyphMeasure + 'static,
+    ) -> Self {
+        self.glyph_measure = Box::new(glyph_measure);
+        // Glyphs drawn by other reporters were measured with ExactBounds
+        self.instance_cache = None;
+        self
+    }
+
+    /// Only draw the glyphs that words shape to, the first time they're
+    /// needed, rather than drawing every glyph in the font when an
+    /// [`InstanceReporter`] is created.
+    ///
+    /// Saves memory (and time) for fonts with many glyphs (e.g. CJK fonts)
+    /// checked with word lists that only use a few of them, especially when
+    /// checking many locations at once. Glyphs that fail to draw are then
+    /// only reported when a word needs them.
+    ///
+    /// Only affects [`InstanceReporter`]s created after this is set.
+    #[must_use]
+    pub const fn with_on_demand_glyphs(mut self, on_demand: bool) -> Self {
+        self.on_demand_glyphs = on_demand;
+        self
+    }
+
+    /// Measure words containing newlines (`\n`) as multi-line blocks, rather
+    /// than shaping the newlines along with the rest of the word.
+    ///
+    /// Each line is placed below the previous one by the font's line height
+    /// (ascender − descender + line gap, from `OS/2` if `USE_TYPO_METRICS` is
+    /// set, `hhea` otherwise), and the extremes reported are those of the
+    /// whole block relative to the first line's baseline. Blank lines are
+    /// skipped, though they still take up a line.
+    ///
+    /// Only affects [`InstanceReporter`]s created after this is set.
+    #[must_use]
+    pub const fn with_multi_line(mut self, multi_line: bool) -> Self {
+        self.multi_line = multi_line;
+        self
+    }
+
+    /// Collect a [`Histogram`] of how high & low the words checked reached,
+    /// with bins `bin_size` font units wide, in each [`Report`].
+    ///
+    /// Only affects [`InstanceReporter`]s created after this is set.
+    ///
+    /// # Panics
+    ///
+    /// If `bin_size` isn't positive & finite.
+    #[must_use]
+    pub fn with_histogram(mut self, bin_size: f64) -> Self {
+        assert!(
+            bin_size > 0.0 && bin_size.is_finite(),
+            "histogram bin size must be positive"
+        );
+        self.histogram_bin_size = Some(bin_size);
+        self
+    }
+
+    /// Leave characters missing from the font to `fallback_fonts`, as a
+    /// renderer would, rather than skipping words containing them.
+    ///
+    /// Only the fallback fonts' `cmap`s are used: clusters containing
+    /// missing glyphs are measured as if drawn by a fallback font, so are
+    /// excluded from the words' extremes. This keeps words that would
+    /// otherwise be skipped for a single missing character (e.g. uncommon
+    /// punctuation). Words are still skipped if the fallback fonts don't
+    /// support their missing characters either. The number of words measured
+    /// like this is counted in [`ReportStatistics::fallback`].
+    ///
+    /// Only affects [`InstanceReporter`]s created after this is set. Fails if
+    /// any of `fallback_fonts` can't be parsed.
+    pub fn with_fallback_fonts(
+        mut self,
+        fallback_fonts: &[&[u8]],
+    ) -> Result<Self, SkrifaReadError> {
+        let coverage = fallback_fonts
+            .iter()
+            .map(|font_bytes| {
+                let font = FontRef::new(font_bytes)?;
+                Ok(font
+                    .charmap()
+                    .mappings()
+                    .map(|(codepoint, _)| codepoint)
+                    .collect::<Vec<_>>())
+            })
+            .flatten_ok()
+            .collect::<Result<HashSet<_>, ReadError>>()?;
+        self.fallback_coverage =
+            (!fallback_fonts.is_empty()).then(|| Arc::new(coverage));
+        // Which characters are missing depends on the fallback fonts
+        if self.shaping_cache.is_some() {
+            self.shaping_cache = Some(Arc::default());
+        }
+        Ok(self)
+    }
+
+    /// Shape each word only once, re-using the glyphs it shaped to (and their
+    /// positions) at every location checked, rather than shaping it again at
+    /// each one. Cuts the time taken to check many locations, at the cost of
+    /// holding the glyphs of every word checked in memory.
+    ///
+    /// Only takes effect for fonts that shape words the same way at every
+    /// location: those without `GSUB`/`GPOS` feature variations or varying
+    /// `GPOS` values, which have a `GPOS` table and no AAT tables. See
+    /// [`Reporter::caches_shaping`].
+    ///
+    /// The cache is shared by all [`InstanceReporter`]s created after this is
+    /// set, and only used by [`InstanceReporter::check`] & the methods
+    /// wrapping it.
+    #[must_use]
+    pub fn with_shaping_cache(mut self, shaping_cache: bool) -> Self {
+        self.shaping_cache = (shaping_cache
+            && !shaping_cache::shaping_varies(&self.font))
+        .then(Arc::default);
+        self
+    }
+
+    /// Judge how well the font supports the language of each word list with
+    /// `language_support`, recording it in each [`Report`] (see
+    /// [`Report::support`]).
+    ///
+    /// Each word list is judged once, however many locations it's checked at.
+    /// Only affects [`InstanceReporter`]s created after this is set.
+    #[must_use]
+    pub fn with_language_support(
+        mut self,
+        language_support: impl LanguageSupport + 'static,
+    ) -> Self {
+        self.language_support =
+            Some(Arc::new(LanguageSupportCache::new(language_support)));
+        self
+    }
+
+    /// Whether shaping results are re-used between locations, if enabled with
+    /// [`Reporter::with_shaping_cache`] and supported by the font.
+    #[inline]
+    #[must_use]
+    pub const fn caches_shaping(&self) -> bool {
+        self.shaping_cache.is_some()
+    }
+
+    /// The distance between the baselines of multi-line words at `location`,
+    /// if measuring them.
+    pub(crate) fn line_height(
+        &self,
+        location: &skrifa::instance::Location,
+    ) -> Option<f64> {
+        self.multi_line.then(|| {
+            let metrics = self.font.metrics(Size::unscaled(), location);
+            f64::from(metrics.ascent - metrics.descent + metrics.leading)
         })
     }
 
+    /// The extremes beyond which text is clipped by `profile`, in font units.
+    ///
+    /// Metrics are read from the font's tables as-is, so don't include any
+    /// variations from `MVAR`.
+    pub fn clip_box(
+        &self,
+        profile: ClippingProfile,
+    ) -> Result<VerticalExtremes, SkrifaReadError> {
+        profile.clip_box(&self.font)
+    }
+
+    /// The space each line takes up when lines are stacked by `metrics`.
+    ///
+    /// Metrics are read from the font's tables as-is, so don't include any
+    /// variations from `MVAR`.
+    pub fn line_box(
+        &self,
+        metrics: LineMetrics,
+    ) -> Result<LineBox, SkrifaReadError> {
+        LineBox::new(&self.font, metrics)
+    }
+
+    /// The font's ideographic em-box, that CJK layouts clip & align text by.
+    ///
+    /// It's read from the `ideo` & `idtp` baselines of the `BASE` table (for
+    /// `hani`, then `kana`, `hang` & `DFLT`), then from the typo ascender &
+    /// descender if they span exactly one UPM, falling back to the
+    /// conventional 880 & -120 per 1000 units. See [`EmBox::source`] for
+    /// which was used.
+    ///
+    /// Baselines are read as-is, so don't include any variations.
+    pub fn ideographic_em_box(&self) -> Result<EmBox, SkrifaReadError> {
+        EmBox::new(&self.font)
+    }
+
     /// Access the `read-fonts`-parsed font.
     ///
     /// ⚠️ Warning: changes to the return type of this function (i.e. by
@@ -83,46 +402,263 @@ impl<'a> Reporter<'a> {
         &self.font
     }
 
+    /// Problems with the font that may make the reports made for it
+    /// misleading (e.g. [`ReportWarning::NoNamedInstances`]).
+    ///
+    /// These aren't repeated in each [`Report::warnings`].
+    #[must_use]
+    pub fn warnings(&self) -> Vec<ReportWarning> {
+        warnings::font_warnings(&self.font)
+    }
+
+    /// Gets the font's named instances, with their names & [`Location`]s.
+    ///
+    /// Names are the instance's subfamily name (e.g. "Bold Condensed") from
+    /// the `name` table, preferring English. Instances missing a name are
+    /// named by their index instead (e.g. "Instance 3").
+    #[must_use]
+    pub fn named_instances(&self) -> Vec<(String, Location)> {
+        let axes = self.font.axes();
+        self.font
+            .named_instances()
+            .iter()
+            .enumerate()
+            .map(|(index, instance)| {
+                let name = self
+                    .font
+                    .localized_strings(instance.subfamily_name_id())
+                    .english_or_first()
+                    .map_or_else(
+                        || format!("Instance {index}"),
+                        |name| name.to_string(),
+                    );
+                let location = axes::named_instance_coords(&axes, &instance)
+                    .into_iter()
+                    .collect();
+                (name, location)
+            })
+            .collect()
+    }
+
+    /// Approximates the locations of the font's masters (the sources it was
+    /// built from) from the peaks of its glyph variations in `gvar`.
+    ///
+    /// Each distinct peak is converted back to user coordinates (undoing any
+    /// `avar` mapping), with axes that don't vary at that peak at their default
+    /// values. The default location is not included. Fonts without a `gvar`
+    /// table (e.g. static fonts, or `CFF2` fonts) have no master locations.
+    ///
+    /// Unlike [`Reporter::interesting_locations`], this scales with the number
+    /// of masters rather than exponentially with the number of axes, and
+    /// includes intermediate masters that the axis extremes don't cover.
+    pub fn master_locations(&self) -> Result<Vec<Location>, SkrifaReadError> {
+        let gvar = match self.font.gvar() {
+            Ok(gvar) => gvar,
+            Err(ReadError::TableIsMissing(_)) => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut peaks = BTreeSet::new();
+        for glyph_id in 0..u32::from(gvar.glyph_count()) {
+            let Some(variations) =
+                gvar.glyph_variation_data(GlyphId::new(glyph_id))?
+            else {
+                continue;
+            };
+            peaks.extend(variations.tuples().map(|variation| {
+                variation
+                    .peak()
+                    .values()
+                    .iter()
+                    .map(|coord| coord.get().to_bits())
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        Ok(peaks
+            .into_iter()
+            .map(|peak| {
+                let coords = peak
+                    .into_iter()
+                    .map(F2Dot14::from_bits)
+                    .collect::<Vec<_>>();
+                Location::from_normalized(&self.font, LocationRef::new(&coords))
+            })
+            .collect())
+    }
+
+    /// Gets the [`Location`] that normalizes (including `avar`) to `coords`,
+    /// given in `fvar` axis order.
+    ///
+    /// Coordinates are clamped to `-1.0..=1.0`. Missing coordinates are taken
+    /// to be the default, and extra coordinates are ignored.
+    ///
+    /// Sampling uniformly in normalized space (e.g. for random locations)
+    /// spreads points evenly between masters, whereas sampling user
+    /// coordinates may leave parts of the design space unchecked.
+    #[must_use]
+    pub fn location_from_normalized(&self, coords: &[f32]) -> Location {
+        let coords = coords
+            .iter()
+            .map(|coord| F2Dot14::from_f32(coord.clamp(-1.0, 1.0)))
+            .collect::<Vec<_>>();
+        Location::from_normalized(&self.font, LocationRef::new(&coords))
+    }
+
     /// Gets all combinations of axis coordinates seen in named instances, axis
-    /// extremes, and the default location.
+    /// extremes, the middle of each `GSUB` feature variation's region (see
+    /// [`Reporter::feature_variation_regions`]), and the default location.
     ///
     /// Note: the number of [`Location`]s this method returns scales
     /// exponentially with the number of axes.
     #[must_use]
     pub fn interesting_locations(&self) -> Vec<Location> {
-        // Note: this could probably be a NotNan<f32>, but then all the .into
-        // calls become exceedingly painful
+        let axes = self.font.axes();
         let mut axis_coords =
-            vec![BTreeSet::<OrderedFloat<f32>>::new(); self.font.axes().len()];
+            AxisCoords::new(axes.iter().map(|axis| axis.tag()));
 
         self.font
             .named_instances()
             .iter()
-            .flat_map(|instance| instance.user_coords().enumerate())
-            .for_each(|(axis, coord)| {
-                axis_coords[axis].insert(coord.into());
-            });
+            .flat_map(|instance| axes::named_instance_coords(&axes, &instance))
+            .for_each(|(tag, coord)| axis_coords.insert(tag, coord));
 
-        self.font.axes().iter().for_each(|axis| {
-            axis_coords[axis.index()].extend(&[
-                axis.default_value().into(),
-                axis.min_value().into(),
-                axis.max_value().into(),
-            ]);
+        axes.iter().for_each(|axis| {
+            [axis.default_value(), axis.min_value(), axis.max_value()]
+                .into_iter()
+                .for_each(|coord| axis_coords.insert(axis.tag(), coord));
         });
 
-        axis_coords
+        // Feature variations may substitute different glyphs in only part of
+        // the design space, so make sure a point inside each is checked
+        self.feature_variation_regions()
             .iter()
-            .multi_cartesian_product()
-            .map(|coords| {
-                self.font
-                    .axes()
-                    .iter()
-                    .zip(coords)
-                    .map(|(axis, coord)| (axis.tag(), coord.into_inner()))
-                    .collect()
-            })
-            .collect()
+            .flat_map(|region| &region.conditions)
+            .for_each(|(tag, range)| {
+                axis_coords.insert(*tag, (range.start() + range.end()) / 2.0);
+            });
+
+        axis_coords.locations()
+    }
+
+    /// Gets the [`Location`]s chosen by `strategy`, e.g.
+    /// [`locations::Corners`] to skip named instances.
+    ///
+    /// [`Reporter::interesting_locations`] is the same as
+    /// [`locations::CornersAndNamed`].
+    pub fn locations(
+        &self,
+        strategy: &(impl LocationStrategy + ?Sized),
+    ) -> Result<Vec<Location>, FontHeightError> {
+        strategy.locations(self)
+    }
+
+    /// Gets the scripts the font supports, as
+    /// [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) codes (e.g.
+    /// `Latn`).
+    ///
+    /// A script is considered supported if any of its codepoints are mapped in
+    /// the font's `cmap`. Codepoints shared between scripts (e.g. digits &
+    /// punctuation) don't count towards any script.
+    ///
+    /// This is computed on first use, and cached thereafter.
+    #[must_use]
+    pub fn supported_scripts(&self) -> &BTreeSet<String> {
+        self.supported_scripts.get_or_init(|| {
+            let mut buffer = UnicodeBuffer::new();
+            self.font
+                .charmap()
+                .mappings()
+                .filter_map(|(codepoint, _)| char::from_u32(codepoint))
+                .filter_map(|c| {
+                    buffer.clear();
+                    buffer.add(c, 0);
+                    buffer.guess_segment_properties();
+                    let script = buffer.script();
+                    (!is_shared_script(script)).then_some(script)
+                })
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|script| script.tag().to_string())
+                .collect()
+        })
+    }
+
+    /// Whether the [`WordList`] is written in a script the font supports (see
+    /// [`Reporter::supported_scripts`]).
+    ///
+    /// Word lists without a script, or whose script is shared between scripts
+    /// (e.g. `Zyyy`), are always considered supported.
+    #[must_use]
+    pub fn supports_word_list(&self, word_list: &WordList) -> bool {
+        let Some(script) = word_list
+            .script()
+            .and_then(|script| Script::from_str(script).ok())
+        else {
+            return true;
+        };
+        is_shared_script(script)
+            || self.supported_scripts().contains(&script.tag().to_string())
+    }
+
+    /// Gets the languages the font declares it's designed for (`dlng`) or
+    /// supports (`slng`) in its `meta` table, designed-for languages first.
+    ///
+    /// Fonts without a `meta` table declare no languages. Use
+    /// [`DeclaredLanguage::matches`] to find the word lists covering each.
+    pub fn declared_languages(
+        &self,
+    ) -> Result<Vec<DeclaredLanguage>, SkrifaReadError> {
+        declared_languages::declared_languages(&self.font)
+    }
+
+    /// Find the OpenType language system the font has for the [`WordList`]'s
+    /// language, in its `GSUB` or `GPOS` table.
+    ///
+    /// Where a language may be covered by several language systems (see
+    /// [`iso639_to_opentype_all`]), the most preferred is returned.
+    ///
+    /// Returns `Ok(None)` if the word list has no script or language, or if the
+    /// font has no language system for it; shaping then uses the script's
+    /// default language system.
+    pub fn language_system(
+        &self,
+        word_list: &WordList,
+    ) -> Result<Option<Tag>, SkrifaReadError> {
+        let (Some(script), Some(language)) =
+            (word_list.script(), word_list.language())
+        else {
+            return Ok(None);
+        };
+        let language_tags = iso639_to_opentype_all(language);
+        // Fonts may still use the old tags for Indic scripts (e.g. `deva`
+        // instead of `dev2`)
+        let script_tags = [
+            iso15924_to_opentype(script).ok(),
+            Tag::new_checked(script.to_ascii_lowercase().as_bytes()).ok(),
+        ];
+
+        let script_lists = [
+            self.font.gsub().map(|gsub| gsub.script_list()),
+            self.font.gpos().map(|gpos| gpos.script_list()),
+        ];
+        let mut best = None::<usize>;
+        for script_list in script_lists {
+            let script_list = match script_list {
+                Ok(script_list) => script_list?,
+                Err(ReadError::TableIsMissing(_)) => continue,
+                Err(why) => return Err(why.into()),
+            };
+            best = best
+                .into_iter()
+                .chain(language_system_index(
+                    &script_list,
+                    &script_tags,
+                    &language_tags,
+                )?)
+                .min();
+        }
+        Ok(best.map(|index| language_tags[index]))
     }
 
     /// Create an [`InstanceReporter`] at a given location.
@@ -141,16 +677,40 @@ impl<'a> Reporter<'a> {
         location: &'a Location,
     ) -> Result<InstanceReporter<'a>, FontHeightError> {
         // Creating InstanceExtremes also validates the Location; do this first
-        let instance_extremes = InstanceExtremes::new(&self.font, location)?;
+        let instance_extremes = if self.on_demand_glyphs {
+            location.validate_for(&self.font)?;
+            InstanceGlyphs::OnDemand(OnDemandExtremes::new(
+                &self.font,
+                location,
+                self.glyph_measure.as_ref(),
+            ))
+        } else {
+            InstanceGlyphs::Drawn(self.draw_instance(location)?)
+        };
         let shaper_instance =
             ShaperInstance::from_variations(&self.font, location.to_harfrust());
 
+        let line_height = self.line_height(&location.to_skrifa(&self.font));
+        let feature_variation = self.active_feature_variation(location);
+
         Ok(InstanceReporter {
             font: &self.font,
-            location: Cow::Borrowed(location),
+            location,
             shaper_data: &self.shaper_data,
             shaper_instance,
             instance_extremes,
+            // &ControlBounds is promoted to a static
+            control_boxes: InstanceGlyphs::OnDemand(OnDemandExtremes::new(
+                &self.font,
+                location,
+                &ControlBounds,
+            )),
+            line_height,
+            feature_variation,
+            fallback_coverage: self.fallback_coverage.clone(),
+            histogram_bin_size: self.histogram_bin_size,
+            shaping_cache: self.shaping_cache.clone(),
+            language_support: self.language_support.clone(),
         })
     }
 
@@ -160,28 +720,112 @@ impl<'a> Reporter<'a> {
     pub fn default_instance(
         &'a self,
     ) -> Result<InstanceReporter<'a>, SkrifaDrawError> {
-        let location = Cow::<Location>::default();
-        let instance_extremes = InstanceExtremes::new(&self.font, &location)
-            .map_err(|err| {
-                let FontHeightError::Drawing(draw_err) = err else {
-                    unreachable!(
-                        "InstanceExtremes with a known-good location returned \
-                         an error that wasn't a SkrifaDrawError"
-                    );
-                };
-                draw_err
-            })?;
+        let location = &self.default_location;
+        let instance_extremes = if self.on_demand_glyphs {
+            InstanceGlyphs::OnDemand(OnDemandExtremes::new(
+                &self.font,
+                location,
+                self.glyph_measure.as_ref(),
+            ))
+        } else {
+            let instance_extremes =
+                self.draw_instance(location).map_err(|err| {
+                    let FontHeightError::Drawing(draw_err) = err else {
+                        unreachable!(
+                            "InstanceExtremes with a known-good location \
+                             returned an error that wasn't a SkrifaDrawError"
+                        );
+                    };
+                    draw_err
+                })?;
+            InstanceGlyphs::Drawn(instance_extremes)
+        };
         let shaper_instance =
             ShaperInstance::from_variations(&self.font, location.to_harfrust());
 
+        let line_height = self.line_height(&location.to_skrifa(&self.font));
+        let feature_variation = self.active_feature_variation(location);
+
         Ok(InstanceReporter {
             font: &self.font,
             location,
             shaper_data: &self.shaper_data,
             shaper_instance,
             instance_extremes,
+            // &ControlBounds is promoted to a static
+            control_boxes: InstanceGlyphs::OnDemand(OnDemandExtremes::new(
+                &self.font,
+                location,
+                &ControlBounds,
+            )),
+            line_height,
+            feature_variation,
+            fallback_coverage: self.fallback_coverage.clone(),
+            histogram_bin_size: self.histogram_bin_size,
+            shaping_cache: self.shaping_cache.clone(),
+            language_support: self.language_support.clone(),
         })
     }
+
+    /// Draw every glyph at `location`, or get them from the
+    /// [`AnalysisSession`] if it's already drawn them.
+    fn draw_instance(
+        &self,
+        location: &Location,
+    ) -> Result<Arc<InstanceExtremes>, FontHeightError> {
+        let draw = || {
+            InstanceExtremes::new(
+                &self.font,
+                location,
+                self.glyph_measure.as_ref(),
+            )
+        };
+        match &self.instance_cache {
+            Some(instance_cache) => instance_cache.get_or_draw(location, draw),
+            None => draw().map(Arc::new),
+        }
+    }
+}
+
+/// Create the [`ShapingMeta`] for a [`WordList`] (or any other
+/// [`WordSource`]), if it specifies a script.
+fn shaping_meta(
+    word_list: &(impl WordSource + ?Sized),
+    shaper: &Shaper,
+) -> Result<Option<ShapingMeta>, WordListShapingPlanError> {
+    shaping_meta_with_features(word_list, &[], shaper)
+}
+
+/// Like [`shaping_meta`], also applying `features` on top of the word list's
+/// own.
+fn shaping_meta_with_features(
+    word_list: &(impl WordSource + ?Sized),
+    features: &[String],
+    shaper: &Shaper,
+) -> Result<Option<ShapingMeta>, WordListShapingPlanError> {
+    word_list
+        .script()
+        .map(|script| {
+            ShapingMeta::new_with_features(
+                script,
+                word_list.language(),
+                word_list
+                    .features()
+                    .chain(features.iter().map(String::as_str)),
+                shaper,
+            )
+        })
+        .transpose()
+        .map_err(|err| WordListShapingPlanError {
+            word_list_name: word_list.name().to_owned(),
+            inner: err,
+        })
+}
+
+/// Whether the script is used across many scripts, rather than being a script
+/// in its own right.
+const fn is_shared_script(script: Script) -> bool {
+    matches!(script, script::COMMON | script::INHERITED | script::UNKNOWN)
 }
 
 /// A Font Height [`Reporter`] configured to a specific font instance.
@@ -189,269 +833,1247 @@ impl<'a> Reporter<'a> {
 /// Re-use this if you want to check multiple word-lists at this location.
 pub struct InstanceReporter<'a> {
     font: &'a FontRef<'a>,
-    location: Cow<'a, Location>,
+    location: &'a Location,
     shaper_data: &'a ShaperData,
     shaper_instance: ShaperInstance,
-    instance_extremes: InstanceExtremes,
+    instance_extremes: InstanceGlyphs<'a>,
+    // Drawn as checks measuring by control boxes (or searching for exemplars
+    // by them) need them
+    control_boxes: InstanceGlyphs<'a>,
+    line_height: Option<f64>,
+    feature_variation: Option<usize>,
+    fallback_coverage: Option<FallbackCoverage>,
+    histogram_bin_size: Option<f64>,
+    shaping_cache: Option<Arc<ShapingCache>>,
+    language_support: Option<Arc<LanguageSupportCache>>,
 }
 
-impl<'a> InstanceReporter<'a> {
-    /// Get the [`Location`] that this instance reporter is checking.
-    #[inline]
-    #[must_use]
-    pub fn location(&self) -> &Location {
-        self.location.as_ref()
+impl<'a> InstanceReporter<'a> {
+    /// Get the [`Location`] that this instance reporter is checking.
+    #[inline]
+    #[must_use]
+    pub const fn location(&self) -> &Location {
+        self.location
+    }
+
+    /// Get the index of the `GSUB` feature variation applied at this
+    /// instance's location, if any (see
+    /// [`Reporter::active_feature_variation`]).
+    #[inline]
+    #[must_use]
+    pub const fn feature_variation(&self) -> Option<usize> {
+        self.feature_variation
+    }
+
+    /// Get the cached glyph bounds for this instance, for use with
+    /// [`measure_shaped_word`].
+    ///
+    /// With [`Reporter::with_on_demand_glyphs`], the first call draws every
+    /// glyph in the font, leaving out any that fail to draw.
+    #[inline]
+    #[must_use]
+    pub fn instance_extremes(&self) -> &InstanceExtremes {
+        self.instance_extremes.all()
+    }
+
+    /// Share the glyph bounds cached for this instance, so glyphs can be
+    /// measured at its location without drawing them again (e.g. when drawing
+    /// exemplars) once the instance is dropped.
+    ///
+    /// With [`Reporter::with_on_demand_glyphs`], only the glyphs drawn so far
+    /// are included.
+    #[must_use]
+    pub fn shared_instance_extremes(&self) -> Arc<InstanceExtremes> {
+        self.instance_extremes.shared()
+    }
+
+    /// Estimate the memory used by the glyph bounds cached for this instance
+    /// so far, in bytes.
+    ///
+    /// With [`Reporter::with_on_demand_glyphs`], this grows as words need
+    /// more glyphs.
+    #[must_use]
+    pub fn estimated_memory(&self) -> usize {
+        self.instance_extremes.estimated_memory()
+    }
+
+    fn word_shaper(&self) -> WordShaper {
+        WordShaper::new(self.line_height)
+            .with_fallback(self.fallback_coverage.clone())
+    }
+
+    /// Create an iterator for [`WordExtremes`] with the given [`WordList`].
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid.
+    pub fn to_word_extremes_iter(
+        &self,
+        word_list: &'a WordList,
+    ) -> Result<WordExtremesIterator<'_>, WordListShapingPlanError> {
+        let shaper = self
+            .shaper_data
+            .shaper(self.font)
+            .instance(Some(&self.shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(word_list, &shaper)?;
+        Ok(WordExtremesIterator {
+            shaper,
+            instance_extremes: &self.instance_extremes,
+            shaping_meta,
+            offset: word_list.offset(),
+            word_iter: word_list.iter().enumerate(),
+            word_shaper: self.word_shaper(),
+            on_shaped: None,
+            cancellation: None,
+            truncated: false,
+            exclusions: Vec::new(),
+            excluded: 0,
+            error: None,
+        })
+    }
+
+    /// Shape a single word from a [`WordList`], reporting the extremes reached
+    /// by each of its script runs.
+    ///
+    /// Words are split into runs of a single script before shaping; this is
+    /// mostly useful for understanding the results of mixed-script words.
+    /// Returns `Ok(None)` if any glyphs needed are missing from the font.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    pub fn run_extremes<'w>(
+        &self,
+        word_list: &WordList,
+        word: &'w str,
+    ) -> Result<Option<Vec<RunExtremes<'w>>>, FontHeightError> {
+        let shaper = self
+            .shaper_data
+            .shaper(self.font)
+            .instance(Some(&self.shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(word_list, &shaper)?;
+        self.word_shaper()
+            .shape_runs(
+                &shaper,
+                shaping_meta.as_ref(),
+                &self.instance_extremes,
+                word,
+            )
+            .map_err(|err| err.for_word(word))
+    }
+
+    /// Shape a single word from a [`WordList`], reporting the extremes reached
+    /// by each of its grapheme clusters.
+    ///
+    /// Clusters are given as byte ranges of `word`, in logical order. This is
+    /// useful for finding which parts of a word drive its extremes, e.g. for
+    /// heatmaps. Returns `Ok(None)` if any glyphs needed are missing from the
+    /// font.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    pub fn cluster_extremes(
+        &self,
+        word_list: &WordList,
+        word: &str,
+    ) -> Result<Option<Vec<ClusterExtremes>>, FontHeightError> {
+        let shaper = self
+            .shaper_data
+            .shaper(self.font)
+            .instance(Some(&self.shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(word_list, &shaper)?;
+        self.word_shaper()
+            .shape_clusters(
+                &shaper,
+                shaping_meta.as_ref(),
+                &self.instance_extremes,
+                word,
+            )
+            .map_err(|err| err.for_word(word))
+    }
+
+    /// Shape a single word from a [`WordList`], reporting each glyph it shaped
+    /// to with its outline's extremes and how far shaping moved it.
+    ///
+    /// Glyphs are in logical order by script run, and in visual order within
+    /// each run. Returns `Ok(None)` if any glyphs needed are missing from the
+    /// font.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    pub fn placed_glyphs(
+        &self,
+        word_list: &WordList,
+        word: &str,
+    ) -> Result<Option<Vec<PlacedGlyph>>, FontHeightError> {
+        let shaper = self
+            .shaper_data
+            .shaper(self.font)
+            .instance(Some(&self.shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(word_list, &shaper)?;
+        self.word_shaper()
+            .shape_placed(
+                &shaper,
+                shaping_meta.as_ref(),
+                &self.instance_extremes,
+                word,
+            )
+            .map_err(|err| err.for_word(word))
+    }
+
+    /// Explain why a single word from a [`WordList`] reaches further (or less
+    /// far) at `to`'s location than at this one, splitting the difference in
+    /// its extremes into the part due to its glyphs' outlines varying and the
+    /// part due to their positioning (`GPOS`) varying.
+    ///
+    /// `to` should be an instance of the same font. Returns `Ok(None)` if any
+    /// glyphs needed are missing from the font, or the word shaped to no
+    /// glyphs.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    pub fn explain_delta(
+        &self,
+        to: &InstanceReporter,
+        word_list: &WordList,
+        word: &str,
+    ) -> Result<Option<DeltaAttribution>, FontHeightError> {
+        let Some(from_glyphs) = self.placed_glyphs(word_list, word)? else {
+            return Ok(None);
+        };
+        let Some(to_glyphs) = to.placed_glyphs(word_list, word)? else {
+            return Ok(None);
+        };
+        Ok(DeltaAttribution::new(from_glyphs, to_glyphs))
+    }
+
+    /// Shape a single word from a [`WordList`] with each [`JoiningControl`]
+    /// inserted at each plausible position in turn, reporting the extremes
+    /// reached by each.
+    ///
+    /// Controls are inserted between characters, but not before combining
+    /// marks (as classed in the font's `GDEF`) or next to another joining
+    /// control. Permutations with glyphs missing from the font, or that
+    /// shape to nothing visible, are left out. Mostly useful for words in
+    /// [joining scripts](is_joining_script), where the controls change how
+    /// the characters around them are formed.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    pub fn joiner_permutations(
+        &self,
+        word_list: &WordList,
+        word: &str,
+    ) -> Result<Vec<JoinerPermutation>, FontHeightError> {
+        let shaper = self
+            .shaper_data
+            .shaper(self.font)
+            .instance(Some(&self.shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(word_list, &shaper)?;
+        let mut word_shaper = self.word_shaper();
+        let mut permutations = Vec::new();
+        for position in joiners::joiner_positions(self.font, word) {
+            for control in JoiningControl::ALL {
+                let mut permuted = word.to_owned();
+                permuted.insert(position, control.char());
+                let shaped = word_shaper
+                    .shape(
+                        &shaper,
+                        shaping_meta.as_ref(),
+                        &self.instance_extremes,
+                        &permuted,
+                    )
+                    .map_err(|err| err.for_word(&permuted))?;
+                if let Shaped::Extremes(extremes) = shaped.shaped {
+                    permutations.push(JoinerPermutation {
+                        control,
+                        position,
+                        word: permuted,
+                        extremes,
+                    });
+                }
+            }
+        }
+        Ok(permutations)
+    }
+
+    /// Create an iterator for [`DetailedWordExtremes`] with the given
+    /// [`WordList`].
+    ///
+    /// This is slower than [`InstanceReporter::to_word_extremes_iter`], so
+    /// prefer [`InstanceReporter::cluster_extremes`] if you only need the
+    /// breakdown for a few words.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid.
+    pub fn to_detailed_word_extremes_iter(
+        &self,
+        word_list: &'a WordList,
+    ) -> Result<DetailedWordExtremesIterator<'_>, WordListShapingPlanError>
+    {
+        let shaper = self
+            .shaper_data
+            .shaper(self.font)
+            .instance(Some(&self.shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(word_list, &shaper)?;
+        Ok(DetailedWordExtremesIterator {
+            shaper,
+            instance_extremes: &self.instance_extremes,
+            shaping_meta,
+            offset: word_list.offset(),
+            word_iter: word_list.iter().enumerate(),
+            word_shaper: self.word_shaper(),
+            error: None,
+        })
+    }
+
+    /// Check a [`WordList`] in parallel, as configured by `options`.
+    ///
+    /// The [`Report`] doesn't borrow the instance reporter, so the instance
+    /// reporter (and its glyph cache) can be dropped while keeping the report.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    #[cfg(feature = "rayon")]
+    pub fn check(
+        &self,
+        word_list: &'a WordList,
+        options: &AnalysisOptions,
+    ) -> Result<Report<'a>, FontHeightError> {
+        self.par_check_impl(word_list, options, None, None)
+    }
+
+    /// Like [`InstanceReporter::check`], but stops early once `cancellation`
+    /// is cancelled, e.g. when the request that started the check timed out.
+    ///
+    /// The words already measured are still reported, with
+    /// [`ReportStatistics::truncated`] set if any words were left unchecked.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    #[cfg(feature = "rayon")]
+    pub fn check_cancellable(
+        &self,
+        word_list: &'a WordList,
+        options: &AnalysisOptions,
+        cancellation: &CancellationToken,
+    ) -> Result<Report<'a>, FontHeightError> {
+        self.par_check_impl(word_list, options, Some(cancellation.flag()), None)
+    }
+
+    /// Like [`InstanceReporter::check`], but also passes every word measured
+    /// to `on_word` as soon as it's shaped, e.g. to write them all to disk
+    /// without holding them in memory.
+    ///
+    /// Words are measured in parallel, so `on_word` is called from multiple
+    /// threads, with the words in no particular order.
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    #[cfg(feature = "rayon")]
+    pub fn check_streaming(
+        &self,
+        word_list: &'a WordList,
+        options: &AnalysisOptions,
+        on_word: impl Fn(&WordExtremes<'a>) + Sync,
+    ) -> Result<Report<'a>, FontHeightError> {
+        self.par_check_impl(word_list, options, None, Some(&on_word))
+    }
+
+    /// Create a parallel iterator for [`WordExtremes`] at a given location.
+    ///
+    /// Equivalent to [`InstanceReporter::check`] with the given
+    /// [`AnalysisOptions::with_words_limit`] &
+    /// [`AnalysisOptions::with_exemplars`].
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    #[cfg(feature = "rayon")]
+    pub fn par_check(
+        &self,
+        word_list: &'a WordList,
+        k_words: Option<usize>,
+        n_exemplars: usize,
+    ) -> Result<Report<'a>, FontHeightError> {
+        let options = AnalysisOptions::new()
+            .with_words_limit(k_words)
+            .with_exemplars(n_exemplars);
+        self.check(word_list, &options)
+    }
+
+    /// Like [`InstanceReporter::par_check`], but only keeps the most extreme
+    /// of words that differ only by case or punctuation (e.g. "Ögonblick" and
+    /// "ögonblick!").
+    ///
+    /// Equivalent to [`InstanceReporter::check`] with
+    /// [`AnalysisOptions::with_distinct`].
+    ///
+    /// Can fail if the [`WordList`]'s metadata is invalid, or if shaping
+    /// produced a glyph the font has no outline for.
+    #[cfg(feature = "rayon")]
+    pub fn par_check_distinct(
+        &self,
+        word_list: &'a WordList,
+        k_words: Option<usize>,
+        n_exemplars: usize,
+    ) -> Result<Report<'a>, FontHeightError> {
+        let options = AnalysisOptions::new()
+            .with_words_limit(k_words)
+            .with_exemplars(n_exemplars)
+            .with_distinct(true);
+        self.check(word_list, &options)
+    }
+
+    /// Check any [`WordSource`], e.g. words streamed from a file or generated
+    /// on the fly, as configured by `options`.
+    ///
+    /// Sources held in memory (see [`WordSource::par_iter`]) are checked like
+    /// [`InstanceReporter::check`]. Others are read in batches, each shaped in
+    /// parallel, so only a batch of their words is held in memory at a time;
+    /// as those words don't outlive their batch, the report owns its words.
+    /// The font's [language support](Reporter::with_language_support) isn't
+    /// judged for word sources.
+    ///
+    /// Can fail if the source's metadata is invalid, if reading its words
+    /// failed, or if shaping produced a glyph the font has no outline for.
+    #[cfg(feature = "rayon")]
+    pub fn check_source(
+        &self,
+        source: &(impl WordSource + ?Sized),
+        options: &AnalysisOptions,
+    ) -> Result<OwnedReport, FontHeightError> {
+        use exemplars::ExemplarCollector;
+        use rayon::prelude::*;
+        use warnings::{SCRIPT_SAMPLE_SIZE, source_warnings};
+
+        // Streamed sources are shaped this many words at a time
+        const BATCH_SIZE: usize = 10_000;
+
+        let read_error = |error| FontHeightError::ReadingWords {
+            name: source.name().to_owned(),
+            error,
+        };
+        let limit = options.words_limit().unwrap_or(usize::MAX);
+        let sample = source
+            .iter()
+            .take(SCRIPT_SAMPLE_SIZE)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(read_error)?;
+        let warnings =
+            source_warnings(source.script(), sample.iter().map(|word| &**word));
+
+        let (exemplars, statistics, histogram) = match source.par_iter() {
+            Some(words) => {
+                let (collector, statistics, histogram) = self.par_measure(
+                    source,
+                    words.take(limit),
+                    0,
+                    options,
+                    None,
+                    None,
+                )?;
+                (
+                    OwnedExemplars::from(&collector.build()),
+                    statistics,
+                    histogram,
+                )
+            },
+            None => {
+                let mut exemplars = OwnedExemplars::default();
+                let mut statistics = ReportStatistics::default();
+                let mut histogram = self.histogram_bin_size.map(Histogram::new);
+                let mut words = source.iter().take(limit);
+                let mut offset = 0;
+                loop {
+                    let batch = words
+                        .by_ref()
+                        .take(BATCH_SIZE)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(read_error)?;
+                    if batch.is_empty() {
+                        break;
+                    }
+                    let (batch_collector, batch_statistics, batch_histogram) =
+                        self.par_measure(
+                            source,
+                            batch.par_iter().map(|word| &**word),
+                            offset,
+                            options,
+                            None,
+                            None,
+                        )?;
+                    // Earlier batches' exemplars stay in the running
+                    let mut collector =
+                        ExemplarCollector::new(options.exemplars())
+                            .distinct(options.distinct());
+                    exemplars
+                        .lowest()
+                        .iter()
+                        .chain(exemplars.highest())
+                        .map(OwnedWordExtremes::borrowed)
+                        .unique()
+                        .for_each(|word_extremes| {
+                            collector.push(word_extremes)
+                        });
+                    collector.merge_with(batch_collector);
+                    exemplars = OwnedExemplars::from(&collector.build());
+                    statistics = statistics.merge(batch_statistics);
+                    histogram = match (histogram, batch_histogram) {
+                        (Some(histogram), Some(other)) => {
+                            Some(histogram.merge(other))
+                        },
+                        (histogram, other) => histogram.or(other),
+                    };
+                    offset += batch.len();
+                }
+                (exemplars, statistics, histogram)
+            },
+        };
+
+        Ok(OwnedReport {
+            location: self.location.clone(),
+            word_list_name: source.name().to_owned(),
+            word_list_source: source.source().map(str::to_owned),
+            exemplars,
+            statistics,
+            feature_variation: self.feature_variation,
+            histogram,
+            warnings,
+            support: None,
+            shaping: self
+                .shaping_properties(source, sample.iter().map(|word| &**word)),
+        })
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_check_impl(
+        &self,
+        word_list: &'a WordList,
+        options: &AnalysisOptions,
+        cancelled: Option<&AtomicBool>,
+        on_word: Option<&(dyn Fn(&WordExtremes<'a>) + Sync)>,
+    ) -> Result<Report<'a>, FontHeightError> {
+        use rayon::iter::IndexedParallelIterator;
+        use warnings::word_list_warnings;
+
+        let (exemplars, statistics, histogram) = self.par_measure(
+            word_list,
+            word_list
+                .par_iter()
+                .take(options.words_limit().unwrap_or(usize::MAX)),
+            word_list.offset(),
+            options,
+            cancelled,
+            on_word,
+        )?;
+        let exemplars = exemplars.build();
+
+        Ok(Report {
+            location: self.location,
+            word_list,
+            exemplars,
+            statistics,
+            feature_variation: self.feature_variation,
+            histogram,
+            warnings: word_list_warnings(word_list),
+            support: self
+                .language_support
+                .as_ref()
+                .and_then(|cache| cache.support(self.font, word_list)),
+            shaping: self.shaping_properties(word_list, word_list.iter()),
+        })
     }
 
-    /// Create an iterator for [`WordExtremes`] with the given [`WordList`].
-    ///
-    /// Can fail if the [`WordList`]'s metadata is invalid.
-    pub fn to_word_extremes_iter(
+    /// The script, language & direction the first
+    /// [`SCRIPT_SAMPLE_SIZE`](warnings::SCRIPT_SAMPLE_SIZE) of `words` are
+    /// shaped with.
+    fn shaping_properties<'w>(
         &self,
-        word_list: &'a WordList,
-    ) -> Result<WordExtremesIterator<'_>, WordListShapingPlanError> {
-        let shaper = self
-            .shaper_data
-            .shaper(self.font)
-            .instance(Some(&self.shaper_instance))
-            .build();
-        let shaping_meta = word_list
+        source: &(impl WordSource + ?Sized),
+        words: impl Iterator<Item = &'w str>,
+    ) -> Vec<ShapingProperties> {
+        let script = source
             .script()
-            .map(|script| {
-                ShapingMeta::new(script, word_list.language(), &shaper)
-            })
-            .transpose()
-            .map_err(|err| WordListShapingPlanError {
-                word_list_name: word_list.name().to_owned(),
-                inner: err,
-            })?;
-        Ok(WordExtremesIterator {
-            shaper,
-            instance_extremes: &self.instance_extremes,
-            shaping_meta,
-            word_iter: word_list.iter(),
-            unicode_buffer: Some(UnicodeBuffer::new()),
-        })
+            .and_then(|script| Script::from_str(script).ok());
+        self.word_shaper().shaping_properties(
+            script,
+            source.language(),
+            words.take(warnings::SCRIPT_SAMPLE_SIZE),
+        )
     }
 
-    /// Create a parallel iterator for [`WordExtremes`] at a given location.
-    ///
-    /// Can fail if the [`WordList`]'s metadata is invalid.
+    /// Shape & measure `words` from `source` in parallel, numbering them from
+    /// `offset`.
     #[cfg(feature = "rayon")]
-    pub fn par_check(
-        &'a self,
-        word_list: &'a WordList,
-        k_words: Option<usize>,
-        n_exemplars: usize,
-    ) -> Result<Report<'a>, WordListShapingPlanError> {
-        use std::convert::identity;
+    fn par_measure<'w>(
+        &self,
+        source: &(impl WordSource + ?Sized),
+        words: impl rayon::iter::IndexedParallelIterator<Item = &'w str>,
+        offset: usize,
+        options: &AnalysisOptions,
+        cancelled: Option<&AtomicBool>,
+        on_word: Option<&(dyn Fn(&WordExtremes<'w>) + Sync)>,
+    ) -> Result<Measured<'w>, FontHeightError> {
+        use std::sync::atomic::Ordering;
 
         use exemplars::ExemplarCollector;
-        use rayon::prelude::*;
-
-        struct WorkerState {
-            // UnicodeBuffer is transformed into another type during shaping,
-            // and then can only be reverted once we've finished
-            // analysing the shaped buffer. The Option allows us to
-            // take ownership of it during each iteration for these
-            // type changes to happen, while still re-using the buffer
-            unicode_buffer: Option<UnicodeBuffer>,
-        }
+        use rayon::{iter::Either, prelude::*};
 
         let shaper = self
             .shaper_data
             .shaper(self.font)
             .instance(Some(&self.shaper_instance))
             .build();
-        let shaping_meta = word_list
-            .script()
-            .map(|script| {
-                ShapingMeta::new(script, word_list.language(), &shaper)
-            })
-            .transpose()
-            .map_err(|err| WordListShapingPlanError {
-                word_list_name: word_list.name().to_owned(),
-                inner: err,
-            })?;
-
-        let exemplars = word_list
-            .par_iter()
-            .take(k_words.unwrap_or(usize::MAX))
+        let shaping_meta =
+            shaping_meta_with_features(source, options.features(), &shaper)?;
+        let shaping_cache = self.shaping_cache.as_ref().map(|cache| {
+            cache.for_word_list(
+                source,
+                options.features(),
+                self.line_height.is_some(),
+                options.partial_words(),
+            )
+        });
+
+        // Refined searches first measure every word with control boxes,
+        // keeping enough candidates to re-measure exactly afterwards
+        let refined_candidates = match options.exemplar_search() {
+            ExemplarSearch::Refined { candidates }
+                if on_word.is_none()
+                    && self.histogram_bin_size.is_none()
+                    && options.thresholds().is_none()
+                    && options.measurement() == Measurement::Instance =>
+            {
+                Some(candidates.max(options.exemplars()))
+            },
+            _ => None,
+        };
+        let glyphs = if refined_candidates.is_some()
+            || options.measurement() == Measurement::ControlBoxes
+        {
+            &self.control_boxes
+        } else {
+            &self.instance_extremes
+        };
+        let shape_word = |word_shaper: &mut WordShaper,
+                          glyphs: &InstanceGlyphs,
+                          word: &str| {
+            match &shaping_cache {
+                Some(cache) => word_shaper.shape_cached(
+                    cache,
+                    &shaper,
+                    shaping_meta.as_ref(),
+                    glyphs,
+                    word,
+                ),
+                None => word_shaper.shape(
+                    &shaper,
+                    shaping_meta.as_ref(),
+                    glyphs,
+                    word,
+                ),
+            }
+            .map_err(|err| err.for_word(word))
+        };
+
+        let new_acc = || {
+            (
+                ExemplarCollector::new(
+                    refined_candidates.unwrap_or(options.exemplars()),
+                )
+                .distinct(options.distinct()),
+                ReportStatistics::default(),
+                self.histogram_bin_size.map(Histogram::new),
+            )
+        };
+        // Set if any words were skipped after being cancelled
+        let truncated = AtomicBool::new(false);
+        let words = words.enumerate();
+        // Every segment of an entry keeps the entry's index
+        let words = match options.segmentation() {
+            Segmentation::Whole => Either::Left(words),
+            segmentation => {
+                Either::Right(words.flat_map_iter(move |(index, entry)| {
+                    segmentation
+                        .segments(entry)
+                        .into_iter()
+                        .map(move |segment| (index, segment))
+                }))
+            },
+        };
+        // Excluded words are marked (keeping the word, so it can be counted),
+        // and words over the length limit are skipped (likewise), truncated or
+        // chunked
+        let words = match options.word_length_limit() {
+            Some(limit) => {
+                Either::Left(words.flat_map_iter(move |(index, word)| {
+                    // Exclusions apply to the whole word, so it's passed on
+                    // whole to be excluded
+                    if options.excludes(word) {
+                        return vec![(index, word, WordLength::Excluded)];
+                    }
+                    let pieces = limit.apply(word);
+                    if pieces == [word] {
+                        return vec![(index, word, WordLength::Within)];
+                    }
+                    if pieces.is_empty() {
+                        return vec![(index, word, WordLength::Skipped)];
+                    }
+                    // Only the first piece counts the word as too long
+                    pieces
+                        .into_iter()
+                        .enumerate()
+                        .map(|(piece, text)| {
+                            let length = if piece == 0 {
+                                WordLength::Shortened
+                            } else {
+                                WordLength::Within
+                            };
+                            (index, text, length)
+                        })
+                        .collect()
+                }))
+            },
+            None => Either::Right(words.map(move |(index, word)| {
+                let length = if options.excludes(word) {
+                    WordLength::Excluded
+                } else {
+                    WordLength::Within
+                };
+                (index, word, length)
+            })),
+        };
+        // Each word is also shaped wrapped in each kind of punctuation
+        let words = if options.punctuation() {
+            Either::Left(words.flat_map_iter(|(index, word, length)| {
+                punctuation::WITH_UNWRAPPED
+                    .into_iter()
+                    .map(move |punctuation| (index, word, length, punctuation))
+            }))
+        } else {
+            Either::Right(
+                words.map(|(index, word, length)| (index, word, length, None)),
+            )
+        };
+        let measured = words
             .map_init(
-                || WorkerState {
-                    unicode_buffer: Some(UnicodeBuffer::new()),
+                || {
+                    self.word_shaper()
+                        .with_partial_words(options.partial_words())
                 },
-                |state, word| {
-                    // Take buffer; it should always be present
-                    let mut buffer = state.unicode_buffer.take().unwrap();
-                    buffer.push_str(word);
-
-                    // Default features are still included by default
-                    let glyph_buffer = match &shaping_meta {
-                        Some(meta) => shaper.shape_with_meta(meta, buffer, &[]),
-                        None => {
-                            buffer.guess_segment_properties();
-                            shaper.shape(buffer, &[])
-                        },
-                    };
-
-                    let glyphs_missing = glyph_buffer
-                        .glyph_infos()
-                        .iter()
-                        .any(|info| info.glyph_id == 0); // is .notdef
-                    if glyphs_missing {
-                        // Return buffer, abort mission
-                        state.unicode_buffer = Some(glyph_buffer.clear());
+                |word_shaper, (index, word, length, punctuation)| {
+                    // Skip through the remaining words if we've been told to
+                    // stop
+                    if cancelled
+                        .is_some_and(|flag| flag.load(Ordering::Relaxed))
+                    {
+                        truncated.store(true, Ordering::Relaxed);
                         return None;
                     }
+                    // Excluded & skipped words are only counted (once, not
+                    // for each punctuation), without shaping them
+                    if matches!(
+                        length,
+                        WordLength::Skipped | WordLength::Excluded
+                    ) {
+                        return punctuation.is_none().then_some(Ok(
+                            MeasuredWord {
+                                index,
+                                word,
+                                length,
+                                punctuation,
+                                shaped: None,
+                            },
+                        ));
+                    }
 
-                    let extremes = glyph_buffer
-                        .glyph_infos()
-                        .iter()
-                        .zip(glyph_buffer.glyph_positions())
-                        .map(|(info, pos)| {
-                            // TODO: Remove empty glyphs?
-                            let y_offset = NotNan::new(pos.y_offset as f64)
-                                .expect("NaN y offset");
-                            let heights = self
-                                .instance_extremes
-                                .get(info.glyph_id)
-                                .unwrap();
-
-                            VerticalExtremes {
-                                lowest: heights.lowest + y_offset,
-                                highest: heights.highest + y_offset,
-                            }
-                        })
-                        .reduce(VerticalExtremes::merge)
-                        .unwrap_or_default();
-
-                    // Return buffer
-                    state.unicode_buffer = Some(glyph_buffer.clear());
-                    Some(WordExtremes { word, extremes })
+                    let shaped = match punctuation {
+                        Some(punctuation) => shape_word(
+                            word_shaper,
+                            glyphs,
+                            &punctuation.wrap(word),
+                        ),
+                        None => shape_word(word_shaper, glyphs, word),
+                    };
+                    Some(shaped.map(|shaped| MeasuredWord {
+                        index,
+                        word,
+                        length,
+                        punctuation,
+                        shaped: Some(shaped),
+                    }))
                 },
             )
-            .filter_map(identity)
-            .fold(
-                || ExemplarCollector::new(n_exemplars),
-                |mut acc, word_extremes| {
-                    acc.push(word_extremes);
-                    acc
+            .flatten()
+            .try_fold(
+                new_acc,
+                |(mut collector, mut statistics, mut histogram),
+                 measured|
+                 -> Result<_, FontHeightError> {
+                    let MeasuredWord {
+                        index,
+                        word,
+                        length,
+                        punctuation,
+                        shaped,
+                    } = measured?;
+                    if matches!(
+                        length,
+                        WordLength::Shortened | WordLength::Skipped
+                    ) && punctuation.is_none()
+                    {
+                        statistics.too_long += 1;
+                    }
+                    let Some(ShapedWord { shaped, counts }) = shaped else {
+                        if length == WordLength::Excluded {
+                            statistics.excluded += 1;
+                        }
+                        return Ok((collector, statistics, histogram));
+                    };
+                    statistics.words_checked += 1;
+                    match shaped {
+                        Shaped::Extremes(extremes) => {
+                            statistics.fallback +=
+                                usize::from(counts.used_fallback);
+                            statistics.partial +=
+                                usize::from(counts.skipped_glyphs > 0);
+                            if let Some(histogram) = &mut histogram {
+                                histogram.add(extremes);
+                            }
+                            let word_extremes = WordExtremes {
+                                word,
+                                index: offset + index,
+                                extremes,
+                                skipped_glyphs: counts.skipped_glyphs,
+                                punctuation,
+                            };
+                            if let Some(on_word) = on_word {
+                                on_word(&word_extremes);
+                            }
+                            statistics.exceeding +=
+                                usize::from(options.thresholds().is_some_and(
+                                    |thresholds| {
+                                        thresholds.exceeded_by(extremes)
+                                    },
+                                ));
+                            if options.exemplar_filter().is_none_or(|filter| {
+                                filter.keeps(extremes, counts.glyph_count)
+                            }) {
+                                collector.push(word_extremes);
+                            } else {
+                                statistics.filtered += 1;
+                            }
+                        },
+                        Shaped::MissingGlyphs => statistics.missing_glyphs += 1,
+                        // Words left entirely to fallback fonts (or made up
+                        // only of missing glyphs) have nothing of this font to
+                        // measure
+                        Shaped::NoInk
+                            if counts.used_fallback
+                                || counts.skipped_glyphs > 0 =>
+                        {
+                            statistics.missing_glyphs += 1
+                        },
+                        Shaped::NoInk => statistics.anomalies += 1,
+                    }
+                    Ok((collector, statistics, histogram))
                 },
             )
-            .reduce(
-                || ExemplarCollector::new(n_exemplars),
-                |mut acc, other| {
-                    acc.merge_with(other);
-                    acc
+            .try_reduce(
+                new_acc,
+                |(mut collector, statistics, histogram),
+                 (other, other_statistics, other_histogram)| {
+                    collector.merge_with(other);
+                    let histogram = match (histogram, other_histogram) {
+                        (Some(histogram), Some(other)) => {
+                            Some(histogram.merge(other))
+                        },
+                        (histogram, other) => histogram.or(other),
+                    };
+                    Ok((
+                        collector,
+                        statistics.merge(other_statistics),
+                        histogram,
+                    ))
                 },
             )
-            .build();
+            .map(|(collector, mut statistics, histogram)| {
+                statistics.truncated = truncated.into_inner();
+                (collector, statistics, histogram)
+            });
+        if refined_candidates.is_none() {
+            return measured;
+        }
 
-        Ok(Report {
-            location: self.location.as_ref(),
-            word_list,
-            exemplars,
-        })
+        let (candidates, mut statistics, histogram) = measured?;
+        let mut collector = ExemplarCollector::new(options.exemplars())
+            .distinct(options.distinct());
+        let mut word_shaper = self
+            .word_shaper()
+            .with_partial_words(options.partial_words());
+        for candidate in candidates.build() {
+            let shaped = shape_word(
+                &mut word_shaper,
+                &self.instance_extremes,
+                &candidate.shaped_text(),
+            )?;
+            // The candidate was measured with the same glyphs, so it still
+            // has some ink to measure
+            if let Shaped::Extremes(extremes) = shaped.shaped {
+                // Control boxes can be taller than the glyphs' exact bounds,
+                // so candidates are filtered again
+                if options.exemplar_filter().is_some_and(|filter| {
+                    !filter.keeps(extremes, shaped.counts.glyph_count)
+                }) {
+                    statistics.filtered += 1;
+                    continue;
+                }
+                collector.push(WordExtremes {
+                    extremes,
+                    skipped_glyphs: shaped.counts.skipped_glyphs,
+                    ..candidate
+                });
+            }
+        }
+        Ok((collector, statistics, histogram))
     }
 }
 
+/// How a word measured compares to the
+/// [word length limit](AnalysisOptions::with_word_length_limit).
+#[cfg(feature = "rayon")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum WordLength {
+    /// Within the limit, or a piece of a chunked word after the first.
+    Within,
+    /// Truncated, or the first piece of a chunked word.
+    Shortened,
+    /// Skipped, without being shaped.
+    Skipped,
+    /// [Excluded](AnalysisOptions::with_exclusion) whole, without being shaped
+    /// or measured against the limit.
+    Excluded,
+}
+
+/// A word (or a piece, or punctuated form, of one) as measured in
+/// [`InstanceReporter::par_measure`], before it's collected.
+#[cfg(feature = "rayon")]
+struct MeasuredWord<'w> {
+    // The index of the word it came from, before segmentation & chunking
+    index: usize,
+    word: &'w str,
+    length: WordLength,
+    punctuation: Option<Punctuation>,
+    // None if the word was excluded or skipped, without shaping it
+    shaped: Option<ShapedWord>,
+}
+
+/// The exemplars, statistics & histogram collected from measuring words.
+#[cfg(feature = "rayon")]
+type Measured<'w> = (
+    exemplars::ExemplarCollector<'w>,
+    ReportStatistics,
+    Option<Histogram>,
+);
+
 /// An iterator of [`WordExtremes`] for one specific font, [`WordList`], and
 /// [`Location`].
 ///
 /// Produced by a [`InstanceReporter`].
 pub struct WordExtremesIterator<'a> {
     shaper: Shaper<'a>,
-    instance_extremes: &'a InstanceExtremes,
+    instance_extremes: &'a InstanceGlyphs<'a>,
     shaping_meta: Option<ShapingMeta>,
-    word_iter: WordListIter<'a>,
-    // UnicodeBuffer is transformed into another type during shaping, and then
-    // can only be reverted once we've finished analysing the shaped buffer.
-    // The Option allows us to take ownership of it during each iteration for
-    // these type changes to happen, while still re-using the buffer
-    unicode_buffer: Option<UnicodeBuffer>,
+    offset: usize,
+    word_iter: iter::Enumerate<WordListIter<'a>>,
+    word_shaper: WordShaper,
+    on_shaped: Option<OnShaped<'a>>,
+    cancellation: Option<CancellationToken>,
+    truncated: bool,
+    exclusions: Vec<WordFilter>,
+    excluded: usize,
+    error: Option<FontHeightError>,
+}
+
+/// A callback passed each word shaped & its [`GlyphBuffer`]s, see
+/// [`WordExtremesIterator::inspect_shaped`].
+type OnShaped<'a> = Box<dyn FnMut(&str, &[GlyphBuffer]) + 'a>;
+
+impl<'a> WordExtremesIterator<'a> {
+    /// Also pass each word yielded to `on_shaped`, with the [`GlyphBuffer`]s
+    /// it shaped to, so other analyses (e.g. of advance widths or mark counts)
+    /// can use the same shaping without shaping every word again.
+    ///
+    /// There's a [`GlyphBuffer`] for each script run of the word (and each
+    /// line, with [`Reporter::with_multi_line`]), in logical order.
+    #[must_use]
+    pub fn inspect_shaped(
+        mut self,
+        on_shaped: impl FnMut(&str, &[GlyphBuffer]) + 'a,
+    ) -> Self {
+        self.word_shaper = self.word_shaper.keep_glyph_buffers(true);
+        self.on_shaped = Some(Box::new(on_shaped));
+        self
+    }
+
+    /// Stop yielding words once `cancellation` is cancelled, which is checked
+    /// before each word is shaped.
+    ///
+    /// Use [`WordExtremesIterator::is_truncated`] to tell whether the
+    /// iterator ended early.
+    #[must_use]
+    pub fn with_cancellation(
+        mut self,
+        cancellation: CancellationToken,
+    ) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Whether the iterator stopped early as it was
+    /// [cancelled](WordExtremesIterator::with_cancellation), leaving words
+    /// unchecked.
+    #[inline]
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Skip words `exclusion` matches, without shaping them, as
+    /// [`AnalysisOptions::with_exclusion`] does for checks.
+    #[must_use]
+    pub fn with_exclusion(mut self, exclusion: WordFilter) -> Self {
+        self.exclusions.push(exclusion);
+        self
+    }
+
+    /// Measure words with glyphs missing from the font by the glyphs they do
+    /// have, rather than skipping them, as
+    /// [`AnalysisOptions::with_partial_words`] does for checks.
+    #[must_use]
+    pub fn with_partial_words(mut self, partial_words: bool) -> Self {
+        self.word_shaper = self.word_shaper.with_partial_words(partial_words);
+        self
+    }
+
+    /// The number of words skipped so far by the iterator's
+    /// [exclusions](WordExtremesIterator::with_exclusion).
+    #[inline]
+    #[must_use]
+    pub const fn excluded(&self) -> usize {
+        self.excluded
+    }
+
+    /// The error that stopped the iterator, leaving words unchecked: a word
+    /// shaped to a glyph the font has no bounds for
+    /// ([`FontHeightError::MissingGlyphBounds`], e.g. in a malformed font),
+    /// or drawing a glyph failed.
+    ///
+    /// Words with glyphs missing from the font (.notdefs) are skipped
+    /// instead, see [`WordExtremesIterator::with_partial_words`].
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> Option<&FontHeightError> {
+        self.error.as_ref()
+    }
 }
 
 impl<'a> Iterator for WordExtremesIterator<'a> {
     type Item = WordExtremes<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        debug_assert!(
-            self.unicode_buffer.is_some(),
-            "WordExtremesIterator.unicode_buffer wasn't stored back in self \
-             during the previous iteration"
-        );
+        if self.error.is_some() {
+            return None;
+        }
+        // Consume words until we get one without .notdefs
+        for (index, word) in self.word_iter.by_ref() {
+            if self
+                .cancellation
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+            {
+                self.truncated = true;
+                return None;
+            }
+            if self
+                .exclusions
+                .iter()
+                .any(|exclusion| exclusion.excludes(word))
+            {
+                self.excluded += 1;
+                continue;
+            }
+            let shaped = self.word_shaper.shape(
+                &self.shaper,
+                self.shaping_meta.as_ref(),
+                self.instance_extremes,
+                word,
+            );
+            let on_shaped = self.on_shaped.as_mut();
+            self.word_shaper.drain_glyph_buffers(|glyph_buffers| {
+                if let (
+                    Some(on_shaped),
+                    Ok(ShapedWord {
+                        shaped: Shaped::Extremes(_),
+                        ..
+                    }),
+                ) = (on_shaped, &shaped)
+                {
+                    on_shaped(word, glyph_buffers);
+                }
+            });
+            match shaped.map(|shaped| (shaped.shaped, shaped.counts)) {
+                Ok((Shaped::Extremes(extremes), counts)) => {
+                    return Some(WordExtremes {
+                        word,
+                        index: self.offset + index,
+                        extremes,
+                        skipped_glyphs: counts.skipped_glyphs,
+                        punctuation: None,
+                    });
+                },
+                Ok((Shaped::MissingGlyphs | Shaped::NoInk, _)) => {},
+                Err(err) => {
+                    self.error = Some(err.for_word(word));
+                    return None;
+                },
+            }
+        }
+        None
+    }
+}
 
-        // Consume words until we get a shaped buffer without .notdefs
-        let (word, glyph_buffer) = self.word_iter.find_map(|word| {
-            // Take buffer; it should always be present
-            let mut buffer = self.unicode_buffer.take().unwrap();
-            buffer.push_str(word);
+/// An iterator of [`DetailedWordExtremes`] for one specific font,
+/// [`WordList`], and [`Location`].
+///
+/// Produced by a [`InstanceReporter`].
+pub struct DetailedWordExtremesIterator<'a> {
+    shaper: Shaper<'a>,
+    instance_extremes: &'a InstanceGlyphs<'a>,
+    shaping_meta: Option<ShapingMeta>,
+    offset: usize,
+    word_iter: iter::Enumerate<WordListIter<'a>>,
+    word_shaper: WordShaper,
+    error: Option<FontHeightError>,
+}
 
-            // Default features are still included by default
-            let glyph_buffer = match &self.shaping_meta {
-                Some(meta) => self.shaper.shape_with_meta(meta, buffer, &[]),
-                None => {
-                    buffer.guess_segment_properties();
-                    self.shaper.shape(buffer, &[])
+impl DetailedWordExtremesIterator<'_> {
+    /// The error that stopped the iterator, leaving words unchecked, as with
+    /// [`WordExtremesIterator::error`].
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> Option<&FontHeightError> {
+        self.error.as_ref()
+    }
+}
+
+impl<'a> Iterator for DetailedWordExtremesIterator<'a> {
+    type Item = DetailedWordExtremes<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        // Consume words until we get one without .notdefs
+        for (index, word) in self.word_iter.by_ref() {
+            let clusters = match self.word_shaper.shape_clusters(
+                &self.shaper,
+                self.shaping_meta.as_ref(),
+                self.instance_extremes,
+                word,
+            ) {
+                Ok(Some(clusters)) => clusters,
+                Ok(None) => continue,
+                Err(err) => {
+                    self.error = Some(err.for_word(word));
+                    return None;
                 },
             };
-
-            let glyphs_missing = glyph_buffer
-                .glyph_infos()
+            // Skip words that shaped to nothing visible, as with
+            // WordExtremesIterator
+            if clusters
                 .iter()
-                .any(|info| info.glyph_id == 0); // is .notdef
-
-            if !glyphs_missing {
-                // Buffer still held, can't be replaced until after calculating
-                // VerticalExtremes
-                Some((word, glyph_buffer))
-            } else {
-                // Return buffer
-                self.unicode_buffer = Some(glyph_buffer.clear());
-                None
+                .all(|(_, extremes)| extremes.lowest == extremes.highest)
+            {
+                continue;
             }
-        })?;
-
-        let word_extremes = glyph_buffer
-            .glyph_infos()
-            .iter()
-            .zip(glyph_buffer.glyph_positions())
-            .map(|(info, pos)| {
-                // TODO: Remove empty glyphs?
-                let y_offset =
-                    NotNan::new(pos.y_offset as f64).expect("NaN y offset");
-                let heights =
-                    self.instance_extremes.get(info.glyph_id).unwrap();
-
-                VerticalExtremes {
-                    lowest: heights.lowest + y_offset,
-                    highest: heights.highest + y_offset,
-                }
-            })
-            .reduce(VerticalExtremes::merge)
-            .unwrap_or_default();
-
-        // Return buffer
-        self.unicode_buffer = Some(glyph_buffer.clear());
-
-        Some(WordExtremes {
-            word,
-            extremes: word_extremes,
-        })
+            let extremes = clusters
+                .iter()
+                .map(|(_, extremes)| *extremes)
+                .reduce(VerticalExtremes::merge)
+                .unwrap_or_default();
+            return Some(DetailedWordExtremes {
+                word_extremes: WordExtremes {
+                    word,
+                    index: self.offset + index,
+                    extremes,
+                    // Partial words aren't measured here, so words with
+                    // missing glyphs were skipped
+                    skipped_glyphs: 0,
+                    punctuation: None,
+                },
+                clusters,
+            });
+        }
+        None
     }
 }
 
+/// [`WordExtremes`] with a breakdown of the extremes reached by each grapheme
+/// cluster of the word.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DetailedWordExtremes<'w> {
+    /// The word & the extremes it reached overall.
+    pub word_extremes: WordExtremes<'w>,
+    /// The extremes reached by each cluster, as byte ranges of the word in
+    /// logical order.
+    pub clusters: Vec<ClusterExtremes>,
+}
+
 /// A word and the vertical extremes it reached when shaped.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WordExtremes<'w> {
     /// The word that was shaped.
     pub word: &'w str,
+    /// The index of the word within its [`WordList`].
+    ///
+    /// Useful for correlating results with the original corpus, e.g. for
+    /// frequency-ranked word lists. For [slices](WordList::slice) of a word
+    /// list, this is the index within the original word list.
+    pub index: usize,
     /// The high & low point reached while shaping.
     pub extremes: VerticalExtremes,
+    /// The number of glyphs missing from the font that were left out of the
+    /// extremes, with [`AnalysisOptions::with_partial_words`].
+    pub skipped_glyphs: usize,
+    /// The punctuation the word was wrapped in when it was shaped, with
+    /// [`AnalysisOptions::with_punctuation`]. `word` is the word from the
+    /// [`WordList`] alone, see [`WordExtremes::shaped_text`].
+    pub punctuation: Option<Punctuation>,
 }
 
-impl WordExtremes<'_> {
+impl<'w> WordExtremes<'w> {
+    /// The text that was shaped: the word, wrapped in its
+    /// [`punctuation`](Self::punctuation) if it has any.
+    #[must_use]
+    pub fn shaped_text(&self) -> Cow<'w, str> {
+        match self.punctuation {
+            Some(punctuation) => Cow::Owned(punctuation.wrap(self.word)),
+            None => Cow::Borrowed(self.word),
+        }
+    }
+
+    /// Whether glyphs missing from the font were left out of the extremes,
+    /// so the word was only partially measured.
+    #[inline]
+    #[must_use]
+    pub const fn is_partial(&self) -> bool {
+        self.skipped_glyphs > 0
+    }
+
     /// The lowest/smaller extreme, in font units.
     ///
     /// Sugar for [`VerticalExtremes::lowest`].
@@ -493,43 +2115,96 @@ impl WordExtremes<'_> {
     }
 }
 
+/// Find the most preferred of `language_tags` that has a language system under
+/// any of `script_tags` in `script_list`, returning its index.
+fn language_system_index(
+    script_list: &ScriptList,
+    script_tags: &[Option<Tag>],
+    language_tags: &[Tag],
+) -> Result<Option<usize>, ReadError> {
+    let mut best = None::<usize>;
+    for record in script_list.script_records() {
+        if !script_tags.contains(&Some(record.script_tag())) {
+            continue;
+        }
+        let script = record.script(script_list.offset_data())?;
+        best = script
+            .lang_sys_records()
+            .iter()
+            .filter_map(|lang_sys| {
+                language_tags
+                    .iter()
+                    .position(|tag| *tag == lang_sys.lang_sys_tag())
+            })
+            .chain(best)
+            .min();
+    }
+    Ok(best)
+}
+
 /// A cache of the vertical bounds for all the glyphs in a font at a certain
 /// location.
+///
+/// Used with [`measure_shaped_word`] to measure words without a
+/// [`Reporter`].
 #[derive(Debug)]
-pub(crate) struct InstanceExtremes(HashMap<u32, VerticalExtremes>);
+pub struct InstanceExtremes(HashMap<u32, VerticalExtremes>);
 
 impl InstanceExtremes {
     /// Create the cache for the given `font` at a [`Location`].
     pub fn new(
         font: &FontRef,
         location: &Location,
+        glyph_measure: &dyn GlyphMeasure,
     ) -> Result<Self, FontHeightError> {
         location.validate_for(font)?;
-        let instance_extremes = font
-            .outline_glyphs()
+        let skrifa_location = location.to_skrifa(font);
+        let outlines = font.outline_glyphs();
+        let measure = |(id, outline): (GlyphId, OutlineGlyph)| {
+            let extremes =
+                draw_extremes(id, &outline, &skrifa_location, glyph_measure)?;
+            Ok((u32::from(id), extremes))
+        };
+
+        #[cfg(feature = "rayon")]
+        let instance_extremes = {
+            use rayon::prelude::*;
+
+            // Drawing a glyph is quick, so hand them out in chunks rather than
+            // one at a time
+            const CHUNK_SIZE: usize = 64;
+            let num_glyphs =
+                font.maxp().map_err(SkrifaReadError::from)?.num_glyphs();
+            (0..u32::from(num_glyphs))
+                .into_par_iter()
+                .with_min_len(CHUNK_SIZE)
+                .filter_map(|id| {
+                    let id = GlyphId::new(id);
+                    outlines.get(id).map(|outline| (id, outline))
+                })
+                .map(measure)
+                .collect::<Result<HashMap<_, _>, SkrifaDrawError>>()?
+        };
+        #[cfg(not(feature = "rayon"))]
+        let instance_extremes = outlines
             .iter()
-            .map(|(id, outline)| -> Result<(u32, VerticalExtremes), SkrifaDrawError> {
-                let mut bounds_pen = BoundsPen::new();
-                outline
-                    .draw(
-                        DrawSettings::unhinted(
-                            Size::unscaled(),
-                            &location.to_skrifa(font),
-                        ),
-                        &mut bounds_pen,
-                    )
-                    .map_err(|err| SkrifaDrawError(id, err))?;
+            .map(measure)
+            .collect::<Result<HashMap<_, _>, SkrifaDrawError>>()?;
 
-                let harfshapedfa::kurbo::Rect { y0, y1, .. } = bounds_pen.bounds();
-                Ok((u32::from(id), VerticalExtremes {
-                    lowest: NotNan::new(y0).expect("bounding box with NaN y0"),
-                    highest: NotNan::new(y1).expect("bounding box with NaN y1"),
-                }))
-            })
-            .collect::<Result<HashMap<_, _>, _>>()?;
         Ok(InstanceExtremes(instance_extremes))
     }
 
+    /// Estimate the memory used by the cache, in bytes.
+    ///
+    /// Useful for deciding how many instances to keep at once for large fonts.
+    #[must_use]
+    pub fn estimated_memory(&self) -> usize {
+        // hashbrown stores a control byte alongside each bucket
+        mem::size_of::<Self>()
+            + self.0.capacity()
+                * (mem::size_of::<(u32, VerticalExtremes)>() + 1)
+    }
+
     /// Get the [`VerticalExtremes`] for the given glyph ID.
     #[must_use]
     pub fn get(&self, glyph_id: u32) -> Option<VerticalExtremes> {
@@ -537,6 +2212,34 @@ impl InstanceExtremes {
     }
 }
 
+/// Draw a glyph to find its [`VerticalExtremes`].
+fn draw_extremes(
+    id: GlyphId,
+    outline: &OutlineGlyph,
+    location: &skrifa::instance::Location,
+    glyph_measure: &dyn GlyphMeasure,
+) -> Result<VerticalExtremes, SkrifaDrawError> {
+    let mut bounds_pen = BoundsPen::new();
+    outline
+        .draw(
+            DrawSettings::unhinted(Size::unscaled(), location),
+            &mut bounds_pen,
+        )
+        .map_err(|err| SkrifaDrawError(id, err))?;
+    Ok(glyph_measure.measure(bounds_pen.path()))
+}
+
+impl GlyphExtremes for InstanceExtremes {
+    type Error = MissingGlyphBoundsError;
+
+    fn glyph_extremes(
+        &self,
+        glyph_id: u32,
+    ) -> Result<VerticalExtremes, Self::Error> {
+        self.get(glyph_id).ok_or(MissingGlyphBoundsError(glyph_id))
+    }
+}
+
 /// The highest & lowest point on the vertical (y) axis.
 ///
 /// Vertical extremes are measured in font units.
@@ -588,6 +2291,50 @@ impl VerticalExtremes {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for VerticalExtremes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("VerticalExtremes", 2)?;
+        state.serialize_field("lowest", &self.lowest())?;
+        state.serialize_field("highest", &self.highest())?;
+        state.end()
+    }
+}
+
+/// Fails if either value is `NaN`, or if `lowest > highest`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VerticalExtremes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct Fields {
+            lowest: f64,
+            highest: f64,
+        }
+
+        let Fields { lowest, highest } = Fields::deserialize(deserializer)?;
+        let lowest = NotNan::new(lowest)
+            .map_err(|_| D::Error::custom("lowest was NaN"))?;
+        let highest = NotNan::new(highest)
+            .map_err(|_| D::Error::custom("highest was NaN"))?;
+        if lowest > highest {
+            return Err(D::Error::custom(
+                "lowest value was greater than highest value",
+            ));
+        }
+        Ok(Self { lowest, highest })
+    }
+}
+
 /// A report documenting the furthest extents reached at a location by a word
 /// list.
 #[derive(Debug, Clone)]
@@ -601,10 +2348,32 @@ pub struct Report<'a> {
     pub word_list: &'a WordList,
     /// The highest & lowest-reaching words shaped.
     pub exemplars: Exemplars<'a>,
+    /// Counts of the words checked.
+    pub statistics: ReportStatistics,
+    /// The index of the `GSUB` feature variation that was applied while
+    /// shaping, if any (see [`Reporter::active_feature_variation`]).
+    pub feature_variation: Option<usize>,
+    /// How high & low all the words checked reached, if collected (see
+    /// [`Reporter::with_histogram`]).
+    pub histogram: Option<Histogram>,
+    /// Problems found that may make the report misleading, e.g. the word
+    /// list's metadata not matching its words.
+    pub warnings: Vec<ReportWarning>,
+    /// How well the font supports the word list's language, if judged (see
+    /// [`Reporter::with_language_support`]).
+    pub support: Option<SupportLevel>,
+    /// The scripts, languages & directions the words were shaped with, from
+    /// the word list's metadata or guessed from runs of text it doesn't cover.
+    ///
+    /// Only the first 100 words are looked at, so scripts only appearing later
+    /// in the word list may be missing.
+    pub shaping: Vec<ShapingProperties>,
 }
 
 impl<'a> Report<'a> {
-    /// Create a new report from its fields.
+    /// Create a new report from its fields, with empty
+    /// [`ReportStatistics`], no feature variation, no histogram, no warnings,
+    /// no support level and no shaping properties.
     #[inline]
     #[must_use]
     pub const fn new(
@@ -616,6 +2385,166 @@ impl<'a> Report<'a> {
             location,
             word_list,
             exemplars,
+            statistics: ReportStatistics::EMPTY,
+            feature_variation: None,
+            histogram: None,
+            warnings: Vec::new(),
+            support: None,
+            shaping: Vec::new(),
         }
     }
+
+    /// Set the report's [`Histogram`].
+    #[inline]
+    #[must_use]
+    pub fn with_histogram(mut self, histogram: Histogram) -> Self {
+        self.histogram = Some(histogram);
+        self
+    }
+
+    /// Set the [`ShapingProperties`] the report's words were shaped with.
+    #[inline]
+    #[must_use]
+    pub fn with_shaping(mut self, shaping: Vec<ShapingProperties>) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Set the report's [`ReportWarning`]s.
+    #[inline]
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: Vec<ReportWarning>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Set how well the font supports the word list's language.
+    #[inline]
+    #[must_use]
+    pub const fn with_support(mut self, support: Option<SupportLevel>) -> Self {
+        self.support = support;
+        self
+    }
+
+    /// Set the report's [`ReportStatistics`].
+    #[inline]
+    #[must_use]
+    pub const fn with_statistics(
+        mut self,
+        statistics: ReportStatistics,
+    ) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Set the index of the feature variation applied while shaping.
+    #[inline]
+    #[must_use]
+    pub const fn with_feature_variation(
+        mut self,
+        feature_variation: Option<usize>,
+    ) -> Self {
+        self.feature_variation = feature_variation;
+        self
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const FONT: &[u8] = include_bytes!("../tests/fonts/DejaVuSans.ttf");
+
+    #[test]
+    fn iterators_stop_at_glyphs_without_bounds() {
+        let reporter = Reporter::new(FONT).unwrap();
+        let location = Location::default();
+        let instance = reporter.instance(&location).unwrap();
+        // As if the font were malformed, and shaping produced glyph IDs past
+        // the end of its glyphs
+        let truncated = InstanceGlyphs::Drawn(Arc::new(InstanceExtremes(
+            instance
+                .instance_extremes
+                .all()
+                .0
+                .iter()
+                .filter(|&(&glyph_id, _)| glyph_id < 10)
+                .map(|(&glyph_id, &extremes)| (glyph_id, extremes))
+                .collect(),
+        )));
+        let word_list = WordList::define("test", ["hi", "there"]);
+
+        let mut iter = instance.to_word_extremes_iter(&word_list).unwrap();
+        iter.instance_extremes = &truncated;
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert!(matches!(
+            iter.error(),
+            Some(FontHeightError::MissingGlyphBounds { glyph_id, word })
+                if *glyph_id >= 10 && word == "hi"
+        ));
+
+        let mut iter =
+            instance.to_detailed_word_extremes_iter(&word_list).unwrap();
+        iter.instance_extremes = &truncated;
+        assert_eq!(iter.next(), None);
+        assert!(matches!(
+            iter.error(),
+            Some(FontHeightError::MissingGlyphBounds { word, .. })
+                if word == "hi"
+        ));
+
+        // The real glyphs are all there
+        let mut iter = instance.to_word_extremes_iter(&word_list).unwrap();
+        assert_eq!(iter.by_ref().count(), 2);
+        assert!(iter.error().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn checks_count_thresholds_and_partial_words() {
+        let reporter = Reporter::new(FONT).unwrap();
+        let instance = reporter.default_instance().unwrap();
+        // DejaVu Sans has no Devanagari
+        let word_list = WordList::define("test", ["ab", "Éa", "aक"]);
+        let options = AnalysisOptions::new()
+            .with_thresholds(Some(Thresholds::new(-500.0, 1600.0)));
+
+        let report = instance.check(&word_list, &options).unwrap();
+        assert_eq!(report.statistics.words_checked, 3);
+        assert_eq!(report.statistics.exceeding, 1);
+        assert_eq!(report.statistics.missing_glyphs, 1);
+
+        let report = instance
+            .check(&word_list, &options.with_partial_words(true))
+            .unwrap();
+        assert_eq!(report.statistics.partial, 1);
+        assert_eq!(report.statistics.missing_glyphs, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn checks_count_excluded_words_once() {
+        let reporter = Reporter::new(FONT).unwrap();
+        let instance = reporter.default_instance().unwrap();
+        let word_list =
+            WordList::define("test", ["ab", "123", "abcdefgh", "12345678"]);
+        let options = AnalysisOptions::new()
+            .with_exclusion(WordFilter::Categories(vec![CharCategory::Number]))
+            .with_punctuation(true);
+
+        let report = instance.check(&word_list, &options).unwrap();
+        assert_eq!(report.statistics.excluded, 2);
+        assert_eq!(report.statistics.too_long, 0);
+
+        // Excluded words aren't also counted as too long
+        let report = instance
+            .check(
+                &word_list,
+                &options.with_word_length_limit(Some(WordLengthLimit::new(4))),
+            )
+            .unwrap();
+        assert_eq!(report.statistics.excluded, 2);
+        assert_eq!(report.statistics.too_long, 1);
+    }
 }
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use skrifa::{FontRef, raw::TableProvider};
+
+use crate::{VerticalExtremes, errors::SkrifaReadError};
+
+/// The vertical metrics lines of text can be stacked by.
+///
+/// See [`Reporter::line_box`](crate::Reporter::line_box).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum LineMetrics {
+    /// `hhea.ascender`, `hhea.descender` & `hhea.lineGap`, used by macOS and
+    /// by browsers unless `USE_TYPO_METRICS` is set.
+    Hhea,
+    /// `OS/2.sTypoAscender`, `OS/2.sTypoDescender` & `OS/2.sTypoLineGap`,
+    /// used by browsers if `USE_TYPO_METRICS` is set.
+    Typo,
+}
+
+impl LineMetrics {
+    /// Every set of line metrics.
+    pub const ALL: [LineMetrics; 2] = [LineMetrics::Hhea, LineMetrics::Typo];
+
+    /// A short, lowercase name for the metrics (e.g. `hhea`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            LineMetrics::Hhea => "hhea",
+            LineMetrics::Typo => "typo",
+        }
+    }
+}
+
+impl fmt::Display for LineMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The space each line takes up when lines of text are stacked: from the
+/// descender to the ascender, with half the line gap added above & below.
+///
+/// Lines are stacked [`height`](LineBox::height) apart, so anything reaching
+/// beyond the box overlaps the box of the line above or below it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LineBox {
+    /// The metrics the box is made from.
+    pub metrics: LineMetrics,
+    /// The ascender, in font units above the baseline.
+    pub ascender: f64,
+    /// The descender, in font units (negative below the baseline).
+    pub descender: f64,
+    /// The gap between lines, in font units.
+    pub line_gap: f64,
+}
+
+impl LineBox {
+    /// Read the line box of `metrics` from `font`.
+    pub(crate) fn new(
+        font: &FontRef,
+        metrics: LineMetrics,
+    ) -> Result<Self, SkrifaReadError> {
+        let (ascender, descender, line_gap) = match metrics {
+            LineMetrics::Hhea => {
+                let hhea = font.hhea()?;
+                (
+                    hhea.ascender().to_i16(),
+                    hhea.descender().to_i16(),
+                    hhea.line_gap().to_i16(),
+                )
+            },
+            LineMetrics::Typo => {
+                let os2 = font.os2()?;
+                (
+                    os2.s_typo_ascender(),
+                    os2.s_typo_descender(),
+                    os2.s_typo_line_gap(),
+                )
+            },
+        };
+        Ok(LineBox {
+            metrics,
+            ascender: f64::from(ascender),
+            descender: f64::from(descender),
+            line_gap: f64::from(line_gap),
+        })
+    }
+
+    /// The distance between the baselines of stacked lines.
+    #[must_use]
+    pub fn height(&self) -> f64 {
+        self.ascender - self.descender + self.line_gap
+    }
+
+    /// The bottom & top of the box, relative to its line's baseline.
+    ///
+    /// Broken metrics (e.g. a descender above the ascender) are swapped
+    /// rather than panicking.
+    #[must_use]
+    pub fn extremes(&self) -> VerticalExtremes {
+        let half_gap = self.line_gap / 2.0;
+        let top = self.ascender + half_gap;
+        let bottom = self.descender - half_gap;
+        VerticalExtremes::new(bottom.min(top), top.max(bottom))
+    }
+
+    /// How far `extremes` reach into the box of the line above, if at all.
+    #[must_use]
+    pub fn into_line_above(&self, extremes: VerticalExtremes) -> Option<f64> {
+        let overflow = extremes.highest() - self.extremes().highest();
+        (overflow > 0.0).then_some(overflow)
+    }
+
+    /// How far `extremes` reach into the box of the line below, if at all.
+    #[must_use]
+    pub fn into_line_below(&self, extremes: VerticalExtremes) -> Option<f64> {
+        let overflow = self.extremes().lowest() - extremes.lowest();
+        (overflow > 0.0).then_some(overflow)
+    }
+}
@@ -0,0 +1,215 @@
+//! Strategies for choosing the locations of a variable font to check.
+//!
+//! [`Reporter::interesting_locations`](crate::Reporter::interesting_locations)
+//! uses [`CornersAndNamed`]. Use
+//! [`Reporter::locations`](crate::Reporter::locations) with another strategy
+//! to check fewer (or different) locations, or plug in your own.
+//!
+//! Every strategy gives the default location for static fonts.
+
+use std::collections::HashSet;
+
+use rand_core::{RngCore, SeedableRng};
+use rand_pcg::Pcg64;
+use skrifa::MetadataProvider;
+
+use crate::{Location, Reporter, axes::AxisCoords, errors::FontHeightError};
+
+/// A way of choosing the [`Location`]s of a font to check.
+pub trait LocationStrategy {
+    /// Choose the locations of `reporter`'s font to check.
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError>;
+}
+
+/// Every combination of the coordinates of named instances, axis extremes, the
+/// middle of each `GSUB` feature variation's region, and the default location.
+///
+/// This is the default strategy, see
+/// [`Reporter::interesting_locations`](crate::Reporter::interesting_locations).
+/// The number of locations scales exponentially with the number of axes.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CornersAndNamed;
+
+impl LocationStrategy for CornersAndNamed {
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError> {
+        Ok(reporter.interesting_locations())
+    }
+}
+
+/// Every combination of each axis' minimum, default & maximum.
+///
+/// Cheaper than [`CornersAndNamed`] for fonts with many named instances, but
+/// misses designs that only appear between the extremes.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Corners;
+
+impl LocationStrategy for Corners {
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError> {
+        let axes = reporter.fontref().axes();
+        let mut axis_coords =
+            AxisCoords::new(axes.iter().map(|axis| axis.tag()));
+        axes.iter().for_each(|axis| {
+            [axis.default_value(), axis.min_value(), axis.max_value()]
+                .into_iter()
+                .for_each(|coord| axis_coords.insert(axis.tag(), coord));
+        });
+        Ok(axis_coords.locations())
+    }
+}
+
+/// The default location, and the location of each named instance.
+///
+/// Scales with the number of instances rather than exponentially with the
+/// number of axes, but only checks the designs the font names.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NamedInstances;
+
+impl LocationStrategy for NamedInstances {
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError> {
+        Ok(with_default(
+            reporter,
+            reporter
+                .named_instances()
+                .into_iter()
+                .map(|(_, location)| location),
+        ))
+    }
+}
+
+/// The default location, and the approximate location of each master (see
+/// [`Reporter::master_locations`](crate::Reporter::master_locations)).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Masters;
+
+impl LocationStrategy for Masters {
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError> {
+        Ok(with_default(reporter, reporter.master_locations()?))
+    }
+}
+
+/// `count` locations sampled uniformly in the font's normalized design space
+/// (after `avar`), generated from `seed` so they can be reproduced.
+///
+/// See [`Reporter::location_from_normalized`](crate::Reporter::location_from_normalized)
+/// for why normalized coordinates are sampled.
+#[derive(Debug, Copy, Clone)]
+pub struct RandomSampling {
+    /// The number of locations to sample.
+    pub count: usize,
+    /// The seed the locations are generated from.
+    pub seed: u64,
+}
+
+impl LocationStrategy for RandomSampling {
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError> {
+        let axis_count = reporter.fontref().axes().len();
+        if axis_count == 0 {
+            return Ok(vec![Location::new()]);
+        }
+        let mut rng = Pcg64::seed_from_u64(self.seed);
+        Ok((0..self.count)
+            .map(|_| {
+                let coords = (0..axis_count)
+                    .map(|_| (next_f64(&mut rng) * 2.0 - 1.0) as f32)
+                    .collect::<Vec<_>>();
+                reporter.location_from_normalized(&coords)
+            })
+            .collect())
+    }
+}
+
+/// A number in `0.0..1.0` from `rng`.
+///
+/// `rand_pcg` keeps its output for a seed stable between versions, so the
+/// locations sampled for a seed never change.
+fn next_f64(rng: &mut Pcg64) -> f64 {
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+impl<F> LocationStrategy for F
+where
+    F: Fn(&Reporter) -> Result<Vec<Location>, FontHeightError>,
+{
+    fn locations(
+        &self,
+        reporter: &Reporter,
+    ) -> Result<Vec<Location>, FontHeightError> {
+        self(reporter)
+    }
+}
+
+/// The default location followed by `locations`, without duplicates.
+///
+/// The default location has every axis' coordinate given, as the locations
+/// from [`Reporter::interesting_locations`] do, so that a named instance at
+/// the default location isn't checked twice.
+fn with_default(
+    reporter: &Reporter,
+    locations: impl IntoIterator<Item = Location>,
+) -> Vec<Location> {
+    let default = reporter
+        .fontref()
+        .axes()
+        .iter()
+        .map(|axis| (axis.tag(), axis.default_value()))
+        .collect();
+    dedup_after(default, locations)
+}
+
+/// `first` followed by `locations`, without duplicates.
+fn dedup_after(
+    first: Location,
+    locations: impl IntoIterator<Item = Location>,
+) -> Vec<Location> {
+    let mut seen = HashSet::from([first.clone()]);
+    let mut all = vec![first];
+    locations.into_iter().for_each(|location| {
+        if seen.insert(location.clone()) {
+            all.push(location);
+        }
+    });
+    all
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use skrifa::Tag;
+
+    use super::*;
+
+    #[test]
+    fn default_comes_first_without_duplicates() {
+        let wght = |coord| Location::from_iter([(Tag::new(b"wght"), coord)]);
+        let locations =
+            dedup_after(wght(400.0), [wght(700.0), wght(400.0), wght(700.0)]);
+        assert_eq!(locations, [wght(400.0), wght(700.0)]);
+    }
+
+    #[test]
+    fn samples_reproducibly() {
+        // Pinned, so a change in the locations sampled for a seed is noticed
+        let mut rng = Pcg64::seed_from_u64(0);
+        assert_eq!(
+            next_f64(&mut rng),
+            (0x20AE_25DA_D4E2_BC53_u64 >> 11) as f64 / (1u64 << 53) as f64,
+        );
+    }
+}
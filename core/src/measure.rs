@@ -0,0 +1,88 @@
+//! Strategies for measuring the vertical extremes of glyphs.
+//!
+//! By default, [`Reporter`](crate::Reporter)s measure the exact bounding box
+//! of each glyph's outline. Use
+//! [`Reporter::with_glyph_measure`](crate::Reporter::with_glyph_measure) to
+//! trade accuracy for speed, or to plug in your own measurement logic.
+
+use harfshapedfa::kurbo::{BezPath, Shape};
+
+use crate::VerticalExtremes;
+
+/// A way of measuring the [`VerticalExtremes`] of a glyph from its outline.
+///
+/// Outlines are in font units, and are drawn unhinted.
+///
+/// ⚠️ Warning: changes to [`kurbo`](harfshapedfa::kurbo)'s version are **not**
+/// covered by this crate's efforts to follow SemVer.
+pub trait GlyphMeasure: Send + Sync {
+    /// Measure the vertical extremes of a glyph's outline.
+    ///
+    /// `outline` is empty for glyphs without one (e.g. spaces).
+    fn measure(&self, outline: &BezPath) -> VerticalExtremes;
+}
+
+/// Measures the exact bounds of a glyph, including curves that extend past
+/// their on-curve points.
+///
+/// This is the default [`GlyphMeasure`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ExactBounds;
+
+impl GlyphMeasure for ExactBounds {
+    fn measure(&self, outline: &BezPath) -> VerticalExtremes {
+        let bounds = outline.bounding_box();
+        VerticalExtremes::new(bounds.y0, bounds.y1)
+    }
+}
+
+/// Measures the bounds of all of a glyph's points, including off-curve points.
+///
+/// Faster than [`ExactBounds`], but may overestimate the extremes of curves
+/// that don't have points on their extremes.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ControlBounds;
+
+impl GlyphMeasure for ControlBounds {
+    fn measure(&self, outline: &BezPath) -> VerticalExtremes {
+        let bounds = outline.control_box();
+        VerticalExtremes::new(bounds.y0, bounds.y1)
+    }
+}
+
+impl<F> GlyphMeasure for F
+where
+    F: Fn(&BezPath) -> VerticalExtremes + Send + Sync,
+{
+    fn measure(&self, outline: &BezPath) -> VerticalExtremes {
+        self(outline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use harfshapedfa::kurbo::Point;
+
+    use super::*;
+
+    #[test]
+    fn control_bounds_include_off_curve_points() {
+        let mut outline = BezPath::new();
+        outline.move_to(Point::new(0.0, 0.0));
+        outline.quad_to(Point::new(50.0, 100.0), Point::new(100.0, 0.0));
+        outline.close_path();
+
+        assert_eq!(
+            ExactBounds.measure(&outline),
+            VerticalExtremes::new(0.0, 50.0),
+        );
+        assert_eq!(
+            ControlBounds.measure(&outline),
+            VerticalExtremes::new(0.0, 100.0),
+        );
+        assert_eq!(
+            ExactBounds.measure(&BezPath::new()),
+            VerticalExtremes::default(),
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Searching a font's design space for the location at which a word reaches
+//! furthest.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use harfrust::ShaperInstance;
+use skrifa::{FontRef, GlyphId, MetadataProvider};
+
+use crate::{
+    Location, Reporter, VerticalExtremes, WordList, draw_extremes,
+    errors::{FontHeightError, SkrifaDrawError},
+    measure::GlyphMeasure,
+    shaping::{GlyphExtremes, Shaped, WordShaper},
+    shaping_meta,
+};
+
+/// The maximum number of times each axis is searched.
+const MAX_PASSES: usize = 4;
+
+/// Axis searches stop once the bracketed range falls below this fraction of
+/// the axis' range.
+const TOLERANCE: f32 = 1e-3;
+
+/// Which of a word's extremes to optimise for.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Extreme {
+    /// Find where the word reaches highest.
+    Highest,
+    /// Find where the word reaches lowest.
+    Lowest,
+}
+
+impl Extreme {
+    fn score(self, extremes: VerticalExtremes) -> f64 {
+        match self {
+            Extreme::Highest => extremes.highest(),
+            Extreme::Lowest => -extremes.lowest(),
+        }
+    }
+}
+
+/// The result of [`Reporter::optimize_location`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizedLocation {
+    /// The most extreme location found.
+    pub location: Location,
+    /// The extremes the word reached at [`OptimizedLocation::location`].
+    pub extremes: VerticalExtremes,
+}
+
+impl Reporter<'_> {
+    /// Search the font's design space for the location at which `word` (from
+    /// `word_list`) reaches furthest in the direction of `extreme`, starting
+    /// from `start`.
+    ///
+    /// [`Reporter::interesting_locations`] only covers the corners of the
+    /// design space & named instances, which can miss the true extreme if it
+    /// lies between them. This searches each axis in turn (a golden-section
+    /// search, also checking the axis' limits), repeating until the result
+    /// stops improving. As with any local search it may not find the global
+    /// extreme, but the result is never less extreme than `start`.
+    ///
+    /// Returns `Ok(None)` if glyphs needed to shape the word are missing from
+    /// the font, or if it shapes to nothing visible.
+    ///
+    /// Fails if `start` isn't valid for the font, the [`WordList`]'s metadata
+    /// is invalid, or if an error occurs while drawing glyphs.
+    pub fn optimize_location(
+        &self,
+        word_list: &WordList,
+        word: &str,
+        extreme: Extreme,
+        start: &Location,
+    ) -> Result<Option<OptimizedLocation>, FontHeightError> {
+        start.validate_for(&self.font)?;
+        let start_coords = start.to_std();
+        let axes = self.font.axes();
+        let coords = axes
+            .iter()
+            .map(|axis| {
+                start_coords
+                    .get(&axis.tag().to_string())
+                    .copied()
+                    .unwrap_or_else(|| axis.default_value())
+            })
+            .collect::<Vec<_>>();
+
+        let mut search = Search {
+            reporter: self,
+            word_list,
+            word,
+            extreme,
+            word_shaper: WordShaper::new(None),
+            best: None,
+        };
+        if search.score_at(&coords)?.is_none() {
+            return Ok(None);
+        }
+
+        for _ in 0..MAX_PASSES {
+            let before = search.best_score();
+            for (index, axis) in axes.iter().enumerate() {
+                search.search_axis(
+                    index,
+                    axis.min_value(),
+                    axis.max_value(),
+                )?;
+            }
+            if search.best_score() <= before {
+                break;
+            }
+        }
+
+        let (coords, extremes) = search.best.expect("start was scored");
+        Ok(Some(OptimizedLocation {
+            location: axes
+                .iter()
+                .zip(coords)
+                .map(|(axis, coord)| (axis.tag(), coord))
+                .collect(),
+            extremes,
+        }))
+    }
+}
+
+struct Search<'a> {
+    reporter: &'a Reporter<'a>,
+    word_list: &'a WordList,
+    word: &'a str,
+    extreme: Extreme,
+    word_shaper: WordShaper,
+    // Coordinates are in the same order as the font's axes
+    best: Option<(Vec<f32>, VerticalExtremes)>,
+}
+
+impl Search<'_> {
+    fn best_score(&self) -> f64 {
+        self.best
+            .as_ref()
+            .map_or(f64::NEG_INFINITY, |(_, extremes)| {
+                self.extreme.score(*extremes)
+            })
+    }
+
+    /// Golden-section search one axis, keeping all others at their best
+    /// coordinates so far.
+    fn search_axis(
+        &mut self,
+        axis: usize,
+        min: f32,
+        max: f32,
+    ) -> Result<(), FontHeightError> {
+        const INV_PHI: f32 = 0.618_034;
+
+        let (mut coords, _) = self.best.clone().expect("start was scored");
+        let mut score_with = |search: &mut Self, coord: f32| {
+            coords[axis] = coord;
+            search
+                .score_at(&coords)
+                .map(|score| score.unwrap_or(f64::NEG_INFINITY))
+        };
+
+        // Extremes are very often at the limits of an axis, which the
+        // golden-section search only approaches
+        score_with(self, min)?;
+        score_with(self, max)?;
+
+        let (mut low, mut high) = (min, max);
+        let mut left = high - INV_PHI * (high - low);
+        let mut right = low + INV_PHI * (high - low);
+        let mut left_score = score_with(self, left)?;
+        let mut right_score = score_with(self, right)?;
+        while high - low > TOLERANCE * (max - min) {
+            if left_score > right_score {
+                high = right;
+                right = left;
+                right_score = left_score;
+                left = high - INV_PHI * (high - low);
+                left_score = score_with(self, left)?;
+            } else {
+                low = left;
+                left = right;
+                left_score = right_score;
+                right = low + INV_PHI * (high - low);
+                right_score = score_with(self, right)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Shape the word at the given coordinates, keeping track of the best
+    /// result.
+    ///
+    /// Returns `None` if glyphs are missing or the word has no ink.
+    fn score_at(
+        &mut self,
+        coords: &[f32],
+    ) -> Result<Option<f64>, FontHeightError> {
+        let font = &self.reporter.font;
+        let location = font
+            .axes()
+            .iter()
+            .zip(coords)
+            .map(|(axis, &coord)| (axis.tag(), coord))
+            .collect::<Location>();
+        let shaper_instance =
+            ShaperInstance::from_variations(font, location.to_harfrust());
+        let shaper = self
+            .reporter
+            .shaper_data
+            .shaper(font)
+            .instance(Some(&shaper_instance))
+            .build();
+        let shaping_meta = shaping_meta(self.word_list, &shaper)?;
+        self.word_shaper.line_height =
+            self.reporter.line_height(&location.to_skrifa(font));
+        let glyph_extremes = DrawnExtremes {
+            font,
+            glyph_measure: self.reporter.glyph_measure.as_ref(),
+            location: location.to_skrifa(font),
+            cache: RefCell::default(),
+        };
+
+        let Shaped::Extremes(extremes) = self
+            .word_shaper
+            .shape(&shaper, shaping_meta.as_ref(), &glyph_extremes, self.word)?
+            .shaped
+        else {
+            return Ok(None);
+        };
+
+        let score = self.extreme.score(extremes);
+        if score > self.best_score() {
+            self.best = Some((coords.to_vec(), extremes));
+        }
+        Ok(Some(score))
+    }
+}
+
+/// Draws glyphs as they're needed, rather than the whole font like
+/// [`InstanceExtremes`](crate::InstanceExtremes), as each location is only
+/// used for a single word.
+struct DrawnExtremes<'a> {
+    font: &'a FontRef<'a>,
+    glyph_measure: &'a dyn GlyphMeasure,
+    location: skrifa::instance::Location,
+    cache: RefCell<HashMap<u32, VerticalExtremes>>,
+}
+
+impl GlyphExtremes for DrawnExtremes<'_> {
+    type Error = SkrifaDrawError;
+
+    fn glyph_extremes(
+        &self,
+        glyph_id: u32,
+    ) -> Result<VerticalExtremes, Self::Error> {
+        if let Some(extremes) = self.cache.borrow().get(&glyph_id) {
+            return Ok(*extremes);
+        }
+        let id = GlyphId::new(glyph_id);
+        let extremes = match self.font.outline_glyphs().get(id) {
+            Some(outline) => {
+                draw_extremes(id, &outline, &self.location, self.glyph_measure)?
+            },
+            None => VerticalExtremes::default(),
+        };
+        self.cache.borrow_mut().insert(glyph_id, extremes);
+        Ok(extremes)
+    }
+}
@@ -0,0 +1,501 @@
+use ordered_float::NotNan;
+
+use crate::{
+    Segmentation, Thresholds, VerticalExtremes, WordFilter, WordLengthLimit,
+};
+
+/// How [`InstanceReporter::check`](crate::InstanceReporter::check) measures
+/// the glyphs words shape to.
+///
+/// Glyphs can also be measured some other way for every check, with
+/// [`Reporter::with_glyph_measure`](crate::Reporter::with_glyph_measure), as
+/// each [`InstanceReporter`](crate::InstanceReporter) measures its glyphs
+/// once for all of its checks.
+///
+/// ```
+/// # use fontheight::{AnalysisOptions, Measurement};
+/// let options =
+///     AnalysisOptions::new().with_measurement(Measurement::ControlBoxes);
+/// assert_eq!(options.measurement(), Measurement::ControlBoxes);
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum Measurement {
+    /// With the glyphs measured by the
+    /// [`InstanceReporter`](crate::InstanceReporter), with the
+    /// [`Reporter`](crate::Reporter)'s
+    /// [`GlyphMeasure`](crate::measure::GlyphMeasure) (exact bounds by
+    /// default).
+    #[default]
+    Instance,
+    /// By the glyphs' control boxes (see
+    /// [`ControlBounds`](crate::measure::ControlBounds)), drawn as words need
+    /// them and shared by every check at the same instance.
+    ///
+    /// Faster, but may overestimate extremes. With
+    /// [`Reporter::with_on_demand_glyphs`](crate::Reporter::with_on_demand_glyphs),
+    /// glyphs are never measured exactly for the check. As every word is
+    /// measured the same way, the [`ExemplarSearch`] is always exhaustive.
+    ControlBoxes,
+}
+
+/// How [`InstanceReporter::check`](crate::InstanceReporter::check) searches a
+/// word list for its exemplars.
+///
+/// ```
+/// # use fontheight::{AnalysisOptions, ExemplarSearch};
+/// let options = AnalysisOptions::new()
+///     .with_exemplar_search(ExemplarSearch::Refined { candidates: 50 });
+/// assert_eq!(options.exemplar_search(), ExemplarSearch::Refined {
+///     candidates: 50
+/// });
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum ExemplarSearch {
+    /// Measure every word with the [`Reporter`](crate::Reporter)'s
+    /// [`GlyphMeasure`](crate::measure::GlyphMeasure).
+    #[default]
+    Exhaustive,
+    /// Measure every word with its glyphs' control boxes first (see
+    /// [`ControlBounds`](crate::measure::ControlBounds)), then re-measure only
+    /// the `candidates` highest & lowest of those with the
+    /// [`Reporter`](crate::Reporter)'s
+    /// [`GlyphMeasure`](crate::measure::GlyphMeasure).
+    ///
+    /// Control boxes are cheap to measure and never smaller than the exact
+    /// bounds, so with enough candidates (several times the number of
+    /// exemplars) the exemplars found are the same in practice. Combined with
+    /// [`Reporter::with_on_demand_glyphs`](crate::Reporter::with_on_demand_glyphs),
+    /// only the candidates' glyphs are measured exactly, which is worthwhile
+    /// for huge word lists or expensive glyph measures.
+    ///
+    /// Histograms & [streamed words](crate::InstanceReporter::check_streaming)
+    /// need every word measured exactly, so the search is exhaustive when
+    /// either is used. The number of candidates is never less than the number
+    /// of exemplars.
+    Refined {
+        /// The number of highest & lowest-reaching words to re-measure.
+        candidates: usize,
+    },
+}
+
+/// Which words measured may be kept as exemplars, see
+/// [`AnalysisOptions::with_exemplar_filter`].
+///
+/// Words made up only of dashes, dots or other flat marks reach barely above
+/// or below the baseline, and would otherwise crowd the lowest exemplars out
+/// with entries that say little about the font's vertical metrics.
+///
+/// ```
+/// # use fontheight::{ExemplarFilter, VerticalExtremes};
+/// let filter = ExemplarFilter::new()
+///     .with_min_ink_height(100.0)
+///     .with_min_glyphs(2);
+/// assert!(filter.keeps(VerticalExtremes::new(-200.0, 700.0), 5));
+/// // An em dash
+/// assert!(!filter.keeps(VerticalExtremes::new(240.0, 310.0), 1));
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ExemplarFilter {
+    min_ink_height: NotNan<f64>,
+    min_glyphs: usize,
+}
+
+impl ExemplarFilter {
+    /// A filter keeping every word.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        ExemplarFilter::default()
+    }
+
+    /// Only keep words whose ink is at least `min_ink_height` font units tall,
+    /// from their lowest to their highest extreme.
+    ///
+    /// `NaN` is treated as 0.
+    #[inline]
+    #[must_use]
+    pub fn with_min_ink_height(mut self, min_ink_height: f64) -> Self {
+        self.min_ink_height = NotNan::new(min_ink_height).unwrap_or_default();
+        self
+    }
+
+    /// Only keep words shaped to at least `min_glyphs` glyphs of the font.
+    #[inline]
+    #[must_use]
+    pub const fn with_min_glyphs(mut self, min_glyphs: usize) -> Self {
+        self.min_glyphs = min_glyphs;
+        self
+    }
+
+    /// The shortest ink a word kept may have, in font units.
+    #[inline]
+    #[must_use]
+    pub fn min_ink_height(&self) -> f64 {
+        *self.min_ink_height
+    }
+
+    /// The fewest glyphs a word kept may be shaped to.
+    #[inline]
+    #[must_use]
+    pub const fn min_glyphs(&self) -> usize {
+        self.min_glyphs
+    }
+
+    /// Whether a word reaching `extremes`, shaped to `glyphs` glyphs of the
+    /// font, may be kept as an exemplar.
+    #[must_use]
+    pub fn keeps(&self, extremes: VerticalExtremes, glyphs: usize) -> bool {
+        extremes.highest() - extremes.lowest() >= self.min_ink_height()
+            && glyphs >= self.min_glyphs
+    }
+}
+
+/// Options for checking a [`WordList`](crate::WordList) with
+/// [`InstanceReporter::check`](crate::InstanceReporter::check).
+///
+/// Options that change the glyphs every check measures (e.g.
+/// [`Reporter::with_glyph_measure`](crate::Reporter::with_glyph_measure) &
+/// [`Reporter::with_fallback_fonts`](crate::Reporter::with_fallback_fonts))
+/// are set on the [`Reporter`](crate::Reporter) instead, as each
+/// [`InstanceReporter`](crate::InstanceReporter) caches its glyphs'
+/// measurements.
+///
+/// ```
+/// # use fontheight::AnalysisOptions;
+/// let options = AnalysisOptions::new()
+///     .with_words_limit(Some(1000))
+///     .with_exemplars(10)
+///     .with_distinct(true);
+/// assert_eq!(options.exemplars(), 10);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AnalysisOptions {
+    words_limit: Option<usize>,
+    exemplars: usize,
+    distinct: bool,
+    segmentation: Segmentation,
+    exclusions: Vec<WordFilter>,
+    exemplar_search: ExemplarSearch,
+    punctuation: bool,
+    word_length_limit: Option<WordLengthLimit>,
+    exemplar_filter: Option<ExemplarFilter>,
+    thresholds: Option<Thresholds>,
+    features: Vec<String>,
+    partial_words: bool,
+    measurement: Measurement,
+}
+
+impl AnalysisOptions {
+    /// The number of exemplars kept by default.
+    pub const DEFAULT_EXEMPLARS: usize = 5;
+
+    /// Create options checking every word, keeping
+    /// [`DEFAULT_EXEMPLARS`](Self::DEFAULT_EXEMPLARS) exemplars.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        AnalysisOptions {
+            words_limit: None,
+            exemplars: Self::DEFAULT_EXEMPLARS,
+            distinct: false,
+            segmentation: Segmentation::Whole,
+            exclusions: Vec::new(),
+            exemplar_search: ExemplarSearch::Exhaustive,
+            punctuation: false,
+            word_length_limit: None,
+            exemplar_filter: None,
+            thresholds: None,
+            features: Vec::new(),
+            partial_words: false,
+            measurement: Measurement::Instance,
+        }
+    }
+
+    /// Only check the first `words_limit` words of each word list, or every
+    /// word if `None`.
+    #[inline]
+    #[must_use]
+    pub const fn with_words_limit(
+        mut self,
+        words_limit: Option<usize>,
+    ) -> Self {
+        self.words_limit = words_limit;
+        self
+    }
+
+    /// Keep the `exemplars` highest & lowest-reaching words.
+    #[inline]
+    #[must_use]
+    pub const fn with_exemplars(mut self, exemplars: usize) -> Self {
+        self.exemplars = exemplars;
+        self
+    }
+
+    /// Only keep the most extreme of words that differ only by case or
+    /// punctuation (e.g. "Ögonblick" and "ögonblick!").
+    #[inline]
+    #[must_use]
+    pub const fn with_distinct(mut self, distinct: bool) -> Self {
+        self.distinct = distinct;
+        self
+    }
+
+    /// Break each word list entry up with `segmentation` before shaping it,
+    /// e.g. to check the words of word lists made up of phrases.
+    ///
+    /// Words limits count entries, not the words they're split into.
+    #[inline]
+    #[must_use]
+    pub const fn with_segmentation(
+        mut self,
+        segmentation: Segmentation,
+    ) -> Self {
+        self.segmentation = segmentation;
+        self
+    }
+
+    /// Leave words `exclusion` matches out of the check, e.g. numbers or URLs
+    /// that would otherwise crowd the exemplars. Can be called repeatedly to
+    /// exclude words matching any of several filters.
+    ///
+    /// Filters are applied to each word shaped, so to the pieces entries are
+    /// split into by their [`Segmentation`]. Excluded words still count
+    /// towards the words limit.
+    #[inline]
+    #[must_use]
+    pub fn with_exclusion(mut self, exclusion: WordFilter) -> Self {
+        self.exclusions.push(exclusion);
+        self
+    }
+
+    /// Search for exemplars with `exemplar_search`, e.g. to only measure the
+    /// likeliest candidates exactly.
+    #[inline]
+    #[must_use]
+    pub const fn with_exemplar_search(
+        mut self,
+        exemplar_search: ExemplarSearch,
+    ) -> Self {
+        self.exemplar_search = exemplar_search;
+        self
+    }
+
+    /// Also shape each word wrapped in each [`Punctuation`](crate::Punctuation)
+    /// (e.g. in parentheses), so the exemplars account for the marks words
+    /// are typically set beside.
+    ///
+    /// Wrapped words are reported with their
+    /// [`punctuation`](crate::WordExtremes::punctuation), and each counts as a
+    /// word checked.
+    #[inline]
+    #[must_use]
+    pub const fn with_punctuation(mut self, punctuation: bool) -> Self {
+        self.punctuation = punctuation;
+        self
+    }
+
+    /// Guard against extremely long words (e.g. URLs) slowing the check down by
+    /// skipping, truncating or chunking words longer than `word_length_limit`,
+    /// or check words of any length if `None`.
+    ///
+    /// The limit applies to each word shaped, so to the pieces entries are
+    /// split into by their [`Segmentation`], before any
+    /// [punctuation](AnalysisOptions::with_punctuation) is added. Words over
+    /// the limit are counted in
+    /// [`ReportStatistics::too_long`](crate::ReportStatistics::too_long).
+    #[inline]
+    #[must_use]
+    pub const fn with_word_length_limit(
+        mut self,
+        word_length_limit: Option<WordLengthLimit>,
+    ) -> Self {
+        self.word_length_limit = word_length_limit;
+        self
+    }
+
+    /// Keep words `exemplar_filter` doesn't keep out of the exemplars, e.g.
+    /// words made up only of dashes or dots, or keep any word as an exemplar
+    /// if `None`.
+    ///
+    /// Filtered words are still measured, so still count as words checked and
+    /// towards histograms, and are counted in
+    /// [`ReportStatistics::filtered`](crate::ReportStatistics::filtered).
+    #[inline]
+    #[must_use]
+    pub const fn with_exemplar_filter(
+        mut self,
+        exemplar_filter: Option<ExemplarFilter>,
+    ) -> Self {
+        self.exemplar_filter = exemplar_filter;
+        self
+    }
+
+    /// Count the words measured reaching beyond `thresholds` (e.g. the font's
+    /// clipping metrics) in
+    /// [`ReportStatistics::exceeding`](crate::ReportStatistics::exceeding),
+    /// or don't count them if `None`.
+    ///
+    /// Every word needs measuring exactly to be counted, so the
+    /// [`ExemplarSearch`] is exhaustive when thresholds are set.
+    #[inline]
+    #[must_use]
+    pub const fn with_thresholds(
+        mut self,
+        thresholds: Option<Thresholds>,
+    ) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Also shape words with the OpenType `feature`, in the syntax of
+    /// [`harfrust::Feature`]'s `FromStr` (e.g. `smcp`, `-liga` or `ss01=1`),
+    /// on top of those the word list specifies. Can be called repeatedly to
+    /// apply several features.
+    ///
+    /// Like word lists' own features, these only apply to word lists that
+    /// specify a script. Invalid features make the check fail.
+    #[inline]
+    #[must_use]
+    pub fn with_feature(mut self, feature: impl Into<String>) -> Self {
+        self.features.push(feature.into());
+        self
+    }
+
+    /// Measure words with glyphs missing from the font by the glyphs they do
+    /// have, rather than skipping them, e.g. for early-stage fonts where
+    /// skipping every word with a missing character leaves few results.
+    ///
+    /// The missing (`.notdef`) glyphs are left out of the words' extremes, so
+    /// these words are partial: their extremes may not be what the finished
+    /// font would reach. Each word counts the glyphs left out in
+    /// [`WordExtremes::skipped_glyphs`](crate::WordExtremes::skipped_glyphs),
+    /// and the number of partial words is counted in
+    /// [`ReportStatistics::partial`](crate::ReportStatistics::partial).
+    /// Characters supported by
+    /// [fallback fonts](crate::Reporter::with_fallback_fonts) are still left
+    /// to them rather than counted as skipped.
+    #[inline]
+    #[must_use]
+    pub const fn with_partial_words(mut self, partial_words: bool) -> Self {
+        self.partial_words = partial_words;
+        self
+    }
+
+    /// Measure glyphs with `measurement`, e.g. to measure them by their
+    /// control boxes for a quick check.
+    #[inline]
+    #[must_use]
+    pub const fn with_measurement(mut self, measurement: Measurement) -> Self {
+        self.measurement = measurement;
+        self
+    }
+
+    /// The number of words checked from each word list, if limited.
+    #[inline]
+    #[must_use]
+    pub const fn words_limit(&self) -> Option<usize> {
+        self.words_limit
+    }
+
+    /// The number of highest & lowest-reaching words kept.
+    #[inline]
+    #[must_use]
+    pub const fn exemplars(&self) -> usize {
+        self.exemplars
+    }
+
+    /// Whether only the most extreme of words that differ only by case or
+    /// punctuation are kept.
+    #[inline]
+    #[must_use]
+    pub const fn distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// How each word list entry is broken up before shaping it.
+    #[inline]
+    #[must_use]
+    pub const fn segmentation(&self) -> Segmentation {
+        self.segmentation
+    }
+
+    /// How exemplars are searched for.
+    #[inline]
+    #[must_use]
+    pub const fn exemplar_search(&self) -> ExemplarSearch {
+        self.exemplar_search
+    }
+
+    /// Whether each word is also shaped wrapped in each
+    /// [`Punctuation`](crate::Punctuation).
+    #[inline]
+    #[must_use]
+    pub const fn punctuation(&self) -> bool {
+        self.punctuation
+    }
+
+    /// The limit on the length of words checked, if any.
+    #[inline]
+    #[must_use]
+    pub const fn word_length_limit(&self) -> Option<WordLengthLimit> {
+        self.word_length_limit
+    }
+
+    /// The filter deciding which words may be kept as exemplars, if any.
+    #[inline]
+    #[must_use]
+    pub const fn exemplar_filter(&self) -> Option<ExemplarFilter> {
+        self.exemplar_filter
+    }
+
+    /// The limits beyond which words are counted, if any.
+    #[inline]
+    #[must_use]
+    pub const fn thresholds(&self) -> Option<Thresholds> {
+        self.thresholds
+    }
+
+    /// The OpenType features words are shaped with, on top of those the word
+    /// list specifies.
+    #[inline]
+    #[must_use]
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    /// Whether words with glyphs missing from the font are measured by the
+    /// glyphs they do have, rather than skipped.
+    #[inline]
+    #[must_use]
+    pub const fn partial_words(&self) -> bool {
+        self.partial_words
+    }
+
+    /// How glyphs are measured.
+    #[inline]
+    #[must_use]
+    pub const fn measurement(&self) -> Measurement {
+        self.measurement
+    }
+
+    /// The filters words are excluded by.
+    #[inline]
+    #[must_use]
+    pub fn exclusions(&self) -> &[WordFilter] {
+        &self.exclusions
+    }
+
+    /// Whether `word` is excluded by any of the filters.
+    pub(crate) fn excludes(&self, word: &str) -> bool {
+        self.exclusions
+            .iter()
+            .any(|exclusion| exclusion.excludes(word))
+    }
+}
+
+impl Default for AnalysisOptions {
+    #[inline]
+    fn default() -> Self {
+        AnalysisOptions::new()
+    }
+}
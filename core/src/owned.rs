@@ -0,0 +1,218 @@
+use crate::{
+    Exemplars, Histogram, Location, Punctuation, Report, ReportStatistics,
+    ReportWarning, ShapingProperties, VerticalExtremes, WordExtremes,
+    language_support::SupportLevel,
+};
+
+/// An owned version of [`WordExtremes`], not borrowing from its
+/// [`WordList`](crate::WordList).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedWordExtremes {
+    /// The word that was shaped.
+    pub word: String,
+    /// The index of the word within its [`WordList`](crate::WordList).
+    pub index: usize,
+    /// The high & low point reached while shaping.
+    pub extremes: VerticalExtremes,
+    /// The number of glyphs missing from the font that were left out of the
+    /// extremes.
+    pub skipped_glyphs: usize,
+    /// The punctuation the word was wrapped in when it was shaped.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub punctuation: Option<Punctuation>,
+}
+
+impl OwnedWordExtremes {
+    /// Borrow as a [`WordExtremes`].
+    #[must_use]
+    pub fn borrowed(&self) -> WordExtremes<'_> {
+        WordExtremes {
+            word: &self.word,
+            index: self.index,
+            extremes: self.extremes,
+            skipped_glyphs: self.skipped_glyphs,
+            punctuation: self.punctuation,
+        }
+    }
+}
+
+impl From<&WordExtremes<'_>> for OwnedWordExtremes {
+    fn from(word_extremes: &WordExtremes<'_>) -> Self {
+        let WordExtremes {
+            word,
+            index,
+            extremes,
+            skipped_glyphs,
+            punctuation,
+        } = *word_extremes;
+        OwnedWordExtremes {
+            word: word.to_owned(),
+            index,
+            extremes,
+            skipped_glyphs,
+            punctuation,
+        }
+    }
+}
+
+/// An owned version of [`Exemplars`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "OwnedExemplarsFields")
+)]
+pub struct OwnedExemplars {
+    lowest: Vec<OwnedWordExtremes>,
+    highest: Vec<OwnedWordExtremes>,
+}
+
+impl OwnedExemplars {
+    /// Retrieve the lowest-reaching words.
+    ///
+    /// This slice will always be the same length as [`highest`](Self::highest).
+    #[inline]
+    #[must_use]
+    pub const fn lowest(&self) -> &[OwnedWordExtremes] {
+        self.lowest.as_slice()
+    }
+
+    /// Retrieve the highest-reaching words.
+    ///
+    /// This slice will always be the same length as [`lowest`](Self::lowest).
+    #[inline]
+    #[must_use]
+    pub const fn highest(&self) -> &[OwnedWordExtremes] {
+        self.highest.as_slice()
+    }
+
+    /// Returns `true` if there are no exemplars.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.lowest.is_empty()
+    }
+
+    /// Returns the number of exemplars in each list ([`highest`](Self::highest)
+    /// and [`lowest`](Self::lowest) are always equal in length).
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.lowest.len()
+    }
+
+    /// Borrow as [`Exemplars`], e.g. to [`merge`](Exemplars::merge) with
+    /// exemplars from a check still in progress.
+    #[must_use]
+    pub fn borrowed(&self) -> Exemplars<'_> {
+        Exemplars::from_parts(
+            self.lowest
+                .iter()
+                .map(OwnedWordExtremes::borrowed)
+                .collect(),
+            self.highest
+                .iter()
+                .map(OwnedWordExtremes::borrowed)
+                .collect(),
+        )
+    }
+
+    /// Combine with another set of exemplars, keeping the `n` lowest & `n`
+    /// highest words of both. See [`Exemplars::merge`].
+    #[must_use]
+    pub fn merge(&self, other: &OwnedExemplars, n: usize) -> OwnedExemplars {
+        OwnedExemplars::from(&self.borrowed().merge(&other.borrowed(), n))
+    }
+
+    /// Like [`merge`](Self::merge), but see [`Exemplars::merge_distinct`].
+    #[must_use]
+    pub fn merge_distinct(
+        &self,
+        other: &OwnedExemplars,
+        n: usize,
+    ) -> OwnedExemplars {
+        OwnedExemplars::from(
+            &self.borrowed().merge_distinct(&other.borrowed(), n),
+        )
+    }
+}
+
+/// The fields of [`OwnedExemplars`] as deserialized, before checking there are
+/// as many lowest as highest exemplars.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct OwnedExemplarsFields {
+    lowest: Vec<OwnedWordExtremes>,
+    highest: Vec<OwnedWordExtremes>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<OwnedExemplarsFields> for OwnedExemplars {
+    type Error = &'static str;
+
+    fn try_from(fields: OwnedExemplarsFields) -> Result<Self, Self::Error> {
+        let OwnedExemplarsFields { lowest, highest } = fields;
+        if lowest.len() != highest.len() {
+            return Err("there must be as many lowest as highest exemplars");
+        }
+        Ok(OwnedExemplars { lowest, highest })
+    }
+}
+
+impl From<&Exemplars<'_>> for OwnedExemplars {
+    fn from(exemplars: &Exemplars<'_>) -> Self {
+        OwnedExemplars {
+            lowest: exemplars.lowest().iter().map(Into::into).collect(),
+            highest: exemplars.highest().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// An owned version of [`Report`], not borrowing from its [`Location`] or
+/// [`WordList`](crate::WordList).
+///
+/// As [`WordList`](crate::WordList)s can't be cloned, only the identifying
+/// metadata of the word list is kept.
+#[derive(Debug, Clone)]
+pub struct OwnedReport {
+    /// The [`Location`] the exemplars were found at.
+    pub location: Location,
+    /// The name of the [`WordList`](crate::WordList) that was shaped.
+    pub word_list_name: String,
+    /// The source of the [`WordList`](crate::WordList) that was shaped, if
+    /// known.
+    pub word_list_source: Option<String>,
+    /// The highest & lowest-reaching words shaped.
+    pub exemplars: OwnedExemplars,
+    /// Counts of the words checked.
+    pub statistics: ReportStatistics,
+    /// The index of the `GSUB` feature variation that was applied while
+    /// shaping, if any.
+    pub feature_variation: Option<usize>,
+    /// How high & low all the words checked reached, if collected.
+    pub histogram: Option<Histogram>,
+    /// Problems found that may make the report misleading.
+    pub warnings: Vec<ReportWarning>,
+    /// How well the font supports the word list's language, if judged.
+    pub support: Option<SupportLevel>,
+    /// The scripts, languages & directions the words were shaped with.
+    pub shaping: Vec<ShapingProperties>,
+}
+
+impl From<&Report<'_>> for OwnedReport {
+    fn from(report: &Report<'_>) -> Self {
+        OwnedReport {
+            location: report.location.clone(),
+            word_list_name: report.word_list.name().to_owned(),
+            word_list_source: report.word_list.source().map(str::to_owned),
+            exemplars: OwnedExemplars::from(&report.exemplars),
+            statistics: report.statistics,
+            feature_variation: report.feature_variation,
+            histogram: report.histogram.clone(),
+            warnings: report.warnings.clone(),
+            support: report.support,
+            shaping: report.shaping.clone(),
+        }
+    }
+}
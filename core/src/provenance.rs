@@ -0,0 +1,153 @@
+//! Describing how reports were made, so they can be reproduced.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{AnalysisOptions, WordList};
+
+/// When & how a set of [`Report`](crate::Report)s were made: the versions of
+/// `fontheight` & the shaping and font-parsing crates it was built with, the
+/// contents of the word lists checked, and the [`AnalysisOptions`] used.
+///
+/// Storing this alongside reports makes them self-describing, so a report
+/// read later can be told apart from (or reproduced by) a later run.
+///
+/// ```
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// # use fontheight::{AnalysisOptions, Provenance, WordList};
+/// let mut provenance = Provenance::new(AnalysisOptions::new())
+///     .with_generated_at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+/// provenance.add_word_list(&WordList::define("test", ["a", "b"]));
+/// assert_eq!(provenance.timestamp(), "2023-11-14T22:13:20Z");
+/// assert_eq!(provenance.word_lists()[0].0, "test");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Provenance {
+    generated_at: SystemTime,
+    options: AnalysisOptions,
+    word_lists: Vec<(String, u32)>,
+}
+
+impl Provenance {
+    /// The version of `fontheight` making the reports.
+    pub const FONTHEIGHT_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    /// The version of [`harfrust`] words are shaped with.
+    // Pinned in the workspace's Cargo.toml, so keep this in step with it
+    pub const HARFRUST_VERSION: &'static str = "0.3.2";
+    /// The version of [`skrifa`] fonts are read with.
+    // Pinned in the workspace's Cargo.toml, so keep this in step with it
+    pub const SKRIFA_VERSION: &'static str = "0.37.0";
+
+    /// Describe reports made now, with `options`.
+    #[must_use]
+    pub fn new(options: AnalysisOptions) -> Self {
+        Provenance {
+            generated_at: SystemTime::now(),
+            options,
+            word_lists: Vec::new(),
+        }
+    }
+
+    /// Describe reports made at `generated_at` rather than now, e.g. to give
+    /// reproducible builds a fixed time.
+    #[inline]
+    #[must_use]
+    pub const fn with_generated_at(mut self, generated_at: SystemTime) -> Self {
+        self.generated_at = generated_at;
+        self
+    }
+
+    /// Record that `word_list` was checked, with a hash of its contents (see
+    /// [`WordList::content_hash`]).
+    ///
+    /// Word lists already recorded (by name) aren't hashed again.
+    pub fn add_word_list(&mut self, word_list: &WordList) {
+        if self
+            .word_lists
+            .iter()
+            .all(|(name, _)| name != word_list.name())
+        {
+            self.word_lists
+                .push((word_list.name().to_owned(), word_list.content_hash()));
+        }
+    }
+
+    /// When the reports were made.
+    #[inline]
+    #[must_use]
+    pub const fn generated_at(&self) -> SystemTime {
+        self.generated_at
+    }
+
+    /// When the reports were made, as an
+    /// [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) UTC timestamp to
+    /// the second (e.g. `2025-10-31T02:00:00Z`).
+    ///
+    /// Times before 1970 are given as 1970-01-01.
+    #[must_use]
+    pub fn timestamp(&self) -> String {
+        let secs = self
+            .generated_at
+            .duration_since(UNIX_EPOCH)
+            .as_ref()
+            .map_or(0, Duration::as_secs);
+        let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+        let secs_of_day = secs % 86_400;
+        format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            secs_of_day / 3600,
+            secs_of_day % 3600 / 60,
+            secs_of_day % 60,
+        )
+    }
+
+    /// The options words were checked with.
+    #[inline]
+    #[must_use]
+    pub const fn options(&self) -> &AnalysisOptions {
+        &self.options
+    }
+
+    /// The name & content hash of each word list checked, in the order they
+    /// were added.
+    #[inline]
+    #[must_use]
+    pub fn word_lists(&self) -> &[(String, u32)] {
+        &self.word_lists
+    }
+}
+
+/// The year, month & day `days` after 1970-01-01, by Howard Hinnant's
+/// `civil_from_days`.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn formats_timestamps() {
+        let at = |secs| {
+            Provenance::new(AnalysisOptions::new())
+                .with_generated_at(UNIX_EPOCH + Duration::from_secs(secs))
+                .timestamp()
+        };
+        assert_eq!(at(0), "1970-01-01T00:00:00Z");
+        // A leap day
+        assert_eq!(at(951_782_400 + 3_723), "2000-02-29T01:02:03Z");
+        assert_eq!(at(1_761_876_000), "2025-10-31T02:00:00Z");
+    }
+}
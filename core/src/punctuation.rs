@@ -0,0 +1,97 @@
+//! Wrapping words in punctuation, so their extremes include the marks they're
+//! typically set beside (e.g. an Arabic word in parentheses).
+
+use std::fmt;
+
+/// Punctuation a word can be wrapped in before shaping it, see
+/// [`AnalysisOptions::with_punctuation`](crate::AnalysisOptions::with_punctuation).
+///
+/// The opening character always comes first in the text shaped. Parentheses,
+/// brackets & guillemets are mirrored by the shaper in right-to-left runs, so
+/// the same text is correct for words of either direction.
+///
+/// ```
+/// # use fontheight::Punctuation;
+/// assert_eq!(Punctuation::Parentheses.wrap("كلمة"), "(كلمة)");
+/// assert_eq!(Punctuation::Quotes.wrap("word"), "\u{201C}word\u{201D}");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Punctuation {
+    /// `(word)`
+    Parentheses,
+    /// `[word]`
+    Brackets,
+    /// `“word”`
+    Quotes,
+    /// `«word»`
+    Guillemets,
+}
+
+impl Punctuation {
+    /// Every kind of punctuation words are wrapped in.
+    pub const ALL: [Punctuation; 4] = [
+        Punctuation::Parentheses,
+        Punctuation::Brackets,
+        Punctuation::Quotes,
+        Punctuation::Guillemets,
+    ];
+
+    /// The character placed before the word.
+    #[must_use]
+    pub const fn open(self) -> char {
+        match self {
+            Punctuation::Parentheses => '(',
+            Punctuation::Brackets => '[',
+            Punctuation::Quotes => '\u{201C}',
+            Punctuation::Guillemets => '«',
+        }
+    }
+
+    /// The character placed after the word.
+    #[must_use]
+    pub const fn close(self) -> char {
+        match self {
+            Punctuation::Parentheses => ')',
+            Punctuation::Brackets => ']',
+            Punctuation::Quotes => '\u{201D}',
+            Punctuation::Guillemets => '»',
+        }
+    }
+
+    /// A short, lowercase name for the punctuation (e.g. `parentheses`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Punctuation::Parentheses => "parentheses",
+            Punctuation::Brackets => "brackets",
+            Punctuation::Quotes => "quotes",
+            Punctuation::Guillemets => "guillemets",
+        }
+    }
+
+    /// The text shaped for `word` wrapped in the punctuation.
+    #[must_use]
+    pub fn wrap(self, word: &str) -> String {
+        let mut wrapped = String::with_capacity(word.len() + 6);
+        wrapped.push(self.open());
+        wrapped.push_str(word);
+        wrapped.push(self.close());
+        wrapped
+    }
+}
+
+impl fmt::Display for Punctuation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The unwrapped word, followed by each kind of punctuation.
+pub(crate) const WITH_UNWRAPPED: [Option<Punctuation>; 5] = [
+    None,
+    Some(Punctuation::Parentheses),
+    Some(Punctuation::Brackets),
+    Some(Punctuation::Quotes),
+    Some(Punctuation::Guillemets),
+];
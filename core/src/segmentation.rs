@@ -0,0 +1,172 @@
+use crate::exemplars::is_punctuation;
+
+/// How to break up word list entries before shaping them, for word lists
+/// whose entries are phrases or sentences rather than single words.
+///
+/// Whichever is chosen, the [`WordExtremes`](crate::WordExtremes) of each
+/// word shaped keep the [`index`](crate::WordExtremes::index) of the entry
+/// it came from, so results can be traced back to the word list.
+///
+/// ```
+/// # use fontheight::{AnalysisOptions, Segmentation};
+/// let options = AnalysisOptions::new().with_segmentation(Segmentation::Words);
+/// assert_eq!(options.segmentation(), Segmentation::Words);
+/// assert_eq!(Segmentation::Words.segments("Hvor er du?"), [
+///     "Hvor", "er", "du"
+/// ],);
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum Segmentation {
+    /// Shape each entry whole, as a single run of text.
+    #[default]
+    Whole,
+    /// Split each entry into words, and shape each word on its own.
+    ///
+    /// Entries are split at word boundaries, following the rules of
+    /// [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries) for
+    /// the common cases: words are runs of letters, digits & marks, which may
+    /// contain apostrophes & mid-word punctuation (e.g. "l'eau" or "3.14").
+    /// Spaces & other punctuation are dropped.
+    Words,
+    /// Shape each entry whole, and also each of its words on its own.
+    Both,
+}
+
+impl Segmentation {
+    /// The text to shape for a word list entry, as slices of `entry`.
+    ///
+    /// Entries that are a single word aren't shaped twice with
+    /// [`Segmentation::Both`].
+    #[must_use]
+    pub fn segments(self, entry: &str) -> Vec<&str> {
+        match self {
+            Segmentation::Whole => vec![entry],
+            Segmentation::Words => words(entry),
+            Segmentation::Both => {
+                let mut segments = words(entry);
+                if segments != [entry] {
+                    segments.insert(0, entry);
+                }
+                segments
+            },
+        }
+    }
+
+    /// A short, lowercase name for the segmentation (e.g. `words`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Segmentation::Whole => "whole",
+            Segmentation::Words => "words",
+            Segmentation::Both => "both",
+        }
+    }
+}
+
+/// Whether `c` can be part of a word: anything but spaces & punctuation, so
+/// letters, digits, marks & joining controls.
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !c.is_control() && !is_punctuation(c)
+}
+
+/// Punctuation that doesn't break a word when it's between letters or digits
+/// (`MidLetter` & `MidNumLet` in UAX #29).
+const fn is_mid_letter(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '.'
+            | ':'
+            | '·'
+            | '\u{0387}'
+            | '\u{055F}'
+            | '\u{05F4}'
+            | '\u{2018}'
+            | '\u{2019}'
+            | '\u{2024}'
+            | '\u{2027}'
+            | '\u{FE13}'
+            | '\u{FE52}'
+            | '\u{FE55}'
+            | '\u{FF07}'
+            | '\u{FF0E}'
+            | '\u{FF1A}'
+    )
+}
+
+/// Punctuation that doesn't break a number when it's between digits
+/// (`MidNum` in UAX #29).
+const fn is_mid_num(c: char) -> bool {
+    matches!(
+        c,
+        ',' | ';'
+            | '\u{066C}'
+            | '\u{FE50}'
+            | '\u{FE54}'
+            | '\u{FF0C}'
+            | '\u{FF1B}'
+    )
+}
+
+/// Split `entry` into its words, dropping the spaces & punctuation between
+/// them.
+fn words(entry: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut previous = None::<char>;
+    let mut chars = entry.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, next)| next);
+        let in_word = is_word_char(c)
+            || start.is_some()
+                && match (previous, next) {
+                    (Some(previous), Some(next)) => {
+                        (is_mid_letter(c) && is_word_char(next))
+                            || (is_mid_num(c)
+                                && previous.is_numeric()
+                                && next.is_numeric())
+                    },
+                    _ => false,
+                };
+        match (in_word, start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                words.push(&entry[word_start..index]);
+                start = None;
+            },
+            _ => {},
+        }
+        previous = Some(c);
+    }
+    if let Some(word_start) = start {
+        words.push(&entry[word_start..]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn splits_words() {
+        assert_eq!(
+            words("« L'eau, c'est 3,14 fois… plus — well-known »"),
+            ["L'eau", "c'est", "3,14", "fois", "plus", "well", "known"],
+        );
+        assert_eq!(words("end. Start"), ["end", "Start"]);
+        assert_eq!(words("a, b"), ["a", "b"]);
+        assert_eq!(words("नमस्ते दुनिया"), [
+            "नमस्ते",
+            "दुनिया"
+        ]);
+        assert!(words(" !? ").is_empty());
+    }
+
+    #[test]
+    fn segments_entries() {
+        assert_eq!(Segmentation::Whole.segments("a b"), ["a b"]);
+        assert_eq!(Segmentation::Both.segments("a b"), ["a b", "a", "b"]);
+        assert_eq!(Segmentation::Both.segments("word"), ["word"]);
+        assert_eq!(Segmentation::Both.segments("word!"), ["word!", "word"]);
+    }
+}
@@ -0,0 +1,340 @@
+//! Re-using the setup for checking a font across repeated checks of it.
+//!
+//! Parsing the shaping tables and drawing every glyph at each location checked
+//! takes longer than checking a small sample of words, so a long-running
+//! process (e.g. a server checking each revision of a font sent to it) can
+//! keep them between checks of the same font.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use harfrust::ShaperData;
+use skrifa::{FontRef, MetadataProvider};
+
+use crate::{
+    InstanceExtremes, Location, Reporter,
+    errors::{FontHeightError, SkrifaReadError},
+};
+
+/// Keeps the setup for checking recently checked fonts, so checking one of
+/// them again skips it.
+///
+/// The shaping data of each font is kept, as are the extremes of its glyphs at
+/// each location an [`InstanceReporter`](crate::InstanceReporter) has been
+/// created for. Fonts are told apart by their contents, and the least recently
+/// checked is forgotten once there are more than
+/// [`max_fonts`](AnalysisSession::with_max_fonts), as is the least recently
+/// checked location of a font once there are more than
+/// [`max_instances`](AnalysisSession::with_max_instances). Built-in word lists
+/// stay decompressed once they've been needed regardless.
+///
+/// ```no_run
+/// # use fontheight::{AnalysisOptions, AnalysisSession, WordList};
+/// # let font_bytes = Vec::new();
+/// # let word_list = WordList::define("test", ["a"]);
+/// let session = AnalysisSession::new();
+/// // Only the first check of the font draws its glyphs
+/// for _ in 0..2 {
+///     let font = session.font(&font_bytes)?;
+///     let reporter = font.reporter();
+///     let report = reporter
+///         .default_instance()?
+///         .check(&word_list, &AnalysisOptions::new())?;
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct AnalysisSession {
+    max_fonts: usize,
+    max_instances: usize,
+    // Least recently checked first
+    fonts: Mutex<Vec<Arc<SessionFont>>>,
+}
+
+impl AnalysisSession {
+    /// How many fonts are kept by default.
+    pub const DEFAULT_MAX_FONTS: usize = 8;
+    /// How many locations of each font are kept by default.
+    pub const DEFAULT_MAX_INSTANCES: usize = 32;
+
+    /// Create a session keeping up to
+    /// [`DEFAULT_MAX_FONTS`](AnalysisSession::DEFAULT_MAX_FONTS) fonts, each
+    /// with up to
+    /// [`DEFAULT_MAX_INSTANCES`](AnalysisSession::DEFAULT_MAX_INSTANCES)
+    /// locations.
+    #[must_use]
+    pub const fn new() -> Self {
+        AnalysisSession {
+            max_fonts: AnalysisSession::DEFAULT_MAX_FONTS,
+            max_instances: AnalysisSession::DEFAULT_MAX_INSTANCES,
+            fonts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Keep up to `max_fonts` fonts, rather than
+    /// [`DEFAULT_MAX_FONTS`](AnalysisSession::DEFAULT_MAX_FONTS).
+    #[must_use]
+    pub const fn with_max_fonts(mut self, max_fonts: usize) -> Self {
+        self.max_fonts = max_fonts;
+        self
+    }
+
+    /// Keep the glyphs of each font drawn at up to `max_instances` locations,
+    /// rather than
+    /// [`DEFAULT_MAX_INSTANCES`](AnalysisSession::DEFAULT_MAX_INSTANCES).
+    #[must_use]
+    pub const fn with_max_instances(mut self, max_instances: usize) -> Self {
+        self.max_instances = max_instances;
+        self
+    }
+
+    /// Get the font with the contents `font_bytes`, parsing it if it isn't
+    /// one of the fonts kept.
+    ///
+    /// Fails if the bytes couldn't be parsed, or if the font has no outlines
+    /// (see [`FontHeightError::NoOutlines`]).
+    pub fn font(
+        &self,
+        font_bytes: &[u8],
+    ) -> Result<Arc<SessionFont>, FontHeightError> {
+        let checksum = crc32fast::hash(font_bytes);
+        let mut fonts =
+            self.fonts.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = fonts.iter().position(|font| {
+            font.checksum == checksum && *font.bytes == *font_bytes
+        }) {
+            let font = fonts.remove(index);
+            fonts.push(Arc::clone(&font));
+            return Ok(font);
+        }
+        // Parse without the lock held, so kept fonts can be checked meanwhile
+        drop(fonts);
+
+        let font = Arc::new(SessionFont::new(
+            font_bytes.to_vec(),
+            checksum,
+            self.max_instances,
+        )?);
+        let mut fonts =
+            self.fonts.lock().unwrap_or_else(PoisonError::into_inner);
+        fonts.push(Arc::clone(&font));
+        let excess = fonts.len().saturating_sub(self.max_fonts);
+        fonts.drain(..excess);
+        Ok(font)
+    }
+}
+
+impl Default for AnalysisSession {
+    fn default() -> Self {
+        AnalysisSession::new()
+    }
+}
+
+impl fmt::Debug for AnalysisSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fonts = self.fonts.lock().unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("AnalysisSession")
+            .field("max_fonts", &self.max_fonts)
+            .field("max_instances", &self.max_instances)
+            .field("fonts", &*fonts)
+            .finish()
+    }
+}
+
+/// A font kept by an [`AnalysisSession`], with its shaping data and the
+/// extremes of its glyphs at each location drawn so far.
+pub struct SessionFont {
+    checksum: u32,
+    bytes: Vec<u8>,
+    shaper_data: Arc<ShaperData>,
+    instances: Arc<InstanceCache>,
+}
+
+impl SessionFont {
+    fn new(
+        bytes: Vec<u8>,
+        checksum: u32,
+        max_instances: usize,
+    ) -> Result<Self, FontHeightError> {
+        let font = FontRef::new(&bytes).map_err(SkrifaReadError::from)?;
+        if font.outline_glyphs().format().is_none() {
+            return Err(FontHeightError::NoOutlines);
+        }
+        let shaper_data = Arc::new(ShaperData::new(&font));
+        Ok(SessionFont {
+            checksum,
+            bytes,
+            shaper_data,
+            instances: Arc::new(InstanceCache::new(max_instances)),
+        })
+    }
+
+    /// The CRC-32 of the font's contents.
+    #[inline]
+    #[must_use]
+    pub const fn checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    /// The font's contents.
+    #[inline]
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Create a [`Reporter`] for the font, sharing its shaping data and drawn
+    /// glyphs.
+    ///
+    /// Glyphs are only shared while they're measured with the default
+    /// [`ExactBounds`](crate::measure::ExactBounds) and drawn up-front (not
+    /// [on demand](Reporter::with_on_demand_glyphs)).
+    #[must_use]
+    pub fn reporter(&self) -> Reporter<'_> {
+        // The bytes were parsed when the font was kept
+        let font = FontRef::new(&self.bytes)
+            .expect("session font should have been parsed already");
+        Reporter::from_shared(
+            font,
+            Arc::clone(&self.shaper_data),
+            Some(Arc::clone(&self.instances)),
+        )
+    }
+}
+
+impl fmt::Debug for SessionFont {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionFont")
+            .field("checksum", &format_args!("{:08x}", self.checksum))
+            .field("len", &self.bytes.len())
+            .field("instances", &self.instances)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The extremes of a font's glyphs at each location drawn so far, forgetting
+/// the least recently used once there are more than `max_instances`.
+#[derive(Debug)]
+pub(crate) struct InstanceCache {
+    max_instances: usize,
+    drawn: Mutex<DrawnInstances>,
+}
+
+#[derive(Debug, Default)]
+struct DrawnInstances {
+    // Each with when it was last used, to forget the least recently used
+    instances: HashMap<Location, (u64, Arc<InstanceExtremes>)>,
+    uses: u64,
+}
+
+impl InstanceCache {
+    fn new(max_instances: usize) -> Self {
+        InstanceCache {
+            max_instances,
+            drawn: Mutex::default(),
+        }
+    }
+
+    /// Get the extremes at `location`, calling `draw` if they haven't been
+    /// drawn yet.
+    pub(crate) fn get_or_draw<E>(
+        &self,
+        location: &Location,
+        draw: impl FnOnce() -> Result<InstanceExtremes, E>,
+    ) -> Result<Arc<InstanceExtremes>, E> {
+        let mut drawn =
+            self.drawn.lock().unwrap_or_else(PoisonError::into_inner);
+        drawn.uses += 1;
+        let uses = drawn.uses;
+        if let Some((last_used, extremes)) = drawn.instances.get_mut(location) {
+            *last_used = uses;
+            return Ok(Arc::clone(extremes));
+        }
+        // Draw without the lock held, so other locations can be drawn at the
+        // same time. Two threads may draw the same location; the first to
+        // finish is kept, and given to both
+        drop(drawn);
+
+        let extremes = Arc::new(draw()?);
+        let mut drawn =
+            self.drawn.lock().unwrap_or_else(PoisonError::into_inner);
+        let (_, extremes) = drawn
+            .instances
+            .entry(location.clone())
+            .or_insert((uses, extremes));
+        let extremes = Arc::clone(extremes);
+        if drawn.instances.len() > self.max_instances {
+            let least_recently_used = drawn
+                .instances
+                .iter()
+                .min_by_key(|(_, (last_used, _))| *last_used)
+                .map(|(location, _)| location.clone());
+            if let Some(location) = least_recently_used {
+                drawn.instances.remove(&location);
+            }
+        }
+        Ok(extremes)
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.drawn
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .instances
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::measure::ExactBounds;
+
+    #[test]
+    fn rejects_unparseable_fonts() {
+        let session = AnalysisSession::new();
+        assert!(matches!(
+            session.font(b"not a font"),
+            Err(FontHeightError::Skrifa(_))
+        ));
+        assert!(session.fonts.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn reuses_drawn_instances() {
+        let font_bytes = include_bytes!("../tests/fonts/DejaVuSans.ttf");
+        let session = AnalysisSession::new().with_max_instances(1);
+        let font = session.font(font_bytes).unwrap();
+        let reporter = font.reporter();
+        let location = Location::new();
+        let first = reporter.draw_instance(&location).unwrap();
+        let second = reporter.draw_instance(&location).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(font.instances.len(), 1);
+
+        // Looking the font up again shares its drawn instances
+        let again = session.font(font_bytes).unwrap();
+        assert!(Arc::ptr_eq(&font, &again));
+        let third = again.reporter().draw_instance(&location).unwrap();
+        assert!(Arc::ptr_eq(&first, &third));
+        assert_eq!(font.instances.len(), 1);
+
+        // Only max_instances are kept, forgetting the least recently used. The
+        // font isn't variable, so draw its default under another location
+        let mut other = Location::new();
+        other.axis("wght", 700.0).unwrap();
+        let draw =
+            || InstanceExtremes::new(&reporter.font, &location, &ExactBounds);
+        let fourth = font.instances.get_or_draw(&other, draw).unwrap();
+        assert!(Arc::ptr_eq(
+            &fourth,
+            &font.instances.get_or_draw(&other, draw).unwrap(),
+        ));
+        assert_eq!(font.instances.len(), 1);
+        let fifth = reporter.draw_instance(&location).unwrap();
+        assert!(!Arc::ptr_eq(&first, &fifth));
+    }
+}
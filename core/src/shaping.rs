@@ -0,0 +1,1063 @@
+//! Shaping words to find their [`VerticalExtremes`].
+//!
+//! Words are split into runs of a single script before shaping, so that
+//! mixed-script words (e.g. Arabic containing a Latin brand name) have each
+//! part shaped with the correct script & direction.
+//!
+//! When measuring multi-line words, each line is shaped separately and moved
+//! below the previous one by the font's line height.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::Infallible,
+    fmt, mem,
+    ops::Range,
+    sync::Arc,
+};
+
+use harfrust::{Direction, GlyphBuffer, Script, Shaper, UnicodeBuffer};
+use harfshapedfa::{
+    HarfRustShaperExt, ShapingMeta, convert::direction_from_script,
+};
+use ordered_float::NotNan;
+
+use crate::{
+    InstanceExtremes, VerticalExtremes, is_shared_script,
+    shaping_cache::WordListCache,
+};
+
+/// The codepoints supported by a [`Reporter`](crate::Reporter)'s fallback
+/// fonts.
+pub(crate) type FallbackCoverage = Arc<HashSet<u32>>;
+
+/// A source of the [`VerticalExtremes`] of individual glyphs.
+pub(crate) trait GlyphExtremes {
+    type Error;
+
+    fn glyph_extremes(
+        &self,
+        glyph_id: u32,
+    ) -> Result<VerticalExtremes, Self::Error>;
+}
+
+/// The vertical extremes reached by a single script run of a word.
+///
+/// See [`InstanceReporter::run_extremes`](crate::InstanceReporter::run_extremes).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RunExtremes<'w> {
+    /// The part of the word in this run.
+    pub text: &'w str,
+    /// The [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) code of the
+    /// run's script.
+    ///
+    /// `None` if the run only contains characters shared between scripts (e.g.
+    /// digits & punctuation).
+    pub script: Option<String>,
+    /// The high & low point reached while shaping the run.
+    pub extremes: VerticalExtremes,
+}
+
+/// The script, language & direction some of a word list's words were shaped
+/// with.
+///
+/// See [`Report::shaping`](crate::Report::shaping).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ShapingProperties {
+    /// The [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) code of the
+    /// script, e.g. `Zzzz` (Unknown) for guessed runs only containing
+    /// characters shared between scripts.
+    pub script: String,
+    /// The language, if the word list has one and it was shaped with it.
+    pub language: Option<String>,
+    /// The direction the text was laid out in.
+    pub direction: TextDirection,
+    /// Whether the script & direction were guessed from the text, rather than
+    /// taken from the word list's metadata.
+    pub guessed: bool,
+}
+
+/// The direction text is laid out in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TextDirection {
+    /// Left-to-right, e.g. Latin.
+    LeftToRight,
+    /// Right-to-left, e.g. Arabic.
+    RightToLeft,
+    /// Top-to-bottom, e.g. vertical Mongolian.
+    TopToBottom,
+    /// Bottom-to-top.
+    BottomToTop,
+}
+
+impl TextDirection {
+    /// A short, lowercase name for the direction (e.g. `rtl`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            TextDirection::LeftToRight => "ltr",
+            TextDirection::RightToLeft => "rtl",
+            TextDirection::TopToBottom => "ttb",
+            TextDirection::BottomToTop => "btt",
+        }
+    }
+
+    const fn from_harfrust(direction: Direction) -> Self {
+        match direction {
+            Direction::RightToLeft => TextDirection::RightToLeft,
+            Direction::TopToBottom => TextDirection::TopToBottom,
+            Direction::BottomToTop => TextDirection::BottomToTop,
+            // harfrust lays out text with an invalid direction left-to-right
+            Direction::LeftToRight | Direction::Invalid => {
+                TextDirection::LeftToRight
+            },
+        }
+    }
+}
+
+impl fmt::Display for TextDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The vertical extremes reached by a single grapheme cluster of a word,
+/// alongside the cluster's byte range within the word.
+///
+/// See [`InstanceReporter::cluster_extremes`](crate::InstanceReporter::cluster_extremes).
+pub type ClusterExtremes = (Range<usize>, VerticalExtremes);
+
+/// One of the glyphs a word shaped to, with its outline's extremes and how
+/// far shaping moved it vertically.
+///
+/// See [`InstanceReporter::placed_glyphs`](crate::InstanceReporter::placed_glyphs).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlacedGlyph {
+    /// The glyph's ID in the font.
+    pub glyph_id: u32,
+    /// The byte offset within the word of the cluster the glyph belongs to.
+    pub cluster: usize,
+    /// The extremes of the glyph's outline, before it's moved.
+    pub bounds: VerticalExtremes,
+    /// How far the glyph was raised, by `GPOS` (e.g. mark positioning) and,
+    /// for the lines of [multi-line](crate::Reporter::with_multi_line) words
+    /// after the first, lowered to its line's baseline.
+    pub y_offset: f64,
+}
+
+impl PlacedGlyph {
+    /// The extremes the glyph reaches once it's moved.
+    #[inline]
+    #[must_use]
+    pub fn extremes(&self) -> VerticalExtremes {
+        lowered(self.bounds, -self.y_offset)
+    }
+}
+
+/// The outcome of [`WordShaper::shape`].
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum Shaped {
+    Extremes(VerticalExtremes),
+    /// Glyphs needed to shape the word are missing from the font.
+    MissingGlyphs,
+    /// The word shaped to no glyphs, or only glyphs without outlines.
+    NoInk,
+}
+
+/// A word shaped by [`WordShaper::shape`], with how its glyphs were found.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ShapedWord {
+    pub shaped: Shaped,
+    pub counts: GlyphCounts,
+}
+
+/// How the glyphs a word (or run) shaped to were found.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct GlyphCounts {
+    // Whether any characters were left to fallback fonts
+    pub used_fallback: bool,
+    // The number of missing glyphs left out of the measurements
+    pub skipped_glyphs: usize,
+    // The number of the font's glyphs shaped to
+    pub glyph_count: usize,
+}
+
+impl GlyphCounts {
+    const fn add(&mut self, other: GlyphCounts) {
+        self.used_fallback |= other.used_fallback;
+        self.skipped_glyphs += other.skipped_glyphs;
+        self.glyph_count += other.glyph_count;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ScriptRun {
+    // None if the run only contains characters shared between scripts
+    script: Option<Script>,
+    range: Range<usize>,
+}
+
+/// Buffers that can be re-used between words, one set per thread.
+pub(crate) struct WordShaper {
+    // UnicodeBuffer is transformed into another type during shaping, and then
+    // can only be reverted once we've finished analysing the shaped buffer.
+    // The Option allows us to take ownership of it during each iteration for
+    // these type changes to happen, while still re-using the buffer
+    unicode_buffer: Option<UnicodeBuffer>,
+    // harfrust doesn't expose the script of a character directly, so we
+    // look it up by having it guess the script of a single-character buffer
+    script_buffer: UnicodeBuffer,
+    runs: Vec<ScriptRun>,
+    // The distance between the baselines of the lines of multi-line words,
+    // None if words are always measured as a single line
+    pub line_height: Option<f64>,
+    // The codepoints that can be left to fallback fonts when missing from the
+    // font, if there are any
+    fallback_coverage: Option<FallbackCoverage>,
+    // Whether to measure the glyphs of words that are present in the font
+    // when others are missing, rather than skipping the words
+    partial_words: bool,
+    // Whether to keep the glyph buffers each run shaped to in glyph_buffers,
+    // rather than clearing them for the next run
+    keep_glyph_buffers: bool,
+    glyph_buffers: Vec<GlyphBuffer>,
+    // Buffers to shape the next runs with while glyph_buffers holds on to the
+    // last word's
+    spare_buffers: Vec<UnicodeBuffer>,
+}
+
+/// A line of a word, with its byte offset within the word and its index among
+/// the word's lines (see [`WordShaper::baseline`]).
+type Line<'w> = (usize, &'w str, usize);
+
+impl WordShaper {
+    pub fn new(line_height: Option<f64>) -> Self {
+        WordShaper {
+            unicode_buffer: Some(UnicodeBuffer::new()),
+            script_buffer: UnicodeBuffer::new(),
+            runs: Vec::new(),
+            line_height,
+            fallback_coverage: None,
+            partial_words: false,
+            keep_glyph_buffers: false,
+            glyph_buffers: Vec::new(),
+            spare_buffers: Vec::new(),
+        }
+    }
+
+    /// Keep the [`GlyphBuffer`]s each word shapes to, to be passed on by
+    /// [`WordShaper::drain_glyph_buffers`].
+    pub const fn keep_glyph_buffers(mut self, keep: bool) -> Self {
+        self.keep_glyph_buffers = keep;
+        self
+    }
+
+    /// Pass the [`GlyphBuffer`] of each run shaped since the last call to
+    /// `on_shaped`, in the order they were shaped, and reuse them for the next
+    /// word.
+    ///
+    /// Only has buffers to pass with [`WordShaper::keep_glyph_buffers`].
+    pub fn drain_glyph_buffers(
+        &mut self,
+        on_shaped: impl FnOnce(&[GlyphBuffer]),
+    ) {
+        on_shaped(&self.glyph_buffers);
+        self.spare_buffers.extend(
+            self.glyph_buffers
+                .drain(..)
+                .map(|glyph_buffer| glyph_buffer.clear()),
+        );
+    }
+
+    /// Leave characters missing from the font to fallback fonts covering
+    /// `fallback_coverage`, rather than skipping words containing them.
+    pub fn with_fallback(
+        mut self,
+        fallback_coverage: Option<FallbackCoverage>,
+    ) -> Self {
+        self.fallback_coverage = fallback_coverage;
+        self
+    }
+
+    /// Measure the glyphs present in the font of words with missing glyphs,
+    /// counting the missing glyphs in [`GlyphCounts::skipped_glyphs`], rather
+    /// than skipping the words.
+    pub const fn with_partial_words(mut self, partial_words: bool) -> Self {
+        self.partial_words = partial_words;
+        self
+    }
+
+    /// Split `word` on newlines if measuring multi-line words.
+    ///
+    /// Blank lines are skipped, though they still take up a line.
+    fn lines<'w>(&self, word: &'w str) -> Vec<Line<'w>> {
+        if self.line_height.is_none() {
+            return vec![(0, word, 0)];
+        }
+        let mut offset = 0;
+        word.split('\n')
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let line_offset = offset;
+                offset += line.len() + 1;
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                (!line.is_empty()).then_some((line_offset, line, index))
+            })
+            .collect()
+    }
+
+    /// How far the baseline of the `index`th line is below the first line's.
+    fn baseline(&self, index: usize) -> f64 {
+        baseline(index, self.line_height)
+    }
+
+    /// Shape `word`, finding the extremes it reaches.
+    pub fn shape<G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        word: &str,
+    ) -> Result<ShapedWord, G::Error> {
+        let mut counts = GlyphCounts::default();
+        let mut block = Shaped::NoInk;
+        for (_, line, index) in self.lines(word) {
+            let shaped = self.shape_line(
+                shaper,
+                shaping_meta,
+                glyph_extremes,
+                line,
+                &mut counts,
+            )?;
+            match shaped {
+                Shaped::Extremes(extremes) => {
+                    let extremes = lowered(extremes, self.baseline(index));
+                    block = Shaped::Extremes(match block {
+                        Shaped::Extremes(block) => block.merge(extremes),
+                        _ => extremes,
+                    });
+                },
+                Shaped::MissingGlyphs => {
+                    return Ok(ShapedWord {
+                        shaped: Shaped::MissingGlyphs,
+                        counts,
+                    });
+                },
+                Shaped::NoInk => {},
+            }
+        }
+        Ok(ShapedWord {
+            shaped: block,
+            counts,
+        })
+    }
+
+    /// Like [`WordShaper::shape`], going through `cache` so the word is only
+    /// shaped the first time it's seen at any location.
+    pub fn shape_cached<G: GlyphExtremes>(
+        &mut self,
+        cache: &WordListCache,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        word: &str,
+    ) -> Result<ShapedWord, G::Error> {
+        let glyphs = cache.get_or_shape(word, || {
+            self.shape_glyphs(shaper, shaping_meta, word)
+        });
+        Ok(ShapedWord {
+            shaped: glyphs.measure(glyph_extremes, self.line_height)?,
+            counts: glyphs.counts(),
+        })
+    }
+
+    /// Shape `word`, keeping the glyphs it shaped to rather than measuring
+    /// them.
+    fn shape_glyphs(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        word: &str,
+    ) -> ShapedGlyphs {
+        let mut counts = GlyphCounts::default();
+        let mut lines = Vec::new();
+        for (_, line, index) in self.lines(word) {
+            self.segment(line);
+            let mut glyphs = Vec::new();
+            for ScriptRun { script, range } in self.runs.clone() {
+                let glyphs_found = self.shape_run_positions(
+                    shaper,
+                    shaping_meta,
+                    &line[range],
+                    script,
+                    |_, glyph| {
+                        glyphs.push(glyph);
+                        Ok::<_, Infallible>(())
+                    },
+                );
+                match glyphs_found {
+                    Ok(Some(run_counts)) => counts.add(run_counts),
+                    Ok(None) => return ShapedGlyphs::MissingGlyphs,
+                    Err(never) => match never {},
+                }
+            }
+            lines.push((index, glyphs.into_boxed_slice()));
+        }
+        ShapedGlyphs::Glyphs {
+            lines: lines.into_boxed_slice(),
+            counts,
+        }
+    }
+
+    fn shape_line<G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        word: &str,
+        counts: &mut GlyphCounts,
+    ) -> Result<Shaped, G::Error> {
+        self.segment(word);
+        // Vertical extremes don't depend on the visual order of the runs, so
+        // there's no need to reorder them
+        let mut extremes = None::<VerticalExtremes>;
+        let mut has_ink = false;
+        for index in 0..self.runs.len() {
+            let ScriptRun { script, range } = self.runs[index].clone();
+            let glyphs_found = self.shape_run_glyphs(
+                shaper,
+                shaping_meta,
+                glyph_extremes,
+                &word[range],
+                script,
+                |_, glyph| {
+                    // Glyphs without outlines are measured as a single point
+                    has_ink |= glyph.lowest < glyph.highest;
+                    extremes = Some(
+                        extremes
+                            .map_or(glyph, |extremes| extremes.merge(glyph)),
+                    );
+                },
+            )?;
+            match glyphs_found {
+                Some(run_counts) => counts.add(run_counts),
+                None => return Ok(Shaped::MissingGlyphs),
+            }
+        }
+        Ok(match extremes {
+            Some(extremes) if has_ink => Shaped::Extremes(extremes),
+            _ => Shaped::NoInk,
+        })
+    }
+
+    /// Like [`WordShaper::shape`], but reports on each run individually.
+    pub fn shape_runs<'w, G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        word: &'w str,
+    ) -> Result<Option<Vec<RunExtremes<'w>>>, G::Error> {
+        let mut runs = Vec::new();
+        for (_, line, index) in self.lines(word) {
+            let baseline = self.baseline(index);
+            self.segment(line);
+            for ScriptRun { script, range } in self.runs.clone() {
+                let text = &line[range];
+                let Some(extremes) = self.shape_run(
+                    shaper,
+                    shaping_meta,
+                    glyph_extremes,
+                    text,
+                    script,
+                )?
+                else {
+                    return Ok(None);
+                };
+                runs.push(RunExtremes {
+                    text,
+                    script: script.map(|script| script.tag().to_string()),
+                    extremes: lowered(extremes, baseline),
+                });
+            }
+        }
+        Ok(Some(runs))
+    }
+
+    /// Like [`WordShaper::shape`], but reports on each grapheme cluster
+    /// individually.
+    ///
+    /// Clusters are given as byte ranges of `word`, in logical order.
+    pub fn shape_clusters<G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        word: &str,
+    ) -> Result<Option<Vec<ClusterExtremes>>, G::Error> {
+        let mut clusters = Vec::new();
+        for (line_offset, line, index) in self.lines(word) {
+            let baseline = self.baseline(index);
+            self.segment(line);
+            for ScriptRun { script, range } in self.runs.clone() {
+                let range = line_offset + range.start..line_offset + range.end;
+                // Glyphs are in visual order, and a cluster may be made up of
+                // several glyphs
+                let mut run_clusters =
+                    BTreeMap::<usize, VerticalExtremes>::new();
+                let glyphs_found = self.shape_run_glyphs(
+                    shaper,
+                    shaping_meta,
+                    glyph_extremes,
+                    &word[range.clone()],
+                    script,
+                    |cluster, extremes| {
+                        let extremes = lowered(extremes, baseline);
+                        run_clusters
+                            .entry(range.start + cluster as usize)
+                            .and_modify(|cluster| {
+                                *cluster = cluster.merge(extremes)
+                            })
+                            .or_insert(extremes);
+                    },
+                )?;
+                if glyphs_found.is_none() {
+                    return Ok(None);
+                }
+                // Each cluster continues until the next one starts
+                let starts = run_clusters.keys().copied().collect::<Vec<_>>();
+                let ends = starts.iter().skip(1).copied().chain([range.end]);
+                clusters.extend(
+                    run_clusters
+                        .into_values()
+                        .zip(starts.iter().copied().zip(ends))
+                        .map(|(extremes, (start, end))| (start..end, extremes)),
+                );
+            }
+        }
+        Ok(Some(clusters))
+    }
+
+    /// Like [`WordShaper::shape`], but reports each glyph the word shaped to,
+    /// in logical order by run and visual order within each run.
+    pub fn shape_placed<G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        word: &str,
+    ) -> Result<Option<Vec<PlacedGlyph>>, G::Error> {
+        let mut glyphs = Vec::new();
+        for (line_offset, line, index) in self.lines(word) {
+            let baseline = self.baseline(index);
+            self.segment(line);
+            for ScriptRun { script, range } in self.runs.clone() {
+                let start = line_offset + range.start;
+                let glyphs_found = self.shape_run_positions(
+                    shaper,
+                    shaping_meta,
+                    &line[range],
+                    script,
+                    |cluster, glyph| {
+                        glyphs.push(PlacedGlyph {
+                            glyph_id: glyph.glyph_id,
+                            cluster: start + cluster as usize,
+                            bounds: glyph_extremes
+                                .glyph_extremes(glyph.glyph_id)?,
+                            y_offset: *glyph.y_offset - baseline,
+                        });
+                        Ok(())
+                    },
+                )?;
+                if glyphs_found.is_none() {
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(glyphs))
+    }
+
+    /// Whether the fallback fonts support every character of the clusters
+    /// containing missing (`.notdef`) glyphs, so a renderer would draw them
+    /// with a fallback font.
+    fn fallback_supports(
+        &self,
+        text: &str,
+        glyph_infos: &[harfrust::GlyphInfo],
+    ) -> bool {
+        let Some(coverage) = &self.fallback_coverage else {
+            return false;
+        };
+        glyph_infos
+            .iter()
+            .filter(|info| info.glyph_id == 0)
+            .all(|missing| {
+                // Glyphs may be in visual order, so find where the cluster
+                // ends from the next cluster to start
+                let start = missing.cluster as usize;
+                let end = glyph_infos
+                    .iter()
+                    .map(|info| info.cluster as usize)
+                    .filter(|&cluster| cluster > start)
+                    .min()
+                    .unwrap_or(text.len());
+                text.get(start..end).is_some_and(|cluster| {
+                    cluster.chars().all(|c| coverage.contains(&u32::from(c)))
+                })
+            })
+    }
+
+    /// The script, language & direction each run of `words` is shaped with,
+    /// each listed once in the order first seen, without shaping them.
+    ///
+    /// `script` & `language` are the word list's, if it has them.
+    pub fn shaping_properties<'w>(
+        &mut self,
+        script: Option<Script>,
+        language: Option<&str>,
+        words: impl IntoIterator<Item = &'w str>,
+    ) -> Vec<ShapingProperties> {
+        let mut properties = Vec::<ShapingProperties>::new();
+        for word in words {
+            for (_, line, _) in self.lines(word) {
+                self.segment(line);
+                for ScriptRun {
+                    script: run_script,
+                    range,
+                } in self.runs.clone()
+                {
+                    // As in shape_run_positions
+                    let run_properties = match script {
+                        Some(script)
+                            if run_script.is_none_or(|run| run == script) =>
+                        {
+                            ShapingProperties {
+                                script: script.tag().to_string(),
+                                language: language.map(str::to_owned),
+                                direction: TextDirection::from_harfrust(
+                                    direction_from_script(script)
+                                        .unwrap_or(Direction::LeftToRight),
+                                ),
+                                guessed: false,
+                            }
+                        },
+                        _ => {
+                            let mut buffer =
+                                self.unicode_buffer.take().unwrap();
+                            buffer.push_str(&line[range]);
+                            guess_run_properties(&mut buffer, run_script);
+                            let run_properties = ShapingProperties {
+                                script: buffer.script().tag().to_string(),
+                                language: None,
+                                direction: TextDirection::from_harfrust(
+                                    buffer.direction(),
+                                ),
+                                guessed: true,
+                            };
+                            buffer.clear();
+                            self.unicode_buffer = Some(buffer);
+                            run_properties
+                        },
+                    };
+                    if !properties.contains(&run_properties) {
+                        properties.push(run_properties);
+                    }
+                }
+            }
+        }
+        properties
+    }
+
+    fn script_of(&mut self, c: char) -> Script {
+        self.script_buffer.clear();
+        self.script_buffer.add(c, 0);
+        self.script_buffer.guess_segment_properties();
+        self.script_buffer.script()
+    }
+
+    /// Split `word` into runs of a single script.
+    ///
+    /// Characters shared between scripts join the run before them, or the
+    /// first run if they start the word.
+    fn segment(&mut self, word: &str) {
+        self.runs.clear();
+        let mut current = None;
+        let mut start = 0;
+        for (index, c) in word.char_indices() {
+            let script = self.script_of(c);
+            if is_shared_script(script) {
+                continue;
+            }
+            match current {
+                Some(current_script) if current_script != script => {
+                    self.runs.push(ScriptRun {
+                        script: Some(current_script),
+                        range: start..index,
+                    });
+                    start = index;
+                    current = Some(script);
+                },
+                Some(_) => {},
+                None => current = Some(script),
+            }
+        }
+        self.runs.push(ScriptRun {
+            script: current,
+            range: start..word.len(),
+        });
+    }
+
+    fn shape_run<G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        text: &str,
+        script: Option<Script>,
+    ) -> Result<Option<VerticalExtremes>, G::Error> {
+        let mut extremes = None::<VerticalExtremes>;
+        let glyphs_found = self.shape_run_glyphs(
+            shaper,
+            shaping_meta,
+            glyph_extremes,
+            text,
+            script,
+            |_, glyph| {
+                extremes = Some(
+                    extremes.map_or(glyph, |extremes| extremes.merge(glyph)),
+                );
+            },
+        )?;
+        Ok(glyphs_found.map(|_| extremes.unwrap_or_default()))
+    }
+
+    /// Shape a run, passing the cluster & extremes of each glyph to
+    /// `on_glyph`.
+    ///
+    /// Returns how the run's glyphs were found, or `None` (without calling
+    /// `on_glyph`) if any glyphs are missing from the font, unless the fallback
+    /// fonts support the clusters they're in or
+    /// [partial words](WordShaper::with_partial_words) are measured. Missing
+    /// glyphs aren't passed to `on_glyph`.
+    fn shape_run_glyphs<G: GlyphExtremes>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        glyph_extremes: &G,
+        text: &str,
+        script: Option<Script>,
+        mut on_glyph: impl FnMut(u32, VerticalExtremes),
+    ) -> Result<Option<GlyphCounts>, G::Error> {
+        self.shape_run_positions(
+            shaper,
+            shaping_meta,
+            text,
+            script,
+            |cluster, glyph| {
+                on_glyph(cluster, glyph.measure(glyph_extremes)?);
+                Ok(())
+            },
+        )
+    }
+
+    /// Like [`WordShaper::shape_run_glyphs`], but passes each glyph to
+    /// `on_glyph` with its position rather than its extremes.
+    fn shape_run_positions<E>(
+        &mut self,
+        shaper: &Shaper,
+        shaping_meta: Option<&ShapingMeta>,
+        text: &str,
+        script: Option<Script>,
+        mut on_glyph: impl FnMut(u32, PositionedGlyph) -> Result<(), E>,
+    ) -> Result<Option<GlyphCounts>, E> {
+        // Take buffer; it should always be present
+        let mut buffer = self.unicode_buffer.take().unwrap();
+        buffer.push_str(text);
+
+        // Default features are still included by default
+        let glyph_buffer = match shaping_meta {
+            // Runs in the word list's script (or with no script of their own)
+            // use its shaping plan & language
+            Some(meta)
+                if script.is_none_or(|script| script == meta.script()) =>
+            {
+                shaper.shape_with_meta(meta, buffer, meta.features())
+            },
+            _ => {
+                guess_run_properties(&mut buffer, script);
+                shaper.shape(buffer, &[])
+            },
+        };
+
+        let glyph_infos = glyph_buffer.glyph_infos();
+        let glyphs_missing = glyph_infos.iter().any(|info| info.glyph_id == 0); // is .notdef
+
+        let fallback =
+            glyphs_missing && self.fallback_supports(text, glyph_infos);
+
+        let result = if glyphs_missing && !fallback && !self.partial_words {
+            Ok(None)
+        } else {
+            let mut counts = GlyphCounts {
+                used_fallback: fallback,
+                ..GlyphCounts::default()
+            };
+            if glyphs_missing && !fallback {
+                counts.skipped_glyphs = glyph_infos
+                    .iter()
+                    .filter(|info| info.glyph_id == 0)
+                    .count();
+            }
+            glyph_infos
+                .iter()
+                .zip(glyph_buffer.glyph_positions())
+                .filter(|(info, _)| info.glyph_id != 0)
+                .try_for_each(|(info, pos)| {
+                    counts.glyph_count += 1;
+                    // TODO: Remove empty glyphs?
+                    let y_offset =
+                        NotNan::new(pos.y_offset as f64).expect("NaN y offset");
+                    on_glyph(info.cluster, PositionedGlyph {
+                        glyph_id: info.glyph_id,
+                        y_offset,
+                    })
+                })
+                .map(|()| Some(counts))
+        };
+
+        // Return buffer
+        self.unicode_buffer = Some(if self.keep_glyph_buffers {
+            self.glyph_buffers.push(glyph_buffer);
+            self.spare_buffers.pop().unwrap_or_default()
+        } else {
+            glyph_buffer.clear()
+        });
+        result
+    }
+}
+
+/// Set up `buffer` to shape a run that isn't shaped with its word list's
+/// shaping plan, in the run's own script (if it has one).
+fn guess_run_properties(buffer: &mut UnicodeBuffer, script: Option<Script>) {
+    if let Some(script) = script {
+        buffer.set_script(script);
+        buffer.set_direction(
+            direction_from_script(script).unwrap_or(Direction::LeftToRight),
+        );
+    }
+    buffer.guess_segment_properties();
+}
+
+/// A glyph a word shaped to, and how far it was raised by shaping.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PositionedGlyph {
+    glyph_id: u32,
+    y_offset: NotNan<f64>,
+}
+
+impl PositionedGlyph {
+    fn measure<G: GlyphExtremes>(
+        self,
+        glyph_extremes: &G,
+    ) -> Result<VerticalExtremes, G::Error> {
+        let heights = glyph_extremes.glyph_extremes(self.glyph_id)?;
+        Ok(VerticalExtremes {
+            lowest: heights.lowest + self.y_offset,
+            highest: heights.highest + self.y_offset,
+        })
+    }
+}
+
+/// The glyphs a word shaped to, so the word can be measured at other
+/// locations without shaping it again.
+///
+/// Only valid at locations the font shapes the same way, see
+/// [`shaping_varies`](crate::shaping_cache::shaping_varies).
+#[derive(Debug)]
+pub(crate) enum ShapedGlyphs {
+    Glyphs {
+        // The glyphs of each of the word's non-blank lines, by line index
+        lines: Box<[(usize, Box<[PositionedGlyph]>)]>,
+        counts: GlyphCounts,
+    },
+    MissingGlyphs,
+}
+
+impl ShapedGlyphs {
+    /// How the glyphs were found, or no glyphs if some were missing.
+    const fn counts(&self) -> GlyphCounts {
+        match self {
+            ShapedGlyphs::Glyphs { counts, .. } => *counts,
+            ShapedGlyphs::MissingGlyphs => GlyphCounts {
+                used_fallback: false,
+                skipped_glyphs: 0,
+                glyph_count: 0,
+            },
+        }
+    }
+
+    /// Measure the glyphs with `glyph_extremes`, as [`WordShaper::shape`]
+    /// would.
+    fn measure<G: GlyphExtremes>(
+        &self,
+        glyph_extremes: &G,
+        line_height: Option<f64>,
+    ) -> Result<Shaped, G::Error> {
+        let ShapedGlyphs::Glyphs { lines, .. } = self else {
+            return Ok(Shaped::MissingGlyphs);
+        };
+        let mut block = None::<VerticalExtremes>;
+        for (index, glyphs) in lines {
+            let mut extremes = None::<VerticalExtremes>;
+            let mut has_ink = false;
+            for glyph in glyphs {
+                let glyph = glyph.measure(glyph_extremes)?;
+                // Glyphs without outlines are measured as a single point
+                has_ink |= glyph.lowest < glyph.highest;
+                extremes = Some(
+                    extremes.map_or(glyph, |extremes| extremes.merge(glyph)),
+                );
+            }
+            if let Some(extremes) = extremes.filter(|_| has_ink) {
+                let extremes = lowered(extremes, baseline(*index, line_height));
+                block =
+                    Some(block.map_or(extremes, |block| block.merge(extremes)));
+            }
+        }
+        Ok(block.map_or(Shaped::NoInk, Shaped::Extremes))
+    }
+}
+
+/// Shape a single `word`, finding the extremes it reaches.
+///
+/// This is the building block used by
+/// [`InstanceReporter`](crate::InstanceReporter), for driving your own loop
+/// over words (e.g. when fuzzing). The word is split into runs of a single
+/// script, each shaped with its guessed script & direction. `buffer` is re-used
+/// between calls to avoid allocating a new one for every word.
+///
+/// `instance_extremes` should have been created for the same font & location
+/// as `shaper`, otherwise the extremes will be wrong.
+///
+/// Returns `None` if glyphs needed to shape the word are missing from the
+/// font (or from `instance_extremes`), or the word shaped to nothing visible.
+#[must_use]
+pub fn measure_shaped_word(
+    shaper: &Shaper,
+    instance_extremes: &InstanceExtremes,
+    buffer: &mut UnicodeBuffer,
+    word: &str,
+) -> Option<VerticalExtremes> {
+    let mut word_shaper = WordShaper {
+        unicode_buffer: Some(mem::take(buffer)),
+        script_buffer: UnicodeBuffer::new(),
+        runs: Vec::new(),
+        line_height: None,
+        fallback_coverage: None,
+        partial_words: false,
+        keep_glyph_buffers: false,
+        glyph_buffers: Vec::new(),
+        spare_buffers: Vec::new(),
+    };
+    let shaped = word_shaper.shape(shaper, None, instance_extremes, word);
+    *buffer = word_shaper
+        .unicode_buffer
+        .take()
+        .expect("buffer is returned after shaping");
+    match shaped.map(|shaped| shaped.shaped) {
+        Ok(Shaped::Extremes(extremes)) => Some(extremes),
+        Ok(Shaped::MissingGlyphs | Shaped::NoInk) | Err(_) => None,
+    }
+}
+
+/// How far the baseline of the `index`th line of a word is below the first
+/// line's.
+fn baseline(index: usize, line_height: Option<f64>) -> f64 {
+    index as f64 * line_height.unwrap_or_default()
+}
+
+/// Move `extremes` down by `distance` font units.
+fn lowered(extremes: VerticalExtremes, distance: f64) -> VerticalExtremes {
+    VerticalExtremes::new(
+        extremes.lowest() - distance,
+        extremes.highest() - distance,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn segments(word: &str) -> Vec<(Option<String>, &str)> {
+        let mut word_shaper = WordShaper::new(None);
+        word_shaper.segment(word);
+        word_shaper
+            .runs
+            .iter()
+            .map(|run| {
+                (
+                    run.script.map(|script| script.tag().to_string()),
+                    &word[run.range.clone()],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn segmentation() {
+        assert_eq!(segments("hello"), [(Some("Latn".to_owned()), "hello")]);
+        assert_eq!(segments("123"), [(None, "123")]);
+        assert_eq!(segments(""), [(None, "")]);
+        assert_eq!(segments("(مرحبا Google!)"), [
+            (Some("Arab".to_owned()), "(مرحبا "),
+            (Some("Latn".to_owned()), "Google!)"),
+        ],);
+    }
+
+    #[test]
+    fn multi_line_words() {
+        let word_shaper = WordShaper::new(Some(1000.0));
+        assert_eq!(word_shaper.lines("ab\r\n\ncd"), [
+            (0, "ab", 0),
+            (5, "cd", 2),
+        ]);
+        assert_eq!(word_shaper.baseline(2), 2000.0);
+        assert_eq!(WordShaper::new(None).lines("ab\ncd"), [(0, "ab\ncd", 0)]);
+    }
+
+    #[test]
+    fn shaping_properties() {
+        let mut word_shaper = WordShaper::new(None);
+        let labels = |properties: Vec<ShapingProperties>| {
+            properties
+                .into_iter()
+                .map(|properties| {
+                    (
+                        properties.script,
+                        properties.direction,
+                        properties.guessed,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let arabic = Script::from_str("Arab").ok();
+        assert_eq!(
+            labels(word_shaper.shaping_properties(arabic, Some("ar"), [
+                "مرحبا",
+                "(مرحبا Google!)",
+            ])),
+            [
+                ("Arab".to_owned(), TextDirection::RightToLeft, false),
+                ("Latn".to_owned(), TextDirection::LeftToRight, true),
+            ],
+        );
+        let properties = word_shaper.shaping_properties(None, None, ["שלום"]);
+        assert_eq!(properties, [ShapingProperties {
+            script: "Hebr".to_owned(),
+            language: None,
+            direction: TextDirection::RightToLeft,
+            guessed: true,
+        }]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Re-using the glyphs words shaped to across locations.
+//!
+//! Shaping a word gives the same glyphs at every location unless `GSUB`/`GPOS`
+//! feature variations substitute different lookups, and the same vertical
+//! offsets unless `GPOS` values vary (through `GDEF`'s item variation store).
+//! For fonts where neither happens, each word only needs shaping once, and
+//! measuring it at another location just needs that location's glyph bounds.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, RandomState},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use skrifa::{
+    FontRef, Tag,
+    raw::{ReadError, TableProvider},
+};
+
+use crate::{shaping::ShapedGlyphs, word_source::WordSource};
+
+// Words are looked up from every thread checking a word list, so the words are
+// split between locks to avoid contention
+const SHARDS: usize = 64;
+
+/// Whether shaping may give different glyphs or positions at different
+/// locations in `font`, so shaping results can't be re-used between them.
+///
+/// Errs on the side of caution: fonts without `GPOS` vary too, as harfrust
+/// positions marks by their (varying) glyph bounds, as do fonts shaped with
+/// AAT tables.
+pub(crate) fn shaping_varies(font: &FontRef) -> bool {
+    let gsub_varies = match font.gsub() {
+        Ok(gsub) => gsub.feature_variations().is_some(),
+        Err(ReadError::TableIsMissing(_)) => false,
+        Err(_) => true,
+    };
+    let gpos_varies = match font.gpos() {
+        Ok(gpos) => gpos.feature_variations().is_some(),
+        Err(_) => true,
+    };
+    let gdef_varies = match font.gdef() {
+        Ok(gdef) => gdef.item_var_store().is_some(),
+        Err(ReadError::TableIsMissing(_)) => false,
+        Err(_) => true,
+    };
+    let uses_aat = [b"morx", b"mort", b"kerx"]
+        .into_iter()
+        .any(|tag| font.table_data(Tag::new(tag)).is_some());
+    gsub_varies || gpos_varies || gdef_varies || uses_aat
+}
+
+/// The glyphs words shaped to, for each way word lists are shaped.
+///
+/// See [`Reporter::with_shaping_cache`](crate::Reporter::with_shaping_cache).
+#[derive(Debug, Default)]
+pub(crate) struct ShapingCache {
+    word_lists: Mutex<HashMap<CacheKey, Arc<WordListCache>>>,
+}
+
+/// What affects how a word list's words are shaped: its script, language &
+/// features (which decide the shaping plan), whether words are split into
+/// lines, and whether words with missing glyphs are shaped.
+type CacheKey = (Option<String>, Option<String>, Vec<String>, bool, bool);
+
+impl ShapingCache {
+    /// Get the cache for words shaped like `word_list`'s, with `features` on
+    /// top of its own.
+    pub(crate) fn for_word_list(
+        &self,
+        word_list: &(impl WordSource + ?Sized),
+        features: &[String],
+        multi_line: bool,
+        partial_words: bool,
+    ) -> Arc<WordListCache> {
+        let key = (
+            word_list.script().map(str::to_owned),
+            word_list.language().map(str::to_owned),
+            word_list
+                .features()
+                .map(str::to_owned)
+                .chain(features.iter().cloned())
+                .collect(),
+            multi_line,
+            partial_words,
+        );
+        let mut word_lists = self
+            .word_lists
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(word_lists.entry(key).or_default())
+    }
+}
+
+/// The glyphs words shaped to, for word lists of one script & language.
+#[derive(Debug)]
+pub(crate) struct WordListCache {
+    hasher: RandomState,
+    shards: Box<[Mutex<Shard>]>,
+}
+
+type Shard = HashMap<Box<str>, Arc<ShapedGlyphs>>;
+
+impl Default for WordListCache {
+    fn default() -> Self {
+        WordListCache {
+            hasher: RandomState::new(),
+            shards: (0..SHARDS).map(|_| Mutex::default()).collect(),
+        }
+    }
+}
+
+impl WordListCache {
+    /// Get the glyphs `word` shaped to, with `shape` if it hasn't been seen
+    /// before.
+    pub(crate) fn get_or_shape(
+        &self,
+        word: &str,
+        shape: impl FnOnce() -> ShapedGlyphs,
+    ) -> Arc<ShapedGlyphs> {
+        let shard = &self.shards
+            [self.hasher.hash_one(word) as usize % self.shards.len()];
+        let lock = || shard.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(glyphs) = lock().get(word) {
+            return Arc::clone(glyphs);
+        }
+        // Shaped without holding the lock, so other threads can carry on. If
+        // another thread shapes the same word meanwhile, their glyphs are the
+        // same
+        let glyphs = Arc::new(shape());
+        lock().insert(Box::from(word), Arc::clone(&glyphs));
+        glyphs
+    }
+}
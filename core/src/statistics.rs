@@ -0,0 +1,87 @@
+/// Counts of the words checked to make a [`Report`](crate::Report).
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct ReportStatistics {
+    /// The number of words shaped, including those that were skipped.
+    ///
+    /// Entries broken up by their [`Segmentation`](crate::Segmentation) count
+    /// once for each piece shaped.
+    pub words_checked: usize,
+    /// The number of words skipped as glyphs needed to shape them are missing
+    /// from the font.
+    pub missing_glyphs: usize,
+    /// The number of words skipped as they shaped to nothing visible, e.g.
+    /// words made up of spaces or control characters.
+    ///
+    /// These would otherwise be reported with extremes of 0, polluting the
+    /// lowest exemplars.
+    pub anomalies: usize,
+    /// The number of words measured despite having characters missing from
+    /// the font, as the [fallback fonts](crate::Reporter::with_fallback_fonts)
+    /// support them.
+    ///
+    /// The glyphs of the fallback fonts aren't included in the extremes.
+    pub fallback: usize,
+    /// The number of words measured despite having glyphs missing from the
+    /// font, as [partial words](crate::AnalysisOptions::with_partial_words)
+    /// are measured.
+    ///
+    /// The missing glyphs aren't included in the extremes.
+    pub partial: usize,
+    /// The number of words left out of the check by its
+    /// [exclusions](crate::AnalysisOptions::with_exclusion), without being
+    /// shaped.
+    pub excluded: usize,
+    /// The number of words longer than the
+    /// [word length limit](crate::AnalysisOptions::with_word_length_limit),
+    /// whether they were skipped, truncated or chunked.
+    ///
+    /// Skipped words aren't shaped, so don't count as words checked. Each
+    /// piece of a chunked word does.
+    pub too_long: usize,
+    /// The number of words measured but kept out of the exemplars by the
+    /// [exemplar filter](crate::AnalysisOptions::with_exemplar_filter), e.g.
+    /// as they're made up only of dashes or dots.
+    pub filtered: usize,
+    /// The number of words measured reaching beyond the check's
+    /// [thresholds](crate::AnalysisOptions::with_thresholds), or 0 if it had
+    /// none.
+    pub exceeding: usize,
+    /// Whether the check was [cancelled](crate::CancellationToken) before
+    /// every word was checked, so the report only covers some of them.
+    pub truncated: bool,
+}
+
+impl ReportStatistics {
+    pub(crate) const EMPTY: Self = ReportStatistics {
+        words_checked: 0,
+        missing_glyphs: 0,
+        anomalies: 0,
+        fallback: 0,
+        partial: 0,
+        excluded: 0,
+        too_long: 0,
+        filtered: 0,
+        exceeding: 0,
+        truncated: false,
+    };
+
+    /// Combine the statistics of two checks, e.g. of different parts of a
+    /// word list.
+    #[inline]
+    #[must_use]
+    pub const fn merge(self, other: Self) -> Self {
+        ReportStatistics {
+            words_checked: self.words_checked + other.words_checked,
+            missing_glyphs: self.missing_glyphs + other.missing_glyphs,
+            anomalies: self.anomalies + other.anomalies,
+            fallback: self.fallback + other.fallback,
+            partial: self.partial + other.partial,
+            excluded: self.excluded + other.excluded,
+            too_long: self.too_long + other.too_long,
+            filtered: self.filtered + other.filtered,
+            exceeding: self.exceeding + other.exceeding,
+            truncated: self.truncated || other.truncated,
+        }
+    }
+}
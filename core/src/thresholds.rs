@@ -0,0 +1,187 @@
+use std::iter::FusedIterator;
+
+use ordered_float::NotNan;
+
+use crate::{VerticalExtremes, WordExtremes};
+
+/// Vertical limits to count the words reaching beyond, see
+/// [`AnalysisOptions::with_thresholds`](crate::AnalysisOptions::with_thresholds).
+///
+/// ```
+/// # use fontheight::{Thresholds, VerticalExtremes};
+/// let thresholds = Thresholds::new(-250.0, 950.0);
+/// assert!(thresholds.exceeded_by(VerticalExtremes::new(-300.0, 700.0)));
+/// // Reaching exactly the limits is within them
+/// assert!(!thresholds.exceeded_by(VerticalExtremes::new(-250.0, 950.0)));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Thresholds {
+    lowest: NotNan<f64>,
+    highest: NotNan<f64>,
+}
+
+impl Thresholds {
+    /// Limits of `lowest` & `highest`, in font units. Use infinities to only
+    /// limit one side.
+    ///
+    /// # Panics
+    ///
+    /// If either limit is `NaN`.
+    #[must_use]
+    pub fn new(lowest: f64, highest: f64) -> Self {
+        Thresholds {
+            lowest: NotNan::new(lowest).expect("lowest was NaN"),
+            highest: NotNan::new(highest).expect("highest was NaN"),
+        }
+    }
+
+    /// The lowest words may reach, in font units.
+    #[inline]
+    #[must_use]
+    pub fn lowest(&self) -> f64 {
+        *self.lowest
+    }
+
+    /// The highest words may reach, in font units.
+    #[inline]
+    #[must_use]
+    pub fn highest(&self) -> f64 {
+        *self.highest
+    }
+
+    /// Whether a word reaching `extremes` reaches above the highest limit or
+    /// below the lowest, as [`ThresholdIteratorExt::exceeding`] finds.
+    #[inline]
+    #[must_use]
+    pub fn exceeded_by(&self, extremes: VerticalExtremes) -> bool {
+        extremes.highest() > self.highest() || extremes.lowest() < self.lowest()
+    }
+}
+
+/// Iterator adapters to find words reaching beyond vertical limits, e.g. the
+/// font's clipping metrics.
+///
+/// Implemented for every iterator of [`WordExtremes`], such as
+/// [`WordExtremesIterator`](crate::WordExtremesIterator). As words are shaped
+/// lazily, stopping early (e.g. with
+/// [`first_violation`](Self::first_violation)) skips shaping the rest of the
+/// word list, for quick pass/fail checks.
+pub trait ThresholdIteratorExt<'a>:
+    Iterator<Item = WordExtremes<'a>> + Sized + private::Sealed
+{
+    /// Only yield words reaching above `highest` or below `lowest`, in font
+    /// units.
+    ///
+    /// Words reaching exactly `highest` or `lowest` are within the limits.
+    fn exceeding(self, highest: f64, lowest: f64) -> Exceeding<Self> {
+        Exceeding {
+            iter: self,
+            highest,
+            lowest,
+        }
+    }
+
+    /// Find the first word reaching above `highest` or below `lowest`, in font
+    /// units, without checking any further words.
+    ///
+    /// Returns `None` if every word is within the limits.
+    fn first_violation(
+        self,
+        highest: f64,
+        lowest: f64,
+    ) -> Option<WordExtremes<'a>> {
+        self.exceeding(highest, lowest).next()
+    }
+}
+
+impl<'a, I> ThresholdIteratorExt<'a> for I where
+    I: Iterator<Item = WordExtremes<'a>>
+{
+}
+
+/// An iterator of the words reaching beyond vertical limits.
+///
+/// Returned by [`ThresholdIteratorExt::exceeding`].
+#[derive(Debug, Clone)]
+pub struct Exceeding<I> {
+    iter: I,
+    highest: f64,
+    lowest: f64,
+}
+
+impl<'a, I> Iterator for Exceeding<I>
+where
+    I: Iterator<Item = WordExtremes<'a>>,
+{
+    type Item = WordExtremes<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (highest, lowest) = (self.highest, self.lowest);
+        self.iter.find(|word_extremes| {
+            word_extremes.extremes.highest() > highest
+                || word_extremes.extremes.lowest() < lowest
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, I> FusedIterator for Exceeding<I> where
+    I: FusedIterator<Item = WordExtremes<'a>>
+{
+}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {}
+
+    impl<'a, I> Sealed for I where I: Iterator<Item = WordExtremes<'a>> {}
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::VerticalExtremes;
+
+    fn word_extremes(
+        word: &str,
+        lowest: f64,
+        highest: f64,
+    ) -> WordExtremes<'_> {
+        WordExtremes {
+            word,
+            index: 0,
+            skipped_glyphs: 0,
+            punctuation: None,
+            extremes: VerticalExtremes::new(lowest, highest),
+        }
+    }
+
+    #[test]
+    fn exceeding_thresholds() {
+        let words = [
+            word_extremes("within", -200.0, 700.0),
+            word_extremes("limits", -250.0, 800.0),
+            word_extremes("tall", -100.0, 900.0),
+            word_extremes("deep", -300.0, 500.0),
+        ];
+        let exceeding = words
+            .into_iter()
+            .exceeding(800.0, -250.0)
+            .map(|word_extremes| word_extremes.word)
+            .collect::<Vec<_>>();
+        assert_eq!(exceeding, ["tall", "deep"]);
+
+        assert_eq!(
+            words
+                .into_iter()
+                .first_violation(800.0, -250.0)
+                .map(|word_extremes| word_extremes.word),
+            Some("tall"),
+        );
+        assert_eq!(words.into_iter().first_violation(1000.0, -1000.0), None);
+    }
+}
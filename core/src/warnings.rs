@@ -0,0 +1,165 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use harfrust::{Script, UnicodeBuffer};
+use skrifa::{FontRef, MetadataProvider};
+
+use crate::{WordList, is_shared_script};
+
+/// The number of words from the start of a word list to detect its script
+/// from.
+pub(crate) const SCRIPT_SAMPLE_SIZE: usize = 100;
+
+/// A problem found while making a [`Report`](crate::Report) that may make its
+/// results misleading, without stopping the check.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ReportWarning {
+    /// The script declared in the [`WordList`]'s metadata isn't the script
+    /// most of its words are written in.
+    ///
+    /// Words are shaped with the declared script, so mistakes in word list
+    /// metadata would otherwise go unnoticed while shaping them wrongly.
+    ScriptMismatch {
+        /// The script declared in the word list's metadata.
+        declared: String,
+        /// The script most characters of the sampled words are written in.
+        detected: String,
+    },
+    /// The font is variable but has no named instances, so
+    /// [`Reporter::interesting_locations`](crate::Reporter::interesting_locations)
+    /// only has its default location, axis extremes & feature variation
+    /// regions.
+    ///
+    /// Designs between them may reach further, so consider checking
+    /// [master](crate::Reporter::master_locations) or
+    /// [random](crate::Reporter::location_from_normalized) locations too.
+    NoNamedInstances,
+}
+
+impl fmt::Display for ReportWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportWarning::ScriptMismatch { declared, detected } => write!(
+                f,
+                "word list is declared as {declared}, but its words are \
+                 mostly {detected}",
+            ),
+            ReportWarning::NoNamedInstances => f.write_str(
+                "font is variable but has no named instances, so only its \
+                 default location & axis extremes are checked",
+            ),
+        }
+    }
+}
+
+/// Problems with the font that apply to every report made for it.
+pub(crate) fn font_warnings(font: &FontRef) -> Vec<ReportWarning> {
+    let mut warnings = Vec::new();
+    if !font.axes().is_empty() && font.named_instances().is_empty() {
+        warnings.push(ReportWarning::NoNamedInstances);
+    }
+    warnings
+}
+
+/// Check the [`WordList`]'s metadata against its words.
+///
+/// Only the first [`SCRIPT_SAMPLE_SIZE`] words are checked, so this is cheap
+/// enough to do for every report.
+pub(crate) fn word_list_warnings(word_list: &WordList) -> Vec<ReportWarning> {
+    source_warnings(word_list.script(), word_list.iter())
+}
+
+/// Check the script declared for a corpus against its words.
+///
+/// Like [`word_list_warnings`], only the first [`SCRIPT_SAMPLE_SIZE`] words
+/// are taken from `words`.
+pub(crate) fn source_warnings<'a>(
+    declared: Option<&str>,
+    words: impl Iterator<Item = &'a str>,
+) -> Vec<ReportWarning> {
+    declared
+        .and_then(|declared| {
+            script_mismatch(declared, words.take(SCRIPT_SAMPLE_SIZE))
+        })
+        .into_iter()
+        .collect()
+}
+
+fn script_mismatch<'a>(
+    declared: &str,
+    words: impl Iterator<Item = &'a str>,
+) -> Option<ReportWarning> {
+    let declared_script = Script::from_str(declared).ok()?;
+    if is_shared_script(declared_script) {
+        return None;
+    }
+    let detected = detect_script(words)?;
+    let covered = match &declared_script.tag().to_be_bytes() {
+        // Japanese & Korean are written in several scripts
+        b"Jpan" => &[b"Hani", b"Hira", b"Kana"][..],
+        b"Kore" => &[b"Hang", b"Hani"][..],
+        _ => &[],
+    };
+    let detected_tag = detected.tag().to_be_bytes();
+    (detected != declared_script && !covered.contains(&&detected_tag)).then(
+        || ReportWarning::ScriptMismatch {
+            declared: declared.to_owned(),
+            detected: detected.tag().to_string(),
+        },
+    )
+}
+
+/// The script most characters of the words are written in, ignoring
+/// characters shared between scripts (e.g. digits & punctuation).
+fn detect_script<'a>(words: impl Iterator<Item = &'a str>) -> Option<Script> {
+    let mut buffer = UnicodeBuffer::new();
+    let mut counts = HashMap::<Script, usize>::new();
+    words.flat_map(str::chars).for_each(|c| {
+        buffer.clear();
+        buffer.add(c, 0);
+        buffer.guess_segment_properties();
+        let script = buffer.script();
+        if !is_shared_script(script) {
+            *counts.entry(script).or_default() += 1;
+        }
+    });
+    counts
+        .into_iter()
+        .max_by_key(|&(script, count)| (count, script.tag()))
+        .map(|(script, _)| script)
+}
+
+#[cfg(test)]
+mod tests {
+    use static_lang_word_lists::ALL_WORD_LISTS;
+
+    use super::*;
+
+    #[test]
+    fn built_in_word_lists_match_their_scripts() {
+        ALL_WORD_LISTS.iter().for_each(|word_list| {
+            assert_eq!(
+                word_list_warnings(word_list),
+                [],
+                "{}",
+                word_list.id(),
+            );
+        });
+    }
+
+    #[test]
+    fn detects_script_mismatch() {
+        assert_eq!(
+            script_mismatch("Latn", ["Ελληνικά", "λέξεις", "and"].into_iter()),
+            Some(ReportWarning::ScriptMismatch {
+                declared: String::from("Latn"),
+                detected: String::from("Grek"),
+            }),
+        );
+        assert_eq!(script_mismatch("Grek", ["Ελληνικά"].into_iter()), None);
+        assert_eq!(
+            script_mismatch("Jpan", ["ひらがな", "漢字"].into_iter()),
+            None
+        );
+    }
+}
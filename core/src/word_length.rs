@@ -0,0 +1,273 @@
+//! Limiting the length of the words checked, so pathological word list
+//! entries don't slow checks down.
+
+/// A limit on how long the words checked may be, see
+/// [`AnalysisOptions::with_word_length_limit`](crate::AnalysisOptions::with_word_length_limit).
+///
+/// Extremely long entries (e.g. URLs or strings run together in scraped
+/// corpora) are slow to shape, and rarely reach further than the words they're
+/// made of. Words longer than the limit are skipped by default, or can be
+/// [truncated](LongWords::Truncate) or [chunked](LongWords::Chunk) instead.
+///
+/// ```
+/// # use fontheight::{LengthUnit, LongWords, WordLengthLimit};
+/// let limit = WordLengthLimit::new(4).with_long_words(LongWords::Chunk);
+/// assert_eq!(limit.apply("abcdefghij"), ["abcd", "efgh", "ij"]);
+/// assert!(WordLengthLimit::new(4).apply("abcdefghij").is_empty());
+///
+/// // e + combining acute accent
+/// let clusters = WordLengthLimit::new(2)
+///     .with_unit(LengthUnit::Clusters)
+///     .with_long_words(LongWords::Truncate);
+/// assert_eq!(clusters.apply("e\u{301}e\u{301}e\u{301}"), [
+///     "e\u{301}e\u{301}"
+/// ]);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct WordLengthLimit {
+    max: usize,
+    unit: LengthUnit,
+    long_words: LongWords,
+}
+
+/// What a [`WordLengthLimit`] counts the length of words in.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum LengthUnit {
+    /// Unicode scalar values (Rust `char`s).
+    #[default]
+    Chars,
+    /// Grapheme clusters, approximated as a character followed by any
+    /// combining marks, variation selectors & joining controls, and
+    /// characters joined to it by a zero width joiner.
+    ///
+    /// The marks of scripts in common use are recognised, but this isn't a
+    /// full implementation of
+    /// [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries).
+    Clusters,
+}
+
+/// What happens to words longer than a [`WordLengthLimit`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum LongWords {
+    /// Leave them out of the check, without shaping them.
+    #[default]
+    Skip,
+    /// Only check their beginning, up to the limit.
+    Truncate,
+    /// Break them into pieces no longer than the limit and check each piece,
+    /// as if they were separate words.
+    Chunk,
+}
+
+impl WordLengthLimit {
+    /// Limit words to `max` characters, skipping those longer.
+    ///
+    /// A limit of 0 is treated as 1.
+    #[inline]
+    #[must_use]
+    pub const fn new(max: usize) -> Self {
+        WordLengthLimit {
+            max: if max == 0 { 1 } else { max },
+            unit: LengthUnit::Chars,
+            long_words: LongWords::Skip,
+        }
+    }
+
+    /// Count the length of words in `unit`, rather than characters.
+    #[inline]
+    #[must_use]
+    pub const fn with_unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Handle words longer than the limit with `long_words`, rather than
+    /// skipping them.
+    #[inline]
+    #[must_use]
+    pub const fn with_long_words(mut self, long_words: LongWords) -> Self {
+        self.long_words = long_words;
+        self
+    }
+
+    /// The longest a word may be.
+    #[inline]
+    #[must_use]
+    pub const fn max(&self) -> usize {
+        self.max
+    }
+
+    /// What the length of words is counted in.
+    #[inline]
+    #[must_use]
+    pub const fn unit(&self) -> LengthUnit {
+        self.unit
+    }
+
+    /// What happens to words longer than the limit.
+    #[inline]
+    #[must_use]
+    pub const fn long_words(&self) -> LongWords {
+        self.long_words
+    }
+
+    /// The length of `word`, in the limit's [unit](WordLengthLimit::unit).
+    #[must_use]
+    pub fn length(&self, word: &str) -> usize {
+        self.starts(word).count()
+    }
+
+    /// The text to check for `word`, as slices of it: `word` itself if it's
+    /// within the limit, otherwise nothing, its beginning, or its pieces
+    /// depending on [`long_words`](WordLengthLimit::long_words).
+    #[must_use]
+    pub fn apply<'w>(&self, word: &'w str) -> Vec<&'w str> {
+        // The byte offset each piece starts at
+        let mut cuts = self.starts(word).step_by(self.max);
+        cuts.next();
+        let Some(first_cut) = cuts.next() else {
+            return vec![word];
+        };
+        match self.long_words {
+            LongWords::Skip => Vec::new(),
+            LongWords::Truncate => vec![&word[..first_cut]],
+            LongWords::Chunk => {
+                let mut pieces = Vec::new();
+                let mut start = 0;
+                for cut in [first_cut].into_iter().chain(cuts) {
+                    pieces.push(&word[start..cut]);
+                    start = cut;
+                }
+                pieces.push(&word[start..]);
+                pieces
+            },
+        }
+    }
+
+    /// The byte offsets of the start of each unit of `word`.
+    fn starts<'w>(&self, word: &'w str) -> impl Iterator<Item = usize> + 'w {
+        let unit = self.unit;
+        let mut previous = None;
+        word.char_indices().filter_map(move |(index, c)| {
+            let starts = match unit {
+                LengthUnit::Chars => true,
+                LengthUnit::Clusters => {
+                    previous.is_none()
+                        || !(is_extending(c) || previous == Some(ZWJ))
+                },
+            };
+            previous = Some(c);
+            starts.then_some(index)
+        })
+    }
+}
+
+const ZWJ: char = '\u{200D}';
+
+/// Whether `c` continues the grapheme cluster before it: combining marks of
+/// common scripts, variation selectors, emoji modifiers & joining controls.
+const fn is_extending(c: char) -> bool {
+    match c {
+        // Brahmi-derived scripts of India (but Sinhala) share a layout, with
+        // their vowel signs, viramas & nuktas at the same offsets in each
+        // block
+        '\u{0900}'..='\u{0D7F}' => matches!(
+            c as u32 & 0x7F,
+            0x00..=0x03 | 0x3A..=0x3C | 0x3E..=0x4F | 0x51..=0x57 | 0x62..=0x63
+        ),
+        _ => matches!(
+            c,
+            // Combining Diacritical Marks (and their extensions & supplement)
+            '\u{0300}'..='\u{036F}'
+                | '\u{1AB0}'..='\u{1AFF}'
+                | '\u{1DC0}'..='\u{1DFF}'
+                | '\u{20D0}'..='\u{20FF}'
+                | '\u{FE20}'..='\u{FE2F}'
+                // Cyrillic
+                | '\u{0483}'..='\u{0489}'
+                // Hebrew
+                | '\u{0591}'..='\u{05BD}'
+                | '\u{05BF}'
+                | '\u{05C1}'..='\u{05C2}'
+                | '\u{05C4}'..='\u{05C5}'
+                | '\u{05C7}'
+                // Arabic
+                | '\u{0610}'..='\u{061A}'
+                | '\u{064B}'..='\u{065F}'
+                | '\u{0670}'
+                | '\u{06D6}'..='\u{06DC}'
+                | '\u{06DF}'..='\u{06E4}'
+                | '\u{06E7}'..='\u{06E8}'
+                | '\u{06EA}'..='\u{06ED}'
+                | '\u{08D3}'..='\u{08E1}'
+                | '\u{08E3}'..='\u{08FF}'
+                // Sinhala
+                | '\u{0D81}'..='\u{0D83}'
+                | '\u{0DCA}'
+                | '\u{0DCF}'..='\u{0DDF}'
+                | '\u{0DF2}'..='\u{0DF3}'
+                // Thai & Lao
+                | '\u{0E31}'
+                | '\u{0E34}'..='\u{0E3A}'
+                | '\u{0E47}'..='\u{0E4E}'
+                | '\u{0EB1}'
+                | '\u{0EB4}'..='\u{0EBC}'
+                | '\u{0EC8}'..='\u{0ECE}'
+                // Tibetan
+                | '\u{0F71}'..='\u{0F84}'
+                | '\u{0F8D}'..='\u{0FBC}'
+                // Myanmar
+                | '\u{102B}'..='\u{103E}'
+                // Khmer
+                | '\u{17B4}'..='\u{17D3}'
+                // Hangul jamo vowels & finals, which join the syllable before
+                | '\u{1160}'..='\u{11FF}'
+                // Japanese (semi-)voiced sound marks
+                | '\u{3099}'..='\u{309A}'
+                // Joining controls
+                | '\u{200C}'..='\u{200D}'
+                // Variation selectors & emoji modifiers
+                | '\u{FE00}'..='\u{FE0F}'
+                | '\u{E0100}'..='\u{E01EF}'
+                | '\u{1F3FB}'..='\u{1F3FF}'
+                // Emoji tags
+                | '\u{E0020}'..='\u{E007F}'
+        ),
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn words_within_the_limit_are_kept() {
+        let limit = WordLengthLimit::new(5);
+        assert_eq!(limit.apply("hello"), ["hello"]);
+        assert_eq!(limit.apply(""), [""]);
+        assert_eq!(limit.apply("hello!"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn counts_clusters() {
+        let limit = WordLengthLimit::new(8).with_unit(LengthUnit::Clusters);
+        // क्षि: ka, virama, ssa, vowel sign i
+        assert_eq!(limit.length("\u{0915}\u{094D}\u{0937}\u{093F}"), 2);
+        // Waving hand with a skin tone, and a family joined by ZWJs
+        assert_eq!(limit.length("\u{1F44B}\u{1F3FD}"), 1);
+        assert_eq!(
+            limit.length("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            1
+        );
+        // Arabic with harakat
+        assert_eq!(limit.length("\u{0643}\u{064E}\u{062A}\u{064E}\u{0628}"), 3);
+    }
+
+    #[test]
+    fn chunks_by_clusters() {
+        let limit = WordLengthLimit::new(1)
+            .with_unit(LengthUnit::Clusters)
+            .with_long_words(LongWords::Chunk);
+        assert_eq!(limit.apply("a\u{301}b\u{301}"), ["a\u{301}", "b\u{301}"]);
+    }
+}
@@ -0,0 +1,321 @@
+//! Corpora to check other than in-memory [`WordList`]s, such as words
+//! streamed from a file or generated on the fly.
+//!
+//! Check a [`WordSource`] with
+//! [`InstanceReporter::check_source`](crate::InstanceReporter::check_source).
+
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    iter,
+    path::PathBuf,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::iter::IndexedParallelIterator;
+
+use crate::WordList;
+
+/// A corpus of words to check, with metadata describing them.
+///
+/// Implemented by [`WordList`], [`WordFile`] (for words streamed from disk)
+/// and [`GeneratedWords`] (for words generated by a closure, e.g. a fuzzer or
+/// permutator). Implement it to plug in any other kind of corpus.
+pub trait WordSource: Sync {
+    /// The name of the corpus.
+    fn name(&self) -> &str;
+
+    /// Where the corpus came from, if known.
+    fn source(&self) -> Option<&str> {
+        None
+    }
+
+    /// The ISO 15924 script the words are written in, if known.
+    fn script(&self) -> Option<&str> {
+        None
+    }
+
+    /// The language of the words, if known.
+    fn language(&self) -> Option<&str> {
+        None
+    }
+
+    /// The OpenType features to shape the words with on top of the defaults,
+    /// in [`harfrust`]'s syntax (e.g. `cswh`, `-liga` or `ss01=1`).
+    ///
+    /// Only used if the script is known, as they're part of its shaping plan.
+    fn features(&self) -> impl Iterator<Item = &str> {
+        iter::empty()
+    }
+
+    /// Iterate through the words in order, stopping at the first error.
+    ///
+    /// Called each time the corpus is checked (e.g. once per location), so it
+    /// should produce the same words each time.
+    fn iter(&self) -> impl Iterator<Item = io::Result<Cow<'_, str>>>;
+
+    /// Iterate through the words in parallel, if they're all held in memory.
+    ///
+    /// Sources that can't (the default) are read through
+    /// [`iter`](Self::iter) in batches, with each batch shaped in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    fn par_iter(&self) -> Option<impl IndexedParallelIterator<Item = &str>> {
+        None::<rayon::iter::Empty<&str>>
+    }
+
+    /// How many words there are, if known without iterating through them.
+    fn len_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl WordSource for WordList {
+    fn name(&self) -> &str {
+        self.name()
+    }
+
+    fn source(&self) -> Option<&str> {
+        self.source()
+    }
+
+    fn script(&self) -> Option<&str> {
+        self.script()
+    }
+
+    fn language(&self) -> Option<&str> {
+        self.language()
+    }
+
+    fn features(&self) -> impl Iterator<Item = &str> {
+        self.features()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = io::Result<Cow<'_, str>>> {
+        self.iter().map(|word| Ok(Cow::Borrowed(word)))
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_iter(&self) -> Option<impl IndexedParallelIterator<Item = &str>> {
+        Some(self.par_iter())
+    }
+
+    fn len_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Words streamed from a file, separated by whitespace, without reading the
+/// whole file into memory.
+///
+/// The file is re-read each time it's checked, so prefer [`WordList::load`]
+/// for files small enough to hold in memory.
+#[derive(Debug, Clone)]
+pub struct WordFile {
+    path: PathBuf,
+    name: String,
+    script: Option<String>,
+    language: Option<String>,
+    features: Vec<String>,
+}
+
+impl WordFile {
+    /// Stream the words of the file at `path`, named after the file.
+    ///
+    /// The file isn't opened until it's checked.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let name = path
+            .file_stem()
+            .unwrap_or(path.as_os_str())
+            .to_string_lossy()
+            .replace("/", "_");
+        WordFile {
+            path,
+            name,
+            script: None,
+            language: None,
+            features: Vec::new(),
+        }
+    }
+
+    /// Set the ISO 15924 script the words are written in.
+    #[must_use]
+    pub fn with_script(mut self, script: impl Into<String>) -> Self {
+        self.script = Some(script.into());
+        self
+    }
+
+    /// Set the language of the words.
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Set the OpenType features to shape the words with, on top of the
+    /// defaults (see [`WordSource::features`]).
+    #[must_use]
+    pub fn with_features(
+        mut self,
+        features: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.features = features.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl WordSource for WordFile {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn source(&self) -> Option<&str> {
+        self.path.to_str()
+    }
+
+    fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    fn features(&self) -> impl Iterator<Item = &str> {
+        self.features.iter().map(String::as_str)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = io::Result<Cow<'_, str>>> {
+        let mut lines = match File::open(&self.path) {
+            Ok(file) => Ok(BufReader::new(file).lines()),
+            Err(error) => Err(Some(error)),
+        };
+        let mut words = Vec::new().into_iter();
+        iter::from_fn(move || {
+            loop {
+                if let Some(word) = words.next() {
+                    return Some(Ok(Cow::Owned(word)));
+                }
+                match &mut lines {
+                    Ok(lines) => match lines.next()? {
+                        Ok(line) => {
+                            words = line
+                                .split_whitespace()
+                                .map(str::to_owned)
+                                .collect::<Vec<_>>()
+                                .into_iter();
+                        },
+                        Err(error) => return Some(Err(error)),
+                    },
+                    // Only report failing to open the file once
+                    Err(error) => return error.take().map(Err),
+                }
+            }
+        })
+    }
+}
+
+/// Words generated on the fly by a closure, e.g. a fuzzer or a permutator of
+/// another word list's words.
+///
+/// `generate` is called each time the words are checked, and should produce
+/// the same words each time so reports from different locations are
+/// comparable (e.g. by seeding any randomness the same way).
+pub struct GeneratedWords<F> {
+    name: String,
+    script: Option<String>,
+    language: Option<String>,
+    generate: F,
+}
+
+impl<F, I> GeneratedWords<F>
+where
+    F: Fn() -> I + Sync,
+    I: Iterator<Item = String>,
+{
+    /// Generate words with `generate`, named `name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, generate: F) -> Self {
+        GeneratedWords {
+            name: name.into(),
+            script: None,
+            language: None,
+            generate,
+        }
+    }
+
+    /// Set the ISO 15924 script the words are written in.
+    #[must_use]
+    pub fn with_script(mut self, script: impl Into<String>) -> Self {
+        self.script = Some(script.into());
+        self
+    }
+
+    /// Set the language of the words.
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+}
+
+impl<F, I> WordSource for GeneratedWords<F>
+where
+    F: Fn() -> I + Sync,
+    I: Iterator<Item = String>,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+
+    fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = io::Result<Cow<'_, str>>> {
+        (self.generate)().map(|word| Ok(Cow::Owned(word)))
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::fs;
+
+    use super::*;
+
+    fn words(source: &impl WordSource) -> io::Result<Vec<String>> {
+        source
+            .iter()
+            .map(|word| word.map(Cow::into_owned))
+            .collect()
+    }
+
+    #[test]
+    fn streams_word_files() {
+        let path = std::env::temp_dir()
+            .join(format!("fontheight-words-{}.txt", std::process::id()));
+        fs::write(&path, "hello world\n\n  again\n").unwrap();
+        let word_file = WordFile::new(&path);
+        assert_eq!(words(&word_file).unwrap(), ["hello", "world", "again"]);
+        fs::remove_file(&path).unwrap();
+        let mut failed = word_file.iter();
+        assert!(failed.next().unwrap().is_err());
+        assert!(failed.next().is_none());
+    }
+
+    #[test]
+    fn generates_words() {
+        let generated = GeneratedWords::new("doubled", || {
+            ["a", "b"].into_iter().map(|c| c.repeat(2))
+        });
+        assert_eq!(words(&generated).unwrap(), ["aa", "bb"]);
+        assert_eq!(generated.len_hint(), None);
+    }
+}
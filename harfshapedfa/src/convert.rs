@@ -1,4 +1,7 @@
+use std::cmp;
+
 use harfrust::{Direction, Script, Tag, script};
+use skrifa::{Axis, MetadataProvider, raw::types::F2Dot14};
 
 use crate::errors::InvalidTagError;
 
@@ -129,8 +132,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "aa" => Tag::new(b"AFR "),  // Afar
         "aae" => Tag::new(b"SQI "), // Arbëreshë Albanian -> Albanian
         "aao" => Tag::new(b"ARA "), // Algerian Saharan Arabic -> Arabic
-        // "aaq" => Tag::new(b"AAQ "), // Eastern Abnaki -> Eastern
-        // Abenaki
         "aat" => Tag::new(b"SQI "), // Arvanitika Albanian -> Albanian
         "ab" => Tag::new(b"ABK "),  // Abkhazian
         // "aba" => Tag::new(&[0; 4]), // Abé != Abaza
@@ -140,20 +141,15 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "abv" => Tag::new(b"ARA "), // Baharna Arabic -> Arabic
         "acf" => Tag::new(b"FAN "), /* Saint Lucian Creole French -> French
                                       * Antillean */
-        // "acf" => Tag::new(b"CPP "), // Saint Lucian Creole French -> Creoles
-        // "ach" => Tag::new(b"ACH "), // Acoli -> Acholi
         "acm" => Tag::new(b"ARA "), // Mesopotamian Arabic -> Arabic
         "acq" => Tag::new(b"ARA "), // Ta'izzi-Adeni Arabic -> Arabic
         "acr" => Tag::new(b"ACR "), // Achi
-        // "acr" => Tag::new(b"MYN "), // Achi -> Mayan
         "acw" => Tag::new(b"ARA "), // Hijazi Arabic -> Arabic
         "acx" => Tag::new(b"ARA "), // Omani Arabic -> Arabic
         "acy" => Tag::new(b"ACY "), // Cypriot Arabic
-        // "acy" => Tag::new(b"ARA "), // Cypriot Arabic -> Arabic
         "ada" => Tag::new(b"DNG "), // Adangme -> Dangme
         "adf" => Tag::new(b"ARA "), // Dhofari Arabic -> Arabic
         "adp" => Tag::new(b"DZN "), // Adap(retired code) -> Dzongkha
-        // "ady" => Tag::new(b"ADY "), // Adyghe
         "aeb" => Tag::new(b"ARA "), // Tunisian Arabic -> Arabic
         "aec" => Tag::new(b"ARA "), // Saidi Arabic -> Arabic
         "af" => Tag::new(b"AFK "),  // Afrikaans
@@ -167,8 +163,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "aig" => Tag::new(b"CPP "), /* Antigua and Barbuda Creole English ->
                                       * Creoles */
         "aii" => Tag::new(b"SWA "), // Assyrian Neo-Aramaic -> Swadaya Aramaic
-        // "aii" => Tag::new(b"SYR "), // Assyrian Neo-Aramaic -> Syriac
-        // "aio" => Tag::new(b"AIO "), // Aiton
         "aiw" => Tag::new(b"ARI "), // Aari
         "ajp" => Tag::new(b"ARA "), /* South Levantine Arabic(retired code)
                                       * -> Arabic */
@@ -176,16 +170,12 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
                                       * -> Arabic */
         "ak" => Tag::new(b"AKA "), // Akan [macrolanguage]
         "akb" => Tag::new(b"AKB "), // Batak Angkola
-        // "akb" => Tag::new(b"BTK "), // Batak Angkola -> Batak
         "aln" => Tag::new(b"SQI "), // Gheg Albanian -> Albanian
         "als" => Tag::new(b"SQI "), // Tosk Albanian -> Albanian
-        // "alt" => Tag::new(b"ALT "), // Southern Altai -> Altai
         "am" => Tag::new(b"AMH "),  // Amharic
         "amf" => Tag::new(b"HBN "), // Hamer-Banna -> Hammer-Banna
         "amw" => Tag::new(b"SYR "), // Western Neo-Aramaic -> Syriac
         "an" => Tag::new(b"ARG "),  // Aragonese
-        // "ang" => Tag::new(b"ANG "), // Old English (ca. 450-1100) ->
-        // Anglo-Saxon
         "aoa" => Tag::new(b"CPP "), // Angolar -> Creoles
         "apa" => Tag::new(b"ATH "), // Apache  [collection] -> Athapaskan
         "apc" => Tag::new(b"ARA "), // Levantine Arabic -> Arabic
@@ -204,21 +194,14 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "arq" => Tag::new(b"ARA "), // Algerian Arabic -> Arabic
         "ars" => Tag::new(b"ARA "), // Najdi Arabic -> Arabic
         "ary" => Tag::new(b"MOR "), // Moroccan Arabic -> Moroccan
-        // "ary" => Tag::new(b"ARA "), // Moroccan Arabic -> Arabic
         "arz" => Tag::new(b"ARA "), // Egyptian Arabic -> Arabic
         "as" => Tag::new(b"ASM "),  // Assamese
-        // "ast" => Tag::new(b"AST "), // Asturian
-        // "ath" => Tag::new(b"ATH "), // Athapascan  [collection] ->
-        // Athapaskan
         "atj" => Tag::new(b"RCR "), // Atikamekw -> R-Cree
-        // "ats" => Tag::new(b"ATS "), // Gros Ventre (Atsina)
         "atv" => Tag::new(b"ALT "), // Northern Altai -> Altai
         "auj" => Tag::new(b"BBR "), // Awjilah -> Berber
         "auz" => Tag::new(b"ARA "), // Uzbeki Arabic -> Arabic
         "av" => Tag::new(b"AVR "),  // Avaric -> Avar
         "avl" => Tag::new(b"ARA "), // Eastern Egyptian Bedawi Arabic -> Arabic
-        // "avn" => Tag::new(b"AVN "), // Avatime
-        // "awa" => Tag::new(b"AWA "), // Awadhi
         "ay" => Tag::new(b"AYM "), // Aymara [macrolanguage]
         "ayc" => Tag::new(b"AYM "), // Southern Aymara -> Aymara
         "ayh" => Tag::new(b"ARA "), // Hadrami Arabic -> Arabic
@@ -228,7 +211,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ayr" => Tag::new(b"AYM "), // Central Aymara -> Aymara
         "az" => Tag::new(b"AZE "), // Azerbaijani [macrolanguage]
         "azb" => Tag::new(b"AZB "), // South Azerbaijani -> Torki
-        // "azb" => Tag::new(b"AZE "), // South Azerbaijani -> Azerbaijani
         "azd" => Tag::new(b"NAH "), // Eastern Durango Nahuatl -> Nahuatl
         "azj" => Tag::new(b"AZE "), // North Azerbaijani -> Azerbaijani
         "azn" => Tag::new(b"NAH "), // Western Durango Nahuatl -> Nahuatl
@@ -239,11 +221,8 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "bah" => Tag::new(b"CPP "), // Bahamas Creole English -> Creoles
         "bai" => Tag::new(b"BML "), // Bamileke  [collection]
         "bal" => Tag::new(b"BLI "), // Baluchi [macrolanguage]
-        // "ban" => Tag::new(b"BAN "), // Balinese
-        // "bar" => Tag::new(b"BAR "), // Bavarian
         // "bau" => Tag::new(&[0; 4]), // Bada (Nigeria) != Baulé
         "bbc" => Tag::new(b"BBC "), // Batak Toba
-        // "bbc" => Tag::new(b"BTK "), // Batak Toba -> Batak
         "bbj" => Tag::new(b"BML "), // Ghomálá' -> Bamileke
         "bbp" => Tag::new(b"BAD0"), // West Central Banda -> Banda
         // "bbr" => Tag::new(&[0; 4]), // Girawa != Berber
@@ -255,12 +234,9 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "bcl" => Tag::new(b"BIK "), // Central Bikol -> Bikol
         "bcq" => Tag::new(b"BCH "), // Bench
         "bcr" => Tag::new(b"ATH "), // Babine -> Athapaskan
-        // "bdc" => Tag::new(b"BDC "), // Emberá-Baudó
-        // "bdy" => Tag::new(b"BDY "), // Bandjalang
         "be" => Tag::new(b"BEL "),  // Belarusian
         "bea" => Tag::new(b"ATH "), // Beaver -> Athapaskan
         "beb" => Tag::new(b"BTI "), // Bebele -> Beti
-        // "bem" => Tag::new(b"BEM "), // Bemba (Zambia)
         "ber" => Tag::new(b"BBR "), // Berber  [collection]
         "bew" => Tag::new(b"CPP "), // Betawi -> Creoles
         "bfl" => Tag::new(b"BAD0"), // Banda-Ndélé -> Banda
@@ -269,24 +245,17 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "bfu" => Tag::new(b"LAH "), // Gahri -> Lahuli
         "bfy" => Tag::new(b"BAG "), // Bagheli -> Baghelkhandi
         "bg" => Tag::new(b"BGR "),  // Bulgarian
-        // "bgc" => Tag::new(b"BGC "), // Haryanvi
         "bgn" => Tag::new(b"BLI "), // Western Balochi -> Baluchi
         "bgp" => Tag::new(b"BLI "), // Eastern Balochi -> Baluchi
         "bgq" => Tag::new(b"BGQ "), // Bagri
-        // "bgq" => Tag::new(b"RAJ "), // Bagri -> Rajasthani
         "bgr" => Tag::new(b"QIN "), // Bawm Chin -> Chin
         "bhb" => Tag::new(b"BHI "), // Bhili
-        // "bhi" => Tag::new(b"BHI "), // Bhilali -> Bhili
         "bhk" => Tag::new(b"BIK "), // Albay Bicolano(retired code) -> Bikol
-        // "bho" => Tag::new(b"BHO "), // Bhojpuri
         "bhr" => Tag::new(b"MLG "), // Bara Malagasy -> Malagasy
         "bi" => Tag::new(b"BIS "),  // Bislama
-        // "bi" => Tag::new(b"CPP "),  // Bislama -> Creoles
-        // "bik" => Tag::new(b"BIK "), // Bikol [macrolanguage]
         // "bil" => Tag::new(&[0; 4]), // Bile != Bilen
         "bin" => Tag::new(b"EDO "), // Edo
         "biu" => Tag::new(b"QIN "), // Biete -> Chin
-        // "bjj" => Tag::new(b"BJJ "), // Kanauji
         "bjn" => Tag::new(b"MLY "), // Banjar -> Malay
         "bjo" => Tag::new(b"BAD0"), // Mid-Southern Banda -> Banda
         "bjq" => Tag::new(b"MLG "), /* Southern Betsimisaraka
@@ -300,7 +269,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "blg" => Tag::new(b"IBA "), // Balau(retired code) -> Iban
         // "bli" => Tag::new(&[0; 4]), // Bolia != Baluchi
         "blk" => Tag::new(b"BLK "), // Pa’o Karen
-        // "blk" => Tag::new(b"KRN "), // Pa'o Karen -> Karen
         "bln" => Tag::new(b"BIK "), // Southern Catanduanes Bikol -> Bikol
         // "blt" => Tag::new(&[0; 4]), // Tai Dam != Balti
         "bm" => Tag::new(b"BMB "),  // Bambara (Bamanankan)
@@ -313,36 +281,24 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "bpd" => Tag::new(b"BAD0"), // Banda-Banda -> Banda
         "bpl" => Tag::new(b"CPP "), // Broome Pearling Lugger Pidgin -> Creoles
         "bpq" => Tag::new(b"CPP "), // Banda Malay -> Creoles
-        // "bpy" => Tag::new(b"BPY "), // Bishnupriya -> Bishnupriya
-        // Manipuri
         "bqi" => Tag::new(b"LRC "), // Bakhtiari -> Luri
         "bqk" => Tag::new(b"BAD0"), // Banda-Mbrès -> Banda
         "br" => Tag::new(b"BRE "),  // Breton
         "bra" => Tag::new(b"BRI "), // Braj -> Braj Bhasha
         "brc" => Tag::new(b"CPP "), // Berbice Creole Dutch -> Creoles
-        // "brh" => Tag::new(b"BRH "), // Brahui
         // "bri" => Tag::new(&[0; 4]), // Mokpwe != Braj Bhasha
         // "brm" => Tag::new(&[0; 4]), // Barambu != Burmese
-        // "brx" => Tag::new(b"BRX "), // Bodo (India)
         "bs" => Tag::new(b"BOS "),  // Bosnian
         // "bsh" => Tag::new(&[0; 4]), // Kati != Bashkir
-        // "bsk" => Tag::new(b"BSK "), // Burushaski
         "btb" => Tag::new(b"BTI "), // Beti (Cameroon)(retired code)
         "btd" => Tag::new(b"BTD "), // Batak Dairi (Pakpak)
-        // "btd" => Tag::new(b"BTK "), // Batak Dairi -> Batak
         // "bti" => Tag::new(&[0; 4]), // Burate != Beti
         "btj" => Tag::new(b"MLY "), // Bacanese Malay -> Malay
-        // "btk" => Tag::new(b"BTK "), // Batak  [collection]
         "btm" => Tag::new(b"BTM "), // Batak Mandailing
-        // "btm" => Tag::new(b"BTK "), // Batak Mandailing -> Batak
         "bto" => Tag::new(b"BIK "), // Rinconada Bikol -> Bikol
         "bts" => Tag::new(b"BTS "), // Batak Simalungun
-        // "bts" => Tag::new(b"BTK "), // Batak Simalungun -> Batak
         "btx" => Tag::new(b"BTX "), // Batak Karo
-        // "btx" => Tag::new(b"BTK "), // Batak Karo -> Batak
         "btz" => Tag::new(b"BTZ "), // Batak Alas-Kluet
-        // "btz" => Tag::new(b"BTK "), // Batak Alas-Kluet -> Batak
-        // "bug" => Tag::new(b"BUG "), // Buginese -> Bugis
         "bum" => Tag::new(b"BTI "), // Bulu (Cameroon) -> Beti
         "bve" => Tag::new(b"MLY "), // Berau Malay -> Malay
         "bvu" => Tag::new(b"MLY "), // Bukit Malay -> Malay
@@ -353,7 +309,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "bxr" => Tag::new(b"RBU "), // Russia Buriat -> Russian Buriat
         "byn" => Tag::new(b"BIL "), // Bilin -> Bilen
         "byv" => Tag::new(b"BYV "), // Medumba
-        // "byv" => Tag::new(b"BML "), // Medumba -> Bamileke
         "bzc" => Tag::new(b"MLG "), /* Southern Betsimisaraka Malagasy ->
                                       * Malagasy */
         "bzj" => Tag::new(b"CPP "), // Belize Kriol English -> Creoles
@@ -362,13 +317,8 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "caa" => Tag::new(b"MYN "), // Chortí -> Mayan
         "cac" => Tag::new(b"MYN "), // Chuj -> Mayan
         "caf" => Tag::new(b"CRR "), // Southern Carrier -> Carrier
-        // "caf" => Tag::new(b"ATH "), // Southern Carrier -> Athapaskan
         "cak" => Tag::new(b"CAK "), // Kaqchikel
-        // "cak" => Tag::new(b"MYN "), // Kaqchikel -> Mayan
-        // "cay" => Tag::new(b"CAY "), // Cayuga
-        // "cbg" => Tag::new(b"CBG "), // Chimila
         "cbk" => Tag::new(b"CBK "), // Chavacano -> Zamboanga Chavacano
-        // "cbk" => Tag::new(b"CPP "), // Chavacano -> Creoles
         "cbl" => Tag::new(b"QIN "), // Bualkhaw Chin -> Chin
         "ccl" => Tag::new(b"CPP "), // Cutchi-Swahili -> Creoles
         "ccm" => Tag::new(b"CPP "), // Malaccan Creole Malay -> Creoles
@@ -376,12 +326,9 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ccq" => Tag::new(b"ARK "), // Chaungtha(retired code) -> Rakhine
         "cdo" => Tag::new(b"ZHS "), // Min Dong Chinese -> Chinese, Simplified
         "ce" => Tag::new(b"CHE "),  // Chechen
-        // "ceb" => Tag::new(b"CEB "), // Cebuano
         "cek" => Tag::new(b"QIN "), // Eastern Khumi Chin -> Chin
         "cey" => Tag::new(b"QIN "), // Ekai Chin -> Chin
         "cfm" => Tag::new(b"HAL "), // Halam (Falam Chin)
-        // "cfm" => Tag::new(b"QIN "), // Falam Chin -> Chin
-        // "cgg" => Tag::new(b"CGG "), // Chiga
         "ch" => Tag::new(b"CHA "),  // Chamorro
         "chf" => Tag::new(b"MYN "), // Tabasco Chontal -> Mayan
         // "chg" => Tag::new(&[0; 4]), // Chagatai != Chaha Gurage
@@ -390,20 +337,11 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "chk" => Tag::new(b"CHK0"), // Chuukese
         "chm" => Tag::new(b"HMA "), /* Mari (Russia) [macrolanguage] -> High
                                       * Mari */
-        // "chm" => Tag::new(b"LMA "), // Mari (Russia) [macrolanguage] -> Low
-        // Mari
         "chn" => Tag::new(b"CPP "), // Chinook jargon -> Creoles
-        // "cho" => Tag::new(b"CHO "), // Choctaw
         "chp" => Tag::new(b"CHP "), // Chipewyan
-        // "chp" => Tag::new(b"SAY "), // Chipewyan -> Sayisi
-        // "chp" => Tag::new(b"ATH "), // Chipewyan -> Athapaskan
         "chq" => Tag::new(b"CCHN"), // Quiotepec Chinantec -> Chinantec
-        // "chr" => Tag::new(b"CHR "), // Cherokee
-        // "chy" => Tag::new(b"CHY "), // Cheyenne
         "chz" => Tag::new(b"CCHN"), // Ozumacín Chinantec -> Chinantec
         "ciw" => Tag::new(b"OJB "), // Chippewa -> Ojibway
-        // "cja" => Tag::new(b"CJA "), // Western Cham
-        // "cjm" => Tag::new(b"CJM "), // Eastern Cham
         "cjy" => Tag::new(b"ZHS "), // Jinyu Chinese -> Chinese, Simplified
         "cka" => Tag::new(b"QIN "), // Khumi Awa Chin(retired code) -> Chin
         "ckb" => Tag::new(b"KUR "), // Central Kurdish -> Kurdish
@@ -418,7 +356,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "clj" => Tag::new(b"QIN "), // Laitu Chin -> Chin
         "cls" => Tag::new(b"SAN "), // Classical Sanskrit -> Sanskrit
         "clt" => Tag::new(b"QIN "), // Lautu Chin -> Chin
-        // "cmi" => Tag::new(b"CMI "), // Emberá-Chamí
         "cmn" => Tag::new(b"ZHS "), // Mandarin Chinese -> Chinese, Simplified
         "cmr" => Tag::new(b"QIN "), // Mro-Khimi Chin -> Chin
         "cnb" => Tag::new(b"QIN "), // Chinbon Chin -> Chin
@@ -434,8 +371,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "co" => Tag::new(b"COS "),  // Corsican
         "coa" => Tag::new(b"MLY "), // Cocos Islands Malay -> Malay
         "cob" => Tag::new(b"MYN "), // Chicomuceltec -> Mayan
-        // "coo" => Tag::new(b"COO "), // Comox
-        // "cop" => Tag::new(b"COP "), // Coptic
         "coq" => Tag::new(b"ATH "), // Coquille -> Athapaskan
         "cpa" => Tag::new(b"CCHN"), // Palantla Chinantec -> Chinantec
         "cpe" => Tag::new(b"CPP "), /* English-based creoles and pidgins
@@ -443,39 +378,25 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "cpf" => Tag::new(b"CPP "), /* French-based creoles and pidgins
                                       * [collection] -> Creoles */
         "cpi" => Tag::new(b"CPP "), // Chinese Pidgin English -> Creoles
-        // "cpp" => Tag::new(b"CPP "), // Portuguese-based creoles and
-        // pidgins [collection] -> Creoles
         "cpx" => Tag::new(b"ZHS "), // Pu-Xian Chinese -> Chinese, Simplified
         "cqd" => Tag::new(b"HMN "), // Chuanqiandian Cluster Miao -> Hmong
         "cqu" => Tag::new(b"QUH "), /* Chilean Quechua(retired code) ->
                                       * Quechua (Bolivia) */
-        // "cqu" => Tag::new(b"QUZ "), // Chilean Quechua(retired code) ->
-        // Quechua
         "cr" => Tag::new(b"CRE "), // Cree [macrolanguage]
         "crh" => Tag::new(b"CRT "), // Crimean Tatar
         "cri" => Tag::new(b"CPP "), // Sãotomense -> Creoles
         "crj" => Tag::new(b"ECR "), // Southern East Cree -> Eastern Cree
-        // "crj" => Tag::new(b"YCR "), // Southern East Cree -> Y-Cree
-        // "crj" => Tag::new(b"CRE "), // Southern East Cree -> Cree
         "crk" => Tag::new(b"WCR "), // Plains Cree -> West-Cree
-        // "crk" => Tag::new(b"YCR "), // Plains Cree -> Y-Cree
-        // "crk" => Tag::new(b"CRE "), // Plains Cree -> Cree
         "crl" => Tag::new(b"ECR "), // Northern East Cree -> Eastern Cree
-        // "crl" => Tag::new(b"YCR "), // Northern East Cree -> Y-Cree
-        // "crl" => Tag::new(b"CRE "), // Northern East Cree -> Cree
         "crm" => Tag::new(b"MCR "), // Moose Cree
-        // "crm" => Tag::new(b"LCR "), // Moose Cree -> L-Cree
-        // "crm" => Tag::new(b"CRE "), // Moose Cree -> Cree
         "crp" => Tag::new(b"CPP "), /* Creoles and pidgins [collection] ->
                                       * Creoles */
         // "crr" => Tag::new(&[0; 4]), // Carolina Algonquian != Carrier
         "crs" => Tag::new(b"CPP "), // Seselwa Creole French -> Creoles
         // "crt" => Tag::new(&[0; 4]), // Iyojwa'ja Chorote != Crimean Tatar
         "crx" => Tag::new(b"CRR "), // Carrier
-        // "crx" => Tag::new(b"ATH "), // Carrier -> Athapaskan
         "cs" => Tag::new(b"CSY "),  // Czech
         "csa" => Tag::new(b"CCHN"), // Chiltepec Chinantec -> Chinantec
-        // "csb" => Tag::new(b"CSB "), // Kashubian
         "csh" => Tag::new(b"QIN "), // Asho Chin -> Chin
         "csj" => Tag::new(b"QIN "), // Songlai Chin -> Chin
         // "csl" => Tag::new(&[0; 4]), // Chinese Sign Language != Church Slavonic
@@ -484,77 +405,56 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
                                       * Simplified */
         "csv" => Tag::new(b"QIN "), // Sumtu Chin -> Chin
         "csw" => Tag::new(b"NCR "), // Swampy Cree -> N-Cree
-        // "csw" => Tag::new(b"NHC "), // Swampy Cree -> Norway House Cree
-        // "csw" => Tag::new(b"CRE "), // Swampy Cree -> Cree
         "csy" => Tag::new(b"QIN "), // Siyin Chin -> Chin
         "ctc" => Tag::new(b"ATH "), // Chetco -> Athapaskan
         "ctd" => Tag::new(b"QIN "), // Tedim Chin -> Chin
         "cte" => Tag::new(b"CCHN"), // Tepinapa Chinantec -> Chinantec
-        // "ctg" => Tag::new(b"CTG "), // Chittagonian
         "cth" => Tag::new(b"QIN "), // Thaiphum Chin -> Chin
         "ctl" => Tag::new(b"CCHN"), // Tlacoatzintepec Chinantec -> Chinantec
-        // "cto" => Tag::new(b"CTO "), // Emberá-Catío
         "cts" => Tag::new(b"BIK "), // Northern Catanduanes Bikol -> Bikol
-        // "ctt" => Tag::new(b"CTT "), // Wayanad Chetti
         "ctu" => Tag::new(b"MYN "), // Chol -> Mayan
         "cu" => Tag::new(b"CSL "),  // Church Slavonic
         "cuc" => Tag::new(b"CCHN"), // Usila Chinantec -> Chinantec
-        // "cuk" => Tag::new(b"CUK "), // San Blas Kuna
         "cv" => Tag::new(b"CHU "),  // Chuvash
         "cvn" => Tag::new(b"CCHN"), // Valle Nacional Chinantec -> Chinantec
         "cwd" => Tag::new(b"DCR "), // Woods Cree
-        // "cwd" => Tag::new(b"TCR "), // Woods Cree -> TH-Cree
-        // "cwd" => Tag::new(b"CRE "), // Woods Cree -> Cree
         "cy" => Tag::new(b"WEL "),  // Welsh
         "czh" => Tag::new(b"ZHS "), // Huizhou Chinese -> Chinese, Simplified
         "czo" => Tag::new(b"ZHS "), // Min Zhong Chinese -> Chinese, Simplified
         "czt" => Tag::new(b"QIN "), // Zotung Chin -> Chin
         "da" => Tag::new(b"DAN "),  // Danish
-        // "dag" => Tag::new(b"DAG "), // Dagbani
         "dao" => Tag::new(b"QIN "), // Daai Chin -> Chin
         "dap" => Tag::new(b"NIS "), // Nisi (India)(retired code)
-        // "dar" => Tag::new(b"DAR "), // Dargwa
-        // "dax" => Tag::new(b"DAX "), // Dayi
         "dcr" => Tag::new(b"CPP "), // Negerhollands -> Creoles
         "de" => Tag::new(b"DEU "),  // German
         "den" => Tag::new(b"SLA "), /* Slave (Athapascan) [macrolanguage] ->
                                       * Slavey */
-        // "den" => Tag::new(b"ATH "), // Slave (Athapascan) [macrolanguage] ->
-        // Athapaskan
         "dep" => Tag::new(b"CPP "), // Pidgin Delaware -> Creoles
         "dgo" => Tag::new(b"DGO "), // Dogri (individual language)
-        // "dgo" => Tag::new(b"DGR "), // Dogri (macrolanguage)
         "dgr" => Tag::new(b"ATH "), // Tlicho -> Athapaskan
         "dhd" => Tag::new(b"MAW "), // Dhundari -> Marwari
-        // "dhg" => Tag::new(b"DHG "), // Dhangu
         // "dhv" => Tag::new(&[0; 4]), // Dehu != Divehi (Dhivehi, Maldivian) (deprecated)
         "dib" => Tag::new(b"DNK "), // South Central Dinka -> Dinka
         "dik" => Tag::new(b"DNK "), // Southwestern Dinka -> Dinka
         "din" => Tag::new(b"DNK "), // Dinka [macrolanguage]
         "dip" => Tag::new(b"DNK "), // Northeastern Dinka -> Dinka
         "diq" => Tag::new(b"DIQ "), // Dimli
-        // "diq" => Tag::new(b"ZZA "), // Dimli  -> Zazaki
         "diw" => Tag::new(b"DNK "), // Northwestern Dinka -> Dinka
         "dje" => Tag::new(b"DJR "), // Zarma
         "djk" => Tag::new(b"CPP "), // Eastern Maroon Creole -> Creoles
         "djr" => Tag::new(b"DJR0"), // Djambarrpuyngu
         "dks" => Tag::new(b"DNK "), // Southeastern Dinka -> Dinka
         "dng" => Tag::new(b"DUN "), // Dungan
-        // "dnj" => Tag::new(b"DNJ "), // Dan
         // "dnk" => Tag::new(&[0; 4]), // Dengka != Dinka
         "doi" => Tag::new(b"DGR "), // Dogri (macrolanguage) [macrolanguage]
         "drh" => Tag::new(b"MNG "), // Darkhat(retired code) -> Mongolian
         // "dri" => Tag::new(&[0; 4]), // C'Lela != Dari
         "drw" => Tag::new(b"DRI "), // Darwazi(retired code) -> Dari
-        // "drw" => Tag::new(b"FAR "), // Darwazi(retired code) -> Persian
         "dsb" => Tag::new(b"LSB "), // Lower Sorbian
         "dty" => Tag::new(b"NEP "), // Dotyali -> Nepali
-        // "duj" => Tag::new(b"DUJ "), // Dhuwal(retired code)
         // "dun" => Tag::new(&[0; 4]), // Dusun Deyah != Dungan
         "dup" => Tag::new(b"MLY "), // Duano -> Malay
         "dv" => Tag::new(b"DIV "),  // Divehi (Dhivehi, Maldivian)
-        // "dv" => Tag::new(b"DHV "),  // Divehi (Dhivehi, Maldivian)
-        // (deprecated)
         "dwk" => Tag::new(b"KUI "), // Dawik Kui -> Kui
         "dwu" => Tag::new(b"DUJ "), // Dhuwal
         "dwy" => Tag::new(b"DUJ "), // Dhuwaya -> Dhuwal
@@ -563,13 +463,10 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "dzn" => Tag::new(&[0; 4]), // Dzando != Dzongkha
         // "ecr" => Tag::new(&[0; 4]), // Eteocretan != Eastern Cree
         "ee" => Tag::new(b"EWE "),  // Ewe
-        // "efi" => Tag::new(b"EFI "), // Efik
         "ekk" => Tag::new(b"ETI "), // Standard Estonian -> Estonian
         "eky" => Tag::new(b"KRN "), // Eastern Kayah -> Karen
         "el" => Tag::new(b"ELL "),  // Modern Greek (1453-) -> Greek
         "emk" => Tag::new(b"EMK "), // Eastern Maninkakan
-        // "emk" => Tag::new(b"MNK "), // Eastern Maninkakan -> Maninka
-        // "emp" => Tag::new(b"EMP "), // Northern Emberá
         "emy" => Tag::new(b"MYN "), // Epigraphic Mayan -> Mayan
         "en" => Tag::new(b"ENG "),  // English
         "enb" => Tag::new(b"KAL "), // Markweeta -> Kalenjin
@@ -580,7 +477,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "esg" => Tag::new(b"GON "), // Aheri Gondi -> Gondi
         "esi" => Tag::new(b"IPK "), // North Alaskan Inupiatun -> Inupiat
         "esk" => Tag::new(b"IPK "), // Northwest Alaska Inupiatun -> Inupiat
-        // "esu" => Tag::new(b"ESU "), // Central Yupik
         "et" => Tag::new(b"ETI "), // Estonian [macrolanguage]
         "eto" => Tag::new(b"BTI "), // Eton (Cameroon) -> Beti
         "eu" => Tag::new(b"EUQ "), // Basque
@@ -592,10 +488,8 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "fa" => Tag::new(b"FAR "), // Persian [macrolanguage]
         "fab" => Tag::new(b"CPP "), // Fa d'Ambu -> Creoles
         "fan" => Tag::new(b"FAN0"), // Fang (Equatorial Guinea)
-        // "fan" => Tag::new(b"BTI "), // Fang (Equatorial Guinea) -> Beti
         // "far" => Tag::new(&[0; 4]), // Fataleka != Persian
         "fat" => Tag::new(b"FAT "), // Fanti
-        // "fat" => Tag::new(b"AKA "), // Fanti -> Akan
         "fbl" => Tag::new(b"BIK "), // West Albay Bikol -> Bikol
         "ff" => Tag::new(b"FUL "),  // Fulah [macrolanguage]
         "ffm" => Tag::new(b"FUL "), // Maasina Fulfulde -> Fulah
@@ -603,36 +497,27 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "fil" => Tag::new(b"PIL "), // Filipino
         "fj" => Tag::new(b"FJI "),  // Fijian
         "flm" => Tag::new(b"HAL "), // Halam (Falam Chin)(retired code)
-        // "flm" => Tag::new(b"QIN "), // Falam Chin(retired code) -> Chin
         "fmp" => Tag::new(b"FMP "), // Fe’fe’
-        // "fmp" => Tag::new(b"BML "), // Fe'fe' -> Bamileke
         "fng" => Tag::new(b"CPP "), // Fanagalo -> Creoles
         "fo" => Tag::new(b"FOS "),  // Faroese
-        // "fon" => Tag::new(b"FON "), // Fon
         // "fos" => Tag::new(&[0; 4]), // Siraya != Faroese
         "fpe" => Tag::new(b"CPP "), // Fernando Po Creole English -> Creoles
         "fr" => Tag::new(b"FRA "),  // French
-        // "frc" => Tag::new(b"FRC "), // Cajun French
-        // "frp" => Tag::new(b"FRP "), // Arpitan
         "fub" => Tag::new(b"FUL "), // Adamawa Fulfulde -> Fulah
         "fuc" => Tag::new(b"FUL "), // Pulaar -> Fulah
         "fue" => Tag::new(b"FUL "), // Borgu Fulfulde -> Fulah
         "fuf" => Tag::new(b"FTA "), // Pular -> Futa
-        // "fuf" => Tag::new(b"FUL "), // Pular -> Fulah
         "fuh" => Tag::new(b"FUL "), // Western Niger Fulfulde -> Fulah
         "fui" => Tag::new(b"FUL "), // Bagirmi Fulfulde -> Fulah
         "fuq" => Tag::new(b"FUL "), // Central-Eastern Niger Fulfulde -> Fulah
         "fur" => Tag::new(b"FRL "), // Friulian
         "fuv" => Tag::new(b"FUV "), // Nigerian Fulfulde
-        // "fuv" => Tag::new(b"FUL "), // Nigerian Fulfulde -> Fulah
         "fy" => Tag::new(b"FRI "), // Western Frisian -> Frisian
         "ga" => Tag::new(b"IRI "), // Irish
-        // "ga" => Tag::new(b"IRT "),  // Irish -> Irish Traditional
         "gaa" => Tag::new(b"GAD "), // Ga
         "gac" => Tag::new(b"CPP "), // Mixed Great Andamanese -> Creoles
         // "gad" => Tag::new(&[0; 4]), // Gaddang != Ga
         // "gae" => Tag::new(&[0; 4]), // Guarequena != Scottish Gaelic
-        // "gag" => Tag::new(b"GAG "), // Gagauz
         // "gal" => Tag::new(&[0; 4]), // Galolen != Galician
         "gan" => Tag::new(b"ZHS "), // Gan Chinese -> Chinese, Simplified
         // "gar" => Tag::new(&[0; 4]), // Galeya != Garshuni
@@ -646,7 +531,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "gcr" => Tag::new(b"CPP "), // Guianese Creole French -> Creoles
         "gd" => Tag::new(b"GAE "),  // Scottish Gaelic
         "gda" => Tag::new(b"RAJ "), // Gade Lohar -> Rajasthani
-        // "gez" => Tag::new(b"GEZ "), // Geez
         "ggo" => Tag::new(b"GON "), // Southern Gondi(retired code) -> Gondi
         "gha" => Tag::new(b"BBR "), // Ghadamès -> Berber
         "ghc" => Tag::new(b"IRT "), /* Hiberno-Scottish Gaelic -> Irish
@@ -654,23 +538,17 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ghk" => Tag::new(b"KRN "), // Geko Karen -> Karen
         "gho" => Tag::new(b"BBR "), // Ghomara -> Berber
         "gib" => Tag::new(b"CPP "), // Gibanawa -> Creoles
-        // "gih" => Tag::new(b"GIH "), // Githabul
         "gil" => Tag::new(b"GIL0"), // Kiribati (Gilbertese)
         "gju" => Tag::new(b"RAJ "), // Gujari -> Rajasthani
         "gkp" => Tag::new(b"GKP "), // Guinea Kpelle -> Kpelle (Guinea)
-        // "gkp" => Tag::new(b"KPL "), // Guinea Kpelle -> Kpelle
         "gl" => Tag::new(b"GAL "),  // Galician
         "gld" => Tag::new(b"NAN "), // Nanai
-        // "glk" => Tag::new(b"GLK "), // Gilaki
         // "gmz" => Tag::new(&[0; 4]), // Mgbolizhia != Gumuz
         "gn" => Tag::new(b"GUA "),  // Guarani [macrolanguage]
         "gnb" => Tag::new(b"QIN "), // Gangte -> Chin
-        // "gnn" => Tag::new(b"GNN "), // Gumatj
         "gno" => Tag::new(b"GON "), // Northern Gondi -> Gondi
         "gnw" => Tag::new(b"GUA "), // Western Bolivian Guaraní -> Guarani
-        // "gog" => Tag::new(b"GOG "), // Gogo
         "gom" => Tag::new(b"KOK "), // Goan Konkani -> Konkani
-        // "gon" => Tag::new(b"GON "), // Gondi [macrolanguage]
         "goq" => Tag::new(b"CPP "), // Gorap -> Creoles
         "gox" => Tag::new(b"BAD0"), // Gobu -> Banda
         "gpe" => Tag::new(b"CPP "), // Ghanaian Pidgin English -> Creoles
@@ -681,14 +559,11 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "gsw" => Tag::new(b"ALS "), // Alsatian
         "gu" => Tag::new(b"GUJ "),  // Gujarati
         // "gua" => Tag::new(&[0; 4]), // Shiki != Guarani
-        // "guc" => Tag::new(b"GUC "), // Wayuu
-        // "guf" => Tag::new(b"GUF "), // Gupapuyngu
         "gug" => Tag::new(b"GUA "), // Paraguayan Guaraní -> Guarani
         "gui" => Tag::new(b"GUA "), // Eastern Bolivian Guaraní -> Guarani
         "guk" => Tag::new(b"GMZ "), // Gumuz
         "gul" => Tag::new(b"CPP "), // Sea Island Creole English -> Creoles
         "gun" => Tag::new(b"GUA "), // Mbyá Guaraní -> Guarani
-        // "guz" => Tag::new(b"GUZ "), // Gusii
         "gv" => Tag::new(b"MNX "),  // Manx
         "gwi" => Tag::new(b"ATH "), // Gwichʼin -> Athapaskan
         "gyn" => Tag::new(b"CPP "), // Guyanese Creole English -> Creoles
@@ -699,24 +574,18 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "hak" => Tag::new(b"ZHS "), // Hakka Chinese -> Chinese, Simplified
         // "hal" => Tag::new(&[0; 4]), // Halang != Halam (Falam Chin)
         "har" => Tag::new(b"HRI "), // Harari
-        // "haw" => Tag::new(b"HAW "), // Hawaiian
         "hax" => Tag::new(b"HAI0"), // Southern Haida -> Haida
-        // "hay" => Tag::new(b"HAY "), // Haya
-        // "haz" => Tag::new(b"HAZ "), // Hazaragi
         // "hbn" => Tag::new(&[0; 4]), // Heiban != Hammer-Banna
         "hca" => Tag::new(b"CPP "), // Andaman Creole Hindi -> Creoles
         "hdn" => Tag::new(b"HAI0"), // Northern Haida -> Haida
         "he" => Tag::new(b"IWR "),  // Hebrew
         "hea" => Tag::new(b"HMN "), // Northern Qiandong Miao -> Hmong
-        // "hei" => Tag::new(b"HEI "), // Heiltsuk
         "hi" => Tag::new(b"HIN "), // Hindi
-        // "hil" => Tag::new(b"HIL "), // Hiligaynon
         "hji" => Tag::new(b"MLY "), // Haji -> Malay
         "hlt" => Tag::new(b"QIN "), // Matu Chin -> Chin
         "hma" => Tag::new(b"HMN "), // Southern Mashan Hmong -> Hmong
         "hmc" => Tag::new(b"HMN "), // Central Huishui Hmong -> Hmong
         "hmd" => Tag::new(b"HMD "), // Large Flowery Miao -> A-Hmao
-        // "hmd" => Tag::new(b"HMN "), // Large Flowery Miao -> Hmong
         "hme" => Tag::new(b"HMN "), // Eastern Huishui Hmong -> Hmong
         "hmg" => Tag::new(b"HMN "), // Southwestern Guiyang Hmong -> Hmong
         "hmh" => Tag::new(b"HMN "), // Southwestern Huishui Hmong -> Hmong
@@ -724,7 +593,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "hmj" => Tag::new(b"HMN "), // Ge -> Hmong
         "hml" => Tag::new(b"HMN "), // Luopohe Hmong -> Hmong
         "hmm" => Tag::new(b"HMN "), // Central Mashan Hmong -> Hmong
-        // "hmn" => Tag::new(b"HMN "), // Hmong [macrolanguage]
         "hmp" => Tag::new(b"HMN "), // Northern Mashan Hmong -> Hmong
         "hmq" => Tag::new(b"HMN "), // Eastern Qiandong Miao -> Hmong
         "hmr" => Tag::new(b"QIN "), // Hmar -> Chin
@@ -732,43 +600,33 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "hmw" => Tag::new(b"HMN "), // Western Mashan Hmong -> Hmong
         "hmy" => Tag::new(b"HMN "), // Southern Guiyang Hmong -> Hmong
         "hmz" => Tag::new(b"HMZ "), // Hmong Shua -> Hmong Shuat
-        // "hmz" => Tag::new(b"HMN "), // Hmong Shua -> Hmong
-        // "hnd" => Tag::new(b"HND "), // Southern Hindko -> Hindko
         "hne" => Tag::new(b"CHH "), // Chhattisgarhi -> Chattisgarhi
         "hnj" => Tag::new(b"HMN "), // Hmong Njua -> Hmong
         "hnm" => Tag::new(b"ZHS "), // Hainanese -> Chinese, Simplified
         "hno" => Tag::new(b"HND "), // Northern Hindko -> Hindko
         "ho" => Tag::new(b"HMO "),  // Hiri Motu
-        // "ho" => Tag::new(b"CPP "),  // Hiri Motu -> Creoles
         "hoc" => Tag::new(b"HO  "), // Ho
         "hoi" => Tag::new(b"ATH "), // Holikachuk -> Athapaskan
         "hoj" => Tag::new(b"HAR "), // Hadothi -> Harauti
-        // "hoj" => Tag::new(b"RAJ "), // Hadothi -> Rajasthani
         "hr" => Tag::new(b"HRV "),  // Croatian
         "hra" => Tag::new(b"QIN "), // Hrangkhol -> Chin
         "hrm" => Tag::new(b"HMN "), // Horned Miao -> Hmong
         "hsb" => Tag::new(b"USB "), // Upper Sorbian
         "hsn" => Tag::new(b"ZHS "), // Xiang Chinese -> Chinese, Simplified
         "ht" => Tag::new(b"HAI "),  // Haitian (Haitian Creole)
-        // "ht" => Tag::new(b"CPP "),  // Haitian -> Creoles
         "hu" => Tag::new(b"HUN "),  // Hungarian
         "huj" => Tag::new(b"HMN "), // Northern Guiyang Hmong -> Hmong
         "hup" => Tag::new(b"ATH "), // Hupa -> Athapaskan
-        // "hur" => Tag::new(b"HUR "), // Halkomelem
         "hus" => Tag::new(b"MYN "), // Huastec -> Mayan
         "hwc" => Tag::new(b"CPP "), // Hawai'i Creole English -> Creoles
         "hy" => Tag::new(b"HYE0"),  // Armenian -> Armenian East
-        // "hy" => Tag::new(b"HYE "),  // Armenian
         "hyw" => Tag::new(b"HYE "), // Western Armenian -> Armenian
         "hz" => Tag::new(b"HER "),  // Herero
         "ia" => Tag::new(b"INA "),  /* Interlingua (International Auxiliary
                                       * Language Association) */
-        // "iba" => Tag::new(b"IBA "), // Iban
-        // "ibb" => Tag::new(b"IBB "), // Ibibio
         "iby" => Tag::new(b"IJO "), // Ibani -> Ijo
         "icr" => Tag::new(b"CPP "), // Islander Creole English -> Creoles
         "id" => Tag::new(b"IND "),  // Indonesian
-        // "id" => Tag::new(b"MLY "),  // Indonesian -> Malay
         "ida" => Tag::new(b"LUH "), // Idakho-Isukha-Tiriki -> Luyia
         "idb" => Tag::new(b"CPP "), // Indo-Portuguese -> Creoles
         "ie" => Tag::new(b"ILE "),  // Interlingue
@@ -779,28 +637,20 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ijc" => Tag::new(b"IJO "), // Izon -> Ijo
         "ije" => Tag::new(b"IJO "), // Biseni -> Ijo
         "ijn" => Tag::new(b"IJO "), // Kalabari -> Ijo
-        // "ijo" => Tag::new(b"IJO "), // Ijo  [collection]
         "ijs" => Tag::new(b"IJO "), // Southeast Ijo -> Ijo
         "ik" => Tag::new(b"IPK "),  // Inupiaq [macrolanguage] -> Inupiat
         "ike" => Tag::new(b"INU "), // Eastern Canadian Inuktitut -> Inuktitut
-        // "ike" => Tag::new(b"INUK"), // Eastern Canadian Inuktitut -> Nunavik
-        // Inuktitut
         "ikt" => Tag::new(b"INU "), // Inuinnaqtun -> Inuktitut
-        // "ilo" => Tag::new(b"ILO "), // Iloko -> Ilokano
         "in" => Tag::new(b"IND "), // Indonesian(retired code)
-        // "in" => Tag::new(b"MLY "),  // Indonesian(retired code) -> Malay
         "ing" => Tag::new(b"ATH "), // Degexit'an -> Athapaskan
         "inh" => Tag::new(b"ING "), // Ingush
         "io" => Tag::new(b"IDO "),  // Ido
         // "iri" => Tag::new(&[0; 4]), // Rigwe != Irish
-        // "iru" => Tag::new(b"IRU "), // Irula
         "is" => Tag::new(b"ISL "),  // Icelandic
         // "ism" => Tag::new(&[0; 4]), // Masimasi != Inari Sami
         "it" => Tag::new(b"ITA "),  // Italian
         "itz" => Tag::new(b"MYN "), // Itzá -> Mayan
         "iu" => Tag::new(b"INU "),  // Inuktitut [macrolanguage]
-        // "iu" => Tag::new(b"INUK"),  // Inuktitut [macrolanguage] -> Nunavik
-        // Inuktitut
         "iw" => Tag::new(b"IWR "), // Hebrew(retired code)
         "ixl" => Tag::new(b"MYN "), // Ixil -> Mayan
         "ja" => Tag::new(b"JAN "), // Japanese
@@ -808,14 +658,10 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "jak" => Tag::new(b"MLY "), // Jakun -> Malay
         "jam" => Tag::new(b"JAM "), /* Jamaican Creole English -> Jamaican
                                      * Creole */
-        // "jam" => Tag::new(b"CPP "), // Jamaican Creole English -> Creoles
         // "jan" => Tag::new(&[0; 4]), // Jandai != Japanese
         "jax" => Tag::new(b"MLY "), // Jambi Malay -> Malay
         "jbe" => Tag::new(b"BBR "), // Judeo-Berber -> Berber
         "jbn" => Tag::new(b"BBR "), // Nafusi -> Berber
-        // "jbo" => Tag::new(b"JBO "), // Lojban
-        // "jct" => Tag::new(b"JCT "), // Krymchak
-        // "jdt" => Tag::new(b"JDT "), // Judeo-Tat
         "jgo" => Tag::new(b"BML "), // Ngomba -> Bamileke
         "ji" => Tag::new(b"JII "),  // Yiddish(retired code)
         // "jii" => Tag::new(&[0; 4]), // Jiiddu != Yiddish
@@ -829,26 +675,18 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ka" => Tag::new(b"KAT "),  // Georgian
         "kaa" => Tag::new(b"KRK "), // Karakalpak
         "kab" => Tag::new(b"KAB0"), // Kabyle
-        // "kab" => Tag::new(b"BBR "), // Kabyle -> Berber
         // "kac" => Tag::new(&[0; 4]), // Kachin != Kachchi
         "kam" => Tag::new(b"KMB "), // Kamba (Kenya)
         "kar" => Tag::new(b"KRN "), // Karen  [collection]
-        // "kaw" => Tag::new(b"KAW "), // Kawi (Old Javanese)
-        // "kbc" => Tag::new(b"KBC "), // Kadiwéu
         "kbd" => Tag::new(b"KAB "), // Kabardian
         "kby" => Tag::new(b"KNR "), // Manga Kanuri -> Kanuri
         "kca" => Tag::new(b"KHK "), // Khanty -> Khanty-Kazim
-        // "kca" => Tag::new(b"KHS "), // Khanty -> Khanty-Shurishkar
-        // "kca" => Tag::new(b"KHV "), // Khanty -> Khanty-Vakhi
         "kcn" => Tag::new(b"CPP "), // Nubi -> Creoles
-        // "kde" => Tag::new(b"KDE "), // Makonde
         "kdr" => Tag::new(b"KRM "), // Karaim
         "kdt" => Tag::new(b"KUY "), // Kuy
         "kea" => Tag::new(b"KEA "), // Kabuverdianu (Crioulo)
-        // "kea" => Tag::new(b"CPP "), // Kabuverdianu -> Creoles
         // "keb" => Tag::new(&[0; 4]), // Kélé != Kebena
         "kek" => Tag::new(b"KEK "), // Kekchi
-        // "kek" => Tag::new(b"MYN "), // Kekchí -> Mayan
         "kex" => Tag::new(b"KKN "), // Kukna -> Kokni
         "kfa" => Tag::new(b"KOD "), // Kodava -> Kodagu
         "kfr" => Tag::new(b"KAC "), // Kachhi -> Kachchi
@@ -856,29 +694,21 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "kfy" => Tag::new(b"KMN "), // Kumaoni
         "kg" => Tag::new(b"KON0"),  // Kongo [macrolanguage]
         // "kge" => Tag::new(&[0; 4]), // Komering != Khutsuri Georgian
-        // "kgf" => Tag::new(b"KGF "), // Kube
         "kha" => Tag::new(b"KSI "), // Khasi
         "khb" => Tag::new(b"XBD "), // Lü
         "khk" => Tag::new(b"MNG "), // Halh Mongolian -> Mongolian
         // "khn" => Tag::new(&[0; 4]), // Khandesi != Khamti Shan(Microsoft fonts)
         // "khs" => Tag::new(&[0; 4]), // Kasua != Khanty-Shurishkar
         "kht" => Tag::new(b"KHT "), // Khamti -> Khamti Shan
-        // "kht" => Tag::new(b"KHN "), // Khamti -> Khamti Shan(Microsoft fonts)
         // "khv" => Tag::new(&[0; 4]), // Khvarshi != Khanty-Vakhi
-        // "khw" => Tag::new(b"KHW "), // Khowar
         "ki" => Tag::new(b"KIK "),  // Kikuyu (Gikuyu)
         // "kis" => Tag::new(&[0; 4]), // Kis != Kisii
         "kiu" => Tag::new(b"KIU "), // Kirmanjki
-        // "kiu" => Tag::new(b"ZZA "), // Kirmanjki  -> Zazaki
         "kj" => Tag::new(b"KUA "),  // Kuanyama
         "kjb" => Tag::new(b"MYN "), // Q'anjob'al -> Mayan
-        // "kjd" => Tag::new(b"KJD "), // Southern Kiwai
         "kjh" => Tag::new(b"KHA "), // Khakas -> Khakass
-        // "kjj" => Tag::new(b"KJJ "), // Khinalugh -> Khinalug
         "kjp" => Tag::new(b"KJP "), // Pwo Eastern Karen -> Eastern Pwo Karen
-        // "kjp" => Tag::new(b"KRN "), // Pwo Eastern Karen -> Karen
         "kjt" => Tag::new(b"KRN "), // Phrae Pwo Karen -> Karen
-        // "kjz" => Tag::new(b"KJZ "), // Bumthangkha
         "kk" => Tag::new(b"KAZ "),  // Kazakh
         // "kkn" => Tag::new(&[0; 4]), // Kon Keu != Kokni
         "kkz" => Tag::new(b"ATH "), // Kaska -> Athapaskan
@@ -887,15 +717,12 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "kln" => Tag::new(b"KAL "), // Kalenjin [macrolanguage]
         "km" => Tag::new(b"KHM "),  // Khmer
         "kmb" => Tag::new(b"MBN "), // Kimbundu -> Mbundu
-        // "kmg" => Tag::new(b"KMG "), // Kâte
         // "kmn" => Tag::new(&[0; 4]), // Awtuw != Kumaoni
         // "kmo" => Tag::new(&[0; 4]), // Kwoma != Komo
         "kmr" => Tag::new(b"KUR "), // Northern Kurdish -> Kurdish
         // "kms" => Tag::new(&[0; 4]), // Kamasau != Komso
         "kmv" => Tag::new(b"CPP "), // Karipúna Creole French -> Creoles
         "kmw" => Tag::new(b"KMO "), // Komo (Democratic Republic of Congo)
-        // "kmz" => Tag::new(b"KMZ "), // Khorasani Turkish -> Khorasani
-        // Turkic
         "kn" => Tag::new(b"KAN "),  // Kannada
         "knc" => Tag::new(b"KNR "), // Central Kanuri -> Kanuri
         "kng" => Tag::new(b"KON0"), // Koongo -> Kongo
@@ -906,9 +733,7 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "kod" => Tag::new(&[0; 4]), // Kodi != Kodagu
         // "koh" => Tag::new(&[0; 4]), // Koyo != Korean Old Hangul
         "koi" => Tag::new(b"KOP "), // Komi-Permyak
-        // "kok" => Tag::new(b"KOK "), // Konkani  [macrolanguage]
         // "kop" => Tag::new(&[0; 4]), // Waube != Komi-Permyak
-        // "kos" => Tag::new(b"KOS "), // Kosraean
         "koy" => Tag::new(b"ATH "), // Koyukon -> Athapaskan
         // "koz" => Tag::new(&[0; 4]), // Korak != Komi-Zyrian
         "kpe" => Tag::new(b"KPL "), // Kpelle [macrolanguage]
@@ -922,7 +747,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "krc" => Tag::new(b"KAR "), // Karachay-Balkar -> Karachay
         "kri" => Tag::new(b"KRI "), // Krio
         // "krk" => Tag::new(&[0; 4]), // Kerek != Karakalpak
-        // "krl" => Tag::new(b"KRL "), // Karelian
         // "krm" => Tag::new(&[0; 4]), // Krim(retired code) != Karaim
         // "krn" => Tag::new(&[0; 4]), // Sapo != Karen
         "krt" => Tag::new(b"KNR "), // Tumari Kanuri -> Kanuri
@@ -932,9 +756,7 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "ksi" => Tag::new(&[0; 4]), // Krisa != Khasi
         // "ksm" => Tag::new(&[0; 4]), // Kumba != Kildin Sami
         "kss" => Tag::new(b"KIS "), // Southern Kisi -> Kisii
-        // "ksu" => Tag::new(b"KSU "), // Khamyang
         "ksw" => Tag::new(b"KSW "), // S’gaw Karen
-        // "ksw" => Tag::new(b"KRN "), // S'gaw Karen -> Karen
         "ktb" => Tag::new(b"KEB "), // Kambaata -> Kebena
         "ktu" => Tag::new(b"KON "), /* Kituba (Democratic Republic of Congo)
                                       * -> Kikongo */
@@ -942,7 +764,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ku" => Tag::new(b"KUR "),  // Kurdish [macrolanguage]
         // "kui" => Tag::new(&[0; 4]), // Kuikúro-Kalapálo != Kui
         // "kul" => Tag::new(&[0; 4]), // Kulere != Kulvi
-        // "kum" => Tag::new(b"KUM "), // Kumyk
         "kuu" => Tag::new(b"ATH "), // Upper Kuskokwim -> Athapaskan
         "kuw" => Tag::new(b"BAD0"), // Kpagua -> Banda
         // "kuy" => Tag::new(&[0; 4]), // Kuuku-Ya'u != Kuy
@@ -950,13 +771,11 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "kvb" => Tag::new(b"MLY "), // Kubu -> Malay
         "kvl" => Tag::new(b"KRN "), // Kayaw -> Karen
         "kvq" => Tag::new(b"KVQ "), // Geba Karen
-        // "kvq" => Tag::new(b"KRN "), // Geba Karen -> Karen
         "kvr" => Tag::new(b"MLY "), // Kerinci -> Malay
         "kvt" => Tag::new(b"KRN "), // Lahta Karen -> Karen
         "kvu" => Tag::new(b"KRN "), // Yinbaw Karen -> Karen
         "kvy" => Tag::new(b"KRN "), // Yintale Karen -> Karen
         "kw" => Tag::new(b"COR "),  // Cornish
-        // "kwk" => Tag::new(b"KWK "), // Kwak'wala -> Kwakʼwala
         "kww" => Tag::new(b"CPP "), // Kwinti -> Creoles
         "kwy" => Tag::new(b"KON0"), // San Salvador Kongo -> Kongo
         "kxc" => Tag::new(b"KMS "), // Konso -> Komso
@@ -968,7 +787,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ky" => Tag::new(b"KIR "),  // Kirghiz (Kyrgyz)
         // "kyk" => Tag::new(&[0; 4]), // Kamayo != Koryak
         "kyu" => Tag::new(b"KYU "), // Western Kayah
-        // "kyu" => Tag::new(b"KRN "), // Western Kayah -> Karen
         "la" => Tag::new(b"LAT "),  // Latin
         "lac" => Tag::new(b"MYN "), // Lacandon -> Mayan
         "lad" => Tag::new(b"JUD "), // Ladino
@@ -984,36 +802,25 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "lcf" => Tag::new(b"MLY "), // Lubu -> Malay
         "ldi" => Tag::new(b"KON0"), // Laari -> Kongo
         // "ldk" => Tag::new(&[0; 4]), // Leelau != Ladakhi
-        // "lef" => Tag::new(b"LEF "), // Lelemi
-        // "lez" => Tag::new(b"LEZ "), // Lezghian -> Lezgi
         "lg" => Tag::new(b"LUG "),  // Ganda
         "li" => Tag::new(b"LIM "),  // Limburgish
         "lif" => Tag::new(b"LMB "), // Limbu
-        // "lij" => Tag::new(b"LIJ "), // Ligurian
         "lir" => Tag::new(b"CPP "), // Liberian English -> Creoles
-        // "lis" => Tag::new(b"LIS "), // Lisu
-        // "liv" => Tag::new(b"LIV "), // Liv
         "liw" => Tag::new(b"MLY "), // Col -> Malay
         "liy" => Tag::new(b"BAD0"), // Banda-Bambari -> Banda
-        // "ljp" => Tag::new(b"LJP "), // Lampung Api -> Lampung
         "lkb" => Tag::new(b"LUH "), // Kabras -> Luyia
-        // "lki" => Tag::new(b"LKI "), // Laki
         "lko" => Tag::new(b"LUH "), // Khayo -> Luyia
         "lks" => Tag::new(b"LUH "), // Kisa -> Luyia
         "lld" => Tag::new(b"LAD "), // Ladin
         // "lma" => Tag::new(&[0; 4]), // East Limba != Low Mari
         // "lmb" => Tag::new(&[0; 4]), // Merei != Limbu
         "lmn" => Tag::new(b"LAM "), // Lambadi -> Lambani
-        // "lmo" => Tag::new(b"LMO "), // Lombard
         // "lmw" => Tag::new(&[0; 4]), // Lake Miwok != Lomwe
         "ln" => Tag::new(b"LIN "),  // Lingala
         "lna" => Tag::new(b"BAD0"), // Langbashe -> Banda
         "lnl" => Tag::new(b"BAD0"), // South Central Banda -> Banda
         "lo" => Tag::new(b"LAO "),  // Lao
-        // "lom" => Tag::new(b"LOM "), // Loma (Liberia)
         "lou" => Tag::new(b"CPP "), // Louisiana Creole -> Creoles
-        // "lpo" => Tag::new(b"LPO "), // Lipo
-        // "lrc" => Tag::new(b"LRC "), // Northern Luri -> Luri
         "lri" => Tag::new(b"LUH "), // Marachi -> Luyia
         "lrm" => Tag::new(b"LUH "), // Marama -> Luyia
         "lrt" => Tag::new(b"CPP "), // Larantuka Malay -> Creoles
@@ -1025,12 +832,8 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "lto" => Tag::new(b"LUH "), // Tsotso -> Luyia
         "lts" => Tag::new(b"LUH "), // Tachoni -> Luyia
         "lu" => Tag::new(b"LUB "),  // Luba-Katanga
-        // "lua" => Tag::new(b"LUA "), // Luba-Lulua
         "luh" => Tag::new(b"ZHS "), // Leizhou Chinese -> Chinese, Simplified
-        // "luo" => Tag::new(b"LUO "), // Luo (Kenya and Tanzania)
         "lus" => Tag::new(b"MIZ "), // Lushai -> Mizo
-        // "lus" => Tag::new(b"QIN "), // Lushai -> Chin
-        // "lut" => Tag::new(b"LUT "), // Lushootseed
         "luy" => Tag::new(b"LUH "), // Luyia [macrolanguage]
         "luz" => Tag::new(b"LRC "), // Southern Luri -> Luri
         "lv" => Tag::new(b"LVI "),  // Latvian [macrolanguage]
@@ -1039,38 +842,28 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "lwg" => Tag::new(b"LUH "), // Wanga -> Luyia
         "lzh" => Tag::new(b"ZHT "), // Literary Chinese -> Chinese, Traditional
         "lzz" => Tag::new(b"LAZ "), // Laz
-        // "mad" => Tag::new(b"MAD "), // Madurese -> Madura
-        // "mag" => Tag::new(b"MAG "), // Magahi
         "mai" => Tag::new(b"MTH "), // Maithili
         // "maj" => Tag::new(&[0; 4]), // Jalapa De Díaz Mazatec != Majang
         "mak" => Tag::new(b"MKR "), // Makasar
         "mam" => Tag::new(b"MAM "), // Mam
-        // "mam" => Tag::new(b"MYN "), // Mam -> Mayan
         "man" => Tag::new(b"MNK "), // Mandingo [macrolanguage] -> Maninka
         // "map" => Tag::new(&[0; 4]), // Austronesian  [collection] != Mapudungun
         // "maw" => Tag::new(&[0; 4]), // Mampruli != Marwari
         "max" => Tag::new(b"MLY "), // North Moluccan Malay -> Malay
-        // "max" => Tag::new(b"CPP "), // North Moluccan Malay -> Creoles
         "mbf" => Tag::new(b"CPP "), // Baba Malay -> Creoles
         // "mbn" => Tag::new(&[0; 4]), // Macaguán != Mbundu
-        // "mbo" => Tag::new(b"MBO "), // Mbo (Cameroon)
         // "mch" => Tag::new(&[0; 4]), // Maquiritari != Manchu
         "mcm" => Tag::new(b"CPP "), // Malaccan Creole Portuguese -> Creoles
         // "mcr" => Tag::new(&[0; 4]), // Menya != Moose Cree
         "mct" => Tag::new(b"BTI "), // Mengisa -> Beti
         // "mde" => Tag::new(&[0; 4]), // Maba (Chad) != Mende
         "mdf" => Tag::new(b"MOK "), // Moksha
-        // "mdr" => Tag::new(b"MDR "), // Mandar
         "mdy" => Tag::new(b"MLE "), // Male (Ethiopia)
         "men" => Tag::new(b"MDE "), // Mende (Sierra Leone)
         "meo" => Tag::new(b"MLY "), // Kedah Malay -> Malay
-        // "mer" => Tag::new(b"MER "), // Meru
-        // "mev" => Tag::new(b"MEV "), // Mano
         "mfa" => Tag::new(b"MFA "), // Pattani Malay
-        // "mfa" => Tag::new(b"MLY "), // Pattani Malay -> Malay
         "mfb" => Tag::new(b"MLY "), // Bangka -> Malay
         "mfe" => Tag::new(b"MFE "), // Morisyen
-        // "mfe" => Tag::new(b"CPP "), // Morisyen -> Creoles
         "mfp" => Tag::new(b"CPP "), // Makassar Malay -> Creoles
         "mg" => Tag::new(b"MLG "),  // Malagasy [macrolanguage]
         "mga" => Tag::new(b"SGA "), // Middle Irish (900-1200) -> Old Irish
@@ -1080,19 +873,15 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "mhv" => Tag::new(b"ARK "), // Arakanese(retired code) -> Rakhine
         "mi" => Tag::new(b"MRI "),  // Maori
         "min" => Tag::new(b"MIN "), // Minangkabau
-        // "min" => Tag::new(b"MLY "), // Minangkabau -> Malay
         // "miz" => Tag::new(&[0; 4]), // Coatzospan Mixtec != Mizo
         "mk" => Tag::new(b"MKD "),  // Macedonian
         "mkn" => Tag::new(b"CPP "), // Kupang Malay -> Creoles
         // "mkr" => Tag::new(&[0; 4]), // Malas != Makasar
         "mku" => Tag::new(b"MNK "), // Konyanka Maninka -> Maninka
-        // "mkw" => Tag::new(b"MKW "), // Kituba (Congo)
         "ml" => Tag::new(b"MAL "), // Malayalam -> Malayalam Traditional
-        // "ml" => Tag::new(b"MLR "),  // Malayalam -> Malayalam Reformed
         // "mle" => Tag::new(&[0; 4]), // Manambu != Male
         // "mln" => Tag::new(&[0; 4]), // Malango != Malinke
         "mlq" => Tag::new(b"MLN "), // Western Maninkakan -> Malinke
-        // "mlq" => Tag::new(b"MNK "), // Western Maninkakan -> Maninka
         // "mlr" => Tag::new(&[0; 4]), // Vame != Malayalam Reformed
         "mmr" => Tag::new(b"HMN "), // Western Xiangxi Miao -> Hmong
         "mn" => Tag::new(b"MNG "),  // Mongolian [macrolanguage]
@@ -1101,22 +890,16 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "mng" => Tag::new(&[0; 4]), // Eastern Mnong != Mongolian
         "mnh" => Tag::new(b"BAD0"), /* Mono (Democratic Republic of Congo)
                                       * -> Banda */
-        // "mni" => Tag::new(b"MNI "), // Manipuri
         "mnk" => Tag::new(b"MND "), // Mandinka
-        // "mnk" => Tag::new(b"MNK "), // Mandinka -> Maninka
         "mnp" => Tag::new(b"ZHS "), // Min Bei Chinese -> Chinese, Simplified
         "mns" => Tag::new(b"MAN "), // Mansi
         "mnw" => Tag::new(b"MON "), // Mon
-        // "mnw" => Tag::new(b"MONT"), // Mon -> Thailand Mon
         // "mnx" => Tag::new(&[0; 4]), // Manikion != Manx
         "mo" => Tag::new(b"MOL "),  // Moldavian(retired code)
-        // "mo" => Tag::new(b"ROM "),  // Moldavian(retired code) -> Romanian
         "mod" => Tag::new(b"CPP "), // Mobilian -> Creoles
-        // "moh" => Tag::new(b"MOH "), // Mohawk
         // "mok" => Tag::new(&[0; 4]), // Morori != Moksha
         "mop" => Tag::new(b"MYN "), // Mopán Maya -> Mayan
         // "mor" => Tag::new(&[0; 4]), // Moro != Moroccan
-        // "mos" => Tag::new(b"MOS "), // Mossi
         "mpe" => Tag::new(b"MAJ "), // Majang
         "mqg" => Tag::new(b"MLY "), // Kota Bangun Kutai Malay -> Malay
         "mr" => Tag::new(b"MAR "),  // Marathi
@@ -1126,7 +909,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "msc" => Tag::new(b"MNK "), // Sankaran Maninka -> Maninka
         "msh" => Tag::new(b"MLG "), // Masikoro Malagasy -> Malagasy
         "msi" => Tag::new(b"MLY "), // Sabah Malay -> Malay
-        // "msi" => Tag::new(b"CPP "), // Sabah Malay -> Creoles
         "mt" => Tag::new(b"MTS "),  // Maltese
         // "mth" => Tag::new(&[0; 4]), // Munggui != Maithili
         "mtr" => Tag::new(b"MAW "), // Mewari -> Marwari
@@ -1136,30 +918,22 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "mun" => Tag::new(&[0; 4]), // Munda  [collection] != Mundari
         "mup" => Tag::new(b"RAJ "), // Malvi -> Rajasthani
         "muq" => Tag::new(b"HMN "), // Eastern Xiangxi Miao -> Hmong
-        // "mus" => Tag::new(b"MUS "), // Creek -> Muscogee
         "mvb" => Tag::new(b"ATH "), // Mattole -> Athapaskan
         "mve" => Tag::new(b"MAW "), // Marwari (Pakistan)
         "mvf" => Tag::new(b"MNG "), // Peripheral Mongolian -> Mongolian
         "mwk" => Tag::new(b"MNK "), // Kita Maninkakan -> Maninka
-        // "mwl" => Tag::new(b"MWL "), // Mirandese
         "mwq" => Tag::new(b"QIN "), // Mün Chin -> Chin
         "mwr" => Tag::new(b"MAW "), // Marwari [macrolanguage]
         "mww" => Tag::new(b"MWW "), // Hmong Daw
-        // "mww" => Tag::new(b"HMN "), // Hmong Daw -> Hmong
         "my" => Tag::new(b"BRM "),  // Burmese
         "mym" => Tag::new(b"MEN "), // Me’en
-        // "myn" => Tag::new(b"MYN "), // Mayan  [collection]
         "myq" => Tag::new(b"MNK "), // Forest Maninka(retired code) -> Maninka
         "myv" => Tag::new(b"ERZ "), // Erzya
         "mzb" => Tag::new(b"BBR "), // Tumzabt -> Berber
-        // "mzn" => Tag::new(b"MZN "), // Mazanderani
         "mzs" => Tag::new(b"CPP "), // Macanese -> Creoles
         "na" => Tag::new(b"NAU "),  // Nauru -> Nauruan
         "nag" => Tag::new(b"NAG "), // Naga Pidgin -> Naga-Assamese
-        // "nag" => Tag::new(b"CPP "), // Naga Pidgin -> Creoles
-        // "nah" => Tag::new(b"NAH "), // Nahuatl  [collection]
         "nan" => Tag::new(b"ZHS "), // Min Nan Chinese -> Chinese, Simplified
-        // "nap" => Tag::new(b"NAP "), // Neapolitan
         // "nas" => Tag::new(&[0; 4]), // Naasioi != Naskapi
         "naz" => Tag::new(b"NAH "), // Coatepec Nahuatl -> Nahuatl
         "nb" => Tag::new(b"NOR "),  // Norwegian Bokmål -> Norwegian
@@ -1171,14 +945,10 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "ncx" => Tag::new(b"NAH "), // Central Puebla Nahuatl -> Nahuatl
         "nd" => Tag::new(b"NDB "),  // North Ndebele -> Ndebele
         // "ndb" => Tag::new(&[0; 4]), // Kenswei Nsei != Ndebele
-        // "ndc" => Tag::new(b"NDC "), // Ndau
         // "ndg" => Tag::new(&[0; 4]), // Ndengereko != Ndonga
-        // "nds" => Tag::new(b"NDS "), // Low Saxon
         "ne" => Tag::new(b"NEP "), // Nepali  [macrolanguage]
         "nef" => Tag::new(b"CPP "), // Nefamese -> Creoles
-        // "new" => Tag::new(b"NEW "), // Newari
         "ng" => Tag::new(b"NDG "), // Ndonga
-        // "nga" => Tag::new(b"NGA "), // Ngbaka
         "ngl" => Tag::new(b"LMW "), // Lomwe
         "ngm" => Tag::new(b"CPP "), // Ngatik Men's Creole -> Creoles
         "ngo" => Tag::new(b"SXT "), // Ngoni(retired code) -> Sutu
@@ -1203,7 +973,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "nhz" => Tag::new(b"NAH "), // Santa María La Alta Nahuatl -> Nahuatl
         "niq" => Tag::new(b"KAL "), // Nandi -> Kalenjin
         // "nis" => Tag::new(&[0; 4]), // Nimi != Nisi
-        // "niu" => Tag::new(b"NIU "), // Niuean
         "niv" => Tag::new(b"GIL "), // Gilyak
         "njt" => Tag::new(b"CPP "), // Ndyuka-Trio Pidgin -> Creoles
         "njz" => Tag::new(b"NIS "), // Nyishi -> Nisi
@@ -1219,38 +988,27 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "nnz" => Tag::new(b"BML "), // Nda'nda' -> Bamileke
         "no" => Tag::new(b"NOR "),  // Norwegian [macrolanguage]
         "nod" => Tag::new(b"NTA "), // Northern Thai -> Northern Tai
-        // "noe" => Tag::new(b"NOE "), // Nimadi
-        // "nog" => Tag::new(b"NOG "), // Nogai
-        // "nop" => Tag::new(b"NOP "), // Numanggang
-        // "nov" => Tag::new(b"NOV "), // Novial
         "npi" => Tag::new(b"NEP "), // Nepali
         "npl" => Tag::new(b"NAH "), // Southeastern Puebla Nahuatl -> Nahuatl
         "nqo" => Tag::new(b"NKO "), // N’Ko
         "nr" => Tag::new(b"NDB "),  // South Ndebele -> Ndebele
         "nsk" => Tag::new(b"NAS "), // Naskapi
         // "nsm" => Tag::new(&[0; 4]), // Sumi Naga != Northern Sami
-        // "nso" => Tag::new(b"NSO "), // Northern Sotho
         "nsu" => Tag::new(b"NAH "), // Sierra Negra Nahuatl -> Nahuatl
         // "nto" => Tag::new(&[0; 4]), // Ntomba != Esperanto
         "nue" => Tag::new(b"BAD0"), // Ngundu -> Banda
-        // "nuk" => Tag::new(b"NUK "), // Nuu-chah-nulth
         "nuu" => Tag::new(b"BAD0"), // Ngbundu -> Banda
         "nuz" => Tag::new(b"NAH "), // Tlamacazapa Nahuatl -> Nahuatl
         "nv" => Tag::new(b"NAV "),  // Navajo
-        // "nv" => Tag::new(b"ATH "),  // Navajo -> Athapaskan
         "nwe" => Tag::new(b"BML "), // Ngwe -> Bamileke
         "ny" => Tag::new(b"CHI "),  // Chichewa (Chewa, Nyanja)
         "nyd" => Tag::new(b"LUH "), // Nyore -> Luyia
-        // "nym" => Tag::new(b"NYM "), // Nyamwezi
         "nyn" => Tag::new(b"NKL "), // Nyankole
-        // "nza" => Tag::new(b"NZA "), // Tigon Mbembe -> Mbembe Tigon
         "oc" => Tag::new(b"OCI "), // Occitan (post 1500)
         "oj" => Tag::new(b"OJB "), // Ojibwa [macrolanguage] -> Ojibway
-        // "ojb" => Tag::new(b"OJB "), // Northwestern Ojibwa -> Ojibway
         "ojc" => Tag::new(b"OJB "), // Central Ojibwa -> Ojibway
         "ojg" => Tag::new(b"OJB "), // Eastern Ojibwa -> Ojibway
         "ojs" => Tag::new(b"OCR "), // Severn Ojibwa -> Oji-Cree
-        // "ojs" => Tag::new(b"OJB "), // Severn Ojibwa -> Ojibway
         "ojw" => Tag::new(b"OJB "), // Western Ojibwa -> Ojibway
         "okd" => Tag::new(b"IJO "), // Okodia -> Ijo
         "oki" => Tag::new(b"KAL "), // Okiek -> Kalenjin
@@ -1258,8 +1016,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
                                       * Korean Old Hangul */
         "okr" => Tag::new(b"IJO "), // Kirike -> Ijo
         "om" => Tag::new(b"ORO "),  // Oromo [macrolanguage]
-        // "one" => Tag::new(b"ONE "), // Oneida
-        // "ono" => Tag::new(b"ONO "), // Onondaga
         "onx" => Tag::new(b"CPP "), // Onin Based Pidgin -> Creoles
         "oor" => Tag::new(b"CPP "), // Oorlams -> Creoles
         "or" => Tag::new(b"ORI "),  // Odia  [macrolanguage]
@@ -1274,32 +1030,22 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "oua" => Tag::new(b"BBR "), // Tagargrent -> Berber
         "pa" => Tag::new(b"PAN "),  // Punjabi
         // "paa" => Tag::new(&[0; 4]), // Papuan  [collection] != Palestinian Aramaic
-        // "pag" => Tag::new(b"PAG "), // Pangasinan
         // "pal" => Tag::new(&[0; 4]), // Pahlavi != Pali
-        // "pam" => Tag::new(b"PAM "), // Pampanga -> Pampangan
         "pap" => Tag::new(b"PAP0"), // Papiamento -> Papiamentu
-        // "pap" => Tag::new(b"CPP "), // Papiamento -> Creoles
         // "pas" => Tag::new(&[0; 4]), // Papasena != Pashto
-        // "pau" => Tag::new(b"PAU "), // Palauan
         "pbt" => Tag::new(b"PAS "), // Southern Pashto -> Pashto
         "pbu" => Tag::new(b"PAS "), // Northern Pashto -> Pashto
-        // "pcc" => Tag::new(b"PCC "), // Bouyei
-        // "pcd" => Tag::new(b"PCD "), // Picard
         "pce" => Tag::new(b"PLG "), // Ruching Palaung -> Palaung
         "pck" => Tag::new(b"QIN "), // Paite Chin -> Chin
         "pcm" => Tag::new(b"CPP "), // Nigerian Pidgin -> Creoles
-        // "pdc" => Tag::new(b"PDC "), // Pennsylvania German
         "pdu" => Tag::new(b"KRN "), // Kayan -> Karen
         "pea" => Tag::new(b"CPP "), // Peranakan Indonesian -> Creoles
         "pel" => Tag::new(b"MLY "), // Pekal -> Malay
         "pes" => Tag::new(b"FAR "), // Iranian Persian -> Persian
         "pey" => Tag::new(b"CPP "), // Petjo -> Creoles
         "pga" => Tag::new(b"ARA "), // Sudanese Creole Arabic -> Arabic
-        // "pga" => Tag::new(b"CPP "), // Sudanese Creole Arabic -> Creoles
-        // "phk" => Tag::new(b"PHK "), // Phake
         "pi" => Tag::new(b"PAL "),  // Pali
         "pih" => Tag::new(b"PIH "), // Pitcairn-Norfolk -> Norfolk
-        // "pih" => Tag::new(b"CPP "), // Pitcairn-Norfolk -> Creoles
         // "pil" => Tag::new(&[0; 4]), // Yom != Filipino
         "pis" => Tag::new(b"CPP "), // Pijin -> Creoles
         "pkh" => Tag::new(b"QIN "), // Pankhu -> Chin
@@ -1312,21 +1058,14 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "plp" => Tag::new(b"PAP "), // Palpa(retired code)
         "plt" => Tag::new(b"MLG "), // Plateau Malagasy -> Malagasy
         "pml" => Tag::new(b"CPP "), // Lingua Franca -> Creoles
-        // "pms" => Tag::new(b"PMS "), // Piemontese
         "pmy" => Tag::new(b"CPP "), // Papuan Malay -> Creoles
-        // "pnb" => Tag::new(b"PNB "), // Western Panjabi
         "poc" => Tag::new(b"MYN "), // Poqomam -> Mayan
         "poh" => Tag::new(b"POH "), // Poqomchi' -> Pocomchi
-        // "poh" => Tag::new(b"MYN "), // Poqomchi' -> Mayan
-        // "pon" => Tag::new(b"PON "), // Pohnpeian
         "pov" => Tag::new(b"CPP "), // Upper Guinea Crioulo -> Creoles
         "ppa" => Tag::new(b"BAG "), // Pao(retired code) -> Baghelkhandi
         "pre" => Tag::new(b"CPP "), // Principense -> Creoles
-        // "pro" => Tag::new(b"PRO "), // Old Provençal (to 1500) ->
-        // Provençal / Old Provençal
         "prp" => Tag::new(b"GUJ "), // Parsi(retired code) -> Gujarati
         "prs" => Tag::new(b"DRI "), // Dari
-        // "prs" => Tag::new(b"FAR "), // Dari -> Persian
         "ps" => Tag::new(b"PAS "), // Pashto [macrolanguage]
         "pse" => Tag::new(b"MLY "), // Central Malay -> Malay
         "pst" => Tag::new(b"PAS "), // Central Pashto -> Pashto
@@ -1334,133 +1073,83 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "pub" => Tag::new(b"QIN "), // Purum -> Chin
         "puz" => Tag::new(b"QIN "), // Purum Naga(retired code) -> Chin
         "pwo" => Tag::new(b"PWO "), // Pwo Western Karen -> Western Pwo Karen
-        // "pwo" => Tag::new(b"KRN "), // Pwo Western Karen -> Karen
         "pww" => Tag::new(b"KRN "), // Pwo Northern Karen -> Karen
         "qu" => Tag::new(b"QUZ "),  // Quechua [macrolanguage]
         "qub" => Tag::new(b"QWH "), /* Huallaga Huánuco Quechua -> Quechua
                                       * (Peru) */
-        // "qub" => Tag::new(b"QUZ "), // Huallaga Huánuco Quechua -> Quechua
         "quc" => Tag::new(b"QUC "), // K’iche’
-        // "quc" => Tag::new(b"MYN "), // K'iche' -> Mayan
         "qud" => Tag::new(b"QVI "), /* Calderón Highland Quichua -> Quechua
                                       * (Ecuador) */
-        // "qud" => Tag::new(b"QUZ "), // Calderón Highland Quichua -> Quechua
         "quf" => Tag::new(b"QUZ "), // Lambayeque Quechua -> Quechua
         "qug" => Tag::new(b"QVI "), /* Chimborazo Highland Quichua ->
                                       * Quechua (Ecuador) */
-        // "qug" => Tag::new(b"QUZ "), // Chimborazo Highland Quichua -> Quechua
         "quh" => Tag::new(b"QUH "), /* South Bolivian Quechua -> Quechua
                                       * (Bolivia) */
-        // "quh" => Tag::new(b"QUZ "), // South Bolivian Quechua -> Quechua
         "quk" => Tag::new(b"QUZ "), // Chachapoyas Quechua -> Quechua
         "qul" => Tag::new(b"QUH "), /* North Bolivian Quechua -> Quechua
                                       * (Bolivia) */
-        // "qul" => Tag::new(b"QUZ "), // North Bolivian Quechua -> Quechua
         "qum" => Tag::new(b"MYN "), // Sipacapense -> Mayan
         "qup" => Tag::new(b"QVI "), /* Southern Pastaza Quechua -> Quechua
                                       * (Ecuador) */
-        // "qup" => Tag::new(b"QUZ "), // Southern Pastaza Quechua -> Quechua
         "qur" => Tag::new(b"QWH "), /* Yanahuanca Pasco Quechua -> Quechua
                                       * (Peru) */
-        // "qur" => Tag::new(b"QUZ "), // Yanahuanca Pasco Quechua -> Quechua
         "qus" => Tag::new(b"QUH "), /* Santiago del Estero Quichua ->
                                       * Quechua (Bolivia) */
-        // "qus" => Tag::new(b"QUZ "), // Santiago del Estero Quichua -> Quechua
         "quv" => Tag::new(b"MYN "), // Sacapulteco -> Mayan
         "quw" => Tag::new(b"QVI "), /* Tena Lowland Quichua -> Quechua
                                       * (Ecuador) */
-        // "quw" => Tag::new(b"QUZ "), // Tena Lowland Quichua -> Quechua
         "qux" => Tag::new(b"QWH "), // Yauyos Quechua -> Quechua (Peru)
-        // "qux" => Tag::new(b"QUZ "), // Yauyos Quechua -> Quechua
         "quy" => Tag::new(b"QUZ "), // Ayacucho Quechua -> Quechua
-        // "quz" => Tag::new(b"QUZ "), // Cusco Quechua -> Quechua
         "qva" => Tag::new(b"QWH "), // Ambo-Pasco Quechua -> Quechua (Peru)
-        // "qva" => Tag::new(b"QUZ "), // Ambo-Pasco Quechua -> Quechua
         "qvc" => Tag::new(b"QUZ "), // Cajamarca Quechua -> Quechua
         "qve" => Tag::new(b"QUZ "), // Eastern Apurímac Quechua -> Quechua
         "qvh" => Tag::new(b"QWH "), /* Huamalíes-Dos de Mayo Huánuco Quechua
                                       * -> Quechua (Peru) */
-        // "qvh" => Tag::new(b"QUZ "), // Huamalíes-Dos de Mayo Huánuco Quechua
-        // -> Quechua
         "qvi" => Tag::new(b"QVI "), /* Imbabura Highland Quichua -> Quechua
                                       * (Ecuador) */
-        // "qvi" => Tag::new(b"QUZ "), // Imbabura Highland Quichua -> Quechua
         "qvj" => Tag::new(b"QVI "), /* Loja Highland Quichua -> Quechua
                                       * (Ecuador) */
-        // "qvj" => Tag::new(b"QUZ "), // Loja Highland Quichua -> Quechua
         "qvl" => Tag::new(b"QWH "), /* Cajatambo North Lima Quechua ->
                                       * Quechua (Peru) */
-        // "qvl" => Tag::new(b"QUZ "), // Cajatambo North Lima Quechua ->
-        // Quechua
         "qvm" => Tag::new(b"QWH "), /* Margos-Yarowilca-Lauricocha Quechua
                                       * -> Quechua (Peru) */
-        // "qvm" => Tag::new(b"QUZ "), // Margos-Yarowilca-Lauricocha Quechua ->
-        // Quechua
         "qvn" => Tag::new(b"QWH "), // North Junín Quechua -> Quechua (Peru)
-        // "qvn" => Tag::new(b"QUZ "), // North Junín Quechua -> Quechua
         "qvo" => Tag::new(b"QVI "), /* Napo Lowland Quechua -> Quechua
                                       * (Ecuador) */
-        // "qvo" => Tag::new(b"QUZ "), // Napo Lowland Quechua -> Quechua
         "qvp" => Tag::new(b"QWH "), // Pacaraos Quechua -> Quechua (Peru)
-        // "qvp" => Tag::new(b"QUZ "), // Pacaraos Quechua -> Quechua
         "qvs" => Tag::new(b"QUZ "), // San Martín Quechua -> Quechua
         "qvw" => Tag::new(b"QWH "), // Huaylla Wanca Quechua -> Quechua (Peru)
-        // "qvw" => Tag::new(b"QUZ "), // Huaylla Wanca Quechua -> Quechua
         "qvz" => Tag::new(b"QVI "), /* Northern Pastaza Quichua -> Quechua
                                       * (Ecuador) */
-        // "qvz" => Tag::new(b"QUZ "), // Northern Pastaza Quichua -> Quechua
         "qwa" => Tag::new(b"QWH "), // Corongo Ancash Quechua -> Quechua (Peru)
-        // "qwa" => Tag::new(b"QUZ "), // Corongo Ancash Quechua -> Quechua
         "qwc" => Tag::new(b"QUZ "), // Classical Quechua -> Quechua
         "qwh" => Tag::new(b"QWH "), // Huaylas Ancash Quechua -> Quechua (Peru)
-        // "qwh" => Tag::new(b"QUZ "), // Huaylas Ancash Quechua -> Quechua
         "qws" => Tag::new(b"QWH "), // Sihuas Ancash Quechua -> Quechua (Peru)
-        // "qws" => Tag::new(b"QUZ "), // Sihuas Ancash Quechua -> Quechua
         "qwt" => Tag::new(b"ATH "), // Kwalhioqua-Tlatskanai -> Athapaskan
         "qxa" => Tag::new(b"QWH "), /* Chiquián Ancash Quechua -> Quechua
                                       * (Peru) */
-        // "qxa" => Tag::new(b"QUZ "), // Chiquián Ancash Quechua -> Quechua
         "qxc" => Tag::new(b"QWH "), // Chincha Quechua -> Quechua (Peru)
-        // "qxc" => Tag::new(b"QUZ "), // Chincha Quechua -> Quechua
         "qxh" => Tag::new(b"QWH "), // Panao Huánuco Quechua -> Quechua (Peru)
-        // "qxh" => Tag::new(b"QUZ "), // Panao Huánuco Quechua -> Quechua
         "qxl" => Tag::new(b"QVI "), /* Salasaca Highland Quichua -> Quechua
                                       * (Ecuador) */
-        // "qxl" => Tag::new(b"QUZ "), // Salasaca Highland Quichua -> Quechua
         "qxn" => Tag::new(b"QWH "), /* Northern Conchucos Ancash Quechua ->
                                       * Quechua (Peru) */
-        // "qxn" => Tag::new(b"QUZ "), // Northern Conchucos Ancash Quechua ->
-        // Quechua
         "qxo" => Tag::new(b"QWH "), /* Southern Conchucos Ancash Quechua ->
                                       * Quechua (Peru) */
-        // "qxo" => Tag::new(b"QUZ "), // Southern Conchucos Ancash Quechua ->
-        // Quechua
         "qxp" => Tag::new(b"QUZ "), // Puno Quechua -> Quechua
         "qxr" => Tag::new(b"QVI "), /* Cañar Highland Quichua -> Quechua
                                       * (Ecuador) */
-        // "qxr" => Tag::new(b"QUZ "), // Cañar Highland Quichua -> Quechua
         "qxt" => Tag::new(b"QWH "), /* Santa Ana de Tusi Pasco Quechua ->
                                       * Quechua (Peru) */
-        // "qxt" => Tag::new(b"QUZ "), // Santa Ana de Tusi Pasco Quechua ->
-        // Quechua
         "qxu" => Tag::new(b"QUZ "), // Arequipa-La Unión Quechua -> Quechua
         "qxw" => Tag::new(b"QWH "), // Jauja Wanca Quechua -> Quechua (Peru)
-        // "qxw" => Tag::new(b"QUZ "), // Jauja Wanca Quechua -> Quechua
         "rag" => Tag::new(b"LUH "), // Logooli -> Luyia
-        // "raj" => Tag::new(b"RAJ "), // Rajasthani [macrolanguage]
         "ral" => Tag::new(b"QIN "), // Ralte -> Chin
-        // "rar" => Tag::new(b"RAR "), // Rarotongan
         "rbb" => Tag::new(b"PLG "), // Rumai Palaung -> Palaung
         "rbl" => Tag::new(b"BIK "), // Miraya Bikol -> Bikol
         "rcf" => Tag::new(b"CPP "), // Réunion Creole French -> Creoles
-        // "rej" => Tag::new(b"REJ "), // Rejang
-        // "rhg" => Tag::new(b"RHG "), // Rohingya
-        // "ria" => Tag::new(b"RIA "), // Riang (India)
         "rif" => Tag::new(b"RIF "), // Tarifit
-        // "rif" => Tag::new(b"BBR "), // Tarifit -> Berber
-        // "rit" => Tag::new(b"RIT "), // Ritharrngu -> Ritarungo
         "rki" => Tag::new(b"ARK "), // Rakhine
-        // "rkw" => Tag::new(b"RKW "), // Arakwal
         "rm" => Tag::new(b"RMS "),  // Romansh
         "rmc" => Tag::new(b"ROY "), // Carpathian Romani -> Romany
         "rmf" => Tag::new(b"ROY "), // Kalo Finnish Romani -> Romany
@@ -1470,78 +1159,56 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "rms" => Tag::new(&[0; 4]), // Romanian Sign Language != Romansh
         "rmw" => Tag::new(b"ROY "), // Welsh Romani -> Romany
         "rmy" => Tag::new(b"RMY "), // Vlax Romani
-        // "rmy" => Tag::new(b"ROY "), // Vlax Romani -> Romany
         "rmz" => Tag::new(b"ARK "), // Marma -> Rakhine
         "rn" => Tag::new(b"RUN "),  // Rundi
         "ro" => Tag::new(b"ROM "),  // Romanian
         "rom" => Tag::new(b"ROY "), // Romany [macrolanguage]
         "rop" => Tag::new(b"CPP "), // Kriol -> Creoles
         "rtc" => Tag::new(b"QIN "), // Rungtu Chin -> Chin
-        // "rtm" => Tag::new(b"RTM "), // Rotuman
         "ru" => Tag::new(b"RUS "),  // Russian
         "rue" => Tag::new(b"RSY "), // Rusyn
-        // "rup" => Tag::new(b"RUP "), // Aromanian
         "rw" => Tag::new(b"RUA "),  // Kinyarwanda
         "rwr" => Tag::new(b"MAW "), // Marwari (India)
         "sa" => Tag::new(b"SAN "),  // Sanskrit [macrolanguage]
         // "sad" => Tag::new(&[0; 4]), // Sandawe != Sadri
         "sah" => Tag::new(b"YAK "), // Yakut -> Sakha
         "sam" => Tag::new(b"PAA "), // Samaritan Aramaic -> Palestinian Aramaic
-        // "sas" => Tag::new(b"SAS "), // Sasak
-        // "sat" => Tag::new(b"SAT "), // Santali
         // "say" => Tag::new(&[0; 4]), // Saya != Sayisi
         "sc" => Tag::new(b"SRD "),  // Sardinian [macrolanguage]
         "scf" => Tag::new(b"CPP "), // San Miguel Creole French -> Creoles
         "sch" => Tag::new(b"QIN "), // Sakachep -> Chin
         "sci" => Tag::new(b"CPP "), // Sri Lankan Creole Malay -> Creoles
         "sck" => Tag::new(b"SAD "), // Sadri
-        // "scn" => Tag::new(b"SCN "), // Sicilian
-        // "sco" => Tag::new(b"SCO "), // Scots
         "scs" => Tag::new(b"SCS "), // North Slavey
-        // "scs" => Tag::new(b"SLA "), // North Slavey -> Slavey
-        // "scs" => Tag::new(b"ATH "), // North Slavey -> Athapaskan
         "sd" => Tag::new(b"SND "),  // Sindhi
         "sdc" => Tag::new(b"SRD "), // Sassarese Sardinian -> Sardinian
         "sdh" => Tag::new(b"KUR "), // Southern Kurdish -> Kurdish
         "sdn" => Tag::new(b"SRD "), // Gallurese Sardinian -> Sardinian
         "sds" => Tag::new(b"BBR "), // Sened -> Berber
         "se" => Tag::new(b"NSM "),  // Northern Sami
-        // "see" => Tag::new(b"SEE "), // Seneca
         "seh" => Tag::new(b"SNA "), // Sena
         "sek" => Tag::new(b"ATH "), // Sekani -> Athapaskan
-        // "sel" => Tag::new(b"SEL "), // Selkup
         "sez" => Tag::new(b"QIN "), // Senthang Chin -> Chin
         "sfm" => Tag::new(b"SFM "), // Small Flowery Miao
-        // "sfm" => Tag::new(b"HMN "), // Small Flowery Miao -> Hmong
         "sg" => Tag::new(b"SGO "), // Sango
-        // "sga" => Tag::new(b"SGA "), // Old Irish (to 900)
         "sgc" => Tag::new(b"KAL "), // Kipsigis -> Kalenjin
         // "sgo" => Tag::new(&[0; 4]), // Songa(retired code) != Sango
-        // "sgs" => Tag::new(b"SGS "), // Samogitian
         "sgw" => Tag::new(b"CHG "), // Sebat Bet Gurage -> Chaha Gurage
         "sh" => Tag::new(b"BOS "),  /* Serbo-Croatian [macrolanguage] ->
                                       * Bosnian */
-        // "sh" => Tag::new(b"HRV "),  // Serbo-Croatian [macrolanguage] ->
-        // Croatian "sh" => Tag::new(b"SRB "),  // Serbo-Croatian
         // [macrolanguage] -> Serbian
         "shi" => Tag::new(b"SHI "), // Tachelhit
-        // "shi" => Tag::new(b"BBR "), // Tachelhit -> Berber
         "shl" => Tag::new(b"QIN "), // Shendu -> Chin
-        // "shn" => Tag::new(b"SHN "), // Shan
         "shu" => Tag::new(b"ARA "), // Chadian Arabic -> Arabic
         "shy" => Tag::new(b"BBR "), // Tachawit -> Berber
         "si" => Tag::new(b"SNH "),  // Sinhala (Sinhalese)
         // "sib" => Tag::new(&[0; 4]), // Sebop != Sibe
-        // "sid" => Tag::new(b"SID "), // Sidamo
         // "sig" => Tag::new(&[0; 4]), // Paasaal != Silte Gurage
         "siz" => Tag::new(b"BBR "), // Siwi -> Berber
-        // "sja" => Tag::new(b"SJA "), // Epena
         "sjc" => Tag::new(b"ZHS "), // Shaojiang Chinese -> Chinese, Simplified
         "sjd" => Tag::new(b"KSM "), // Kildin Sami
-        // "sje" => Tag::new(b"SJE "), // Pite Sami
         "sjo" => Tag::new(b"SIB "), // Xibe -> Sibe
         "sjs" => Tag::new(b"BBR "), // Senhaja De Srair -> Berber
-        // "sju" => Tag::new(b"SJU "), // Ume Sami
         "sk" => Tag::new(b"SKY "),  // Slovak
         "skg" => Tag::new(b"MLG "), // Sakalava Malagasy -> Malagasy
         "skr" => Tag::new(b"SRK "), // Saraiki
@@ -1561,10 +1228,8 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "sn" => Tag::new(b"SNA0"),  // Shona
         "snb" => Tag::new(b"IBA "), // Sebuyau(retired code) -> Iban
         // "snh" => Tag::new(&[0; 4]), // Shinabo(retired code) != Sinhala (Sinhalese)
-        // "snk" => Tag::new(b"SNK "), // Soninke
         "so" => Tag::new(b"SML "),  // Somali
         // "sog" => Tag::new(&[0; 4]), // Sogdian != Sodo Gurage
-        // "sop" => Tag::new(b"SOP "), // Songe
         "spv" => Tag::new(b"ORI "), // Sambalpuri -> Odia
         "spy" => Tag::new(b"KAL "), // Sabaot -> Kalenjin
         "sq" => Tag::new(b"SQI "),  // Albanian [macrolanguage]
@@ -1575,7 +1240,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "srm" => Tag::new(b"CPP "), // Saramaccan -> Creoles
         "srn" => Tag::new(b"CPP "), // Sranan Tongo -> Creoles
         "sro" => Tag::new(b"SRD "), // Campidanese Sardinian -> Sardinian
-        // "srr" => Tag::new(b"SRR "), // Serer
         "srs" => Tag::new(b"ATH "), // Sarsi -> Athapaskan
         "ss" => Tag::new(b"SWZ "),  // Swati
         "ssh" => Tag::new(b"ARA "), // Shihhi Arabic -> Arabic
@@ -1583,15 +1247,11 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "ssm" => Tag::new(&[0; 4]), // Semnam != Southern Sami
         "st" => Tag::new(b"SOT "),  // Southern Sotho
         "sta" => Tag::new(b"CPP "), // Settla -> Creoles
-        // "stq" => Tag::new(b"STQ "), // Saterfriesisch -> Saterland
-        // Frisian "str" => Tag::new(b"STR "), // Straits Salish
         "stv" => Tag::new(b"SIG "), // Silt'e -> Silte Gurage
         "su" => Tag::new(b"SUN "),  // Sundanese
-        // "suk" => Tag::new(b"SUK "), // Sukuma
         "suq" => Tag::new(b"SUR "), // Suri
         // "sur" => Tag::new(&[0; 4]), // Mwaghavul != Suri
         "sv" => Tag::new(b"SVE "),  // Swedish
-        // "sva" => Tag::new(b"SVA "), // Svan
         "svc" => Tag::new(b"CPP "), // Vincentian Creole English -> Creoles
         // "sve" => Tag::new(&[0; 4]), // Serili != Swedish
         "sw" => Tag::new(b"SWK "),  // Swahili  [macrolanguage]
@@ -1601,21 +1261,13 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         // "swk" => Tag::new(&[0; 4]), // Malawi Sena != Swahili
         "swn" => Tag::new(b"BBR "), // Sawknah -> Berber
         "swv" => Tag::new(b"MAW "), // Shekhawati -> Marwari
-        // "sxu" => Tag::new(b"SXU "), // Upper Saxon
         "syc" => Tag::new(b"SYR "), // Classical Syriac -> Syriac
-        // "syl" => Tag::new(b"SYL "), // Sylheti
-        // "syr" => Tag::new(b"SYR "), // Syriac [macrolanguage]
-        // "szl" => Tag::new(b"SZL "), // Silesian
         "ta" => Tag::new(b"TAM "),  // Tamil
         "taa" => Tag::new(b"ATH "), // Lower Tanana -> Athapaskan
-        // "tab" => Tag::new(b"TAB "), // Tabassaran -> Tabasaran
         // "taj" => Tag::new(&[0; 4]), // Eastern Tamang != Tajiki
         "taq" => Tag::new(b"TAQ "), // Tamasheq
-        // "taq" => Tag::new(b"TMH "), // Tamasheq -> Tamashek
-        // "taq" => Tag::new(b"BBR "), // Tamasheq -> Berber
         "tas" => Tag::new(b"CPP "), // Tay Boi -> Creoles
         "tau" => Tag::new(b"ATH "), // Upper Tanana -> Athapaskan
-        // "tbv" => Tag::new(b"TBV "), // Tobo
         "tcb" => Tag::new(b"ATH "), // Tanacross -> Athapaskan
         "tce" => Tag::new(b"ATH "), // Southern Tutchone -> Athapaskan
         "tch" => Tag::new(b"CPP "), /* Turks And Caicos Creole English ->
@@ -1624,13 +1276,10 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "tcs" => Tag::new(b"CPP "), // Torres Strait Creole -> Creoles
         "tcy" => Tag::new(b"TUL "), // Tulu
         "tcz" => Tag::new(b"QIN "), // Thado Chin -> Chin
-        // "tdc" => Tag::new(b"TDC "), // Emberá-Tadó
-        // "tdd" => Tag::new(b"TDD "), // Tai Nüa -> Dehong Dai
         "tdx" => Tag::new(b"MLG "), // Tandroy-Mahafaly Malagasy -> Malagasy
         "te" => Tag::new(b"TEL "),  // Telugu
         "tec" => Tag::new(b"KAL "), // Terik -> Kalenjin
         "tem" => Tag::new(b"TMN "), // Timne -> Temne
-        // "tet" => Tag::new(b"TET "), // Tetum
         "tez" => Tag::new(b"BBR "), // Tetserret -> Berber
         "tfn" => Tag::new(b"ATH "), // Tanaina -> Athapaskan
         "tg" => Tag::new(b"TAJ "),  // Tajik -> Tajiki
@@ -1641,36 +1290,25 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "tgx" => Tag::new(b"ATH "), // Tagish -> Athapaskan
         // "tgy" => Tag::new(&[0; 4]), // Togoyo != Tigrinya
         "th" => Tag::new(b"THA "),  // Thai
-        // "thp" => Tag::new(b"THP "), // Thompson
         "tht" => Tag::new(b"ATH "), // Tahltan -> Athapaskan
         "thv" => Tag::new(b"THV "), // Tahaggart Tamahaq
-        // "thv" => Tag::new(b"TMH "), // Tahaggart Tamahaq -> Tamashek
-        // "thv" => Tag::new(b"BBR "), // Tahaggart Tamahaq -> Berber
         "thz" => Tag::new(b"THZ "), // Tayart Tamajeq
-        // "thz" => Tag::new(b"TMH "), // Tayart Tamajeq -> Tamashek
-        // "thz" => Tag::new(b"BBR "), // Tayart Tamajeq -> Berber
         "ti" => Tag::new(b"TGY "),  // Tigrinya
         "tia" => Tag::new(b"BBR "), // Tidikelt Tamazight -> Berber
         "tig" => Tag::new(b"TGR "), // Tigre
-        // "tiv" => Tag::new(b"TIV "), // Tiv
-        // "tjl" => Tag::new(b"TJL "), // Tai Laing
         "tjo" => Tag::new(b"BBR "), // Temacine Tamazight -> Berber
         "tk" => Tag::new(b"TKM "),  // Turkmen
         "tkg" => Tag::new(b"MLG "), // Tesaka Malagasy -> Malagasy
         // "tkm" => Tag::new(&[0; 4]), // Takelma != Turkmen
         "tl" => Tag::new(b"TGL "),  // Tagalog
-        // "tli" => Tag::new(b"TLI "), // Tlingit
-        // "tly" => Tag::new(b"TLY "), // Talysh
         "tmg" => Tag::new(b"CPP "), // Ternateño -> Creoles
         "tmh" => Tag::new(b"TMH "), // Tamashek [macrolanguage]
-        // "tmh" => Tag::new(b"BBR "), // Tamashek [macrolanguage] -> Berber
         // "tmn" => Tag::new(&[0; 4]), // Taman (Indonesia) != Temne
         "tmw" => Tag::new(b"MLY "), // Temuan -> Malay
         "tn" => Tag::new(b"TNA "),  // Tswana
         // "tna" => Tag::new(&[0; 4]), // Tacana != Tswana
         // "tne" => Tag::new(&[0; 4]), Tinoc Kallahan(retired code) != Tundra Enets
         "tnf" => Tag::new(b"DRI "), // Tangshewi(retired code) -> Dari
-        // "tnf" => Tag::new(b"FAR "), // Tangshewi(retired code) -> Persian
         // "tng" => Tag::new(&[0; 4]), // Tobanga != Tonga
         "to" => Tag::new(b"TGN "),  // Tonga (Tonga Islands) -> Tongan
         "tod" => Tag::new(b"TOD0"), // Toma
@@ -1679,51 +1317,36 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "tol" => Tag::new(b"ATH "), // Tolowa -> Athapaskan
         "tor" => Tag::new(b"BAD0"), // Togbo-Vara Banda -> Banda
         "tpi" => Tag::new(b"TPI "), // Tok Pisin
-        // "tpi" => Tag::new(b"CPP "), // Tok Pisin -> Creoles
         "tr" => Tag::new(b"TRK "),  // Turkish
         "trf" => Tag::new(b"CPP "), // Trinidadian Creole English -> Creoles
         // "trk" => Tag::new(&[0; 4]), // Turkic  [collection] != Turkish
         "tru" => Tag::new(b"TUA "), // Turoyo -> Turoyo Aramaic
-        // "tru" => Tag::new(b"SYR "), // Turoyo -> Syriac
         "ts" => Tag::new(b"TSG "),  // Tsonga
         // "tsg" => Tag::new(&[0; 4]), // Tausug != Tsonga
-        // "tsj" => Tag::new(b"TSJ "), // Tshangla
         "tt" => Tag::new(b"TAT "),  // Tatar
         "ttc" => Tag::new(b"MYN "), // Tektiteko -> Mayan
         "ttm" => Tag::new(b"ATH "), // Northern Tutchone -> Athapaskan
         "ttq" => Tag::new(b"TTQ "), // Tawallammat Tamajaq
-        // "ttq" => Tag::new(b"TMH "), // Tawallammat Tamajaq -> Tamashek
-        // "ttq" => Tag::new(b"BBR "), // Tawallammat Tamajaq -> Berber
         // "tua" => Tag::new(&[0; 4]), // Wiarumus != Turoyo Aramaic
         // "tul" => Tag::new(&[0; 4]), // Tula != Tulu
-        // "tum" => Tag::new(b"TUM "), // Tumbuka
-        // "tus" => Tag::new(b"TUS "), // Tuscarora
         "tuu" => Tag::new(b"ATH "), // Tututni -> Athapaskan
         // "tuv" => Tag::new(&[0; 4]), // Turkana != Tuvin
         "tuy" => Tag::new(b"KAL "), // Tugen -> Kalenjin
-        // "tvl" => Tag::new(b"TVL "), // Tuvalu
         "tvy" => Tag::new(b"CPP "), // Timor Pidgin -> Creoles
         "tw" => Tag::new(b"TWI "),  // Twi
-        // "tw" => Tag::new(b"AKA "),  // Twi -> Akan
         "txc" => Tag::new(b"ATH "), // Tsetsaut -> Athapaskan
         "txy" => Tag::new(b"MLG "), // Tanosy Malagasy -> Malagasy
         "ty" => Tag::new(b"THT "),  // Tahitian
         "tyv" => Tag::new(b"TUV "), // Tuvinian -> Tuvin
-        // "tyz" => Tag::new(b"TYZ "), // Tày
         "tzh" => Tag::new(b"MYN "), // Tzeltal -> Mayan
         "tzj" => Tag::new(b"MYN "), // Tz'utujil -> Mayan
         "tzm" => Tag::new(b"TZM "), // Central Atlas Tamazight -> Tamazight
-        // "tzm" => Tag::new(b"BBR "), // Central Atlas Tamazight -> Berber
         "tzo" => Tag::new(b"TZO "), // Tzotzil
-        // "tzo" => Tag::new(b"MYN "), // Tzotzil -> Mayan
         "ubl" => Tag::new(b"BIK "), // Buhi'non Bikol -> Bikol
-        // "udi" => Tag::new(b"UDI "), // Udi
-        // "udm" => Tag::new(b"UDM "), // Udmurt
         "ug" => Tag::new(b"UYG "),  // Uyghur
         "uk" => Tag::new(b"UKR "),  // Ukrainian
         "uki" => Tag::new(b"KUI "), // Kui (India)
         "uln" => Tag::new(b"CPP "), // Unserdeutsch -> Creoles
-        // "umb" => Tag::new(b"UMB "), // Umbundu
         "unr" => Tag::new(b"MUN "), // Mundari
         "ur" => Tag::new(b"URD "),  // Urdu
         "urk" => Tag::new(b"MLY "), // Urak Lawoi' -> Malay
@@ -1733,7 +1356,6 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "uzs" => Tag::new(b"UZB "), // Southern Uzbek -> Uzbek
         "vap" => Tag::new(b"QIN "), // Vaiphei -> Chin
         "ve" => Tag::new(b"VEN "),  // Venda
-        // "vec" => Tag::new(b"VEC "), // Venetian
         "vi" => Tag::new(b"VIT "),  // Vietnamese
         "vic" => Tag::new(b"CPP "), // Virgin Islands Creole English -> Creoles
         // "vit" => Tag::new(&[0; 4]), // Viti != Vietnamese
@@ -1744,17 +1366,11 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "vmw" => Tag::new(b"MAK "), // Makhuwa
         "vo" => Tag::new(b"VOL "),  // Volapük
         "vro" => Tag::new(b"VRO "), // Võro
-        // "vro" => Tag::new(b"ETI "), // Võro -> Estonian
         "vsn" => Tag::new(b"SAN "), // Vedic Sanskrit -> Sanskrit
         "wa" => Tag::new(b"WLN "),  // Walloon
         // "wag" => Tag::new(&[0; 4]), // Wa'ema != Wagdi
-        // "war" => Tag::new(b"WAR "), // Waray (Philippines) ->
-        // Waray-Waray "wbl" => Tag::new(b"WBL "), // Wakhi
         "wbm" => Tag::new(b"WA  "), // Wa
         "wbr" => Tag::new(b"WAG "), // Wagdi
-        // "wbr" => Tag::new(b"RAJ "), // Wagdi -> Rajasthani
-        // "wci" => Tag::new(b"WCI "), // Waci Gbe
-        // "wdt" => Tag::new(b"WDT "), // Wendat
         "wea" => Tag::new(b"KRN "), // Wewaw -> Karen
         "wes" => Tag::new(b"CPP "), // Cameroon Pidgin -> Creoles
         "weu" => Tag::new(b"QIN "), // Rawngtu Chin -> Chin
@@ -1765,67 +1381,46 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "wo" => Tag::new(b"WLF "),  // Wolof
         "wry" => Tag::new(b"MAW "), // Merwari -> Marwari
         "wsg" => Tag::new(b"GON "), // Adilabad Gondi -> Gondi
-        // "wtm" => Tag::new(b"WTM "), // Mewati
         "wuu" => Tag::new(b"ZHS "), // Wu Chinese -> Chinese, Simplified
         "wya" => Tag::new(b"WDT "), // Wyandot(retired code) -> Wendat
-        // "wya" => Tag::new(b"WYN "), // Wyandot(retired code)
-        // "wyn" => Tag::new(b"WYN "), // Wyandot
         "xal" => Tag::new(b"KLM "), // Kalmyk
-        // "xal" => Tag::new(b"TOD "), // Kalmyk -> Todo
         "xan" => Tag::new(b"SEK "), // Xamtanga -> Sekota
         // "xbd" => Tag::new(&[0; 4]), // Bindal != Lü
         "xh" => Tag::new(b"XHS "),  // Xhosa
-        // "xjb" => Tag::new(b"XJB "), // Minjungbal -> Minjangbal
-        // "xkf" => Tag::new(b"XKF "), // Khengkha
         "xmg" => Tag::new(b"BML "), // Mengaka -> Bamileke
         "xmm" => Tag::new(b"MLY "), // Manado Malay -> Malay
-        // "xmm" => Tag::new(b"CPP "), // Manado Malay -> Creoles
         "xmv" => Tag::new(b"MLG "), // Antankarana Malagasy -> Malagasy
         "xmw" => Tag::new(b"MLG "), // Tsimihety Malagasy -> Malagasy
         "xnj" => Tag::new(b"SXT "), // Ngoni (Tanzania) -> Sutu
         "xnq" => Tag::new(b"SXT "), // Ngoni (Mozambique) -> Sutu
         "xnr" => Tag::new(b"DGR "), // Kangri -> Dogri (macrolanguage)
-        // "xog" => Tag::new(b"XOG "), // Soga
         "xpe" => Tag::new(b"XPE "), // Liberia Kpelle -> Kpelle (Liberia)
-        // "xpe" => Tag::new(b"KPL "), // Liberia Kpelle -> Kpelle
         "xsl" => Tag::new(b"SSL "), // South Slavey
-        // "xsl" => Tag::new(b"SLA "), // South Slavey -> Slavey
-        // "xsl" => Tag::new(b"ATH "), // South Slavey -> Athapaskan
         "xst" => Tag::new(b"SIG "), // Silt'e(retired code) -> Silte Gurage
-        // "xub" => Tag::new(b"XUB "), // Betta Kurumba -> Bette Kuruma
-        // "xuj" => Tag::new(b"XUJ "), // Jennu Kurumba -> Jennu Kuruma
         "xup" => Tag::new(b"ATH "), // Upper Umpqua -> Athapaskan
         "xwo" => Tag::new(b"TOD "), // Written Oirat -> Todo
         "yaj" => Tag::new(b"BAD0"), // Banda-Yangere -> Banda
         // "yak" => Tag::new(&[0; 4]), // Yakama != Sakha
-        // "yao" => Tag::new(b"YAO "), // Yao
-        // "yap" => Tag::new(b"YAP "), // Yapese
         // "yba" => Tag::new(&[0; 4]), // Yala != Yoruba
         "ybb" => Tag::new(b"BML "), // Yemba -> Bamileke
         "ybd" => Tag::new(b"ARK "), // Yangbye(retired code) -> Rakhine
         "ycr" => Tag::new(b"CPP "), // Yilan Creole -> Creoles
         "ydd" => Tag::new(b"JII "), // Eastern Yiddish -> Yiddish
-        // "ygp" => Tag::new(b"YGP "), // Gepo
         "yi" => Tag::new(b"JII "), // Yiddish [macrolanguage]
         "yih" => Tag::new(b"JII "), // Western Yiddish -> Yiddish
         // "yim" => Tag::new(&[0; 4]), // Yimchungru Naga != Yi Modern
-        // "yna" => Tag::new(b"YNA "), // Aluo
         "yo" => Tag::new(b"YBA "),  // Yoruba
         "yos" => Tag::new(b"QIN "), // Yos(retired code) -> Chin
         "yua" => Tag::new(b"MYN "), // Yucateco -> Mayan
         "yue" => Tag::new(b"ZHH "), /* Yue Chinese -> Chinese, Traditional,
                                       * Hong Kong SAR */
-        // "yuf" => Tag::new(b"YUF "), // Havasupai-Walapai-Yavapai
-        // "ywq" => Tag::new(b"YWQ "), // Wuding-Luquan Yi
         "za" => Tag::new(b"ZHA "), // Zhuang [macrolanguage]
         "zch" => Tag::new(b"ZHA "), // Central Hongshuihe Zhuang -> Zhuang
         "zdj" => Tag::new(b"CMR "), // Ngazidja Comorian -> Comorian
-        // "zea" => Tag::new(b"ZEA "), // Zeeuws -> Zealandic
         "zeh" => Tag::new(b"ZHA "), // Eastern Hongshuihe Zhuang -> Zhuang
         "zen" => Tag::new(b"BBR "), // Zenaga -> Berber
         "zgb" => Tag::new(b"ZHA "), // Guibei Zhuang -> Zhuang
         "zgh" => Tag::new(b"ZGH "), // Standard Moroccan Tamazight
-        // "zgh" => Tag::new(b"BBR "), // Standard Moroccan Tamazight -> Berber
         "zgm" => Tag::new(b"ZHA "), // Minz Zhuang -> Zhuang
         "zgn" => Tag::new(b"ZHA "), // Guibian Zhuang -> Zhuang
         "zh" => Tag::new(b"ZHS "),  // Chinese, Simplified [macrolanguage]
@@ -1850,9 +1445,451 @@ pub fn iso639_to_opentype(language: &str) -> Option<Tag> {
         "zyj" => Tag::new(b"ZHA "), // Youjiang Zhuang -> Zhuang
         "zyn" => Tag::new(b"ZHA "), // Yongnan Zhuang -> Zhuang
         "zyp" => Tag::new(b"QIN "), // Zyphe Chin -> Chin
-        // "zza" => Tag::new(b"ZZA "), // Zazaki [macrolanguage]
         "zzj" => Tag::new(b"ZHA "), // Zuojiang Zhuang -> Zhuang
         _ => return None,           // Unknown
     };
     Some(tag)
 }
+
+/// Converts an [ISO 639](https://en.wikipedia.org/wiki/ISO_639) language code
+/// to all the [OpenType language tags](https://learn.microsoft.com/en-us/typography/opentype/spec/languagetags)
+/// a font may use for it, most preferred first.
+///
+/// Some languages correspond to several language systems, e.g. Saint Lucian
+/// Creole French (`acf`) may be covered by French Antillean (`FAN `) or
+/// Creoles (`CPP `). The first tag is the one given by [`iso639_to_opentype`],
+/// if any.
+///
+/// Any subtags following the language (e.g. the `-BR` of `pt-BR`) are ignored.
+#[must_use]
+pub fn iso639_to_opentype_all(language: &str) -> Vec<Tag> {
+    let language = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    iso639_to_opentype(&language)
+        .into_iter()
+        .chain(
+            other_opentype_languages(&language)
+                .iter()
+                .map(|tag| Tag::new(tag)),
+        )
+        .collect()
+}
+
+/// OpenType language tags for an ISO 639 language code, other than the one
+/// returned by [`iso639_to_opentype`].
+#[rustfmt::skip]
+fn other_opentype_languages(language: &str) -> &'static [&'static [u8; 4]] {
+    match language {
+        "aaq" => &[b"AAQ "], // Eastern Abnaki -> Eastern Abenaki
+        "acf" => &[b"CPP "], // Saint Lucian Creole French -> Creoles
+        "ach" => &[b"ACH "], // Acoli -> Acholi
+        "acr" => &[b"MYN "], // Achi -> Mayan
+        "acy" => &[b"ARA "], // Cypriot Arabic -> Arabic
+        "ady" => &[b"ADY "], // Adyghe
+        "aii" => &[b"SYR "], // Assyrian Neo-Aramaic -> Syriac
+        "aio" => &[b"AIO "], // Aiton
+        "akb" => &[b"BTK "], // Batak Angkola -> Batak
+        "alt" => &[b"ALT "], // Southern Altai -> Altai
+        "ang" => &[b"ANG "], // Old English (ca. 450-1100) -> Anglo-Saxon
+        "ary" => &[b"ARA "], // Moroccan Arabic -> Arabic
+        "ast" => &[b"AST "], // Asturian
+        "ath" => &[b"ATH "], // Athapascan  [collection] -> Athapaskan
+        "ats" => &[b"ATS "], // Gros Ventre (Atsina)
+        "avn" => &[b"AVN "], // Avatime
+        "awa" => &[b"AWA "], // Awadhi
+        "azb" => &[b"AZE "], // South Azerbaijani -> Azerbaijani
+        "ban" => &[b"BAN "], // Balinese
+        "bar" => &[b"BAR "], // Bavarian
+        "bbc" => &[b"BTK "], // Batak Toba -> Batak
+        "bdc" => &[b"BDC "], // Emberá-Baudó
+        "bdy" => &[b"BDY "], // Bandjalang
+        "bem" => &[b"BEM "], // Bemba (Zambia)
+        "bgc" => &[b"BGC "], // Haryanvi
+        "bgq" => &[b"RAJ "], // Bagri -> Rajasthani
+        "bhi" => &[b"BHI "], // Bhilali -> Bhili
+        "bho" => &[b"BHO "], // Bhojpuri
+        "bi" => &[b"CPP "], // Bislama -> Creoles
+        "bik" => &[b"BIK "], // Bikol [macrolanguage]
+        "bjj" => &[b"BJJ "], // Kanauji
+        "blk" => &[b"KRN "], // Pa'o Karen -> Karen
+        "bpy" => &[b"BPY "], // Bishnupriya -> Bishnupriya Manipuri
+        "brh" => &[b"BRH "], // Brahui
+        "brx" => &[b"BRX "], // Bodo (India)
+        "bsk" => &[b"BSK "], // Burushaski
+        "btd" => &[b"BTK "], // Batak Dairi -> Batak
+        "btk" => &[b"BTK "], // Batak  [collection]
+        "btm" => &[b"BTK "], // Batak Mandailing -> Batak
+        "bts" => &[b"BTK "], // Batak Simalungun -> Batak
+        "btx" => &[b"BTK "], // Batak Karo -> Batak
+        "btz" => &[b"BTK "], // Batak Alas-Kluet -> Batak
+        "bug" => &[b"BUG "], // Buginese -> Bugis
+        "byv" => &[b"BML "], // Medumba -> Bamileke
+        "caf" => &[b"ATH "], // Southern Carrier -> Athapaskan
+        "cak" => &[b"MYN "], // Kaqchikel -> Mayan
+        "cay" => &[b"CAY "], // Cayuga
+        "cbg" => &[b"CBG "], // Chimila
+        "cbk" => &[b"CPP "], // Chavacano -> Creoles
+        "ceb" => &[b"CEB "], // Cebuano
+        "cfm" => &[b"QIN "], // Falam Chin -> Chin
+        "cgg" => &[b"CGG "], // Chiga
+        "chm" => &[b"LMA "], // Mari (Russia) [macrolanguage] -> Low Mari
+        "cho" => &[b"CHO "], // Choctaw
+        "chp" => &[b"SAY ", b"ATH "], // Chipewyan -> Sayisi; Chipewyan -> Athapaskan
+        "chr" => &[b"CHR "], // Cherokee
+        "chy" => &[b"CHY "], // Cheyenne
+        "cja" => &[b"CJA "], // Western Cham
+        "cjm" => &[b"CJM "], // Eastern Cham
+        "cmi" => &[b"CMI "], // Emberá-Chamí
+        "coo" => &[b"COO "], // Comox
+        "cop" => &[b"COP "], // Coptic
+        "cpp" => &[b"CPP "], // Portuguese-based creoles and pidgins [collection] -> Creoles
+        "cqu" => &[b"QUZ "], // Chilean Quechua(retired code) -> Quechua
+        "crj" => &[b"YCR ", b"CRE "], // Southern East Cree -> Y-Cree; Southern East Cree -> Cree
+        "crk" => &[b"YCR ", b"CRE "], // Plains Cree -> Y-Cree; Plains Cree -> Cree
+        "crl" => &[b"YCR ", b"CRE "], // Northern East Cree -> Y-Cree; Northern East Cree -> Cree
+        "crm" => &[b"LCR ", b"CRE "], // Moose Cree -> L-Cree; Moose Cree -> Cree
+        "crx" => &[b"ATH "], // Carrier -> Athapaskan
+        "csb" => &[b"CSB "], // Kashubian
+        "csw" => &[b"NHC ", b"CRE "], // Swampy Cree -> Norway House Cree; Swampy Cree -> Cree
+        "ctg" => &[b"CTG "], // Chittagonian
+        "cto" => &[b"CTO "], // Emberá-Catío
+        "ctt" => &[b"CTT "], // Wayanad Chetti
+        "cuk" => &[b"CUK "], // San Blas Kuna
+        "cwd" => &[b"TCR ", b"CRE "], // Woods Cree -> TH-Cree; Woods Cree -> Cree
+        "dag" => &[b"DAG "], // Dagbani
+        "dar" => &[b"DAR "], // Dargwa
+        "dax" => &[b"DAX "], // Dayi
+        "den" => &[b"ATH "], // Slave (Athapascan) [macrolanguage] -> Athapaskan
+        "dgo" => &[b"DGR "], // Dogri (macrolanguage)
+        "dhg" => &[b"DHG "], // Dhangu
+        "diq" => &[b"ZZA "], // Dimli  -> Zazaki
+        "dnj" => &[b"DNJ "], // Dan
+        "drw" => &[b"FAR "], // Darwazi(retired code) -> Persian
+        "duj" => &[b"DUJ "], // Dhuwal(retired code)
+        "dv" => &[b"DHV "], // Divehi (Dhivehi, Maldivian) (deprecated)
+        "efi" => &[b"EFI "], // Efik
+        "emk" => &[b"MNK "], // Eastern Maninkakan -> Maninka
+        "emp" => &[b"EMP "], // Northern Emberá
+        "esu" => &[b"ESU "], // Central Yupik
+        "fan" => &[b"BTI "], // Fang (Equatorial Guinea) -> Beti
+        "fat" => &[b"AKA "], // Fanti -> Akan
+        "flm" => &[b"QIN "], // Falam Chin(retired code) -> Chin
+        "fmp" => &[b"BML "], // Fe'fe' -> Bamileke
+        "fon" => &[b"FON "], // Fon
+        "frc" => &[b"FRC "], // Cajun French
+        "frp" => &[b"FRP "], // Arpitan
+        "fuf" => &[b"FUL "], // Pular -> Fulah
+        "fuv" => &[b"FUL "], // Nigerian Fulfulde -> Fulah
+        "ga" => &[b"IRT "], // Irish -> Irish Traditional
+        "gag" => &[b"GAG "], // Gagauz
+        "gez" => &[b"GEZ "], // Geez
+        "gih" => &[b"GIH "], // Githabul
+        "gkp" => &[b"KPL "], // Guinea Kpelle -> Kpelle
+        "glk" => &[b"GLK "], // Gilaki
+        "gnn" => &[b"GNN "], // Gumatj
+        "gog" => &[b"GOG "], // Gogo
+        "gon" => &[b"GON "], // Gondi [macrolanguage]
+        "guc" => &[b"GUC "], // Wayuu
+        "guf" => &[b"GUF "], // Gupapuyngu
+        "guz" => &[b"GUZ "], // Gusii
+        "haw" => &[b"HAW "], // Hawaiian
+        "hay" => &[b"HAY "], // Haya
+        "haz" => &[b"HAZ "], // Hazaragi
+        "hei" => &[b"HEI "], // Heiltsuk
+        "hil" => &[b"HIL "], // Hiligaynon
+        "hmd" => &[b"HMN "], // Large Flowery Miao -> Hmong
+        "hmn" => &[b"HMN "], // Hmong [macrolanguage]
+        "hmz" => &[b"HMN "], // Hmong Shua -> Hmong
+        "hnd" => &[b"HND "], // Southern Hindko -> Hindko
+        "ho" => &[b"CPP "], // Hiri Motu -> Creoles
+        "hoj" => &[b"RAJ "], // Hadothi -> Rajasthani
+        "ht" => &[b"CPP "], // Haitian -> Creoles
+        "hur" => &[b"HUR "], // Halkomelem
+        "hy" => &[b"HYE "], // Armenian
+        "iba" => &[b"IBA "], // Iban
+        "ibb" => &[b"IBB "], // Ibibio
+        "id" => &[b"MLY "], // Indonesian -> Malay
+        "ijo" => &[b"IJO "], // Ijo  [collection]
+        "ike" => &[b"INUK"], // Eastern Canadian Inuktitut -> Nunavik Inuktitut
+        "ilo" => &[b"ILO "], // Iloko -> Ilokano
+        "in" => &[b"MLY "], // Indonesian(retired code) -> Malay
+        "iru" => &[b"IRU "], // Irula
+        "iu" => &[b"INUK"], // Inuktitut [macrolanguage] -> Nunavik Inuktitut
+        "jam" => &[b"CPP "], // Jamaican Creole English -> Creoles
+        "jbo" => &[b"JBO "], // Lojban
+        "jct" => &[b"JCT "], // Krymchak
+        "jdt" => &[b"JDT "], // Judeo-Tat
+        "kab" => &[b"BBR "], // Kabyle -> Berber
+        "kaw" => &[b"KAW "], // Kawi (Old Javanese)
+        "kbc" => &[b"KBC "], // Kadiwéu
+        "kca" => &[b"KHS ", b"KHV "], // Khanty -> Khanty-Shurishkar; Khanty -> Khanty-Vakhi
+        "kde" => &[b"KDE "], // Makonde
+        "kea" => &[b"CPP "], // Kabuverdianu -> Creoles
+        "kek" => &[b"MYN "], // Kekchí -> Mayan
+        "kgf" => &[b"KGF "], // Kube
+        "kht" => &[b"KHN "], // Khamti -> Khamti Shan(Microsoft fonts)
+        "khw" => &[b"KHW "], // Khowar
+        "kiu" => &[b"ZZA "], // Kirmanjki  -> Zazaki
+        "kjd" => &[b"KJD "], // Southern Kiwai
+        "kjj" => &[b"KJJ "], // Khinalugh -> Khinalug
+        "kjp" => &[b"KRN "], // Pwo Eastern Karen -> Karen
+        "kjz" => &[b"KJZ "], // Bumthangkha
+        "kmg" => &[b"KMG "], // Kâte
+        "kmz" => &[b"KMZ "], // Khorasani Turkish -> Khorasani Turkic
+        "kok" => &[b"KOK "], // Konkani  [macrolanguage]
+        "kos" => &[b"KOS "], // Kosraean
+        "krl" => &[b"KRL "], // Karelian
+        "ksu" => &[b"KSU "], // Khamyang
+        "ksw" => &[b"KRN "], // S'gaw Karen -> Karen
+        "kum" => &[b"KUM "], // Kumyk
+        "kvq" => &[b"KRN "], // Geba Karen -> Karen
+        "kwk" => &[b"KWK "], // Kwak'wala -> Kwakʼwala
+        "kyu" => &[b"KRN "], // Western Kayah -> Karen
+        "lef" => &[b"LEF "], // Lelemi
+        "lez" => &[b"LEZ "], // Lezghian -> Lezgi
+        "lij" => &[b"LIJ "], // Ligurian
+        "lis" => &[b"LIS "], // Lisu
+        "liv" => &[b"LIV "], // Liv
+        "ljp" => &[b"LJP "], // Lampung Api -> Lampung
+        "lki" => &[b"LKI "], // Laki
+        "lmo" => &[b"LMO "], // Lombard
+        "lom" => &[b"LOM "], // Loma (Liberia)
+        "lpo" => &[b"LPO "], // Lipo
+        "lrc" => &[b"LRC "], // Northern Luri -> Luri
+        "lua" => &[b"LUA "], // Luba-Lulua
+        "luo" => &[b"LUO "], // Luo (Kenya and Tanzania)
+        "lus" => &[b"QIN "], // Lushai -> Chin
+        "lut" => &[b"LUT "], // Lushootseed
+        "mad" => &[b"MAD "], // Madurese -> Madura
+        "mag" => &[b"MAG "], // Magahi
+        "mam" => &[b"MYN "], // Mam -> Mayan
+        "max" => &[b"CPP "], // North Moluccan Malay -> Creoles
+        "mbo" => &[b"MBO "], // Mbo (Cameroon)
+        "mdr" => &[b"MDR "], // Mandar
+        "mer" => &[b"MER "], // Meru
+        "mev" => &[b"MEV "], // Mano
+        "mfa" => &[b"MLY "], // Pattani Malay -> Malay
+        "mfe" => &[b"CPP "], // Morisyen -> Creoles
+        "min" => &[b"MLY "], // Minangkabau -> Malay
+        "mkw" => &[b"MKW "], // Kituba (Congo)
+        "ml" => &[b"MLR "], // Malayalam -> Malayalam Reformed
+        "mlq" => &[b"MNK "], // Western Maninkakan -> Maninka
+        "mni" => &[b"MNI "], // Manipuri
+        "mnk" => &[b"MNK "], // Mandinka -> Maninka
+        "mnw" => &[b"MONT"], // Mon -> Thailand Mon
+        "mo" => &[b"ROM "], // Moldavian(retired code) -> Romanian
+        "moh" => &[b"MOH "], // Mohawk
+        "mos" => &[b"MOS "], // Mossi
+        "msi" => &[b"CPP "], // Sabah Malay -> Creoles
+        "mus" => &[b"MUS "], // Creek -> Muscogee
+        "mwl" => &[b"MWL "], // Mirandese
+        "mww" => &[b"HMN "], // Hmong Daw -> Hmong
+        "myn" => &[b"MYN "], // Mayan  [collection]
+        "mzn" => &[b"MZN "], // Mazanderani
+        "nag" => &[b"CPP "], // Naga Pidgin -> Creoles
+        "nah" => &[b"NAH "], // Nahuatl  [collection]
+        "nap" => &[b"NAP "], // Neapolitan
+        "ndc" => &[b"NDC "], // Ndau
+        "nds" => &[b"NDS "], // Low Saxon
+        "new" => &[b"NEW "], // Newari
+        "nga" => &[b"NGA "], // Ngbaka
+        "niu" => &[b"NIU "], // Niuean
+        "noe" => &[b"NOE "], // Nimadi
+        "nog" => &[b"NOG "], // Nogai
+        "nop" => &[b"NOP "], // Numanggang
+        "nov" => &[b"NOV "], // Novial
+        "nso" => &[b"NSO "], // Northern Sotho
+        "nuk" => &[b"NUK "], // Nuu-chah-nulth
+        "nv" => &[b"ATH "], // Navajo -> Athapaskan
+        "nym" => &[b"NYM "], // Nyamwezi
+        "nza" => &[b"NZA "], // Tigon Mbembe -> Mbembe Tigon
+        "ojb" => &[b"OJB "], // Northwestern Ojibwa -> Ojibway
+        "ojs" => &[b"OJB "], // Severn Ojibwa -> Ojibway
+        "one" => &[b"ONE "], // Oneida
+        "ono" => &[b"ONO "], // Onondaga
+        "pag" => &[b"PAG "], // Pangasinan
+        "pam" => &[b"PAM "], // Pampanga -> Pampangan
+        "pap" => &[b"CPP "], // Papiamento -> Creoles
+        "pau" => &[b"PAU "], // Palauan
+        "pcc" => &[b"PCC "], // Bouyei
+        "pcd" => &[b"PCD "], // Picard
+        "pdc" => &[b"PDC "], // Pennsylvania German
+        "pga" => &[b"CPP "], // Sudanese Creole Arabic -> Creoles
+        "phk" => &[b"PHK "], // Phake
+        "pih" => &[b"CPP "], // Pitcairn-Norfolk -> Creoles
+        "pms" => &[b"PMS "], // Piemontese
+        "pnb" => &[b"PNB "], // Western Panjabi
+        "poh" => &[b"MYN "], // Poqomchi' -> Mayan
+        "pon" => &[b"PON "], // Pohnpeian
+        "pro" => &[b"PRO "], // Old Provençal (to 1500) -> Provençal / Old Provençal
+        "prs" => &[b"FAR "], // Dari -> Persian
+        "pwo" => &[b"KRN "], // Pwo Western Karen -> Karen
+        "qub" => &[b"QUZ "], // Huallaga Huánuco Quechua -> Quechua
+        "quc" => &[b"MYN "], // K'iche' -> Mayan
+        "qud" => &[b"QUZ "], // Calderón Highland Quichua -> Quechua
+        "qug" => &[b"QUZ "], // Chimborazo Highland Quichua -> Quechua
+        "quh" => &[b"QUZ "], // South Bolivian Quechua -> Quechua
+        "qul" => &[b"QUZ "], // North Bolivian Quechua -> Quechua
+        "qup" => &[b"QUZ "], // Southern Pastaza Quechua -> Quechua
+        "qur" => &[b"QUZ "], // Yanahuanca Pasco Quechua -> Quechua
+        "qus" => &[b"QUZ "], // Santiago del Estero Quichua -> Quechua
+        "quw" => &[b"QUZ "], // Tena Lowland Quichua -> Quechua
+        "qux" => &[b"QUZ "], // Yauyos Quechua -> Quechua
+        "quz" => &[b"QUZ "], // Cusco Quechua -> Quechua
+        "qva" => &[b"QUZ "], // Ambo-Pasco Quechua -> Quechua
+        "qvh" => &[b"QUZ "], // Huamalíes-Dos de Mayo Huánuco Quechua -> Quechua
+        "qvi" => &[b"QUZ "], // Imbabura Highland Quichua -> Quechua
+        "qvj" => &[b"QUZ "], // Loja Highland Quichua -> Quechua
+        "qvl" => &[b"QUZ "], // Cajatambo North Lima Quechua -> Quechua
+        "qvm" => &[b"QUZ "], // Margos-Yarowilca-Lauricocha Quechua -> Quechua
+        "qvn" => &[b"QUZ "], // North Junín Quechua -> Quechua
+        "qvo" => &[b"QUZ "], // Napo Lowland Quechua -> Quechua
+        "qvp" => &[b"QUZ "], // Pacaraos Quechua -> Quechua
+        "qvw" => &[b"QUZ "], // Huaylla Wanca Quechua -> Quechua
+        "qvz" => &[b"QUZ "], // Northern Pastaza Quichua -> Quechua
+        "qwa" => &[b"QUZ "], // Corongo Ancash Quechua -> Quechua
+        "qwh" => &[b"QUZ "], // Huaylas Ancash Quechua -> Quechua
+        "qws" => &[b"QUZ "], // Sihuas Ancash Quechua -> Quechua
+        "qxa" => &[b"QUZ "], // Chiquián Ancash Quechua -> Quechua
+        "qxc" => &[b"QUZ "], // Chincha Quechua -> Quechua
+        "qxh" => &[b"QUZ "], // Panao Huánuco Quechua -> Quechua
+        "qxl" => &[b"QUZ "], // Salasaca Highland Quichua -> Quechua
+        "qxn" => &[b"QUZ "], // Northern Conchucos Ancash Quechua -> Quechua
+        "qxo" => &[b"QUZ "], // Southern Conchucos Ancash Quechua -> Quechua
+        "qxr" => &[b"QUZ "], // Cañar Highland Quichua -> Quechua
+        "qxt" => &[b"QUZ "], // Santa Ana de Tusi Pasco Quechua -> Quechua
+        "qxw" => &[b"QUZ "], // Jauja Wanca Quechua -> Quechua
+        "raj" => &[b"RAJ "], // Rajasthani [macrolanguage]
+        "rar" => &[b"RAR "], // Rarotongan
+        "rej" => &[b"REJ "], // Rejang
+        "rhg" => &[b"RHG "], // Rohingya
+        "ria" => &[b"RIA "], // Riang (India)
+        "rif" => &[b"BBR "], // Tarifit -> Berber
+        "rit" => &[b"RIT "], // Ritharrngu -> Ritarungo
+        "rkw" => &[b"RKW "], // Arakwal
+        "rmy" => &[b"ROY "], // Vlax Romani -> Romany
+        "rtm" => &[b"RTM "], // Rotuman
+        "rup" => &[b"RUP "], // Aromanian
+        "sas" => &[b"SAS "], // Sasak
+        "sat" => &[b"SAT "], // Santali
+        "scn" => &[b"SCN "], // Sicilian
+        "sco" => &[b"SCO "], // Scots
+        "scs" => &[b"SLA ", b"ATH "], // North Slavey -> Slavey; North Slavey -> Athapaskan
+        "see" => &[b"SEE "], // Seneca
+        "sel" => &[b"SEL "], // Selkup
+        "sfm" => &[b"HMN "], // Small Flowery Miao -> Hmong
+        "sga" => &[b"SGA "], // Old Irish (to 900)
+        "sgs" => &[b"SGS "], // Samogitian
+        "sh" => &[b"HRV "], // Serbo-Croatian [macrolanguage] -> Croatian "sh" => Tag::new(b"SRB "),  // Serbo-Croatian
+        "shi" => &[b"BBR "], // Tachelhit -> Berber
+        "shn" => &[b"SHN "], // Shan
+        "sid" => &[b"SID "], // Sidamo
+        "sja" => &[b"SJA "], // Epena
+        "sje" => &[b"SJE "], // Pite Sami
+        "sju" => &[b"SJU "], // Ume Sami
+        "snk" => &[b"SNK "], // Soninke
+        "sop" => &[b"SOP "], // Songe
+        "srr" => &[b"SRR "], // Serer
+        "stq" => &[b"STQ "], // Saterfriesisch -> Saterland Frisian "str" => Tag::new(b"STR "), // Straits Salish
+        "suk" => &[b"SUK "], // Sukuma
+        "sva" => &[b"SVA "], // Svan
+        "sxu" => &[b"SXU "], // Upper Saxon
+        "syl" => &[b"SYL "], // Sylheti
+        "syr" => &[b"SYR "], // Syriac [macrolanguage]
+        "szl" => &[b"SZL "], // Silesian
+        "tab" => &[b"TAB "], // Tabassaran -> Tabasaran
+        "taq" => &[b"TMH ", b"BBR "], // Tamasheq -> Tamashek; Tamasheq -> Berber
+        "tbv" => &[b"TBV "], // Tobo
+        "tdc" => &[b"TDC "], // Emberá-Tadó
+        "tdd" => &[b"TDD "], // Tai Nüa -> Dehong Dai
+        "tet" => &[b"TET "], // Tetum
+        "thp" => &[b"THP "], // Thompson
+        "thv" => &[b"TMH ", b"BBR "], // Tahaggart Tamahaq -> Tamashek; Tahaggart Tamahaq -> Berber
+        "thz" => &[b"TMH ", b"BBR "], // Tayart Tamajeq -> Tamashek; Tayart Tamajeq -> Berber
+        "tiv" => &[b"TIV "], // Tiv
+        "tjl" => &[b"TJL "], // Tai Laing
+        "tli" => &[b"TLI "], // Tlingit
+        "tly" => &[b"TLY "], // Talysh
+        "tmh" => &[b"BBR "], // Tamashek [macrolanguage] -> Berber
+        "tnf" => &[b"FAR "], // Tangshewi(retired code) -> Persian
+        "tpi" => &[b"CPP "], // Tok Pisin -> Creoles
+        "tru" => &[b"SYR "], // Turoyo -> Syriac
+        "tsj" => &[b"TSJ "], // Tshangla
+        "ttq" => &[b"TMH ", b"BBR "], // Tawallammat Tamajaq -> Tamashek; Tawallammat Tamajaq -> Berber
+        "tum" => &[b"TUM "], // Tumbuka
+        "tus" => &[b"TUS "], // Tuscarora
+        "tvl" => &[b"TVL "], // Tuvalu
+        "tw" => &[b"AKA "], // Twi -> Akan
+        "tyz" => &[b"TYZ "], // Tày
+        "tzm" => &[b"BBR "], // Central Atlas Tamazight -> Berber
+        "tzo" => &[b"MYN "], // Tzotzil -> Mayan
+        "udi" => &[b"UDI "], // Udi
+        "udm" => &[b"UDM "], // Udmurt
+        "umb" => &[b"UMB "], // Umbundu
+        "vec" => &[b"VEC "], // Venetian
+        "vro" => &[b"ETI "], // Võro -> Estonian
+        "war" => &[b"WAR "], // Waray (Philippines) -> Waray-Waray "wbl" => Tag::new(b"WBL "), // Wakhi
+        "wbr" => &[b"RAJ "], // Wagdi -> Rajasthani
+        "wci" => &[b"WCI "], // Waci Gbe
+        "wdt" => &[b"WDT "], // Wendat
+        "wtm" => &[b"WTM "], // Mewati
+        "wya" => &[b"WYN "], // Wyandot(retired code)
+        "wyn" => &[b"WYN "], // Wyandot
+        "xal" => &[b"TOD "], // Kalmyk -> Todo
+        "xjb" => &[b"XJB "], // Minjungbal -> Minjangbal
+        "xkf" => &[b"XKF "], // Khengkha
+        "xmm" => &[b"CPP "], // Manado Malay -> Creoles
+        "xog" => &[b"XOG "], // Soga
+        "xpe" => &[b"KPL "], // Liberia Kpelle -> Kpelle
+        "xsl" => &[b"SLA ", b"ATH "], // South Slavey -> Slavey; South Slavey -> Athapaskan
+        "xub" => &[b"XUB "], // Betta Kurumba -> Bette Kuruma
+        "xuj" => &[b"XUJ "], // Jennu Kurumba -> Jennu Kuruma
+        "yao" => &[b"YAO "], // Yao
+        "yap" => &[b"YAP "], // Yapese
+        "ygp" => &[b"YGP "], // Gepo
+        "yna" => &[b"YNA "], // Aluo
+        "yuf" => &[b"YUF "], // Havasupai-Walapai-Yavapai
+        "ywq" => &[b"YWQ "], // Wuding-Luquan Yi
+        "zea" => &[b"ZEA "], // Zeeuws -> Zealandic
+        "zgh" => &[b"BBR "], // Standard Moroccan Tamazight -> Berber
+        "zza" => &[b"ZZA "], // Zazaki [macrolanguage]
+        _ => &[],
+    }
+}
+
+/// Finds the user coordinate on `axis` that normalizes (including `avar`) to
+/// `coord`, preferring whole numbers (where masters usually are).
+///
+/// The inverse of normalizing a single axis value. See
+/// [`Location::from_normalized`](crate::Location::from_normalized) to
+/// denormalize a whole location.
+#[must_use]
+pub fn denormalize(font: &skrifa::FontRef, axis: &Axis, coord: F2Dot14) -> f32 {
+    let normalize = |user: f32| {
+        font.axes().location([(axis.tag(), user)]).coords()[axis.index()]
+    };
+    // Normalization is monotonic, so binary search the axis' range
+    let (mut low, mut high) = match coord.to_bits().cmp(&0) {
+        cmp::Ordering::Less => (axis.min_value(), axis.default_value()),
+        cmp::Ordering::Equal => return axis.default_value(),
+        cmp::Ordering::Greater => (axis.default_value(), axis.max_value()),
+    };
+    for _ in 0..32 {
+        let middle = (low + high) / 2.0;
+        if normalize(middle) < coord {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    // Masters are usually at whole numbers, rounding errors aside
+    let rounded = high.round();
+    if normalize(rounded) == coord {
+        rounded
+    } else {
+        high
+    }
+}
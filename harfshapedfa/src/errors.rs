@@ -19,6 +19,10 @@ pub enum ShapingPlanError {
     /// invalid
     #[error("invalid language: {0}")]
     UnknownLanguage(#[from] HarfRustUnknownLanguageError),
+    /// A feature wasn't in [`harfrust`]'s syntax (e.g. `kern`, `-liga` or
+    /// `ss01=1`)
+    #[error(transparent)]
+    InvalidFeature(#[from] InvalidFeatureError),
 }
 
 /// [`harfrust`] didn't recognise the language.
@@ -36,6 +40,21 @@ impl HarfRustUnknownLanguageError {
     }
 }
 
+/// [`harfrust`] couldn't parse the feature.
+#[derive(Debug, Error)]
+#[error("invalid feature: \"{feature}\"")]
+pub struct InvalidFeatureError {
+    feature: String,
+}
+
+impl InvalidFeatureError {
+    pub(crate) fn new(feature: impl Into<String>) -> Self {
+        InvalidFeatureError {
+            feature: feature.into(),
+        }
+    }
+}
+
 /// Returned by [`Location::validate_for`](crate::Location::validate_for),
 /// indicating axes are specified in the [`Location`](crate::Location) that
 /// aren't in the font being validated against.
@@ -50,3 +69,15 @@ pub struct MismatchedAxesError {
 #[derive(Debug, Error)]
 #[error(transparent)]
 pub struct InvalidTagError(#[from] pub(crate) InvalidTag);
+
+/// A [`Location`](crate::Location) couldn't be created from the given axis
+/// values.
+#[derive(Debug, Error)]
+pub enum LocationError {
+    /// An axis tag was invalid.
+    #[error(transparent)]
+    InvalidTag(#[from] InvalidTagError),
+    /// An axis value was `NaN`.
+    #[error("{0} coordinate was NaN")]
+    NotANumber(skrifa::Tag),
+}
@@ -11,7 +11,10 @@ pub use location::*;
 
 use crate::{
     convert::direction_from_script,
-    errors::{HarfRustUnknownLanguageError, InvalidTagError, ShapingPlanError},
+    errors::{
+        HarfRustUnknownLanguageError, InvalidFeatureError, InvalidTagError,
+        ShapingPlanError,
+    },
 };
 
 /// Helper functions for converting between differing standards.
@@ -35,7 +38,7 @@ pub mod pens;
 /// This should cover the API surface that [`pens`] exposes.
 #[cfg(feature = "pens")]
 pub mod kurbo {
-    pub use kurbo::{BezPath, PathEl, Point, Rect};
+    pub use kurbo::{BezPath, PathEl, Point, Rect, Shape};
 }
 
 /// Metadata related to shaping.
@@ -51,6 +54,7 @@ pub struct ShapingMeta {
     script: Script,
     direction: Direction,
     language: Option<Language>,
+    features: Vec<Feature>,
 }
 
 impl ShapingMeta {
@@ -61,6 +65,21 @@ impl ShapingMeta {
         script: &str,
         language: Option<&str>,
         shaper: &Shaper,
+    ) -> Result<Self, ShapingPlanError> {
+        ShapingMeta::new_with_features(script, language, None::<&str>, shaper)
+    }
+
+    /// Create a new `ShapingMeta` that also applies `features`, in
+    /// [`harfrust`]'s syntax (e.g. `cswh`, `-liga` or `ss01=1`), on top of
+    /// the default features.
+    ///
+    /// Errors if `script`, `language` or any of `features` are
+    /// invalid/unrecognised.
+    pub fn new_with_features(
+        script: &str,
+        language: Option<&str>,
+        features: impl IntoIterator<Item = impl AsRef<str>>,
+        shaper: &Shaper,
     ) -> Result<Self, ShapingPlanError> {
         let script_tag = script.parse::<Tag>().map_err(InvalidTagError)?;
         // Unwrap is safe here as script_tag is never null as [0, 0, 0, 0] isn't
@@ -75,6 +94,14 @@ impl ShapingMeta {
                     .map_err(|_| HarfRustUnknownLanguageError::new(lang))
             })
             .transpose()?;
+        let features = features
+            .into_iter()
+            .map(|feature| {
+                let feature = feature.as_ref();
+                Feature::from_str(feature)
+                    .map_err(|_| InvalidFeatureError::new(feature))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         let direction =
             direction_from_script(script).unwrap_or(Direction::LeftToRight);
 
@@ -84,7 +111,7 @@ impl ShapingMeta {
             Some(script),
             language.as_ref(),
             // Default features are still included by default
-            &[],
+            &features,
         );
 
         Ok(Self {
@@ -92,9 +119,29 @@ impl ShapingMeta {
             script,
             direction,
             language,
+            features,
         })
     }
 
+    /// The script words will be shaped as.
+    #[must_use]
+    pub const fn script(&self) -> Script {
+        self.script
+    }
+
+    /// The direction words will be shaped in, derived from the script.
+    #[must_use]
+    pub const fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// The features the shaping plan was made with, on top of the default
+    /// features. Pass these when shaping with the plan.
+    #[must_use]
+    pub fn features(&self) -> &[Feature] {
+        &self.features
+    }
+
     /// Get access to the inner [`ShapePlan`].
     #[must_use]
     pub const fn shaping_plan(&self) -> &ShapePlan {
@@ -129,6 +176,9 @@ pub trait HarfRustShaperExt: private::Sealed {
     /// buffer.configure_with_meta(meta);
     /// shaper.shape_with_plan(meta.shaping_plan(), buffer, features)
     /// ```
+    ///
+    /// `features` should be the same as the plan's
+    /// ([`ShapingMeta::features`]), or the result will likely be incorrect.
     fn shape_with_meta(
         &self,
         meta: &ShapingMeta,
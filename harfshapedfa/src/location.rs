@@ -2,17 +2,24 @@ use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
     fmt,
+    hash::{Hash, Hasher},
+    iter,
 };
 
 use indexmap::IndexMap;
 use ordered_float::NotNan;
-use skrifa::MetadataProvider;
+use skrifa::{MetadataProvider, instance::LocationRef, raw::types::F2Dot14};
 
-use crate::errors::{InvalidTagError, MismatchedAxesError};
+use crate::{
+    convert::denormalize,
+    errors::{InvalidTagError, LocationError, MismatchedAxesError},
+};
 
 /// A mapping of axis tags to values.
 ///
-/// Retains insertion order of axes.
+/// Retains insertion order of axes, but locations with the same axis values
+/// are equal (and hash the same) whatever order their axes are in, so they
+/// can be used as keys of a [`HashMap`] or in a [`HashSet`].
 ///
 /// ```
 /// # use harfshapedfa::Location;
@@ -91,19 +98,109 @@ impl Location {
         Ok(self)
     }
 
+    /// Set the value of an axis, failing rather than panicking if `value` is
+    /// `NaN`.
+    ///
+    /// Fails if `tag` isn't a valid axis tag, or `value` is `NaN`.
+    pub fn try_axis(
+        &mut self,
+        tag: impl AsRef<[u8]>,
+        value: f32,
+    ) -> Result<&mut Self, LocationError> {
+        let tag = skrifa::Tag::new_checked(tag.as_ref())
+            .map_err(InvalidTagError::from)?;
+        let value =
+            NotNan::new(value).map_err(|_| LocationError::NotANumber(tag))?;
+        self.0.insert(tag, value);
+        Ok(self)
+    }
+
+    /// Create a location from axis tags & values, in order.
+    ///
+    /// The fallible equivalent of [`Location::from_iter`], failing if any tags
+    /// are invalid or any values are `NaN`. Later values for the same axis
+    /// replace earlier ones.
+    ///
+    /// ```
+    /// # use harfshapedfa::{Location, errors::LocationError};
+    /// # fn main() -> Result<(), LocationError> {
+    /// let loc = Location::try_from_iter([("wght", 700.0), ("wdth", 75.0)])?;
+    /// assert_eq!(loc.to_string(), "wght=700,wdth=75");
+    /// assert!(Location::try_from_iter([("wght", f32::NAN)]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_iter<T: AsRef<[u8]>>(
+        iter: impl IntoIterator<Item = (T, f32)>,
+    ) -> Result<Self, LocationError> {
+        iter.into_iter()
+            .try_fold(Location::new(), |mut loc, (tag, value)| {
+                loc.try_axis(tag, value)?;
+                Ok(loc)
+            })
+    }
+
+    /// Create a location from normalized coordinates (such as a
+    /// [`skrifa::instance::Location`]), the inverse of
+    /// [`Location::to_skrifa`].
+    ///
+    /// The location has a value for every axis in `font`, in `fvar` order.
+    /// Coordinates are denormalized including `avar`, preferring whole
+    /// numbers (where masters usually are). Missing coordinates are taken to
+    /// be the axis default, and extra coordinates are ignored.
+    ///
+    /// ```
+    /// # use harfshapedfa::Location;
+    /// # fn check(font: &skrifa::FontRef) {
+    /// let loc = Location::try_from_iter([("wght", 700.0)]).unwrap();
+    /// let skrifa_loc = loc.to_skrifa(font);
+    /// let round_trip = Location::from_normalized(font, (&skrifa_loc).into());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_normalized(
+        font: &skrifa::FontRef,
+        location: LocationRef<'_>,
+    ) -> Self {
+        let axes = font.axes();
+        Self(
+            axes.iter()
+                .zip(
+                    location
+                        .coords()
+                        .iter()
+                        .copied()
+                        .chain(iter::repeat(F2Dot14::ZERO)),
+                )
+                .map(|(axis, coord)| {
+                    let value = denormalize(font, &axis, coord);
+                    // Values come from within the axis range, never NaN
+                    (axis.tag(), NotNan::new(value).unwrap_or_default())
+                })
+                .collect(),
+        )
+    }
+
     /// Converts a [`HashMap`] to a Font Height [`Location`].
     ///
-    /// Fails if any keys aren't valid axis tags.
+    /// Fails if any keys aren't valid axis tags, or any values are `NaN`.
     ///
     /// Note: this is just an alias to the [`TryFrom`] implementation.
     ///
-    /// # Panics
-    ///
-    /// If any axis values are `NaN`.
-    // TODO: I think this one should error, not panic, on NaNs
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use harfshapedfa::{Location, errors::LocationError};
+    /// let loc = HashMap::from([(String::from("wght"), 700.0)]);
+    /// assert_eq!(Location::try_from_std(loc).unwrap().to_string(), "wght=700");
+    /// let nan = HashMap::from([(String::from("wght"), f32::NAN)]);
+    /// assert!(matches!(
+    ///     Location::try_from_std(nan),
+    ///     Err(LocationError::NotANumber(_)),
+    /// ));
+    /// ```
     pub fn try_from_std(
         location: HashMap<String, f32>,
-    ) -> Result<Self, InvalidTagError> {
+    ) -> Result<Self, LocationError> {
         Self::try_from(location)
     }
 
@@ -166,23 +263,56 @@ impl Location {
 
     /// Sort axes lexicographically.
     ///
-    /// Axes being ordered allows for [sorting](Location::partial_cmp).
+    /// Sorting doesn't change how locations [compare](Location::partial_cmp),
+    /// only the order they're written in.
     pub fn sort_axes(&mut self) {
         self.0.sort_keys();
     }
 
+    /// The axes & their values, sorted by tag.
+    fn sorted(&self) -> Vec<(&skrifa::Tag, &NotNan<f32>)> {
+        let mut axes = self.0.iter().collect::<Vec<_>>();
+        axes.sort_unstable_by_key(|(tag, _)| **tag);
+        axes
+    }
+
     // TODO
     // pub fn sort_axes_by(&mut self, func)
     // pub fn sort_axes_with_fvar(&mut self, font)
     // pub fn sort_axes_with_stat(&mut self, font)
 }
 
+/// Consistent with [`PartialEq`], so ignores the order of the axes.
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use harfshapedfa::Location;
+/// let locations = HashSet::from([
+///     Location::from_iter([("wght", 700.0), ("wdth", 75.0)]),
+///     Location::from_iter([("wdth", 75.0), ("wght", 700.0)]),
+/// ]);
+/// assert_eq!(locations.len(), 1);
+/// ```
+impl Hash for Location {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sorted().hash(state);
+    }
+}
+
 impl PartialOrd for Location {
-    /// Sorts two `Location`s iff they have the same axes in the same order.
-    /// Will return `None` if this is not the case.
-    // FIXME: will return None for some Locations that are considered equal
-    //        (when axis order differs). Does this violate expected
-    //        invariants of PartialOrd/Eq?
+    /// Sorts two `Location`s iff they have the same axes, comparing their
+    /// values axis by axis in order of their tags (whatever order the axes
+    /// were inserted in).
+    /// Will return `None` if they have different axes.
+    ///
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use harfshapedfa::Location;
+    /// let light = Location::from_iter([("wght", 300.0), ("wdth", 100.0)]);
+    /// let narrow = Location::from_iter([("wdth", 75.0), ("wght", 700.0)]);
+    /// assert_eq!(narrow.partial_cmp(&light), Some(Ordering::Less));
+    /// assert_eq!(light.partial_cmp(&Location::new()), None);
+    /// ```
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self.0.len() != other.0.len() {
             // Difference in axes
@@ -190,10 +320,10 @@ impl PartialOrd for Location {
         }
 
         for ((left_tag, left_val), (right_tag, right_val)) in
-            self.0.iter().zip(other.0.iter())
+            self.sorted().into_iter().zip(other.sorted())
         {
             if left_tag != right_tag {
-                // Difference in axes (order)
+                // Difference in axes
                 return None;
             }
             match NotNan::cmp(left_val, right_val) {
@@ -213,6 +343,25 @@ impl fmt::Debug for Location {
     }
 }
 
+/// Writes axis values separated by commas, in order.
+///
+/// ```
+/// # use harfshapedfa::Location;
+/// let loc = Location::from_iter([("wght", 700.0), ("wdth", 75.0)]);
+/// assert_eq!(loc.to_string(), "wght=700,wdth=75");
+/// assert_eq!(Location::new().to_string(), "");
+/// ```
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().enumerate().try_for_each(|(i, (tag, value))| {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{tag}={value}")
+        })
+    }
+}
+
 impl<T> FromIterator<(T, f32)> for Location
 where
     T: AsRef<[u8]>,
@@ -233,26 +382,136 @@ where
     }
 }
 
+/// Fails if any keys aren't valid axis tags, or any values are `NaN`.
 impl TryFrom<HashMap<String, f32>> for Location {
-    type Error = InvalidTagError;
+    type Error = LocationError;
 
     /// Convert standard library types into a `Location`.
-    ///
-    /// # Panics
-    ///
-    /// If any value of `location` is `NaN`.
-    // TODO: make NaNs an error
     fn try_from(location: HashMap<String, f32>) -> Result<Self, Self::Error> {
-        let user_coords = location
-            .into_iter()
-            .map(|(tag, value)| -> Result<_, InvalidTagError> {
-                let tag = skrifa::Tag::new_checked(tag.as_bytes())?;
-                let value = NotNan::new(value).unwrap_or_else(|_| {
-                    panic!("{tag} coordinate was NaN");
-                });
-                Ok((tag, value))
+        Location::try_from_iter(location)
+    }
+}
+
+/// Fails if any keys aren't valid axis tags, or any values are `NaN`.
+impl<T: AsRef<[u8]>> TryFrom<Vec<(T, f32)>> for Location {
+    type Error = LocationError;
+
+    fn try_from(location: Vec<(T, f32)>) -> Result<Self, Self::Error> {
+        Location::try_from_iter(location)
+    }
+}
+
+/// Fails if any keys aren't valid axis tags, or any values are `NaN`.
+impl<T: AsRef<[u8]>, const N: usize> TryFrom<[(T, f32); N]> for Location {
+    type Error = LocationError;
+
+    fn try_from(location: [(T, f32); N]) -> Result<Self, Self::Error> {
+        Location::try_from_iter(location)
+    }
+}
+
+/// Fails if any values are `NaN`.
+impl TryFrom<&[harfrust::Variation]> for Location {
+    type Error = LocationError;
+
+    fn try_from(
+        variations: &[harfrust::Variation],
+    ) -> Result<Self, Self::Error> {
+        variations
+            .iter()
+            .map(|variation| {
+                NotNan::new(variation.value)
+                    .map(|value| (variation.tag, value))
+                    .map_err(|_| LocationError::NotANumber(variation.tag))
             })
-            .collect::<Result<_, _>>()?;
-        Ok(Self(user_coords))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Fails if any values are `NaN`.
+impl TryFrom<Vec<harfrust::Variation>> for Location {
+    type Error = LocationError;
+
+    fn try_from(
+        variations: Vec<harfrust::Variation>,
+    ) -> Result<Self, Self::Error> {
+        Location::try_from(variations.as_slice())
+    }
+}
+
+/// The axis values, in order. See [`Location::to_harfrust`].
+impl From<&Location> for Vec<harfrust::Variation> {
+    fn from(location: &Location) -> Self {
+        location.to_harfrust().collect()
+    }
+}
+
+/// The axis tags & values, in order.
+impl From<&Location> for Vec<(String, f32)> {
+    fn from(location: &Location) -> Self {
+        location
+            .0
+            .iter()
+            .map(|(tag, value)| (tag.to_string(), value.into_inner()))
+            .collect()
+    }
+}
+
+/// Serialized as a map of axis tags to values, in order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Location {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        self.0.iter().try_for_each(|(tag, value)| {
+            map.serialize_entry(&tag.to_string(), &value.into_inner())
+        })?;
+        map.end()
+    }
+}
+
+/// Fails if any keys aren't valid axis tags, or any values are `NaN`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Location {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, MapAccess, Visitor};
+
+        struct LocationVisitor;
+
+        impl<'de> Visitor<'de> for LocationVisitor {
+            type Value = Location;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of axis tags to values")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut location = Location::new();
+                while let Some((tag, value)) =
+                    map.next_entry::<String, f32>()?
+                {
+                    let tag = skrifa::Tag::new_checked(tag.as_bytes())
+                        .map_err(A::Error::custom)?;
+                    let value = NotNan::new(value).map_err(|_| {
+                        A::Error::custom(format!("{tag} coordinate was NaN"))
+                    })?;
+                    location.0.insert(tag, value);
+                }
+                Ok(location)
+            }
+        }
+
+        deserializer.deserialize_map(LocationVisitor)
     }
 }
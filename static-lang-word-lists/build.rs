@@ -6,7 +6,7 @@ use std::{
     fs,
     fs::{File, OpenOptions},
     io,
-    io::Cursor,
+    io::{Cursor, Write},
     path::{Component, Path, PathBuf},
     thread,
 };
@@ -16,15 +16,24 @@ use brotli::enc::{
 };
 use zip::ZipArchive;
 
-// Provides WORD_LISTS: &[&str] for word list relative path
+// Provides WORD_LISTS: &[(&str, u32)] for word list relative path & the
+// content hash of the version of the word list this crate was released with
 include!("chicken.rs");
 
+// Provides content_hash, shared with the crate & xtask
+include!("content_hash.rs");
+
+// Smaller blocks mean less to decompress when only a few words are needed, at
+// the cost of compressing less well
+const WORDS_PER_BLOCK: usize = 4096;
+
 static IS_DOCS_RS: bool = option_env!("DOCS_RS").is_some();
 static LOCAL_BUILD: bool =
     option_env!("STATIC_LANG_WORD_LISTS_LOCAL").is_some();
 
 fn main() {
     println!("cargo::rerun-if-changed=chicken.rs");
+    println!("cargo::rerun-if-changed=content_hash.rs");
     println!("cargo::rerun-if-changed=build.rs");
     println!("cargo::rerun-if-env-changed=STATIC_LANG_WORD_LISTS_LOCAL");
 
@@ -57,16 +66,28 @@ fn main() {
     thread::scope(|s| {
         // Bind references to names so they can be copied to each spawned thread
         let wordlist_source_dir = word_list_source_dir.as_path();
-        WORD_LISTS.iter().copied().for_each(|rel_path| {
-            s.spawn(move || {
-                let bytes = get_a_file(rel_path, wordlist_source_dir);
-                // Validate the bytes are UTF-8 now so we don't need to at
-                // runtime
-                str::from_utf8(&bytes)
-                    .expect("word list should be valid UTF-8");
-                compress(&bytes, rel_path, compression_level);
+        WORD_LISTS
+            .iter()
+            .copied()
+            .for_each(|(rel_path, expected_hash)| {
+                s.spawn(move || {
+                    let bytes = get_a_file(rel_path, wordlist_source_dir);
+                    // Validate the bytes are UTF-8 now so we don't need to at
+                    // runtime
+                    let words = str::from_utf8(&bytes)
+                        .expect("word list should be valid UTF-8");
+                    // Upstream changes would otherwise silently change results
+                    let content_hash = content_hash(words.split_whitespace());
+                    assert_eq!(
+                        content_hash, expected_hash,
+                        "{rel_path} has changed since this version was \
+                         released (content hash {content_hash:#010x}, \
+                         expected {expected_hash:#010x}). Run `cargo xtask \
+                         slwl` if this is intended",
+                    );
+                    compress(words, rel_path, compression_level);
+                });
             });
-        });
     });
 }
 
@@ -159,24 +180,61 @@ fn download_repo_word_lists() -> PathBuf {
     out_dir.join("data")
 }
 
-fn compress(
-    bytes: &[u8],
-    relative_path: &str,
-    compression_level: u8,
-) -> PathBuf {
+/// Compress a word list in blocks of [`WORDS_PER_BLOCK`] words, so they can
+/// be decompressed separately, writing an index of the blocks alongside it.
+///
+/// The index is little-endian `u32`s: the number of words, the number of words
+/// per block, then the end offset of each compressed block (from the start of
+/// the `.br` file) followed by the CRC-32 of the decompressed block, which is
+/// verified after decompression at runtime.
+fn compress(words: &str, relative_path: &str, compression_level: u8) {
     let br_path = out_dir_path(relative_path).with_extension("txt.br");
+    let index_path = out_dir_path(relative_path).with_extension("txt.idx");
     let mut br_file = open_path(&br_path);
 
-    let mut cursor = Cursor::new(bytes);
-    brotli::BrotliCompress(&mut cursor, &mut br_file, &BrotliEncoderParams {
-        mode: BrotliEncoderMode::BROTLI_MODE_TEXT,
-        quality: compression_level as i32,
-        size_hint: bytes.len(),
-        ..Default::default()
-    })
-    .unwrap_or_else(|err| panic!("failed to compress {relative_path}: {err}"));
+    let words = words.split_whitespace().collect::<Vec<_>>();
+    let mut index = vec![words.len(), WORDS_PER_BLOCK];
+    let mut compressed_len = 0;
+    words.chunks(WORDS_PER_BLOCK).for_each(|block| {
+        let block = block
+            .iter()
+            .flat_map(|word| [*word, "\n"])
+            .collect::<String>();
+        let mut compressed = Vec::new();
+        brotli::BrotliCompress(
+            &mut block.as_bytes(),
+            &mut compressed,
+            &BrotliEncoderParams {
+                mode: BrotliEncoderMode::BROTLI_MODE_TEXT,
+                quality: compression_level as i32,
+                size_hint: block.len(),
+                ..Default::default()
+            },
+        )
+        .unwrap_or_else(|err| {
+            panic!("failed to compress {relative_path}: {err}")
+        });
+        br_file.write_all(&compressed).unwrap_or_else(|err| {
+            panic!("failed to write {}: {err}", br_path.display())
+        });
+        compressed_len += compressed.len();
+        index.push(compressed_len);
+        index.push(crc32fast::hash(block.as_bytes()) as usize);
+    });
 
-    br_path
+    let index = index
+        .into_iter()
+        .flat_map(|value| {
+            u32::try_from(value)
+                .expect("word lists must be smaller than 4 GiB")
+                .to_le_bytes()
+        })
+        .collect::<Vec<_>>();
+    open_path(&index_path)
+        .write_all(&index)
+        .unwrap_or_else(|err| {
+            panic!("failed to write index for {relative_path}: {err}")
+        });
 }
 
 fn out_dir_path(name: &str) -> PathBuf {
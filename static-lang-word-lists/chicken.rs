@@ -1,1613 +1,2362 @@
 // @generated by cargo xtask slwl
-static WORD_LISTS: &[&str] = &[
+static WORD_LISTS: &[(&str, u32)] = &[
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )]
-    "aosp/af_Latn.txt",
+    ("aosp/af_Latn.txt", 0x899394e1),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-ethi",
-            feature = "lang-am",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-ethi",
+                feature = "lang-am",
+            )
         )
     )]
-    "aosp/am_Ethi.txt",
+    ("aosp/am_Ethi.txt", 0x04c9368b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )]
-    "aosp/ar_Arab.txt",
+    ("aosp/ar_Arab.txt", 0xad6de64a),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )]
-    "aosp/as_Beng.txt",
+    ("aosp/as_Beng.txt", 0x95879efd),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-az",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-az",
+            )
         )
     )]
-    "aosp/az_Latn.txt",
+    ("aosp/az_Latn.txt", 0x224096d2),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )]
-    "aosp/be_Cyrl.txt",
+    ("aosp/be_Cyrl.txt", 0x49374c5a),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-be",
+            )
         )
     )]
-    "aosp/be_Latn.txt",
+    ("aosp/be_Latn.txt", 0x7ceca779),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )]
-    "aosp/bg_Cyrl.txt",
+    ("aosp/bg_Cyrl.txt", 0xd5cbe926),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )]
-    "aosp/bn_Beng.txt",
+    ("aosp/bn_Beng.txt", 0x72d4387e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )]
-    "aosp/bs_Latn.txt",
+    ("aosp/bs_Latn.txt", 0xdce9d500),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )]
-    "aosp/ca_Latn.txt",
+    ("aosp/ca_Latn.txt", 0x2bce9350),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )]
-    "aosp/cs_Latn.txt",
+    ("aosp/cs_Latn.txt", 0x471f7eb2),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )]
-    "aosp/da_Latn.txt",
+    ("aosp/da_Latn.txt", 0x9616b7a2),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
-    "aosp/de_Latn.txt",
+    ("aosp/de_Latn.txt", 0xf1f97ab4),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )]
-    "aosp/el_Grek.txt",
+    ("aosp/el_Grek.txt", 0xa81e02e9),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
-    "aosp/en_Latn.txt",
+    ("aosp/en_Latn.txt", 0x61b122a5),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "aosp/es_Latn.txt",
+    ("aosp/es_Latn.txt", 0x53548ad3),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )]
-    "aosp/et_Latn.txt",
+    ("aosp/et_Latn.txt", 0xb28d4f4d),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-eu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-eu",
+            )
         )
     )]
-    "aosp/eu_Latn.txt",
+    ("aosp/eu_Latn.txt", 0x4ca8a62e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )]
-    "aosp/fa_Arab.txt",
+    ("aosp/fa_Arab.txt", 0xec59c42c),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fi",
+            )
         )
     )]
-    "aosp/fi_Latn.txt",
+    ("aosp/fi_Latn.txt", 0x30d1e458),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )]
-    "aosp/fr_Latn.txt",
+    ("aosp/fr_Latn.txt", 0x888af7b8),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )]
-    "aosp/gl_Latn.txt",
+    ("aosp/gl_Latn.txt", 0xd8204d26),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )]
-    "aosp/gu_Gujr.txt",
+    ("aosp/gu_Gujr.txt", 0xa98da351),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )]
-    "aosp/hi_Deva.txt",
+    ("aosp/hi_Deva.txt", 0x5dbc4b97),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hi",
+            )
         )
     )]
-    "aosp/hi_Latn.txt",
+    ("aosp/hi_Latn.txt", 0xc376a975),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )]
-    "aosp/hr_Latn.txt",
+    ("aosp/hr_Latn.txt", 0x1e65bbab),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hu",
+            )
         )
     )]
-    "aosp/hu_Latn.txt",
+    ("aosp/hu_Latn.txt", 0x4684a883),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-armn",
-            feature = "lang-hy",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-armn",
+                feature = "lang-hy",
+            )
         )
     )]
-    "aosp/hy_Armn.txt",
+    ("aosp/hy_Armn.txt", 0x658673e7),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )]
-    "aosp/is_Latn.txt",
+    ("aosp/is_Latn.txt", 0x5994cb49),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )]
-    "aosp/it_Latn.txt",
+    ("aosp/it_Latn.txt", 0x476982d6),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hira",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hira",
+                feature = "lang-ja",
+            )
         )
     )]
-    "aosp/ja_Hira.txt",
+    ("aosp/ja_Hira.txt", 0xcc12f88e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-kana",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-kana",
+                feature = "lang-ja",
+            )
         )
     )]
-    "aosp/ja_Kana.txt",
+    ("aosp/ja_Kana.txt", 0x2f08971a),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-geor",
-            feature = "lang-ka",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-geor",
+                feature = "lang-ka",
+            )
         )
     )]
-    "aosp/ka_Geor.txt",
+    ("aosp/ka_Geor.txt", 0xaeeccf82),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-kk",
+            )
         )
     )]
-    "aosp/kk_Cyrl.txt",
+    ("aosp/kk_Cyrl.txt", 0xcd8e5605),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-kk",
+            )
         )
     )]
-    "aosp/kk_Latn.txt",
+    ("aosp/kk_Latn.txt", 0x2943873f),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-khmr",
-            feature = "lang-km",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-khmr",
+                feature = "lang-km",
+            )
         )
     )]
-    "aosp/km_Khmr.txt",
+    ("aosp/km_Khmr.txt", 0x8c84b3b4),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )]
-    "aosp/kn_Knda.txt",
+    ("aosp/kn_Knda.txt", 0xcb991083),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hang",
-            feature = "lang-ko",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hang",
+                feature = "lang-ko",
+            )
         )
     )]
-    "aosp/ko_Hang.txt",
+    ("aosp/ko_Hang.txt", 0x50266b57),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ky",
+            )
         )
     )]
-    "aosp/ky_Cyrl.txt",
+    ("aosp/ky_Cyrl.txt", 0xa146a878),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ky",
+            )
         )
     )]
-    "aosp/ky_Latn.txt",
+    ("aosp/ky_Latn.txt", 0xf9735b4b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )]
-    "aosp/lo_Laoo.txt",
+    ("aosp/lo_Laoo.txt", 0x8aefaa0b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )]
-    "aosp/lt_Latn.txt",
+    ("aosp/lt_Latn.txt", 0x892ec409),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )]
-    "aosp/lv_Latn.txt",
+    ("aosp/lv_Latn.txt", 0x76029aa9),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mk",
+            )
         )
     )]
-    "aosp/mk_Cyrl.txt",
+    ("aosp/mk_Cyrl.txt", 0x32c04b5e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mlym",
-            feature = "lang-ml",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mlym",
+                feature = "lang-ml",
+            )
         )
     )]
-    "aosp/ml_Mlym.txt",
+    ("aosp/ml_Mlym.txt", 0xdaddb018),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mn",
+            )
         )
     )]
-    "aosp/mn_Cyrl.txt",
+    ("aosp/mn_Cyrl.txt", 0x5f2b5ee6),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )]
-    "aosp/mr_Deva.txt",
-    #[cfg(any(feature = "all", feature = "aosp", feature = "lang-ms"))]
-    "aosp/ms.txt",
+    ("aosp/mr_Deva.txt", 0x00d7bb82),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mymr",
-            feature = "lang-my",
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-ms")
         )
     )]
-    "aosp/my_Mymr.txt",
+    ("aosp/ms.txt", 0x9494815f),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mymr",
+                feature = "lang-my",
+            )
         )
     )]
-    "aosp/nb_Latn.txt",
+    ("aosp/my_Mymr.txt", 0x7c7c5210),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )]
-    "aosp/ne_Deva.txt",
+    ("aosp/nb_Latn.txt", 0x9082902b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )]
-    "aosp/nl_Latn.txt",
+    ("aosp/ne_Deva.txt", 0x780ec8c8),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )]
-    "aosp/or_Orya.txt",
+    ("aosp/nl_Latn.txt", 0xed943429),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )]
-    "aosp/pa_Guru.txt",
+    ("aosp/or_Orya.txt", 0x5e29cbba),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )]
-    "aosp/pl_Latn.txt",
+    ("aosp/pa_Guru.txt", 0x3c400254),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )]
-    "aosp/pt_Latn.txt",
+    ("aosp/pl_Latn.txt", 0x11e97dc2),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-rm",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )]
-    "aosp/rm_Latn.txt",
+    ("aosp/pt_Latn.txt", 0xaee9dafe),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-rm",
+            )
         )
     )]
-    "aosp/ro_Latn.txt",
+    ("aosp/rm_Latn.txt", 0x255e5f29),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )]
-    "aosp/ru_Cyrl.txt",
+    ("aosp/ro_Latn.txt", 0x7ba79286),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )]
-    "aosp/si_Sinh.txt",
+    ("aosp/ru_Cyrl.txt", 0x2cdfde86),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )]
-    "aosp/sk_Latn.txt",
+    ("aosp/si_Sinh.txt", 0x6a1d1ddf),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )]
-    "aosp/sl_Latn.txt",
+    ("aosp/sk_Latn.txt", 0xab66d475),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )]
-    "aosp/sq_Latn.txt",
+    ("aosp/sl_Latn.txt", 0xb0c81a0b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )]
-    "aosp/sr_Cyrl.txt",
+    ("aosp/sq_Latn.txt", 0xb0347f71),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )]
-    "aosp/sr_Latn.txt",
+    ("aosp/sr_Cyrl.txt", 0x24c0d7ff),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )]
-    "aosp/sv_Latn.txt",
+    ("aosp/sr_Latn.txt", 0x936fd02c),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )]
-    "aosp/sw_Latn.txt",
+    ("aosp/sv_Latn.txt", 0xef187e89),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )]
-    "aosp/ta_Taml.txt",
+    ("aosp/sw_Latn.txt", 0x680670e5),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )]
-    "aosp/te_Telu.txt",
+    ("aosp/ta_Taml.txt", 0xdb25a808),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )]
-    "aosp/th_Thai.txt",
-    #[cfg(any(feature = "all", feature = "aosp", feature = "lang-tl"))]
-    "aosp/tl.txt",
+    ("aosp/te_Telu.txt", 0x21b80038),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )]
-    "aosp/tr_Latn.txt",
+    ("aosp/th_Thai.txt", 0x6bf4bac0),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-uk",
-        )
-    )]
-    "aosp/uk_Cyrl.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ur",
-        )
-    )]
-    "aosp/ur_Arab.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-uz",
-        )
-    )]
-    "aosp/uz_Latn.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-vi",
-        )
-    )]
-    "aosp/vi_Latn.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hani",
-            feature = "lang-zh",
-        )
-    )]
-    "aosp/zh_Hani.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-zu",
-        )
-    )]
-    "aosp/zu_Latn.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-adlm"))]
-    "diffenator/Adlam.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-arab"))]
-    "diffenator/Arabic.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-armn"))]
-    "diffenator/Armenian.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-avst"))]
-    "diffenator/Avestan.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-beng"))]
-    "diffenator/Bengali.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-bopo"))]
-    "diffenator/Bopomofo.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cans"))]
-    "diffenator/Canadian_Aboriginal.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cakm"))]
-    "diffenator/Chakma.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cher"))]
-    "diffenator/Cherokee.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-zyyy"))]
-    "diffenator/Common.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cyrl"))]
-    "diffenator/Cyrillic.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-deva"))]
-    "diffenator/Devanagari.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-ethi"))]
-    "diffenator/Ethiopic.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-geor"))]
-    "diffenator/Georgian.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-grek"))]
-    "diffenator/Greek.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-gujr"))]
-    "diffenator/Gujarati.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-guru"))]
-    "diffenator/Gurmukhi.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-hebr"))]
-    "diffenator/Hebrew.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-hira"))]
-    "diffenator/Hiragana.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-jpan"))]
-    "diffenator/Japanese.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-kana"))]
-    "diffenator/Katakana.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-khmr"))]
-    "diffenator/Khmer.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-laoo"))]
-    "diffenator/Lao.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-latn"))]
-    "diffenator/Latin.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-lisu"))]
-    "diffenator/Lisu.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-mlym"))]
-    "diffenator/Malayalam.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-mong"))]
-    "diffenator/Mongolian.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-mymr"))]
-    "diffenator/Myanmar.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-olck"))]
-    "diffenator/Ol_Chiki.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-orya"))]
-    "diffenator/Oriya.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-osge"))]
-    "diffenator/Osage.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-sinh"))]
-    "diffenator/Sinhala.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-syrc"))]
-    "diffenator/Syriac.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-taml"))]
-    "diffenator/Tamil.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-telu"))]
-    "diffenator/Telugu.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-thai"))]
-    "diffenator/Thai.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-thaa"))]
-    "diffenator/Thanaa.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-tibt"))]
-    "diffenator/Tibetan.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-tfng"))]
-    "diffenator/Tifinagh.txt",
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-vaii"))]
-    "diffenator/Vai.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-af",
-        )
-    )]
-    "libreoffice/af_ZA.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-an",
-        )
-    )]
-    "libreoffice/an_ES.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-ar",
-        )
-    )]
-    "libreoffice/ar.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-as",
-        )
-    )]
-    "libreoffice/as_IN.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-be",
-        )
-    )]
-    "libreoffice/be-official.txt",
-    #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-bg",
-        )
-    )]
-    "libreoffice/bg_BG.txt",
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-tl")
+        )
+    )]
+    ("aosp/tl.txt", 0x5492e8de),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
+        )
+    )]
+    ("aosp/tr_Latn.txt", 0x5236ca3b),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
+        )
+    )]
+    ("aosp/uk_Cyrl.txt", 0x44a1c4f2),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ur",
+            )
+        )
+    )]
+    ("aosp/ur_Arab.txt", 0xf92a48bf),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-uz",
+            )
+        )
+    )]
+    ("aosp/uz_Latn.txt", 0xad20f48b),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
+        )
+    )]
+    ("aosp/vi_Latn.txt", 0xa9999a66),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hani",
+                feature = "lang-zh",
+            )
+        )
+    )]
+    ("aosp/zh_Hani.txt", 0x92b578c9),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-zu",
+            )
+        )
+    )]
+    ("aosp/zu_Latn.txt", 0x59717bcc),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-adlm")
+        )
+    )]
+    ("diffenator/Adlam.txt", 0x4354ccb7),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-arab")
+        )
+    )]
+    ("diffenator/Arabic.txt", 0x5d08aa65),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-armn")
+        )
+    )]
+    ("diffenator/Armenian.txt", 0xbd8615c7),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-avst")
+        )
+    )]
+    ("diffenator/Avestan.txt", 0x0711b9de),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-beng")
+        )
+    )]
+    ("diffenator/Bengali.txt", 0x23af0786),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-bopo")
+        )
+    )]
+    ("diffenator/Bopomofo.txt", 0x15782874),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cans")
+        )
+    )]
+    ("diffenator/Canadian_Aboriginal.txt", 0x9d9d9c43),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cakm")
+        )
+    )]
+    ("diffenator/Chakma.txt", 0x01059dff),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cher")
+        )
+    )]
+    ("diffenator/Cherokee.txt", 0xbcff2e81),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-zyyy")
+        )
+    )]
+    ("diffenator/Common.txt", 0x15f1bd4c),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cyrl")
+        )
+    )]
+    ("diffenator/Cyrillic.txt", 0xbf83f3a3),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-deva")
+        )
+    )]
+    ("diffenator/Devanagari.txt", 0xb461dfd7),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-ethi")
+        )
+    )]
+    ("diffenator/Ethiopic.txt", 0x6277706b),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-geor")
+        )
+    )]
+    ("diffenator/Georgian.txt", 0xe366ecda),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-grek")
+        )
+    )]
+    ("diffenator/Greek.txt", 0x3c9129af),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-gujr")
+        )
+    )]
+    ("diffenator/Gujarati.txt", 0x2ada2da8),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-guru")
+        )
+    )]
+    ("diffenator/Gurmukhi.txt", 0x6da8622c),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hebr")
+        )
+    )]
+    ("diffenator/Hebrew.txt", 0xadb12d1b),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hira")
+        )
+    )]
+    ("diffenator/Hiragana.txt", 0xd8dd86ef),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-jpan")
+        )
+    )]
+    ("diffenator/Japanese.txt", 0x48a3683e),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-kana")
+        )
+    )]
+    ("diffenator/Katakana.txt", 0x3df536e8),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-khmr")
+        )
+    )]
+    ("diffenator/Khmer.txt", 0x9e77e515),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-laoo")
+        )
+    )]
+    ("diffenator/Lao.txt", 0x6f2948ec),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-latn")
+        )
+    )]
+    ("diffenator/Latin.txt", 0xfa824902),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-lisu")
+        )
+    )]
+    ("diffenator/Lisu.txt", 0xc23ca46d),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mlym")
+        )
+    )]
+    ("diffenator/Malayalam.txt", 0x36a83828),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mong")
+        )
+    )]
+    ("diffenator/Mongolian.txt", 0x387094f5),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mymr")
+        )
+    )]
+    ("diffenator/Myanmar.txt", 0xdc4183c8),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-olck")
+        )
+    )]
+    ("diffenator/Ol_Chiki.txt", 0xf003c0be),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-orya")
+        )
+    )]
+    ("diffenator/Oriya.txt", 0xc20b224d),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-osge")
+        )
+    )]
+    ("diffenator/Osage.txt", 0x45757243),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-sinh")
+        )
+    )]
+    ("diffenator/Sinhala.txt", 0x82d06d62),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-syrc")
+        )
+    )]
+    ("diffenator/Syriac.txt", 0x015cb37e),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-taml")
+        )
+    )]
+    ("diffenator/Tamil.txt", 0x3c545e66),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-telu")
+        )
+    )]
+    ("diffenator/Telugu.txt", 0xd2271718),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thai")
+        )
+    )]
+    ("diffenator/Thai.txt", 0x9fd5b10e),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thaa")
+        )
+    )]
+    ("diffenator/Thanaa.txt", 0xd354ee95),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tibt")
+        )
+    )]
+    ("diffenator/Tibetan.txt", 0xbd5d6a94),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tfng")
+        )
+    )]
+    ("diffenator/Tifinagh.txt", 0xd1c39374),
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-vaii")
+        )
+    )]
+    ("diffenator/Vai.txt", 0xf9caf297),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
+        )
+    )]
+    ("libreoffice/af_ZA.txt", 0x314a9747),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-an",
+            )
+        )
+    )]
+    ("libreoffice/an_ES.txt", 0x21596abf),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
+        )
+    )]
+    ("libreoffice/ar.txt", 0x093db1c5),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
+        )
+    )]
+    ("libreoffice/as_IN.txt", 0x4e3066e8),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
+        )
+    )]
+    ("libreoffice/be-official.txt", 0x80d3d23c),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
+        )
+    )]
+    ("libreoffice/bg_BG.txt", 0x69170a31),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
+        )
+    )]
+    ("libreoffice/bn_BD.txt", 0x4020cebb),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-tibt",
+                feature = "lang-bo",
+            )
+        )
+    )]
+    ("libreoffice/bo.txt", 0x242f46f6),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-br",
+            )
+        )
+    )]
+    ("libreoffice/br_FR.txt", 0x1f2bf2bd),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
+        )
+    )]
+    ("libreoffice/bs_BA.txt", 0xb633a7e5),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )]
+    ("libreoffice/ca-valencia.txt", 0x42a1afaf),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
+        )
+    )]
+    ("libreoffice/ca.txt", 0x68d2a31b),
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-arab")
+        )
+    )]
+    ("libreoffice/ckb.txt", 0x9846e25c),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )]
-    "libreoffice/bn_BD.txt",
+    ("libreoffice/cs_CZ.txt", 0x30503e21),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-tibt",
-            feature = "lang-bo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )]
-    "libreoffice/bo.txt",
+    ("libreoffice/da_DK.txt", 0x2e8c62bb),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-br",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
-    "libreoffice/br_FR.txt",
+    ("libreoffice/de_AT_frami.txt", 0x38951278),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
-    "libreoffice/bs_BA.txt",
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-latn"))]
-    "libreoffice/ca-valencia.txt",
+    ("libreoffice/de_CH_frami.txt", 0x6d56b13b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
-    "libreoffice/ca.txt",
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-arab"))]
-    "libreoffice/ckb.txt",
+    ("libreoffice/de_DE_frami.txt", 0x841dd897),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )]
-    "libreoffice/cs_CZ.txt",
+    ("libreoffice/el_GR.txt", 0x340aed06),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
-    "libreoffice/da_DK.txt",
+    ("libreoffice/en_AU.txt", 0xc69af799),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
-    "libreoffice/de_AT_frami.txt",
+    ("libreoffice/en_CA.txt", 0x37252fd6),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
-    "libreoffice/de_CH_frami.txt",
+    ("libreoffice/en_GB.txt", 0x0ead1aeb),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
-    "libreoffice/de_DE_frami.txt",
+    ("libreoffice/en_US.txt", 0x61c16cb3),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
-    "libreoffice/el_GR.txt",
+    ("libreoffice/en_ZA.txt", 0xce592a9b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-eo",
+            )
         )
     )]
-    "libreoffice/en_AU.txt",
+    ("libreoffice/eo.txt", 0xf397c9b5),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/en_CA.txt",
+    ("libreoffice/es_AR.txt", 0x2f093fbf),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/en_GB.txt",
+    ("libreoffice/es_BO.txt", 0x45451680),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/en_US.txt",
+    ("libreoffice/es_CL.txt", 0x5f7b35fa),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/en_ZA.txt",
+    ("libreoffice/es_CO.txt", 0x51e70a88),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-eo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/eo.txt",
+    ("libreoffice/es_CR.txt", 0xc4dea78d),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_AR.txt",
+    ("libreoffice/es_CU.txt", 0xf711780e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_BO.txt",
+    ("libreoffice/es_DO.txt", 0x03315f69),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_CL.txt",
+    ("libreoffice/es_EC.txt", 0xd7e499fa),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_CO.txt",
+    ("libreoffice/es_ES.txt", 0x3a0241d8),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_CR.txt",
+    ("libreoffice/es_GQ.txt", 0xce52eba6),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_CU.txt",
+    ("libreoffice/es_GT.txt", 0x3eb980bd),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_DO.txt",
+    ("libreoffice/es_HN.txt", 0x4f42037c),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_EC.txt",
+    ("libreoffice/es_MX.txt", 0x7c3a0dc7),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_ES.txt",
+    ("libreoffice/es_NI.txt", 0x24ada3e5),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_GQ.txt",
+    ("libreoffice/es_PA.txt", 0x0b7d8d0f),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_GT.txt",
+    ("libreoffice/es_PE.txt", 0x323167cc),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_HN.txt",
+    ("libreoffice/es_PH.txt", 0x58136e4e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_MX.txt",
+    ("libreoffice/es_PR.txt", 0x2a4a202a),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_NI.txt",
+    ("libreoffice/es_PY.txt", 0x5ad3b382),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_PA.txt",
+    ("libreoffice/es_SV.txt", 0xe70c6434),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_PE.txt",
+    ("libreoffice/es_US.txt", 0x6ea7e247),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_PH.txt",
+    ("libreoffice/es_UY.txt", 0x552dd505),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
-    "libreoffice/es_PR.txt",
+    ("libreoffice/es_VE.txt", 0xc4060b8c),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )]
-    "libreoffice/es_PY.txt",
+    ("libreoffice/et_EE.txt", 0x7c90d349),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )]
-    "libreoffice/es_SV.txt",
+    ("libreoffice/fa-IR.txt", 0x068fec5e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )]
-    "libreoffice/es_US.txt",
+    ("libreoffice/fr.txt", 0x73c34d84),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gd",
+            )
         )
     )]
-    "libreoffice/es_UY.txt",
+    ("libreoffice/gd_GB.txt", 0x415ffc55),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )]
-    "libreoffice/es_VE.txt",
+    ("libreoffice/gl_ES.txt", 0xe213b869),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )]
-    "libreoffice/et_EE.txt",
+    ("libreoffice/gu_IN.txt", 0x36970b9e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
         )
     )]
-    "libreoffice/fa-IR.txt",
+    ("libreoffice/gug.txt", 0x6d325e36),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-hebr",
+                feature = "lang-he",
+            )
         )
     )]
-    "libreoffice/fr.txt",
+    ("libreoffice/he_IL.txt", 0x9ccec0f9),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gd",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )]
-    "libreoffice/gd_GB.txt",
+    ("libreoffice/hi_IN.txt", 0x0c3a8098),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )]
-    "libreoffice/gl_ES.txt",
+    ("libreoffice/hr_HR.txt", 0x715baede),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-id",
+            )
         )
     )]
-    "libreoffice/gu_IN.txt",
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-latn"))]
-    "libreoffice/gug.txt",
+    ("libreoffice/id_ID.txt", 0xd1392c55),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-hebr",
-            feature = "lang-he",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )]
-    "libreoffice/he_IL.txt",
+    ("libreoffice/is.txt", 0xc0c48e29),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )]
-    "libreoffice/hi_IN.txt",
+    ("libreoffice/it_IT.txt", 0xdee8ec24),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
         )
     )]
-    "libreoffice/hr_HR.txt",
+    ("libreoffice/kmr_Latn.txt", 0x430e7cc7),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-id",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )]
-    "libreoffice/id_ID.txt",
+    ("libreoffice/kn_IN.txt", 0xf512ed6b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )]
-    "libreoffice/is.txt",
+    ("libreoffice/lo_LA.txt", 0xdd01bbf5),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )]
-    "libreoffice/it_IT.txt",
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-latn"))]
-    "libreoffice/kmr_Latn.txt",
+    ("libreoffice/lt.txt", 0xd26dd12b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )]
-    "libreoffice/kn_IN.txt",
+    ("libreoffice/lv_LV.txt", 0x034941fa),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )]
-    "libreoffice/lo_LA.txt",
+    ("libreoffice/mr_IN.txt", 0xc1b33421),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )]
-    "libreoffice/lt.txt",
+    ("libreoffice/nb_NO.txt", 0xbd3ab2eb),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )]
-    "libreoffice/lv_LV.txt",
+    ("libreoffice/ne_NP.txt", 0x4c7b9ce3),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )]
-    "libreoffice/mr_IN.txt",
+    ("libreoffice/nl_NL.txt", 0x11ed15d5),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nn",
+            )
         )
     )]
-    "libreoffice/nb_NO.txt",
+    ("libreoffice/nn_NO.txt", 0xca57fb44),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-oc",
+            )
         )
     )]
-    "libreoffice/ne_NP.txt",
+    ("libreoffice/oc_FR.txt", 0x0ac6f599),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )]
-    "libreoffice/nl_NL.txt",
+    ("libreoffice/or_IN.txt", 0x55191452),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )]
-    "libreoffice/nn_NO.txt",
+    ("libreoffice/pa_IN.txt", 0xfcc2771a),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-oc",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )]
-    "libreoffice/oc_FR.txt",
+    ("libreoffice/pl_PL.txt", 0x77987596),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )]
-    "libreoffice/or_IN.txt",
+    ("libreoffice/pt_BR.txt", 0x359d059a),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )]
-    "libreoffice/pa_IN.txt",
+    ("libreoffice/pt_PT.txt", 0x23e267ab),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )]
-    "libreoffice/pl_PL.txt",
+    ("libreoffice/ro_RO.txt", 0xbc396dd6),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )]
-    "libreoffice/pt_BR.txt",
+    ("libreoffice/ru_RU.txt", 0x292d536b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-sa",
+            )
         )
     )]
-    "libreoffice/pt_PT.txt",
+    ("libreoffice/sa_IN.txt", 0x7b133de2),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )]
-    "libreoffice/ro_RO.txt",
+    ("libreoffice/si_LK.txt", 0x757a979e),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )]
-    "libreoffice/ru_RU.txt",
+    ("libreoffice/sk_SK.txt", 0x70107670),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-sa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )]
-    "libreoffice/sa_IN.txt",
+    ("libreoffice/sl_SI.txt", 0x5b4013ab),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )]
-    "libreoffice/si_LK.txt",
+    ("libreoffice/sq_AL.txt", 0x04fcfb90),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )]
-    "libreoffice/sk_SK.txt",
+    ("libreoffice/sr-Latn.txt", 0x34195e53),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )]
-    "libreoffice/sl_SI.txt",
+    ("libreoffice/sr.txt", 0x0b6faa89),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )]
-    "libreoffice/sq_AL.txt",
+    ("libreoffice/sv_FI.txt", 0x781d76dc),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )]
-    "libreoffice/sr-Latn.txt",
+    ("libreoffice/sv_SE.txt", 0xea7082d7),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )]
-    "libreoffice/sr.txt",
+    ("libreoffice/sw_TZ.txt", 0x136eea87),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )]
-    "libreoffice/sv_FI.txt",
+    ("libreoffice/ta_IN.txt", 0xd358d734),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )]
-    "libreoffice/sv_SE.txt",
+    ("libreoffice/te_IN.txt", 0x7bde1829),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )]
-    "libreoffice/sw_TZ.txt",
+    ("libreoffice/th_TH.txt", 0xea28f9b6),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )]
-    "libreoffice/ta_IN.txt",
+    ("libreoffice/tr_TR.txt", 0xf38e00d8),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )]
-    "libreoffice/te_IN.txt",
+    ("libreoffice/uk_UA.txt", 0x1c3d6f0b),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )]
-    "libreoffice/th_TH.txt",
+    ("libreoffice/vi_VN.txt", 0x1dc387b3),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
         )
     )]
-    "libreoffice/tr_TR.txt",
+    ("ucd/Emoji_All.txt", 0xeaae3b20),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
         )
     )]
-    "libreoffice/uk_UA.txt",
+    ("ucd/Emoji_Color.txt", 0x15e06444),
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
         )
     )]
-    "libreoffice/vi_VN.txt",
-    #[cfg(any(feature = "all", feature = "ucd", feature = "script-zyyy"))]
-    "ucd/Emoji_All.txt",
-    #[cfg(any(feature = "all", feature = "ucd", feature = "script-zyyy"))]
-    "ucd/Emoji_Color.txt",
-    #[cfg(any(feature = "all", feature = "ucd", feature = "script-zyyy"))]
-    "ucd/Emoji_Textual.txt",
+    ("ucd/Emoji_Textual.txt", 0xa577146e),
 ];
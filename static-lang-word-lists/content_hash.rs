@@ -0,0 +1,13 @@
+// Included by the crate, its build script & xtask, so they all hash word lists
+// the same way
+
+/// The CRC-32 of the words in a word list, each followed by a newline, so it
+/// doesn't depend on how the word list file was formatted (e.g. line endings).
+fn content_hash<'a>(words: impl IntoIterator<Item = &'a str>) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    words.into_iter().for_each(|word| {
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+    });
+    hasher.finalize()
+}
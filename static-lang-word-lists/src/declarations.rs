@@ -2,28 +2,36 @@
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_AFRIKAANS_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_afrikaans_latin", Some("Latn"), Some("af")), bytes :
+    ::WordListMetadata::new("aosp_afrikaans_latin", "aosp", Some("Latn"), Some("af"),
+    "Apache-2.0", 0x899394e1,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/af_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/af_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )
 )]
@@ -36,28 +44,36 @@ pub static AOSP_AFRIKAANS_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-ethi",
-            feature = "lang-am",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-ethi",
+                feature = "lang-am",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_AMHARIC_ETHIOPIC, metadata : crate
-    ::WordListMetadata::new("aosp_amharic_ethiopic", Some("Ethi"), Some("am")), bytes :
+    ::WordListMetadata::new("aosp_amharic_ethiopic", "aosp", Some("Ethi"), Some("am"),
+    "Apache-2.0", 0x04c9368b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/am_Ethi.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/am_Ethi.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-ethi",
-            feature = "lang-am",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-ethi",
+                feature = "lang-am",
+            )
         )
     )
 )]
@@ -70,28 +86,35 @@ pub static AOSP_AMHARIC_ETHIOPIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )
 )]
 word_list! {
-    ident : AOSP_ARABIC, metadata : crate ::WordListMetadata::new("aosp_arabic",
-    Some("Arab"), Some("ar")), bytes :
+    ident : AOSP_ARABIC, metadata : crate ::WordListMetadata::new("aosp_arabic", "aosp",
+    Some("Arab"), Some("ar"), "Apache-2.0", 0xad6de64a,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ar_Arab.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ar_Arab.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )
 )]
@@ -104,28 +127,36 @@ pub static AOSP_ARABIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ASSAMESE_BENGALI, metadata : crate
-    ::WordListMetadata::new("aosp_assamese_bengali", Some("Beng"), Some("as")), bytes :
+    ::WordListMetadata::new("aosp_assamese_bengali", "aosp", Some("Beng"), Some("as"),
+    "Apache-2.0", 0x95879efd,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/as_Beng.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/as_Beng.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )
 )]
@@ -138,28 +169,36 @@ pub static AOSP_ASSAMESE_BENGALI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-az",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-az",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_AZERBAIJANI_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_azerbaijani_latin", Some("Latn"), Some("az")), bytes :
+    ::WordListMetadata::new("aosp_azerbaijani_latin", "aosp", Some("Latn"), Some("az"),
+    "Apache-2.0", 0x224096d2,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/az_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/az_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-az",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-az",
+            )
         )
     )
 )]
@@ -172,28 +211,36 @@ pub static AOSP_AZERBAIJANI_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BELARUSIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_belarusian_cyrillic", Some("Cyrl"), Some("be")), bytes
-    : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "aosp/be_Cyrl.txt", ".br")),
+    ::WordListMetadata::new("aosp_belarusian_cyrillic", "aosp", Some("Cyrl"), Some("be"),
+    "Apache-2.0", 0x49374c5a,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/be_Cyrl.txt",
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/be_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )
 )]
@@ -206,28 +253,36 @@ pub static AOSP_BELARUSIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-be",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BELARUSIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_belarusian_latin", Some("Latn"), Some("be")), bytes :
+    ::WordListMetadata::new("aosp_belarusian_latin", "aosp", Some("Latn"), Some("be"),
+    "Apache-2.0", 0x7ceca779,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/be_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/be_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-be",
+            )
         )
     )
 )]
@@ -240,28 +295,36 @@ pub static AOSP_BELARUSIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BULGARIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_bulgarian_cyrillic", Some("Cyrl"), Some("bg")), bytes :
+    ::WordListMetadata::new("aosp_bulgarian_cyrillic", "aosp", Some("Cyrl"), Some("bg"),
+    "Apache-2.0", 0xd5cbe926,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/bg_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/bg_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )
 )]
@@ -274,28 +337,35 @@ pub static AOSP_BULGARIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BENGALI, metadata : crate ::WordListMetadata::new("aosp_bengali",
-    Some("Beng"), Some("bn")), bytes :
+    "aosp", Some("Beng"), Some("bn"), "Apache-2.0", 0x72d4387e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/bn_Beng.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/bn_Beng.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )
 )]
@@ -308,28 +378,36 @@ pub static AOSP_BENGALI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BOSNIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_bosnian_latin", Some("Latn"), Some("bs")), bytes :
+    ::WordListMetadata::new("aosp_bosnian_latin", "aosp", Some("Latn"), Some("bs"),
+    "Apache-2.0", 0xdce9d500,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/bs_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/bs_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )
 )]
@@ -342,28 +420,36 @@ pub static AOSP_BOSNIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_CATALAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_catalan_latin", Some("Latn"), Some("ca")), bytes :
+    ::WordListMetadata::new("aosp_catalan_latin", "aosp", Some("Latn"), Some("ca"),
+    "Apache-2.0", 0x2bce9350,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ca_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ca_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )
 )]
@@ -376,28 +462,36 @@ pub static AOSP_CATALAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_CZECH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_czech_latin", Some("Latn"), Some("cs")), bytes :
+    ::WordListMetadata::new("aosp_czech_latin", "aosp", Some("Latn"), Some("cs"),
+    "Apache-2.0", 0x471f7eb2,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/cs_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/cs_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )
 )]
@@ -410,28 +504,36 @@ pub static AOSP_CZECH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_DANISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_danish_latin", Some("Latn"), Some("da")), bytes :
+    ::WordListMetadata::new("aosp_danish_latin", "aosp", Some("Latn"), Some("da"),
+    "Apache-2.0", 0x9616b7a2,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/da_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/da_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )
 )]
@@ -444,28 +546,36 @@ pub static AOSP_DANISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_GERMAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_german_latin", Some("Latn"), Some("de")), bytes :
+    ::WordListMetadata::new("aosp_german_latin", "aosp", Some("Latn"), Some("de"),
+    "Apache-2.0", 0xf1f97ab4,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/de_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/de_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
@@ -478,28 +588,36 @@ pub static AOSP_GERMAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_MODERN_GREEK, metadata : crate
-    ::WordListMetadata::new("aosp_modern_greek", Some("Grek"), Some("el")), bytes :
+    ::WordListMetadata::new("aosp_modern_greek", "aosp", Some("Grek"), Some("el"),
+    "Apache-2.0", 0xa81e02e9,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/el_Grek.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/el_Grek.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )
 )]
@@ -512,28 +630,36 @@ pub static AOSP_MODERN_GREEK: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ENGLISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_english_latin", Some("Latn"), Some("en")), bytes :
+    ::WordListMetadata::new("aosp_english_latin", "aosp", Some("Latn"), Some("en"),
+    "Apache-2.0", 0x61b122a5,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/en_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/en_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
@@ -546,28 +672,36 @@ pub static AOSP_ENGLISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SPANISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_spanish_latin", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("aosp_spanish_latin", "aosp", Some("Latn"), Some("es"),
+    "Apache-2.0", 0x53548ad3,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/es_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/es_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -580,28 +714,36 @@ pub static AOSP_SPANISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ESTONIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_estonian_latin", Some("Latn"), Some("et")), bytes :
+    ::WordListMetadata::new("aosp_estonian_latin", "aosp", Some("Latn"), Some("et"),
+    "Apache-2.0", 0xb28d4f4d,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/et_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/et_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )
 )]
@@ -614,28 +756,36 @@ pub static AOSP_ESTONIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-eu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-eu",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BASQUE_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_basque_latin", Some("Latn"), Some("eu")), bytes :
+    ::WordListMetadata::new("aosp_basque_latin", "aosp", Some("Latn"), Some("eu"),
+    "Apache-2.0", 0x4ca8a62e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/eu_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/eu_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-eu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-eu",
+            )
         )
     )
 )]
@@ -648,28 +798,36 @@ pub static AOSP_BASQUE_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_PERSIAN_ARABIC, metadata : crate
-    ::WordListMetadata::new("aosp_persian_arabic", Some("Arab"), Some("fa")), bytes :
+    ::WordListMetadata::new("aosp_persian_arabic", "aosp", Some("Arab"), Some("fa"),
+    "Apache-2.0", 0xec59c42c,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/fa_Arab.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/fa_Arab.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )
 )]
@@ -682,28 +840,36 @@ pub static AOSP_PERSIAN_ARABIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fi",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_FINNISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_finnish_latin", Some("Latn"), Some("fi")), bytes :
+    ::WordListMetadata::new("aosp_finnish_latin", "aosp", Some("Latn"), Some("fi"),
+    "Apache-2.0", 0x30d1e458,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/fi_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/fi_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fi",
+            )
         )
     )
 )]
@@ -716,28 +882,36 @@ pub static AOSP_FINNISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_FRENCH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_french_latin", Some("Latn"), Some("fr")), bytes :
+    ::WordListMetadata::new("aosp_french_latin", "aosp", Some("Latn"), Some("fr"),
+    "Apache-2.0", 0x888af7b8,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/fr_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/fr_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )
 )]
@@ -750,28 +924,36 @@ pub static AOSP_FRENCH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_GALICIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_galician_latin", Some("Latn"), Some("gl")), bytes :
+    ::WordListMetadata::new("aosp_galician_latin", "aosp", Some("Latn"), Some("gl"),
+    "Apache-2.0", 0xd8204d26,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/gl_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/gl_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )
 )]
@@ -784,28 +966,35 @@ pub static AOSP_GALICIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_GUJARATI, metadata : crate ::WordListMetadata::new("aosp_gujarati",
-    Some("Gujr"), Some("gu")), bytes :
+    "aosp", Some("Gujr"), Some("gu"), "Apache-2.0", 0xa98da351,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/gu_Gujr.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/gu_Gujr.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )
 )]
@@ -818,28 +1007,36 @@ pub static AOSP_GUJARATI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_HINDI_DEVANAGARI, metadata : crate
-    ::WordListMetadata::new("aosp_hindi_devanagari", Some("Deva"), Some("hi")), bytes :
+    ::WordListMetadata::new("aosp_hindi_devanagari", "aosp", Some("Deva"), Some("hi"),
+    "Apache-2.0", 0x5dbc4b97,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/hi_Deva.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/hi_Deva.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )
 )]
@@ -852,28 +1049,36 @@ pub static AOSP_HINDI_DEVANAGARI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hi",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_HINDI_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_hindi_latin", Some("Latn"), Some("hi")), bytes :
+    ::WordListMetadata::new("aosp_hindi_latin", "aosp", Some("Latn"), Some("hi"),
+    "Apache-2.0", 0xc376a975,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/hi_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/hi_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hi",
+            )
         )
     )
 )]
@@ -886,28 +1091,36 @@ pub static AOSP_HINDI_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_CROATIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_croatian_latin", Some("Latn"), Some("hr")), bytes :
+    ::WordListMetadata::new("aosp_croatian_latin", "aosp", Some("Latn"), Some("hr"),
+    "Apache-2.0", 0x1e65bbab,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/hr_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/hr_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )
 )]
@@ -920,28 +1133,36 @@ pub static AOSP_CROATIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hu",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_HUNGARIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_hungarian_latin", Some("Latn"), Some("hu")), bytes :
+    ::WordListMetadata::new("aosp_hungarian_latin", "aosp", Some("Latn"), Some("hu"),
+    "Apache-2.0", 0x4684a883,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/hu_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/hu_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hu",
+            )
         )
     )
 )]
@@ -954,28 +1175,35 @@ pub static AOSP_HUNGARIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-armn",
-            feature = "lang-hy",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-armn",
+                feature = "lang-hy",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ARMENIAN, metadata : crate ::WordListMetadata::new("aosp_armenian",
-    Some("Armn"), Some("hy")), bytes :
+    "aosp", Some("Armn"), Some("hy"), "Apache-2.0", 0x658673e7,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/hy_Armn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/hy_Armn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-armn",
-            feature = "lang-hy",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-armn",
+                feature = "lang-hy",
+            )
         )
     )
 )]
@@ -988,28 +1216,36 @@ pub static AOSP_ARMENIAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ICELANDIC_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_icelandic_latin", Some("Latn"), Some("is")), bytes :
+    ::WordListMetadata::new("aosp_icelandic_latin", "aosp", Some("Latn"), Some("is"),
+    "Apache-2.0", 0x5994cb49,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/is_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/is_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )
 )]
@@ -1022,28 +1258,36 @@ pub static AOSP_ICELANDIC_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ITALIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_italian_latin", Some("Latn"), Some("it")), bytes :
+    ::WordListMetadata::new("aosp_italian_latin", "aosp", Some("Latn"), Some("it"),
+    "Apache-2.0", 0x476982d6,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/it_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/it_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )
 )]
@@ -1056,28 +1300,36 @@ pub static AOSP_ITALIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hira",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hira",
+                feature = "lang-ja",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_JAPANESE_HIRAGANA, metadata : crate
-    ::WordListMetadata::new("aosp_japanese_hiragana", Some("Hira"), Some("ja")), bytes :
+    ::WordListMetadata::new("aosp_japanese_hiragana", "aosp", Some("Hira"), Some("ja"),
+    "Apache-2.0", 0xcc12f88e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ja_Hira.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ja_Hira.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hira",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hira",
+                feature = "lang-ja",
+            )
         )
     )
 )]
@@ -1090,28 +1342,36 @@ pub static AOSP_JAPANESE_HIRAGANA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-kana",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-kana",
+                feature = "lang-ja",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_JAPANESE_KATAKANA, metadata : crate
-    ::WordListMetadata::new("aosp_japanese_katakana", Some("Kana"), Some("ja")), bytes :
+    ::WordListMetadata::new("aosp_japanese_katakana", "aosp", Some("Kana"), Some("ja"),
+    "Apache-2.0", 0x2f08971a,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ja_Kana.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ja_Kana.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-kana",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-kana",
+                feature = "lang-ja",
+            )
         )
     )
 )]
@@ -1124,28 +1384,35 @@ pub static AOSP_JAPANESE_KATAKANA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-geor",
-            feature = "lang-ka",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-geor",
+                feature = "lang-ka",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_GEORGIAN, metadata : crate ::WordListMetadata::new("aosp_georgian",
-    Some("Geor"), Some("ka")), bytes :
+    "aosp", Some("Geor"), Some("ka"), "Apache-2.0", 0xaeeccf82,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ka_Geor.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ka_Geor.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-geor",
-            feature = "lang-ka",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-geor",
+                feature = "lang-ka",
+            )
         )
     )
 )]
@@ -1158,28 +1425,36 @@ pub static AOSP_GEORGIAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-kk",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_KAZAKH_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_kazakh_cyrillic", Some("Cyrl"), Some("kk")), bytes :
+    ::WordListMetadata::new("aosp_kazakh_cyrillic", "aosp", Some("Cyrl"), Some("kk"),
+    "Apache-2.0", 0xcd8e5605,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/kk_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/kk_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-kk",
+            )
         )
     )
 )]
@@ -1192,28 +1467,36 @@ pub static AOSP_KAZAKH_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-kk",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_KAZAKH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_kazakh_latin", Some("Latn"), Some("kk")), bytes :
+    ::WordListMetadata::new("aosp_kazakh_latin", "aosp", Some("Latn"), Some("kk"),
+    "Apache-2.0", 0x2943873f,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/kk_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/kk_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-kk",
+            )
         )
     )
 )]
@@ -1226,28 +1509,36 @@ pub static AOSP_KAZAKH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-khmr",
-            feature = "lang-km",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-khmr",
+                feature = "lang-km",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_CENTRAL_KHMER, metadata : crate
-    ::WordListMetadata::new("aosp_central_khmer", Some("Khmr"), Some("km")), bytes :
+    ::WordListMetadata::new("aosp_central_khmer", "aosp", Some("Khmr"), Some("km"),
+    "Apache-2.0", 0x8c84b3b4,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/km_Khmr.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/km_Khmr.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-khmr",
-            feature = "lang-km",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-khmr",
+                feature = "lang-km",
+            )
         )
     )
 )]
@@ -1260,28 +1551,35 @@ pub static AOSP_CENTRAL_KHMER: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_KANNADA, metadata : crate ::WordListMetadata::new("aosp_kannada",
-    Some("Knda"), Some("kn")), bytes :
+    "aosp", Some("Knda"), Some("kn"), "Apache-2.0", 0xcb991083,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/kn_Knda.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/kn_Knda.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )
 )]
@@ -1294,28 +1592,36 @@ pub static AOSP_KANNADA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hang",
-            feature = "lang-ko",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hang",
+                feature = "lang-ko",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_KOREAN_HANGUL, metadata : crate
-    ::WordListMetadata::new("aosp_korean_hangul", Some("Hang"), Some("ko")), bytes :
+    ::WordListMetadata::new("aosp_korean_hangul", "aosp", Some("Hang"), Some("ko"),
+    "Apache-2.0", 0x50266b57,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ko_Hang.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ko_Hang.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hang",
-            feature = "lang-ko",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hang",
+                feature = "lang-ko",
+            )
         )
     )
 )]
@@ -1328,28 +1634,36 @@ pub static AOSP_KOREAN_HANGUL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ky",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_KIRGHIZ_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_kirghiz_cyrillic", Some("Cyrl"), Some("ky")), bytes :
+    ::WordListMetadata::new("aosp_kirghiz_cyrillic", "aosp", Some("Cyrl"), Some("ky"),
+    "Apache-2.0", 0xa146a878,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ky_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ky_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ky",
+            )
         )
     )
 )]
@@ -1362,28 +1676,36 @@ pub static AOSP_KIRGHIZ_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ky",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_KIRGHIZ_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_kirghiz_latin", Some("Latn"), Some("ky")), bytes :
+    ::WordListMetadata::new("aosp_kirghiz_latin", "aosp", Some("Latn"), Some("ky"),
+    "Apache-2.0", 0xf9735b4b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ky_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ky_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ky",
+            )
         )
     )
 )]
@@ -1396,27 +1718,35 @@ pub static AOSP_KIRGHIZ_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )
 )]
 word_list! {
-    ident : AOSP_LAO, metadata : crate ::WordListMetadata::new("aosp_lao", Some("Laoo"),
-    Some("lo")), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
-    '/', "aosp/lo_Laoo.txt", ".br")),
+    ident : AOSP_LAO, metadata : crate ::WordListMetadata::new("aosp_lao", "aosp",
+    Some("Laoo"), Some("lo"), "Apache-2.0", 0x8aefaa0b,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/lo_Laoo.txt",
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/lo_Laoo.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )
 )]
@@ -1429,28 +1759,36 @@ pub static AOSP_LAO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_LITHUANIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_lithuanian_latin", Some("Latn"), Some("lt")), bytes :
+    ::WordListMetadata::new("aosp_lithuanian_latin", "aosp", Some("Latn"), Some("lt"),
+    "Apache-2.0", 0x892ec409,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/lt_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/lt_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )
 )]
@@ -1463,28 +1801,36 @@ pub static AOSP_LITHUANIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_LATVIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_latvian_latin", Some("Latn"), Some("lv")), bytes :
+    ::WordListMetadata::new("aosp_latvian_latin", "aosp", Some("Latn"), Some("lv"),
+    "Apache-2.0", 0x76029aa9,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/lv_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/lv_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )
 )]
@@ -1497,28 +1843,36 @@ pub static AOSP_LATVIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mk",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_MACEDONIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_macedonian_cyrillic", Some("Cyrl"), Some("mk")), bytes
-    : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "aosp/mk_Cyrl.txt", ".br")),
+    ::WordListMetadata::new("aosp_macedonian_cyrillic", "aosp", Some("Cyrl"), Some("mk"),
+    "Apache-2.0", 0x32c04b5e,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/mk_Cyrl.txt",
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/mk_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mk",
+            )
         )
     )
 )]
@@ -1531,28 +1885,35 @@ pub static AOSP_MACEDONIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mlym",
-            feature = "lang-ml",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mlym",
+                feature = "lang-ml",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_MALAYALAM, metadata : crate ::WordListMetadata::new("aosp_malayalam",
-    Some("Mlym"), Some("ml")), bytes :
+    "aosp", Some("Mlym"), Some("ml"), "Apache-2.0", 0xdaddb018,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ml_Mlym.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ml_Mlym.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mlym",
-            feature = "lang-ml",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mlym",
+                feature = "lang-ml",
+            )
         )
     )
 )]
@@ -1565,28 +1926,36 @@ pub static AOSP_MALAYALAM: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mn",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_MONGOLIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_mongolian_cyrillic", Some("Cyrl"), Some("mn")), bytes :
+    ::WordListMetadata::new("aosp_mongolian_cyrillic", "aosp", Some("Cyrl"), Some("mn"),
+    "Apache-2.0", 0x5f2b5ee6,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/mn_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/mn_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mn",
+            )
         )
     )
 )]
@@ -1599,28 +1968,36 @@ pub static AOSP_MONGOLIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_MARATHI_DEVANAGARI, metadata : crate
-    ::WordListMetadata::new("aosp_marathi_devanagari", Some("Deva"), Some("mr")), bytes :
+    ::WordListMetadata::new("aosp_marathi_devanagari", "aosp", Some("Deva"), Some("mr"),
+    "Apache-2.0", 0x00d7bb82,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/mr_Deva.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/mr_Deva.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )
 )]
@@ -1630,13 +2007,31 @@ word_list! {
 
 Compiled into the binary compressed with Brotli, decompressed at runtime.*/
 pub static AOSP_MARATHI_DEVANAGARI: crate::WordList = crate::WordList::stub();
-#[cfg(all(not(docsrs), any(feature = "all", feature = "aosp", feature = "lang-ms")))]
+#[cfg(
+    all(
+        not(docsrs),
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-ms")
+        )
+    )
+)]
 word_list! {
-    ident : AOSP_MALAY, metadata : crate ::WordListMetadata::new("aosp_malay", None,
-    Some("ms")), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
-    '/', "aosp/ms.txt", ".br")),
+    ident : AOSP_MALAY, metadata : crate ::WordListMetadata::new("aosp_malay", "aosp",
+    None, Some("ms"), "Apache-2.0", 0x9494815f,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ms.txt",
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ms.txt", ".idx")),
 }
-#[cfg(all(docsrs, any(feature = "all", feature = "aosp", feature = "lang-ms")))]
+#[cfg(
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-ms")
+        )
+    )
+)]
 ///The
 #[doc = ::std::stringify!(AOSP_MALAY)]
 /** word list.
@@ -1646,28 +2041,36 @@ pub static AOSP_MALAY: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mymr",
-            feature = "lang-my",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mymr",
+                feature = "lang-my",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_BURMESE_MYANMAR, metadata : crate
-    ::WordListMetadata::new("aosp_burmese_myanmar", Some("Mymr"), Some("my")), bytes :
+    ::WordListMetadata::new("aosp_burmese_myanmar", "aosp", Some("Mymr"), Some("my"),
+    "Apache-2.0", 0x7c7c5210,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/my_Mymr.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/my_Mymr.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mymr",
-            feature = "lang-my",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mymr",
+                feature = "lang-my",
+            )
         )
     )
 )]
@@ -1680,28 +2083,36 @@ pub static AOSP_BURMESE_MYANMAR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_NORWEGIAN_BOKMÅL_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_norwegian_bokmål_latin", Some("Latn"), Some("nb")),
-    bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "aosp/nb_Latn.txt", ".br")),
+    ::WordListMetadata::new("aosp_norwegian_bokmål_latin", "aosp", Some("Latn"),
+    Some("nb"), "Apache-2.0", 0x9082902b,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/nb_Latn.txt",
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/nb_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )
 )]
@@ -1714,28 +2125,36 @@ pub static AOSP_NORWEGIAN_BOKMÅL_LATIN: crate::WordList = crate::WordList::stub
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_NEPALI_DEVANAGARI, metadata : crate
-    ::WordListMetadata::new("aosp_nepali_devanagari", Some("Deva"), Some("ne")), bytes :
+    ::WordListMetadata::new("aosp_nepali_devanagari", "aosp", Some("Deva"), Some("ne"),
+    "Apache-2.0", 0x780ec8c8,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ne_Deva.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ne_Deva.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )
 )]
@@ -1748,28 +2167,36 @@ pub static AOSP_NEPALI_DEVANAGARI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_DUTCH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_dutch_latin", Some("Latn"), Some("nl")), bytes :
+    ::WordListMetadata::new("aosp_dutch_latin", "aosp", Some("Latn"), Some("nl"),
+    "Apache-2.0", 0xed943429,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/nl_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/nl_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )
 )]
@@ -1782,28 +2209,35 @@ pub static AOSP_DUTCH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )
 )]
 word_list! {
-    ident : AOSP_ORIYA, metadata : crate ::WordListMetadata::new("aosp_oriya",
-    Some("Orya"), Some("or")), bytes :
+    ident : AOSP_ORIYA, metadata : crate ::WordListMetadata::new("aosp_oriya", "aosp",
+    Some("Orya"), Some("or"), "Apache-2.0", 0x5e29cbba,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/or_Orya.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/or_Orya.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )
 )]
@@ -1816,28 +2250,36 @@ pub static AOSP_ORIYA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_PANJABI_GURMUKHI, metadata : crate
-    ::WordListMetadata::new("aosp_panjabi_gurmukhi", Some("Guru"), Some("pa")), bytes :
+    ::WordListMetadata::new("aosp_panjabi_gurmukhi", "aosp", Some("Guru"), Some("pa"),
+    "Apache-2.0", 0x3c400254,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/pa_Guru.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/pa_Guru.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )
 )]
@@ -1850,28 +2292,36 @@ pub static AOSP_PANJABI_GURMUKHI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_POLISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_polish_latin", Some("Latn"), Some("pl")), bytes :
+    ::WordListMetadata::new("aosp_polish_latin", "aosp", Some("Latn"), Some("pl"),
+    "Apache-2.0", 0x11e97dc2,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/pl_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/pl_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )
 )]
@@ -1884,28 +2334,36 @@ pub static AOSP_POLISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_PORTUGUESE_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_portuguese_latin", Some("Latn"), Some("pt")), bytes :
+    ::WordListMetadata::new("aosp_portuguese_latin", "aosp", Some("Latn"), Some("pt"),
+    "Apache-2.0", 0xaee9dafe,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/pt_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/pt_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )
 )]
@@ -1918,28 +2376,36 @@ pub static AOSP_PORTUGUESE_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-rm",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-rm",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ROMANSH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_romansh_latin", Some("Latn"), Some("rm")), bytes :
+    ::WordListMetadata::new("aosp_romansh_latin", "aosp", Some("Latn"), Some("rm"),
+    "Apache-2.0", 0x255e5f29,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/rm_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/rm_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-rm",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-rm",
+            )
         )
     )
 )]
@@ -1952,28 +2418,36 @@ pub static AOSP_ROMANSH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ROMANIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_romanian_latin", Some("Latn"), Some("ro")), bytes :
+    ::WordListMetadata::new("aosp_romanian_latin", "aosp", Some("Latn"), Some("ro"),
+    "Apache-2.0", 0x7ba79286,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ro_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ro_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )
 )]
@@ -1986,28 +2460,36 @@ pub static AOSP_ROMANIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_RUSSIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_russian_cyrillic", Some("Cyrl"), Some("ru")), bytes :
+    ::WordListMetadata::new("aosp_russian_cyrillic", "aosp", Some("Cyrl"), Some("ru"),
+    "Apache-2.0", 0x2cdfde86,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ru_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ru_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )
 )]
@@ -2020,28 +2502,35 @@ pub static AOSP_RUSSIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SINHALA, metadata : crate ::WordListMetadata::new("aosp_sinhala",
-    Some("Sinh"), Some("si")), bytes :
+    "aosp", Some("Sinh"), Some("si"), "Apache-2.0", 0x6a1d1ddf,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/si_Sinh.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/si_Sinh.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )
 )]
@@ -2054,28 +2543,36 @@ pub static AOSP_SINHALA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SLOVAK_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_slovak_latin", Some("Latn"), Some("sk")), bytes :
+    ::WordListMetadata::new("aosp_slovak_latin", "aosp", Some("Latn"), Some("sk"),
+    "Apache-2.0", 0xab66d475,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sk_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sk_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )
 )]
@@ -2088,28 +2585,36 @@ pub static AOSP_SLOVAK_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SLOVENIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_slovenian_latin", Some("Latn"), Some("sl")), bytes :
+    ::WordListMetadata::new("aosp_slovenian_latin", "aosp", Some("Latn"), Some("sl"),
+    "Apache-2.0", 0xb0c81a0b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sl_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sl_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )
 )]
@@ -2122,28 +2627,36 @@ pub static AOSP_SLOVENIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ALBANIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_albanian_latin", Some("Latn"), Some("sq")), bytes :
+    ::WordListMetadata::new("aosp_albanian_latin", "aosp", Some("Latn"), Some("sq"),
+    "Apache-2.0", 0xb0347f71,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sq_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sq_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )
 )]
@@ -2156,28 +2669,36 @@ pub static AOSP_ALBANIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SERBIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_serbian_cyrillic", Some("Cyrl"), Some("sr")), bytes :
+    ::WordListMetadata::new("aosp_serbian_cyrillic", "aosp", Some("Cyrl"), Some("sr"),
+    "Apache-2.0", 0x24c0d7ff,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sr_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sr_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )
 )]
@@ -2190,28 +2711,36 @@ pub static AOSP_SERBIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SERBIAN_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_serbian_latin", Some("Latn"), Some("sr")), bytes :
+    ::WordListMetadata::new("aosp_serbian_latin", "aosp", Some("Latn"), Some("sr"),
+    "Apache-2.0", 0x936fd02c,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sr_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sr_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )
 )]
@@ -2224,28 +2753,36 @@ pub static AOSP_SERBIAN_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SWEDISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_swedish_latin", Some("Latn"), Some("sv")), bytes :
+    ::WordListMetadata::new("aosp_swedish_latin", "aosp", Some("Latn"), Some("sv"),
+    "Apache-2.0", 0xef187e89,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sv_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sv_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )
 )]
@@ -2258,28 +2795,36 @@ pub static AOSP_SWEDISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_SWAHILI_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_swahili_latin", Some("Latn"), Some("sw")), bytes :
+    ::WordListMetadata::new("aosp_swahili_latin", "aosp", Some("Latn"), Some("sw"),
+    "Apache-2.0", 0x680670e5,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/sw_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/sw_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )
 )]
@@ -2292,28 +2837,35 @@ pub static AOSP_SWAHILI_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )
 )]
 word_list! {
-    ident : AOSP_TAMIL, metadata : crate ::WordListMetadata::new("aosp_tamil",
-    Some("Taml"), Some("ta")), bytes :
+    ident : AOSP_TAMIL, metadata : crate ::WordListMetadata::new("aosp_tamil", "aosp",
+    Some("Taml"), Some("ta"), "Apache-2.0", 0xdb25a808,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ta_Taml.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ta_Taml.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )
 )]
@@ -2326,28 +2878,35 @@ pub static AOSP_TAMIL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )
 )]
 word_list! {
-    ident : AOSP_TELUGU, metadata : crate ::WordListMetadata::new("aosp_telugu",
-    Some("Telu"), Some("te")), bytes :
+    ident : AOSP_TELUGU, metadata : crate ::WordListMetadata::new("aosp_telugu", "aosp",
+    Some("Telu"), Some("te"), "Apache-2.0", 0x21b80038,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/te_Telu.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/te_Telu.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )
 )]
@@ -2360,28 +2919,35 @@ pub static AOSP_TELUGU: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )
 )]
 word_list! {
-    ident : AOSP_THAI, metadata : crate ::WordListMetadata::new("aosp_thai",
-    Some("Thai"), Some("th")), bytes :
+    ident : AOSP_THAI, metadata : crate ::WordListMetadata::new("aosp_thai", "aosp",
+    Some("Thai"), Some("th"), "Apache-2.0", 0x6bf4bac0,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/th_Thai.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/th_Thai.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )
 )]
@@ -2391,13 +2957,31 @@ word_list! {
 
 Compiled into the binary compressed with Brotli, decompressed at runtime.*/
 pub static AOSP_THAI: crate::WordList = crate::WordList::stub();
-#[cfg(all(not(docsrs), any(feature = "all", feature = "aosp", feature = "lang-tl")))]
+#[cfg(
+    all(
+        not(docsrs),
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-tl")
+        )
+    )
+)]
 word_list! {
-    ident : AOSP_TAGALOG, metadata : crate ::WordListMetadata::new("aosp_tagalog", None,
-    Some("tl")), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
-    '/', "aosp/tl.txt", ".br")),
+    ident : AOSP_TAGALOG, metadata : crate ::WordListMetadata::new("aosp_tagalog",
+    "aosp", None, Some("tl"), "Apache-2.0", 0x5492e8de,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/tl.txt",
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/tl.txt", ".idx")),
 }
-#[cfg(all(docsrs, any(feature = "all", feature = "aosp", feature = "lang-tl")))]
+#[cfg(
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-tl")
+        )
+    )
+)]
 ///The
 #[doc = ::std::stringify!(AOSP_TAGALOG)]
 /** word list.
@@ -2407,28 +2991,36 @@ pub static AOSP_TAGALOG: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_TURKISH_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_turkish_latin", Some("Latn"), Some("tr")), bytes :
+    ::WordListMetadata::new("aosp_turkish_latin", "aosp", Some("Latn"), Some("tr"),
+    "Apache-2.0", 0x5236ca3b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/tr_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/tr_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )
 )]
@@ -2441,28 +3033,36 @@ pub static AOSP_TURKISH_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_UKRAINIAN_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("aosp_ukrainian_cyrillic", Some("Cyrl"), Some("uk")), bytes :
+    ::WordListMetadata::new("aosp_ukrainian_cyrillic", "aosp", Some("Cyrl"), Some("uk"),
+    "Apache-2.0", 0x44a1c4f2,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/uk_Cyrl.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/uk_Cyrl.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )
 )]
@@ -2475,28 +3075,36 @@ pub static AOSP_UKRAINIAN_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ur",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ur",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_URDU_ARABIC, metadata : crate
-    ::WordListMetadata::new("aosp_urdu_arabic", Some("Arab"), Some("ur")), bytes :
+    ::WordListMetadata::new("aosp_urdu_arabic", "aosp", Some("Arab"), Some("ur"),
+    "Apache-2.0", 0xf92a48bf,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/ur_Arab.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/ur_Arab.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ur",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ur",
+            )
         )
     )
 )]
@@ -2509,28 +3117,36 @@ pub static AOSP_URDU_ARABIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-uz",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-uz",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_UZBEK_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_uzbek_latin", Some("Latn"), Some("uz")), bytes :
+    ::WordListMetadata::new("aosp_uzbek_latin", "aosp", Some("Latn"), Some("uz"),
+    "Apache-2.0", 0xad20f48b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/uz_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/uz_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-uz",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-uz",
+            )
         )
     )
 )]
@@ -2543,28 +3159,36 @@ pub static AOSP_UZBEK_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_VIETNAMESE_LATIN, metadata : crate
-    ::WordListMetadata::new("aosp_vietnamese_latin", Some("Latn"), Some("vi")), bytes :
+    ::WordListMetadata::new("aosp_vietnamese_latin", "aosp", Some("Latn"), Some("vi"),
+    "Apache-2.0", 0xa9999a66,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/vi_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/vi_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )
 )]
@@ -2577,28 +3201,36 @@ pub static AOSP_VIETNAMESE_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hani",
-            feature = "lang-zh",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hani",
+                feature = "lang-zh",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_CHINESE_HAN, metadata : crate
-    ::WordListMetadata::new("aosp_chinese_han", Some("Hani"), Some("zh")), bytes :
+    ::WordListMetadata::new("aosp_chinese_han", "aosp", Some("Hani"), Some("zh"),
+    "Apache-2.0", 0x92b578c9,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/zh_Hani.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/zh_Hani.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hani",
-            feature = "lang-zh",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hani",
+                feature = "lang-zh",
+            )
         )
     )
 )]
@@ -2611,28 +3243,35 @@ pub static AOSP_CHINESE_HAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-zu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-zu",
+            )
         )
     )
 )]
 word_list! {
     ident : AOSP_ZULU_LATIN, metadata : crate ::WordListMetadata::new("aosp_zulu_latin",
-    Some("Latn"), Some("zu")), bytes :
+    "aosp", Some("Latn"), Some("zu"), "Apache-2.0", 0x59717bcc,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/', "aosp/zu_Latn.txt",
-    ".br")),
+    ".br")), index : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "aosp/zu_Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-zu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-zu",
+            )
         )
     )
 )]
@@ -2645,17 +3284,29 @@ pub static AOSP_ZULU_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-adlm")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-adlm")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_ADLAM, metadata : crate
-    ::WordListMetadata::new("diffenator_adlam", Some("Adlm"), None), bytes :
+    ::WordListMetadata::new("diffenator_adlam", "diffenator", Some("Adlm"), None,
+    "Apache-2.0", 0x4354ccb7,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Adlam.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Adlam.txt", ".br")),
+    "diffenator/Adlam.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-adlm"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-adlm")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_ADLAM)]
@@ -2666,17 +3317,29 @@ pub static DIFFENATOR_ADLAM: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-arab")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-arab")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_ARABIC, metadata : crate
-    ::WordListMetadata::new("diffenator_arabic", Some("Arab"), None), bytes :
+    ::WordListMetadata::new("diffenator_arabic", "diffenator", Some("Arab"), None,
+    "Apache-2.0", 0x5d08aa65,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Arabic.txt", ".br")),
+    "diffenator/Arabic.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Arabic.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-arab"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-arab")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_ARABIC)]
@@ -2687,17 +3350,29 @@ pub static DIFFENATOR_ARABIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-armn")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-armn")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_ARMENIAN, metadata : crate
-    ::WordListMetadata::new("diffenator_armenian", Some("Armn"), None), bytes :
+    ::WordListMetadata::new("diffenator_armenian", "diffenator", Some("Armn"), None,
+    "Apache-2.0", 0xbd8615c7,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Armenian.txt", ".br")),
+    "diffenator/Armenian.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Armenian.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-armn"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-armn")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_ARMENIAN)]
@@ -2708,17 +3383,29 @@ pub static DIFFENATOR_ARMENIAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-avst")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-avst")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_AVESTAN, metadata : crate
-    ::WordListMetadata::new("diffenator_avestan", Some("Avst"), None), bytes :
+    ::WordListMetadata::new("diffenator_avestan", "diffenator", Some("Avst"), None,
+    "Apache-2.0", 0x0711b9de,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Avestan.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Avestan.txt", ".br")),
+    "diffenator/Avestan.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-avst"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-avst")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_AVESTAN)]
@@ -2729,17 +3416,29 @@ pub static DIFFENATOR_AVESTAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-beng")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-beng")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_BENGALI, metadata : crate
-    ::WordListMetadata::new("diffenator_bengali", Some("Beng"), None), bytes :
+    ::WordListMetadata::new("diffenator_bengali", "diffenator", Some("Beng"), None,
+    "Apache-2.0", 0x23af0786,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Bengali.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Bengali.txt", ".br")),
+    "diffenator/Bengali.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-beng"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-beng")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_BENGALI)]
@@ -2750,17 +3449,29 @@ pub static DIFFENATOR_BENGALI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-bopo")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-bopo")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_BOPOMOFO, metadata : crate
-    ::WordListMetadata::new("diffenator_bopomofo", Some("Bopo"), None), bytes :
+    ::WordListMetadata::new("diffenator_bopomofo", "diffenator", Some("Bopo"), None,
+    "Apache-2.0", 0x15782874,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Bopomofo.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Bopomofo.txt", ".br")),
+    "diffenator/Bopomofo.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-bopo"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-bopo")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_BOPOMOFO)]
@@ -2771,17 +3482,29 @@ pub static DIFFENATOR_BOPOMOFO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-cans")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cans")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_CANADIAN_ABORIGINAL, metadata : crate
-    ::WordListMetadata::new("diffenator_canadian_aboriginal", Some("Cans"), None), bytes
-    : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Canadian_Aboriginal.txt", ".br")),
+    ::WordListMetadata::new("diffenator_canadian_aboriginal", "diffenator", Some("Cans"),
+    None, "Apache-2.0", 0x9d9d9c43,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Canadian_Aboriginal.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Canadian_Aboriginal.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-cans"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cans")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_CANADIAN_ABORIGINAL)]
@@ -2792,17 +3515,29 @@ pub static DIFFENATOR_CANADIAN_ABORIGINAL: crate::WordList = crate::WordList::st
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-cakm")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cakm")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_CHAKMA, metadata : crate
-    ::WordListMetadata::new("diffenator_chakma", Some("Cakm"), None), bytes :
+    ::WordListMetadata::new("diffenator_chakma", "diffenator", Some("Cakm"), None,
+    "Apache-2.0", 0x01059dff,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Chakma.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Chakma.txt", ".br")),
+    "diffenator/Chakma.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-cakm"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cakm")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_CHAKMA)]
@@ -2813,17 +3548,29 @@ pub static DIFFENATOR_CHAKMA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-cher")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cher")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_CHEROKEE, metadata : crate
-    ::WordListMetadata::new("diffenator_cherokee", Some("Cher"), None), bytes :
+    ::WordListMetadata::new("diffenator_cherokee", "diffenator", Some("Cher"), None,
+    "Apache-2.0", 0xbcff2e81,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Cherokee.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Cherokee.txt", ".br")),
+    "diffenator/Cherokee.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-cher"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cher")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_CHEROKEE)]
@@ -2834,17 +3581,29 @@ pub static DIFFENATOR_CHEROKEE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-zyyy")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-zyyy")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_COMMON, metadata : crate
-    ::WordListMetadata::new("diffenator_common", Some("Zyyy"), None), bytes :
+    ::WordListMetadata::new("diffenator_common", "diffenator", Some("Zyyy"), None,
+    "Apache-2.0", 0x15f1bd4c,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Common.txt", ".br")),
+    "diffenator/Common.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Common.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-zyyy"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-zyyy")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_COMMON)]
@@ -2855,17 +3614,29 @@ pub static DIFFENATOR_COMMON: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-cyrl")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cyrl")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_CYRILLIC, metadata : crate
-    ::WordListMetadata::new("diffenator_cyrillic", Some("Cyrl"), None), bytes :
+    ::WordListMetadata::new("diffenator_cyrillic", "diffenator", Some("Cyrl"), None,
+    "Apache-2.0", 0xbf83f3a3,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Cyrillic.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Cyrillic.txt", ".br")),
+    "diffenator/Cyrillic.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-cyrl"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cyrl")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_CYRILLIC)]
@@ -2876,17 +3647,29 @@ pub static DIFFENATOR_CYRILLIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-deva")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-deva")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_DEVANAGARI, metadata : crate
-    ::WordListMetadata::new("diffenator_devanagari", Some("Deva"), None), bytes :
+    ::WordListMetadata::new("diffenator_devanagari", "diffenator", Some("Deva"), None,
+    "Apache-2.0", 0xb461dfd7,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Devanagari.txt", ".br")),
+    "diffenator/Devanagari.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Devanagari.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-deva"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-deva")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_DEVANAGARI)]
@@ -2897,17 +3680,29 @@ pub static DIFFENATOR_DEVANAGARI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-ethi")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-ethi")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_ETHIOPIC, metadata : crate
-    ::WordListMetadata::new("diffenator_ethiopic", Some("Ethi"), None), bytes :
+    ::WordListMetadata::new("diffenator_ethiopic", "diffenator", Some("Ethi"), None,
+    "Apache-2.0", 0x6277706b,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Ethiopic.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Ethiopic.txt", ".br")),
+    "diffenator/Ethiopic.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-ethi"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-ethi")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_ETHIOPIC)]
@@ -2918,17 +3713,29 @@ pub static DIFFENATOR_ETHIOPIC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-geor")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-geor")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_GEORGIAN, metadata : crate
-    ::WordListMetadata::new("diffenator_georgian", Some("Geor"), None), bytes :
+    ::WordListMetadata::new("diffenator_georgian", "diffenator", Some("Geor"), None,
+    "Apache-2.0", 0xe366ecda,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Georgian.txt", ".br")),
+    "diffenator/Georgian.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Georgian.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-geor"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-geor")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_GEORGIAN)]
@@ -2939,17 +3746,29 @@ pub static DIFFENATOR_GEORGIAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-grek")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-grek")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_GREEK, metadata : crate
-    ::WordListMetadata::new("diffenator_greek", Some("Grek"), None), bytes :
+    ::WordListMetadata::new("diffenator_greek", "diffenator", Some("Grek"), None,
+    "Apache-2.0", 0x3c9129af,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Greek.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Greek.txt", ".br")),
+    "diffenator/Greek.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-grek"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-grek")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_GREEK)]
@@ -2960,17 +3779,29 @@ pub static DIFFENATOR_GREEK: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-gujr")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-gujr")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_GUJARATI, metadata : crate
-    ::WordListMetadata::new("diffenator_gujarati", Some("Gujr"), None), bytes :
+    ::WordListMetadata::new("diffenator_gujarati", "diffenator", Some("Gujr"), None,
+    "Apache-2.0", 0x2ada2da8,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Gujarati.txt", ".br")),
+    "diffenator/Gujarati.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Gujarati.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-gujr"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-gujr")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_GUJARATI)]
@@ -2981,17 +3812,29 @@ pub static DIFFENATOR_GUJARATI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-guru")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-guru")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_GURMUKHI, metadata : crate
-    ::WordListMetadata::new("diffenator_gurmukhi", Some("Guru"), None), bytes :
+    ::WordListMetadata::new("diffenator_gurmukhi", "diffenator", Some("Guru"), None,
+    "Apache-2.0", 0x6da8622c,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Gurmukhi.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Gurmukhi.txt", ".br")),
+    "diffenator/Gurmukhi.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-guru"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-guru")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_GURMUKHI)]
@@ -3002,17 +3845,29 @@ pub static DIFFENATOR_GURMUKHI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-hebr")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hebr")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_HEBREW, metadata : crate
-    ::WordListMetadata::new("diffenator_hebrew", Some("Hebr"), None), bytes :
+    ::WordListMetadata::new("diffenator_hebrew", "diffenator", Some("Hebr"), None,
+    "Apache-2.0", 0xadb12d1b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Hebrew.txt", ".br")),
+    "diffenator/Hebrew.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Hebrew.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-hebr"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hebr")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_HEBREW)]
@@ -3023,17 +3878,29 @@ pub static DIFFENATOR_HEBREW: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-hira")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hira")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_HIRAGANA, metadata : crate
-    ::WordListMetadata::new("diffenator_hiragana", Some("Hira"), None), bytes :
+    ::WordListMetadata::new("diffenator_hiragana", "diffenator", Some("Hira"), None,
+    "Apache-2.0", 0xd8dd86ef,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Hiragana.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Hiragana.txt", ".br")),
+    "diffenator/Hiragana.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-hira"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hira")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_HIRAGANA)]
@@ -3044,17 +3911,29 @@ pub static DIFFENATOR_HIRAGANA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-jpan")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-jpan")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_JAPANESE, metadata : crate
-    ::WordListMetadata::new("diffenator_japanese", Some("Jpan"), None), bytes :
+    ::WordListMetadata::new("diffenator_japanese", "diffenator", Some("Jpan"), None,
+    "Apache-2.0", 0x48a3683e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Japanese.txt", ".br")),
+    "diffenator/Japanese.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Japanese.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-jpan"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-jpan")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_JAPANESE)]
@@ -3065,17 +3944,29 @@ pub static DIFFENATOR_JAPANESE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-kana")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-kana")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_KATAKANA, metadata : crate
-    ::WordListMetadata::new("diffenator_katakana", Some("Kana"), None), bytes :
+    ::WordListMetadata::new("diffenator_katakana", "diffenator", Some("Kana"), None,
+    "Apache-2.0", 0x3df536e8,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Katakana.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Katakana.txt", ".br")),
+    "diffenator/Katakana.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-kana"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-kana")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_KATAKANA)]
@@ -3086,17 +3977,29 @@ pub static DIFFENATOR_KATAKANA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-khmr")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-khmr")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_KHMER, metadata : crate
-    ::WordListMetadata::new("diffenator_khmer", Some("Khmr"), None), bytes :
+    ::WordListMetadata::new("diffenator_khmer", "diffenator", Some("Khmr"), None,
+    "Apache-2.0", 0x9e77e515,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Khmer.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Khmer.txt", ".br")),
+    "diffenator/Khmer.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-khmr"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-khmr")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_KHMER)]
@@ -3107,17 +4010,28 @@ pub static DIFFENATOR_KHMER: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-laoo")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-laoo")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_LAO, metadata : crate ::WordListMetadata::new("diffenator_lao",
-    Some("Laoo"), None), bytes :
+    "diffenator", Some("Laoo"), None, "Apache-2.0", 0x6f2948ec,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Lao.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Lao.txt", ".br")),
+    "diffenator/Lao.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-laoo"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-laoo")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_LAO)]
@@ -3128,17 +4042,29 @@ pub static DIFFENATOR_LAO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-latn")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-latn")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_LATIN, metadata : crate
-    ::WordListMetadata::new("diffenator_latin", Some("Latn"), None), bytes :
+    ::WordListMetadata::new("diffenator_latin", "diffenator", Some("Latn"), None,
+    "Apache-2.0", 0xfa824902,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Latin.txt", ".br")),
+    "diffenator/Latin.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Latin.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-latn"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-latn")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_LATIN)]
@@ -3149,17 +4075,28 @@ pub static DIFFENATOR_LATIN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-lisu")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-lisu")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_LISU, metadata : crate ::WordListMetadata::new("diffenator_lisu",
-    Some("Lisu"), None), bytes :
+    "diffenator", Some("Lisu"), None, "Apache-2.0", 0xc23ca46d,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Lisu.txt", ".br")),
+    "diffenator/Lisu.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Lisu.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-lisu"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-lisu")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_LISU)]
@@ -3170,17 +4107,29 @@ pub static DIFFENATOR_LISU: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-mlym")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mlym")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_MALAYALAM, metadata : crate
-    ::WordListMetadata::new("diffenator_malayalam", Some("Mlym"), None), bytes :
+    ::WordListMetadata::new("diffenator_malayalam", "diffenator", Some("Mlym"), None,
+    "Apache-2.0", 0x36a83828,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Malayalam.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Malayalam.txt", ".br")),
+    "diffenator/Malayalam.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-mlym"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mlym")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_MALAYALAM)]
@@ -3191,17 +4140,29 @@ pub static DIFFENATOR_MALAYALAM: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-mong")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mong")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_MONGOLIAN, metadata : crate
-    ::WordListMetadata::new("diffenator_mongolian", Some("Mong"), None), bytes :
+    ::WordListMetadata::new("diffenator_mongolian", "diffenator", Some("Mong"), None,
+    "Apache-2.0", 0x387094f5,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Mongolian.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Mongolian.txt", ".br")),
+    "diffenator/Mongolian.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-mong"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mong")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_MONGOLIAN)]
@@ -3212,17 +4173,29 @@ pub static DIFFENATOR_MONGOLIAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-mymr")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mymr")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_MYANMAR, metadata : crate
-    ::WordListMetadata::new("diffenator_myanmar", Some("Mymr"), None), bytes :
+    ::WordListMetadata::new("diffenator_myanmar", "diffenator", Some("Mymr"), None,
+    "Apache-2.0", 0xdc4183c8,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Myanmar.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Myanmar.txt", ".br")),
+    "diffenator/Myanmar.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-mymr"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mymr")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_MYANMAR)]
@@ -3233,17 +4206,29 @@ pub static DIFFENATOR_MYANMAR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-olck")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-olck")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_OL_CHIKI, metadata : crate
-    ::WordListMetadata::new("diffenator_ol_chiki", Some("Olck"), None), bytes :
+    ::WordListMetadata::new("diffenator_ol_chiki", "diffenator", Some("Olck"), None,
+    "Apache-2.0", 0xf003c0be,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Ol_Chiki.txt", ".br")),
+    "diffenator/Ol_Chiki.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Ol_Chiki.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-olck"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-olck")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_OL_CHIKI)]
@@ -3254,17 +4239,29 @@ pub static DIFFENATOR_OL_CHIKI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-orya")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-orya")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_ORIYA, metadata : crate
-    ::WordListMetadata::new("diffenator_oriya", Some("Orya"), None), bytes :
+    ::WordListMetadata::new("diffenator_oriya", "diffenator", Some("Orya"), None,
+    "Apache-2.0", 0xc20b224d,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Oriya.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Oriya.txt", ".br")),
+    "diffenator/Oriya.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-orya"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-orya")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_ORIYA)]
@@ -3275,17 +4272,29 @@ pub static DIFFENATOR_ORIYA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-osge")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-osge")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_OSAGE, metadata : crate
-    ::WordListMetadata::new("diffenator_osage", Some("Osge"), None), bytes :
+    ::WordListMetadata::new("diffenator_osage", "diffenator", Some("Osge"), None,
+    "Apache-2.0", 0x45757243,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Osage.txt", ".br")),
+    "diffenator/Osage.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Osage.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-osge"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-osge")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_OSAGE)]
@@ -3296,17 +4305,29 @@ pub static DIFFENATOR_OSAGE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-sinh")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-sinh")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_SINHALA, metadata : crate
-    ::WordListMetadata::new("diffenator_sinhala", Some("Sinh"), None), bytes :
+    ::WordListMetadata::new("diffenator_sinhala", "diffenator", Some("Sinh"), None,
+    "Apache-2.0", 0x82d06d62,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Sinhala.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Sinhala.txt", ".br")),
+    "diffenator/Sinhala.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-sinh"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-sinh")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_SINHALA)]
@@ -3317,17 +4338,29 @@ pub static DIFFENATOR_SINHALA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-syrc")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-syrc")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_SYRIAC, metadata : crate
-    ::WordListMetadata::new("diffenator_syriac", Some("Syrc"), None), bytes :
+    ::WordListMetadata::new("diffenator_syriac", "diffenator", Some("Syrc"), None,
+    "Apache-2.0", 0x015cb37e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Syriac.txt", ".br")),
+    "diffenator/Syriac.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Syriac.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-syrc"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-syrc")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_SYRIAC)]
@@ -3338,17 +4371,29 @@ pub static DIFFENATOR_SYRIAC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-taml")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-taml")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_TAMIL, metadata : crate
-    ::WordListMetadata::new("diffenator_tamil", Some("Taml"), None), bytes :
+    ::WordListMetadata::new("diffenator_tamil", "diffenator", Some("Taml"), None,
+    "Apache-2.0", 0x3c545e66,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Tamil.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Tamil.txt", ".br")),
+    "diffenator/Tamil.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-taml"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-taml")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_TAMIL)]
@@ -3359,17 +4404,29 @@ pub static DIFFENATOR_TAMIL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-telu")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-telu")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_TELUGU, metadata : crate
-    ::WordListMetadata::new("diffenator_telugu", Some("Telu"), None), bytes :
+    ::WordListMetadata::new("diffenator_telugu", "diffenator", Some("Telu"), None,
+    "Apache-2.0", 0xd2271718,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Telugu.txt", ".br")),
+    "diffenator/Telugu.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Telugu.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-telu"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-telu")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_TELUGU)]
@@ -3380,17 +4437,28 @@ pub static DIFFENATOR_TELUGU: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-thai")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thai")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_THAI, metadata : crate ::WordListMetadata::new("diffenator_thai",
-    Some("Thai"), None), bytes :
+    "diffenator", Some("Thai"), None, "Apache-2.0", 0x9fd5b10e,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Thai.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Thai.txt", ".br")),
+    "diffenator/Thai.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-thai"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thai")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_THAI)]
@@ -3401,17 +4469,29 @@ pub static DIFFENATOR_THAI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-thaa")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thaa")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_THANAA, metadata : crate
-    ::WordListMetadata::new("diffenator_thanaa", Some("Thaa"), None), bytes :
+    ::WordListMetadata::new("diffenator_thanaa", "diffenator", Some("Thaa"), None,
+    "Apache-2.0", 0xd354ee95,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Thanaa.txt", ".br")),
+    "diffenator/Thanaa.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Thanaa.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-thaa"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thaa")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_THANAA)]
@@ -3422,17 +4502,29 @@ pub static DIFFENATOR_THANAA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-tibt")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tibt")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_TIBETAN, metadata : crate
-    ::WordListMetadata::new("diffenator_tibetan", Some("Tibt"), None), bytes :
+    ::WordListMetadata::new("diffenator_tibetan", "diffenator", Some("Tibt"), None,
+    "Apache-2.0", 0xbd5d6a94,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Tibetan.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Tibetan.txt", ".br")),
+    "diffenator/Tibetan.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-tibt"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tibt")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_TIBETAN)]
@@ -3443,17 +4535,29 @@ pub static DIFFENATOR_TIBETAN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-tfng")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tfng")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_TIFINAGH, metadata : crate
-    ::WordListMetadata::new("diffenator_tifinagh", Some("Tfng"), None), bytes :
+    ::WordListMetadata::new("diffenator_tifinagh", "diffenator", Some("Tfng"), None,
+    "Apache-2.0", 0xd1c39374,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Tifinagh.txt", ".br")),
+    "diffenator/Tifinagh.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Tifinagh.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-tfng"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tfng")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_TIFINAGH)]
@@ -3464,17 +4568,28 @@ pub static DIFFENATOR_TIFINAGH: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "diffenator", feature = "script-vaii")
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-vaii")
+        )
     )
 )]
 word_list! {
     ident : DIFFENATOR_VAI, metadata : crate ::WordListMetadata::new("diffenator_vai",
-    Some("Vaii"), None), bytes :
+    "diffenator", Some("Vaii"), None, "Apache-2.0", 0xf9caf297,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "diffenator/Vai.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "diffenator/Vai.txt", ".br")),
+    "diffenator/Vai.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "diffenator", feature = "script-vaii"))
+    all(
+        docsrs,
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-vaii")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(DIFFENATOR_VAI)]
@@ -3485,28 +4600,37 @@ pub static DIFFENATOR_VAI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_AF_ZA, metadata : crate
-    ::WordListMetadata::new("libreoffice_af_za", Some("Latn"), Some("af")), bytes :
+    ::WordListMetadata::new("libreoffice_af_za", "libreoffice", Some("Latn"), Some("af"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x314a9747,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/af_ZA.txt", ".br")),
+    "libreoffice/af_ZA.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/af_ZA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )
 )]
@@ -3519,28 +4643,37 @@ pub static LIBREOFFICE_AF_ZA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-an",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-an",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_AN_ES, metadata : crate
-    ::WordListMetadata::new("libreoffice_an_es", Some("Latn"), Some("an")), bytes :
+    ::WordListMetadata::new("libreoffice_an_es", "libreoffice", Some("Latn"), Some("an"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x21596abf,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/an_ES.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/an_ES.txt", ".br")),
+    "libreoffice/an_ES.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-an",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-an",
+            )
         )
     )
 )]
@@ -3553,28 +4686,36 @@ pub static LIBREOFFICE_AN_ES: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_AR, metadata : crate ::WordListMetadata::new("libreoffice_ar",
-    Some("Arab"), Some("ar")), bytes :
+    "libreoffice", Some("Arab"), Some("ar"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0x093db1c5,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/ar.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ar.txt", ".br")),
+    "libreoffice/ar.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )
 )]
@@ -3587,28 +4728,37 @@ pub static LIBREOFFICE_AR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_AS_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_as_in", Some("Beng"), Some("as")), bytes :
+    ::WordListMetadata::new("libreoffice_as_in", "libreoffice", Some("Beng"), Some("as"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x4e3066e8,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/as_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/as_IN.txt", ".br")),
+    "libreoffice/as_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )
 )]
@@ -3621,28 +4771,37 @@ pub static LIBREOFFICE_AS_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_BE_OFFICIAL, metadata : crate
-    ::WordListMetadata::new("libreoffice_be_official", Some("Cyrl"), Some("be")), bytes :
+    ::WordListMetadata::new("libreoffice_be_official", "libreoffice", Some("Cyrl"),
+    Some("be"), "MPL-2.0 OR LGPL-3.0-or-later", 0x80d3d23c,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/be-official.txt", ".br")),
+    "libreoffice/be-official.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/be-official.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )
 )]
@@ -3655,28 +4814,37 @@ pub static LIBREOFFICE_BE_OFFICIAL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_BG_BG, metadata : crate
-    ::WordListMetadata::new("libreoffice_bg_bg", Some("Cyrl"), Some("bg")), bytes :
+    ::WordListMetadata::new("libreoffice_bg_bg", "libreoffice", Some("Cyrl"), Some("bg"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x69170a31,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/bg_BG.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/bg_BG.txt", ".br")),
+    "libreoffice/bg_BG.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )
 )]
@@ -3689,28 +4857,37 @@ pub static LIBREOFFICE_BG_BG: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_BN_BD, metadata : crate
-    ::WordListMetadata::new("libreoffice_bn_bd", Some("Beng"), Some("bn")), bytes :
+    ::WordListMetadata::new("libreoffice_bn_bd", "libreoffice", Some("Beng"), Some("bn"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x4020cebb,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/bn_BD.txt", ".br")),
+    "libreoffice/bn_BD.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/bn_BD.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )
 )]
@@ -3723,28 +4900,36 @@ pub static LIBREOFFICE_BN_BD: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-tibt",
-            feature = "lang-bo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-tibt",
+                feature = "lang-bo",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_BO, metadata : crate ::WordListMetadata::new("libreoffice_bo",
-    Some("Tibt"), Some("bo")), bytes :
+    "libreoffice", Some("Tibt"), Some("bo"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0x242f46f6,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/bo.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/bo.txt", ".br")),
+    "libreoffice/bo.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-tibt",
-            feature = "lang-bo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-tibt",
+                feature = "lang-bo",
+            )
         )
     )
 )]
@@ -3757,28 +4942,37 @@ pub static LIBREOFFICE_BO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-br",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-br",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_BR_FR, metadata : crate
-    ::WordListMetadata::new("libreoffice_br_fr", Some("Latn"), Some("br")), bytes :
+    ::WordListMetadata::new("libreoffice_br_fr", "libreoffice", Some("Latn"), Some("br"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x1f2bf2bd,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/br_FR.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/br_FR.txt", ".br")),
+    "libreoffice/br_FR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-br",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-br",
+            )
         )
     )
 )]
@@ -3791,28 +4985,37 @@ pub static LIBREOFFICE_BR_FR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_BS_BA, metadata : crate
-    ::WordListMetadata::new("libreoffice_bs_ba", Some("Latn"), Some("bs")), bytes :
+    ::WordListMetadata::new("libreoffice_bs_ba", "libreoffice", Some("Latn"), Some("bs"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xb633a7e5,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/bs_BA.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/bs_BA.txt", ".br")),
+    "libreoffice/bs_BA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )
 )]
@@ -3825,17 +5028,29 @@ pub static LIBREOFFICE_BS_BA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_CA_VALENCIA, metadata : crate
-    ::WordListMetadata::new("libreoffice_ca_valencia", Some("Latn"), None), bytes :
+    ::WordListMetadata::new("libreoffice_ca_valencia", "libreoffice", Some("Latn"), None,
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x42a1afaf,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/ca-valencia.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ca-valencia.txt", ".br")),
+    "libreoffice/ca-valencia.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "libreoffice", feature = "script-latn"))
+    all(
+        docsrs,
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(LIBREOFFICE_CA_VALENCIA)]
@@ -3846,28 +5061,36 @@ pub static LIBREOFFICE_CA_VALENCIA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_CA, metadata : crate ::WordListMetadata::new("libreoffice_ca",
-    Some("Latn"), Some("ca")), bytes :
+    "libreoffice", Some("Latn"), Some("ca"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0x68d2a31b,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/ca.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ca.txt", ".br")),
+    "libreoffice/ca.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )
 )]
@@ -3880,17 +5103,28 @@ pub static LIBREOFFICE_CA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "libreoffice", feature = "script-arab")
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-arab")
+        )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_CKB, metadata : crate ::WordListMetadata::new("libreoffice_ckb",
-    Some("Arab"), None), bytes :
+    "libreoffice", Some("Arab"), None, "MPL-2.0 OR LGPL-3.0-or-later", 0x9846e25c,),
+    bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/ckb.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ckb.txt", ".br")),
+    "libreoffice/ckb.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "libreoffice", feature = "script-arab"))
+    all(
+        docsrs,
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-arab")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(LIBREOFFICE_CKB)]
@@ -3901,28 +5135,37 @@ pub static LIBREOFFICE_CKB: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_CS_CZ, metadata : crate
-    ::WordListMetadata::new("libreoffice_cs_cz", Some("Latn"), Some("cs")), bytes :
+    ::WordListMetadata::new("libreoffice_cs_cz", "libreoffice", Some("Latn"), Some("cs"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x30503e21,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/cs_CZ.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/cs_CZ.txt", ".br")),
+    "libreoffice/cs_CZ.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )
 )]
@@ -3935,28 +5178,37 @@ pub static LIBREOFFICE_CS_CZ: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_DA_DK, metadata : crate
-    ::WordListMetadata::new("libreoffice_da_dk", Some("Latn"), Some("da")), bytes :
+    ::WordListMetadata::new("libreoffice_da_dk", "libreoffice", Some("Latn"), Some("da"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x2e8c62bb,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/da_DK.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/da_DK.txt", ".br")),
+    "libreoffice/da_DK.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )
 )]
@@ -3969,28 +5221,37 @@ pub static LIBREOFFICE_DA_DK: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_DE_AT_FRAMI, metadata : crate
-    ::WordListMetadata::new("libreoffice_de_at_frami", Some("Latn"), Some("de")), bytes :
+    ::WordListMetadata::new("libreoffice_de_at_frami", "libreoffice", Some("Latn"),
+    Some("de"), "MPL-2.0 OR LGPL-3.0-or-later", 0x38951278,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/de_AT_frami.txt", ".br")),
+    "libreoffice/de_AT_frami.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/de_AT_frami.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
@@ -4003,28 +5264,37 @@ pub static LIBREOFFICE_DE_AT_FRAMI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_DE_CH_FRAMI, metadata : crate
-    ::WordListMetadata::new("libreoffice_de_ch_frami", Some("Latn"), Some("de")), bytes :
+    ::WordListMetadata::new("libreoffice_de_ch_frami", "libreoffice", Some("Latn"),
+    Some("de"), "MPL-2.0 OR LGPL-3.0-or-later", 0x6d56b13b,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/de_CH_frami.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/de_CH_frami.txt", ".br")),
+    "libreoffice/de_CH_frami.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
@@ -4037,28 +5307,37 @@ pub static LIBREOFFICE_DE_CH_FRAMI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_DE_DE_FRAMI, metadata : crate
-    ::WordListMetadata::new("libreoffice_de_de_frami", Some("Latn"), Some("de")), bytes :
+    ::WordListMetadata::new("libreoffice_de_de_frami", "libreoffice", Some("Latn"),
+    Some("de"), "MPL-2.0 OR LGPL-3.0-or-later", 0x841dd897,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/de_DE_frami.txt", ".br")),
+    "libreoffice/de_DE_frami.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/de_DE_frami.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )
 )]
@@ -4071,28 +5350,37 @@ pub static LIBREOFFICE_DE_DE_FRAMI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EL_GR, metadata : crate
-    ::WordListMetadata::new("libreoffice_el_gr", Some("Grek"), Some("el")), bytes :
+    ::WordListMetadata::new("libreoffice_el_gr", "libreoffice", Some("Grek"), Some("el"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x340aed06,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/el_GR.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/el_GR.txt", ".br")),
+    "libreoffice/el_GR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )
 )]
@@ -4105,28 +5393,37 @@ pub static LIBREOFFICE_EL_GR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EN_AU, metadata : crate
-    ::WordListMetadata::new("libreoffice_en_au", Some("Latn"), Some("en")), bytes :
+    ::WordListMetadata::new("libreoffice_en_au", "libreoffice", Some("Latn"), Some("en"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xc69af799,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/en_AU.txt", ".br")),
+    "libreoffice/en_AU.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/en_AU.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
@@ -4139,28 +5436,37 @@ pub static LIBREOFFICE_EN_AU: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EN_CA, metadata : crate
-    ::WordListMetadata::new("libreoffice_en_ca", Some("Latn"), Some("en")), bytes :
+    ::WordListMetadata::new("libreoffice_en_ca", "libreoffice", Some("Latn"), Some("en"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x37252fd6,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/en_CA.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/en_CA.txt", ".br")),
+    "libreoffice/en_CA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
@@ -4173,28 +5479,37 @@ pub static LIBREOFFICE_EN_CA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EN_GB, metadata : crate
-    ::WordListMetadata::new("libreoffice_en_gb", Some("Latn"), Some("en")), bytes :
+    ::WordListMetadata::new("libreoffice_en_gb", "libreoffice", Some("Latn"), Some("en"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x0ead1aeb,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/en_GB.txt", ".br")),
+    "libreoffice/en_GB.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/en_GB.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
@@ -4207,28 +5522,37 @@ pub static LIBREOFFICE_EN_GB: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EN_US, metadata : crate
-    ::WordListMetadata::new("libreoffice_en_us", Some("Latn"), Some("en")), bytes :
+    ::WordListMetadata::new("libreoffice_en_us", "libreoffice", Some("Latn"), Some("en"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x61c16cb3,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/en_US.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/en_US.txt", ".br")),
+    "libreoffice/en_US.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
@@ -4241,28 +5565,37 @@ pub static LIBREOFFICE_EN_US: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EN_ZA, metadata : crate
-    ::WordListMetadata::new("libreoffice_en_za", Some("Latn"), Some("en")), bytes :
+    ::WordListMetadata::new("libreoffice_en_za", "libreoffice", Some("Latn"), Some("en"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xce592a9b,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/en_ZA.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/en_ZA.txt", ".br")),
+    "libreoffice/en_ZA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )
 )]
@@ -4275,28 +5608,36 @@ pub static LIBREOFFICE_EN_ZA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-eo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-eo",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_EO, metadata : crate ::WordListMetadata::new("libreoffice_eo",
-    Some("Latn"), Some("eo")), bytes :
+    "libreoffice", Some("Latn"), Some("eo"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0xf397c9b5,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/eo.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/eo.txt", ".br")),
+    "libreoffice/eo.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-eo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-eo",
+            )
         )
     )
 )]
@@ -4309,28 +5650,37 @@ pub static LIBREOFFICE_EO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_AR, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_ar", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_ar", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x2f093fbf,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_AR.txt", ".br")),
+    "libreoffice/es_AR.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_AR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4343,28 +5693,37 @@ pub static LIBREOFFICE_ES_AR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_BO, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_bo", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_bo", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x45451680,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_BO.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_BO.txt", ".br")),
+    "libreoffice/es_BO.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4377,28 +5736,37 @@ pub static LIBREOFFICE_ES_BO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_CL, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_cl", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_cl", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x5f7b35fa,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_CL.txt", ".br")),
+    "libreoffice/es_CL.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_CL.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4411,28 +5779,37 @@ pub static LIBREOFFICE_ES_CL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_CO, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_co", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_co", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x51e70a88,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_CO.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_CO.txt", ".br")),
+    "libreoffice/es_CO.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4445,28 +5822,37 @@ pub static LIBREOFFICE_ES_CO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_CR, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_cr", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_cr", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xc4dea78d,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_CR.txt", ".br")),
+    "libreoffice/es_CR.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_CR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4479,28 +5865,37 @@ pub static LIBREOFFICE_ES_CR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_CU, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_cu", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_cu", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xf711780e,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_CU.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_CU.txt", ".br")),
+    "libreoffice/es_CU.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4513,28 +5908,37 @@ pub static LIBREOFFICE_ES_CU: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_DO, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_do", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_do", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x03315f69,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_DO.txt", ".br")),
+    "libreoffice/es_DO.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_DO.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4547,28 +5951,37 @@ pub static LIBREOFFICE_ES_DO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_EC, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_ec", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_ec", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xd7e499fa,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_EC.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_EC.txt", ".br")),
+    "libreoffice/es_EC.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4581,28 +5994,37 @@ pub static LIBREOFFICE_ES_EC: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_ES, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_es", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_es", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x3a0241d8,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_ES.txt", ".br")),
+    "libreoffice/es_ES.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_ES.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4615,28 +6037,37 @@ pub static LIBREOFFICE_ES_ES: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_GQ, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_gq", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_gq", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xce52eba6,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_GQ.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_GQ.txt", ".br")),
+    "libreoffice/es_GQ.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4649,28 +6080,37 @@ pub static LIBREOFFICE_ES_GQ: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_GT, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_gt", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_gt", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x3eb980bd,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_GT.txt", ".br")),
+    "libreoffice/es_GT.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_GT.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4683,28 +6123,37 @@ pub static LIBREOFFICE_ES_GT: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_HN, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_hn", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_hn", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x4f42037c,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_HN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_HN.txt", ".br")),
+    "libreoffice/es_HN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4717,28 +6166,37 @@ pub static LIBREOFFICE_ES_HN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_MX, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_mx", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_mx", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x7c3a0dc7,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_MX.txt", ".br")),
+    "libreoffice/es_MX.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_MX.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4751,28 +6209,37 @@ pub static LIBREOFFICE_ES_MX: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_NI, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_ni", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_ni", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x24ada3e5,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_NI.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_NI.txt", ".br")),
+    "libreoffice/es_NI.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4785,28 +6252,37 @@ pub static LIBREOFFICE_ES_NI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_PA, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_pa", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_pa", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x0b7d8d0f,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_PA.txt", ".br")),
+    "libreoffice/es_PA.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_PA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4819,28 +6295,37 @@ pub static LIBREOFFICE_ES_PA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_PE, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_pe", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_pe", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x323167cc,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_PE.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_PE.txt", ".br")),
+    "libreoffice/es_PE.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4853,28 +6338,37 @@ pub static LIBREOFFICE_ES_PE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_PH, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_ph", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_ph", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x58136e4e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_PH.txt", ".br")),
+    "libreoffice/es_PH.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_PH.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4887,28 +6381,37 @@ pub static LIBREOFFICE_ES_PH: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_PR, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_pr", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_pr", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x2a4a202a,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_PR.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_PR.txt", ".br")),
+    "libreoffice/es_PR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4921,28 +6424,37 @@ pub static LIBREOFFICE_ES_PR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_PY, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_py", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_py", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x5ad3b382,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_PY.txt", ".br")),
+    "libreoffice/es_PY.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_PY.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4955,28 +6467,37 @@ pub static LIBREOFFICE_ES_PY: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_SV, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_sv", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_sv", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xe70c6434,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_SV.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_SV.txt", ".br")),
+    "libreoffice/es_SV.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -4989,28 +6510,37 @@ pub static LIBREOFFICE_ES_SV: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_US, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_us", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_us", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x6ea7e247,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_US.txt", ".br")),
+    "libreoffice/es_US.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_US.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -5023,28 +6553,37 @@ pub static LIBREOFFICE_ES_US: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_UY, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_uy", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_uy", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x552dd505,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_UY.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_UY.txt", ".br")),
+    "libreoffice/es_UY.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -5057,28 +6596,37 @@ pub static LIBREOFFICE_ES_UY: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ES_VE, metadata : crate
-    ::WordListMetadata::new("libreoffice_es_ve", Some("Latn"), Some("es")), bytes :
+    ::WordListMetadata::new("libreoffice_es_ve", "libreoffice", Some("Latn"), Some("es"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xc4060b8c,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/es_VE.txt", ".br")),
+    "libreoffice/es_VE.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/es_VE.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )
 )]
@@ -5091,28 +6639,37 @@ pub static LIBREOFFICE_ES_VE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ET_EE, metadata : crate
-    ::WordListMetadata::new("libreoffice_et_ee", Some("Latn"), Some("et")), bytes :
+    ::WordListMetadata::new("libreoffice_et_ee", "libreoffice", Some("Latn"), Some("et"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x7c90d349,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/et_EE.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/et_EE.txt", ".br")),
+    "libreoffice/et_EE.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )
 )]
@@ -5125,28 +6682,37 @@ pub static LIBREOFFICE_ET_EE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_FA_IR, metadata : crate
-    ::WordListMetadata::new("libreoffice_fa_ir", Some("Arab"), Some("fa")), bytes :
+    ::WordListMetadata::new("libreoffice_fa_ir", "libreoffice", Some("Arab"), Some("fa"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x068fec5e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/fa-IR.txt", ".br")),
+    "libreoffice/fa-IR.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/fa-IR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )
 )]
@@ -5159,28 +6725,36 @@ pub static LIBREOFFICE_FA_IR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_FR, metadata : crate ::WordListMetadata::new("libreoffice_fr",
-    Some("Latn"), Some("fr")), bytes :
+    "libreoffice", Some("Latn"), Some("fr"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0x73c34d84,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/fr.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/fr.txt", ".br")),
+    "libreoffice/fr.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )
 )]
@@ -5193,28 +6767,37 @@ pub static LIBREOFFICE_FR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gd",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gd",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_GD_GB, metadata : crate
-    ::WordListMetadata::new("libreoffice_gd_gb", Some("Latn"), Some("gd")), bytes :
+    ::WordListMetadata::new("libreoffice_gd_gb", "libreoffice", Some("Latn"), Some("gd"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x415ffc55,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/gd_GB.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/gd_GB.txt", ".br")),
+    "libreoffice/gd_GB.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gd",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gd",
+            )
         )
     )
 )]
@@ -5227,28 +6810,37 @@ pub static LIBREOFFICE_GD_GB: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_GL_ES, metadata : crate
-    ::WordListMetadata::new("libreoffice_gl_es", Some("Latn"), Some("gl")), bytes :
+    ::WordListMetadata::new("libreoffice_gl_es", "libreoffice", Some("Latn"), Some("gl"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xe213b869,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/gl_ES.txt", ".br")),
+    "libreoffice/gl_ES.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/gl_ES.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )
 )]
@@ -5261,28 +6853,37 @@ pub static LIBREOFFICE_GL_ES: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_GU_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_gu_in", Some("Gujr"), Some("gu")), bytes :
+    ::WordListMetadata::new("libreoffice_gu_in", "libreoffice", Some("Gujr"), Some("gu"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x36970b9e,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/gu_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/gu_IN.txt", ".br")),
+    "libreoffice/gu_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )
 )]
@@ -5295,17 +6896,28 @@ pub static LIBREOFFICE_GU_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_GUG, metadata : crate ::WordListMetadata::new("libreoffice_gug",
-    Some("Latn"), None), bytes :
+    "libreoffice", Some("Latn"), None, "MPL-2.0 OR LGPL-3.0-or-later", 0x6d325e36,),
+    bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/gug.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/gug.txt", ".br")),
+    "libreoffice/gug.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "libreoffice", feature = "script-latn"))
+    all(
+        docsrs,
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(LIBREOFFICE_GUG)]
@@ -5316,28 +6928,37 @@ pub static LIBREOFFICE_GUG: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-hebr",
-            feature = "lang-he",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-hebr",
+                feature = "lang-he",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_HE_IL, metadata : crate
-    ::WordListMetadata::new("libreoffice_he_il", Some("Hebr"), Some("he")), bytes :
+    ::WordListMetadata::new("libreoffice_he_il", "libreoffice", Some("Hebr"), Some("he"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x9ccec0f9,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/he_IL.txt", ".br")),
+    "libreoffice/he_IL.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/he_IL.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-hebr",
-            feature = "lang-he",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-hebr",
+                feature = "lang-he",
+            )
         )
     )
 )]
@@ -5350,28 +6971,37 @@ pub static LIBREOFFICE_HE_IL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_HI_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_hi_in", Some("Deva"), Some("hi")), bytes :
+    ::WordListMetadata::new("libreoffice_hi_in", "libreoffice", Some("Deva"), Some("hi"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x0c3a8098,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/hi_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/hi_IN.txt", ".br")),
+    "libreoffice/hi_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )
 )]
@@ -5384,28 +7014,37 @@ pub static LIBREOFFICE_HI_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_HR_HR, metadata : crate
-    ::WordListMetadata::new("libreoffice_hr_hr", Some("Latn"), Some("hr")), bytes :
+    ::WordListMetadata::new("libreoffice_hr_hr", "libreoffice", Some("Latn"), Some("hr"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x715baede,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/hr_HR.txt", ".br")),
+    "libreoffice/hr_HR.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/hr_HR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )
 )]
@@ -5418,28 +7057,37 @@ pub static LIBREOFFICE_HR_HR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-id",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-id",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_ID_ID, metadata : crate
-    ::WordListMetadata::new("libreoffice_id_id", Some("Latn"), Some("id")), bytes :
+    ::WordListMetadata::new("libreoffice_id_id", "libreoffice", Some("Latn"), Some("id"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xd1392c55,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/id_ID.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/id_ID.txt", ".br")),
+    "libreoffice/id_ID.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-id",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-id",
+            )
         )
     )
 )]
@@ -5452,28 +7100,36 @@ pub static LIBREOFFICE_ID_ID: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_IS, metadata : crate ::WordListMetadata::new("libreoffice_is",
-    Some("Latn"), Some("is")), bytes :
+    "libreoffice", Some("Latn"), Some("is"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0xc0c48e29,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/is.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/is.txt", ".br")),
+    "libreoffice/is.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )
 )]
@@ -5486,28 +7142,37 @@ pub static LIBREOFFICE_IS: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_IT_IT, metadata : crate
-    ::WordListMetadata::new("libreoffice_it_it", Some("Latn"), Some("it")), bytes :
+    ::WordListMetadata::new("libreoffice_it_it", "libreoffice", Some("Latn"), Some("it"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xdee8ec24,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/it_IT.txt", ".br")),
+    "libreoffice/it_IT.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/it_IT.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )
 )]
@@ -5520,17 +7185,29 @@ pub static LIBREOFFICE_IT_IT: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_KMR_LATN, metadata : crate
-    ::WordListMetadata::new("libreoffice_kmr_latn", Some("Latn"), None), bytes :
+    ::WordListMetadata::new("libreoffice_kmr_latn", "libreoffice", Some("Latn"), None,
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x430e7cc7,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/kmr_Latn.txt", ".br")),
+    "libreoffice/kmr_Latn.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/kmr_Latn.txt", ".idx")),
 }
 #[cfg(
-    all(docsrs, any(feature = "all", feature = "libreoffice", feature = "script-latn"))
+    all(
+        docsrs,
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )
 )]
 ///The
 #[doc = ::std::stringify!(LIBREOFFICE_KMR_LATN)]
@@ -5541,28 +7218,37 @@ pub static LIBREOFFICE_KMR_LATN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_KN_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_kn_in", Some("Knda"), Some("kn")), bytes :
+    ::WordListMetadata::new("libreoffice_kn_in", "libreoffice", Some("Knda"), Some("kn"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xf512ed6b,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/kn_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/kn_IN.txt", ".br")),
+    "libreoffice/kn_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )
 )]
@@ -5575,28 +7261,37 @@ pub static LIBREOFFICE_KN_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_LO_LA, metadata : crate
-    ::WordListMetadata::new("libreoffice_lo_la", Some("Laoo"), Some("lo")), bytes :
+    ::WordListMetadata::new("libreoffice_lo_la", "libreoffice", Some("Laoo"), Some("lo"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xdd01bbf5,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/lo_LA.txt", ".br")),
+    "libreoffice/lo_LA.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/lo_LA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )
 )]
@@ -5609,28 +7304,36 @@ pub static LIBREOFFICE_LO_LA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_LT, metadata : crate ::WordListMetadata::new("libreoffice_lt",
-    Some("Latn"), Some("lt")), bytes :
+    "libreoffice", Some("Latn"), Some("lt"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0xd26dd12b,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/lt.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/lt.txt", ".br")),
+    "libreoffice/lt.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )
 )]
@@ -5643,28 +7346,37 @@ pub static LIBREOFFICE_LT: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_LV_LV, metadata : crate
-    ::WordListMetadata::new("libreoffice_lv_lv", Some("Latn"), Some("lv")), bytes :
+    ::WordListMetadata::new("libreoffice_lv_lv", "libreoffice", Some("Latn"), Some("lv"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x034941fa,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/lv_LV.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/lv_LV.txt", ".br")),
+    "libreoffice/lv_LV.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )
 )]
@@ -5677,28 +7389,37 @@ pub static LIBREOFFICE_LV_LV: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_MR_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_mr_in", Some("Deva"), Some("mr")), bytes :
+    ::WordListMetadata::new("libreoffice_mr_in", "libreoffice", Some("Deva"), Some("mr"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xc1b33421,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/mr_IN.txt", ".br")),
+    "libreoffice/mr_IN.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/mr_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )
 )]
@@ -5711,28 +7432,37 @@ pub static LIBREOFFICE_MR_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_NB_NO, metadata : crate
-    ::WordListMetadata::new("libreoffice_nb_no", Some("Latn"), Some("nb")), bytes :
+    ::WordListMetadata::new("libreoffice_nb_no", "libreoffice", Some("Latn"), Some("nb"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xbd3ab2eb,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/nb_NO.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/nb_NO.txt", ".br")),
+    "libreoffice/nb_NO.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )
 )]
@@ -5745,28 +7475,37 @@ pub static LIBREOFFICE_NB_NO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_NE_NP, metadata : crate
-    ::WordListMetadata::new("libreoffice_ne_np", Some("Deva"), Some("ne")), bytes :
+    ::WordListMetadata::new("libreoffice_ne_np", "libreoffice", Some("Deva"), Some("ne"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x4c7b9ce3,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ne_NP.txt", ".br")),
+    "libreoffice/ne_NP.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/ne_NP.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )
 )]
@@ -5779,28 +7518,37 @@ pub static LIBREOFFICE_NE_NP: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_NL_NL, metadata : crate
-    ::WordListMetadata::new("libreoffice_nl_nl", Some("Latn"), Some("nl")), bytes :
+    ::WordListMetadata::new("libreoffice_nl_nl", "libreoffice", Some("Latn"), Some("nl"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x11ed15d5,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/nl_NL.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/nl_NL.txt", ".br")),
+    "libreoffice/nl_NL.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )
 )]
@@ -5813,28 +7561,37 @@ pub static LIBREOFFICE_NL_NL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nn",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_NN_NO, metadata : crate
-    ::WordListMetadata::new("libreoffice_nn_no", Some("Latn"), Some("nn")), bytes :
+    ::WordListMetadata::new("libreoffice_nn_no", "libreoffice", Some("Latn"), Some("nn"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xca57fb44,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/nn_NO.txt", ".br")),
+    "libreoffice/nn_NO.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/nn_NO.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nn",
+            )
         )
     )
 )]
@@ -5847,28 +7604,37 @@ pub static LIBREOFFICE_NN_NO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-oc",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-oc",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_OC_FR, metadata : crate
-    ::WordListMetadata::new("libreoffice_oc_fr", Some("Latn"), Some("oc")), bytes :
+    ::WordListMetadata::new("libreoffice_oc_fr", "libreoffice", Some("Latn"), Some("oc"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x0ac6f599,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/oc_FR.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/oc_FR.txt", ".br")),
+    "libreoffice/oc_FR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-oc",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-oc",
+            )
         )
     )
 )]
@@ -5881,28 +7647,37 @@ pub static LIBREOFFICE_OC_FR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_OR_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_or_in", Some("Orya"), Some("or")), bytes :
+    ::WordListMetadata::new("libreoffice_or_in", "libreoffice", Some("Orya"), Some("or"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x55191452,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/or_IN.txt", ".br")),
+    "libreoffice/or_IN.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/or_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )
 )]
@@ -5915,28 +7690,37 @@ pub static LIBREOFFICE_OR_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_PA_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_pa_in", Some("Guru"), Some("pa")), bytes :
+    ::WordListMetadata::new("libreoffice_pa_in", "libreoffice", Some("Guru"), Some("pa"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xfcc2771a,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/pa_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/pa_IN.txt", ".br")),
+    "libreoffice/pa_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )
 )]
@@ -5949,28 +7733,37 @@ pub static LIBREOFFICE_PA_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_PL_PL, metadata : crate
-    ::WordListMetadata::new("libreoffice_pl_pl", Some("Latn"), Some("pl")), bytes :
+    ::WordListMetadata::new("libreoffice_pl_pl", "libreoffice", Some("Latn"), Some("pl"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x77987596,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/pl_PL.txt", ".br")),
+    "libreoffice/pl_PL.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/pl_PL.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )
 )]
@@ -5983,28 +7776,37 @@ pub static LIBREOFFICE_PL_PL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_PT_BR, metadata : crate
-    ::WordListMetadata::new("libreoffice_pt_br", Some("Latn"), Some("pt")), bytes :
+    ::WordListMetadata::new("libreoffice_pt_br", "libreoffice", Some("Latn"), Some("pt"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x359d059a,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/pt_BR.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/pt_BR.txt", ".br")),
+    "libreoffice/pt_BR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )
 )]
@@ -6017,28 +7819,37 @@ pub static LIBREOFFICE_PT_BR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_PT_PT, metadata : crate
-    ::WordListMetadata::new("libreoffice_pt_pt", Some("Latn"), Some("pt")), bytes :
+    ::WordListMetadata::new("libreoffice_pt_pt", "libreoffice", Some("Latn"), Some("pt"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x23e267ab,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/pt_PT.txt", ".br")),
+    "libreoffice/pt_PT.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/pt_PT.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )
 )]
@@ -6051,28 +7862,37 @@ pub static LIBREOFFICE_PT_PT: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_RO_RO, metadata : crate
-    ::WordListMetadata::new("libreoffice_ro_ro", Some("Latn"), Some("ro")), bytes :
+    ::WordListMetadata::new("libreoffice_ro_ro", "libreoffice", Some("Latn"), Some("ro"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xbc396dd6,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/ro_RO.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ro_RO.txt", ".br")),
+    "libreoffice/ro_RO.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )
 )]
@@ -6085,28 +7905,37 @@ pub static LIBREOFFICE_RO_RO: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_RU_RU, metadata : crate
-    ::WordListMetadata::new("libreoffice_ru_ru", Some("Cyrl"), Some("ru")), bytes :
+    ::WordListMetadata::new("libreoffice_ru_ru", "libreoffice", Some("Cyrl"), Some("ru"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x292d536b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ru_RU.txt", ".br")),
+    "libreoffice/ru_RU.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/ru_RU.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )
 )]
@@ -6119,28 +7948,37 @@ pub static LIBREOFFICE_RU_RU: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-sa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-sa",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SA_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_sa_in", Some("Deva"), Some("sa")), bytes :
+    ::WordListMetadata::new("libreoffice_sa_in", "libreoffice", Some("Deva"), Some("sa"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x7b133de2,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sa_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sa_IN.txt", ".br")),
+    "libreoffice/sa_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-sa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-sa",
+            )
         )
     )
 )]
@@ -6153,28 +7991,37 @@ pub static LIBREOFFICE_SA_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SI_LK, metadata : crate
-    ::WordListMetadata::new("libreoffice_si_lk", Some("Sinh"), Some("si")), bytes :
+    ::WordListMetadata::new("libreoffice_si_lk", "libreoffice", Some("Sinh"), Some("si"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x757a979e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/si_LK.txt", ".br")),
+    "libreoffice/si_LK.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/si_LK.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )
 )]
@@ -6187,28 +8034,37 @@ pub static LIBREOFFICE_SI_LK: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SK_SK, metadata : crate
-    ::WordListMetadata::new("libreoffice_sk_sk", Some("Latn"), Some("sk")), bytes :
+    ::WordListMetadata::new("libreoffice_sk_sk", "libreoffice", Some("Latn"), Some("sk"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x70107670,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sk_SK.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sk_SK.txt", ".br")),
+    "libreoffice/sk_SK.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )
 )]
@@ -6221,28 +8077,37 @@ pub static LIBREOFFICE_SK_SK: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SL_SI, metadata : crate
-    ::WordListMetadata::new("libreoffice_sl_si", Some("Latn"), Some("sl")), bytes :
+    ::WordListMetadata::new("libreoffice_sl_si", "libreoffice", Some("Latn"), Some("sl"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x5b4013ab,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sl_SI.txt", ".br")),
+    "libreoffice/sl_SI.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sl_SI.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )
 )]
@@ -6255,28 +8120,37 @@ pub static LIBREOFFICE_SL_SI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SQ_AL, metadata : crate
-    ::WordListMetadata::new("libreoffice_sq_al", Some("Latn"), Some("sq")), bytes :
+    ::WordListMetadata::new("libreoffice_sq_al", "libreoffice", Some("Latn"), Some("sq"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x04fcfb90,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sq_AL.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sq_AL.txt", ".br")),
+    "libreoffice/sq_AL.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )
 )]
@@ -6289,28 +8163,37 @@ pub static LIBREOFFICE_SQ_AL: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SR_LATN, metadata : crate
-    ::WordListMetadata::new("libreoffice_sr_latn", Some("Latn"), Some("sr")), bytes :
+    ::WordListMetadata::new("libreoffice_sr_latn", "libreoffice", Some("Latn"),
+    Some("sr"), "MPL-2.0 OR LGPL-3.0-or-later", 0x34195e53,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sr-Latn.txt", ".br")),
+    "libreoffice/sr-Latn.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sr-Latn.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )
 )]
@@ -6323,28 +8206,36 @@ pub static LIBREOFFICE_SR_LATN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SR, metadata : crate ::WordListMetadata::new("libreoffice_sr",
-    Some("Cyrl"), Some("sr")), bytes :
+    "libreoffice", Some("Cyrl"), Some("sr"), "MPL-2.0 OR LGPL-3.0-or-later",
+    0x0b6faa89,), bytes : ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"),
+    '/', "libreoffice/sr.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sr.txt", ".br")),
+    "libreoffice/sr.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )
 )]
@@ -6357,28 +8248,37 @@ pub static LIBREOFFICE_SR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SV_FI, metadata : crate
-    ::WordListMetadata::new("libreoffice_sv_fi", Some("Latn"), Some("sv")), bytes :
+    ::WordListMetadata::new("libreoffice_sv_fi", "libreoffice", Some("Latn"), Some("sv"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x781d76dc,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sv_FI.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sv_FI.txt", ".br")),
+    "libreoffice/sv_FI.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )
 )]
@@ -6391,28 +8291,37 @@ pub static LIBREOFFICE_SV_FI: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SV_SE, metadata : crate
-    ::WordListMetadata::new("libreoffice_sv_se", Some("Latn"), Some("sv")), bytes :
+    ::WordListMetadata::new("libreoffice_sv_se", "libreoffice", Some("Latn"), Some("sv"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xea7082d7,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sv_SE.txt", ".br")),
+    "libreoffice/sv_SE.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sv_SE.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )
 )]
@@ -6425,28 +8334,37 @@ pub static LIBREOFFICE_SV_SE: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_SW_TZ, metadata : crate
-    ::WordListMetadata::new("libreoffice_sw_tz", Some("Latn"), Some("sw")), bytes :
+    ::WordListMetadata::new("libreoffice_sw_tz", "libreoffice", Some("Latn"), Some("sw"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x136eea87,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/sw_TZ.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/sw_TZ.txt", ".br")),
+    "libreoffice/sw_TZ.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )
 )]
@@ -6459,28 +8377,37 @@ pub static LIBREOFFICE_SW_TZ: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_TA_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_ta_in", Some("Taml"), Some("ta")), bytes :
+    ::WordListMetadata::new("libreoffice_ta_in", "libreoffice", Some("Taml"), Some("ta"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xd358d734,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/ta_IN.txt", ".br")),
+    "libreoffice/ta_IN.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/ta_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )
 )]
@@ -6493,28 +8420,37 @@ pub static LIBREOFFICE_TA_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_TE_IN, metadata : crate
-    ::WordListMetadata::new("libreoffice_te_in", Some("Telu"), Some("te")), bytes :
+    ::WordListMetadata::new("libreoffice_te_in", "libreoffice", Some("Telu"), Some("te"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x7bde1829,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/te_IN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/te_IN.txt", ".br")),
+    "libreoffice/te_IN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )
 )]
@@ -6527,28 +8463,37 @@ pub static LIBREOFFICE_TE_IN: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_TH_TH, metadata : crate
-    ::WordListMetadata::new("libreoffice_th_th", Some("Thai"), Some("th")), bytes :
+    ::WordListMetadata::new("libreoffice_th_th", "libreoffice", Some("Thai"), Some("th"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xea28f9b6,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/th_TH.txt", ".br")),
+    "libreoffice/th_TH.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/th_TH.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )
 )]
@@ -6561,28 +8506,37 @@ pub static LIBREOFFICE_TH_TH: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_TR_TR, metadata : crate
-    ::WordListMetadata::new("libreoffice_tr_tr", Some("Latn"), Some("tr")), bytes :
+    ::WordListMetadata::new("libreoffice_tr_tr", "libreoffice", Some("Latn"), Some("tr"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0xf38e00d8,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/tr_TR.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/tr_TR.txt", ".br")),
+    "libreoffice/tr_TR.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )
 )]
@@ -6595,28 +8549,37 @@ pub static LIBREOFFICE_TR_TR: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_UK_UA, metadata : crate
-    ::WordListMetadata::new("libreoffice_uk_ua", Some("Cyrl"), Some("uk")), bytes :
+    ::WordListMetadata::new("libreoffice_uk_ua", "libreoffice", Some("Cyrl"), Some("uk"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x1c3d6f0b,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/uk_UA.txt", ".br")),
+    "libreoffice/uk_UA.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/uk_UA.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )
 )]
@@ -6629,28 +8592,37 @@ pub static LIBREOFFICE_UK_UA: crate::WordList = crate::WordList::stub();
 #[cfg(
     all(
         not(docsrs),
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )
 )]
 word_list! {
     ident : LIBREOFFICE_VI_VN, metadata : crate
-    ::WordListMetadata::new("libreoffice_vi_vn", Some("Latn"), Some("vi")), bytes :
+    ::WordListMetadata::new("libreoffice_vi_vn", "libreoffice", Some("Latn"), Some("vi"),
+    "MPL-2.0 OR LGPL-3.0-or-later", 0x1dc387b3,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "libreoffice/vi_VN.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "libreoffice/vi_VN.txt", ".br")),
+    "libreoffice/vi_VN.txt", ".idx")),
 }
 #[cfg(
     all(
         docsrs,
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )
 )]
@@ -6660,42 +8632,97 @@ word_list! {
 
 Compiled into the binary compressed with Brotli, decompressed at runtime.*/
 pub static LIBREOFFICE_VI_VN: crate::WordList = crate::WordList::stub();
-#[cfg(all(not(docsrs), any(feature = "all", feature = "ucd", feature = "script-zyyy")))]
+#[cfg(
+    all(
+        not(docsrs),
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )
+)]
 word_list! {
     ident : UCD_EMOJI_ALL, metadata : crate ::WordListMetadata::new("ucd_emoji_all",
-    Some("Zyyy"), None), bytes :
+    "ucd", Some("Zyyy"), None, "Unicode-3.0", 0xeaae3b20,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "ucd/Emoji_All.txt", ".br")),
+    "ucd/Emoji_All.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "ucd/Emoji_All.txt", ".idx")),
 }
-#[cfg(all(docsrs, any(feature = "all", feature = "ucd", feature = "script-zyyy")))]
+#[cfg(
+    all(
+        docsrs,
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )
+)]
 ///The
 #[doc = ::std::stringify!(UCD_EMOJI_ALL)]
 /** word list.
 
 Compiled into the binary compressed with Brotli, decompressed at runtime.*/
 pub static UCD_EMOJI_ALL: crate::WordList = crate::WordList::stub();
-#[cfg(all(not(docsrs), any(feature = "all", feature = "ucd", feature = "script-zyyy")))]
+#[cfg(
+    all(
+        not(docsrs),
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )
+)]
 word_list! {
     ident : UCD_EMOJI_COLOR, metadata : crate ::WordListMetadata::new("ucd_emoji_color",
-    Some("Zyyy"), None), bytes :
+    "ucd", Some("Zyyy"), None, "Unicode-3.0", 0x15e06444,), bytes :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "ucd/Emoji_Color.txt", ".br")), index :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "ucd/Emoji_Color.txt", ".br")),
+    "ucd/Emoji_Color.txt", ".idx")),
 }
-#[cfg(all(docsrs, any(feature = "all", feature = "ucd", feature = "script-zyyy")))]
+#[cfg(
+    all(
+        docsrs,
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )
+)]
 ///The
 #[doc = ::std::stringify!(UCD_EMOJI_COLOR)]
 /** word list.
 
 Compiled into the binary compressed with Brotli, decompressed at runtime.*/
 pub static UCD_EMOJI_COLOR: crate::WordList = crate::WordList::stub();
-#[cfg(all(not(docsrs), any(feature = "all", feature = "ucd", feature = "script-zyyy")))]
+#[cfg(
+    all(
+        not(docsrs),
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )
+)]
 word_list! {
     ident : UCD_EMOJI_TEXTUAL, metadata : crate
-    ::WordListMetadata::new("ucd_emoji_textual", Some("Zyyy"), None), bytes :
+    ::WordListMetadata::new("ucd_emoji_textual", "ucd", Some("Zyyy"), None,
+    "Unicode-3.0", 0xa577146e,), bytes :
     ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
-    "ucd/Emoji_Textual.txt", ".br")),
+    "ucd/Emoji_Textual.txt", ".br")), index :
+    ::std::include_bytes!(::std::concat!(::std::env!("OUT_DIR"), '/',
+    "ucd/Emoji_Textual.txt", ".idx")),
 }
-#[cfg(all(docsrs, any(feature = "all", feature = "ucd", feature = "script-zyyy")))]
+#[cfg(
+    all(
+        docsrs,
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )
+)]
 ///The
 #[doc = ::std::stringify!(UCD_EMOJI_TEXTUAL)]
 /** word list.
@@ -6705,1613 +8732,2362 @@ pub static UCD_EMOJI_TEXTUAL: crate::WordList = crate::WordList::stub();
 ///All the available [`WordList`](crate::WordList)s
 pub static ALL_WORD_LISTS: &[&crate::WordList] = &[
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )]
     &AOSP_AFRIKAANS_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-ethi",
-            feature = "lang-am",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-ethi",
+                feature = "lang-am",
+            )
         )
     )]
     &AOSP_AMHARIC_ETHIOPIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )]
     &AOSP_ARABIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )]
     &AOSP_ASSAMESE_BENGALI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-az",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-az",
+            )
         )
     )]
     &AOSP_AZERBAIJANI_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )]
     &AOSP_BELARUSIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-be",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-be",
+            )
         )
     )]
     &AOSP_BELARUSIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )]
     &AOSP_BULGARIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )]
     &AOSP_BENGALI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )]
     &AOSP_BOSNIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )]
     &AOSP_CATALAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )]
     &AOSP_CZECH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )]
     &AOSP_DANISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
     &AOSP_GERMAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )]
     &AOSP_MODERN_GREEK,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
     &AOSP_ENGLISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &AOSP_SPANISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )]
     &AOSP_ESTONIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-eu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-eu",
+            )
         )
     )]
     &AOSP_BASQUE_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )]
     &AOSP_PERSIAN_ARABIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fi",
+            )
         )
     )]
     &AOSP_FINNISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )]
     &AOSP_FRENCH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )]
     &AOSP_GALICIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )]
     &AOSP_GUJARATI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )]
     &AOSP_HINDI_DEVANAGARI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hi",
+            )
         )
     )]
     &AOSP_HINDI_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )]
     &AOSP_CROATIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-hu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-hu",
+            )
         )
     )]
     &AOSP_HUNGARIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-armn",
-            feature = "lang-hy",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-armn",
+                feature = "lang-hy",
+            )
         )
     )]
     &AOSP_ARMENIAN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )]
     &AOSP_ICELANDIC_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )]
     &AOSP_ITALIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hira",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hira",
+                feature = "lang-ja",
+            )
         )
     )]
     &AOSP_JAPANESE_HIRAGANA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-kana",
-            feature = "lang-ja",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-kana",
+                feature = "lang-ja",
+            )
         )
     )]
     &AOSP_JAPANESE_KATAKANA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-geor",
-            feature = "lang-ka",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-geor",
+                feature = "lang-ka",
+            )
         )
     )]
     &AOSP_GEORGIAN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-kk",
+            )
         )
     )]
     &AOSP_KAZAKH_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-kk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-kk",
+            )
         )
     )]
     &AOSP_KAZAKH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-khmr",
-            feature = "lang-km",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-khmr",
+                feature = "lang-km",
+            )
         )
     )]
     &AOSP_CENTRAL_KHMER,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )]
     &AOSP_KANNADA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hang",
-            feature = "lang-ko",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hang",
+                feature = "lang-ko",
+            )
         )
     )]
     &AOSP_KOREAN_HANGUL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ky",
+            )
         )
     )]
     &AOSP_KIRGHIZ_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ky",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ky",
+            )
         )
     )]
     &AOSP_KIRGHIZ_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )]
     &AOSP_LAO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )]
     &AOSP_LITHUANIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )]
     &AOSP_LATVIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mk",
+            )
         )
     )]
     &AOSP_MACEDONIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mlym",
-            feature = "lang-ml",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mlym",
+                feature = "lang-ml",
+            )
         )
     )]
     &AOSP_MALAYALAM,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-mn",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-mn",
+            )
         )
     )]
     &AOSP_MONGOLIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )]
     &AOSP_MARATHI_DEVANAGARI,
-    #[cfg(any(feature = "all", feature = "aosp", feature = "lang-ms"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-ms")
+        )
+    )]
     &AOSP_MALAY,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-mymr",
-            feature = "lang-my",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-mymr",
+                feature = "lang-my",
+            )
         )
     )]
     &AOSP_BURMESE_MYANMAR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )]
     &AOSP_NORWEGIAN_BOKMÅL_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )]
     &AOSP_NEPALI_DEVANAGARI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )]
     &AOSP_DUTCH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )]
     &AOSP_ORIYA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )]
     &AOSP_PANJABI_GURMUKHI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )]
     &AOSP_POLISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )]
     &AOSP_PORTUGUESE_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-rm",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-rm",
+            )
         )
     )]
     &AOSP_ROMANSH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )]
     &AOSP_ROMANIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )]
     &AOSP_RUSSIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )]
     &AOSP_SINHALA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )]
     &AOSP_SLOVAK_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )]
     &AOSP_SLOVENIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )]
     &AOSP_ALBANIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )]
     &AOSP_SERBIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )]
     &AOSP_SERBIAN_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )]
     &AOSP_SWEDISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )]
     &AOSP_SWAHILI_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )]
     &AOSP_TAMIL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )]
     &AOSP_TELUGU,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )]
     &AOSP_THAI,
-    #[cfg(any(feature = "all", feature = "aosp", feature = "lang-tl"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "aosp", feature = "lang-tl")
+        )
+    )]
     &AOSP_TAGALOG,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )]
     &AOSP_TURKISH_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )]
     &AOSP_UKRAINIAN_CYRILLIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-arab",
-            feature = "lang-ur",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-arab",
+                feature = "lang-ur",
+            )
         )
     )]
     &AOSP_URDU_ARABIC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-uz",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-uz",
+            )
         )
     )]
     &AOSP_UZBEK_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )]
     &AOSP_VIETNAMESE_LATIN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-hani",
-            feature = "lang-zh",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-hani",
+                feature = "lang-zh",
+            )
         )
     )]
     &AOSP_CHINESE_HAN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "aosp",
-            feature = "script-latn",
-            feature = "lang-zu",
+        all(
+            feature = "license-apache-2-0",
+            any(
+                feature = "all",
+                feature = "aosp",
+                feature = "script-latn",
+                feature = "lang-zu",
+            )
         )
     )]
     &AOSP_ZULU_LATIN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-adlm"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-adlm")
+        )
+    )]
     &DIFFENATOR_ADLAM,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-arab"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-arab")
+        )
+    )]
     &DIFFENATOR_ARABIC,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-armn"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-armn")
+        )
+    )]
     &DIFFENATOR_ARMENIAN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-avst"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-avst")
+        )
+    )]
     &DIFFENATOR_AVESTAN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-beng"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-beng")
+        )
+    )]
     &DIFFENATOR_BENGALI,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-bopo"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-bopo")
+        )
+    )]
     &DIFFENATOR_BOPOMOFO,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cans"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cans")
+        )
+    )]
     &DIFFENATOR_CANADIAN_ABORIGINAL,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cakm"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cakm")
+        )
+    )]
     &DIFFENATOR_CHAKMA,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cher"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cher")
+        )
+    )]
     &DIFFENATOR_CHEROKEE,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-zyyy"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-zyyy")
+        )
+    )]
     &DIFFENATOR_COMMON,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-cyrl"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-cyrl")
+        )
+    )]
     &DIFFENATOR_CYRILLIC,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-deva"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-deva")
+        )
+    )]
     &DIFFENATOR_DEVANAGARI,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-ethi"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-ethi")
+        )
+    )]
     &DIFFENATOR_ETHIOPIC,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-geor"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-geor")
+        )
+    )]
     &DIFFENATOR_GEORGIAN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-grek"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-grek")
+        )
+    )]
     &DIFFENATOR_GREEK,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-gujr"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-gujr")
+        )
+    )]
     &DIFFENATOR_GUJARATI,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-guru"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-guru")
+        )
+    )]
     &DIFFENATOR_GURMUKHI,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-hebr"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hebr")
+        )
+    )]
     &DIFFENATOR_HEBREW,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-hira"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-hira")
+        )
+    )]
     &DIFFENATOR_HIRAGANA,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-jpan"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-jpan")
+        )
+    )]
     &DIFFENATOR_JAPANESE,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-kana"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-kana")
+        )
+    )]
     &DIFFENATOR_KATAKANA,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-khmr"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-khmr")
+        )
+    )]
     &DIFFENATOR_KHMER,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-laoo"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-laoo")
+        )
+    )]
     &DIFFENATOR_LAO,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-latn"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-latn")
+        )
+    )]
     &DIFFENATOR_LATIN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-lisu"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-lisu")
+        )
+    )]
     &DIFFENATOR_LISU,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-mlym"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mlym")
+        )
+    )]
     &DIFFENATOR_MALAYALAM,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-mong"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mong")
+        )
+    )]
     &DIFFENATOR_MONGOLIAN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-mymr"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-mymr")
+        )
+    )]
     &DIFFENATOR_MYANMAR,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-olck"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-olck")
+        )
+    )]
     &DIFFENATOR_OL_CHIKI,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-orya"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-orya")
+        )
+    )]
     &DIFFENATOR_ORIYA,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-osge"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-osge")
+        )
+    )]
     &DIFFENATOR_OSAGE,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-sinh"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-sinh")
+        )
+    )]
     &DIFFENATOR_SINHALA,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-syrc"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-syrc")
+        )
+    )]
     &DIFFENATOR_SYRIAC,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-taml"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-taml")
+        )
+    )]
     &DIFFENATOR_TAMIL,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-telu"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-telu")
+        )
+    )]
     &DIFFENATOR_TELUGU,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-thai"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thai")
+        )
+    )]
     &DIFFENATOR_THAI,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-thaa"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-thaa")
+        )
+    )]
     &DIFFENATOR_THANAA,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-tibt"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tibt")
+        )
+    )]
     &DIFFENATOR_TIBETAN,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-tfng"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-tfng")
+        )
+    )]
     &DIFFENATOR_TIFINAGH,
-    #[cfg(any(feature = "all", feature = "diffenator", feature = "script-vaii"))]
+    #[cfg(
+        all(
+            feature = "license-apache-2-0",
+            any(feature = "all", feature = "diffenator", feature = "script-vaii")
+        )
+    )]
     &DIFFENATOR_VAI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-af",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-af",
+            )
         )
     )]
     &LIBREOFFICE_AF_ZA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-an",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-an",
+            )
         )
     )]
     &LIBREOFFICE_AN_ES,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-ar",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-ar",
+            )
         )
     )]
     &LIBREOFFICE_AR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-as",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-as",
+            )
         )
     )]
     &LIBREOFFICE_AS_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-be",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-be",
+            )
         )
     )]
     &LIBREOFFICE_BE_OFFICIAL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-bg",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-bg",
+            )
         )
     )]
     &LIBREOFFICE_BG_BG,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-beng",
-            feature = "lang-bn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-beng",
+                feature = "lang-bn",
+            )
         )
     )]
     &LIBREOFFICE_BN_BD,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-tibt",
-            feature = "lang-bo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-tibt",
+                feature = "lang-bo",
+            )
         )
     )]
     &LIBREOFFICE_BO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-br",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-br",
+            )
         )
     )]
     &LIBREOFFICE_BR_FR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-bs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-bs",
+            )
         )
     )]
     &LIBREOFFICE_BS_BA,
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-latn"))]
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )]
     &LIBREOFFICE_CA_VALENCIA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ca",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ca",
+            )
         )
     )]
     &LIBREOFFICE_CA,
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-arab"))]
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-arab")
+        )
+    )]
     &LIBREOFFICE_CKB,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-cs",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-cs",
+            )
         )
     )]
     &LIBREOFFICE_CS_CZ,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-da",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-da",
+            )
         )
     )]
     &LIBREOFFICE_DA_DK,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
     &LIBREOFFICE_DE_AT_FRAMI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
     &LIBREOFFICE_DE_CH_FRAMI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-de",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-de",
+            )
         )
     )]
     &LIBREOFFICE_DE_DE_FRAMI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-grek",
-            feature = "lang-el",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-grek",
+                feature = "lang-el",
+            )
         )
     )]
     &LIBREOFFICE_EL_GR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
     &LIBREOFFICE_EN_AU,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
     &LIBREOFFICE_EN_CA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
     &LIBREOFFICE_EN_GB,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
     &LIBREOFFICE_EN_US,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-en",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-en",
+            )
         )
     )]
     &LIBREOFFICE_EN_ZA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-eo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-eo",
+            )
         )
     )]
     &LIBREOFFICE_EO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_AR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_BO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_CL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_CO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_CR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_CU,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_DO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_EC,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_ES,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_GQ,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_GT,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_HN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_MX,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_NI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_PA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_PE,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_PH,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_PR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_PY,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_SV,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_US,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_UY,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-es",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-es",
+            )
         )
     )]
     &LIBREOFFICE_ES_VE,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-et",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-et",
+            )
         )
     )]
     &LIBREOFFICE_ET_EE,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-arab",
-            feature = "lang-fa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-arab",
+                feature = "lang-fa",
+            )
         )
     )]
     &LIBREOFFICE_FA_IR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-fr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-fr",
+            )
         )
     )]
     &LIBREOFFICE_FR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gd",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gd",
+            )
         )
     )]
     &LIBREOFFICE_GD_GB,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-gl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-gl",
+            )
         )
     )]
     &LIBREOFFICE_GL_ES,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-gujr",
-            feature = "lang-gu",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-gujr",
+                feature = "lang-gu",
+            )
         )
     )]
     &LIBREOFFICE_GU_IN,
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-latn"))]
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )]
     &LIBREOFFICE_GUG,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-hebr",
-            feature = "lang-he",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-hebr",
+                feature = "lang-he",
+            )
         )
     )]
     &LIBREOFFICE_HE_IL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-hi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-hi",
+            )
         )
     )]
     &LIBREOFFICE_HI_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-hr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-hr",
+            )
         )
     )]
     &LIBREOFFICE_HR_HR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-id",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-id",
+            )
         )
     )]
     &LIBREOFFICE_ID_ID,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-is",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-is",
+            )
         )
     )]
     &LIBREOFFICE_IS,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-it",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-it",
+            )
         )
     )]
     &LIBREOFFICE_IT_IT,
-    #[cfg(any(feature = "all", feature = "libreoffice", feature = "script-latn"))]
+    #[cfg(
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(feature = "all", feature = "libreoffice", feature = "script-latn")
+        )
+    )]
     &LIBREOFFICE_KMR_LATN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-knda",
-            feature = "lang-kn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-knda",
+                feature = "lang-kn",
+            )
         )
     )]
     &LIBREOFFICE_KN_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-laoo",
-            feature = "lang-lo",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-laoo",
+                feature = "lang-lo",
+            )
         )
     )]
     &LIBREOFFICE_LO_LA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lt",
+            )
         )
     )]
     &LIBREOFFICE_LT,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-lv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-lv",
+            )
         )
     )]
     &LIBREOFFICE_LV_LV,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-mr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-mr",
+            )
         )
     )]
     &LIBREOFFICE_MR_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nb",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nb",
+            )
         )
     )]
     &LIBREOFFICE_NB_NO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-ne",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-ne",
+            )
         )
     )]
     &LIBREOFFICE_NE_NP,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nl",
+            )
         )
     )]
     &LIBREOFFICE_NL_NL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-nn",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-nn",
+            )
         )
     )]
     &LIBREOFFICE_NN_NO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-oc",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-oc",
+            )
         )
     )]
     &LIBREOFFICE_OC_FR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-orya",
-            feature = "lang-or",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-orya",
+                feature = "lang-or",
+            )
         )
     )]
     &LIBREOFFICE_OR_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-guru",
-            feature = "lang-pa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-guru",
+                feature = "lang-pa",
+            )
         )
     )]
     &LIBREOFFICE_PA_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pl",
+            )
         )
     )]
     &LIBREOFFICE_PL_PL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )]
     &LIBREOFFICE_PT_BR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-pt",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-pt",
+            )
         )
     )]
     &LIBREOFFICE_PT_PT,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-ro",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-ro",
+            )
         )
     )]
     &LIBREOFFICE_RO_RO,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-ru",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-ru",
+            )
         )
     )]
     &LIBREOFFICE_RU_RU,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-deva",
-            feature = "lang-sa",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-deva",
+                feature = "lang-sa",
+            )
         )
     )]
     &LIBREOFFICE_SA_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-sinh",
-            feature = "lang-si",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-sinh",
+                feature = "lang-si",
+            )
         )
     )]
     &LIBREOFFICE_SI_LK,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sk",
+            )
         )
     )]
     &LIBREOFFICE_SK_SK,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sl",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sl",
+            )
         )
     )]
     &LIBREOFFICE_SL_SI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sq",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sq",
+            )
         )
     )]
     &LIBREOFFICE_SQ_AL,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sr",
+            )
         )
     )]
     &LIBREOFFICE_SR_LATN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-sr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-sr",
+            )
         )
     )]
     &LIBREOFFICE_SR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )]
     &LIBREOFFICE_SV_FI,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sv",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sv",
+            )
         )
     )]
     &LIBREOFFICE_SV_SE,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-sw",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-sw",
+            )
         )
     )]
     &LIBREOFFICE_SW_TZ,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-taml",
-            feature = "lang-ta",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-taml",
+                feature = "lang-ta",
+            )
         )
     )]
     &LIBREOFFICE_TA_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-telu",
-            feature = "lang-te",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-telu",
+                feature = "lang-te",
+            )
         )
     )]
     &LIBREOFFICE_TE_IN,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-thai",
-            feature = "lang-th",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-thai",
+                feature = "lang-th",
+            )
         )
     )]
     &LIBREOFFICE_TH_TH,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-tr",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-tr",
+            )
         )
     )]
     &LIBREOFFICE_TR_TR,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-cyrl",
-            feature = "lang-uk",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-cyrl",
+                feature = "lang-uk",
+            )
         )
     )]
     &LIBREOFFICE_UK_UA,
     #[cfg(
-        any(
-            feature = "all",
-            feature = "libreoffice",
-            feature = "script-latn",
-            feature = "lang-vi",
+        all(
+            feature = "license-mpl-2-0-or-lgpl-3-0-or-later",
+            any(
+                feature = "all",
+                feature = "libreoffice",
+                feature = "script-latn",
+                feature = "lang-vi",
+            )
         )
     )]
     &LIBREOFFICE_VI_VN,
-    #[cfg(any(feature = "all", feature = "ucd", feature = "script-zyyy"))]
+    #[cfg(
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )]
     &UCD_EMOJI_ALL,
-    #[cfg(any(feature = "all", feature = "ucd", feature = "script-zyyy"))]
+    #[cfg(
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )]
     &UCD_EMOJI_COLOR,
-    #[cfg(any(feature = "all", feature = "ucd", feature = "script-zyyy"))]
+    #[cfg(
+        all(
+            feature = "license-unicode-3-0",
+            any(feature = "all", feature = "ucd", feature = "script-zyyy")
+        )
+    )]
     &UCD_EMOJI_TEXTUAL,
 ];
@@ -0,0 +1,84 @@
+use std::{fmt, ops::Range, sync::Arc};
+
+use crate::{
+    newline_delimited_words,
+    word_lists::{WordBuffer, WordSource, WordStore},
+};
+
+/// A word list compiled into the binary, compressed in blocks by the build
+/// script.
+///
+/// See `compress` in the build script for the format of the index.
+pub(crate) struct EmbeddedWords {
+    name: &'static str,
+    compressed: &'static [u8],
+    index: &'static [u8],
+}
+
+impl EmbeddedWords {
+    /// Read a word list's index, without decompressing any of its words.
+    // Used by word_list!
+    pub(crate) fn load(
+        name: &'static str,
+        compressed: &'static [u8],
+        index: &'static [u8],
+    ) -> WordSource {
+        let embedded = EmbeddedWords {
+            name,
+            compressed,
+            index,
+        };
+        let len = embedded.read(0);
+        let words_per_block = embedded.read(1);
+        Arc::new(WordStore::lazy(len, words_per_block, embedded))
+    }
+
+    fn read(&self, position: usize) -> usize {
+        let bytes = &self.index[position * 4..position * 4 + 4];
+        u32::from_le_bytes(bytes.try_into().unwrap()) as usize
+    }
+
+    fn compressed_range(&self, block: usize) -> Range<usize> {
+        let start = match block {
+            0 => 0,
+            _ => self.read(2 * block),
+        };
+        start..self.read(2 * block + 2)
+    }
+
+    /// Decompress one block of words.
+    ///
+    /// # Panics
+    ///
+    /// If the block can't be decompressed, or doesn't match the checksum the
+    /// build script recorded for it.
+    pub(crate) fn block(&self, block: usize) -> WordBuffer {
+        let name = self.name;
+        let mut brotli_bytes = &self.compressed[self.compressed_range(block)];
+        let mut buf = Vec::with_capacity(brotli_bytes.len() * 4);
+        brotli_decompressor::BrotliDecompress(&mut brotli_bytes, &mut buf)
+            .unwrap_or_else(|err| panic!("failed to decode {name}: {err}"));
+        // Make sure we decompressed exactly what the build script validated
+        // before trusting it to be UTF-8
+        let checksum = crc32fast::hash(&buf);
+        let expected = self.read(2 * block + 3) as u32;
+        assert_eq!(
+            checksum, expected,
+            "{name} failed its integrity check (CRC-32 {checksum:#010x}, \
+             expected {expected:#010x}), the binary may be corrupted",
+        );
+        // SAFETY: the build script wrote the block from a &str, and the
+        // checksum above ensures these are the same bytes
+        let raw_words = unsafe { String::from_utf8_unchecked(buf) };
+        log::debug!("loaded block {block} of words for {name}");
+        newline_delimited_words(raw_words)
+    }
+}
+
+impl fmt::Debug for EmbeddedWords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmbeddedWords")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
@@ -16,8 +16,13 @@
 //! by name.
 //! The crate also provides the static [`ALL_WORD_LISTS`] for convenient
 //! iteration/filtering.
+//! To look word lists up by name, including ones created at runtime, use a
+//! [`WordListRegistry`].
 //!
-//! Word lists are decompressed when you call [`WordList::iter`].
+//! Word lists are decompressed lazily, in blocks of words, as their words are
+//! accessed (e.g. by [`WordList::iter`] or indexing).
+//! [`WordList::len`], [`WordList::slice`] & [`WordList::chunks`] don't need to
+//! decompress anything.
 //!
 //! ## Feature flags
 //!
@@ -66,29 +71,41 @@
 //! download by setting the environment variable `STATIC_LANG_WORD_LISTS_LOCAL`.
 //! Otherwise, you're welcome to audit the [build script](https://github.com/googlefonts/fontheight/blob/main/static-lang-word-lists/build.rs).
 
+mod embedded;
+mod registry;
+mod scripts;
+mod validation;
 mod word_lists;
 
+pub use registry::WordListRegistry;
+pub use scripts::{script_name, script_native_name};
+pub use validation::{WordIssue, WordIssueKind};
 pub(crate) use word_lists::WordListMetadata;
 #[cfg(feature = "rayon")]
 pub use word_lists::rayon::ParWordListIter;
-pub use word_lists::{WordList, WordListError, WordListIter};
+pub use word_lists::{WordList, WordListError, WordListId, WordListIter};
 
-use crate::word_lists::{Word, WordSource};
+use crate::word_lists::{WordBuffer, offset};
 
-fn newline_delimited_words(input: impl AsRef<str>) -> WordSource {
-    input
-        .as_ref()
+fn newline_delimited_words(input: String) -> WordBuffer {
+    // The words are kept in place, rather than copying them out from between
+    // the whitespace
+    let ranges = input
         .split_whitespace()
-        .filter(|word| !word.is_empty())
-        .map(Word::from)
-        .collect()
+        .map(|word| {
+            let start = word.as_ptr() as usize - input.as_ptr() as usize;
+            offset(start)..offset(start + word.len())
+        })
+        .collect();
+    WordBuffer::new(input, ranges)
 }
 
 macro_rules! word_list {
     (
         ident: $ident:ident,
         metadata: $metadata:expr,
-        bytes: $bytes:expr $(,)?
+        bytes: $bytes:expr,
+        index: $index:expr $(,)?
     ) => {
         /// The
         #[doc = ::std::stringify!($ident)]
@@ -99,24 +116,11 @@ macro_rules! word_list {
         pub static $ident: $crate::WordList = $crate::WordList::new_lazy(
             $metadata,
             ::std::sync::LazyLock::new(|| {
-                let mut brotli_bytes: &[u8] = $bytes;
-                let mut buf =
-                    ::std::vec::Vec::with_capacity(brotli_bytes.len());
-                ::brotli_decompressor::BrotliDecompress(
-                    &mut brotli_bytes,
-                    &mut buf,
+                $crate::embedded::EmbeddedWords::load(
+                    ::std::stringify!($ident),
+                    $bytes,
+                    $index,
                 )
-                .unwrap_or_else(|err| {
-                    ::std::panic!(
-                        "failed to decode {}: {err}",
-                        ::std::stringify!($ident),
-                    );
-                });
-                let raw_words =
-                    // SAFETY: UTF-8 validity is checked by the build script
-                    unsafe { ::std::string::String::from_utf8_unchecked(buf) };
-                ::log::debug!("loaded words for {}", ::std::stringify!($ident));
-                $crate::newline_delimited_words(raw_words)
             }),
         );
     };
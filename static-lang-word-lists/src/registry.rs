@@ -0,0 +1,97 @@
+use crate::{ALL_WORD_LISTS, WordList};
+
+/// The built-in word lists ([`ALL_WORD_LISTS`]), augmented with word lists
+/// registered at runtime, looked up by name or ID.
+///
+/// A registered word list overrides any built-in word list with the same
+/// name, so applications can replace the built-in corpus for a language with
+/// their own.
+///
+/// ```
+/// # use static_lang_word_lists::{WordList, WordListRegistry};
+/// let mut registry = WordListRegistry::new();
+/// registry.register(WordList::define("my_latin", ["hello", "world"]));
+///
+/// assert_eq!(registry.lookup("my_latin").unwrap().len(), 2);
+/// assert!(registry.lookup("diffenator/diffenator_latin").is_some());
+/// assert!(
+///     registry
+///         .list()
+///         .any(|word_list| word_list.name() == "my_latin")
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct WordListRegistry {
+    registered: Vec<WordList>,
+}
+
+impl WordListRegistry {
+    /// A registry of only the built-in word lists.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        WordListRegistry {
+            registered: Vec::new(),
+        }
+    }
+
+    /// Add `word_list` to the registry, overriding any built-in word list
+    /// with the same name.
+    ///
+    /// Returns the word list previously registered with the same name, if
+    /// there was one.
+    pub fn register(&mut self, word_list: WordList) -> Option<WordList> {
+        match self
+            .registered
+            .iter_mut()
+            .find(|registered| registered.name() == word_list.name())
+        {
+            Some(registered) => Some(std::mem::replace(registered, word_list)),
+            None => {
+                self.registered.push(word_list);
+                None
+            },
+        }
+    }
+
+    /// Find the word list with `name_or_id` as its name
+    /// ([`WordList::name`]) or ID ([`WordList::id`], e.g.
+    /// `diffenator/diffenator_latin`).
+    ///
+    /// Built-in word lists overridden by a registered one can't be found.
+    #[must_use]
+    pub fn lookup(&self, name_or_id: &str) -> Option<&WordList> {
+        self.list().find(|word_list| {
+            word_list.name() == name_or_id
+                || word_list.id().to_string() == name_or_id
+        })
+    }
+
+    /// The word lists in the registry: those registered, in the order they
+    /// were registered, followed by the built-in word lists they don't
+    /// override.
+    pub fn list(&self) -> impl Iterator<Item = &WordList> {
+        self.registered.iter().chain(
+            ALL_WORD_LISTS
+                .iter()
+                .copied()
+                .filter(|builtin| !self.is_overridden(builtin)),
+        )
+    }
+
+    /// The word lists registered at runtime, in the order they were
+    /// registered.
+    #[inline]
+    #[must_use]
+    pub fn registered(&self) -> &[WordList] {
+        &self.registered
+    }
+
+    /// Whether a registered word list overrides `builtin`.
+    #[must_use]
+    pub fn is_overridden(&self, builtin: &WordList) -> bool {
+        self.registered
+            .iter()
+            .any(|registered| registered.name() == builtin.name())
+    }
+}
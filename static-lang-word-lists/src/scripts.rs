@@ -0,0 +1,142 @@
+// ISO 15924 code, English name, and the script's name for itself (if it has a
+// common one)
+const SCRIPTS: &[(&str, &str, Option<&str>)] = &[
+    ("Adlm", "Adlam", None),
+    ("Aghb", "Caucasian Albanian", None),
+    ("Ahom", "Ahom", None),
+    ("Arab", "Arabic", Some("العربية")),
+    ("Armi", "Imperial Aramaic", None),
+    ("Armn", "Armenian", Some("Հայոց")),
+    ("Avst", "Avestan", None),
+    ("Bali", "Balinese", None),
+    ("Bamu", "Bamum", None),
+    ("Bass", "Bassa Vah", None),
+    ("Batk", "Batak", None),
+    ("Beng", "Bengali", Some("বাংলা")),
+    ("Bhks", "Bhaiksuki", None),
+    ("Bopo", "Bopomofo", Some("注音符號")),
+    ("Brah", "Brahmi", None),
+    ("Brai", "Braille", None),
+    ("Bugi", "Buginese", None),
+    ("Buhd", "Buhid", None),
+    ("Cakm", "Chakma", None),
+    ("Cans", "Unified Canadian Aboriginal Syllabics", None),
+    ("Cari", "Carian", None),
+    ("Cham", "Cham", None),
+    ("Cher", "Cherokee", Some("ᏣᎳᎩ")),
+    ("Copt", "Coptic", None),
+    ("Cprt", "Cypriot", None),
+    ("Cyrl", "Cyrillic", Some("Кириллица")),
+    ("Deva", "Devanagari", Some("देवनागरी")),
+    ("Dsrt", "Deseret", None),
+    ("Egyp", "Egyptian hieroglyphs", None),
+    ("Ethi", "Ethiopic", Some("ግዕዝ")),
+    ("Geor", "Georgian", Some("ქართული")),
+    ("Glag", "Glagolitic", None),
+    ("Goth", "Gothic", None),
+    ("Gran", "Grantha", None),
+    ("Grek", "Greek", Some("Ελληνικά")),
+    ("Gujr", "Gujarati", Some("ગુજરાતી")),
+    ("Guru", "Gurmukhi", Some("ਗੁਰਮੁਖੀ")),
+    ("Hang", "Hangul", Some("한글")),
+    ("Hani", "Han", Some("漢字")),
+    ("Hano", "Hanunoo", None),
+    ("Hans", "Han (Simplified)", Some("简体字")),
+    ("Hant", "Han (Traditional)", Some("繁體字")),
+    ("Hebr", "Hebrew", Some("עברית")),
+    ("Hira", "Hiragana", Some("ひらがな")),
+    ("Hmng", "Pahawh Hmong", None),
+    ("Hmnp", "Nyiakeng Puachue Hmong", None),
+    ("Ital", "Old Italic", None),
+    ("Jamo", "Jamo", None),
+    ("Java", "Javanese", None),
+    ("Jpan", "Japanese", None),
+    ("Kali", "Kayah Li", None),
+    ("Kana", "Katakana", Some("カタカナ")),
+    ("Khar", "Kharoshthi", None),
+    ("Khmr", "Khmer", Some("ខ្មែរ")),
+    ("Knda", "Kannada", Some("ಕನ್ನಡ")),
+    ("Kore", "Korean", None),
+    ("Lana", "Tai Tham", None),
+    ("Laoo", "Lao", Some("ລາວ")),
+    ("Latn", "Latin", Some("Latina")),
+    ("Lepc", "Lepcha", None),
+    ("Limb", "Limbu", None),
+    ("Linb", "Linear B", None),
+    ("Lisu", "Lisu", None),
+    ("Mand", "Mandaic", None),
+    ("Mlym", "Malayalam", Some("മലയാളം")),
+    ("Mong", "Mongolian", Some("ᠮᠣᠩᠭᠣᠯ")),
+    ("Mtei", "Meetei Mayek", None),
+    ("Mymr", "Myanmar", Some("မြန်မာ")),
+    ("Nkoo", "N’Ko", Some("ߒߞߏ")),
+    ("Ogam", "Ogham", None),
+    ("Olck", "Ol Chiki", Some("ᱚᱞ ᱪᱤᱠᱤ")),
+    ("Orkh", "Old Turkic", None),
+    ("Orya", "Oriya", Some("ଓଡ଼ିଆ")),
+    ("Osge", "Osage", None),
+    ("Phag", "Phags-pa", None),
+    ("Phnx", "Phoenician", None),
+    ("Rohg", "Hanifi Rohingya", None),
+    ("Runr", "Runic", None),
+    ("Samr", "Samaritan", None),
+    ("Saur", "Saurashtra", None),
+    ("Shaw", "Shavian", None),
+    ("Sinh", "Sinhala", Some("සිංහල")),
+    ("Sund", "Sundanese", None),
+    ("Sylo", "Syloti Nagri", None),
+    ("Syrc", "Syriac", Some("ܣܘܪܝܝܐ")),
+    ("Tagb", "Tagbanwa", None),
+    ("Tale", "Tai Le", None),
+    ("Talu", "New Tai Lue", None),
+    ("Taml", "Tamil", Some("தமிழ்")),
+    ("Tavt", "Tai Viet", None),
+    ("Telu", "Telugu", Some("తెలుగు")),
+    ("Tfng", "Tifinagh", Some("ⵜⵉⴼⵉⵏⴰⵖ")),
+    ("Tglg", "Tagalog", None),
+    ("Thaa", "Thaana", Some("ތާނަ")),
+    ("Thai", "Thai", Some("ไทย")),
+    ("Tibt", "Tibetan", Some("བོད་ཡིག")),
+    ("Vaii", "Vai", None),
+    ("Yiii", "Yi", None),
+    ("Zmth", "Mathematical notation", None),
+    ("Zsye", "Emoji", None),
+    ("Zsym", "Symbols", None),
+    ("Zyyy", "Common", None),
+    ("Zzzz", "Unknown", None),
+];
+
+fn find(
+    code: &str,
+) -> Option<&'static (&'static str, &'static str, Option<&'static str>)> {
+    SCRIPTS
+        .iter()
+        .find(|(script, ..)| script.eq_ignore_ascii_case(code))
+}
+
+/// Get the English name of an
+/// [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) script code (e.g.
+/// "Devanagari" for `Deva`), if known.
+///
+/// Codes are matched case-insensitively.
+///
+/// ```
+/// # use static_lang_word_lists::script_name;
+/// assert_eq!(script_name("Deva"), Some("Devanagari"));
+/// assert_eq!(script_name("latn"), Some("Latin"));
+/// assert_eq!(script_name("Qaaa"), None);
+/// ```
+#[must_use]
+pub fn script_name(code: &str) -> Option<&'static str> {
+    find(code).map(|(_, name, _)| *name)
+}
+
+/// Get the name a script has for itself, written in that script (e.g.
+/// "देवनागरी" for `Deva`), if known.
+///
+/// Codes are matched case-insensitively. Not every script has a native name
+/// listed, e.g. historic scripts.
+#[must_use]
+pub fn script_native_name(code: &str) -> Option<&'static str> {
+    find(code).and_then(|(_, _, native_name)| *native_name)
+}
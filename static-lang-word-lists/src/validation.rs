@@ -0,0 +1,176 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt,
+};
+
+use crate::WordList;
+
+/// A problem with a word in a [`WordList`], found by [`WordList::validate`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct WordIssue {
+    /// The index of the word within the word list.
+    pub index: usize,
+    /// What's wrong with the word.
+    pub kind: WordIssueKind,
+}
+
+/// The kinds of problem [`WordList::validate`] can find.
+///
+/// These are characters that are almost always artefacts of how a corpus was
+/// collected, and that would skew the extremes reported for the word.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum WordIssueKind {
+    /// The word contains a byte order mark (U+FEFF), usually left at the start
+    /// of a file by the editor that saved it.
+    ByteOrderMark,
+    /// The word contains a Unicode replacement character (U+FFFD), a sign that
+    /// the corpus was decoded with the wrong encoding.
+    ReplacementCharacter,
+    /// The word contains a bidirectional formatting character (e.g. U+200F
+    /// RIGHT-TO-LEFT MARK), which word lists shouldn't need.
+    BidiControl(char),
+    /// The word contains a control character (e.g. U+0000 NULL).
+    ControlCharacter(char),
+    /// The word starts or ends with a zero-width joiner or non-joiner, or has
+    /// two in a row, where they can't affect shaping.
+    MisplacedJoiner(char),
+    /// The word starts with a combining mark, which has no base to combine
+    /// with.
+    LeadingCombiningMark(char),
+    /// The word has a combining diacritic (U+0300..=U+036F) following a basic
+    /// Latin letter, which usually has a precomposed form.
+    ///
+    /// Word lists are expected to be in Normalization Form C (precomposed), so
+    /// this typically means part of the corpus was decomposed. This is a
+    /// heuristic: some letter & diacritic combinations have no precomposed
+    /// form.
+    Decomposed,
+    /// The word is a duplicate of the word at the given index.
+    Duplicate(usize),
+}
+
+impl fmt::Display for WordIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordIssueKind::ByteOrderMark => {
+                f.write_str("contains a byte order mark")
+            },
+            WordIssueKind::ReplacementCharacter => {
+                f.write_str("contains a replacement character (U+FFFD)")
+            },
+            WordIssueKind::BidiControl(c) => {
+                write!(f, "contains bidi control character U+{:04X}", *c as u32)
+            },
+            WordIssueKind::ControlCharacter(c) => {
+                write!(f, "contains control character U+{:04X}", *c as u32)
+            },
+            WordIssueKind::MisplacedJoiner(c) => {
+                write!(f, "has misplaced joiner U+{:04X}", *c as u32)
+            },
+            WordIssueKind::LeadingCombiningMark(c) => {
+                write!(f, "starts with combining mark U+{:04X}", *c as u32)
+            },
+            WordIssueKind::Decomposed => {
+                f.write_str("has decomposed diacritics (not NFC)")
+            },
+            WordIssueKind::Duplicate(index) => {
+                write!(f, "duplicates word {index}")
+            },
+        }
+    }
+}
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+const fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2066}'..='\u{2069}'
+    )
+}
+
+const fn is_combining_mark(c: char) -> bool {
+    // The combining blocks; marks in script-specific blocks aren't covered
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+fn word_issues(word: &str) -> Vec<WordIssueKind> {
+    let chars = word.chars().collect::<Vec<_>>();
+    let mut issues = Vec::new();
+
+    if let Some(&first) = chars.first()
+        && is_combining_mark(first)
+    {
+        issues.push(WordIssueKind::LeadingCombiningMark(first));
+    }
+
+    for (i, &c) in chars.iter().enumerate() {
+        let issue = match c {
+            '\u{FEFF}' => WordIssueKind::ByteOrderMark,
+            '\u{FFFD}' => WordIssueKind::ReplacementCharacter,
+            c if is_bidi_control(c) => WordIssueKind::BidiControl(c),
+            c if c.is_control() => WordIssueKind::ControlCharacter(c),
+            ZWNJ | ZWJ
+                if i == 0
+                    || i == chars.len() - 1
+                    || matches!(chars[i - 1], ZWNJ | ZWJ) =>
+            {
+                WordIssueKind::MisplacedJoiner(c)
+            },
+            '\u{0300}'..='\u{036F}'
+                if i > 0 && chars[i - 1].is_ascii_alphabetic() =>
+            {
+                WordIssueKind::Decomposed
+            },
+            _ => continue,
+        };
+        if !issues.contains(&issue) {
+            issues.push(issue);
+        }
+    }
+
+    issues
+}
+
+impl WordList {
+    /// Check the words for characters that are likely to be corpus problems,
+    /// such as byte order marks, bidi controls, or decomposed diacritics.
+    ///
+    /// Returns the issues found, in word order. Each distinct issue is reported
+    /// once per word.
+    #[must_use]
+    pub fn validate(&self) -> Vec<WordIssue> {
+        let mut first_seen = HashMap::new();
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, word)| {
+                let duplicate = match first_seen.entry(word) {
+                    Entry::Occupied(entry) => {
+                        Some(WordIssueKind::Duplicate(*entry.get()))
+                    },
+                    Entry::Vacant(entry) => {
+                        entry.insert(index);
+                        None
+                    },
+                };
+                word_issues(word)
+                    .into_iter()
+                    .chain(duplicate)
+                    .map(move |kind| WordIssue { index, kind })
+            })
+            .collect()
+    }
+}
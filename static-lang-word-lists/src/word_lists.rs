@@ -1,27 +1,207 @@
 use std::{
     borrow::Cow,
-    fs, io,
-    ops::{Deref, Index},
+    fmt, fs, io,
+    ops::{Bound, Deref, Index, Range, RangeBounds},
     path::{Path, PathBuf},
-    slice,
-    sync::LazyLock,
+    sync::{Arc, LazyLock, OnceLock},
 };
 
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::newline_delimited_words;
+use crate::{embedded::EmbeddedWords, newline_delimited_words};
 
-// TODO: this can be Box<str>
-pub(crate) type Word = String;
-pub(crate) type WordSource = Box<[Word]>;
+// Provides content_hash, shared with the build script & xtask
+include!("../content_hash.rs");
 
-#[derive(Debug, Deserialize)]
+// Reference counted so that slices of a word list can share its words
+pub(crate) type WordSource = Arc<WordStore>;
+
+/// The words of a word list, in blocks that are loaded when first accessed.
+///
+/// Word lists created at runtime have all their words in a single block, at
+/// the ready. Built-in word lists decompress each block on demand.
+#[derive(Debug)]
+pub(crate) struct WordStore {
+    len: usize,
+    words_per_block: usize,
+    blocks: Box<[OnceLock<WordBuffer>]>,
+    embedded: Option<EmbeddedWords>,
+}
+
+impl WordStore {
+    fn eager(buffer: WordBuffer) -> Self {
+        let len = buffer.ranges.len();
+        WordStore {
+            len,
+            words_per_block: len.max(1),
+            blocks: Box::new([OnceLock::from(buffer)]),
+            embedded: None,
+        }
+    }
+
+    pub(crate) fn lazy(
+        len: usize,
+        words_per_block: usize,
+        embedded: EmbeddedWords,
+    ) -> Self {
+        assert_ne!(words_per_block, 0, "blocks must have words");
+        WordStore {
+            len,
+            words_per_block,
+            blocks: (0..len.div_ceil(words_per_block))
+                .map(|_| OnceLock::new())
+                .collect(),
+            embedded: Some(embedded),
+        }
+    }
+
+    fn word(&self, index: usize) -> &str {
+        let block = index / self.words_per_block;
+        let buffer = self.blocks[block].get_or_init(|| {
+            self.embedded
+                .as_ref()
+                .expect("eager word stores have all their words")
+                .block(block)
+        });
+        buffer.words().get(index % self.words_per_block)
+    }
+}
+
+impl From<WordBuffer> for WordSource {
+    fn from(buffer: WordBuffer) -> Self {
+        Arc::new(WordStore::eager(buffer))
+    }
+}
+
+/// The words of (part of) a word list, stored in one string rather than
+/// allocating each word separately.
+///
+/// Words needn't be contiguous; there may be delimiters (e.g. newlines)
+/// between them.
+#[derive(Debug, Default)]
+pub(crate) struct WordBuffer {
+    text: Box<str>,
+    // The byte range of each word within text
+    ranges: Vec<Range<u32>>,
+}
+
+impl WordBuffer {
+    /// Create a buffer from `text` and the byte `ranges` of its words.
+    ///
+    /// # Panics
+    ///
+    /// If `text` is 4 GiB or larger, as ranges are stored as `u32`s to save
+    /// memory.
+    pub(crate) fn new(
+        text: impl Into<Box<str>>,
+        ranges: Vec<Range<u32>>,
+    ) -> Self {
+        let text = text.into();
+        assert!(
+            u32::try_from(text.len()).is_ok(),
+            "word lists must be smaller than 4 GiB",
+        );
+        WordBuffer { text, ranges }
+    }
+
+    fn words(&self) -> BufferWords<'_> {
+        BufferWords {
+            text: &self.text,
+            ranges: &self.ranges,
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for WordBuffer {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut text = String::new();
+        let ranges = iter
+            .into_iter()
+            .map(|word| {
+                let start = text.len();
+                text.push_str(word.as_ref());
+                offset(start)..offset(text.len())
+            })
+            .collect();
+        WordBuffer::new(text, ranges)
+    }
+}
+
+/// Convert a byte offset into a [`WordBuffer`]'s text for storage.
+pub(crate) fn offset(offset: usize) -> u32 {
+    u32::try_from(offset).expect("word lists must be smaller than 4 GiB")
+}
+
+/// The borrowed words of a [`WordBuffer`].
+#[derive(Debug, Copy, Clone)]
+struct BufferWords<'a> {
+    text: &'a str,
+    ranges: &'a [Range<u32>],
+}
+
+impl<'a> BufferWords<'a> {
+    fn get(self, index: usize) -> &'a str {
+        let range = &self.ranges[index];
+        &self.text[range.start as usize..range.end as usize]
+    }
+}
+
+/// A borrowed run of words from a [`WordStore`].
+#[derive(Debug, Copy, Clone)]
+struct Words<'a> {
+    store: &'a WordStore,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Words<'a> {
+    fn get(self, index: usize) -> &'a str {
+        let len = self.len();
+        assert!(
+            index < len,
+            "index {index} is out of bounds for {len} words"
+        );
+        self.store.word(self.start + index)
+    }
+
+    fn slice(self, range: Range<usize>) -> Self {
+        assert!(range.start <= range.end && range.end <= self.len());
+        Words {
+            store: self.store,
+            start: self.start + range.start,
+            end: self.start + range.end,
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        (self.slice(0..index), self.slice(index..self.len()))
+    }
+
+    const fn len(self) -> usize {
+        self.end - self.start
+    }
+
+    const fn iter(self) -> WordListIter<'a> {
+        WordListIter { words: self }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct WordListMetadata {
     name: Cow<'static, str>,
+    source: Option<Cow<'static, str>>,
     script: Option<Cow<'static, str>>,
     language: Option<Cow<'static, str>>,
+    license: Option<Cow<'static, str>>,
+    #[serde(default)]
+    features: Cow<'static, [Cow<'static, str>]>,
+    // Generated alongside the declarations of built-in word lists, so they
+    // don't need decompressing to be hashed
+    #[serde(skip)]
+    content_hash: Option<u32>,
 }
 
 impl WordListMetadata {
@@ -29,8 +209,33 @@ impl WordListMetadata {
     #[must_use]
     pub(crate) const fn new(
         name: &'static str,
+        source: &'static str,
         script: Option<&'static str>,
         language: Option<&'static str>,
+        license: &'static str,
+        content_hash: u32,
+    ) -> Self {
+        WordListMetadata::new_with_features(
+            name,
+            source,
+            script,
+            language,
+            license,
+            content_hash,
+            &[],
+        )
+    }
+
+    // Used by word_list! for word lists with features
+    #[must_use]
+    pub(crate) const fn new_with_features(
+        name: &'static str,
+        source: &'static str,
+        script: Option<&'static str>,
+        language: Option<&'static str>,
+        license: &'static str,
+        content_hash: u32,
+        features: &'static [Cow<'static, str>],
     ) -> Self {
         // Can't use Option::map in const context
         let script = match script {
@@ -43,8 +248,12 @@ impl WordListMetadata {
         };
         WordListMetadata {
             name: Cow::Borrowed(name),
+            source: Some(Cow::Borrowed(source)),
             script,
             language,
+            license: Some(Cow::Borrowed(license)),
+            features: Cow::Borrowed(features),
+            content_hash: Some(content_hash),
         }
     }
 
@@ -64,8 +273,12 @@ impl WordListMetadata {
     fn new_from_name(name: impl Into<String>) -> Self {
         WordListMetadata {
             name: Cow::Owned(name.into()),
+            source: None,
             script: None,
             language: None,
+            license: None,
+            features: Cow::Borrowed(&[]),
+            content_hash: None,
         }
     }
 }
@@ -74,7 +287,12 @@ impl WordListMetadata {
 #[derive(Debug)]
 pub struct WordList {
     words: EagerOrLazy<WordSource>,
+    // The part of words that this word list covers, None for all of it
+    range: Option<Range<usize>>,
     metadata: WordListMetadata,
+    // For word lists without a content hash in their metadata, computed when
+    // first needed
+    content_hash: OnceLock<u32>,
 }
 
 impl WordList {
@@ -126,7 +344,10 @@ impl WordList {
 
         Ok(WordList {
             metadata: WordListMetadata::new_from_name(name),
-            words: newline_delimited_words(file_content).into(),
+            words: WordSource::from(newline_delimited_words(file_content))
+                .into(),
+            range: None,
+            content_hash: OnceLock::new(),
         })
     }
 
@@ -140,7 +361,15 @@ impl WordList {
     ) -> Self {
         WordList {
             metadata: WordListMetadata::new_from_name(name.into()),
-            words: words.into_iter().map(Into::into).collect::<Vec<_>>().into(),
+            words: WordSource::from(
+                words
+                    .into_iter()
+                    .map(Into::<String>::into)
+                    .collect::<WordBuffer>(),
+            )
+            .into(),
+            range: None,
+            content_hash: OnceLock::new(),
         }
     }
 
@@ -152,7 +381,9 @@ impl WordList {
     ) -> Self {
         WordList {
             words: EagerOrLazy::Lazy(words),
+            range: None,
             metadata,
+            content_hash: OnceLock::new(),
         }
     }
 
@@ -162,13 +393,150 @@ impl WordList {
         WordList {
             metadata: WordListMetadata {
                 name: Cow::Borrowed("stub"),
+                source: None,
                 script: None,
                 language: None,
+                license: None,
+                features: Cow::Borrowed(&[]),
+                content_hash: None,
             },
             words: EagerOrLazy::Lazy(LazyLock::new(|| unreachable!())),
+            range: None,
+            content_hash: OnceLock::new(),
         }
     }
 
+    /// Create a view of part of the word list, named e.g. `latin[10..20]`.
+    ///
+    /// The words aren't copied; the view shares them with this word list.
+    /// Metadata other than the name is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds, or its start is after its end.
+    #[must_use]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => {
+                start.checked_add(1).expect("range start is out of bounds")
+            },
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => {
+                end.checked_add(1).expect("range end is out of bounds")
+            },
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        self.view(start..end, format!("{}[{start}..{end}]", self.name()))
+    }
+
+    /// Split the word list into `n` views of (near) equal size, named e.g.
+    /// `latin[2/8]`.
+    ///
+    /// The words aren't copied; the views share them with this word list.
+    /// The split is deterministic, so the same chunk can be recreated
+    /// elsewhere (e.g. to share work between machines). If there are fewer
+    /// than `n` words, some chunks will be empty.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    #[must_use]
+    pub fn chunks(&self, n: usize) -> Vec<Self> {
+        assert_ne!(n, 0, "can't split a word list into zero chunks");
+        (0..n).map(|index| self.chunk(index, n)).collect()
+    }
+
+    /// Create a view of the `index`th (from zero) of `n` (near) equal parts of
+    /// the word list, named e.g. `latin[2/8]`.
+    ///
+    /// This is the same view as `chunks(n)[index]`, without creating the
+    /// others.
+    ///
+    /// # Panics
+    ///
+    /// If `index` isn't less than `n`.
+    #[must_use]
+    pub fn chunk(&self, index: usize, n: usize) -> Self {
+        assert!(index < n, "chunk {index} is out of bounds for {n} chunks");
+        // Widened, as index * len can overflow for large n
+        let boundary = |index: usize| {
+            let boundary = index as u128 * self.len() as u128 / n as u128;
+            usize::try_from(boundary).expect("boundary is at most len")
+        };
+        self.view(
+            boundary(index)..boundary(index + 1),
+            format!("{}[{}/{n}]", self.name(), index + 1),
+        )
+    }
+
+    /// Create a word list of only the words matching `predicate`, named e.g.
+    /// `latin[filtered]`.
+    ///
+    /// Unlike [`WordList::slice`], the matching words are copied, so indices
+    /// into the new word list don't correspond to this one's. Metadata other
+    /// than the name is unchanged.
+    #[must_use]
+    pub fn filter(&self, mut predicate: impl FnMut(&str) -> bool) -> Self {
+        WordList {
+            words: WordSource::from(
+                self.iter()
+                    .filter(|word| predicate(word))
+                    .collect::<WordBuffer>(),
+            )
+            .into(),
+            range: None,
+            metadata: WordListMetadata {
+                name: Cow::Owned(format!("{}[filtered]", self.name())),
+                content_hash: None,
+                ..self.metadata.clone()
+            },
+            content_hash: OnceLock::new(),
+        }
+    }
+
+    fn view(&self, range: Range<usize>, name: String) -> Self {
+        let len = self.len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range {range:?} is out of bounds for {} with {len} words",
+            self.name(),
+        );
+        let offset = self.offset();
+        WordList {
+            words: EagerOrLazy::Eager(Arc::clone(&self.words)),
+            range: Some(offset + range.start..offset + range.end),
+            metadata: WordListMetadata {
+                name: Cow::Owned(name),
+                content_hash: None,
+                ..self.metadata.clone()
+            },
+            content_hash: OnceLock::new(),
+        }
+    }
+
+    /// Get the index of this word list's first word within the word list it
+    /// was [sliced](WordList::slice) from.
+    ///
+    /// Always `0` for word lists that aren't slices.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.range.as_ref().map_or(0, |range| range.start)
+    }
+
+    fn words(&self) -> Words<'_> {
+        let store: &WordStore = &self.words;
+        let (start, end) = match &self.range {
+            Some(range) => (range.start, range.end),
+            None => (0, store.len),
+        };
+        Words { store, start, end }
+    }
+
     /// Get the name of the word list.
     #[inline]
     #[must_use]
@@ -176,6 +544,41 @@ impl WordList {
         &self.metadata.name
     }
 
+    /// Get the source of the word list, if known.
+    ///
+    /// Built-in word lists always have a source (e.g. `diffenator`), matching
+    /// the name of their crate feature. Word lists created at runtime only
+    /// have a source if their metadata specifies one.
+    #[inline]
+    #[must_use]
+    pub fn source(&self) -> Option<&str> {
+        self.metadata.source.as_deref()
+    }
+
+    /// Get the namespaced identifier of the word list.
+    ///
+    /// Unlike [`WordList::name`], this distinguishes between word lists with
+    /// the same name from different sources.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> WordListId<'_> {
+        WordListId {
+            source: self.source(),
+            name: self.name(),
+        }
+    }
+
+    /// Find the built-in word list that shares a name with this one, if any.
+    ///
+    /// Only word lists enabled through crate features are considered.
+    /// Built-in word lists don't shadow themselves.
+    #[must_use]
+    pub fn shadowed_builtin(&self) -> Option<&'static WordList> {
+        crate::ALL_WORD_LISTS.iter().copied().find(|builtin| {
+            !std::ptr::eq(*builtin, self) && builtin.name() == self.name()
+        })
+    }
+
     /// Get the script of the word list, if known.
     ///
     /// The script is expected to be an [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924)
@@ -187,6 +590,15 @@ impl WordList {
         self.metadata.script.as_deref()
     }
 
+    /// Get the English name of the word list's script (e.g. "Devanagari"), if
+    /// it has a script with a known name.
+    ///
+    /// See [`script_name`](crate::script_name).
+    #[must_use]
+    pub fn script_name(&self) -> Option<&'static str> {
+        self.script().and_then(crate::script_name)
+    }
+
     /// Get the language of the word list, if known.
     ///
     /// The language is expected to be an [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1)
@@ -197,24 +609,63 @@ impl WordList {
         self.metadata.language.as_deref()
     }
 
+    /// Get the OpenType features the word list is intended to be shaped with,
+    /// in [`harfrust`'s syntax](https://docs.rs/harfrust/latest/harfrust/struct.Feature.html#impl-FromStr-for-Feature)
+    /// (e.g. `cswh`, `-liga` or `ss01=1`).
+    ///
+    /// These are on top of the features shapers apply by default, so most word
+    /// lists have none.
+    #[inline]
+    pub fn features(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.metadata.features.iter().map(AsRef::as_ref)
+    }
+
+    /// Get the license of the word list, if known, as an
+    /// [SPDX license expression](https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/)
+    /// (e.g. `Apache-2.0`).
+    ///
+    /// Built-in word lists always have a license, that of their source. Each
+    /// license has a crate feature (e.g. `license-apache-2-0`), so only word
+    /// lists under acceptable licenses need be included.
+    #[inline]
+    #[must_use]
+    pub fn license(&self) -> Option<&str> {
+        self.metadata.license.as_deref()
+    }
+
+    /// Get a hash of the words in the word list, to detect when a word list has
+    /// changed between runs (e.g. upstream corpora being updated).
+    ///
+    /// This is the CRC-32 of each word followed by a newline, so it doesn't
+    /// depend on how the word list file was formatted (e.g. line endings).
+    /// Views created by [`WordList::slice`] hash only their own words.
+    /// Built-in word lists' hashes are generated with the crate, so don't
+    /// decompress anything; other word lists' are computed on the first call.
+    #[must_use]
+    pub fn content_hash(&self) -> u32 {
+        self.metadata.content_hash.unwrap_or_else(|| {
+            *self.content_hash.get_or_init(|| content_hash(self.iter()))
+        })
+    }
+
     /// Iterate through the word list.
     #[must_use]
     pub fn iter(&self) -> WordListIter<'_> {
-        WordListIter(self.words.iter())
+        self.words().iter()
     }
 
     /// Get how many words there are in the word list.
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
-        self.words.len()
+        self.words().len()
     }
 
     /// Returns `true` if there are no words in the word list.
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.words.is_empty()
+        self.len() == 0
     }
 }
 
@@ -222,7 +673,7 @@ impl Index<usize> for WordList {
     type Output = str;
 
     fn index(&self, index: usize) -> &Self::Output {
-        self.words.index(index).deref()
+        self.words().get(index)
     }
 }
 
@@ -249,9 +700,40 @@ impl<T> Deref for EagerOrLazy<T> {
     }
 }
 
-impl From<Vec<String>> for EagerOrLazy<WordSource> {
-    fn from(value: Vec<String>) -> Self {
-        Self::Eager(value.into_boxed_slice())
+/// A namespaced identifier for a [`WordList`], made up of its source and name.
+///
+/// Formats as `source/name` (e.g. `diffenator/diffenator_latin`), or just the
+/// name if the source is unknown.
+///
+/// Returned by [`WordList::id`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WordListId<'a> {
+    source: Option<&'a str>,
+    name: &'a str,
+}
+
+impl<'a> WordListId<'a> {
+    /// The source of the word list, if known.
+    #[inline]
+    #[must_use]
+    pub const fn source(&self) -> Option<&'a str> {
+        self.source
+    }
+
+    /// The name of the word list.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+}
+
+impl fmt::Display for WordListId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            Some(source) => write!(f, "{source}/{}", self.name),
+            None => f.write_str(self.name),
+        }
     }
 }
 
@@ -259,25 +741,39 @@ impl From<Vec<String>> for EagerOrLazy<WordSource> {
 ///
 /// Returned by [`WordList::iter`].
 #[derive(Debug)]
-pub struct WordListIter<'a>(slice::Iter<'a, String>);
+pub struct WordListIter<'a> {
+    words: Words<'a>,
+}
 
 impl<'a> Iterator for WordListIter<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next().map(String::as_ref)
+        let words = &mut self.words;
+        (words.start < words.end).then(|| {
+            words.start += 1;
+            words.store.word(words.start - 1)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.words.len(), Some(self.words.len()))
     }
 }
 
 impl ExactSizeIterator for WordListIter<'_> {
     fn len(&self) -> usize {
-        self.0.len()
+        self.words.len()
     }
 }
 
 impl DoubleEndedIterator for WordListIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.0.next_back().map(String::as_ref)
+        let words = &mut self.words;
+        (words.start < words.end).then(|| {
+            words.end -= 1;
+            words.store.word(words.end)
+        })
     }
 }
 
@@ -301,13 +797,13 @@ pub(crate) mod rayon {
         },
     };
 
-    use super::{WordList, WordListIter};
+    use super::{WordList, WordListIter, Words};
 
     /// A [`rayon`]-powered parallel iterator over a [`WordList`].
     ///
     /// Returned by [`WordList::par_iter`].
     #[derive(Debug)]
-    pub struct ParWordListIter<'a>(&'a [String]);
+    pub struct ParWordListIter<'a>(Words<'a>);
 
     impl<'a> ParallelIterator for ParWordListIter<'a> {
         type Item = &'a str;
@@ -329,7 +825,7 @@ pub(crate) mod rayon {
         type Item = &'a str;
 
         fn into_iter(self) -> Self::IntoIter {
-            WordListIter(self.0.iter())
+            self.0.iter()
         }
 
         fn split_at(self, index: usize) -> (Self, Self) {
@@ -360,7 +856,7 @@ pub(crate) mod rayon {
         type Iter = ParWordListIter<'a>;
 
         fn into_par_iter(self) -> Self::Iter {
-            ParWordListIter(&self.words)
+            ParWordListIter(self.words())
         }
     }
 
@@ -368,7 +864,7 @@ pub(crate) mod rayon {
         /// Iterate through the word list in parallel with `rayon`.
         #[must_use]
         pub fn par_iter(&self) -> ParWordListIter<'_> {
-            ParWordListIter(&self.words)
+            ParWordListIter(self.words())
         }
     }
 }
@@ -0,0 +1,95 @@
+#![allow(missing_docs)]
+
+use static_lang_word_lists::WordList;
+
+#[test]
+fn slices_and_chunks() {
+    let word_list = WordList::define("test", ["a", "b", "c", "d", "e"]);
+
+    let slice = word_list.slice(1..4);
+    assert_eq!(slice.name(), "test[1..4]");
+    assert_eq!(slice.iter().collect::<Vec<_>>(), ["b", "c", "d"]);
+    assert_eq!(&slice[0], "b");
+    assert_eq!(slice.offset(), 1);
+
+    let nested = slice.slice(1..);
+    assert_eq!(nested.iter().collect::<Vec<_>>(), ["c", "d"]);
+    assert_eq!(nested.offset(), 2);
+
+    let chunks = word_list.chunks(2);
+    assert_eq!(chunks.iter().map(WordList::name).collect::<Vec<_>>(), [
+        "test[1/2]",
+        "test[2/2]"
+    ],);
+    assert_eq!(
+        chunks.iter().flat_map(WordList::iter).collect::<Vec<_>>(),
+        word_list.iter().collect::<Vec<_>>(),
+    );
+    assert!(word_list.chunks(8).iter().any(WordList::is_empty));
+}
+
+#[test]
+fn filters() {
+    let word_list = WordList::define("test", ["ab", "bc", "cd"]);
+    let filtered = word_list.filter(|word| word.contains('c'));
+    assert_eq!(filtered.name(), "test[filtered]");
+    assert_eq!(filtered.iter().collect::<Vec<_>>(), ["bc", "cd"]);
+    assert_eq!(filtered.offset(), 0);
+    assert!(word_list.filter(|_| false).is_empty());
+}
+
+#[test]
+fn content_hashes() {
+    let word_list = WordList::define("test", ["a", "b", "c"]);
+    assert_eq!(word_list.content_hash(), crc32fast::hash(b"a\nb\nc\n"));
+    assert_eq!(
+        word_list.slice(1..).content_hash(),
+        WordList::define("other", ["b", "c"]).content_hash(),
+    );
+
+    // Built-in word lists' generated hashes match their words
+    let builtin = &static_lang_word_lists::DIFFENATOR_ARMENIAN;
+    assert_eq!(
+        builtin.content_hash(),
+        WordList::define("copy", builtin.iter()).content_hash(),
+    );
+    assert_eq!(
+        builtin.slice(1..).content_hash(),
+        WordList::define("copy", builtin.iter().skip(1)).content_hash(),
+    );
+}
+
+#[test]
+fn loads_words_between_whitespace() {
+    let path = std::env::temp_dir().join("slwl_whitespace_test.txt");
+    std::fs::write(&path, "  héllo\r\nwörld\n\n\tfoo bar\n").unwrap();
+    let word_list = WordList::load_without_metadata(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(word_list.iter().collect::<Vec<_>>(), [
+        "héllo", "wörld", "foo", "bar"
+    ]);
+    assert_eq!(word_list.iter().next_back(), Some("bar"));
+    assert_eq!(&word_list.slice(1..)[0], "wörld");
+}
+
+#[test]
+fn loads_metadata_features() {
+    let dir = std::env::temp_dir();
+    let path = dir.join("slwl_features_test.txt");
+    let metadata_path = dir.join("slwl_features_test.toml");
+    std::fs::write(&path, "foo\nbar\n").unwrap();
+    std::fs::write(
+        &metadata_path,
+        "name = \"features_test\"\nscript = \"Latn\"\nfeatures = [\"cswh\", \
+         \"-liga\"]\n",
+    )
+    .unwrap();
+    let word_list = WordList::load(&path, &metadata_path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&metadata_path).unwrap();
+
+    assert_eq!(word_list.features().collect::<Vec<_>>(), ["cswh", "-liga"]);
+    assert_eq!(word_list.slice(1..).features().len(), 2);
+    assert_eq!(WordList::define("test", ["a"]).features().len(), 0);
+}
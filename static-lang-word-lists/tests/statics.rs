@@ -1,5 +1,7 @@
 #![allow(missing_docs)]
 
+use std::collections::HashSet;
+
 use static_lang_word_lists::ALL_WORD_LISTS;
 
 #[test]
@@ -8,3 +10,27 @@ fn word_lists_decompress() {
         let _ = word_list.iter().next();
     });
 }
+
+#[test]
+fn word_list_ids_unique() {
+    let mut seen = HashSet::new();
+    ALL_WORD_LISTS.iter().for_each(|word_list| {
+        assert!(
+            word_list.source().is_some(),
+            "{} has no source",
+            word_list.name(),
+        );
+        assert!(seen.insert(word_list.id()), "duplicate {}", word_list.id());
+    });
+}
+
+#[test]
+fn random_access_across_blocks() {
+    let word_list = &static_lang_word_lists::DIFFENATOR_LATIN;
+    let words = word_list.iter().collect::<Vec<_>>();
+    assert_eq!(word_list.len(), words.len());
+    // Past the first block, read backwards so blocks are loaded out of order
+    let slice = word_list.slice(5000..);
+    assert!(slice.iter().rev().eq(words[5000..].iter().rev().copied()));
+    assert_eq!(&slice[slice.len() - 1], *words.last().unwrap());
+}
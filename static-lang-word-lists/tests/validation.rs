@@ -0,0 +1,31 @@
+#![allow(missing_docs)]
+
+use static_lang_word_lists::{WordIssue, WordIssueKind, WordList};
+
+#[test]
+fn finds_corpus_problems() {
+    let word_list = WordList::define("test", [
+        "\u{FEFF}hello",
+        "fine",
+        "\u{200F}שלום",
+        "cafe\u{0301}",
+        "café",
+        "\u{200D}किं",
+        "\u{0301}x",
+        "fine",
+    ]);
+
+    let issues = word_list
+        .validate()
+        .into_iter()
+        .map(|WordIssue { index, kind }| (index, kind))
+        .collect::<Vec<_>>();
+    assert_eq!(issues, [
+        (0, WordIssueKind::ByteOrderMark),
+        (2, WordIssueKind::BidiControl('\u{200F}')),
+        (3, WordIssueKind::Decomposed),
+        (5, WordIssueKind::MisplacedJoiner('\u{200D}')),
+        (6, WordIssueKind::LeadingCombiningMark('\u{0301}')),
+        (7, WordIssueKind::Duplicate(1)),
+    ]);
+}
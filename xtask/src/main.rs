@@ -1,6 +1,11 @@
 #![allow(missing_docs)]
 
-use std::{env, ffi::OsStr, fs, path::PathBuf};
+use std::{
+    env,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, anyhow};
 use pico_args::Arguments;
@@ -25,8 +30,25 @@ fn main() -> anyhow::Result<()> {
 #[serde(deny_unknown_fields)]
 struct WordListMetadata {
     name: String,
+    // Not used for crate-provided word lists, their source is their directory
+    #[allow(dead_code)]
+    source: Option<String>,
     script: Option<String>,
     language: Option<String>,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+/// The `SOURCE.toml` in each source's directory of
+/// `static-lang-word-lists/data`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SourceMetadata {
+    // Not used by the xtask, documents where the word lists came from
+    #[allow(dead_code)]
+    url: String,
+    /// An SPDX license expression
+    license: String,
 }
 
 fn workspace_root() -> PathBuf {
@@ -41,8 +63,11 @@ fn workspace_root() -> PathBuf {
 fn is_metadata_toml(entry: &DirEntry) -> bool {
     entry.file_type().is_file()
         && entry.path().extension() == Some(OsStr::new("toml"))
+        && entry.file_name() != SOURCE_METADATA_FILE_NAME
 }
 
+const SOURCE_METADATA_FILE_NAME: &str = "SOURCE.toml";
+
 fn load_all_metadata() -> anyhow::Result<Vec<(PathBuf, WordListMetadata)>> {
     let data_dir = workspace_root().join("static-lang-word-lists/data");
     let mut metadatas = WalkDir::new(&data_dir)
@@ -86,6 +111,15 @@ fn load_all_metadata() -> anyhow::Result<Vec<(PathBuf, WordListMetadata)>> {
     Ok(metadatas)
 }
 
+/// Load the `SOURCE.toml` of a source's directory.
+fn load_source_metadata(source_dir: &Path) -> anyhow::Result<SourceMetadata> {
+    let path = source_dir.join(SOURCE_METADATA_FILE_NAME);
+    let bytes = fs::read(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    toml::from_slice(&bytes)
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
 fn format_tokens(stream: TokenStream) -> anyhow::Result<String> {
     syn::parse2(stream)
         .map(|file| prettyplease::unparse(&file))
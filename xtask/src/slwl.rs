@@ -1,5 +1,10 @@
 use std::{
-    collections::BTreeSet, fmt::Write as _, fs::OpenOptions, io::Write as _,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    fs,
+    fs::OpenOptions,
+    io::Write as _,
+    iter,
     path::Path,
 };
 
@@ -9,7 +14,8 @@ use pico_args::Arguments;
 use quote::{format_ident, quote};
 
 use crate::{
-    WordListMetadata, format_tokens, load_all_metadata, workspace_root,
+    SourceMetadata, WordListMetadata, format_tokens, load_all_metadata,
+    load_source_metadata, workspace_root,
 };
 
 pub fn main(mut args: Arguments) -> anyhow::Result<()> {
@@ -34,8 +40,20 @@ pub fn main(mut args: Arguments) -> anyhow::Result<()> {
     let data_dir = workspace_root.join("static-lang-word-lists/data");
 
     let metadatas = load_all_metadata()?;
+    // Every source must declare its license, so word lists can be gated by it
+    let sources = metadatas
+        .iter()
+        .map(|(toml_path, _)| {
+            let source_dir = toml_path.parent().unwrap();
+            Ok((
+                word_list_source(toml_path),
+                load_source_metadata(source_dir)?,
+            ))
+        })
+        .collect::<anyhow::Result<BTreeMap<String, SourceMetadata>>>()?;
     let mut chicken_entries = Vec::with_capacity(metadatas.len());
     let mut all_features = BTreeSet::new();
+    let mut license_features = BTreeSet::new();
     let mut word_list_entries = Vec::with_capacity(metadatas.len());
     let mut all_word_lists_entries = Vec::with_capacity(metadatas.len());
 
@@ -46,7 +64,11 @@ pub fn main(mut args: Arguments) -> anyhow::Result<()> {
             name,
             language,
             script,
+            features: shaping_features,
+            ..
         } = metadata;
+        let source = word_list_source(&word_list_path);
+        let license = &sources[&source].license;
         let ident = format_ident!("{}", name.to_shouty_snake_case());
         let path = word_list_path
             .strip_prefix(&data_dir)
@@ -62,17 +84,47 @@ pub fn main(mut args: Arguments) -> anyhow::Result<()> {
             Some(language) => quote! { Some(#language) },
             None => quote! { None },
         };
-        let feature_list =
-            get_features_for_word_list(&word_list_path, metadata);
+        let contents =
+            fs::read_to_string(&word_list_path).unwrap_or_else(|err| {
+                panic!("failed to read {}: {err}", word_list_path.display())
+            });
+        let content_hash = content_hash(contents.split_whitespace());
+        // Written in hex, to match how they're shown elsewhere
+        let content_hash = syn::LitInt::new(
+            &format!("{content_hash:#010x}"),
+            proc_macro2::Span::call_site(),
+        );
+        // Most word lists have no shaping features, so keep their declarations short
+        let metadata_expr = if shaping_features.is_empty() {
+            quote! {
+                crate::WordListMetadata::new(
+                    #name, #source, #script, #language, #license, #content_hash,
+                )
+            }
+        } else {
+            quote! {
+                crate::WordListMetadata::new_with_features(
+                    #name, #source, #script, #language, #license, #content_hash,
+                    &[#( ::std::borrow::Cow::Borrowed(#shaping_features) ),*],
+                )
+            }
+        };
+        let feature_list = get_features_for_word_list(&word_list_path, metadata);
         all_features.extend(feature_list.clone());
         // This is now an expectation as every word list should have a source,
         // and be part of "all"
         debug_assert!(feature_list.len() > 1);
+        // Any of the other features include a word list, but only if its
+        // license's feature is enabled too
+        let license_feature = license_feature(license);
         let feature_cfg_attr_inner = quote! {
-            any(#( feature = #feature_list, )* )
+            all(feature = #license_feature, any(#( feature = #feature_list, )* ))
         };
+        license_features.insert(license_feature);
 
-        chicken_entries.push(quote! { #[cfg(#feature_cfg_attr_inner)] #path });
+        chicken_entries.push(quote! {
+            #[cfg(#feature_cfg_attr_inner)] (#path, #content_hash)
+        });
 
         all_word_lists_entries.push(quote! {
             #[cfg(#feature_cfg_attr_inner)] &#ident,
@@ -83,10 +135,13 @@ pub fn main(mut args: Arguments) -> anyhow::Result<()> {
             #[cfg(all(not(docsrs), #feature_cfg_attr_inner))]
             word_list! {
                 ident: #ident,
-                metadata: crate::WordListMetadata::new(#name, #script, #language),
+                metadata: #metadata_expr,
                 bytes: ::std::include_bytes!(
                     ::std::concat!(::std::env!("OUT_DIR"), '/', #path, ".br")
                 ),
+                index: ::std::include_bytes!(
+                    ::std::concat!(::std::env!("OUT_DIR"), '/', #path, ".idx")
+                ),
             }
             // Is docs.rs + relevant feature
             #[cfg(all(docsrs, #feature_cfg_attr_inner))]
@@ -98,7 +153,7 @@ pub fn main(mut args: Arguments) -> anyhow::Result<()> {
     });
 
     let chicken = quote! {
-        static WORD_LISTS: &[&str] = &[
+        static WORD_LISTS: &[(&str, u32)] = &[
             #(#chicken_entries),*
         ];
     };
@@ -133,27 +188,22 @@ pub fn main(mut args: Arguments) -> anyhow::Result<()> {
             "make sure crate features are added in \
              static-lang-word-lists/Cargo.toml"
         );
-        println!("{}", format_all_features(&all_features));
+        println!("{}", format_all_features(&license_features, &all_features),);
     }
 
     Ok(())
 }
 
+// Provides content_hash, shared with static-lang-word-lists & its build script
+include!("../../static-lang-word-lists/content_hash.rs");
+
 pub fn get_features_for_word_list(
     path: &Path,
     metadata: &WordListMetadata,
 ) -> Vec<String> {
     let mut features = vec![String::from("all")];
 
-    let source = path
-        .parent()
-        .unwrap()
-        .file_name()
-        .unwrap()
-        .to_str()
-        .expect("parent dir of word list wasn't UTF-8")
-        .to_ascii_lowercase();
-    features.push(source);
+    features.push(word_list_source(path));
 
     if let Some(script) = metadata.script.as_ref() {
         features.push(format!("script-{}", script.to_ascii_lowercase()));
@@ -166,14 +216,42 @@ pub fn get_features_for_word_list(
     features
 }
 
+/// The feature word lists under an SPDX license expression need, e.g.
+/// `license-apache-2-0` for `Apache-2.0`.
+fn license_feature(license: &str) -> String {
+    let slug = license
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    format!("license-{}", slug.to_ascii_lowercase())
+}
+
+/// The source of a word list is the (lowercased) name of its directory.
+fn word_list_source(path: &Path) -> String {
+    path.parent()
+        .unwrap()
+        .file_name()
+        .unwrap()
+        .to_str()
+        .expect("parent dir of word list wasn't UTF-8")
+        .to_ascii_lowercase()
+}
+
 fn format_all_features(
-    all_features: impl IntoIterator<Item = impl AsRef<str>>,
+    license_features: &BTreeSet<String>,
+    all_features: &BTreeSet<String>,
 ) -> String {
-    let mut buf = String::from(
-        "[features]\ndefault = [\"diffenator\"]\nrayon = [\"dep:rayon\"]\n",
-    );
-    all_features.into_iter().for_each(|feature| {
-        writeln!(&mut buf, "{} = []", feature.as_ref()).unwrap();
+    // Word lists under every license are available by default
+    let default = iter::once("diffenator")
+        .chain(license_features.iter().map(String::as_str))
+        .map(|feature| format!("\"{feature}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut buf =
+        format!("[features]\ndefault = [{default}]\nrayon = [\"dep:rayon\"]\n");
+    all_features.union(license_features).for_each(|feature| {
+        writeln!(&mut buf, "{feature} = []").unwrap();
     });
     buf
 }